@@ -3,6 +3,7 @@
 
 use crate::client::config::persistence::ClientPaths;
 use crate::client::config::template::CONFIG_TEMPLATE;
+use clap::ValueEnum;
 use nym_bin_common::logging::LoggingSettings;
 use nym_config::defaults::DEFAULT_WEBSOCKET_LISTENING_PORT;
 use nym_config::{
@@ -27,6 +28,8 @@ mod template;
 
 const DEFAULT_CLIENTS_DIR: &str = "clients";
 
+const DEFAULT_UNIX_SOCKET_FILENAME: &str = "nym-client.sock";
+
 /// Derive default path to clients's config directory.
 /// It should get resolved to `$HOME/.nym/mixnodes/<id>/config`
 pub fn default_config_directory<P: AsRef<Path>>(id: P) -> PathBuf {
@@ -112,12 +115,30 @@ impl Config {
     pub fn with_disabled_socket(mut self, disabled: bool) -> Self {
         if disabled {
             self.socket.socket_type = SocketType::None;
-        } else {
+        } else if !self.socket.socket_type.is_enabled() {
             self.socket.socket_type = SocketType::WebSocket;
         }
         self
     }
 
+    pub fn with_unix_socket_path(mut self, unix_socket_path: PathBuf) -> Self {
+        self.socket.unix_socket_path = Some(unix_socket_path);
+        self
+    }
+
+    pub fn with_socket_framing(mut self, framing: SocketFraming) -> Self {
+        self.socket.framing = framing;
+        self
+    }
+
+    /// Path of the unix domain socket the client is going to listen on (if applicable).
+    /// Unless explicitly set, it's placed inside the client's data directory.
+    pub fn unix_socket_path(&self) -> PathBuf {
+        self.socket.unix_socket_path.clone().unwrap_or_else(|| {
+            default_data_directory(&self.base.client.id).join(DEFAULT_UNIX_SOCKET_FILENAME)
+        })
+    }
+
     pub fn with_host(mut self, host: IpAddr) -> Self {
         self.socket.host = host;
         self
@@ -175,10 +196,11 @@ impl Config {
 
 // define_optional_set_inner!(Config, base, BaseClientConfig);
 
-#[derive(Debug, Deserialize, PartialEq, Eq, Serialize, Clone, Copy)]
+#[derive(Debug, Deserialize, PartialEq, Eq, Serialize, Clone, Copy, ValueEnum)]
 #[serde(deny_unknown_fields)]
 pub enum SocketType {
     WebSocket,
+    Unix,
     None,
 }
 
@@ -186,6 +208,34 @@ impl SocketType {
     pub fn is_websocket(&self) -> bool {
         matches!(self, SocketType::WebSocket)
     }
+
+    pub fn is_unix(&self) -> bool {
+        matches!(self, SocketType::Unix)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, SocketType::None)
+    }
+}
+
+/// Encoding of the requests and responses exchanged over the socket.
+#[derive(Debug, Default, Deserialize, PartialEq, Eq, Serialize, Clone, Copy, ValueEnum)]
+#[serde(deny_unknown_fields)]
+pub enum SocketFraming {
+    /// The native protocol, i.e. the binary and text requests of the websocket. Over the unix
+    /// socket each binary request is prefixed with its u64 (big endian) length.
+    #[default]
+    Native,
+
+    /// JSON-RPC 2.0 calls, sent as text messages over the websocket or as newline delimited
+    /// objects over the unix socket.
+    JsonRpc,
+}
+
+impl SocketFraming {
+    pub fn is_jsonrpc(&self) -> bool {
+        matches!(self, SocketFraming::JsonRpc)
+    }
 }
 
 #[derive(Debug, Deserialize, PartialEq, Eq, Serialize)]
@@ -194,6 +244,12 @@ pub struct Socket {
     pub socket_type: SocketType,
     pub host: IpAddr,
     pub listening_port: u16,
+
+    /// Path of the unix domain socket, if `socket_type` is set to `Unix`.
+    /// If not specified, it defaults to `$HOME/.nym/clients/<id>/data/nym-client.sock`
+    pub unix_socket_path: Option<PathBuf>,
+
+    pub framing: SocketFraming,
 }
 
 impl Default for Socket {
@@ -202,6 +258,8 @@ impl Default for Socket {
             socket_type: SocketType::WebSocket,
             host: IpAddr::V4(Ipv4Addr::LOCALHOST),
             listening_port: DEFAULT_WEBSOCKET_LISTENING_PORT,
+            unix_socket_path: None,
+            framing: SocketFraming::Native,
         }
     }
 }
@@ -88,6 +88,8 @@ impl From<SocketV1_1_20_2> for Socket {
             socket_type: value.socket_type.into(),
             host: value.host,
             listening_port: value.listening_port,
+            unix_socket_path: None,
+            framing: Default::default(),
         }
     }
 }
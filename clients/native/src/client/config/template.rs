@@ -72,7 +72,7 @@ gateway_details = '{{ storage_paths.gateway_details }}'
 
 [socket]
 
-# allowed values are 'WebSocket' or 'None'
+# allowed values are 'WebSocket', 'Unix' or 'None'
 socket_type = '{{ socket.socket_type }}'
 
 # if applicable (for the case of 'WebSocket'), the port on which the client
@@ -83,6 +83,15 @@ listening_port = {{ socket.listening_port }}
 # will be listening for incoming requests
 host = '{{ socket.host }}'
 
+# if applicable (for the case of 'Unix'), path to the socket on which the client
+# will be listening for incoming requests. If not set, the socket is placed in the data directory.
+{{#if socket.unix_socket_path }}
+unix_socket_path = '{{ socket.unix_socket_path }}'
+{{/if}}
+
+# encoding of the requests sent over the socket. allowed values are 'Native' or 'JsonRpc'
+framing = '{{ socket.framing }}'
+
 ##### logging configuration options #####
 
 [logging]
@@ -1,8 +1,10 @@
 // Copyright 2021-2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::client::config::Config;
+use crate::client::config::{Config, SocketType};
 use crate::error::ClientError;
+#[cfg(unix)]
+use crate::unix_socket;
 use crate::websocket;
 use futures::channel::mpsc;
 use log::*;
@@ -42,7 +44,7 @@ impl SocketClient {
         SocketClient { config }
     }
 
-    fn start_websocket_listener(
+    fn start_socket_listener(
         config: &Config,
         client_input: ClientInput,
         client_output: ClientOutput,
@@ -51,8 +53,6 @@ impl SocketClient {
         shutdown: nym_task::TaskClient,
         packet_type: PacketType,
     ) {
-        info!("Starting websocket listener...");
-
         let ClientInput {
             connection_command_sender,
             input_sender,
@@ -68,18 +68,44 @@ impl SocketClient {
             ..
         } = client_state;
 
-        let websocket_handler = websocket::HandlerBuilder::new(
+        let (message_router, router_receiver) =
+            websocket::MessageRouter::new(received_buffer_request_sender);
+        message_router.start(router_receiver, shutdown.clone());
+
+        let handler = websocket::HandlerBuilder::new(
             input_sender,
             connection_command_sender,
-            received_buffer_request_sender,
+            message_router,
             self_address,
             shared_lane_queue_lengths,
             reply_controller_sender,
             Some(packet_type),
+            config.socket.framing,
         );
 
-        websocket::Listener::new(config.socket.host, config.socket.listening_port)
-            .start(websocket_handler, shutdown);
+        match config.socket.socket_type {
+            SocketType::WebSocket => {
+                info!("Starting websocket listener...");
+                websocket::Listener::new(config.socket.host, config.socket.listening_port)
+                    .start(handler, shutdown);
+            }
+            #[cfg(unix)]
+            SocketType::Unix => {
+                info!("Starting unix socket listener...");
+                unix_socket::Listener::new(config.unix_socket_path(), config.socket.framing)
+                    .start(handler, shutdown);
+            }
+            _ => unreachable!("the socket type has been validated before starting the client"),
+        }
+    }
+
+    fn validate_socket_type(&self) -> Result<(), ClientError> {
+        match self.config.socket.socket_type {
+            SocketType::WebSocket => Ok(()),
+            #[cfg(unix)]
+            SocketType::Unix => Ok(()),
+            _ => Err(ClientError::InvalidSocketMode),
+        }
     }
 
     /// blocking version of `start_socket` method. Will run forever (or until SIGINT is sent)
@@ -116,9 +142,7 @@ impl SocketClient {
     }
 
     pub async fn start_socket(self) -> Result<TaskManager, ClientError> {
-        if !self.config.socket.socket_type.is_websocket() {
-            return Err(ClientError::InvalidSocketMode);
-        }
+        self.validate_socket_type()?;
 
         let base_builder = self.create_base_client_builder().await?;
         let packet_type = self.config.base.debug.traffic.packet_type;
@@ -128,7 +152,7 @@ impl SocketClient {
         let client_output = started_client.client_output.register_consumer();
         let client_state = started_client.client_state;
 
-        Self::start_websocket_listener(
+        Self::start_socket_listener(
             &self.config,
             client_input,
             client_output,
@@ -145,7 +169,7 @@ impl SocketClient {
     }
 
     pub async fn start_direct(self) -> Result<DirectClient, ClientError> {
-        if self.config.socket.socket_type.is_websocket() {
+        if self.config.socket.socket_type.is_enabled() {
             return Err(ClientError::InvalidSocketMode);
         }
 
@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::client::config::{
-    default_config_directory, default_config_filepath, default_data_directory,
+    default_config_directory, default_config_filepath, default_data_directory, SocketFraming,
+    SocketType,
 };
 use crate::commands::try_upgrade_config;
 use crate::{
@@ -21,6 +22,7 @@ use nym_sphinx::addressing::clients::Recipient;
 use serde::Serialize;
 use std::fmt::Display;
 use std::net::IpAddr;
+use std::path::PathBuf;
 use std::{fs, io};
 use tap::TapFallible;
 
@@ -57,6 +59,18 @@ pub(crate) struct Init {
     #[clap(long)]
     disable_socket: Option<bool>,
 
+    /// Type of the socket the client is going to listen for requests on
+    #[clap(long, value_enum, conflicts_with = "disable_socket")]
+    socket_type: Option<SocketType>,
+
+    /// Path of the unix domain socket (if applicable) to listen for requests on.
+    #[clap(long)]
+    unix_socket_path: Option<PathBuf>,
+
+    /// Encoding of the requests and responses exchanged over the socket
+    #[clap(long, value_enum)]
+    socket_framing: Option<SocketFraming>,
+
     /// Port for the socket (if applicable) to listen on in all subsequent runs
     #[clap(short, long)]
     port: Option<u16>,
@@ -88,6 +102,9 @@ impl From<Init> for OverrideConfig {
         OverrideConfig {
             nym_apis: init_config.nym_apis,
             disable_socket: init_config.disable_socket,
+            socket_type: init_config.socket_type,
+            unix_socket_path: init_config.unix_socket_path,
+            socket_framing: init_config.socket_framing,
             port: init_config.port,
             host: init_config.host,
             fastmode: init_config.fastmode,
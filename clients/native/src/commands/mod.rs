@@ -4,7 +4,7 @@
 use crate::client::config::old_config_v1_1_13::OldConfigV1_1_13;
 use crate::client::config::old_config_v1_1_20::ConfigV1_1_20;
 use crate::client::config::old_config_v1_1_20_2::ConfigV1_1_20_2;
-use crate::client::config::{BaseClientConfig, Config};
+use crate::client::config::{BaseClientConfig, Config, SocketFraming, SocketType};
 use crate::error::ClientError;
 use clap::CommandFactory;
 use clap::{Parser, Subcommand};
@@ -21,6 +21,7 @@ use nym_client_core::error::ClientCoreError;
 use nym_config::OptionalSet;
 use std::error::Error;
 use std::net::IpAddr;
+use std::path::PathBuf;
 
 pub(crate) mod init;
 pub(crate) mod run;
@@ -67,6 +68,9 @@ pub(crate) enum Commands {
 pub(crate) struct OverrideConfig {
     nym_apis: Option<Vec<url::Url>>,
     disable_socket: Option<bool>,
+    socket_type: Option<SocketType>,
+    unix_socket_path: Option<PathBuf>,
+    socket_framing: Option<SocketFraming>,
     port: Option<u16>,
     host: Option<IpAddr>,
     fastmode: bool,
@@ -91,6 +95,9 @@ pub(crate) async fn execute(args: &Cli) -> Result<(), Box<dyn Error + Send + Syn
 pub(crate) fn override_config(config: Config, args: OverrideConfig) -> Config {
     config
         .with_optional(Config::with_disabled_socket, args.disable_socket)
+        .with_optional(Config::with_socket, args.socket_type)
+        .with_optional(Config::with_unix_socket_path, args.unix_socket_path)
+        .with_optional(Config::with_socket_framing, args.socket_framing)
         .with_base(
             BaseClientConfig::with_high_default_traffic_volume,
             args.fastmode,
@@ -3,7 +3,10 @@
 
 use crate::commands::try_load_current_config;
 use crate::{
-    client::{config::Config, SocketClient},
+    client::{
+        config::{Config, SocketFraming, SocketType},
+        SocketClient,
+    },
     commands::{override_config, OverrideConfig},
     error::ClientError,
 };
@@ -13,6 +16,7 @@ use nym_bin_common::version_checker::is_minor_version_compatible;
 use nym_crypto::asymmetric::identity;
 use std::error::Error;
 use std::net::IpAddr;
+use std::path::PathBuf;
 
 #[derive(Args, Clone)]
 pub(crate) struct Run {
@@ -38,6 +42,18 @@ pub(crate) struct Run {
     #[clap(long)]
     disable_socket: Option<bool>,
 
+    /// Type of the socket the client is going to listen for requests on
+    #[clap(long, value_enum, conflicts_with = "disable_socket")]
+    socket_type: Option<SocketType>,
+
+    /// Path of the unix domain socket (if applicable) to listen for requests on.
+    #[clap(long)]
+    unix_socket_path: Option<PathBuf>,
+
+    /// Encoding of the requests and responses exchanged over the socket
+    #[clap(long, value_enum)]
+    socket_framing: Option<SocketFraming>,
+
     /// Port for the socket to listen on
     #[clap(short, long)]
     port: Option<u16>,
@@ -66,6 +82,9 @@ impl From<Run> for OverrideConfig {
         OverrideConfig {
             nym_apis: run_config.nym_apis,
             disable_socket: run_config.disable_socket,
+            socket_type: run_config.socket_type,
+            unix_socket_path: run_config.unix_socket_path,
+            socket_framing: run_config.socket_framing,
             port: run_config.port,
             host: run_config.host,
             fastmode: run_config.fastmode,
@@ -3,4 +3,6 @@
 
 pub mod client;
 pub mod error;
+#[cfg(unix)]
+pub mod unix_socket;
 pub mod websocket;
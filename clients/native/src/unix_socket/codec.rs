@@ -0,0 +1,150 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::client::config::SocketFraming;
+use bytes::{Bytes, BytesMut};
+use std::io;
+use tokio_util::codec::{Decoder, Encoder, LengthDelimitedCodec, LinesCodec, LinesCodecError};
+
+// keep it consistent with the default maximum message size of the websocket
+const MAX_FRAME_LENGTH: usize = 64 << 20;
+
+// all binary frames are prefixed with their u64 length
+const LENGTH_FIELD_LENGTH: usize = 8;
+
+/// Single request or response exchanged over the unix socket.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Frame {
+    Binary(Vec<u8>),
+    Text(String),
+}
+
+pub(crate) enum SocketCodec {
+    Native(LengthDelimitedCodec),
+    JsonRpc(LinesCodec),
+}
+
+impl SocketCodec {
+    pub(crate) fn new(framing: SocketFraming) -> Self {
+        match framing {
+            SocketFraming::Native => SocketCodec::Native(
+                LengthDelimitedCodec::builder()
+                    .length_field_length(LENGTH_FIELD_LENGTH)
+                    .max_frame_length(MAX_FRAME_LENGTH)
+                    .new_codec(),
+            ),
+            SocketFraming::JsonRpc => {
+                SocketCodec::JsonRpc(LinesCodec::new_with_max_length(MAX_FRAME_LENGTH))
+            }
+        }
+    }
+}
+
+fn lines_error_to_io(err: LinesCodecError) -> io::Error {
+    match err {
+        LinesCodecError::Io(err) => err,
+        other => io::Error::new(io::ErrorKind::InvalidData, other),
+    }
+}
+
+impl Decoder for SocketCodec {
+    type Item = Frame;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self {
+            SocketCodec::Native(codec) => Ok(codec
+                .decode(src)?
+                .map(|frame| Frame::Binary(frame.to_vec()))),
+            SocketCodec::JsonRpc(codec) => codec
+                .decode(src)
+                .map(|line| line.map(Frame::Text))
+                .map_err(lines_error_to_io),
+        }
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self {
+            SocketCodec::Native(codec) => Ok(codec
+                .decode_eof(src)?
+                .map(|frame| Frame::Binary(frame.to_vec()))),
+            SocketCodec::JsonRpc(codec) => codec
+                .decode_eof(src)
+                .map(|line| line.map(Frame::Text))
+                .map_err(lines_error_to_io),
+        }
+    }
+}
+
+impl Encoder<Frame> for SocketCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: Frame, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        match (self, item) {
+            (SocketCodec::Native(codec), Frame::Binary(frame)) => {
+                codec.encode(Bytes::from(frame), dst)
+            }
+            (SocketCodec::JsonRpc(codec), Frame::Text(line)) => {
+                codec.encode(line, dst).map_err(lines_error_to_io)
+            }
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the frame does not match the socket framing",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binary_frames_are_prefixed_with_u64_length() {
+        let mut codec = SocketCodec::new(SocketFraming::Native);
+        let mut buf = BytesMut::new();
+        codec
+            .encode(Frame::Binary(vec![1, 2, 3]), &mut buf)
+            .unwrap();
+
+        assert_eq!(buf.as_ref(), &[0, 0, 0, 0, 0, 0, 0, 3, 1, 2, 3]);
+        assert_eq!(
+            codec.decode(&mut buf).unwrap(),
+            Some(Frame::Binary(vec![1, 2, 3]))
+        );
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn incomplete_binary_frames_are_not_decoded() {
+        let mut codec = SocketCodec::new(SocketFraming::Native);
+        let mut buf = BytesMut::from(&[0, 0, 0, 0, 0, 0, 0, 3, 1, 2][..]);
+        assert!(codec.decode(&mut buf).unwrap().is_none());
+    }
+
+    #[test]
+    fn text_frames_are_newline_delimited() {
+        let mut codec = SocketCodec::new(SocketFraming::JsonRpc);
+        let mut buf = BytesMut::new();
+        codec
+            .encode(Frame::Text(r#"{"foo":42}"#.to_string()), &mut buf)
+            .unwrap();
+
+        assert_eq!(buf.as_ref(), b"{\"foo\":42}\n");
+        assert_eq!(
+            codec.decode(&mut buf).unwrap(),
+            Some(Frame::Text(r#"{"foo":42}"#.to_string()))
+        );
+    }
+
+    #[test]
+    fn mismatched_frames_are_rejected() {
+        let mut buf = BytesMut::new();
+        assert!(SocketCodec::new(SocketFraming::Native)
+            .encode(Frame::Text("foo".to_string()), &mut buf)
+            .is_err());
+        assert!(SocketCodec::new(SocketFraming::JsonRpc)
+            .encode(Frame::Binary(vec![1]), &mut buf)
+            .is_err());
+    }
+}
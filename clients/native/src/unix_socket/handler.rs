@@ -0,0 +1,94 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::codec::{Frame, SocketCodec};
+use crate::client::config::SocketFraming;
+use crate::websocket::handler::Handler;
+use futures::{SinkExt, StreamExt};
+use log::*;
+use nym_client_websocket_requests::{jsonrpc, responses::ServerResponse};
+use nym_sphinx::receiver::ReconstructedMessage;
+use tokio::net::UnixStream;
+use tokio_util::codec::Framed;
+
+fn received_frame(framing: SocketFraming, message: ReconstructedMessage) -> Frame {
+    match framing {
+        SocketFraming::Native => Frame::Binary(ServerResponse::Received(message).into_binary()),
+        SocketFraming::JsonRpc => Frame::Text(jsonrpc::received_notification(message)),
+    }
+}
+
+async fn handle_frame(handler: &mut Handler, frame: Frame) -> Option<Frame> {
+    match frame {
+        Frame::Binary(request) => handler
+            .handle_binary_request(&request)
+            .await
+            .map(Frame::Binary),
+        Frame::Text(request) => handler
+            .handle_jsonrpc_request(&request)
+            .await
+            .map(Frame::Text),
+    }
+}
+
+/// Serves a single unix socket connection. The requests themselves are handled exactly the same
+/// way as the ones received over the websocket, only the transport is different.
+// take ownership of the handler to make sure its `drop` is called after this is done
+pub(crate) async fn handle_connection(
+    mut handler: Handler,
+    stream: UnixStream,
+    framing: SocketFraming,
+    mut task_client: nym_task::TaskClient,
+) {
+    // We don't want a crash in the connection handler to trigger a shutdown of the whole
+    // process.
+    task_client.mark_as_success();
+
+    let mut framed = Framed::new(stream, SocketCodec::new(framing));
+
+    // tell the router to start sending stuff to us
+    let mut msg_receiver = handler.register();
+
+    while !task_client.is_shutdown() {
+        tokio::select! {
+            // we can either get a client request from the socket
+            frame = framed.next() => {
+                let frame = match frame {
+                    Some(Ok(frame)) => frame,
+                    Some(Err(err)) => {
+                        warn!("failed to obtain message from the unix socket! stopping connection handler: {err}");
+                        break;
+                    }
+                    None => break,
+                };
+
+                if let Some(response) = handle_frame(&mut handler, frame).await {
+                    if let Err(err) = framed.send(response).await {
+                        warn!("Failed to send message over the unix socket: {err}. Assuming the connection is dead.");
+                        break;
+                    }
+                }
+            }
+            // or a reconstructed mix message that we need to push back to the client
+            mix_messages = msg_receiver.next() => {
+                let Some(mix_messages) = mix_messages else {
+                    error!("mix messages sender was unexpectedly closed! this shouldn't have ever happened!");
+                    return
+                };
+                let mut frames = futures::stream::iter(
+                    mix_messages
+                        .into_iter()
+                        .map(|message| Ok(received_frame(framing, message))),
+                );
+                if let Err(err) = framed.send_all(&mut frames).await {
+                    warn!("failed to send sphinx packets back to the client - {err}, assuming the connection is dead");
+                    break;
+                }
+            }
+            _ = task_client.recv() => {
+                log::trace!("Unix socket handler: Received shutdown");
+            }
+        }
+    }
+    log::debug!("Unix socket handler: Exiting");
+}
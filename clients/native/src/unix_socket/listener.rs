@@ -0,0 +1,245 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::handler::handle_connection;
+use crate::client::config::SocketFraming;
+use crate::websocket::HandlerBuilder;
+use log::*;
+use std::fs;
+use std::io;
+use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+use std::process;
+use tokio::net::UnixListener;
+use tokio::{sync::mpsc, task::JoinHandle};
+
+// only the user running the client is allowed to talk to it
+const SOCKET_PERMISSIONS: u32 = 0o600;
+const STAGING_DIRECTORY_PERMISSIONS: u32 = 0o700;
+
+// binds the socket inside a freshly created directory that is only accessible to us, so that
+// nobody could connect to it before its permissions got restricted, and only then links it
+// to its final location. Unlike a rename, linking never replaces whatever might already be there
+fn bind_restricted(path: &Path) -> io::Result<UnixListener> {
+    let parent = path.parent().unwrap_or_else(|| Path::new("."));
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid socket path"))?;
+
+    let staging_dir = parent.join(format!(
+        ".{}.{}",
+        file_name.to_string_lossy(),
+        process::id()
+    ));
+    fs::DirBuilder::new()
+        .mode(STAGING_DIRECTORY_PERMISSIONS)
+        .create(&staging_dir)?;
+
+    let staged_path = staging_dir.join(file_name);
+    let bound = UnixListener::bind(&staged_path).and_then(|listener| {
+        fs::set_permissions(&staged_path, fs::Permissions::from_mode(SOCKET_PERMISSIONS))?;
+        fs::hard_link(&staged_path, path)?;
+        Ok(listener)
+    });
+
+    if let Err(err) = fs::remove_dir_all(&staging_dir) {
+        warn!(
+            "failed to remove the temporary socket directory {} - {err}",
+            staging_dir.display()
+        );
+    }
+    bound
+}
+
+pub(crate) struct Listener {
+    path: PathBuf,
+    framing: SocketFraming,
+    active_connections: usize,
+}
+
+impl Listener {
+    pub(crate) fn new(path: PathBuf, framing: SocketFraming) -> Self {
+        Listener {
+            path,
+            framing,
+            active_connections: 0,
+        }
+    }
+
+    fn bind(&self) -> io::Result<UnixListener> {
+        // the socket file might have been left behind by a previous run that didn't exit cleanly.
+        // however, make sure to not remove anything that isn't a socket or that is still in use
+        if let Ok(metadata) = fs::symlink_metadata(&self.path) {
+            if metadata.file_type().is_socket() {
+                match std::os::unix::net::UnixStream::connect(&self.path) {
+                    Ok(_) => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::AddrInUse,
+                            "another process is already listening on the socket",
+                        ))
+                    }
+                    Err(err) if err.kind() == io::ErrorKind::ConnectionRefused => {
+                        fs::remove_file(&self.path)?
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        bind_restricted(&self.path)
+    }
+
+    pub(crate) async fn run(
+        &mut self,
+        handler: HandlerBuilder,
+        mut task_client: nym_task::TaskClient,
+    ) {
+        let unix_listener = match self.bind() {
+            Ok(listener) => listener,
+            Err(err) => {
+                error!("Failed to bind to {} - {err}. Are you sure nothing else is using the specified socket and your user has sufficient permission to create it?", self.path.display());
+                process::exit(1);
+            }
+        };
+
+        let (finished_sender, mut finished_receiver) = mpsc::unbounded_channel();
+
+        loop {
+            tokio::select! {
+                // When a handler finishes we check if shutdown is signalled
+                Some(()) = finished_receiver.recv() => {
+                    self.active_connections = self.active_connections.saturating_sub(1);
+                    if task_client.is_shutdown() && self.active_connections == 0 {
+                        log::trace!("Unix socket listener: detected shutdown after all connections closed");
+                        break;
+                    }
+                }
+                // ... but when there are no connected clients at the time of shutdown being
+                // signalled, we handle it here.
+                _ = task_client.recv(), if !task_client.is_shutdown() => {
+                    if self.active_connections == 0 {
+                        log::trace!("Not connected: shutting down");
+                        break;
+                    }
+                }
+                new_conn = unix_listener.accept(), if !task_client.is_shutdown() => {
+                    match new_conn {
+                        Ok((stream, _)) => {
+                            debug!("Received connection on {}", self.path.display());
+
+                            let finished_sender = finished_sender.clone();
+                            let fresh_handler = handler.create_active_handler();
+                            let task_client_handler = task_client.clone();
+                            let framing = self.framing;
+                            tokio::spawn(async move {
+                                handle_connection(fresh_handler, stream, framing, task_client_handler).await;
+                                finished_sender.send(()).ok();
+                            });
+                            self.active_connections += 1;
+                            debug!("there are now {} active unix socket connections", self.active_connections);
+                        }
+                        Err(err) => warn!("failed to get client: {err}"),
+                    }
+                }
+            }
+        }
+
+        if let Err(err) = fs::remove_file(&self.path) {
+            warn!(
+                "failed to remove the unix socket at {} - {err}",
+                self.path.display()
+            );
+        }
+        log::debug!("Unix socket listener: Exiting");
+    }
+
+    pub(crate) fn start(
+        mut self,
+        handler: HandlerBuilder,
+        shutdown: nym_task::TaskClient,
+    ) -> JoinHandle<()> {
+        info!("Running unix socket on {}", self.path.display());
+
+        tokio::spawn(async move { self.run(handler, shutdown).await })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn socket_is_created_with_restricted_permissions() {
+        let dir = std::env::temp_dir().join(format!("nym-client-socket-test-{}", process::id()));
+        let path = dir.join("client.sock");
+        fs::create_dir_all(&dir).unwrap();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+        let _listener = runtime.block_on(async { bind_restricted(&path) }).unwrap();
+
+        let metadata = fs::symlink_metadata(&path).unwrap();
+        assert!(metadata.file_type().is_socket());
+        assert_eq!(metadata.permissions().mode() & 0o777, SOCKET_PERMISSIONS);
+
+        // the only thing left behind is the socket itself
+        let entries = fs::read_dir(&dir).unwrap().count();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(entries, 1);
+    }
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("nym-client-{name}-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn regular_file_is_never_replaced() {
+        let dir = test_dir("socket-file-test");
+        let path = dir.join("client.sock");
+        fs::write(&path, "important").unwrap();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+        let bound = runtime.block_on(async { bind_restricted(&path) });
+        let kept = fs::read_to_string(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(bound.unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(kept, "important");
+    }
+
+    #[test]
+    fn only_stale_sockets_are_replaced() {
+        let dir = test_dir("stale-socket-test");
+        let path = dir.join("client.sock");
+        let listener = Listener::new(path.clone(), SocketFraming::Native);
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .unwrap();
+
+        // somebody is still listening on the socket
+        let live = std::os::unix::net::UnixListener::bind(&path).unwrap();
+        let rebound = runtime.block_on(async { listener.bind() });
+        assert_eq!(rebound.unwrap_err().kind(), io::ErrorKind::AddrInUse);
+        assert!(std::os::unix::net::UnixStream::connect(&path).is_ok());
+
+        // dropping the listener leaves the socket file behind
+        drop(live);
+        let rebound = runtime.block_on(async { listener.bind() }).unwrap();
+        let connected = std::os::unix::net::UnixStream::connect(&path).is_ok();
+        drop(rebound);
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(connected);
+    }
+}
@@ -0,0 +1,8 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+pub(crate) use listener::Listener;
+
+pub(crate) mod codec;
+pub(crate) mod handler;
+pub(crate) mod listener;
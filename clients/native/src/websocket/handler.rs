@@ -1,17 +1,14 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use futures::channel::mpsc;
+use super::router::{MessageRouter, SubscriberId};
+use crate::client::config::SocketFraming;
 use futures::{SinkExt, StreamExt};
 use log::*;
+use nym_client_core::client::inbound_messages::{InputMessage, InputMessageSender};
+use nym_client_core::client::received_buffer::ReconstructedMessagesReceiver;
 use nym_client_core::client::replies::reply_controller::requests::ReplyControllerSender;
-use nym_client_core::client::{
-    inbound_messages::{InputMessage, InputMessageSender},
-    received_buffer::{
-        ReceivedBufferMessage, ReceivedBufferRequestSender, ReconstructedMessagesReceiver,
-    },
-};
-use nym_client_websocket_requests::{requests::ClientRequest, responses::ServerResponse};
+use nym_client_websocket_requests::{jsonrpc, requests::ClientRequest, responses::ServerResponse};
 use nym_sphinx::addressing::clients::Recipient;
 use nym_sphinx::anonymous_replies::requests::AnonymousSenderTag;
 use nym_sphinx::params::PacketType;
@@ -19,6 +16,9 @@ use nym_sphinx::receiver::ReconstructedMessage;
 use nym_task::connections::{
     ConnectionCommand, ConnectionCommandSender, ConnectionId, LaneQueueLengths, TransmissionLane,
 };
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::net::TcpStream;
 use tokio::time::Instant;
@@ -33,51 +33,60 @@ enum ReceivedResponseType {
     #[default]
     Binary,
     Text,
+    JsonRpc,
 }
 
 pub(crate) struct HandlerBuilder {
     msg_input: InputMessageSender,
     client_connection_tx: ConnectionCommandSender,
-    buffer_requester: ReceivedBufferRequestSender,
+    router: MessageRouter,
     self_full_address: Recipient,
     lane_queue_lengths: LaneQueueLengths,
     reply_controller_sender: ReplyControllerSender,
     packet_type: Option<PacketType>,
+    framing: SocketFraming,
+    next_connection_id: Arc<AtomicU64>,
 }
 
 impl HandlerBuilder {
     pub(crate) fn new(
         msg_input: InputMessageSender,
         client_connection_tx: ConnectionCommandSender,
-        buffer_requester: ReceivedBufferRequestSender,
+        router: MessageRouter,
         self_full_address: &Recipient,
         lane_queue_lengths: LaneQueueLengths,
         reply_controller_sender: ReplyControllerSender,
         packet_type: Option<PacketType>,
+        framing: SocketFraming,
     ) -> Self {
         Self {
             msg_input,
             client_connection_tx,
-            buffer_requester,
+            router,
             self_full_address: *self_full_address,
             lane_queue_lengths,
             reply_controller_sender,
             packet_type,
+            framing,
+            next_connection_id: Arc::new(AtomicU64::new(1)),
         }
     }
 
-    // TODO: make sure we only ever have one active handler
     pub fn create_active_handler(&self) -> Handler {
         Handler {
             msg_input: self.msg_input.clone(),
             client_connection_tx: self.client_connection_tx.clone(),
-            buffer_requester: self.buffer_requester.clone(),
+            router: self.router.clone(),
+            subscriber_id: None,
             self_full_address: self.self_full_address,
             socket: None,
             received_response_type: Default::default(),
             lane_queue_lengths: self.lane_queue_lengths.clone(),
             reply_controller_sender: self.reply_controller_sender.clone(),
             packet_type: self.packet_type,
+            framing: self.framing,
+            connection_ids: HashMap::new(),
+            next_connection_id: Arc::clone(&self.next_connection_id),
         }
     }
 }
@@ -85,35 +94,73 @@ impl HandlerBuilder {
 pub(crate) struct Handler {
     msg_input: InputMessageSender,
     client_connection_tx: ConnectionCommandSender,
-    buffer_requester: ReceivedBufferRequestSender,
+    router: MessageRouter,
+    subscriber_id: Option<SubscriberId>,
     self_full_address: Recipient,
     socket: Option<WebSocketStream<TcpStream>>,
     received_response_type: ReceivedResponseType,
     lane_queue_lengths: LaneQueueLengths,
     reply_controller_sender: ReplyControllerSender,
     packet_type: Option<PacketType>,
+    framing: SocketFraming,
+
+    // connection ids are chosen by the websocket clients themselves, so in order to prevent
+    // multiple clients from interfering with each other's lanes, each id gets mapped onto
+    // one that's unique across all of the connections
+    connection_ids: HashMap<ConnectionId, ConnectionId>,
+    next_connection_id: Arc<AtomicU64>,
 }
 
 impl Drop for Handler {
     fn drop(&mut self) {
-        if self
-            .buffer_requester
-            .unbounded_send(ReceivedBufferMessage::ReceiverDisconnect)
-            .is_err()
-        {
-            error!("we failed to disconnect the receiver from the buffer! presumably the shutdown procedure has been initiated!")
+        if let Some(subscriber_id) = self.subscriber_id {
+            self.router.unregister(subscriber_id)
+        }
+
+        // the websocket client is gone, so nobody is going to close its lanes anymore
+        for internal_id in self.connection_ids.values() {
+            if self
+                .client_connection_tx
+                .unbounded_send(ConnectionCommand::Close(*internal_id))
+                .is_err()
+            {
+                debug!("failed to close connection {internal_id} - presumably we're shutting down");
+                break;
+            }
         }
     }
 }
 
 impl Handler {
+    fn lane(&mut self, connection_id: Option<ConnectionId>) -> TransmissionLane {
+        // We map the absence of a connection id as going into the general lane.
+        let Some(connection_id) = connection_id else {
+            return TransmissionLane::General;
+        };
+
+        let next_connection_id = &self.next_connection_id;
+        let internal_id = *self
+            .connection_ids
+            .entry(connection_id)
+            .or_insert_with(|| next_connection_id.fetch_add(1, Ordering::Relaxed));
+        TransmissionLane::ConnectionId(internal_id)
+    }
+
     async fn get_lane_queue_length(&self, connection_id: ConnectionId) -> Option<ServerResponse> {
         let req_start = Instant::now();
 
+        // if we have never seen this connection, there's nothing queued up for it
+        let Some(internal_id) = self.connection_ids.get(&connection_id).copied() else {
+            return Some(ServerResponse::LaneQueueLength {
+                lane: connection_id,
+                queue_length: 0,
+            });
+        };
+
         // get the base queue length
         // Note that this does _NOT_ take into account the packets that have been received but not
         // yet reach `OutQueueControl`, so it might be a tad low.
-        let conn_lane = TransmissionLane::ConnectionId(connection_id);
+        let conn_lane = TransmissionLane::ConnectionId(internal_id);
         let Ok(base_length) = self
             .lane_queue_lengths
             .lock()
@@ -126,7 +173,7 @@ impl Handler {
         // get the number of pending replies waiting for reply surbs
         let reply_queue_length = self
             .reply_controller_sender
-            .get_lane_queue_length(connection_id)
+            .get_lane_queue_length(internal_id)
             .await;
 
         let queue_length = base_length + reply_queue_length;
@@ -160,10 +207,7 @@ impl Handler {
             message.len() as f64 / 1024.0
         );
 
-        // We map the absence of a connection id as going into the general lane.
-        let lane = connection_id.map_or(TransmissionLane::General, |id| {
-            TransmissionLane::ConnectionId(id)
-        });
+        let lane = self.lane(connection_id);
 
         // the ack control is now responsible for chunking, etc.
         let input_msg = InputMessage::new_regular(recipient, message, lane, self.packet_type);
@@ -173,7 +217,7 @@ impl Handler {
             .expect("InputMessageReceiver has stopped receiving!");
 
         // Only reply back with a `LaneQueueLength` if the sender providided a connection id
-        let TransmissionLane::ConnectionId(connection_id) = lane else {
+        let Some(connection_id) = connection_id else {
           return None
         };
 
@@ -192,10 +236,7 @@ impl Handler {
             message.len() as f64 / 1024.0
         );
 
-        // We map the absence of a connection id as going into the general lane.
-        let lane = connection_id.map_or(TransmissionLane::General, |id| {
-            TransmissionLane::ConnectionId(id)
-        });
+        let lane = self.lane(connection_id);
 
         let input_msg =
             InputMessage::new_anonymous(recipient, message, reply_surbs, lane, self.packet_type);
@@ -205,7 +246,7 @@ impl Handler {
             .expect("InputMessageReceiver has stopped receiving!");
 
         // Only reply back with a `LaneQueueLength` if the sender providided a connection id
-        let TransmissionLane::ConnectionId(connection_id) = lane else {
+        let Some(connection_id) = connection_id else {
           return None
         };
 
@@ -220,10 +261,13 @@ impl Handler {
     ) -> Option<ServerResponse> {
         info!("Attempting to send {:.2} kiB reply message to {recipient_tag} on connection_id {connection_id:?}", message.len() as f64 / 1024.0);
 
-        // We map the absence of a connection id as going into the general lane.
-        let lane = connection_id.map_or(TransmissionLane::General, |id| {
-            TransmissionLane::ConnectionId(id)
-        });
+        // we're conversing with this particular sender, so make sure all of its subsequent
+        // messages end up with us rather than with other connected clients
+        if let Some(subscriber_id) = self.subscriber_id {
+            self.router.subscribe(subscriber_id, vec![recipient_tag]);
+        }
+
+        let lane = self.lane(connection_id);
 
         let input_msg = InputMessage::new_reply(recipient_tag, message, lane, self.packet_type);
         self.msg_input
@@ -232,7 +276,7 @@ impl Handler {
             .expect("InputMessageReceiver has stopped receiving!");
 
         // Only reply back with a `LaneQueueLength` if the sender providided a connection id
-        let TransmissionLane::ConnectionId(connection_id) = lane else {
+        let Some(connection_id) = connection_id else {
           return None
         };
 
@@ -243,17 +287,42 @@ impl Handler {
         ServerResponse::SelfAddress(Box::new(self.self_full_address))
     }
 
-    fn handle_closed_connection(&self, connection_id: u64) -> Option<ServerResponse> {
+    fn handle_closed_connection(&mut self, connection_id: u64) -> Option<ServerResponse> {
+        let Some(internal_id) = self.connection_ids.remove(&connection_id) else {
+            debug!("attempted to close unknown connection {connection_id}");
+            return None;
+        };
         self.client_connection_tx
-            .unbounded_send(ConnectionCommand::Close(connection_id))
+            .unbounded_send(ConnectionCommand::Close(internal_id))
             .unwrap();
         None
     }
 
+    fn handle_subscribe(&self, sender_tags: Vec<AnonymousSenderTag>) -> Option<ServerResponse> {
+        if let Some(subscriber_id) = self.subscriber_id {
+            self.router.subscribe(subscriber_id, sender_tags)
+        }
+        None
+    }
+
+    fn handle_unsubscribe(&self, sender_tags: Vec<AnonymousSenderTag>) -> Option<ServerResponse> {
+        if let Some(subscriber_id) = self.subscriber_id {
+            self.router.unsubscribe(subscriber_id, sender_tags)
+        }
+        None
+    }
+
     async fn handle_get_lane_queue_length(&self, connection_id: u64) -> Option<ServerResponse> {
         self.get_lane_queue_length(connection_id).await
     }
 
+    /// Tells the router to start sending received messages to this handler.
+    pub(crate) fn register(&mut self) -> ReconstructedMessagesReceiver {
+        let (subscriber_id, reconstructed_receiver) = self.router.register();
+        self.subscriber_id = Some(subscriber_id);
+        reconstructed_receiver
+    }
+
     async fn handle_request(&mut self, request: ClientRequest) -> Option<ServerResponse> {
         match request {
             ClientRequest::Send {
@@ -281,6 +350,27 @@ impl Handler {
             ClientRequest::SelfAddress => Some(self.handle_self_address()),
             ClientRequest::ClosedConnection(id) => self.handle_closed_connection(id),
             ClientRequest::GetLaneQueueLength(id) => self.handle_get_lane_queue_length(id).await,
+            ClientRequest::Subscribe { sender_tags } => self.handle_subscribe(sender_tags),
+            ClientRequest::Unsubscribe { sender_tags } => self.handle_unsubscribe(sender_tags),
+        }
+    }
+
+    pub(crate) async fn handle_binary_request(&mut self, msg: &[u8]) -> Option<Vec<u8>> {
+        let response = match ClientRequest::try_from_binary(msg) {
+            Err(err) => Some(ServerResponse::Error(err)),
+            Ok(req) => self.handle_request(req).await,
+        };
+
+        response.map(|resp| resp.into_binary())
+    }
+
+    pub(crate) async fn handle_jsonrpc_request(&mut self, msg: &str) -> Option<String> {
+        match jsonrpc::parse_request(msg) {
+            Err(err) => err.into_response(),
+            Ok((id, req)) => {
+                let response = self.handle_request(req).await;
+                jsonrpc::response(id, response)
+            }
         }
     }
 
@@ -288,6 +378,11 @@ impl Handler {
         debug!("Handling text message request");
         trace!("Content: {:?}", msg);
 
+        if self.framing.is_jsonrpc() {
+            self.received_response_type = ReceivedResponseType::JsonRpc;
+            return self.handle_jsonrpc_request(&msg).await.map(WsMessage::text);
+        }
+
         self.received_response_type = ReceivedResponseType::Text;
         let client_request = ClientRequest::try_from_text(msg);
 
@@ -303,14 +398,7 @@ impl Handler {
         debug!("Handling binary message request");
 
         self.received_response_type = ReceivedResponseType::Binary;
-        let client_request = ClientRequest::try_from_binary(msg);
-
-        let response = match client_request {
-            Err(err) => Some(ServerResponse::Error(err)),
-            Ok(req) => self.handle_request(req).await,
-        };
-
-        response.map(|resp| WsMessage::Binary(resp.into_binary()))
+        self.handle_binary_request(msg).await.map(WsMessage::Binary)
     }
 
     async fn handle_ws_request(&mut self, raw_request: WsMessage) -> Option<WsMessage> {
@@ -334,6 +422,7 @@ impl Handler {
         let response_messages = match self.received_response_type {
             ReceivedResponseType::Binary => prepare_reconstructed_binary(reconstructed_messages),
             ReceivedResponseType::Text => prepare_reconstructed_text(reconstructed_messages),
+            ReceivedResponseType::JsonRpc => prepare_reconstructed_jsonrpc(reconstructed_messages),
         };
 
         let mut send_stream = futures::stream::iter(response_messages);
@@ -432,14 +521,8 @@ impl Handler {
         };
         self.socket = Some(ws_stream);
 
-        let (reconstructed_sender, reconstructed_receiver) = mpsc::unbounded();
-
-        // tell the buffer to start sending stuff to us
-        self.buffer_requester
-            .unbounded_send(ReceivedBufferMessage::ReceiverAnnounce(
-                reconstructed_sender,
-            ))
-            .expect("the buffer request failed!");
+        // tell the router to start sending stuff to us
+        let reconstructed_receiver = self.register();
 
         self.listen_for_requests(reconstructed_receiver, task_client)
             .await;
@@ -469,3 +552,14 @@ fn prepare_reconstructed_text(
         .map(|resp| Ok(WsMessage::Text(resp.into_text())))
         .collect()
 }
+
+// I'm still not entirely sure why `send_all` requires `TryStream` rather than `Stream`, but
+// let's just play along for now
+fn prepare_reconstructed_jsonrpc(
+    reconstructed_messages: Vec<ReconstructedMessage>,
+) -> Vec<Result<WsMessage, WsError>> {
+    reconstructed_messages
+        .into_iter()
+        .map(|msg| Ok(WsMessage::Text(jsonrpc::received_notification(msg))))
+        .collect()
+}
@@ -4,31 +4,19 @@
 use super::handler::HandlerBuilder;
 use log::*;
 use std::net::IpAddr;
-use std::{net::SocketAddr, process, sync::Arc};
-use tokio::io::AsyncWriteExt;
-use tokio::{sync::Notify, task::JoinHandle};
-
-enum State {
-    Connected,
-    AwaitingConnection,
-}
-
-impl State {
-    fn is_connected(&self) -> bool {
-        matches!(self, State::Connected)
-    }
-}
+use std::{net::SocketAddr, process};
+use tokio::{sync::mpsc, task::JoinHandle};
 
 pub(crate) struct Listener {
     address: SocketAddr,
-    state: State,
+    active_connections: usize,
 }
 
 impl Listener {
     pub(crate) fn new(host: IpAddr, port: u16) -> Self {
         Listener {
             address: SocketAddr::new(host, port),
-            state: State::AwaitingConnection,
+            active_connections: 0,
         }
     }
 
@@ -45,56 +33,42 @@ impl Listener {
             }
         };
 
-        let notify = Arc::new(Notify::new());
+        let (finished_sender, mut finished_receiver) = mpsc::unbounded_channel();
 
         loop {
             tokio::select! {
-                // When the handler finishes we check if shutdown is signalled
-                _ = notify.notified() => {
-                    if task_client.is_shutdown() {
-                        log::trace!("Websocket listener: detected shutdown after connection closed");
+                // When a handler finishes we check if shutdown is signalled
+                Some(()) = finished_receiver.recv() => {
+                    self.active_connections = self.active_connections.saturating_sub(1);
+                    if task_client.is_shutdown() && self.active_connections == 0 {
+                        log::trace!("Websocket listener: detected shutdown after all connections closed");
                         break;
                     }
-                    // our connection terminated - we are open to a new one now!
-                    self.state = State::AwaitingConnection;
                 }
-                // ... but when there is no connected client at the time of shutdown being
+                // ... but when there are no connected clients at the time of shutdown being
                 // signalled, we handle it here.
-                _ = task_client.recv() => {
-                    if !self.state.is_connected() {
+                _ = task_client.recv(), if !task_client.is_shutdown() => {
+                    if self.active_connections == 0 {
                         log::trace!("Not connected: shutting down");
                         break;
                     }
                 }
-                new_conn = tcp_listener.accept() => {
+                new_conn = tcp_listener.accept(), if !task_client.is_shutdown() => {
                     match new_conn {
-                        Ok((mut socket, remote_addr)) => {
+                        Ok((socket, remote_addr)) => {
                             debug!("Received connection from {:?}", remote_addr);
-                            if self.state.is_connected() {
-                                warn!("Tried to open a duplicate websocket connection. The request came from {}", remote_addr);
-                                // if we've already got a connection, don't allow another one
-                                // while we only ever want to accept a single connection, we don't want
-                                // to leave clients hanging (and also allow for reconnection if it somehow
-                                // was dropped)
-                                match socket.shutdown().await {
-                                    Ok(_) => trace!(
-                                        "closed the connection between attempting websocket handshake"
-                                    ),
-                                    Err(err) => warn!("failed to cleanly close the connection - {err}"),
-                                };
-                            } else {
-                                // even though we're spawning a new task with the handler here, we will only ever spawn a single one.
-                                // it's done so that any new connections to this listener could be rejected rather than left
-                                // hanging because the executor doesn't come back here
-                                let notify_clone = Arc::clone(&notify);
-                                let fresh_handler = handler.create_active_handler();
-                                let task_client_handler = task_client.clone();
-                                tokio::spawn(async move {
-                                    fresh_handler.handle_connection(socket, task_client_handler).await;
-                                    notify_clone.notify_one();
-                                });
-                                self.state = State::Connected;
-                            }
+
+                            // each connection gets its own handler with all of them sharing
+                            // the underlying client, i.e. its identity and received messages
+                            let finished_sender = finished_sender.clone();
+                            let fresh_handler = handler.create_active_handler();
+                            let task_client_handler = task_client.clone();
+                            tokio::spawn(async move {
+                                fresh_handler.handle_connection(socket, task_client_handler).await;
+                                finished_sender.send(()).ok();
+                            });
+                            self.active_connections += 1;
+                            debug!("there are now {} active websocket connections", self.active_connections);
                         }
                         Err(err) => warn!("failed to get client: {err}"),
                     }
@@ -0,0 +1,323 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::channel::mpsc;
+use futures::StreamExt;
+use log::*;
+use nym_client_core::client::received_buffer::{
+    ReceivedBufferMessage, ReceivedBufferRequestSender, ReconstructedMessagesReceiver,
+    ReconstructedMessagesSender,
+};
+use nym_sphinx::anonymous_replies::requests::AnonymousSenderTag;
+use nym_sphinx::receiver::ReconstructedMessage;
+use std::collections::{BTreeMap, HashSet};
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+
+/// Identifier of a single websocket connection registered with the [`MessageRouter`].
+pub(crate) type SubscriberId = u64;
+
+struct Subscriber {
+    sender: ReconstructedMessagesSender,
+    sender_tags: HashSet<AnonymousSenderTag>,
+}
+
+#[derive(Default)]
+struct Subscribers {
+    next_id: SubscriberId,
+    inner: BTreeMap<SubscriberId, Subscriber>,
+
+    // messages that were already on their way from the received messages buffer when the last
+    // websocket client has disconnected. they're handed over to the next client that connects
+    pending: Vec<ReconstructedMessage>,
+}
+
+impl Subscribers {
+    fn insert(&mut self, sender: ReconstructedMessagesSender) -> SubscriberId {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        if !self.pending.is_empty() {
+            debug!(
+                "delivering {} buffered messages to websocket connection {id}",
+                self.pending.len()
+            );
+            if sender
+                .unbounded_send(std::mem::take(&mut self.pending))
+                .is_err()
+            {
+                debug!("websocket connection {id} has already stopped receiving messages");
+            }
+        }
+
+        self.inner.insert(
+            id,
+            Subscriber {
+                sender,
+                sender_tags: HashSet::new(),
+            },
+        );
+        id
+    }
+
+    // messages from anonymous senders that some connection has subscribed to go exclusively
+    // to that connection. everything else is broadcast to all of them as we have no way of
+    // telling which one is the intended recipient
+    fn targets(&self, sender_tag: Option<&AnonymousSenderTag>) -> Vec<SubscriberId> {
+        if let Some(sender_tag) = sender_tag {
+            let subscribed = self
+                .inner
+                .iter()
+                .filter(|(_, subscriber)| subscriber.sender_tags.contains(sender_tag))
+                .map(|(id, _)| *id)
+                .collect::<Vec<_>>();
+            if !subscribed.is_empty() {
+                return subscribed;
+            }
+        }
+        self.inner.keys().copied().collect()
+    }
+
+    fn route(
+        &self,
+        messages: Vec<ReconstructedMessage>,
+    ) -> BTreeMap<SubscriberId, Vec<ReconstructedMessage>> {
+        let mut routed: BTreeMap<_, Vec<_>> = BTreeMap::new();
+        for message in messages {
+            let targets = self.targets(message.sender_tag.as_ref());
+            let Some((last, rest)) = targets.split_last() else {
+                warn!("there are no connected websocket clients to receive the message");
+                continue;
+            };
+            for id in rest {
+                routed.entry(*id).or_default().push(message.clone());
+            }
+            routed.entry(*last).or_default().push(message);
+        }
+        routed
+    }
+
+    fn dispatch(&mut self, messages: Vec<ReconstructedMessage>) {
+        if self.inner.is_empty() {
+            debug!(
+                "there are no connected websocket clients - buffering {} messages",
+                messages.len()
+            );
+            self.pending.extend(messages);
+            return;
+        }
+
+        for (id, messages) in self.route(messages) {
+            if let Some(subscriber) = self.inner.get(&id) {
+                if subscriber.sender.unbounded_send(messages).is_err() {
+                    debug!("websocket connection {id} has already stopped receiving messages");
+                }
+            }
+        }
+    }
+}
+
+/// Sits between the received messages buffer and all of the currently connected websocket clients
+/// and decides which of them should receive each of the reconstructed messages.
+#[derive(Clone)]
+pub(crate) struct MessageRouter {
+    subscribers: Arc<Mutex<Subscribers>>,
+    buffer_requester: ReceivedBufferRequestSender,
+
+    // sender announced to the received messages buffer whenever there's at least a single
+    // connected websocket client. otherwise the buffer keeps the messages for later.
+    router_sender: ReconstructedMessagesSender,
+}
+
+impl MessageRouter {
+    pub(crate) fn new(
+        buffer_requester: ReceivedBufferRequestSender,
+    ) -> (Self, ReconstructedMessagesReceiver) {
+        let (router_sender, router_receiver) = mpsc::unbounded();
+        (
+            MessageRouter {
+                subscribers: Default::default(),
+                buffer_requester,
+                router_sender,
+            },
+            router_receiver,
+        )
+    }
+
+    fn subscribers(&self) -> std::sync::MutexGuard<'_, Subscribers> {
+        self.subscribers
+            .lock()
+            .expect("message router subscribers lock got poisoned")
+    }
+
+    pub(crate) fn register(&self) -> (SubscriberId, ReconstructedMessagesReceiver) {
+        let (sender, receiver) = mpsc::unbounded();
+        let mut subscribers = self.subscribers();
+        if subscribers.inner.is_empty()
+            && self
+                .buffer_requester
+                .unbounded_send(ReceivedBufferMessage::ReceiverAnnounce(
+                    self.router_sender.clone(),
+                ))
+                .is_err()
+        {
+            error!("failed to announce the message router to the received messages buffer! presumably the shutdown procedure has been initiated!")
+        }
+
+        let id = subscribers.insert(sender);
+        debug!("registered websocket connection {id}");
+        (id, receiver)
+    }
+
+    pub(crate) fn unregister(&self, id: SubscriberId) {
+        let mut subscribers = self.subscribers();
+        subscribers.inner.remove(&id);
+        debug!("unregistered websocket connection {id}");
+
+        if subscribers.inner.is_empty()
+            && self
+                .buffer_requester
+                .unbounded_send(ReceivedBufferMessage::ReceiverDisconnect)
+                .is_err()
+        {
+            error!("we failed to disconnect the receiver from the buffer! presumably the shutdown procedure has been initiated!")
+        }
+    }
+
+    pub(crate) fn subscribe(&self, id: SubscriberId, sender_tags: Vec<AnonymousSenderTag>) {
+        if let Some(subscriber) = self.subscribers().inner.get_mut(&id) {
+            subscriber.sender_tags.extend(sender_tags)
+        }
+    }
+
+    pub(crate) fn unsubscribe(&self, id: SubscriberId, sender_tags: Vec<AnonymousSenderTag>) {
+        if let Some(subscriber) = self.subscribers().inner.get_mut(&id) {
+            for sender_tag in sender_tags {
+                subscriber.sender_tags.remove(&sender_tag);
+            }
+        }
+    }
+
+    async fn run(
+        &self,
+        mut router_receiver: ReconstructedMessagesReceiver,
+        mut task_client: nym_task::TaskClient,
+    ) {
+        while !task_client.is_shutdown() {
+            tokio::select! {
+                messages = router_receiver.next() => {
+                    let Some(messages) = messages else {
+                        log::trace!("MessageRouter: router channel has been closed");
+                        break;
+                    };
+                    self.subscribers().dispatch(messages)
+                }
+                _ = task_client.recv() => {
+                    log::trace!("MessageRouter: Received shutdown");
+                }
+            }
+        }
+        log::debug!("MessageRouter: Exiting");
+    }
+
+    pub(crate) fn start(
+        &self,
+        router_receiver: ReconstructedMessagesReceiver,
+        shutdown: nym_task::TaskClient,
+    ) -> JoinHandle<()> {
+        let this = self.clone();
+        tokio::spawn(async move { this.run(router_receiver, shutdown).await })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nym_sphinx::anonymous_replies::requests::SENDER_TAG_SIZE;
+
+    fn message(content: u8, sender_tag: Option<AnonymousSenderTag>) -> ReconstructedMessage {
+        ReconstructedMessage {
+            message: vec![content],
+            sender_tag,
+        }
+    }
+
+    fn contents(messages: &[ReconstructedMessage]) -> Vec<u8> {
+        messages.iter().map(|m| m.message[0]).collect()
+    }
+
+    #[test]
+    fn messages_are_broadcast_without_subscriptions() {
+        let mut subscribers = Subscribers::default();
+        let first = subscribers.insert(mpsc::unbounded().0);
+        let second = subscribers.insert(mpsc::unbounded().0);
+
+        let tag: AnonymousSenderTag = [1u8; SENDER_TAG_SIZE].into();
+        let routed = subscribers.route(vec![message(1, None), message(2, Some(tag))]);
+
+        assert_eq!(contents(&routed[&first]), vec![1, 2]);
+        assert_eq!(contents(&routed[&second]), vec![1, 2]);
+    }
+
+    #[test]
+    fn subscribed_sender_tags_are_routed_exclusively() {
+        let mut subscribers = Subscribers::default();
+        let first = subscribers.insert(mpsc::unbounded().0);
+        let second = subscribers.insert(mpsc::unbounded().0);
+
+        let tag1: AnonymousSenderTag = [1u8; SENDER_TAG_SIZE].into();
+        let tag2: AnonymousSenderTag = [2u8; SENDER_TAG_SIZE].into();
+        subscribers
+            .inner
+            .get_mut(&second)
+            .unwrap()
+            .sender_tags
+            .insert(tag1);
+
+        let routed = subscribers.route(vec![
+            message(1, Some(tag1)),
+            message(2, Some(tag2)),
+            message(3, None),
+        ]);
+
+        assert_eq!(contents(&routed[&first]), vec![2, 3]);
+        assert_eq!(contents(&routed[&second]), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn messages_without_subscribers_are_dropped() {
+        let subscribers = Subscribers::default();
+        assert!(subscribers.route(vec![message(1, None)]).is_empty());
+    }
+
+    #[test]
+    fn messages_without_subscribers_are_kept_for_the_next_one() {
+        let mut subscribers = Subscribers::default();
+        subscribers.dispatch(vec![message(1, None)]);
+        subscribers.dispatch(vec![message(2, None)]);
+
+        let (sender, mut receiver) = mpsc::unbounded();
+        subscribers.insert(sender);
+        assert!(subscribers.pending.is_empty());
+        assert_eq!(contents(&receiver.try_next().unwrap().unwrap()), vec![1, 2]);
+
+        // and the subsequent messages get dispatched as normal
+        subscribers.dispatch(vec![message(3, None)]);
+        assert_eq!(contents(&receiver.try_next().unwrap().unwrap()), vec![3]);
+    }
+
+    #[test]
+    fn messages_are_kept_after_last_subscriber_leaves() {
+        let mut subscribers = Subscribers::default();
+        let (sender, _receiver) = mpsc::unbounded();
+        let first = subscribers.insert(sender);
+        subscribers.inner.remove(&first);
+
+        subscribers.dispatch(vec![message(1, None)]);
+        assert_eq!(contents(&subscribers.pending), vec![1]);
+
+        let (sender, mut receiver) = mpsc::unbounded();
+        subscribers.insert(sender);
+        assert_eq!(contents(&receiver.try_next().unwrap().unwrap()), vec![1]);
+    }
+}
@@ -0,0 +1,327 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+// JSON-RPC 2.0 framing of the text protocol. The method of each call corresponds to the `type`
+// of the equivalent text request with the remaining fields being passed (by name) as its params,
+// i.e. `{"jsonrpc":"2.0","id":1,"method":"send","params":{"message":"foo","recipient":"..."}}`.
+// The results are the very same objects as the ones returned by the text protocol, while
+// the received messages are pushed to the client as `received` notifications.
+
+use crate::error::{self, ErrorKind};
+use crate::requests::ClientRequest;
+use crate::responses::ServerResponse;
+use crate::text::{ClientRequestText, ServerResponseText, CLIENT_REQUEST_TYPES};
+use nym_sphinx::receiver::ReconstructedMessage;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::convert::TryInto;
+
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// Name of the method of notifications carrying messages received from the mixnet.
+pub const RECEIVED_NOTIFICATION: &str = "received";
+
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const INTERNAL_ERROR: i64 = -32603;
+
+/// Identifier of a JSON-RPC call. `None` indicates a notification which must never be responded to.
+pub type RequestId = Option<Value>;
+
+#[derive(Deserialize)]
+struct RawRequest {
+    jsonrpc: String,
+    // distinguish between an explicit `null` id and a missing one (i.e. a notification)
+    #[serde(default, deserialize_with = "present_id")]
+    id: RequestId,
+    method: String,
+    #[serde(default)]
+    params: Option<Value>,
+}
+
+fn present_id<'de, D>(deserializer: D) -> Result<RequestId, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Value::deserialize(deserializer).map(Some)
+}
+
+#[derive(Serialize)]
+struct ResponseObject<'a> {
+    jsonrpc: &'static str,
+    id: &'a Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<ErrorObject>,
+}
+
+#[derive(Serialize)]
+struct ErrorObject {
+    code: i64,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct NotificationObject {
+    jsonrpc: &'static str,
+    method: &'static str,
+    params: ServerResponseText,
+}
+
+fn error_code(kind: &ErrorKind) -> i64 {
+    match kind {
+        ErrorKind::EmptyRequest | ErrorKind::TooShortRequest => INVALID_REQUEST,
+        ErrorKind::UnknownRequest => METHOD_NOT_FOUND,
+        ErrorKind::MalformedRequest => INVALID_PARAMS,
+        _ => INTERNAL_ERROR,
+    }
+}
+
+/// Error that occurred while processing a JSON-RPC call.
+#[derive(Debug)]
+pub struct JsonRpcError {
+    id: RequestId,
+    code: i64,
+    message: String,
+}
+
+impl JsonRpcError {
+    fn new<S: Into<String>>(id: RequestId, code: i64, message: S) -> Self {
+        JsonRpcError {
+            id,
+            code,
+            message: message.into(),
+        }
+    }
+
+    fn from_error(id: RequestId, err: error::Error) -> Self {
+        JsonRpcError::new(id, error_code(&err.kind), err.to_string())
+    }
+
+    pub fn code(&self) -> i64 {
+        self.code
+    }
+
+    /// Serializes the error into a response object, unless it was caused by a notification.
+    pub fn into_response(self) -> Option<String> {
+        let id = self.id?;
+        let error = ErrorObject {
+            code: self.code,
+            message: self.message,
+        };
+        Some(serialize(&ResponseObject {
+            jsonrpc: JSONRPC_VERSION,
+            id: &id,
+            result: None,
+            error: Some(error),
+        }))
+    }
+}
+
+fn serialize<T: Serialize>(value: &T) -> String {
+    // we only serialize structures with string keys, so this can't fail
+    serde_json::to_string(value).unwrap()
+}
+
+/// Attempts to parse the JSON-RPC call into its id and the equivalent [`ClientRequest`].
+pub fn parse_request(raw: &str) -> Result<(RequestId, ClientRequest), JsonRpcError> {
+    let value: Value = serde_json::from_str(raw)
+        .map_err(|err| JsonRpcError::new(Some(Value::Null), PARSE_ERROR, err.to_string()))?;
+
+    if value.is_array() {
+        return Err(JsonRpcError::new(
+            Some(Value::Null),
+            INVALID_REQUEST,
+            "batch requests are not supported",
+        ));
+    }
+
+    let request: RawRequest = serde_json::from_value(value)
+        .map_err(|err| JsonRpcError::new(Some(Value::Null), INVALID_REQUEST, err.to_string()))?;
+    let id = request.id;
+
+    if request.jsonrpc != JSONRPC_VERSION {
+        return Err(JsonRpcError::new(
+            id,
+            INVALID_REQUEST,
+            format!("unsupported jsonrpc version '{}'", request.jsonrpc),
+        ));
+    }
+
+    if !CLIENT_REQUEST_TYPES.contains(&request.method.as_str()) {
+        return Err(JsonRpcError::new(
+            id,
+            METHOD_NOT_FOUND,
+            format!("method '{}' does not exist", request.method),
+        ));
+    }
+
+    let mut params = match request.params {
+        None | Some(Value::Null) => serde_json::Map::new(),
+        Some(Value::Object(params)) => params,
+        Some(_) => {
+            return Err(JsonRpcError::new(
+                id,
+                INVALID_PARAMS,
+                "only named params are supported",
+            ))
+        }
+    };
+    params.insert("type".to_string(), Value::String(request.method));
+
+    let text_request: ClientRequestText = match serde_json::from_value(Value::Object(params)) {
+        Ok(text_request) => text_request,
+        Err(err) => return Err(JsonRpcError::new(id, INVALID_PARAMS, err.to_string())),
+    };
+
+    match text_request.try_into() {
+        Ok(request) => Ok((id, request)),
+        Err(err) => Err(JsonRpcError::from_error(id, err)),
+    }
+}
+
+/// Serializes the (optional) response to the call with the provided id. Notifications never
+/// get responded to, while calls without an explicit response get a `null` result.
+pub fn response(id: RequestId, response: Option<ServerResponse>) -> Option<String> {
+    let id = id?;
+    let result = match response {
+        Some(ServerResponse::Error(err)) => {
+            return JsonRpcError::from_error(Some(id), err).into_response()
+        }
+        Some(response) => serde_json::to_value(ServerResponseText::from(response)).unwrap(),
+        None => Value::Null,
+    };
+
+    Some(serialize(&ResponseObject {
+        jsonrpc: JSONRPC_VERSION,
+        id: &id,
+        result: Some(result),
+        error: None,
+    }))
+}
+
+/// Serializes the message received from the mixnet into a `received` notification.
+pub fn received_notification(message: ReconstructedMessage) -> String {
+    serialize(&NotificationObject {
+        jsonrpc: JSONRPC_VERSION,
+        method: RECEIVED_NOTIFICATION,
+        params: ServerResponse::Received(message).into(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nym_sphinx::addressing::clients::Recipient;
+
+    #[test]
+    fn calls_are_parsed_into_client_requests() {
+        let (id, request) =
+            parse_request(r#"{"jsonrpc":"2.0","id":42,"method":"selfAddress"}"#).unwrap();
+        assert_eq!(id, Some(Value::from(42)));
+        assert!(matches!(request, ClientRequest::SelfAddress));
+
+        let (id, request) = parse_request(
+            r#"{"jsonrpc":"2.0","id":"foo","method":"subscribe","params":{"senderTags":[]}}"#,
+        )
+        .unwrap();
+        assert_eq!(id, Some(Value::from("foo")));
+        assert!(
+            matches!(request, ClientRequest::Subscribe { sender_tags } if sender_tags.is_empty())
+        );
+    }
+
+    #[test]
+    fn notifications_have_no_id() {
+        let (id, _) = parse_request(r#"{"jsonrpc":"2.0","method":"selfAddress"}"#).unwrap();
+        assert!(id.is_none());
+        assert!(response(id, None).is_none());
+
+        let (id, _) =
+            parse_request(r#"{"jsonrpc":"2.0","id":null,"method":"selfAddress"}"#).unwrap();
+        assert_eq!(id, Some(Value::Null));
+    }
+
+    #[test]
+    fn invalid_calls_get_appropriate_error_codes() {
+        assert_eq!(parse_request("{").unwrap_err().code(), PARSE_ERROR);
+        assert_eq!(parse_request("[]").unwrap_err().code(), INVALID_REQUEST);
+        assert_eq!(
+            parse_request(r#"{"jsonrpc":"1.0","id":1,"method":"selfAddress"}"#)
+                .unwrap_err()
+                .code(),
+            INVALID_REQUEST
+        );
+        assert_eq!(
+            parse_request(r#"{"jsonrpc":"2.0","id":1,"method":"foo"}"#)
+                .unwrap_err()
+                .code(),
+            METHOD_NOT_FOUND
+        );
+        assert_eq!(
+            parse_request(r#"{"jsonrpc":"2.0","id":1,"method":"send","params":["foo"]}"#)
+                .unwrap_err()
+                .code(),
+            INVALID_PARAMS
+        );
+        assert_eq!(
+            parse_request(
+                r#"{"jsonrpc":"2.0","id":1,"method":"send","params":{"message":"foo","recipient":"bar"}}"#
+            )
+            .unwrap_err()
+            .code(),
+            INVALID_PARAMS
+        );
+    }
+
+    #[test]
+    fn errors_of_notifications_are_not_responded_to() {
+        let err = parse_request(r#"{"jsonrpc":"2.0","method":"foo"}"#).unwrap_err();
+        assert!(err.into_response().is_none());
+
+        let err = parse_request("{").unwrap_err();
+        let parsed: Value = serde_json::from_str(&err.into_response().unwrap()).unwrap();
+        assert_eq!(parsed["id"], Value::Null);
+        assert_eq!(parsed["error"]["code"], Value::from(PARSE_ERROR));
+    }
+
+    #[test]
+    fn responses_wrap_text_responses() {
+        let recipient = Recipient::try_from_base58_string("CytBseW6yFXUMzz4SGAKdNLGR7q3sJLLYxyBGvutNEQV.4QXYyEVc5fUDjmmi8PrHN9tdUFV4PCvSJE1278cHyvoe@4sBbL1ngf1vtNqykydQKTFh26sQCw888GpUqvPvyNB4f").unwrap();
+        let text = response(
+            Some(Value::from(1)),
+            Some(ServerResponse::SelfAddress(Box::new(recipient))),
+        )
+        .unwrap();
+        let parsed: Value = serde_json::from_str(&text).unwrap();
+
+        assert_eq!(parsed["jsonrpc"], Value::from(JSONRPC_VERSION));
+        assert_eq!(parsed["id"], Value::from(1));
+        assert_eq!(parsed["result"]["type"], Value::from("selfAddress"));
+        assert_eq!(
+            parsed["result"]["address"],
+            Value::from(recipient.to_string())
+        );
+        assert!(parsed.get("error").is_none());
+
+        let text = response(Some(Value::from(2)), None).unwrap();
+        let parsed: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(parsed["result"], Value::Null);
+    }
+
+    #[test]
+    fn received_messages_are_sent_as_notifications() {
+        let text = received_notification(ReconstructedMessage {
+            message: b"foomp".to_vec(),
+            sender_tag: None,
+        });
+        let notification: Value = serde_json::from_str(&text).unwrap();
+
+        assert_eq!(notification["method"], Value::from(RECEIVED_NOTIFICATION));
+        assert_eq!(notification["params"]["message"], Value::from("foomp"));
+        assert!(notification.get("id").is_none());
+    }
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod error;
+pub mod jsonrpc;
 pub mod requests;
 pub mod responses;
 mod text;
@@ -30,6 +30,12 @@ enum ClientRequestTag {
 
     /// Value tag representing [`GetLaneQueueLength`] variant of the [`ClientRequest`]
     GetLaneQueueLength = 0x05,
+
+    /// Value tag representing [`Subscribe`] variant of the [`ClientRequest`]
+    Subscribe = 0x06,
+
+    /// Value tag representing [`Unsubscribe`] variant of the [`ClientRequest`]
+    Unsubscribe = 0x07,
 }
 
 impl TryFrom<u8> for ClientRequestTag {
@@ -43,6 +49,8 @@ impl TryFrom<u8> for ClientRequestTag {
             _ if value == (Self::SelfAddress as u8) => Ok(Self::SelfAddress),
             _ if value == (Self::ClosedConnection as u8) => Ok(Self::ClosedConnection),
             _ if value == (Self::GetLaneQueueLength as u8) => Ok(Self::GetLaneQueueLength),
+            _ if value == (Self::Subscribe as u8) => Ok(Self::Subscribe),
+            _ if value == (Self::Unsubscribe as u8) => Ok(Self::Unsubscribe),
             n => Err(error::Error::new(
                 ErrorKind::UnknownRequest,
                 format!("{n} does not correspond to any valid request tag"),
@@ -94,6 +102,17 @@ pub enum ClientRequest {
     ClosedConnection(u64),
 
     GetLaneQueueLength(u64),
+
+    /// Request all messages received from the specified anonymous senders to be routed exclusively
+    /// to this websocket connection rather than to all of the connected ones.
+    Subscribe {
+        sender_tags: Vec<AnonymousSenderTag>,
+    },
+
+    /// Stop routing messages from the specified anonymous senders exclusively to this websocket connection.
+    Unsubscribe {
+        sender_tags: Vec<AnonymousSenderTag>,
+    },
 }
 
 // we could have been parsing it directly TryFrom<WsMessage>, but we want to retain
@@ -382,6 +401,77 @@ impl ClientRequest {
         Ok(ClientRequest::GetLaneQueueLength(connection_id))
     }
 
+    // TAG || num_tags || sender_tags
+    fn serialize_sender_tags(
+        tag: ClientRequestTag,
+        sender_tags: Vec<AnonymousSenderTag>,
+    ) -> Vec<u8> {
+        let num_tags_bytes = (sender_tags.len() as u64).to_be_bytes();
+
+        std::iter::once(tag as u8)
+            .chain(num_tags_bytes.into_iter())
+            .chain(sender_tags.into_iter().flat_map(|tag| tag.to_bytes()))
+            .collect()
+    }
+
+    // TAG || num_tags || sender_tags
+    fn deserialize_sender_tags(b: &[u8]) -> Result<Vec<AnonymousSenderTag>, error::Error> {
+        if b.len() < 1 + size_of::<u64>() {
+            return Err(error::Error::new(
+                ErrorKind::TooShortRequest,
+                "not enough data provided to recover the sender tags".to_string(),
+            ));
+        }
+
+        let num_tags = u64::from_be_bytes(b[1..1 + size_of::<u64>()].try_into().unwrap());
+        let tags_bytes = &b[1 + size_of::<u64>()..];
+        if tags_bytes.len() as u64 != num_tags.saturating_mul(SENDER_TAG_SIZE as u64) {
+            return Err(error::Error::new(
+                ErrorKind::MalformedRequest,
+                format!(
+                    "sender tags have inconsistent length. specified: {num_tags} tags got: {} bytes",
+                    tags_bytes.len()
+                ),
+            ));
+        }
+
+        // the unwrap here is fine as each chunk has exactly SENDER_TAG_SIZE bytes
+        Ok(tags_bytes
+            .chunks_exact(SENDER_TAG_SIZE)
+            .map(|chunk| AnonymousSenderTag::from_bytes(chunk.try_into().unwrap()))
+            .collect())
+    }
+
+    // SUBSCRIBE_REQUEST_TAG || num_tags || sender_tags
+    fn serialize_subscribe(sender_tags: Vec<AnonymousSenderTag>) -> Vec<u8> {
+        Self::serialize_sender_tags(ClientRequestTag::Subscribe, sender_tags)
+    }
+
+    // SUBSCRIBE_REQUEST_TAG || num_tags || sender_tags
+    fn deserialize_subscribe(b: &[u8]) -> Result<Self, error::Error> {
+        // this MUST match because it was called by 'deserialize'
+        debug_assert_eq!(b[0], ClientRequestTag::Subscribe as u8);
+
+        Ok(ClientRequest::Subscribe {
+            sender_tags: Self::deserialize_sender_tags(b)?,
+        })
+    }
+
+    // UNSUBSCRIBE_REQUEST_TAG || num_tags || sender_tags
+    fn serialize_unsubscribe(sender_tags: Vec<AnonymousSenderTag>) -> Vec<u8> {
+        Self::serialize_sender_tags(ClientRequestTag::Unsubscribe, sender_tags)
+    }
+
+    // UNSUBSCRIBE_REQUEST_TAG || num_tags || sender_tags
+    fn deserialize_unsubscribe(b: &[u8]) -> Result<Self, error::Error> {
+        // this MUST match because it was called by 'deserialize'
+        debug_assert_eq!(b[0], ClientRequestTag::Unsubscribe as u8);
+
+        Ok(ClientRequest::Unsubscribe {
+            sender_tags: Self::deserialize_sender_tags(b)?,
+        })
+    }
+
     pub fn serialize(self) -> Vec<u8> {
         match self {
             ClientRequest::Send {
@@ -408,6 +498,10 @@ impl ClientRequest {
             ClientRequest::ClosedConnection(id) => Self::serialize_closed_connection(id),
 
             ClientRequest::GetLaneQueueLength(id) => Self::serialize_get_lane_queue_lengths(id),
+
+            ClientRequest::Subscribe { sender_tags } => Self::serialize_subscribe(sender_tags),
+
+            ClientRequest::Unsubscribe { sender_tags } => Self::serialize_unsubscribe(sender_tags),
         }
     }
 
@@ -431,6 +525,8 @@ impl ClientRequest {
             ClientRequestTag::SelfAddress => Self::deserialize_self_address(b),
             ClientRequestTag::ClosedConnection => Self::deserialize_closed_connection(b),
             ClientRequestTag::GetLaneQueueLength => Self::deserialize_get_lane_queue_length(b),
+            ClientRequestTag::Subscribe => Self::deserialize_subscribe(b),
+            ClientRequestTag::Unsubscribe => Self::deserialize_unsubscribe(b),
         }
     }
 
@@ -565,4 +661,39 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[test]
+    fn subscribe_request_serialization_works() {
+        let subscribe_request = ClientRequest::Subscribe {
+            sender_tags: vec![[8u8; SENDER_TAG_SIZE].into(), [9u8; SENDER_TAG_SIZE].into()],
+        };
+        let bytes = subscribe_request.serialize();
+        let recovered = ClientRequest::deserialize(&bytes).unwrap();
+        match recovered {
+            ClientRequest::Subscribe { sender_tags } => assert_eq!(
+                sender_tags,
+                vec![[8u8; SENDER_TAG_SIZE].into(), [9u8; SENDER_TAG_SIZE].into()]
+            ),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn unsubscribe_request_serialization_works() {
+        let unsubscribe_request = ClientRequest::Unsubscribe {
+            sender_tags: vec![[8u8; SENDER_TAG_SIZE].into()],
+        };
+        let mut bytes = unsubscribe_request.serialize();
+        let recovered = ClientRequest::deserialize(&bytes).unwrap();
+        match recovered {
+            ClientRequest::Unsubscribe { sender_tags } => {
+                assert_eq!(sender_tags, vec![[8u8; SENDER_TAG_SIZE].into()])
+            }
+            _ => unreachable!(),
+        }
+
+        // partial tags are rejected
+        bytes.pop();
+        assert!(ClientRequest::deserialize(&bytes).is_err())
+    }
 }
@@ -35,6 +35,34 @@ pub(super) enum ClientRequestText {
         connection_id: Option<u64>,
     },
     SelfAddress,
+    #[serde(rename_all = "camelCase")]
+    Subscribe {
+        sender_tags: Vec<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Unsubscribe {
+        sender_tags: Vec<String>,
+    },
+}
+
+// values of the `type` tag of all of the `ClientRequestText` variants
+pub(super) const CLIENT_REQUEST_TYPES: &[&str] = &[
+    "send",
+    "sendAnonymous",
+    "reply",
+    "selfAddress",
+    "subscribe",
+    "unsubscribe",
+];
+
+fn parse_sender_tags(raw: Vec<String>) -> Result<Vec<AnonymousSenderTag>, crate::error::Error> {
+    raw.into_iter()
+        .map(|tag| {
+            AnonymousSenderTag::try_from_base58_string(tag).map_err(|err| {
+                crate::error::Error::new(ErrorKind::MalformedRequest, err.to_string())
+            })
+        })
+        .collect()
 }
 
 impl TryFrom<String> for ClientRequestText {
@@ -101,6 +129,12 @@ impl TryInto<ClientRequest> for ClientRequestText {
                     connection_id,
                 })
             }
+            ClientRequestText::Subscribe { sender_tags } => Ok(ClientRequest::Subscribe {
+                sender_tags: parse_sender_tags(sender_tags)?,
+            }),
+            ClientRequestText::Unsubscribe { sender_tags } => Ok(ClientRequest::Unsubscribe {
+                sender_tags: parse_sender_tags(sender_tags)?,
+            }),
         }
     }
 }
@@ -29,8 +29,14 @@ pub(crate) struct Init {
     id: String,
 
     /// Address of the socks5 provider to send messages to.
+    #[clap(long, required_unless_present = "automatic_provider_selection")]
+    provider: Option<Recipient>,
+
+    /// Specifies whether the network requester should be chosen automatically, based on the
+    /// service provider directory, and replaced once it stops responding to health checks.
+    /// If a provider is also specified, it's going to be tried first.
     #[clap(long)]
-    provider: Recipient,
+    automatic_provider_selection: bool,
 
     /// Specifies whether this client is going to use an anonymous sender tag for communication with the service provider.
     /// While this is going to hide its actual address information, it will make the actual communication
@@ -92,6 +98,7 @@ impl From<Init> for OverrideConfig {
             nym_apis: init_config.nym_apis,
             port: init_config.port,
             use_anonymous_replies: init_config.use_reply_surbs,
+            automatic_provider_selection: Some(init_config.automatic_provider_selection),
             fastmode: init_config.fastmode,
             no_cover: init_config.no_cover,
             nyxd_urls: init_config.nyxd_urls,
@@ -140,7 +147,10 @@ pub(crate) async fn execute(args: &Init) -> Result<(), Socks5ClientError> {
     eprintln!("Initialising client...");
 
     let id = &args.id;
-    let provider_address = &args.provider;
+    let provider_address = args
+        .provider
+        .map(|provider| provider.to_string())
+        .unwrap_or_default();
 
     let already_init = if default_config_filepath(id).exists() {
         // in case we're using old config, try to upgrade it
@@ -174,7 +184,7 @@ pub(crate) async fn execute(args: &Init) -> Result<(), Socks5ClientError> {
 
     // Load and potentially override config
     let config = override_config(
-        Config::new(id, &provider_address.to_string()),
+        Config::new(id, &provider_address),
         OverrideConfig::from(args.clone()),
     );
 
@@ -70,6 +70,7 @@ pub(crate) struct OverrideConfig {
     nym_apis: Option<Vec<url::Url>>,
     port: Option<u16>,
     use_anonymous_replies: Option<bool>,
+    automatic_provider_selection: Option<bool>,
     fastmode: bool,
     no_cover: bool,
     nyxd_urls: Option<Vec<url::Url>>,
@@ -104,6 +105,10 @@ pub(crate) fn override_config(config: Config, args: OverrideConfig) -> Config {
         .with_base(BaseClientConfig::with_disabled_cover_traffic, args.no_cover)
         .with_base(BaseClientConfig::with_packet_type, packet_type)
         .with_optional(Config::with_anonymous_replies, args.use_anonymous_replies)
+        .with_optional(
+            Config::with_automatic_provider_selection,
+            args.automatic_provider_selection,
+        )
         .with_optional(Config::with_port, args.port)
         .with_optional_base_custom_env(
             BaseClientConfig::with_custom_nym_apis,
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::commands::try_load_current_config;
-use crate::config::Config;
+use crate::config::{default_data_directory, Config};
 use crate::{
     commands::{override_config, OverrideConfig},
     error::Socks5ClientError,
@@ -15,6 +15,8 @@ use nym_crypto::asymmetric::identity;
 use nym_socks5_client_core::NymClient;
 use nym_sphinx::addressing::clients::Recipient;
 
+const PREFERRED_PROVIDERS_FILENAME: &str = "preferred_providers";
+
 #[derive(Args, Clone)]
 pub(crate) struct Run {
     /// Id of the nym-mixnet-client we want to run.
@@ -34,6 +36,11 @@ pub(crate) struct Run {
     #[clap(long)]
     provider: Option<Recipient>,
 
+    /// Specifies whether the network requester should be chosen automatically, based on the
+    /// service provider directory, and replaced once it stops responding to health checks.
+    #[clap(long)]
+    automatic_provider_selection: Option<bool>,
+
     /// Id of the gateway we want to connect to. If overridden, it is user's responsibility to
     /// ensure prior registration happened
     #[clap(long)]
@@ -75,6 +82,7 @@ impl From<Run> for OverrideConfig {
             nym_apis: run_config.nym_apis,
             port: run_config.port,
             use_anonymous_replies: run_config.use_anonymous_replies,
+            automatic_provider_selection: run_config.automatic_provider_selection,
             fastmode: run_config.fastmode,
             no_cover: run_config.no_cover,
             nyxd_urls: run_config.nyxd_urls,
@@ -119,5 +127,10 @@ pub(crate) async fn execute(args: &Run) -> Result<(), Box<dyn std::error::Error
     let storage =
         OnDiskPersistent::from_paths(config.storage_paths.common_paths, &config.core.base.debug)
             .await?;
-    NymClient::new(config.core, storage).run_forever().await
+    NymClient::new(config.core, storage)
+        .with_preferred_providers_store(
+            default_data_directory(&args.id).join(PREFERRED_PROVIDERS_FILENAME),
+        )
+        .run_forever()
+        .await
 }
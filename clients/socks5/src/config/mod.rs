@@ -112,6 +112,13 @@ impl Config {
         self
     }
 
+    pub fn with_automatic_provider_selection(mut self, automatic_provider_selection: bool) -> Self {
+        self.core = self
+            .core
+            .with_automatic_provider_selection(automatic_provider_selection);
+        self
+    }
+
     // poor man's 'builder' method
 
     pub fn with_base<F, T>(mut self, f: F, val: T) -> Self
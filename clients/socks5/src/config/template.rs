@@ -85,6 +85,11 @@ listening_port = {{ core.socks5.listening_port }}
 # Note that some service providers might not support this.
 send_anonymously = {{ core.socks5.send_anonymously }}
 
+# Specifies whether the network requester should be chosen automatically, based on the
+# service provider directory, rather than always using the provider specified above.
+# If enabled, the client will also switch to a different requester once the current one stops responding.
+automatic_provider_selection = {{ core.socks5.automatic_provider_selection }}
+
 ##### logging configuration options #####
 
 [logging]
@@ -71,6 +71,17 @@ pub struct TrafficWasm {
 
     /// Controls whether the sent packets should use outfox as opposed to the default sphinx.
     pub use_outfox: bool,
+
+    /// Controls whether the main packet stream should be shaped into a constant rate envelope,
+    /// i.e. sending exactly `traffic_shaping_packets_per_slot` packets every `traffic_shaping_slot_duration_ms`.
+    /// The packets are evenly spaced within the slot rather than being sent in a single burst.
+    pub enable_traffic_shaping: bool,
+
+    /// The duration of a single sending slot of the shaped packet stream.
+    pub traffic_shaping_slot_duration_ms: u64,
+
+    /// The number of packets sent during every slot of the shaped packet stream.
+    pub traffic_shaping_packets_per_slot: u32,
 }
 
 impl From<TrafficWasm> for ConfigTraffic {
@@ -95,6 +106,11 @@ impl From<TrafficWasm> for ConfigTraffic {
             primary_packet_size: PacketSize::RegularPacket,
             secondary_packet_size: use_extended_packet_size,
             packet_type,
+            enable_traffic_shaping: traffic.enable_traffic_shaping,
+            traffic_shaping_slot_duration: Duration::from_millis(
+                traffic.traffic_shaping_slot_duration_ms,
+            ),
+            traffic_shaping_packets_per_slot: traffic.traffic_shaping_packets_per_slot,
         }
     }
 }
@@ -109,6 +125,10 @@ impl From<ConfigTraffic> for TrafficWasm {
                 .disable_main_poisson_packet_distribution,
             use_extended_packet_size: traffic.secondary_packet_size.is_some(),
             use_outfox: traffic.packet_type == PacketType::Outfox,
+            enable_traffic_shaping: traffic.enable_traffic_shaping,
+            traffic_shaping_slot_duration_ms: traffic.traffic_shaping_slot_duration.as_millis()
+                as u64,
+            traffic_shaping_packets_per_slot: traffic.traffic_shaping_packets_per_slot,
         }
     }
 }
@@ -127,6 +147,22 @@ pub struct CoverTrafficWasm {
     /// Controls whether the dedicated loop cover traffic stream should be enabled.
     /// (and sending packets, on average, every [Self::loop_cover_traffic_average_delay])
     pub disable_loop_cover_traffic_stream: bool,
+
+    /// The parameter of Poisson distribution determining how long, on average,
+    /// it is going to take for another drop cover traffic message to be sent.
+    pub drop_cover_traffic_average_delay_ms: u64,
+
+    /// Controls whether the dedicated drop cover traffic stream should be enabled.
+    /// (and sending packets to random other gateways, on average, every
+    /// [Self::drop_cover_traffic_average_delay_ms])
+    pub enable_drop_cover_traffic_stream: bool,
+
+    /// The parameter of Poisson distribution determining how long, on average,
+    /// it is going to take for our gateway to push us another receive cover message.
+    pub receive_cover_traffic_average_delay_ms: u64,
+
+    /// Controls whether our gateway should be asked to push dummy messages to us.
+    pub enable_receive_cover_traffic: bool,
 }
 
 impl From<CoverTrafficWasm> for ConfigCoverTraffic {
@@ -137,6 +173,14 @@ impl From<CoverTrafficWasm> for ConfigCoverTraffic {
             ),
             cover_traffic_primary_size_ratio: cover_traffic.cover_traffic_primary_size_ratio,
             disable_loop_cover_traffic_stream: cover_traffic.disable_loop_cover_traffic_stream,
+            drop_cover_traffic_average_delay: Duration::from_millis(
+                cover_traffic.drop_cover_traffic_average_delay_ms,
+            ),
+            enable_drop_cover_traffic_stream: cover_traffic.enable_drop_cover_traffic_stream,
+            receive_cover_traffic_average_delay: Duration::from_millis(
+                cover_traffic.receive_cover_traffic_average_delay_ms,
+            ),
+            enable_receive_cover_traffic: cover_traffic.enable_receive_cover_traffic,
         }
     }
 }
@@ -149,6 +193,14 @@ impl From<ConfigCoverTraffic> for CoverTrafficWasm {
                 .as_millis() as u64,
             cover_traffic_primary_size_ratio: cover_traffic.cover_traffic_primary_size_ratio,
             disable_loop_cover_traffic_stream: cover_traffic.disable_loop_cover_traffic_stream,
+            drop_cover_traffic_average_delay_ms: cover_traffic
+                .drop_cover_traffic_average_delay
+                .as_millis() as u64,
+            enable_drop_cover_traffic_stream: cover_traffic.enable_drop_cover_traffic_stream,
+            receive_cover_traffic_average_delay_ms: cover_traffic
+                .receive_cover_traffic_average_delay
+                .as_millis() as u64,
+            enable_receive_cover_traffic: cover_traffic.enable_receive_cover_traffic,
         }
     }
 }
@@ -198,6 +250,16 @@ pub struct AcknowledgementsWasm {
     /// it is assumed it was lost and retransmission of the data packet happens.
     /// In an ideal network with 0 latency, this value would have been 0.
     pub ack_wait_addition_ms: u64,
+
+    /// Controls whether the ack timeouts should be derived purely from the static
+    /// `ack_wait_multiplier` and `ack_wait_addition` rather than from the observed round trip times.
+    /// When adaptive timeouts are used, the static values act as the ceiling of the timeout.
+    pub disable_adaptive_timeouts: bool,
+
+    /// When adaptive timeouts are used, specifies the minimum value added to the expected
+    /// round trip time of an acknowledgement packet before it is assumed it was lost,
+    /// regardless of how fast the network appears to be.
+    pub minimum_ack_wait_addition_ms: u64,
 }
 
 impl From<AcknowledgementsWasm> for ConfigAcknowledgements {
@@ -206,6 +268,10 @@ impl From<AcknowledgementsWasm> for ConfigAcknowledgements {
             average_ack_delay: Duration::from_millis(acknowledgements.average_ack_delay_ms),
             ack_wait_multiplier: acknowledgements.ack_wait_multiplier,
             ack_wait_addition: Duration::from_millis(acknowledgements.ack_wait_addition_ms),
+            disable_adaptive_timeouts: acknowledgements.disable_adaptive_timeouts,
+            minimum_ack_wait_addition: Duration::from_millis(
+                acknowledgements.minimum_ack_wait_addition_ms,
+            ),
         }
     }
 }
@@ -216,6 +282,9 @@ impl From<ConfigAcknowledgements> for AcknowledgementsWasm {
             average_ack_delay_ms: acknowledgements.average_ack_delay.as_millis() as u64,
             ack_wait_multiplier: acknowledgements.ack_wait_multiplier,
             ack_wait_addition_ms: acknowledgements.ack_wait_addition.as_millis() as u64,
+            disable_adaptive_timeouts: acknowledgements.disable_adaptive_timeouts,
+            minimum_ack_wait_addition_ms: acknowledgements.minimum_ack_wait_addition.as_millis()
+                as u64,
         }
     }
 }
@@ -2,8 +2,20 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use nym_client_core::config::GatewayEndpointConfig;
+use nym_topology::gateway;
+use nym_validator_client::client::GatewayBond;
 use wasm_bindgen::prelude::*;
 
+fn gateway_endpoint(bond: &GatewayBond) -> GatewayEndpointConfig {
+    match gateway::Node::try_from(bond) {
+        Ok(node) => node.into(),
+        Err(err) => panic!(
+            "gateway {} has announced malformed details - {err}",
+            bond.gateway.identity_key
+        ),
+    }
+}
+
 #[wasm_bindgen]
 pub async fn get_gateway(api_server: String, preferred: Option<String>) -> GatewayEndpointConfig {
     let validator_client =
@@ -19,14 +31,7 @@ pub async fn get_gateway(api_server: String, preferred: Option<String>) -> Gatew
             .iter()
             .find(|g| g.gateway.identity_key == preferred)
         {
-            return GatewayEndpointConfig {
-                gateway_id: details.gateway.identity_key.clone(),
-                gateway_owner: details.owner.to_string(),
-                gateway_listener: format!(
-                    "ws://{}:{}",
-                    details.gateway.host, details.gateway.clients_port
-                ),
-            };
+            return gateway_endpoint(details);
         }
     }
 
@@ -34,12 +39,5 @@ pub async fn get_gateway(api_server: String, preferred: Option<String>) -> Gatew
         .first()
         .expect("current topology holds no gateways");
 
-    GatewayEndpointConfig {
-        gateway_id: details.gateway.identity_key.clone(),
-        gateway_owner: details.owner.to_string(),
-        gateway_listener: format!(
-            "ws://{}:{}",
-            details.gateway.host, details.gateway.clients_port
-        ),
-    }
+    gateway_endpoint(details)
 }
@@ -216,6 +216,7 @@ pub struct WasmGateway {
     pub sphinx_key: String,
     #[wasm_bindgen(getter_with_clone)]
     pub version: String,
+    pub clients_wss_port: Option<u16>,
 }
 
 #[wasm_bindgen]
@@ -229,6 +230,7 @@ impl WasmGateway {
         identity_key: String,
         sphinx_key: String,
         version: String,
+        clients_wss_port: Option<u16>,
     ) -> Self {
         Self {
             owner,
@@ -238,6 +240,7 @@ impl WasmGateway {
             identity_key,
             sphinx_key,
             version,
+            clients_wss_port,
         }
     }
 }
@@ -257,6 +260,7 @@ impl TryFrom<WasmGateway> for gateway::Node {
             host,
             mix_host,
             clients_port: value.clients_port,
+            clients_wss_port: value.clients_wss_port,
             identity_key: identity::PublicKey::from_base58_string(&value.identity_key)
                 .map_err(GatewayConversionError::from)?,
             sphinx_key: encryption::PublicKey::from_base58_string(&value.sphinx_key)
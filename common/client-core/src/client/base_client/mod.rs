@@ -3,13 +3,15 @@
 
 use super::received_buffer::ReceivedBufferMessage;
 use crate::client::base_client::storage::MixnetClientStorage;
-use crate::client::cover_traffic_stream::LoopCoverTrafficStream;
+use crate::client::cover_traffic_stream::{CoverTrafficKind, CoverTrafficStream};
 use crate::client::inbound_messages::{InputMessage, InputMessageReceiver, InputMessageSender};
 use crate::client::key_manager::persistence::KeyStore;
 use crate::client::key_manager::ManagedKeys;
 use crate::client::mix_traffic::{BatchMixMessageSender, MixTrafficController};
 use crate::client::real_messages_control;
-use crate::client::real_messages_control::RealMessagesController;
+use crate::client::real_messages_control::{
+    AckRttEstimates, RealMessagesController, TrafficShapingStats,
+};
 use crate::client::received_buffer::{
     ReceivedBufferRequestReceiver, ReceivedBufferRequestSender, ReceivedMessagesBufferController,
 };
@@ -31,8 +33,8 @@ use nym_bandwidth_controller::BandwidthController;
 use nym_credential_storage::storage::Storage as CredentialStorage;
 use nym_crypto::asymmetric::{encryption, identity};
 use nym_gateway_client::{
-    AcknowledgementReceiver, AcknowledgementSender, GatewayClient, MixnetMessageReceiver,
-    MixnetMessageSender,
+    AcknowledgementReceiver, AcknowledgementSender, GatewayClient, GatewayShutdownNoticeReceiver,
+    GatewayShutdownNoticeSender, MixnetMessageReceiver, MixnetMessageSender,
 };
 use nym_sphinx::acknowledgements::AckKey;
 use nym_sphinx::addressing::clients::Recipient;
@@ -101,6 +103,8 @@ pub struct ClientState {
     pub shared_lane_queue_lengths: LaneQueueLengths,
     pub reply_controller_sender: ReplyControllerSender,
     pub topology_accessor: TopologyAccessor,
+    pub ack_rtt_estimates: AckRttEstimates,
+    pub traffic_shaping_stats: TrafficShapingStats,
 }
 
 pub enum ClientInputStatus {
@@ -212,9 +216,10 @@ where
         )
     }
 
-    // future constantly pumping loop cover traffic at some specified average rate
+    // future constantly pumping loop (or drop) cover traffic at some specified average rate
     // the pumped traffic goes to the MixTrafficController
     fn start_cover_traffic_stream(
+        kind: CoverTrafficKind,
         debug_config: &DebugConfig,
         ack_key: Arc<AckKey>,
         self_address: Recipient,
@@ -222,9 +227,10 @@ where
         mix_tx: BatchMixMessageSender,
         shutdown: TaskClient,
     ) {
-        info!("Starting loop cover traffic stream...");
+        info!("Starting {kind:?} cover traffic stream...");
 
-        let stream = LoopCoverTrafficStream::new(
+        let stream = CoverTrafficStream::new(
+            kind,
             ack_key,
             debug_config.acknowledgements.average_ack_delay,
             mix_tx,
@@ -249,6 +255,8 @@ where
         reply_controller_receiver: ReplyControllerReceiver,
         lane_queue_lengths: LaneQueueLengths,
         client_connection_rx: ConnectionCommandReceiver,
+        ack_rtt_estimates: AckRttEstimates,
+        traffic_shaping_stats: TrafficShapingStats,
         shutdown: TaskClient,
         packet_type: PacketType,
     ) {
@@ -265,6 +273,8 @@ where
             reply_controller_receiver,
             lane_queue_lengths,
             client_connection_rx,
+            ack_rtt_estimates,
+            traffic_shaping_stats,
         )
         .start_with_shutdown(shutdown, packet_type);
     }
@@ -298,6 +308,7 @@ where
         bandwidth_controller: Option<BandwidthController<C, S::CredentialStore>>,
         mixnet_message_sender: MixnetMessageSender,
         ack_sender: AcknowledgementSender,
+        shutdown_notice_sender: GatewayShutdownNoticeSender,
         shutdown: TaskClient,
     ) -> Result<GatewayClient<C, S::CredentialStore>, ClientCoreError>
     where
@@ -324,6 +335,14 @@ where
         );
 
         gateway_client.set_disabled_credentials_mode(config.client.disabled_credentials_mode);
+        gateway_client.set_shutdown_notice_sender(shutdown_notice_sender);
+
+        let cover_traffic = config.debug.cover_traffic;
+        gateway_client.set_receive_cover_traffic(
+            cover_traffic
+                .enable_receive_cover_traffic
+                .then_some(cover_traffic.receive_cover_traffic_average_delay),
+        );
 
         let shared_key = gateway_client
             .authenticate_and_start()
@@ -399,13 +418,15 @@ where
     // requests?
     fn start_mix_traffic_controller(
         gateway_client: GatewayClient<C, S::CredentialStore>,
+        shutdown_notice_receiver: GatewayShutdownNoticeReceiver,
         shutdown: TaskClient,
     ) -> BatchMixMessageSender
     where
         <S::CredentialStore as CredentialStorage>::StorageError: Send + Sync + 'static,
     {
         info!("Starting mix traffic controller...");
-        let (mix_traffic_controller, mix_tx) = MixTrafficController::new(gateway_client);
+        let (mix_traffic_controller, mix_tx) =
+            MixTrafficController::new(gateway_client, shutdown_notice_receiver);
         mix_traffic_controller.start_with_shutdown(shutdown);
         mix_tx
     }
@@ -463,6 +484,12 @@ where
     {
         info!("Starting nym client");
 
+        // the config might not have gone through `Config::validate` (say, if it was provided
+        // via the sdk or from wasm), so make sure the shaped stream is not going to misbehave
+        if !self.config.debug.traffic.validate_traffic_shaping() {
+            return Err(ClientCoreError::InvalidTrafficShapingConfig);
+        }
+
         // derive (or load) client keys and gateway configuration
         let details = self.initialise_keys_and_gateway().await?;
         let gateway_config = details.gateway_details;
@@ -491,6 +518,9 @@ where
 
         // channels responsible for controlling ack messages
         let (ack_sender, ack_receiver) = mpsc::unbounded();
+
+        // used by the gateway client for announcing our gateway is about to go offline
+        let (shutdown_notice_sender, shutdown_notice_receiver) = mpsc::unbounded();
         let shared_topology_accessor = TopologyAccessor::new();
 
         // Shutdown notifier for signalling tasks to stop
@@ -511,6 +541,7 @@ where
             bandwidth_controller,
             mixnet_messages_sender,
             ack_sender,
+            shutdown_notice_sender,
             task_manager.subscribe(),
         )
         .await?;
@@ -544,8 +575,11 @@ where
         // that are to be sent to the mixnet. They are used by cover traffic stream and real
         // traffic stream.
         // The MixTrafficController then sends the actual traffic
-        let message_sender =
-            Self::start_mix_traffic_controller(gateway_client, task_manager.subscribe());
+        let message_sender = Self::start_mix_traffic_controller(
+            gateway_client,
+            shutdown_notice_receiver,
+            task_manager.subscribe(),
+        );
 
         // Channels that the websocket listener can use to signal downstream to the real traffic
         // controller that connections are closed.
@@ -555,6 +589,14 @@ where
         // primarily to throttle incoming connections (e.g socks5 for attached network-requesters)
         let shared_lane_queue_lengths = LaneQueueLengths::new();
 
+        // Shared round trip estimates of our acknowledgements. Updated by the `ActionController`
+        // and used for deriving adaptive retransmission timeouts.
+        let ack_rtt_estimates = AckRttEstimates::new();
+
+        // Shared statistics of the shaped packet stream. Updated by the `OutQueueController`
+        // (only if traffic shaping is enabled).
+        let traffic_shaping_stats = TrafficShapingStats::new();
+
         let controller_config = real_messages_control::Config::new(
             &self.config.debug,
             managed_keys.ack_key(),
@@ -572,17 +614,32 @@ where
             reply_controller_receiver,
             shared_lane_queue_lengths.clone(),
             client_connection_rx,
+            ack_rtt_estimates.clone(),
+            traffic_shaping_stats.clone(),
             task_manager.subscribe(),
             self.config.debug.traffic.packet_type,
         );
 
-        if !self
-            .config
-            .debug
-            .cover_traffic
-            .disable_loop_cover_traffic_stream
-        {
+        // the shaped traffic stream already sends loop cover packets at a constant rate,
+        // any additional ones would have been visible outside its envelope
+        let traffic_shaping = self.config.debug.traffic.enable_traffic_shaping;
+        let cover_traffic = self.config.debug.cover_traffic;
+
+        if !cover_traffic.disable_loop_cover_traffic_stream && !traffic_shaping {
+            Self::start_cover_traffic_stream(
+                CoverTrafficKind::Loop,
+                &self.config.debug,
+                managed_keys.ack_key(),
+                self_address,
+                shared_topology_accessor.clone(),
+                message_sender.clone(),
+                task_manager.subscribe(),
+            );
+        }
+
+        if cover_traffic.enable_drop_cover_traffic_stream && !traffic_shaping {
             Self::start_cover_traffic_stream(
+                CoverTrafficKind::Drop,
                 &self.config.debug,
                 managed_keys.ack_key(),
                 self_address,
@@ -612,6 +669,8 @@ where
                 shared_lane_queue_lengths,
                 reply_controller_sender,
                 topology_accessor: shared_topology_accessor,
+                ack_rtt_estimates,
+                traffic_shaping_stats,
             },
             task_manager,
         })
@@ -7,11 +7,15 @@ use crate::{config, spawn_future};
 use futures::task::{Context, Poll};
 use futures::{Future, Stream, StreamExt};
 use log::*;
+use nym_bin_common::version_checker::parse_version;
 use nym_sphinx::acknowledgements::AckKey;
 use nym_sphinx::addressing::clients::Recipient;
-use nym_sphinx::cover::generate_loop_cover_packet;
+use nym_sphinx::addressing::nodes::NodeIdentity;
+use nym_sphinx::cover::{generate_drop_cover_packet, generate_loop_cover_packet};
 use nym_sphinx::params::{PacketSize, PacketType};
 use nym_sphinx::utils::sample_poisson_duration;
+use nym_topology::{gateway, NymTopology};
+use rand::seq::SliceRandom;
 use rand::{rngs::OsRng, CryptoRng, Rng};
 use std::pin::Pin;
 use std::sync::Arc;
@@ -24,16 +28,36 @@ use tokio::time;
 #[cfg(target_arch = "wasm32")]
 use wasm_timer;
 
-pub struct LoopCoverTrafficStream<R>
+// gateways running older versions would have put the drop cover addressed to them
+// in their inbox storage rather than discarding it
+const MINIMUM_DROP_COVER_GATEWAY_VERSION: &str = "1.1.23";
+
+/// Kind of the cover traffic produced by the particular [CoverTrafficStream].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverTrafficKind {
+    /// Cover messages sent through the mixnet back to ourselves.
+    Loop,
+
+    /// Cover messages sent through the mixnet to random other gateways, which silently discard them.
+    Drop,
+}
+
+pub struct CoverTrafficStream<R>
 where
     R: CryptoRng + Rng,
 {
+    /// Kind of the cover messages produced by this stream.
+    kind: CoverTrafficKind,
+
     /// Key used to encrypt and decrypt content of an ACK packet.
     ack_key: Arc<AckKey>,
 
     /// Average delay an acknowledgement packet is going to get delay at a single mixnode.
     average_ack_delay: Duration,
 
+    /// Average delay a data packet is going to get delay at a single mixnode.
+    average_packet_delay: Duration,
+
     /// Defines configuration options related to cover traffic.
     cover_traffic: config::CoverTraffic,
 
@@ -58,16 +82,16 @@ where
     /// Accessor to the common instance of network topology.
     topology_access: TopologyAccessor,
 
-    /// Primary predefined packet size used for the loop cover messages.
+    /// Primary predefined packet size used for the cover messages.
     primary_packet_size: PacketSize,
 
-    /// Optional secondary predefined packet size used for the loop cover messages.
+    /// Optional secondary predefined packet size used for the cover messages.
     secondary_packet_size: Option<PacketSize>,
 
     packet_type: PacketType,
 }
 
-impl<R> Stream for LoopCoverTrafficStream<R>
+impl<R> Stream for CoverTrafficStream<R>
 where
     R: CryptoRng + Rng + Unpin,
 {
@@ -85,7 +109,7 @@ where
 
         // we know it's time to send a message, so let's prepare delay for the next one
         // Get the `now` by looking at the current `delay` deadline
-        let avg_delay = self.cover_traffic.loop_cover_traffic_average_delay;
+        let avg_delay = self.average_delay();
         let next_poisson_delay = sample_poisson_duration(&mut self.rng, avg_delay);
 
         // The next interval value is `next_poisson_delay` after the one that just
@@ -108,8 +132,10 @@ where
 
 // obviously when we finally make shared rng that is on 'higher' level, this should become
 // generic `R`
-impl LoopCoverTrafficStream<OsRng> {
+impl CoverTrafficStream<OsRng> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
+        kind: CoverTrafficKind,
         ack_key: Arc<AckKey>,
         average_ack_delay: Duration,
         mix_tx: BatchMixMessageSender,
@@ -126,9 +152,11 @@ impl LoopCoverTrafficStream<OsRng> {
         #[cfg(target_arch = "wasm32")]
         let next_delay = Box::pin(wasm_timer::Delay::new(Default::default()));
 
-        LoopCoverTrafficStream {
+        CoverTrafficStream {
+            kind,
             ack_key,
             average_ack_delay,
+            average_packet_delay: traffic_config.average_packet_delay,
             cover_traffic: cover_config,
             next_delay,
             mix_tx,
@@ -141,6 +169,13 @@ impl LoopCoverTrafficStream<OsRng> {
         }
     }
 
+    fn average_delay(&self) -> Duration {
+        match self.kind {
+            CoverTrafficKind::Loop => self.cover_traffic.loop_cover_traffic_average_delay,
+            CoverTrafficKind::Drop => self.cover_traffic.drop_cover_traffic_average_delay,
+        }
+    }
+
     fn set_next_delay(&mut self, amount: Duration) {
         #[cfg(not(target_arch = "wasm32"))]
         let next_delay = Box::pin(time::sleep(amount));
@@ -151,7 +186,7 @@ impl LoopCoverTrafficStream<OsRng> {
         self.next_delay = next_delay;
     }
 
-    fn loop_cover_message_size(&mut self) -> PacketSize {
+    fn cover_message_size(&mut self) -> PacketSize {
         let Some(secondary_packet_size) = self.secondary_packet_size else {
             return self.primary_packet_size
         };
@@ -170,8 +205,11 @@ impl LoopCoverTrafficStream<OsRng> {
     async fn on_new_message(&mut self) {
         trace!("next cover message!");
 
-        let cover_traffic_packet_size = self.loop_cover_message_size();
-        trace!("the next loop cover message will be put in a {cover_traffic_packet_size} packet");
+        let cover_traffic_packet_size = self.cover_message_size();
+        trace!(
+            "the next {:?} cover message will be put in a {cover_traffic_packet_size} packet",
+            self.kind
+        );
 
         // TODO for way down the line: in very rare cases (during topology update) we might have
         // to wait a really tiny bit before actually obtaining the permit hence messing with our
@@ -184,22 +222,47 @@ impl LoopCoverTrafficStream<OsRng> {
         ) {
             Ok(topology) => topology,
             Err(err) => {
-                warn!("We're not going to send any loop cover message this time, as the current topology seem to be invalid - {err}");
+                warn!("We're not going to send any {:?} cover message this time, as the current topology seem to be invalid - {err}", self.kind);
                 return;
             }
         };
 
-        let cover_message = generate_loop_cover_packet(
-            &mut self.rng,
-            topology_ref,
-            &self.ack_key,
-            &self.our_full_destination,
-            self.average_ack_delay,
-            self.cover_traffic.loop_cover_traffic_average_delay,
-            cover_traffic_packet_size,
-            self.packet_type,
-        )
-        .expect("Somehow failed to generate a loop cover message with a valid topology");
+        let cover_message = match self.kind {
+            CoverTrafficKind::Loop => generate_loop_cover_packet(
+                &mut self.rng,
+                topology_ref,
+                &self.ack_key,
+                &self.our_full_destination,
+                self.average_ack_delay,
+                self.cover_traffic.loop_cover_traffic_average_delay,
+                cover_traffic_packet_size,
+                self.packet_type,
+            )
+            .expect("Somehow failed to generate a loop cover message with a valid topology"),
+            CoverTrafficKind::Drop => {
+                let Some(target_gateway) = random_drop_cover_gateway(
+                    &mut self.rng,
+                    topology_ref,
+                    self.our_full_destination.gateway(),
+                ) else {
+                    debug!("There are no gateways we could send the drop cover message to");
+                    return;
+                };
+
+                generate_drop_cover_packet(
+                    &mut self.rng,
+                    topology_ref,
+                    &self.ack_key,
+                    &self.our_full_destination,
+                    target_gateway,
+                    self.average_ack_delay,
+                    self.average_packet_delay,
+                    cover_traffic_packet_size,
+                    self.packet_type,
+                )
+                .expect("Somehow failed to generate a drop cover message with a valid topology")
+            }
+        };
 
         if let Err(err) = self.mix_tx.try_send(vec![cover_message]) {
             match err {
@@ -228,40 +291,142 @@ impl LoopCoverTrafficStream<OsRng> {
     }
 
     pub fn start_with_shutdown(mut self, mut shutdown: nym_task::TaskClient) {
-        if self.cover_traffic.disable_loop_cover_traffic_stream {
+        let enabled = match self.kind {
+            CoverTrafficKind::Loop => !self.cover_traffic.disable_loop_cover_traffic_stream,
+            CoverTrafficKind::Drop => self.cover_traffic.enable_drop_cover_traffic_stream,
+        };
+        if !enabled {
             // we should have never got here in the first place - the task should have never been created to begin with
             // so panic and review the code that lead to this branch
-            panic!("attempted to start LoopCoverTrafficStream while config explicitly disabled it.")
+            panic!(
+                "attempted to start {:?} CoverTrafficStream while config explicitly disabled it.",
+                self.kind
+            )
         }
 
         // we should set initial delay only when we actually start the stream
-        let sampled = sample_poisson_duration(
-            &mut self.rng,
-            self.cover_traffic.loop_cover_traffic_average_delay,
-        );
+        let sampled = sample_poisson_duration(&mut self.rng, self.average_delay());
         self.set_next_delay(sampled);
 
         spawn_future(async move {
-            debug!("Started LoopCoverTrafficStream with graceful shutdown support");
+            let kind = self.kind;
+            debug!("Started {kind:?} CoverTrafficStream with graceful shutdown support");
 
             while !shutdown.is_shutdown() {
                 tokio::select! {
                     biased;
                     _ = shutdown.recv() => {
-                        log::trace!("LoopCoverTrafficStream: Received shutdown");
+                        log::trace!("{kind:?} CoverTrafficStream: Received shutdown");
                     }
                     next = self.next() => {
                         if next.is_some() {
                             self.on_new_message().await;
                         } else {
-                            log::trace!("LoopCoverTrafficStream: Stopping since channel closed");
+                            log::trace!("{kind:?} CoverTrafficStream: Stopping since channel closed");
                             break;
                         }
                     }
                 }
             }
             shutdown.recv_timeout().await;
-            log::debug!("LoopCoverTrafficStream: Exiting");
+            log::debug!("{kind:?} CoverTrafficStream: Exiting");
         })
     }
 }
+
+/// Chooses a random gateway, other than our own, that is going to discard the drop cover sent to it.
+// note: our own gateway is excluded as otherwise it could have trivially linked the drop cover
+// it received with the packets we've sent
+fn random_drop_cover_gateway<'a, R>(
+    rng: &mut R,
+    topology: &'a NymTopology,
+    our_gateway: &NodeIdentity,
+) -> Option<&'a gateway::Node>
+where
+    R: CryptoRng + Rng,
+{
+    let minimum_version = parse_version(MINIMUM_DROP_COVER_GATEWAY_VERSION).ok()?;
+
+    let candidates = topology
+        .gateways()
+        .iter()
+        .filter(|node| &node.identity_key != our_gateway)
+        .filter(|node| {
+            parse_version(&node.version)
+                .map(|version| version >= minimum_version)
+                .unwrap_or_default()
+        })
+        .collect::<Vec<_>>();
+
+    candidates.choose(rng).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nym_crypto::asymmetric::{encryption, identity};
+    use std::collections::BTreeMap;
+
+    fn test_gateway(seed: u8, version: &str) -> gateway::Node {
+        let identity_key =
+            identity::PublicKey::from(&identity::PrivateKey::from_bytes(&[seed; 32]).unwrap());
+        let sphinx_key =
+            encryption::PublicKey::from(&encryption::PrivateKey::from_bytes(&[seed; 32]).unwrap());
+
+        gateway::Node {
+            owner: format!("owner{seed}"),
+            host: "1.2.3.4".parse().unwrap(),
+            mix_host: "1.2.3.4:1789".parse().unwrap(),
+            clients_port: 9000,
+            clients_wss_port: None,
+            identity_key,
+            sphinx_key,
+            version: version.to_string(),
+        }
+    }
+
+    fn test_topology(gateways: Vec<gateway::Node>) -> NymTopology {
+        NymTopology::new(BTreeMap::new(), gateways)
+    }
+
+    #[test]
+    fn our_own_gateway_is_never_chosen() {
+        let ours = test_gateway(1, "1.1.23");
+        let other = test_gateway(2, "1.1.23");
+        let topology = test_topology(vec![ours.clone(), other.clone()]);
+
+        for _ in 0..50 {
+            let chosen = random_drop_cover_gateway(&mut OsRng, &topology, &ours.identity_key)
+                .expect("there should have been a valid gateway");
+            assert_eq!(chosen.identity_key, other.identity_key);
+        }
+    }
+
+    #[test]
+    fn outdated_gateways_are_never_chosen() {
+        let ours = test_gateway(1, "1.1.23");
+        let outdated = test_gateway(2, "1.1.22");
+        let malformed = test_gateway(3, "foomp");
+        let updated = test_gateway(4, "1.1.24");
+        let topology = test_topology(vec![ours.clone(), outdated, malformed, updated.clone()]);
+
+        for _ in 0..50 {
+            let chosen = random_drop_cover_gateway(&mut OsRng, &topology, &ours.identity_key)
+                .expect("there should have been a valid gateway");
+            assert_eq!(chosen.identity_key, updated.identity_key);
+        }
+    }
+
+    #[test]
+    fn no_gateway_is_chosen_without_valid_candidates() {
+        let ours = test_gateway(1, "1.1.23");
+        let outdated = test_gateway(2, "1.1.20");
+        let topology = test_topology(vec![ours.clone(), outdated]);
+
+        assert!(random_drop_cover_gateway(&mut OsRng, &topology, &ours.identity_key).is_none());
+        assert!(
+            random_drop_cover_gateway(&mut OsRng, &test_topology(vec![]), &ours.identity_key)
+                .is_none()
+        );
+    }
+}
@@ -2,8 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::spawn_future;
+use futures::StreamExt;
 use log::*;
-use nym_gateway_client::GatewayClient;
+use nym_gateway_client::{GatewayClient, GatewayShutdownNotice, GatewayShutdownNoticeReceiver};
 use nym_sphinx::forwarding::packet::MixPacket;
 
 use nym_credential_storage::storage::Storage;
@@ -26,6 +27,12 @@ pub struct MixTrafficController<C, St: Storage> {
     gateway_client: GatewayClient<C, St>,
     mix_rx: BatchMixMessageReceiver,
 
+    /// Channel on which our gateway announces it's about to go offline.
+    shutdown_notice_rx: GatewayShutdownNoticeReceiver,
+
+    /// The most recent shutdown notice received from our gateway, if it's yet to come back online.
+    gateway_shutdown_notice: Option<GatewayShutdownNotice>,
+
     // TODO: this is temporary work-around.
     // in long run `gateway_client` will be moved away from `MixTrafficController` anyway.
     consecutive_gateway_failure_count: usize,
@@ -39,6 +46,7 @@ where
 {
     pub fn new(
         gateway_client: GatewayClient<C, St>,
+        shutdown_notice_rx: GatewayShutdownNoticeReceiver,
     ) -> (MixTrafficController<C, St>, BatchMixMessageSender) {
         let (message_sender, message_receiver) =
             tokio::sync::mpsc::channel(MIX_MESSAGE_RECEIVER_BUFFER_SIZE);
@@ -46,6 +54,8 @@ where
             MixTrafficController {
                 gateway_client,
                 mix_rx: message_receiver,
+                shutdown_notice_rx,
+                gateway_shutdown_notice: None,
                 consecutive_gateway_failure_count: 0,
             },
             message_sender,
@@ -65,6 +75,14 @@ where
         };
 
         match result {
+            Err(err) if self.gateway_shutdown_notice.is_some() => {
+                // we have been warned about it, so don't treat the gateway as dead,
+                // but keep on trying to get back to it once it's online again
+                warn!(
+                    "Failed to send sphinx packet(s) to the gateway that's shutting down - {err}"
+                );
+                self.reconnect_after_gateway_shutdown().await;
+            }
             Err(err) => {
                 error!("Failed to send sphinx packet(s) to the gateway! - {err}");
                 self.consecutive_gateway_failure_count += 1;
@@ -81,6 +99,31 @@ where
         }
     }
 
+    fn on_gateway_shutdown_notice(&mut self, notice: GatewayShutdownNotice) {
+        match &notice.suggested_gateway {
+            // our address is tied to the gateway, so we can't just transparently move to another one
+            Some(gateway) => warn!(
+                "our gateway is shutting down! We will keep reconnecting to it until it's back online. \
+                If you'd rather use the gateway it suggested instead, re-initialise the client with {gateway}"
+            ),
+            None => warn!(
+                "our gateway is shutting down! We will keep reconnecting to it until it's back online"
+            ),
+        }
+        self.gateway_shutdown_notice = Some(notice);
+    }
+
+    async fn reconnect_after_gateway_shutdown(&mut self) {
+        match self.gateway_client.reconnect_after_gateway_shutdown().await {
+            Ok(_) => {
+                info!("managed to reconnect to our gateway after its shutdown");
+                self.gateway_shutdown_notice = None;
+                self.consecutive_gateway_failure_count = 0;
+            }
+            Err(err) => warn!("our gateway is still not back online - {err}"),
+        }
+    }
+
     pub fn start_with_shutdown(mut self, mut shutdown: nym_task::TaskClient) {
         spawn_future(async move {
             debug!("Started MixTrafficController with graceful shutdown support");
@@ -96,6 +139,9 @@ where
                             break;
                         }
                     },
+                    Some(notice) = self.shutdown_notice_rx.next() => {
+                        self.on_gateway_shutdown_notice(notice);
+                    },
                     _ = shutdown.recv_with_delay() => {
                         log::trace!("MixTrafficController: Received shutdown");
                         break;
@@ -1,7 +1,9 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use super::rtt_estimator::AckRttEstimates;
 use super::PendingAcknowledgement;
+use crate::client::helpers::{get_time_now, Instant};
 use crate::client::real_messages_control::acknowledgement_control::RetransmissionRequestSender;
 use futures::channel::mpsc;
 use futures::StreamExt;
@@ -17,7 +19,28 @@ pub(crate) type AckActionSender = mpsc::UnboundedSender<Action>;
 pub(crate) type AckActionReceiver = mpsc::UnboundedReceiver<Action>;
 
 // The actual data being sent off as well as potential key to the delay queue
-type PendingAckEntry = (Arc<PendingAcknowledgement>, Option<QueueKey>);
+struct PendingAckEntry {
+    data: Arc<PendingAcknowledgement>,
+    queue_key: Option<QueueKey>,
+
+    /// Time at which the most recent retransmission timer got started.
+    timer_started: Option<Instant>,
+
+    /// Indicates whether this data has ever been retransmitted. If so, as per Karn's algorithm,
+    /// any ack received for it is ambiguous and must not be used for the round trip estimation.
+    retransmitted: bool,
+}
+
+impl PendingAckEntry {
+    fn new(data: PendingAcknowledgement) -> Self {
+        PendingAckEntry {
+            data: Arc::new(data),
+            queue_key: None,
+            timer_started: None,
+            retransmitted: false,
+        }
+    }
+}
 
 // we can either:
 // - have a completely new set of packets we just sent and need to create entries for
@@ -69,6 +92,11 @@ pub(super) struct Config {
 
     /// Given ack timeout in the form a * BASE_DELAY + b, it specifies the multiplier `a`
     ack_wait_multiplier: f64,
+
+    /// If adaptive timeouts are enabled, specifies the minimum value added to BASE_DELAY
+    /// when determining the ack timeout.
+    /// If disabled, the timeout is always equal to a * BASE_DELAY + b.
+    minimum_ack_wait_addition: Option<Duration>,
 }
 
 impl Config {
@@ -76,8 +104,14 @@ impl Config {
         Config {
             ack_wait_addition,
             ack_wait_multiplier,
+            minimum_ack_wait_addition: None,
         }
     }
+
+    pub(super) fn with_adaptive_timeouts(mut self, minimum_ack_wait_addition: Duration) -> Self {
+        self.minimum_ack_wait_addition = Some(minimum_ack_wait_addition);
+        self
+    }
 }
 
 pub(super) struct ActionController {
@@ -100,6 +134,12 @@ pub(super) struct ActionController {
 
     /// Channel for notifying `RetransmissionRequestListener` about expired acknowledgements.
     retransmission_sender: RetransmissionRequestSender,
+
+    /// Identity of the gateway through which all of our packets (and acks) are going.
+    gateway: String,
+
+    /// Round trip estimates of received acknowledgements used for deriving adaptive timeouts.
+    rtt_estimates: AckRttEstimates,
 }
 
 impl ActionController {
@@ -107,6 +147,8 @@ impl ActionController {
         config: Config,
         retransmission_sender: RetransmissionRequestSender,
         incoming_actions: AckActionReceiver,
+        gateway: String,
+        rtt_estimates: AckRttEstimates,
     ) -> Self {
         ActionController {
             config,
@@ -114,6 +156,31 @@ impl ActionController {
             pending_acks_timers: NonExhaustiveDelayQueue::new(),
             incoming_actions,
             retransmission_sender,
+            gateway,
+            rtt_estimates,
+        }
+    }
+
+    // the static timeout of a * BASE_DELAY + b acts as the ceiling for the adaptive timeout,
+    // while BASE_DELAY + b_min acts as its floor.
+    fn ack_timeout(&self, expected_delay: SphinxDelay) -> Duration {
+        let ceiling = (expected_delay * self.config.ack_wait_multiplier).to_duration()
+            + self.config.ack_wait_addition;
+
+        let Some(minimum_addition) = self.config.minimum_ack_wait_addition else {
+            return ceiling;
+        };
+
+        match self.rtt_estimates.get(&self.gateway) {
+            // until we get our first sample, we have no choice but to rely on the static values
+            Some(estimate) if estimate.samples > 0 => {
+                let expected_delay = expected_delay.to_duration();
+                let floor = expected_delay + minimum_addition;
+                (expected_delay + estimate.ack_wait_addition())
+                    .max(floor)
+                    .min(ceiling)
+            }
+            _ => ceiling,
         }
     }
 
@@ -124,7 +191,7 @@ impl ActionController {
 
             if self
                 .pending_acks_data
-                .insert(frag_id, (Arc::new(pending_ack), None))
+                .insert(frag_id, PendingAckEntry::new(pending_ack))
                 .is_some()
             {
                 panic!("Tried to insert duplicate pending ack")
@@ -135,7 +202,20 @@ impl ActionController {
     fn handle_start_timer(&mut self, frag_id: FragmentIdentifier) {
         trace!("{} is starting its timer", frag_id);
 
-        if let Some((pending_ack_data, queue_key)) = self.pending_acks_data.get_mut(&frag_id) {
+        let Some(expected_delay) = self
+            .pending_acks_data
+            .get(&frag_id)
+            .map(|entry| entry.data.delay)
+        else {
+            debug!(
+                "Tried to START TIMER on pending ack that is already gone! - {}",
+                frag_id
+            );
+            return;
+        };
+        let timeout = self.ack_timeout(expected_delay);
+
+        if let Some(entry) = self.pending_acks_data.get_mut(&frag_id) {
             // the fact that this branch is now POSSIBLE is a sign of a need to refactor this whole
             // retransmission procedure
             //
@@ -147,16 +227,26 @@ impl ActionController {
             //     // timer TWICE for the SAME PendingAcknowledgement
             //     panic!("Tried to start an already started ack timer!")
             // }
-            let timeout = (pending_ack_data.delay * self.config.ack_wait_multiplier).to_duration()
-                + self.config.ack_wait_addition;
-
             let new_queue_key = self.pending_acks_timers.insert(frag_id, timeout);
-            *queue_key = Some(new_queue_key)
-        } else {
-            debug!(
-                "Tried to START TIMER on pending ack that is already gone! - {}",
-                frag_id
+            entry.queue_key = Some(new_queue_key);
+            entry.timer_started = Some(get_time_now());
+        }
+    }
+
+    fn observe_round_trip(&self, entry: &PendingAckEntry) {
+        if self.config.minimum_ack_wait_addition.is_none() || entry.retransmitted {
+            return;
+        }
+
+        if let Some(timer_started) = entry.timer_started {
+            let round_trip = get_time_now().duration_since(timer_started);
+            let sample = round_trip.saturating_sub(entry.data.delay.to_duration());
+            trace!(
+                "observed ack round trip of {:?} ({:?} over the expected delay)",
+                round_trip,
+                sample
             );
+            self.rtt_estimates.observe(&self.gateway, sample);
         }
     }
 
@@ -170,8 +260,9 @@ impl ActionController {
                     frag_id
                 );
             }
-            Some((_, queue_key)) => {
-                if let Some(queue_key) = queue_key {
+            Some(entry) => {
+                self.observe_round_trip(&entry);
+                if let Some(queue_key) = entry.queue_key {
                     // there are no possible checks here, we must GUARANTEE that we NEVER try
                     // to remove an entry that doesn't exist (and we MUST GUARANTEE that
                     // we do not have a stale key)
@@ -194,16 +285,23 @@ impl ActionController {
     fn handle_update_delay(&mut self, frag_id: FragmentIdentifier, delay: SphinxDelay) {
         trace!("{} is updating its delay", frag_id);
         // TODO: is it possible to solve this without either locking or temporarily removing the value?
-        if let Some((pending_ack_data, queue_key)) = self.pending_acks_data.remove(&frag_id) {
+        if let Some(entry) = self.pending_acks_data.remove(&frag_id) {
             // this Action is triggered by `RetransmissionRequestListener` (for 'normal' packets)
             // or `ReplyController` (for 'reply' packets) which held the other potential
             // reference to this Arc. HOWEVER, before the Action was pushed onto the queue, the reference
             // was dropped hence this unwrap is safe.
-            let mut inner_data = Arc::try_unwrap(pending_ack_data).unwrap();
+            let mut inner_data = Arc::try_unwrap(entry.data).unwrap();
             inner_data.update_delay(delay);
 
-            self.pending_acks_data
-                .insert(frag_id, (Arc::new(inner_data), queue_key));
+            self.pending_acks_data.insert(
+                frag_id,
+                PendingAckEntry {
+                    data: Arc::new(inner_data),
+                    queue_key: entry.queue_key,
+                    timer_started: entry.timer_started,
+                    retransmitted: true,
+                },
+            );
         } else {
             debug!(
                 "Tried to UPDATE TIMER on pending ack that is already gone! - {}",
@@ -225,19 +323,23 @@ impl ActionController {
 
         trace!("{} has expired", frag_id);
 
-        if let Some((pending_ack_data, queue_key)) = self.pending_acks_data.get_mut(&frag_id) {
-            if queue_key.is_none() {
+        if let Some(entry) = self.pending_acks_data.get_mut(&frag_id) {
+            if entry.queue_key.is_none() {
                 // this branch should be IMPOSSIBLE under ANY condition. It would imply the timeout
                 // happened before it even started.
                 panic!("Ack expired before it was even scheduled!")
             }
-            *queue_key = None;
+            entry.queue_key = None;
+            if self.config.minimum_ack_wait_addition.is_some() {
+                // as with TCP, after a timeout back off exponentially until we get a fresh sample
+                self.rtt_estimates.backoff(&self.gateway);
+            }
             // downgrading an arc and then upgrading vs cloning is difference of 30ns vs 15ns
             // so it's literally a NO difference while it might prevent us from unnecessarily
             // resending data (in maybe 1 in 1 million cases, but it's something)
             if self
                 .retransmission_sender
-                .unbounded_send(Arc::downgrade(pending_ack_data))
+                .unbounded_send(Arc::downgrade(&entry.data))
                 .is_err()
             {
                 assert!(
@@ -290,3 +392,107 @@ impl ActionController {
         log::debug!("ActionController: Exiting");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GATEWAY: &str = "gateway";
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    fn controller(config: Config, rtt_estimates: AckRttEstimates) -> ActionController {
+        let (retransmission_sender, _) = mpsc::unbounded();
+        let (_, incoming_actions) = mpsc::unbounded();
+        ActionController::new(
+            config,
+            retransmission_sender,
+            incoming_actions,
+            GATEWAY.to_string(),
+            rtt_estimates,
+        )
+    }
+
+    // with 1s of expected delay, the static timeout is 1.5 * 1s + 1.5s = 3s
+    // and the adaptive timeout can't go below 1s + 100ms
+    fn static_config() -> Config {
+        Config::new(ms(1500), 1.5)
+    }
+
+    fn adaptive_config() -> Config {
+        static_config().with_adaptive_timeouts(ms(100))
+    }
+
+    fn expected_delay() -> SphinxDelay {
+        SphinxDelay::new_from_millis(1000)
+    }
+
+    #[test]
+    fn static_timeout_ignores_rtt_estimates() {
+        let estimates = AckRttEstimates::new();
+        estimates.observe(GATEWAY, ms(100));
+
+        let controller = controller(static_config(), estimates);
+        assert_eq!(controller.ack_timeout(expected_delay()), ms(3000));
+    }
+
+    #[test]
+    fn adaptive_timeout_uses_static_value_until_first_sample() {
+        let estimates = AckRttEstimates::new();
+        let controller = controller(adaptive_config(), estimates.clone());
+        assert_eq!(controller.ack_timeout(expected_delay()), ms(3000));
+
+        // a timeout without any samples doesn't change anything
+        estimates.backoff(GATEWAY);
+        assert_eq!(controller.ack_timeout(expected_delay()), ms(3000));
+    }
+
+    #[test]
+    fn adaptive_timeout_is_derived_from_rtt_estimate() {
+        let estimates = AckRttEstimates::new();
+        // SRTT = 200ms, RTTVAR = 100ms
+        estimates.observe(GATEWAY, ms(200));
+
+        let controller = controller(adaptive_config(), estimates);
+        assert_eq!(controller.ack_timeout(expected_delay()), ms(1600));
+    }
+
+    #[test]
+    fn adaptive_timeout_is_clamped_to_the_minimum() {
+        let estimates = AckRttEstimates::new();
+        estimates.observe(GATEWAY, ms(10));
+
+        let controller = controller(adaptive_config(), estimates);
+        assert_eq!(controller.ack_timeout(expected_delay()), ms(1100));
+    }
+
+    #[test]
+    fn adaptive_timeout_is_clamped_to_the_static_value() {
+        let estimates = AckRttEstimates::new();
+        estimates.observe(GATEWAY, ms(1000));
+
+        let controller = controller(adaptive_config(), estimates);
+        assert_eq!(controller.ack_timeout(expected_delay()), ms(3000));
+    }
+
+    #[test]
+    fn adaptive_timeout_backs_off_after_timeouts() {
+        let estimates = AckRttEstimates::new();
+        estimates.observe(GATEWAY, ms(200));
+        let controller = controller(adaptive_config(), estimates.clone());
+
+        estimates.backoff(GATEWAY);
+        assert_eq!(controller.ack_timeout(expected_delay()), ms(2200));
+
+        // the backoff can't push the timeout above the static value
+        estimates.backoff(GATEWAY);
+        assert_eq!(controller.ack_timeout(expected_delay()), ms(3000));
+
+        // and a fresh sample resets it (SRTT = 200ms, RTTVAR = 75ms)
+        estimates.observe(GATEWAY, ms(200));
+        let timeout = controller.ack_timeout(expected_delay());
+        assert!(timeout > ms(1499) && timeout < ms(1501), "{timeout:?}");
+    }
+}
@@ -30,11 +30,13 @@ use std::{
 };
 
 pub(crate) use action_controller::{AckActionSender, Action};
+pub use rtt_estimator::{AckRttEstimates, RttEstimate};
 
 mod acknowledgement_listener;
 mod action_controller;
 mod input_message_listener;
 mod retransmission_request_listener;
+mod rtt_estimator;
 mod sent_notification_listener;
 
 /// Channel used for indicating that the particular `Fragment` should be retransmitted.
@@ -162,6 +164,10 @@ pub(super) struct Config {
     /// Given ack timeout in the form a * BASE_DELAY + b, it specifies the multiplier `a`
     ack_wait_multiplier: f64,
 
+    /// If specified, enables adaptive ack timeouts derived from the observed round trip times
+    /// and defines the minimum value added to BASE_DELAY.
+    minimum_ack_wait_addition: Option<Duration>,
+
     /// Predefined packet size used for the encapsulated messages.
     packet_size: PacketSize,
 }
@@ -171,10 +177,16 @@ impl Config {
         Config {
             ack_wait_addition,
             ack_wait_multiplier,
+            minimum_ack_wait_addition: None,
             packet_size: Default::default(),
         }
     }
 
+    pub fn with_adaptive_timeouts(mut self, minimum_ack_wait_addition: Duration) -> Self {
+        self.minimum_ack_wait_addition = Some(minimum_ack_wait_addition);
+        self
+    }
+
     pub fn with_custom_packet_size(mut self, packet_size: PacketSize) -> Self {
         self.packet_size = packet_size;
         self
@@ -202,15 +214,22 @@ where
         connectors: AcknowledgementControllerConnectors,
         message_handler: MessageHandler<R>,
         reply_controller_sender: ReplyControllerSender,
+        gateway: String,
+        rtt_estimates: AckRttEstimates,
     ) -> Self {
         let (retransmission_tx, retransmission_rx) = mpsc::unbounded();
 
-        let action_config =
+        let mut action_config =
             action_controller::Config::new(config.ack_wait_addition, config.ack_wait_multiplier);
+        if let Some(minimum_ack_wait_addition) = config.minimum_ack_wait_addition {
+            action_config = action_config.with_adaptive_timeouts(minimum_ack_wait_addition);
+        }
         let action_controller = ActionController::new(
             action_config,
             retransmission_tx,
             connectors.ack_action_receiver,
+            gateway,
+            rtt_estimates,
         );
 
         // will listen for any acks coming from the network
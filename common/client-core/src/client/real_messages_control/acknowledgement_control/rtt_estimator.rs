@@ -0,0 +1,215 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+// Standard values as recommended by RFC6298 (section 2)
+const ALPHA: f64 = 1.0 / 8.0;
+const BETA: f64 = 1.0 / 4.0;
+const K: f64 = 4.0;
+
+// we never back off by more than 2^6 = 64 the estimated timeout
+// (though in practice the ceiling is going to get hit way earlier)
+const MAX_BACKOFF_EXPONENT: u32 = 6;
+
+/// Current state of the round trip estimation for acknowledgements going through a particular gateway.
+///
+/// Note that the estimated values do not include the sphinx delays that were explicitly
+/// encoded in the packets, i.e. they represent the "network overhead" on top of the expected
+/// packet delays (processing times, latency between the nodes, gateway congestion, etc.).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RttEstimate {
+    /// Smoothed estimate of the additional round trip time (SRTT).
+    pub smoothed_rtt: Duration,
+
+    /// Estimated variance of the additional round trip time (RTTVAR).
+    pub rtt_variance: Duration,
+
+    /// Current exponent of the exponential backoff applied after consecutive timeouts.
+    pub backoff_exponent: u32,
+
+    /// Number of valid samples used to produce this estimate.
+    pub samples: u64,
+}
+
+impl RttEstimate {
+    /// Returns the additional waiting time, on top of the expected packet delay,
+    /// after which the acknowledgement is assumed to have been lost.
+    pub fn ack_wait_addition(&self) -> Duration {
+        let base = self.smoothed_rtt + self.rtt_variance.mul_f64(K);
+        base * 2u32.pow(self.backoff_exponent)
+    }
+
+    fn update(&mut self, sample: Duration) {
+        if self.samples == 0 {
+            self.smoothed_rtt = sample;
+            self.rtt_variance = sample / 2;
+        } else {
+            let srtt = self.smoothed_rtt.as_secs_f64();
+            let rttvar = self.rtt_variance.as_secs_f64();
+            let sample = sample.as_secs_f64();
+
+            let new_rttvar = (1.0 - BETA) * rttvar + BETA * (srtt - sample).abs();
+            let new_srtt = (1.0 - ALPHA) * srtt + ALPHA * sample;
+
+            self.rtt_variance = Duration::from_secs_f64(new_rttvar);
+            self.smoothed_rtt = Duration::from_secs_f64(new_srtt);
+        }
+        self.samples += 1;
+        self.backoff_exponent = 0;
+    }
+
+    fn backoff(&mut self) {
+        self.backoff_exponent = (self.backoff_exponent + 1).min(MAX_BACKOFF_EXPONENT)
+    }
+}
+
+/// Shared view of the acknowledgement round trip estimates for each gateway
+/// (identified by its base58-encoded identity key) that this client has sent packets through.
+#[derive(Debug, Clone, Default)]
+pub struct AckRttEstimates(Arc<Mutex<HashMap<String, RttEstimate>>>);
+
+impl AckRttEstimates {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the current estimate associated with the particular gateway (if any).
+    pub fn get(&self, gateway: &str) -> Option<RttEstimate> {
+        match self.0.lock() {
+            Ok(inner) => inner.get(gateway).copied(),
+            Err(err) => {
+                log::warn!("Failed to get the ack rtt estimate: {err}");
+                None
+            }
+        }
+    }
+
+    /// Returns the current estimates of all known gateways.
+    pub fn all(&self) -> HashMap<String, RttEstimate> {
+        match self.0.lock() {
+            Ok(inner) => inner.clone(),
+            Err(err) => {
+                log::warn!("Failed to get the ack rtt estimates: {err}");
+                HashMap::new()
+            }
+        }
+    }
+
+    pub(crate) fn observe(&self, gateway: &str, sample: Duration) {
+        self.modify(gateway, |estimate| estimate.update(sample))
+    }
+
+    pub(crate) fn backoff(&self, gateway: &str) {
+        self.modify(gateway, |estimate| estimate.backoff())
+    }
+
+    fn modify<F>(&self, gateway: &str, f: F)
+    where
+        F: FnOnce(&mut RttEstimate),
+    {
+        match self.0.lock() {
+            Ok(mut inner) => f(inner.entry(gateway.to_string()).or_default()),
+            Err(err) => log::warn!("Failed to update the ack rtt estimate: {err}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    fn assert_close(actual: Duration, expected: Duration) {
+        let diff = if actual > expected {
+            actual - expected
+        } else {
+            expected - actual
+        };
+        assert!(
+            diff < Duration::from_micros(1),
+            "{actual:?} is not close to {expected:?}"
+        );
+    }
+
+    #[test]
+    fn first_sample_initialises_the_estimate() {
+        let mut estimate = RttEstimate::default();
+        estimate.update(ms(100));
+
+        assert_eq!(estimate.smoothed_rtt, ms(100));
+        assert_eq!(estimate.rtt_variance, ms(50));
+        assert_eq!(estimate.samples, 1);
+        // SRTT + K * RTTVAR
+        assert_eq!(estimate.ack_wait_addition(), ms(300));
+    }
+
+    #[test]
+    fn subsequent_samples_are_smoothed() {
+        let mut estimate = RttEstimate::default();
+        estimate.update(ms(100));
+        estimate.update(ms(200));
+
+        // RTTVAR = 3/4 * 50ms + 1/4 * |100ms - 200ms|
+        assert_close(estimate.rtt_variance, Duration::from_micros(62_500));
+        // SRTT = 7/8 * 100ms + 1/8 * 200ms
+        assert_close(estimate.smoothed_rtt, Duration::from_micros(112_500));
+        assert_eq!(estimate.samples, 2);
+        assert_close(estimate.ack_wait_addition(), Duration::from_micros(362_500));
+    }
+
+    #[test]
+    fn backoff_doubles_the_addition_up_to_the_limit() {
+        let mut estimate = RttEstimate::default();
+        estimate.update(ms(100));
+
+        estimate.backoff();
+        assert_eq!(estimate.ack_wait_addition(), ms(600));
+        estimate.backoff();
+        assert_eq!(estimate.ack_wait_addition(), ms(1200));
+
+        for _ in 0..10 {
+            estimate.backoff();
+        }
+        assert_eq!(estimate.backoff_exponent, MAX_BACKOFF_EXPONENT);
+        assert_eq!(estimate.ack_wait_addition(), ms(300 * 64));
+    }
+
+    #[test]
+    fn new_sample_resets_the_backoff() {
+        let mut estimate = RttEstimate::default();
+        estimate.update(ms(100));
+        estimate.backoff();
+        estimate.backoff();
+
+        estimate.update(ms(100));
+        assert_eq!(estimate.backoff_exponent, 0);
+        assert_eq!(estimate.samples, 2);
+    }
+
+    #[test]
+    fn estimates_are_tracked_per_gateway() {
+        let estimates = AckRttEstimates::new();
+        let view = estimates.clone();
+
+        estimates.observe("gateway1", ms(100));
+        estimates.backoff("gateway2");
+
+        let first = view.get("gateway1").unwrap();
+        assert_eq!(first.smoothed_rtt, ms(100));
+        assert_eq!(first.samples, 1);
+
+        // timeouts alone do not produce any samples
+        let second = view.get("gateway2").unwrap();
+        assert_eq!(second.samples, 0);
+        assert_eq!(second.backoff_exponent, 1);
+
+        assert!(view.get("gateway3").is_none());
+        assert_eq!(view.all().len(), 2);
+    }
+}
@@ -34,6 +34,8 @@ use std::sync::Arc;
 use crate::client::replies::reply_controller;
 use crate::config;
 pub(crate) use acknowledgement_control::{AckActionSender, Action};
+pub use acknowledgement_control::{AckRttEstimates, RttEstimate};
+pub use real_traffic_stream::TrafficShapingStats;
 
 pub(crate) mod acknowledgement_control;
 pub(crate) mod message_handler;
@@ -62,11 +64,17 @@ pub struct Config {
 
 impl<'a> From<&'a Config> for acknowledgement_control::Config {
     fn from(cfg: &'a Config) -> Self {
-        acknowledgement_control::Config::new(
+        let ack_config = acknowledgement_control::Config::new(
             cfg.acks.ack_wait_addition,
             cfg.acks.ack_wait_multiplier,
         )
-        .with_custom_packet_size(cfg.traffic.primary_packet_size)
+        .with_custom_packet_size(cfg.traffic.primary_packet_size);
+
+        if cfg.acks.disable_adaptive_timeouts {
+            ack_config
+        } else {
+            ack_config.with_adaptive_timeouts(cfg.acks.minimum_ack_wait_addition)
+        }
     }
 }
 
@@ -143,6 +151,8 @@ impl RealMessagesController<OsRng> {
         reply_controller_receiver: ReplyControllerReceiver,
         lane_queue_lengths: LaneQueueLengths,
         client_connection_rx: ConnectionCommandReceiver,
+        ack_rtt_estimates: AckRttEstimates,
+        traffic_shaping_stats: TrafficShapingStats,
     ) -> Self {
         let rng = OsRng;
 
@@ -181,6 +191,8 @@ impl RealMessagesController<OsRng> {
             ack_controller_connectors,
             message_handler.clone(),
             reply_controller_sender,
+            config.self_recipient.gateway().to_base58_string(),
+            ack_rtt_estimates,
         );
 
         let reply_control = ReplyController::new(
@@ -199,6 +211,7 @@ impl RealMessagesController<OsRng> {
             topology_access,
             lane_queue_lengths,
             client_connection_rx,
+            traffic_shaping_stats,
         );
 
         RealMessagesController {
@@ -31,7 +31,10 @@ use tokio::time;
 #[cfg(target_arch = "wasm32")]
 use wasm_timer;
 
+pub use traffic_shaping::TrafficShapingStats;
+
 mod sending_delay_controller;
+mod traffic_shaping;
 
 /// Configurable parameters of the `OutQueueControl`
 pub(crate) struct Config {
@@ -116,6 +119,12 @@ where
 
     /// Report queue lengths so that upstream can backoff sending data, and keep connections open.
     lane_queue_lengths: LaneQueueLengths,
+
+    /// Number of packets that can still be sent out during the current slot of the shaped stream.
+    remaining_slot_packets: u32,
+
+    /// Statistics of the shaped stream, such as how often it had to delay real traffic.
+    traffic_shaping_stats: TrafficShapingStats,
 }
 
 #[derive(Debug)]
@@ -174,6 +183,7 @@ where
         topology_access: TopologyAccessor,
         lane_queue_lengths: LaneQueueLengths,
         client_connection_rx: ConnectionCommandReceiver,
+        traffic_shaping_stats: TrafficShapingStats,
     ) -> Self {
         OutQueueControl {
             config,
@@ -187,6 +197,8 @@ where
             transmission_buffer: TransmissionBuffer::new(),
             client_connection_rx,
             lane_queue_lengths,
+            remaining_slot_packets: 0,
+            traffic_shaping_stats,
         }
     }
 
@@ -466,11 +478,110 @@ where
         }
     }
 
+    fn shaped_packets_per_slot(&self) -> u32 {
+        // the config is not guaranteed to have been validated (say, if it was constructed by hand),
+        // so make sure we always send at least a single packet per slot
+        self.config.traffic.traffic_shaping_packets_per_slot.max(1)
+    }
+
+    fn poll_shaped(&mut self, cx: &mut Context<'_>) -> Poll<Option<StreamMessage>> {
+        // Start by checking if we have any incoming messages about closed connections
+        if let Poll::Ready(Some(id)) = Pin::new(&mut self.client_connection_rx).poll_next(cx) {
+            match id {
+                ConnectionCommand::Close(id) => self.on_close_connection(id),
+            }
+        }
+
+        // note: unlike the poisson stream, the shaped one purposely ignores the backpressure
+        // multiplier so that the observable rate would never change
+        let packets_per_slot = self.shaped_packets_per_slot();
+        let packet_interval = self.config.traffic.traffic_shaping_slot_duration / packets_per_slot;
+
+        if let Some(next_delay) = &mut self.next_delay {
+            // it is not yet time to send the next packet
+            if next_delay.as_mut().poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+
+            // Packets are evenly spread across the slot rather than being sent in a single burst
+            // at its start, so the next one goes out `packet_interval` after the one that just did.
+            // However, if we fell behind (say, because the gateway was slow), we do not attempt
+            // to catch up on the missed packets, as that would have resulted in a burst.
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                let now = time::Instant::now();
+                let next = next_delay.deadline() + packet_interval;
+                if next > now {
+                    next_delay.as_mut().reset(next);
+                } else {
+                    next_delay.as_mut().reset(now + packet_interval);
+                }
+            }
+
+            #[cfg(target_arch = "wasm32")]
+            {
+                next_delay.as_mut().reset(packet_interval);
+            }
+        } else {
+            // we never set an initial delay - let's do it now
+            cx.waker().wake_by_ref();
+
+            #[cfg(not(target_arch = "wasm32"))]
+            let next_delay = Box::pin(time::sleep(packet_interval));
+
+            #[cfg(target_arch = "wasm32")]
+            let next_delay = Box::pin(wasm_timer::Delay::new(packet_interval));
+
+            self.next_delay = Some(next_delay);
+            return Poll::Pending;
+        }
+
+        if self.remaining_slot_packets == 0 {
+            self.remaining_slot_packets = packets_per_slot;
+            self.traffic_shaping_stats.record_slot();
+        }
+
+        let real_next = match Pin::new(&mut self.real_receiver).poll_recv(cx) {
+            // in the case our real message channel stream was closed, we should also indicate we are closed
+            // (and whoever is using the stream should panic)
+            Poll::Ready(None) => return Poll::Ready(None),
+
+            Poll::Ready(Some((real_messages, conn_id))) => {
+                log::trace!("handling real_messages: size: {}", real_messages.len());
+
+                self.transmission_buffer.store(&conn_id, real_messages);
+                self.pop_next_message()
+            }
+
+            Poll::Pending => self.pop_next_message(),
+        };
+
+        self.remaining_slot_packets = self.remaining_slot_packets.saturating_sub(1);
+
+        // real packets always take the place of the cover ones,
+        // while whatever didn't fit in the slot stays queued for the next one
+        let next_message = if let Some(real_next) = real_next {
+            self.traffic_shaping_stats.record_real_packet();
+            StreamMessage::Real(Box::new(real_next))
+        } else {
+            self.traffic_shaping_stats.record_cover_packet();
+            StreamMessage::Cover
+        };
+
+        if self.remaining_slot_packets == 0 && self.transmission_buffer.total_size() > 0 {
+            self.traffic_shaping_stats.record_delayed_slot();
+        }
+
+        Poll::Ready(Some(next_message))
+    }
+
     fn poll_next_message(
         mut self: Pin<&mut Self>,
         cx: &mut Context<'_>,
     ) -> Poll<Option<StreamMessage>> {
-        if self.config.traffic.disable_main_poisson_packet_distribution {
+        if self.config.traffic.enable_traffic_shaping {
+            self.poll_shaped(cx)
+        } else if self.config.traffic.disable_main_poisson_packet_distribution {
             self.poll_immediate(cx)
         } else {
             self.poll_poisson(cx)
@@ -486,7 +597,16 @@ where
         let lanes = self.transmission_buffer.num_lanes();
         let mult = self.sending_delay_controller.current_multiplier();
         let delay = self.current_average_message_sending_delay().as_millis();
-        let status_str = if self.config.traffic.disable_main_poisson_packet_distribution {
+        let status_str = if self.config.traffic.enable_traffic_shaping {
+            let traffic = &self.config.traffic;
+            let per_slot = traffic.traffic_shaping_packets_per_slot;
+            let slot = traffic.traffic_shaping_slot_duration.as_millis();
+            let delayed = self.traffic_shaping_stats.delayed_slots();
+            let slots = self.traffic_shaping_stats.slots();
+            format!(
+                "Status: {lanes} lanes, backlog: {backlog:.2} kiB ({packets}), shaped: {per_slot} packets every {slot}ms, delayed slots: {delayed}/{slots}"
+            )
+        } else if self.config.traffic.disable_main_poisson_packet_distribution {
             format!("Status: {lanes} lanes, backlog: {backlog:.2} kiB ({packets}), no delay")
         } else {
             format!(
@@ -567,3 +687,208 @@ where
         self.poll_next_message(cx)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::channel::mpsc;
+    use nym_crypto::asymmetric::{encryption, identity};
+    use nym_sphinx::addressing::nodes::NymNodeRoutingAddress;
+    use nym_sphinx::params::PacketType;
+    use nym_sphinx::{
+        crypto, Delay as SphinxDelay, Destination, DestinationAddressBytes, Node, NodeAddressBytes,
+        NymPacket, DESTINATION_ADDRESS_LENGTH, IDENTIFIER_LENGTH, NODE_ADDRESS_LENGTH,
+    };
+    use rand::rngs::OsRng;
+    use std::net::SocketAddr;
+    use tokio::time::Instant;
+
+    const SLOT: Duration = Duration::from_millis(100);
+
+    struct TestStream {
+        stream: OutQueueControl<OsRng>,
+        real_sender: BatchRealMessageSender,
+        stats: TrafficShapingStats,
+
+        // the channels have to stay alive for the duration of the test
+        _sent_receiver: mpsc::UnboundedReceiver<FragmentIdentifier>,
+        _mix_receiver: tokio::sync::mpsc::Receiver<Vec<MixPacket>>,
+        _connection_sender: mpsc::UnboundedSender<ConnectionCommand>,
+    }
+
+    impl TestStream {
+        fn new(packets_per_slot: u32) -> Self {
+            let mut rng = OsRng;
+            let our_address = Recipient::new(
+                *identity::KeyPair::new(&mut rng).public_key(),
+                *encryption::KeyPair::new(&mut rng).public_key(),
+                *identity::KeyPair::new(&mut rng).public_key(),
+            );
+            let traffic = config::Traffic {
+                enable_traffic_shaping: true,
+                traffic_shaping_slot_duration: SLOT,
+                traffic_shaping_packets_per_slot: packets_per_slot,
+                ..Default::default()
+            };
+            let config = Config::new(
+                Arc::new(AckKey::new(&mut rng)),
+                our_address,
+                Duration::from_millis(50),
+                traffic,
+                0.5,
+            );
+
+            let (sent_notifier, sent_receiver) = mpsc::unbounded();
+            let (mix_tx, mix_receiver) = tokio::sync::mpsc::channel(16);
+            let (real_sender, real_receiver) = tokio::sync::mpsc::channel(16);
+            let (connection_sender, client_connection_rx) = mpsc::unbounded();
+            let stats = TrafficShapingStats::new();
+
+            let stream = OutQueueControl::new(
+                config,
+                rng,
+                sent_notifier,
+                mix_tx,
+                real_receiver,
+                TopologyAccessor::new(),
+                LaneQueueLengths::new(),
+                client_connection_rx,
+                stats.clone(),
+            );
+
+            TestStream {
+                stream,
+                real_sender,
+                stats,
+                _sent_receiver: sent_receiver,
+                _mix_receiver: mix_receiver,
+                _connection_sender: connection_sender,
+            }
+        }
+
+        async fn send_real(&self, amount: usize) {
+            let messages = (0..amount).map(|_| dummy_real_message()).collect();
+            self.real_sender
+                .send((messages, TransmissionLane::General))
+                .await
+                .unwrap();
+        }
+
+        // returns whether the received messages were real alongside the time they were sent at
+        async fn next_messages(&mut self, amount: usize) -> Vec<(bool, Instant)> {
+            let mut received = Vec::with_capacity(amount);
+            for _ in 0..amount {
+                let message = self.stream.next().await.unwrap();
+                received.push((matches!(message, StreamMessage::Real(_)), Instant::now()));
+            }
+            received
+        }
+    }
+
+    fn dummy_real_message() -> RealMessage {
+        let (_, node_pk) = crypto::keygen();
+        let node = Node::new(
+            NodeAddressBytes::from_bytes([5u8; NODE_ADDRESS_LENGTH]),
+            node_pk,
+        );
+        let destination = Destination::new(
+            DestinationAddressBytes::from_bytes([3u8; DESTINATION_ADDRESS_LENGTH]),
+            [4u8; IDENTIFIER_LENGTH],
+        );
+        let packet = NymPacket::sphinx_build(
+            PacketSize::RegularPacket.payload_size(),
+            b"foomp",
+            &[node],
+            &destination,
+            &[SphinxDelay::new_from_nanos(42)],
+        )
+        .unwrap();
+        let next_hop = NymNodeRoutingAddress::from("1.2.3.4:1789".parse::<SocketAddr>().unwrap());
+
+        RealMessage::new(MixPacket::new(next_hop, packet, PacketType::Mix), None)
+    }
+
+    fn assert_evenly_spaced(received: &[(bool, Instant)], start: Instant, interval: Duration) {
+        let mut expected = start;
+        for (_, sent_at) in received {
+            expected += interval;
+            assert_eq!(*sent_at, expected);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shaped_stream_sends_cover_at_constant_rate_without_real_traffic() {
+        let mut test_stream = TestStream::new(4);
+        let start = Instant::now();
+
+        let received = test_stream.next_messages(8).await;
+        assert!(received.iter().all(|(real, _)| !real));
+
+        // packets are spread across the slot rather than being sent in a burst
+        assert_evenly_spaced(&received, start, SLOT / 4);
+
+        assert_eq!(test_stream.stats.slots(), 2);
+        assert_eq!(test_stream.stats.cover_packets(), 8);
+        assert_eq!(test_stream.stats.real_packets(), 0);
+        assert_eq!(test_stream.stats.delayed_slots(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shaped_stream_replaces_cover_with_real_traffic() {
+        let mut test_stream = TestStream::new(4);
+        test_stream.send_real(2).await;
+        let start = Instant::now();
+
+        let received = test_stream.next_messages(4).await;
+        let real = received.iter().map(|(real, _)| *real).collect::<Vec<_>>();
+        assert_eq!(real, vec![true, true, false, false]);
+
+        // the rate is not affected by the presence of real traffic
+        assert_evenly_spaced(&received, start, SLOT / 4);
+
+        assert_eq!(test_stream.stats.slots(), 1);
+        assert_eq!(test_stream.stats.real_packets(), 2);
+        assert_eq!(test_stream.stats.cover_packets(), 2);
+        assert_eq!(test_stream.stats.delayed_slots(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shaped_stream_queues_real_traffic_beyond_the_slot_budget() {
+        let mut test_stream = TestStream::new(4);
+        test_stream.send_real(6).await;
+        let start = Instant::now();
+
+        let first_slot = test_stream.next_messages(4).await;
+        assert!(first_slot.iter().all(|(real, _)| *real));
+        assert_eq!(test_stream.stats.delayed_slots(), 1);
+
+        let second_slot = test_stream.next_messages(4).await;
+        let real = second_slot
+            .iter()
+            .map(|(real, _)| *real)
+            .collect::<Vec<_>>();
+        assert_eq!(real, vec![true, true, false, false]);
+
+        // the excess packets did not result in any burst
+        assert_evenly_spaced(&[first_slot, second_slot].concat(), start, SLOT / 4);
+
+        assert_eq!(test_stream.stats.slots(), 2);
+        assert_eq!(test_stream.stats.real_packets(), 6);
+        assert_eq!(test_stream.stats.cover_packets(), 2);
+        assert_eq!(test_stream.stats.delayed_slots(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shaped_stream_sends_at_least_one_packet_per_slot() {
+        // such config would have been rejected by the validation, but the stream itself
+        // should not misbehave either
+        let mut test_stream = TestStream::new(0);
+        let start = Instant::now();
+
+        let received = test_stream.next_messages(3).await;
+        assert_evenly_spaced(&received, start, SLOT);
+
+        assert_eq!(test_stream.stats.slots(), 3);
+        assert_eq!(test_stream.stats.cover_packets(), 3);
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Shared view of the statistics of the shaped (constant rate) packet stream.
+/// All values stay at zero unless traffic shaping is enabled.
+#[derive(Debug, Clone, Default)]
+pub struct TrafficShapingStats(Arc<TrafficShapingStatsInner>);
+
+#[derive(Debug, Default)]
+struct TrafficShapingStatsInner {
+    slots: AtomicU64,
+    real_packets: AtomicU64,
+    cover_packets: AtomicU64,
+    delayed_slots: AtomicU64,
+}
+
+impl TrafficShapingStats {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Total number of sending slots that have elapsed.
+    pub fn slots(&self) -> u64 {
+        self.0.slots.load(Ordering::Relaxed)
+    }
+
+    /// Total number of real packets sent as part of the shaped stream.
+    pub fn real_packets(&self) -> u64 {
+        self.0.real_packets.load(Ordering::Relaxed)
+    }
+
+    /// Total number of loop cover packets sent in order to fill the slots.
+    pub fn cover_packets(&self) -> u64 {
+        self.0.cover_packets.load(Ordering::Relaxed)
+    }
+
+    /// Total number of slots at the end of which some real packets had to stay queued,
+    /// i.e. how often the shaping has delayed real traffic.
+    pub fn delayed_slots(&self) -> u64 {
+        self.0.delayed_slots.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn record_slot(&self) {
+        self.0.slots.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_real_packet(&self) {
+        self.0.real_packets.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_cover_packet(&self) {
+        self.0.cover_packets.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_delayed_slot(&self) {
+        self.0.delayed_slots.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_are_shared_between_clones() {
+        let stats = TrafficShapingStats::new();
+        let view = stats.clone();
+
+        stats.record_slot();
+        stats.record_slot();
+        stats.record_real_packet();
+        stats.record_cover_packet();
+        stats.record_cover_packet();
+        stats.record_cover_packet();
+        stats.record_delayed_slot();
+
+        assert_eq!(view.slots(), 2);
+        assert_eq!(view.real_packets(), 1);
+        assert_eq!(view.cover_packets(), 3);
+        assert_eq!(view.delayed_slots(), 1);
+    }
+
+    #[test]
+    fn fresh_stats_are_zeroed() {
+        let stats = TrafficShapingStats::new();
+        assert_eq!(stats.slots(), 0);
+        assert_eq!(stats.real_packets(), 0);
+        assert_eq!(stats.cover_packets(), 0);
+        assert_eq!(stats.delayed_slots(), 0);
+    }
+}
@@ -21,9 +21,14 @@ pub mod old_config_v1_1_20_2;
 const DEFAULT_ACK_WAIT_MULTIPLIER: f64 = 1.5;
 
 const DEFAULT_ACK_WAIT_ADDITION: Duration = Duration::from_millis(1_500);
+const DEFAULT_MINIMUM_ACK_WAIT_ADDITION: Duration = Duration::from_millis(500);
 const DEFAULT_LOOP_COVER_STREAM_AVERAGE_DELAY: Duration = Duration::from_millis(200);
+const DEFAULT_DROP_COVER_STREAM_AVERAGE_DELAY: Duration = Duration::from_millis(200);
+const DEFAULT_RECEIVE_COVER_AVERAGE_DELAY: Duration = Duration::from_millis(200);
 const DEFAULT_MESSAGE_STREAM_AVERAGE_DELAY: Duration = Duration::from_millis(20);
 const DEFAULT_AVERAGE_PACKET_DELAY: Duration = Duration::from_millis(50);
+const DEFAULT_TRAFFIC_SHAPING_SLOT_DURATION: Duration = Duration::from_millis(20);
+const DEFAULT_TRAFFIC_SHAPING_PACKETS_PER_SLOT: u32 = 1;
 const DEFAULT_TOPOLOGY_REFRESH_RATE: Duration = Duration::from_secs(5 * 60); // every 5min
 const DEFAULT_TOPOLOGY_RESOLUTION_TIMEOUT: Duration = Duration::from_millis(5_000);
 // Set this to a high value for now, so that we don't risk sporadic timeouts that might cause
@@ -208,7 +213,7 @@ impl GatewayEndpointConfig {
 
 impl From<nym_topology::gateway::Node> for GatewayEndpointConfig {
     fn from(node: nym_topology::gateway::Node) -> GatewayEndpointConfig {
-        let gateway_listener = node.clients_address();
+        let gateway_listener = node.preferred_clients_address();
         GatewayEndpointConfig {
             gateway_id: node.identity_key.to_base58_string(),
             gateway_owner: node.owner,
@@ -312,6 +317,23 @@ pub struct Traffic {
     pub secondary_packet_size: Option<PacketSize>,
 
     pub packet_type: PacketType,
+
+    /// Controls whether the main packet stream should be shaped into a constant rate envelope.
+    /// When enabled, exactly [Self::traffic_shaping_packets_per_slot] packets are sent every
+    /// [Self::traffic_shaping_slot_duration], real packets taking precedence over loop cover packets.
+    /// The packets are evenly spaced within the slot rather than being sent in a single burst.
+    /// Real packets exceeding the budget of a slot are queued rather than dropped.
+    /// This takes precedence over both the poisson distribution and
+    /// [Self::disable_main_poisson_packet_distribution], and it also replaces the dedicated
+    /// loop and drop cover traffic streams, as any additional packets would break the envelope.
+    pub enable_traffic_shaping: bool,
+
+    /// The duration of a single sending slot of the shaped packet stream.
+    #[serde(with = "humantime_serde")]
+    pub traffic_shaping_slot_duration: Duration,
+
+    /// The number of packets sent during every slot of the shaped packet stream.
+    pub traffic_shaping_packets_per_slot: u32,
 }
 
 impl Traffic {
@@ -323,7 +345,14 @@ impl Traffic {
                 return false;
             }
         }
-        true
+        self.validate_traffic_shaping()
+    }
+
+    /// Checks whether the shaped stream (if enabled) has a non-zero rate.
+    pub fn validate_traffic_shaping(&self) -> bool {
+        !self.enable_traffic_shaping
+            || (!self.traffic_shaping_slot_duration.is_zero()
+                && self.traffic_shaping_packets_per_slot > 0)
     }
 }
 
@@ -336,6 +365,9 @@ impl Default for Traffic {
             primary_packet_size: PacketSize::RegularPacket,
             secondary_packet_size: None,
             packet_type: PacketType::Mix,
+            enable_traffic_shaping: false,
+            traffic_shaping_slot_duration: DEFAULT_TRAFFIC_SHAPING_SLOT_DURATION,
+            traffic_shaping_packets_per_slot: DEFAULT_TRAFFIC_SHAPING_PACKETS_PER_SLOT,
         }
     }
 }
@@ -355,6 +387,27 @@ pub struct CoverTraffic {
     /// Controls whether the dedicated loop cover traffic stream should be enabled.
     /// (and sending packets, on average, every [Self::loop_cover_traffic_average_delay])
     pub disable_loop_cover_traffic_stream: bool,
+
+    /// The parameter of Poisson distribution determining how long, on average,
+    /// it is going to take for another drop cover traffic message to be sent.
+    #[serde(with = "humantime_serde")]
+    pub drop_cover_traffic_average_delay: Duration,
+
+    /// Controls whether the dedicated drop cover traffic stream should be enabled.
+    /// Drop cover messages are sent through the mixnet to random other gateways, which silently
+    /// discard them, so unlike the loop cover, they never come back to us.
+    pub enable_drop_cover_traffic_stream: bool,
+
+    /// The parameter of Poisson distribution determining how long, on average,
+    /// it is going to take for our gateway to push us another receive cover message.
+    /// Note that the gateway might impose a higher minimum value.
+    #[serde(with = "humantime_serde")]
+    pub receive_cover_traffic_average_delay: Duration,
+
+    /// Controls whether our gateway should be asked to push dummy messages to us,
+    /// so that our receive pattern would not reveal the amount of traffic we actually get.
+    /// Note that the gateway charges those messages against our bandwidth.
+    pub enable_receive_cover_traffic: bool,
 }
 
 impl Default for CoverTraffic {
@@ -363,6 +416,10 @@ impl Default for CoverTraffic {
             loop_cover_traffic_average_delay: DEFAULT_LOOP_COVER_STREAM_AVERAGE_DELAY,
             cover_traffic_primary_size_ratio: DEFAULT_COVER_TRAFFIC_PRIMARY_SIZE_RATIO,
             disable_loop_cover_traffic_stream: false,
+            drop_cover_traffic_average_delay: DEFAULT_DROP_COVER_STREAM_AVERAGE_DELAY,
+            enable_drop_cover_traffic_stream: false,
+            receive_cover_traffic_average_delay: DEFAULT_RECEIVE_COVER_AVERAGE_DELAY,
+            enable_receive_cover_traffic: false,
         }
     }
 }
@@ -404,6 +461,17 @@ pub struct Acknowledgements {
     /// In an ideal network with 0 latency, this value would have been 0.
     #[serde(with = "humantime_serde")]
     pub ack_wait_addition: Duration,
+
+    /// Controls whether the ack timeouts should be derived purely from the static
+    /// `ack_wait_multiplier` and `ack_wait_addition` rather than from the observed round trip times.
+    /// When adaptive timeouts are used, the static values act as the ceiling of the timeout.
+    pub disable_adaptive_timeouts: bool,
+
+    /// When adaptive timeouts are used, specifies the minimum value added to the expected
+    /// round trip time of an acknowledgement packet before it is assumed it was lost,
+    /// regardless of how fast the network appears to be.
+    #[serde(with = "humantime_serde")]
+    pub minimum_ack_wait_addition: Duration,
 }
 
 impl Default for Acknowledgements {
@@ -412,6 +480,8 @@ impl Default for Acknowledgements {
             average_ack_delay: DEFAULT_AVERAGE_PACKET_DELAY,
             ack_wait_multiplier: DEFAULT_ACK_WAIT_MULTIPLIER,
             ack_wait_addition: DEFAULT_ACK_WAIT_ADDITION,
+            disable_adaptive_timeouts: false,
+            minimum_ack_wait_addition: DEFAULT_MINIMUM_ACK_WAIT_ADDITION,
         }
     }
 }
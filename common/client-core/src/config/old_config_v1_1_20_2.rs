@@ -142,6 +142,7 @@ impl From<TrafficV1_1_20_2> for Traffic {
             primary_packet_size: value.primary_packet_size,
             secondary_packet_size: value.secondary_packet_size,
             packet_type: PacketType::Mix,
+            ..Default::default()
         }
     }
 }
@@ -174,6 +175,7 @@ impl From<CoverTrafficV1_1_20_2> for CoverTraffic {
             loop_cover_traffic_average_delay: value.loop_cover_traffic_average_delay,
             cover_traffic_primary_size_ratio: value.cover_traffic_primary_size_ratio,
             disable_loop_cover_traffic_stream: value.disable_loop_cover_traffic_stream,
+            ..Default::default()
         }
     }
 }
@@ -227,6 +229,7 @@ impl From<AcknowledgementsV1_1_20_2> for Acknowledgements {
             average_ack_delay: value.average_ack_delay,
             ack_wait_multiplier: value.ack_wait_multiplier,
             ack_wait_addition: value.ack_wait_addition,
+            ..Acknowledgements::default()
         }
     }
 }
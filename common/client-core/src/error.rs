@@ -116,6 +116,9 @@ pub enum ClientCoreError {
 
     #[error("the provided gateway details (for gateway {gateway_id}) do not correspond to the shared keys")]
     MismatchedGatewayDetails { gateway_id: String },
+
+    #[error("the traffic shaping requires a non-zero slot duration and at least a single packet per slot")]
+    InvalidTrafficShapingConfig,
 }
 
 /// Set of messages that the client can send to listeners via the task manager
@@ -92,7 +92,7 @@ async fn connect(endpoint: &str) -> Result<WsConn, ClientCoreError> {
 }
 
 async fn measure_latency(gateway: &gateway::Node) -> Result<GatewayWithLatency, ClientCoreError> {
-    let addr = gateway.clients_address();
+    let addr = gateway.preferred_clients_address();
     trace!(
         "establishing connection to {} ({addr})...",
         gateway.identity_key,
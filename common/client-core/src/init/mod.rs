@@ -405,8 +405,13 @@ where
     K::StorageError: Send + Sync + 'static,
     D::StorageError: Send + Sync + 'static,
 {
-    let mut rng = OsRng;
-    let gateways = current_gateways(&mut rng, validator_servers.unwrap_or_default()).await?;
+    // there's no point in querying the network if we already know everything about our gateway
+    let gateways = if setup.has_full_details() {
+        Vec::new()
+    } else {
+        let mut rng = OsRng;
+        current_gateways(&mut rng, validator_servers.unwrap_or_default()).await?
+    };
 
     setup_gateway_from(
         setup,
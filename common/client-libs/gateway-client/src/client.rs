@@ -4,7 +4,8 @@
 use crate::error::GatewayClientError;
 use crate::packet_router::PacketRouter;
 pub use crate::packet_router::{
-    AcknowledgementReceiver, AcknowledgementSender, MixnetMessageReceiver, MixnetMessageSender,
+    AcknowledgementReceiver, AcknowledgementSender, GatewayShutdownNoticeSender,
+    MixnetMessageReceiver, MixnetMessageSender,
 };
 use crate::socket_state::{PartiallyDelegated, SocketState};
 use crate::{cleanup_socket_message, try_decrypt_binary_message};
@@ -56,6 +57,10 @@ pub struct GatewayClient<C, St> {
     response_timeout_duration: Duration,
     bandwidth_controller: Option<BandwidthController<C, St>>,
 
+    /// If specified, the gateway is going to be asked to push receive cover messages to us,
+    /// on average, with this delay.
+    receive_cover_average_delay: Option<Duration>,
+
     // reconnection related variables
     /// Specifies whether client should try to reconnect to gateway on connection failure.
     should_reconnect_on_failure: bool,
@@ -96,6 +101,7 @@ impl<C, St> GatewayClient<C, St> {
             packet_router: PacketRouter::new(ack_sender, mixnet_message_sender, shutdown.clone()),
             response_timeout_duration,
             bandwidth_controller,
+            receive_cover_average_delay: None,
             should_reconnect_on_failure: true,
             reconnection_attempts: DEFAULT_RECONNECTION_ATTEMPTS,
             reconnection_backoff: DEFAULT_RECONNECTION_BACKOFF,
@@ -107,6 +113,20 @@ impl<C, St> GatewayClient<C, St> {
         self.disabled_credentials_mode = disabled_credentials_mode;
     }
 
+    pub fn set_receive_cover_traffic(&mut self, average_delay: Option<Duration>) {
+        self.receive_cover_average_delay = average_delay;
+    }
+
+    /// Specifies the channel on which the gateway announcing its upcoming shutdown is going to be reported.
+    /// Note: it has to be set before starting to listen for mixnet messages.
+    pub fn set_shutdown_notice_sender(
+        &mut self,
+        shutdown_notice_sender: GatewayShutdownNoticeSender,
+    ) {
+        self.packet_router
+            .set_shutdown_notice_sender(shutdown_notice_sender)
+    }
+
     // TODO: later convert into proper builder methods
     pub fn with_reconnection_on_failure(&mut self, should_reconnect_on_failure: bool) {
         self.should_reconnect_on_failure = should_reconnect_on_failure
@@ -422,6 +442,9 @@ impl<C, St> GatewayClient<C, St> {
             ServerResponse::Error { message } => {
                 return Err(GatewayClientError::GatewayError(message))
             }
+            ServerResponse::ShuttingDown { suggested_gateway } => {
+                return Err(GatewayClientError::GatewayShuttingDown { suggested_gateway })
+            }
             _ => return Err(GatewayClientError::UnexpectedResponse),
         };
 
@@ -476,6 +499,9 @@ impl<C, St> GatewayClient<C, St> {
                 Ok(())
             }
             ServerResponse::Error { message } => Err(GatewayClientError::GatewayError(message)),
+            ServerResponse::ShuttingDown { suggested_gateway } => {
+                Err(GatewayClientError::GatewayShuttingDown { suggested_gateway })
+            }
             _ => Err(GatewayClientError::UnexpectedResponse),
         }
     }
@@ -513,6 +539,9 @@ impl<C, St> GatewayClient<C, St> {
         self.bandwidth_remaining = match self.send_websocket_message(msg).await? {
             ServerResponse::Bandwidth { available_total } => Ok(available_total),
             ServerResponse::Error { message } => Err(GatewayClientError::GatewayError(message)),
+            ServerResponse::ShuttingDown { suggested_gateway } => {
+                Err(GatewayClientError::GatewayShuttingDown { suggested_gateway })
+            }
             _ => Err(GatewayClientError::UnexpectedResponse),
         }?;
         Ok(())
@@ -523,12 +552,46 @@ impl<C, St> GatewayClient<C, St> {
         self.bandwidth_remaining = match self.send_websocket_message(msg).await? {
             ServerResponse::Bandwidth { available_total } => Ok(available_total),
             ServerResponse::Error { message } => Err(GatewayClientError::GatewayError(message)),
+            ServerResponse::ShuttingDown { suggested_gateway } => {
+                Err(GatewayClientError::GatewayShuttingDown { suggested_gateway })
+            }
+            _ => Err(GatewayClientError::UnexpectedResponse),
+        }?;
+
+        Ok(())
+    }
+
+    async fn request_receive_cover_traffic(
+        &mut self,
+        average_delay: Duration,
+    ) -> Result<(), GatewayClientError> {
+        let msg = ClientControlRequest::ReceiveCoverTraffic {
+            average_delay_ms: average_delay.as_millis() as u64,
+        }
+        .into();
+        let average_delay_ms = match self.send_websocket_message(msg).await? {
+            ServerResponse::ReceiveCoverTraffic { average_delay_ms } => Ok(average_delay_ms),
+            ServerResponse::Error { message } => Err(GatewayClientError::GatewayError(message)),
+            ServerResponse::ShuttingDown { suggested_gateway } => {
+                Err(GatewayClientError::GatewayShuttingDown { suggested_gateway })
+            }
             _ => Err(GatewayClientError::UnexpectedResponse),
         }?;
 
+        info!("the gateway is going to push receive cover messages to us every {average_delay_ms}ms on average");
         Ok(())
     }
 
+    // receive cover is purely optional, so failing to enable it (say, because the gateway is
+    // running an older version) should not prevent us from using the connection
+    async fn try_enable_receive_cover_traffic(&mut self) {
+        if let Some(average_delay) = self.receive_cover_average_delay {
+            if let Err(err) = self.request_receive_cover_traffic(average_delay).await {
+                warn!("failed to enable receive cover traffic: {err}");
+            }
+        }
+    }
+
     pub async fn claim_bandwidth(&mut self) -> Result<(), GatewayClientError>
     where
         C: DkgQueryClient + Send + Sync,
@@ -731,6 +794,7 @@ impl<C, St> GatewayClient<C, St> {
 
         // TODO: the name of this method is very deceiving
         self.perform_initial_authentication().await?;
+        self.try_enable_receive_cover_traffic().await;
 
         // this call is NON-blocking
         self.start_listening_for_mixnet_messages()?;
@@ -738,6 +802,16 @@ impl<C, St> GatewayClient<C, St> {
         Ok(())
     }
 
+    /// Drops the current connection to the gateway that has announced its shutdown and attempts
+    /// to establish a fresh one, so that we'd re-authenticate (and retrieve any messages put in our inbox
+    /// in the meantime) as soon as it's back online.
+    pub async fn reconnect_after_gateway_shutdown(&mut self) -> Result<(), GatewayClientError> {
+        // there's no point in trying to recover the stream as the gateway has most likely already
+        // closed the connection. dropping it will also terminate the listener task.
+        self.connection = SocketState::NotConnected;
+        self.attempt_reconnection().await
+    }
+
     pub async fn disconnect(&mut self) -> Result<(), GatewayClientError> {
         self.recover_socket_connection().await?;
         self.connection = SocketState::NotConnected;
@@ -761,6 +835,8 @@ impl<C, St> GatewayClient<C, St> {
             self.claim_bandwidth().await?;
         }
 
+        self.try_enable_receive_cover_traffic().await;
+
         // this call is NON-blocking
         self.start_listening_for_mixnet_messages()?;
 
@@ -797,6 +873,7 @@ impl<C> GatewayClient<C, EphemeralCredentialStorage> {
             packet_router,
             response_timeout_duration,
             bandwidth_controller: None,
+            receive_cover_average_delay: None,
             should_reconnect_on_failure: false,
             reconnection_attempts: DEFAULT_RECONNECTION_ATTEMPTS,
             reconnection_backoff: DEFAULT_RECONNECTION_BACKOFF,
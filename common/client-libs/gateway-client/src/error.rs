@@ -42,6 +42,9 @@ pub enum GatewayClientError {
     #[error("Connection was abruptly closed as gateway was stopped")]
     ConnectionClosedGatewayShutdown,
 
+    #[error("The gateway is shutting down and is no longer accepting requests. Suggested replacement gateway: {suggested_gateway:?}")]
+    GatewayShuttingDown { suggested_gateway: Option<String> },
+
     #[error("Received response was malformed")]
     MalformedResponse,
 
@@ -6,7 +6,9 @@ pub use client::GatewayClient;
 use log::warn;
 use nym_gateway_requests::BinaryResponse;
 pub use packet_router::{
-    AcknowledgementReceiver, AcknowledgementSender, MixnetMessageReceiver, MixnetMessageSender,
+    AcknowledgementReceiver, AcknowledgementSender, GatewayShutdownNotice,
+    GatewayShutdownNoticeReceiver, GatewayShutdownNoticeSender, MixnetMessageReceiver,
+    MixnetMessageSender,
 };
 use tungstenite::{protocol::Message, Error as WsError};
 
@@ -8,6 +8,7 @@ use crate::error::GatewayClientError;
 use futures::channel::mpsc;
 use log::*;
 use nym_sphinx::addressing::nodes::MAX_NODE_ADDRESS_UNPADDED_LEN;
+use nym_sphinx::cover::is_receive_cover;
 use nym_sphinx::params::packet_sizes::PacketSize;
 use nym_task::TaskClient;
 
@@ -17,10 +18,21 @@ pub type MixnetMessageReceiver = mpsc::UnboundedReceiver<Vec<Vec<u8>>>;
 pub type AcknowledgementSender = mpsc::UnboundedSender<Vec<Vec<u8>>>;
 pub type AcknowledgementReceiver = mpsc::UnboundedReceiver<Vec<Vec<u8>>>;
 
+pub type GatewayShutdownNoticeSender = mpsc::UnboundedSender<GatewayShutdownNotice>;
+pub type GatewayShutdownNoticeReceiver = mpsc::UnboundedReceiver<GatewayShutdownNotice>;
+
+/// Announcement received from our gateway that it's about to go offline.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GatewayShutdownNotice {
+    /// Optional identity key of a gateway we're recommended to move to.
+    pub suggested_gateway: Option<String>,
+}
+
 #[derive(Clone, Debug)]
 pub struct PacketRouter {
     ack_sender: AcknowledgementSender,
     mixnet_message_sender: MixnetMessageSender,
+    shutdown_notice_sender: Option<GatewayShutdownNoticeSender>,
     shutdown: TaskClient,
 }
 
@@ -33,10 +45,34 @@ impl PacketRouter {
         PacketRouter {
             ack_sender,
             mixnet_message_sender,
+            shutdown_notice_sender: None,
             shutdown,
         }
     }
 
+    pub fn set_shutdown_notice_sender(
+        &mut self,
+        shutdown_notice_sender: GatewayShutdownNoticeSender,
+    ) {
+        self.shutdown_notice_sender = Some(shutdown_notice_sender)
+    }
+
+    pub fn route_shutdown_notice(&self, notice: GatewayShutdownNotice) {
+        match &self.shutdown_notice_sender {
+            Some(sender) => {
+                if let Err(err) = sender.unbounded_send(notice) {
+                    error!("failed to forward the gateway shutdown notice: {err}");
+                }
+            }
+            None => match notice.suggested_gateway {
+                Some(gateway) => {
+                    warn!("our gateway is shutting down! it suggested moving to {gateway}")
+                }
+                None => warn!("our gateway is shutting down!"),
+            },
+        }
+    }
+
     pub fn route_received(
         &mut self,
         unwrapped_packets: Vec<Vec<u8>>,
@@ -52,6 +88,12 @@ impl PacketRouter {
             PacketSize::OutfoxAckPacket.size() + MAX_NODE_ADDRESS_UNPADDED_LEN;
 
         for received_packet in unwrapped_packets {
+            // the gateway might be pushing us dummy messages if we requested receive cover
+            if is_receive_cover(&received_packet) {
+                trace!("the received packet was a receive cover message! Dropping it");
+                continue;
+            }
+
             if received_packet.len() == PacketSize::AckPacket.plaintext_size()
             // we don't know the real size of the payload, it could be anything <= 48 bytes
                 || received_packet.len() <= PacketSize::OutfoxAckPacket.plaintext_size()
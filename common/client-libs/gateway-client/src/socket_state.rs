@@ -2,13 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::error::GatewayClientError;
-use crate::packet_router::PacketRouter;
+use crate::packet_router::{GatewayShutdownNotice, PacketRouter};
 use crate::{cleanup_socket_messages, try_decrypt_binary_message};
 use futures::channel::oneshot;
 use futures::stream::{SplitSink, SplitStream};
 use futures::{SinkExt, StreamExt};
 use log::*;
 use nym_gateway_requests::registration::handshake::SharedKeys;
+use nym_gateway_requests::ServerResponse;
 use nym_task::TaskClient;
 use std::sync::Arc;
 use tungstenite::Message;
@@ -42,7 +43,11 @@ pub(crate) struct PartiallyDelegated {
 }
 
 impl PartiallyDelegated {
-    fn recover_received_plaintexts(ws_msgs: Vec<Message>, shared_key: &SharedKeys) -> Vec<Vec<u8>> {
+    fn recover_received_plaintexts(
+        ws_msgs: Vec<Message>,
+        packet_router: &PacketRouter,
+        shared_key: &SharedKeys,
+    ) -> Vec<Vec<u8>> {
         let mut plaintexts = Vec::with_capacity(ws_msgs.len());
         for ws_msg in ws_msgs {
             match ws_msg {
@@ -59,6 +64,15 @@ impl PartiallyDelegated {
 
                 // TODO: those can return the "send confirmations" - perhaps it should be somehow worked around?
                 Message::Text(text) => {
+                    if let Ok(ServerResponse::ShuttingDown { suggested_gateway }) =
+                        ServerResponse::try_from(text.clone())
+                    {
+                        // the gateway will keep pushing us messages until it closes the connection,
+                        // so keep on listening, but let the client know it has to prepare for it
+                        packet_router
+                            .route_shutdown_notice(GatewayShutdownNotice { suggested_gateway });
+                        continue;
+                    }
                     trace!(
                     "received a text message - probably a response to some previous query! - {}",
                     text
@@ -76,7 +90,7 @@ impl PartiallyDelegated {
         packet_router: &mut PacketRouter,
         shared_key: &SharedKeys,
     ) -> Result<(), GatewayClientError> {
-        let plaintexts = Self::recover_received_plaintexts(ws_msgs, shared_key);
+        let plaintexts = Self::recover_received_plaintexts(ws_msgs, packet_router, shared_key);
         packet_router.route_received(plaintexts)
     }
 
@@ -4,6 +4,7 @@
 use futures::channel::mpsc;
 use futures::StreamExt;
 use log::*;
+use nym_noise::{upgrade_noise_initiator, NoiseCodec, NoiseConfig};
 use nym_sphinx::addressing::nodes::NymNodeRoutingAddress;
 use nym_sphinx::framing::codec::NymCodec;
 use nym_sphinx::framing::packet::FramedNymPacket;
@@ -25,6 +26,7 @@ pub struct Config {
     initial_connection_timeout: Duration,
     maximum_connection_buffer_size: usize,
     use_legacy_version: bool,
+    noise_config: Option<NoiseConfig>,
 }
 
 impl Config {
@@ -34,6 +36,7 @@ impl Config {
         initial_connection_timeout: Duration,
         maximum_connection_buffer_size: usize,
         use_legacy_version: bool,
+        noise_config: Option<NoiseConfig>,
     ) -> Self {
         Config {
             initial_reconnection_backoff,
@@ -41,6 +44,7 @@ impl Config {
             initial_connection_timeout,
             maximum_connection_buffer_size,
             use_legacy_version,
+            noise_config,
         }
     }
 }
@@ -83,21 +87,42 @@ impl Client {
         }
     }
 
+    /// Attempts to perform the noise handshake on the freshly established connection.
+    /// A legacy unencrypted link is only ever used if the noise key of the remote is not known
+    /// and legacy links are allowed. If the handshake with a known node fails,
+    /// the connection is dropped as the remote might be impersonating it.
+    async fn upgrade_connection(
+        stream: TcpStream,
+        address: SocketAddr,
+        noise_config: Option<&NoiseConfig>,
+    ) -> Option<Framed<TcpStream, NoiseCodec<NymCodec>>> {
+        let Some(noise_config) = noise_config else {
+            return Some(Framed::new(stream, NoiseCodec::new_legacy(NymCodec)));
+        };
+
+        match upgrade_noise_initiator(stream, NymCodec, noise_config, address).await {
+            Ok(conn) => Some(conn),
+            Err(err) => {
+                warn!("failed to establish noise link to {address} - {err}");
+                None
+            }
+        }
+    }
+
     async fn manage_connection(
         address: SocketAddr,
         receiver: mpsc::Receiver<FramedNymPacket>,
         connection_timeout: Duration,
         current_reconnection: &AtomicU32,
+        noise_config: Option<NoiseConfig>,
     ) {
         let connection_fut = TcpStream::connect(address);
 
-        let conn = match tokio::time::timeout(connection_timeout, connection_fut).await {
+        let stream = match tokio::time::timeout(connection_timeout, connection_fut).await {
             Ok(stream_res) => match stream_res {
                 Ok(stream) => {
                     debug!("Managed to establish connection to {}", address);
-                    // if we managed to connect, reset the reconnection count (whatever it might have been)
-                    current_reconnection.store(0, Ordering::Release);
-                    Framed::new(stream, NymCodec)
+                    stream
                 }
                 Err(err) => {
                     debug!(
@@ -119,6 +144,14 @@ impl Client {
             }
         };
 
+        let Some(conn) = Self::upgrade_connection(stream, address, noise_config.as_ref()).await
+        else {
+            current_reconnection.fetch_add(1, Ordering::SeqCst);
+            return;
+        };
+        // if we managed to connect, reset the reconnection count (whatever it might have been)
+        current_reconnection.store(0, Ordering::Release);
+
         // Take whatever the receiver channel produces and put it on the connection.
         // We could have as well used conn.send_all(receiver.map(Ok)), but considering we don't care
         // about neither receiver nor the connection, it doesn't matter which one gets consumed
@@ -172,8 +205,9 @@ impl Client {
         let reconnection_attempt = current_reconnection_attempt.load(Ordering::Acquire);
         let backoff = self.determine_backoff(reconnection_attempt);
 
-        // copy the value before moving into another task
+        // copy the values before moving into another task
         let initial_connection_timeout = self.config.initial_connection_timeout;
+        let noise_config = self.config.noise_config.clone();
 
         tokio::spawn(async move {
             // before executing the manager, wait for what was specified, if anything
@@ -187,6 +221,7 @@ impl Client {
                 receiver,
                 initial_connection_timeout,
                 &current_reconnection_attempt,
+                noise_config,
             )
             .await
         });
@@ -253,6 +288,11 @@ impl SendWithoutResponse for Client {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use nym_crypto::asymmetric::encryption;
+    use nym_noise::{NoisePeer, NoisePeerKeys};
+    use rand::rngs::OsRng;
+    use tokio::io::AsyncReadExt;
+    use tokio::net::TcpListener;
 
     fn dummy_client() -> Client {
         Client::new(Config {
@@ -261,6 +301,7 @@ mod tests {
             initial_connection_timeout: Duration::from_millis(1_500),
             maximum_connection_buffer_size: 128,
             use_legacy_version: false,
+            noise_config: None,
         })
     }
 
@@ -291,4 +332,41 @@ mod tests {
             client.config.maximum_reconnection_backoff
         );
     }
+
+    #[tokio::test]
+    async fn failed_handshake_with_known_node_does_not_fall_back_to_legacy_link() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let peers = NoisePeerKeys::new();
+        peers.insert(
+            "known-node".to_string(),
+            NoisePeer {
+                mix_address: address,
+                noise_key: *encryption::KeyPair::new(&mut OsRng).public_key(),
+                supports_noise: true,
+            },
+        );
+        // legacy links are allowed, but they must not be used for nodes with known keys
+        let noise_config = NoiseConfig::new(Arc::new(encryption::KeyPair::new(&mut OsRng)), peers)
+            .with_legacy_links(true);
+
+        // the remote accepts the connection and closes it as soon as the handshake begins
+        let remote = tokio::spawn(async move {
+            let (mut conn, _) = listener.accept().await.unwrap();
+            let mut prologue = [0u8; 2];
+            conn.read_exact(&mut prologue).await.unwrap();
+            drop(conn);
+
+            // and no other connection attempt is made
+            tokio::time::timeout(Duration::from_millis(500), listener.accept())
+                .await
+                .is_err()
+        });
+
+        let stream = TcpStream::connect(address).await.unwrap();
+        let conn = Client::upgrade_connection(stream, address, Some(&noise_config)).await;
+        assert!(conn.is_none());
+        assert!(remote.await.unwrap());
+    }
 }
@@ -5,6 +5,7 @@ use crate::client::{Client, Config, SendWithoutResponse};
 use futures::channel::mpsc;
 use futures::StreamExt;
 use log::*;
+use nym_noise::NoiseConfig;
 use nym_sphinx::forwarding::packet::MixPacket;
 use std::time::Duration;
 
@@ -26,6 +27,7 @@ impl PacketForwarder {
         initial_connection_timeout: Duration,
         maximum_connection_buffer_size: usize,
         use_legacy_version: bool,
+        noise_config: Option<NoiseConfig>,
         shutdown: nym_task::TaskClient,
     ) -> (PacketForwarder, MixForwardingSender) {
         let client_config = Config::new(
@@ -34,6 +36,7 @@ impl PacketForwarder {
             initial_connection_timeout,
             maximum_connection_buffer_size,
             use_legacy_version,
+            noise_config,
         );
 
         let (packet_sender, packet_receiver) = mpsc::unbounded();
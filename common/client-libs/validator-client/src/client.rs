@@ -6,14 +6,16 @@ use nym_api_requests::coconut::{
     BlindSignRequestBody, BlindedSignatureResponse, VerifyCredentialBody, VerifyCredentialResponse,
 };
 use nym_api_requests::models::{
-    GatewayCoreStatusResponse, MixnodeCoreStatusResponse, MixnodeStatusResponse,
-    RewardEstimationResponse, StakeSaturationResponse,
+    GatewayCoreStatusResponse, LatencyMatrixResponse, MixnodeCoreStatusResponse,
+    MixnodeStatusResponse, RewardEstimationResponse, StakeSaturationResponse,
+    VerlocHistoryResponse,
 };
 use nym_coconut_dkg_common::types::NodeIndex;
 use nym_coconut_interface::VerificationKey;
 pub use nym_mixnet_contract_common::{
     mixnode::MixNodeDetails, GatewayBond, IdentityKey, IdentityKeyRef, MixId,
 };
+use nym_service_provider_directory_common::response::ServiceWithLiveness;
 use url::Url;
 
 #[cfg(feature = "nyxd-client")]
@@ -31,6 +33,7 @@ use nym_coconut_interface::Base58;
 #[cfg(feature = "nyxd-client")]
 use nym_mixnet_contract_common::{
     families::{Family, FamilyHead},
+    jailing::JailedMixnode,
     mixnode::MixNodeBond,
     pending_events::{PendingEpochEvent, PendingIntervalEvent},
     Delegation, RewardedSetNodeStatus, UnbondedMixnode,
@@ -253,6 +256,30 @@ impl<C> Client<C> {
         Ok(members)
     }
 
+    pub async fn get_all_jailed_mixnodes(&self) -> Result<Vec<JailedMixnode>, ValidatorClientError>
+    where
+        C: CosmWasmClient + Sync + Send,
+    {
+        let mut nodes = Vec::new();
+        let mut start_after = None;
+
+        loop {
+            let paged_response = self
+                .nyxd
+                .get_jailed_mixnodes_paged(start_after.take(), None)
+                .await?;
+            nodes.extend(paged_response.nodes);
+
+            if let Some(start_after_res) = paged_response.start_next_after {
+                start_after = Some(start_after_res)
+            } else {
+                break;
+            }
+        }
+
+        Ok(nodes)
+    }
+
     // basically handles paging for us
     pub async fn get_all_nyxd_rewarded_set_mixnodes(
         &self,
@@ -713,6 +740,12 @@ impl NymApiClient {
         Ok(self.nym_api_client.get_gateways().await?)
     }
 
+    pub async fn get_cached_service_providers_detailed(
+        &self,
+    ) -> Result<Vec<ServiceWithLiveness>, ValidatorClientError> {
+        Ok(self.nym_api_client.get_service_providers_detailed().await?)
+    }
+
     pub async fn get_gateway_core_status_count(
         &self,
         identity: IdentityKeyRef<'_>,
@@ -735,6 +768,24 @@ impl NymApiClient {
             .await?)
     }
 
+    pub async fn get_latency_matrix(
+        &self,
+        since: Option<i64>,
+    ) -> Result<LatencyMatrixResponse, ValidatorClientError> {
+        Ok(self.nym_api_client.get_latency_matrix(since).await?)
+    }
+
+    pub async fn get_mixnode_verloc_history(
+        &self,
+        identity: IdentityKeyRef<'_>,
+        since: Option<i64>,
+    ) -> Result<VerlocHistoryResponse, ValidatorClientError> {
+        Ok(self
+            .nym_api_client
+            .get_mixnode_verloc_history(identity, since)
+            .await?)
+    }
+
     pub async fn get_mixnode_status(
         &self,
         mix_id: MixId,
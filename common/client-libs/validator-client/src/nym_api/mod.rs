@@ -8,15 +8,15 @@ use nym_api_requests::coconut::{
 };
 use nym_api_requests::models::{
     ComputeRewardEstParam, GatewayCoreStatusResponse, GatewayStatusReportResponse,
-    GatewayUptimeHistoryResponse, InclusionProbabilityResponse, MixNodeBondAnnotated,
-    MixnodeCoreStatusResponse, MixnodeStatusReportResponse, MixnodeStatusResponse,
-    MixnodeUptimeHistoryResponse, RequestError, RewardEstimationResponse, StakeSaturationResponse,
-    UptimeResponse,
+    GatewayUptimeHistoryResponse, InclusionProbabilityResponse, LatencyMatrixResponse,
+    MixNodeBondAnnotated, MixnodeCoreStatusResponse, MixnodeStatusReportResponse,
+    MixnodeStatusResponse, MixnodeUptimeHistoryResponse, RequestError, RewardEstimationResponse,
+    StakeSaturationResponse, UptimeResponse, VerlocHistoryResponse,
 };
 use nym_mixnet_contract_common::mixnode::MixNodeDetails;
 use nym_mixnet_contract_common::{GatewayBond, IdentityKeyRef, MixId};
 use nym_name_service_common::response::NamesListResponse;
-use nym_service_provider_directory_common::response::ServicesListResponse;
+use nym_service_provider_directory_common::response::{ServiceWithLiveness, ServicesListResponse};
 use reqwest::{Response, StatusCode};
 use serde::{Deserialize, Serialize};
 use url::Url;
@@ -347,6 +347,49 @@ impl Client {
         }
     }
 
+    pub async fn get_latency_matrix(
+        &self,
+        since: Option<i64>,
+    ) -> Result<LatencyMatrixResponse, NymAPIError> {
+        let params = since
+            .map(|since| vec![(SINCE_ARG, since.to_string())])
+            .unwrap_or_default();
+
+        self.query_nym_api(
+            &[
+                routes::API_VERSION,
+                routes::STATUS_ROUTES,
+                routes::VERLOC,
+                routes::LATENCY_MATRIX,
+            ],
+            &params,
+        )
+        .await
+    }
+
+    pub async fn get_mixnode_verloc_history(
+        &self,
+        identity: IdentityKeyRef<'_>,
+        since: Option<i64>,
+    ) -> Result<VerlocHistoryResponse, NymAPIError> {
+        let params = since
+            .map(|since| vec![(SINCE_ARG, since.to_string())])
+            .unwrap_or_default();
+
+        self.query_nym_api(
+            &[
+                routes::API_VERSION,
+                routes::STATUS_ROUTES,
+                routes::VERLOC,
+                routes::MIXNODE,
+                identity,
+                routes::HISTORY,
+            ],
+            &params,
+        )
+        .await
+    }
+
     pub async fn get_mixnode_status(
         &self,
         mix_id: MixId,
@@ -491,6 +534,21 @@ impl Client {
             .await
     }
 
+    pub async fn get_service_providers_detailed(
+        &self,
+    ) -> Result<Vec<ServiceWithLiveness>, NymAPIError> {
+        log::trace!("Getting service providers with their liveness");
+        self.query_nym_api(
+            &[
+                routes::API_VERSION,
+                routes::SERVICE_PROVIDERS,
+                routes::DETAILED,
+            ],
+            NO_PARAMS,
+        )
+        .await
+    }
+
     //pub async fn get_registered_names(&self) -> Result<Vec<NameEntry>, NymAPIError> {
     pub async fn get_registered_names(&self) -> Result<NamesListResponse, NymAPIError> {
         log::trace!("Getting registered names");
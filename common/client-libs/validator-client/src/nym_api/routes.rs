@@ -22,6 +22,8 @@ pub const MIXNODE: &str = "mixnode";
 pub const GATEWAY: &str = "gateway";
 
 pub const CORE_STATUS_COUNT: &str = "core-status-count";
+pub const VERLOC: &str = "verloc";
+pub const LATENCY_MATRIX: &str = "latency-matrix";
 pub const SINCE_ARG: &str = "since";
 
 pub const STATUS: &str = "status";
@@ -34,6 +34,7 @@ pub use cosmrs::rpc::Paging;
 pub use cosmrs::tendermint::abci::responses::{DeliverTx, Event};
 pub use cosmrs::tendermint::abci::tag::Tag;
 pub use cosmrs::tendermint::block::Height;
+pub use cosmrs::tendermint::chain;
 pub use cosmrs::tendermint::hash;
 pub use cosmrs::tendermint::validator::Info as TendermintValidatorInfo;
 pub use cosmrs::tendermint::Time as TendermintTime;
@@ -450,6 +451,23 @@ impl<C> NyxdClient<C> {
         Ok(None)
     }
 
+    pub async fn get_account_sequence(
+        &self,
+        address: &AccountId,
+    ) -> Result<SequenceResponse, NyxdError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        self.client.get_sequence(address).await
+    }
+
+    pub async fn get_chain_id(&self) -> Result<chain::Id, NyxdError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        self.client.get_chain_id().await
+    }
+
     pub async fn get_current_block_timestamp(&self) -> Result<TendermintTime, NyxdError>
     where
         C: CosmWasmClient + Sync,
@@ -539,6 +557,15 @@ impl<C> NyxdClient<C> {
         self.client.search_tx(query).await
     }
 
+    /// Broadcast a transaction that has already been signed, for example offline,
+    /// and wait for its inclusion in a block.
+    pub async fn broadcast_signed_tx(&self, tx_bytes: Vec<u8>) -> Result<TxResponse, NyxdError>
+    where
+        C: CosmWasmClient + Sync,
+    {
+        self.client.broadcast_tx(tx_bytes.into()).await
+    }
+
     pub async fn get_total_supply(&self) -> Result<Vec<Coin>, NyxdError>
     where
         C: CosmWasmClient + Sync,
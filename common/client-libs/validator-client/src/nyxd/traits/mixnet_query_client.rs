@@ -9,13 +9,18 @@ use cosmrs::AccountId;
 use nym_contracts_common::signing::Nonce;
 use nym_mixnet_contract_common::delegation::{MixNodeDelegationResponse, OwnerProxySubKey};
 use nym_mixnet_contract_common::families::Family;
+use nym_mixnet_contract_common::jailing::{
+    MixnodeJailStatusResponse, OffenceNumber, PagedJailedMixnodesResponse,
+    PagedMisbehaviourReportsResponse,
+};
 use nym_mixnet_contract_common::mixnode::{
     MixNodeDetails, MixnodeRewardingDetailsResponse, PagedMixnodesDetailsResponse,
     PagedUnbondedMixnodesResponse, StakeSaturationResponse, UnbondedMixnodeResponse,
 };
 use nym_mixnet_contract_common::reward_params::{Performance, RewardingParams};
 use nym_mixnet_contract_common::rewarding::{
-    EstimatedCurrentEpochRewardResponse, PendingRewardResponse,
+    EstimatedCurrentEpochRewardResponse, PendingGatewayRewardResponse, PendingRewardResponse,
+    RewardCompoundingResponse,
 };
 use nym_mixnet_contract_common::{
     delegation, ContractBuildInformation, ContractState, ContractStateParams,
@@ -335,6 +340,46 @@ pub trait MixnetQueryClient {
         .await
     }
 
+    async fn get_pending_gateway_operator_reward(
+        &self,
+        operator: &AccountId,
+    ) -> Result<PendingGatewayRewardResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetPendingGatewayOperatorReward {
+            address: operator.to_string(),
+        })
+        .await
+    }
+
+    async fn get_pending_gateway_reward(
+        &self,
+        identity: IdentityKey,
+    ) -> Result<PendingGatewayRewardResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetPendingGatewayReward { identity })
+            .await
+    }
+
+    async fn get_operator_reward_compounding(
+        &self,
+        mix_id: MixId,
+    ) -> Result<RewardCompoundingResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetOperatorRewardCompounding { mix_id })
+            .await
+    }
+
+    async fn get_delegator_reward_compounding(
+        &self,
+        delegator: &AccountId,
+        mix_id: MixId,
+        proxy: Option<String>,
+    ) -> Result<RewardCompoundingResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetDelegatorRewardCompounding {
+            address: delegator.to_string(),
+            mix_id,
+            proxy,
+        })
+        .await
+    }
+
     // given the provided performance, estimate the reward at the end of the current epoch
     async fn get_estimated_current_epoch_operator_reward(
         &self,
@@ -365,6 +410,39 @@ pub trait MixnetQueryClient {
         .await
     }
 
+    // jailing-related
+
+    async fn get_mixnode_jail_status(
+        &self,
+        mix_id: MixId,
+    ) -> Result<MixnodeJailStatusResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetMixnodeJailStatus { mix_id })
+            .await
+    }
+
+    async fn get_jailed_mixnodes_paged(
+        &self,
+        start_after: Option<MixId>,
+        limit: Option<u32>,
+    ) -> Result<PagedJailedMixnodesResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetJailedMixnodes { limit, start_after })
+            .await
+    }
+
+    async fn get_misbehaviour_reports_paged(
+        &self,
+        mix_id: MixId,
+        start_after: Option<OffenceNumber>,
+        limit: Option<u32>,
+    ) -> Result<PagedMisbehaviourReportsResponse, NyxdError> {
+        self.query_mixnet_contract(MixnetQueryMsg::GetMisbehaviourReports {
+            mix_id,
+            limit,
+            start_after,
+        })
+        .await
+    }
+
     // interval-related
 
     async fn get_pending_epoch_events_paged(
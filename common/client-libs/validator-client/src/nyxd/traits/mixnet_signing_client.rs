@@ -11,10 +11,12 @@ use cosmrs::AccountId;
 use nym_contracts_common::signing::MessageSignature;
 use nym_mixnet_contract_common::families::FamilyHead;
 use nym_mixnet_contract_common::gateway::GatewayConfigUpdate;
+use nym_mixnet_contract_common::jailing::MisbehaviourEvidence;
 use nym_mixnet_contract_common::mixnode::{MixNodeConfigUpdate, MixNodeCostParams};
 use nym_mixnet_contract_common::reward_params::{IntervalRewardingParamsUpdate, Performance};
 use nym_mixnet_contract_common::{
-    ContractStateParams, ExecuteMsg as MixnetExecuteMsg, Gateway, LayerAssignment, MixId, MixNode,
+    ContractStateParams, ExecuteMsg as MixnetExecuteMsg, Gateway, IdentityKey, LayerAssignment,
+    MixId, MixNode, Percent,
 };
 
 #[async_trait]
@@ -443,6 +445,46 @@ pub trait MixnetSigningClient {
         .await
     }
 
+    async fn transfer_mixnode_ownership(
+        &self,
+        to_address: AccountId,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::TransferMixnodeOwnership {
+                to_address: to_address.to_string(),
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn accept_mixnode_ownership(
+        &self,
+        mix_id: MixId,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::AcceptMixnodeOwnership { mix_id },
+            vec![],
+        )
+        .await
+    }
+
+    async fn cancel_mixnode_ownership_transfer(
+        &self,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::CancelMixnodeOwnershipTransfer {},
+            vec![],
+        )
+        .await
+    }
+
     // gateway-related:
 
     async fn bond_gateway(
@@ -533,6 +575,46 @@ pub trait MixnetSigningClient {
         .await
     }
 
+    async fn transfer_gateway_ownership(
+        &self,
+        to_address: AccountId,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::TransferGatewayOwnership {
+                to_address: to_address.to_string(),
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn accept_gateway_ownership(
+        &self,
+        identity: IdentityKey,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::AcceptGatewayOwnership { identity },
+            vec![],
+        )
+        .await
+    }
+
+    async fn cancel_gateway_ownership_transfer(
+        &self,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::CancelGatewayOwnershipTransfer {},
+            vec![],
+        )
+        .await
+    }
+
     // delegation-related:
 
     async fn delegate_to_mixnode(
@@ -597,6 +679,46 @@ pub trait MixnetSigningClient {
         .await
     }
 
+    async fn redelegate_to_mixnode(
+        &self,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::RedelegateToMixnode {
+                from_mix_id,
+                to_mix_id,
+                amount: amount.into(),
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn redelegate_to_mixnode_on_behalf(
+        &self,
+        delegate: AccountId,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::RedelegateToMixnodeOnBehalf {
+                from_mix_id,
+                to_mix_id,
+                amount: amount.into(),
+                delegate: delegate.to_string(),
+            },
+            vec![],
+        )
+        .await
+    }
+
     // reward-related
 
     async fn reward_mixnode(
@@ -616,6 +738,27 @@ pub trait MixnetSigningClient {
         .await
     }
 
+    async fn jail_mixnode(
+        &self,
+        mix_id: MixId,
+        jail_epochs: u32,
+        slash_fraction: Option<Percent>,
+        evidence: MisbehaviourEvidence,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::JailMixnode {
+                mix_id,
+                jail_epochs,
+                slash_fraction,
+                evidence,
+            },
+            vec![],
+        )
+        .await
+    }
+
     async fn withdraw_operator_reward(&self, fee: Option<Fee>) -> Result<ExecuteResult, NyxdError> {
         self.execute_mixnet_contract(fee, MixnetExecuteMsg::WithdrawOperatorReward {}, vec![])
             .await
@@ -636,6 +779,50 @@ pub trait MixnetSigningClient {
         .await
     }
 
+    async fn reward_gateway(
+        &self,
+        identity: IdentityKey,
+        performance: Performance,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::RewardGateway {
+                identity,
+                performance,
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn withdraw_gateway_operator_reward(
+        &self,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::WithdrawGatewayOperatorReward {},
+            vec![],
+        )
+        .await
+    }
+
+    async fn withdraw_gateway_operator_reward_on_behalf(
+        &self,
+        owner: AccountId,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::WithdrawGatewayOperatorRewardOnBehalf {
+                owner: owner.to_string(),
+            },
+            vec![],
+        )
+        .await
+    }
+
     async fn withdraw_delegator_reward(
         &self,
         mix_id: MixId,
@@ -665,6 +852,69 @@ pub trait MixnetSigningClient {
         )
         .await
     }
+
+    async fn set_operator_reward_compounding(
+        &self,
+        enabled: bool,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::SetOperatorRewardCompounding { enabled },
+            vec![],
+        )
+        .await
+    }
+
+    async fn set_operator_reward_compounding_on_behalf(
+        &self,
+        owner: AccountId,
+        enabled: bool,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::SetOperatorRewardCompoundingOnBehalf {
+                enabled,
+                owner: owner.to_string(),
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn set_delegator_reward_compounding(
+        &self,
+        mix_id: MixId,
+        enabled: bool,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::SetDelegatorRewardCompounding { mix_id, enabled },
+            vec![],
+        )
+        .await
+    }
+
+    async fn set_delegator_reward_compounding_on_behalf(
+        &self,
+        owner: AccountId,
+        mix_id: MixId,
+        enabled: bool,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_mixnet_contract(
+            fee,
+            MixnetExecuteMsg::SetDelegatorRewardCompoundingOnBehalf {
+                mix_id,
+                enabled,
+                owner: owner.to_string(),
+            },
+            vec![],
+        )
+        .await
+    }
 }
 
 #[async_trait]
@@ -8,6 +8,7 @@ use cw3::{ProposalListResponse, ProposalResponse};
 use nym_multisig_contract_common::msg::QueryMsg;
 
 use async_trait::async_trait;
+use cosmrs::AccountId;
 
 #[async_trait]
 pub trait MultisigQueryClient {
@@ -38,25 +39,84 @@ pub trait MultisigQueryClient {
 
         Ok(proposals)
     }
+
+    /// Query the proposal from the provided multisig contract, as opposed to the one
+    /// used by the coconut contracts.
+    async fn get_multisig_proposal(
+        &self,
+        multisig_address: &AccountId,
+        proposal_id: u64,
+    ) -> Result<ProposalResponse, NyxdError>;
+
+    async fn list_multisig_proposals(
+        &self,
+        multisig_address: &AccountId,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> Result<ProposalListResponse, NyxdError>;
+
+    async fn get_all_multisig_proposals(
+        &self,
+        multisig_address: &AccountId,
+    ) -> Result<Vec<ProposalResponse>, NyxdError> {
+        let mut proposals = Vec::new();
+        let mut start_after = None;
+
+        loop {
+            let mut paged_response = self
+                .list_multisig_proposals(multisig_address, start_after.take(), None)
+                .await?;
+
+            let last_id = paged_response.proposals.last().map(|prop| prop.id);
+            proposals.append(&mut paged_response.proposals);
+
+            if let Some(start_after_res) = last_id {
+                start_after = Some(start_after_res)
+            } else {
+                break;
+            }
+        }
+
+        Ok(proposals)
+    }
 }
 
 #[async_trait]
 impl<C: CosmWasmClient + Sync + Send> MultisigQueryClient for NyxdClient<C> {
     async fn get_proposal(&self, proposal_id: u64) -> Result<ProposalResponse, NyxdError> {
+        self.get_multisig_proposal(self.multisig_contract_address(), proposal_id)
+            .await
+    }
+
+    async fn list_proposals(
+        &self,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> Result<ProposalListResponse, NyxdError> {
+        self.list_multisig_proposals(self.multisig_contract_address(), start_after, limit)
+            .await
+    }
+
+    async fn get_multisig_proposal(
+        &self,
+        multisig_address: &AccountId,
+        proposal_id: u64,
+    ) -> Result<ProposalResponse, NyxdError> {
         let request = QueryMsg::Proposal { proposal_id };
         self.client
-            .query_contract_smart(self.multisig_contract_address(), &request)
+            .query_contract_smart(multisig_address, &request)
             .await
     }
 
-    async fn list_proposals(
+    async fn list_multisig_proposals(
         &self,
+        multisig_address: &AccountId,
         start_after: Option<u64>,
         limit: Option<u32>,
     ) -> Result<ProposalListResponse, NyxdError> {
         let request = QueryMsg::ListProposals { start_after, limit };
         self.client
-            .query_contract_smart(self.multisig_contract_address(), &request)
+            .query_contract_smart(multisig_address, &request)
             .await
     }
 }
@@ -8,10 +8,14 @@ use crate::nyxd::{Fee, NyxdClient};
 
 use cw3::Vote;
 use nym_coconut_bandwidth_contract_common::msg::ExecuteMsg as CoconutBandwidthExecuteMsg;
+use nym_mixnet_contract_common::ExecuteMsg as MixnetExecuteMsg;
 use nym_multisig_contract_common::msg::ExecuteMsg;
+use nym_vesting_contract_common::ExecuteMsg as VestingExecuteMsg;
 
 use async_trait::async_trait;
+use cosmrs::AccountId;
 use cosmwasm_std::{to_binary, Coin, CosmosMsg, WasmMsg};
+use serde::Serialize;
 
 #[async_trait]
 pub trait MultisigSigningClient {
@@ -35,6 +39,56 @@ pub trait MultisigSigningClient {
         proposal_id: u64,
         fee: Option<Fee>,
     ) -> Result<ExecuteResult, NyxdError>;
+
+    /// Propose execution of an arbitrary message on the specified contract
+    /// by the provided multisig contract, for example one controlling operator's mixnet infrastructure.
+    #[allow(clippy::too_many_arguments)]
+    async fn propose_contract_execution<M>(
+        &self,
+        multisig_address: &AccountId,
+        title: String,
+        description: String,
+        contract_address: &AccountId,
+        msg: &M,
+        funds: Vec<crate::nyxd::Coin>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError>
+    where
+        M: ?Sized + Serialize + Sync;
+
+    async fn propose_mixnet_execution(
+        &self,
+        multisig_address: &AccountId,
+        title: String,
+        description: String,
+        msg: &MixnetExecuteMsg,
+        funds: Vec<crate::nyxd::Coin>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError>;
+
+    async fn propose_vesting_execution(
+        &self,
+        multisig_address: &AccountId,
+        title: String,
+        description: String,
+        msg: &VestingExecuteMsg,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError>;
+
+    async fn vote_multisig_proposal(
+        &self,
+        multisig_address: &AccountId,
+        proposal_id: u64,
+        yes: bool,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError>;
+
+    async fn execute_multisig_proposal(
+        &self,
+        multisig_address: &AccountId,
+        proposal_id: u64,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError>;
 }
 
 #[async_trait]
@@ -81,6 +135,104 @@ impl<C: SigningCosmWasmClient + Sync + Send> MultisigSigningClient for NyxdClien
         proposal_id: u64,
         vote_yes: bool,
         fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.vote_multisig_proposal(self.multisig_contract_address(), proposal_id, vote_yes, fee)
+            .await
+    }
+
+    async fn execute_proposal(
+        &self,
+        proposal_id: u64,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_multisig_proposal(self.multisig_contract_address(), proposal_id, fee)
+            .await
+    }
+
+    async fn propose_contract_execution<M>(
+        &self,
+        multisig_address: &AccountId,
+        title: String,
+        description: String,
+        contract_address: &AccountId,
+        msg: &M,
+        funds: Vec<crate::nyxd::Coin>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError>
+    where
+        M: ?Sized + Serialize + Sync,
+    {
+        let fee = fee.unwrap_or(Fee::Auto(Some(self.simulated_gas_multiplier)));
+        let execute_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: contract_address.to_string(),
+            msg: to_binary(msg)?,
+            funds: funds.into_iter().map(Into::into).collect(),
+        });
+        let req = ExecuteMsg::Propose {
+            title,
+            description,
+            msgs: vec![execute_msg],
+            latest: None,
+        };
+        self.client
+            .execute(
+                self.address(),
+                multisig_address,
+                &req,
+                fee,
+                "Multisig::Propose::Execute",
+                vec![],
+            )
+            .await
+    }
+
+    async fn propose_mixnet_execution(
+        &self,
+        multisig_address: &AccountId,
+        title: String,
+        description: String,
+        msg: &MixnetExecuteMsg,
+        funds: Vec<crate::nyxd::Coin>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.propose_contract_execution(
+            multisig_address,
+            title,
+            description,
+            self.mixnet_contract_address(),
+            msg,
+            funds,
+            fee,
+        )
+        .await
+    }
+
+    async fn propose_vesting_execution(
+        &self,
+        multisig_address: &AccountId,
+        title: String,
+        description: String,
+        msg: &VestingExecuteMsg,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.propose_contract_execution(
+            multisig_address,
+            title,
+            description,
+            self.vesting_contract_address(),
+            msg,
+            vec![],
+            fee,
+        )
+        .await
+    }
+
+    async fn vote_multisig_proposal(
+        &self,
+        multisig_address: &AccountId,
+        proposal_id: u64,
+        vote_yes: bool,
+        fee: Option<Fee>,
     ) -> Result<ExecuteResult, NyxdError> {
         let fee = fee.unwrap_or(Fee::Auto(Some(self.simulated_gas_multiplier)));
         let vote = if vote_yes { Vote::Yes } else { Vote::No };
@@ -88,7 +240,7 @@ impl<C: SigningCosmWasmClient + Sync + Send> MultisigSigningClient for NyxdClien
         self.client
             .execute(
                 self.address(),
-                self.multisig_contract_address(),
+                multisig_address,
                 &req,
                 fee,
                 "Multisig::Vote",
@@ -97,8 +249,9 @@ impl<C: SigningCosmWasmClient + Sync + Send> MultisigSigningClient for NyxdClien
             .await
     }
 
-    async fn execute_proposal(
+    async fn execute_multisig_proposal(
         &self,
+        multisig_address: &AccountId,
         proposal_id: u64,
         fee: Option<Fee>,
     ) -> Result<ExecuteResult, NyxdError> {
@@ -107,7 +260,7 @@ impl<C: SigningCosmWasmClient + Sync + Send> MultisigSigningClient for NyxdClien
         self.client
             .execute(
                 self.address(),
-                self.multisig_contract_address(),
+                multisig_address,
                 &req,
                 fee,
                 "Multisig::Execute",
@@ -35,6 +35,20 @@ pub trait NameServiceQueryClient {
             .await
     }
 
+    async fn get_names_expiring_before_paged(
+        &self,
+        timestamp: u64,
+        start_after: Option<NameId>,
+        limit: Option<u32>,
+    ) -> Result<PagedNamesListResponse, NyxdError> {
+        self.query_name_service_contract(NameQueryMsg::ExpiringBefore {
+            timestamp,
+            limit,
+            start_after,
+        })
+        .await
+    }
+
     async fn get_names_by_owner(&self, owner: AccountId) -> Result<NamesListResponse, NyxdError> {
         self.query_name_service_contract(NameQueryMsg::ByOwner {
             owner: owner.to_string(),
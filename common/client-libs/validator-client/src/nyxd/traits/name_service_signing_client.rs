@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use async_trait::async_trait;
+use cosmrs::AccountId;
 use nym_name_service_common::{msg::ExecuteMsg as NameExecuteMsg, Address, NameId, NymName};
 
 use crate::nyxd::{
@@ -33,6 +34,32 @@ pub trait NameServiceSigningClient {
         .await
     }
 
+    async fn renew_name(
+        &self,
+        name_id: NameId,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_name_service_contract(fee, NameExecuteMsg::Renew { name_id }, vec![])
+            .await
+    }
+
+    async fn transfer_name(
+        &self,
+        name_id: NameId,
+        to_address: AccountId,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_name_service_contract(
+            fee,
+            NameExecuteMsg::Transfer {
+                name_id,
+                to_address: to_address.to_string(),
+            },
+            vec![],
+        )
+        .await
+    }
+
     async fn delete_name_by_id(
         &self,
         name_id: NameId,
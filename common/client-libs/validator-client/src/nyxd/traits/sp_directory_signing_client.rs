@@ -4,7 +4,7 @@
 use async_trait::async_trait;
 use nym_contracts_common::signing::MessageSignature;
 use nym_service_provider_directory_common::{
-    msg::ExecuteMsg as SpExecuteMsg, NymAddress, ServiceDetails, ServiceId,
+    msg::ExecuteMsg as SpExecuteMsg, NymAddress, ServiceDetails, ServiceId, ServiceMetadata,
 };
 
 use crate::nyxd::{
@@ -39,6 +39,40 @@ pub trait SpDirectorySigningClient {
         .await
     }
 
+    async fn send_service_provider_heartbeat(
+        &self,
+        service_id: ServiceId,
+        signature: MessageSignature,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_service_provider_directory_contract(
+            fee,
+            SpExecuteMsg::Heartbeat {
+                service_id,
+                signature,
+            },
+            vec![],
+        )
+        .await
+    }
+
+    async fn update_service_provider_metadata(
+        &self,
+        service_id: ServiceId,
+        metadata: ServiceMetadata,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_service_provider_directory_contract(
+            fee,
+            SpExecuteMsg::UpdateMetadata {
+                service_id,
+                metadata,
+            },
+            vec![],
+        )
+        .await
+    }
+
     async fn delete_service_provider_by_id(
         &self,
         service_id: ServiceId,
@@ -144,6 +144,15 @@ pub trait VestingSigningClient {
         fee: Option<Fee>,
     ) -> Result<ExecuteResult, NyxdError>;
 
+    async fn vesting_redelegate_to_mixnode(
+        &self,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        on_behalf_of: Option<String>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError>;
+
     async fn create_periodic_vesting_account(
         &self,
         owner_address: &str,
@@ -162,6 +171,18 @@ pub trait VestingSigningClient {
             .await
     }
 
+    async fn vesting_withdraw_gateway_operator_reward(
+        &self,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_vesting_contract(
+            fee,
+            VestingExecuteMsg::ClaimGatewayOperatorReward {},
+            Vec::new(),
+        )
+        .await
+    }
+
     async fn vesting_withdraw_delegator_reward(
         &self,
         mix_id: MixId,
@@ -175,6 +196,33 @@ pub trait VestingSigningClient {
         .await
     }
 
+    async fn vesting_set_operator_reward_compounding(
+        &self,
+        enabled: bool,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_vesting_contract(
+            fee,
+            VestingExecuteMsg::SetOperatorRewardCompounding { enabled },
+            Vec::new(),
+        )
+        .await
+    }
+
+    async fn vesting_set_delegator_reward_compounding(
+        &self,
+        mix_id: MixId,
+        enabled: bool,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_vesting_contract(
+            fee,
+            VestingExecuteMsg::SetDelegatorRewardCompounding { mix_id, enabled },
+            Vec::new(),
+        )
+        .await
+    }
+
     async fn update_locked_pledge_cap(
         &self,
         address: AccountId,
@@ -523,6 +571,27 @@ impl<C: SigningCosmWasmClient + Sync + Send> VestingSigningClient for NyxdClient
         .await
     }
 
+    async fn vesting_redelegate_to_mixnode(
+        &self,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        on_behalf_of: Option<String>,
+        fee: Option<Fee>,
+    ) -> Result<ExecuteResult, NyxdError> {
+        self.execute_vesting_contract(
+            fee,
+            VestingExecuteMsg::RedelegateToMixnode {
+                from_mix_id,
+                to_mix_id,
+                amount: amount.into(),
+                on_behalf_of,
+            },
+            vec![],
+        )
+        .await
+    }
+
     async fn create_periodic_vesting_account(
         &self,
         owner_address: &str,
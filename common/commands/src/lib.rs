@@ -3,5 +3,6 @@
 
 pub mod coconut;
 pub mod context;
+pub mod node;
 pub mod utils;
 pub mod validator;
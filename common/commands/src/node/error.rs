@@ -0,0 +1,34 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use nym_client_core::error::ClientCoreError;
+use nym_gateway_client::error::GatewayClientError;
+use nym_node_tester_utils::error::NetworkTestingError;
+use nym_topology::mix::MixnodeConversionError;
+use nym_validator_client::ValidatorClientError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum NodeTestError {
+    #[error("failed to retrieve the network topology - {0}")]
+    TopologyRetrievalFailure(#[from] ValidatorClientError),
+
+    #[error("'{node}' is neither a mix id nor an identity of any bonded mixnode or gateway")]
+    NodeNotFound { node: String },
+
+    #[error("the bond of the mixnode {mix_id} is malformed - {source}")]
+    MalformedMixnode {
+        mix_id: u32,
+        #[source]
+        source: MixnodeConversionError,
+    },
+
+    #[error("failed to register with the gateway - {0}")]
+    GatewayRegistrationFailure(#[from] ClientCoreError),
+
+    #[error(transparent)]
+    GatewayClientError(#[from] GatewayClientError),
+
+    #[error("failed to prepare the test packets - {0}")]
+    TestPacketsPreparationFailure(#[from] NetworkTestingError),
+}
@@ -0,0 +1,20 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::{Args, Subcommand};
+
+pub mod error;
+pub mod test;
+
+#[derive(Debug, Args)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_required = true)]
+pub struct Node {
+    #[clap(subcommand)]
+    pub command: Option<NodeCommands>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum NodeCommands {
+    /// Send test packets through the specified mixnode or gateway and report packet loss and latency
+    Test(crate::node::test::Args),
+}
@@ -0,0 +1,576 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::context::QueryClientWithNyxd;
+use crate::node::error::NodeTestError;
+use clap::Parser;
+use futures::channel::mpsc;
+use futures::StreamExt;
+use log::{info, warn};
+use nym_bin_common::output_format::OutputFormat;
+use nym_client_core::client::base_client::storage::{Ephemeral, MixnetClientStorage};
+use nym_client_core::init::{setup_gateway_from, GatewaySetup};
+use nym_credential_storage::ephemeral_storage::EphemeralStorage;
+use nym_gateway_client::GatewayClient;
+use nym_node_tester_utils::node::TestableNode;
+use nym_node_tester_utils::processor::Received;
+use nym_node_tester_utils::receiver::SimpleMessageReceiver;
+use nym_node_tester_utils::{Empty, NodeTester};
+use nym_sphinx::chunking::fragment::FragmentIdentifier;
+use nym_sphinx::params::PacketSize;
+use nym_sphinx::preparer::PreparedFragment;
+use nym_task::TaskManager;
+use nym_topology::{gateway, mix, NymTopology};
+use rand_07::rngs::OsRng;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+// the same delays are used by the wasm node tester, so that the results would be comparable
+const AVERAGE_PACKET_DELAY: Duration = Duration::from_millis(5);
+const AVERAGE_ACK_DELAY: Duration = Duration::from_millis(5);
+
+const GATEWAY_RESPONSE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Mix id or identity key of the mixnode, or identity key of the gateway, to test
+    pub node: String,
+
+    /// Number of test packets to send through the node
+    #[clap(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..))]
+    pub packets: u32,
+
+    /// Number of seconds to wait for the test packets and their acknowledgements to come back
+    #[clap(long, default_value_t = 10)]
+    pub timeout_secs: u64,
+
+    /// Identity key of the gateway to send the packets through when testing a mixnode.
+    /// If not provided, a random gateway is used.
+    #[clap(long)]
+    pub gateway: Option<String>,
+
+    #[clap(short, long, default_value_t = OutputFormat::default())]
+    output: OutputFormat,
+}
+
+enum TestTarget {
+    Mixnode(mix::Node),
+    Gateway(gateway::Node),
+}
+
+impl TestTarget {
+    async fn resolve(
+        node: &str,
+        topology: &NymTopology,
+        client: &QueryClientWithNyxd,
+    ) -> Result<Self, NodeTestError> {
+        let mix_id = node.parse::<u32>().ok();
+
+        let active_mix = match mix_id {
+            Some(mix_id) => topology.find_mix(mix_id),
+            None => topology.find_mix_by_identity(node),
+        };
+        if let Some(mix) = active_mix {
+            return Ok(TestTarget::Mixnode(mix.clone()));
+        }
+        if let Some(gateway) = topology.find_gateway(node) {
+            return Ok(TestTarget::Gateway(gateway.clone()));
+        }
+
+        // the mixnode might still be bonded, just not in the active set
+        let bond = client
+            .get_cached_mixnodes()
+            .await?
+            .into_iter()
+            .map(|details| details.bond_information)
+            .find(|bond| Some(bond.mix_id) == mix_id || bond.mix_node.identity_key == node)
+            .ok_or_else(|| NodeTestError::NodeNotFound {
+                node: node.to_string(),
+            })?;
+
+        mix::Node::try_from(&bond)
+            .map(TestTarget::Mixnode)
+            .map_err(|source| NodeTestError::MalformedMixnode {
+                mix_id: bond.mix_id,
+                source,
+            })
+    }
+
+    fn testable_node(&self) -> TestableNode {
+        match self {
+            TestTarget::Mixnode(node) => node.into(),
+            TestTarget::Gateway(node) => node.into(),
+        }
+    }
+
+    fn gateway_setup(&self, requested_gateway: Option<String>) -> GatewaySetup {
+        match self {
+            TestTarget::Mixnode(_) => match requested_gateway {
+                Some(gateway_identity) => GatewaySetup::Specified { gateway_identity },
+                None => GatewaySetup::New { by_latency: false },
+            },
+            // the test packets of a gateway have to be delivered to a client connected to it
+            TestTarget::Gateway(node) => {
+                let gateway_identity = node.identity_key.to_base58_string();
+                if matches!(requested_gateway, Some(requested) if requested != gateway_identity) {
+                    warn!("the --gateway argument is ignored when testing a gateway")
+                }
+                GatewaySetup::Specified { gateway_identity }
+            }
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct NodeTestResult {
+    pub tested_node: TestableNode,
+    pub gateway: String,
+
+    pub sent_packets: u32,
+    pub received_packets: u32,
+    pub received_acks: u32,
+    pub duplicate_packets: u32,
+    pub duplicate_acks: u32,
+
+    /// Percentage of the test packets that never came back.
+    pub packet_loss: f32,
+
+    /// Percentage of the test packets that never got acknowledged.
+    pub ack_loss: f32,
+
+    // note: the latencies are measured from the moment the packets got sent to the gateway
+    // and thus they include the (deliberate) mixing delays
+    #[serde(with = "humantime_serde")]
+    pub average_packet_latency: Option<Duration>,
+    #[serde(with = "humantime_serde")]
+    pub max_packet_latency: Option<Duration>,
+    #[serde(with = "humantime_serde")]
+    pub average_ack_latency: Option<Duration>,
+    #[serde(with = "humantime_serde")]
+    pub max_ack_latency: Option<Duration>,
+}
+
+impl NodeTestResult {
+    /// Overall score of the node, computed the same way as by the wasm node tester.
+    pub fn score(&self) -> f32 {
+        let expected = self.sent_packets * 2;
+        let actual = (self.received_packets + self.received_acks)
+            .saturating_sub(self.duplicate_packets + self.duplicate_acks);
+
+        actual as f32 / expected as f32 * 100.
+    }
+}
+
+fn display_latency(latency: Option<Duration>) -> String {
+    latency
+        .map(|latency| format!("{latency:?}"))
+        .unwrap_or_else(|| "n/a".to_string())
+}
+
+impl Display for NodeTestResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Tested {}", self.tested_node)?;
+        writeln!(f, "Used gateway: {}", self.gateway)?;
+        writeln!(f, "Total score: {:.2}%", self.score())?;
+        writeln!(f, "Sent packets: {}", self.sent_packets)?;
+        writeln!(
+            f,
+            "Received (valid) packets: {} (loss: {:.2}%)",
+            self.received_packets, self.packet_loss
+        )?;
+        writeln!(
+            f,
+            "Received (valid) acks: {} (loss: {:.2}%)",
+            self.received_acks, self.ack_loss
+        )?;
+        writeln!(f, "Received duplicate packets: {}", self.duplicate_packets)?;
+        writeln!(f, "Received duplicate acks: {}", self.duplicate_acks)?;
+        writeln!(
+            f,
+            "Packet latency: average {}, max {}",
+            display_latency(self.average_packet_latency),
+            display_latency(self.max_packet_latency)
+        )?;
+        write!(
+            f,
+            "Ack latency: average {}, max {}",
+            display_latency(self.average_ack_latency),
+            display_latency(self.max_ack_latency)
+        )
+    }
+}
+
+struct TestRun {
+    tested_node: TestableNode,
+    sent_packets: u32,
+    expected_acks: HashSet<FragmentIdentifier>,
+
+    received_packets: HashSet<u32>,
+    received_acks: HashSet<FragmentIdentifier>,
+    duplicate_packets: u32,
+    duplicate_acks: u32,
+
+    packet_latencies: Vec<Duration>,
+    ack_latencies: Vec<Duration>,
+}
+
+fn loss(sent: u32, received: usize) -> f32 {
+    (sent as f32 - received as f32) / sent as f32 * 100.
+}
+
+fn average(latencies: &[Duration]) -> Option<Duration> {
+    if latencies.is_empty() {
+        return None;
+    }
+    Some(latencies.iter().sum::<Duration>() / latencies.len() as u32)
+}
+
+impl TestRun {
+    fn new(tested_node: TestableNode, test_packets: &[PreparedFragment]) -> Self {
+        TestRun {
+            tested_node,
+            sent_packets: test_packets.len() as u32,
+            expected_acks: test_packets
+                .iter()
+                .map(|packet| packet.fragment_identifier)
+                .collect(),
+            received_packets: Default::default(),
+            received_acks: Default::default(),
+            duplicate_packets: 0,
+            duplicate_acks: 0,
+            packet_latencies: Vec::new(),
+            ack_latencies: Vec::new(),
+        }
+    }
+
+    fn on_received(&mut self, received: Received<Empty>, latency: Duration) {
+        match received {
+            Received::Message(msg) => {
+                if msg.tested_node != self.tested_node {
+                    warn!(
+                        "received a test packet for a different node ({})",
+                        msg.tested_node
+                    );
+                } else if self.received_packets.insert(msg.msg_id) {
+                    self.packet_latencies.push(latency);
+                } else {
+                    self.duplicate_packets += 1;
+                }
+            }
+            Received::Ack(frag_id) => {
+                if !self.expected_acks.contains(&frag_id) {
+                    warn!("received an ack that was not part of the test! (id: {frag_id})");
+                } else if self.received_acks.insert(frag_id) {
+                    self.ack_latencies.push(latency);
+                } else {
+                    self.duplicate_acks += 1;
+                }
+            }
+        }
+    }
+
+    fn received_all(&self) -> bool {
+        self.received_packets.len() == self.sent_packets as usize
+            && self.received_acks.len() == self.sent_packets as usize
+    }
+
+    fn finish(self, gateway: String) -> NodeTestResult {
+        NodeTestResult {
+            tested_node: self.tested_node,
+            gateway,
+            sent_packets: self.sent_packets,
+            received_packets: self.received_packets.len() as u32,
+            received_acks: self.received_acks.len() as u32,
+            duplicate_packets: self.duplicate_packets,
+            duplicate_acks: self.duplicate_acks,
+            packet_loss: loss(self.sent_packets, self.received_packets.len()),
+            ack_loss: loss(self.sent_packets, self.received_acks.len()),
+            average_packet_latency: average(&self.packet_latencies),
+            max_packet_latency: self.packet_latencies.iter().max().copied(),
+            average_ack_latency: average(&self.ack_latencies),
+            max_ack_latency: self.ack_latencies.iter().max().copied(),
+        }
+    }
+}
+
+async fn run_test(
+    args: Args,
+    client: QueryClientWithNyxd,
+) -> Result<NodeTestResult, NodeTestError> {
+    let mixnodes = client.get_cached_active_mixnodes().await?;
+    let gateways = client.get_cached_gateways().await?;
+    let topology = NymTopology::from_detailed(mixnodes, gateways);
+
+    let target = TestTarget::resolve(&args.node, &topology, &client).await?;
+    let tested_node = target.testable_node();
+
+    // register with the gateway using fresh, ephemeral, keys
+    let storage = Ephemeral::new();
+    let init_details = setup_gateway_from(
+        &target.gateway_setup(args.gateway),
+        storage.key_store(),
+        storage.gateway_details_store(),
+        false,
+        Some(topology.gateways()),
+    )
+    .await?;
+    let address = init_details.client_address()?;
+    let gateway_identity = init_details
+        .gateway_details
+        .try_get_gateway_identity_key()?;
+    let managed_keys = init_details.managed_keys;
+
+    let task_manager = TaskManager::default();
+    let (mixnet_message_sender, mixnet_message_receiver) = mpsc::unbounded();
+    let (ack_sender, ack_receiver) = mpsc::unbounded();
+
+    let mut gateway_client: GatewayClient<QueryClientWithNyxd, EphemeralStorage> =
+        GatewayClient::new(
+            init_details.gateway_details.gateway_listener,
+            managed_keys.identity_keypair(),
+            gateway_identity,
+            Some(managed_keys.must_get_gateway_shared_key()),
+            mixnet_message_sender,
+            ack_sender,
+            GATEWAY_RESPONSE_TIMEOUT,
+            None,
+            task_manager.subscribe(),
+        );
+    gateway_client.set_disabled_credentials_mode(true);
+    gateway_client.authenticate_and_start().await?;
+
+    let (processed_sender, mut processed_receiver) = mpsc::unbounded();
+    let mut receiver = SimpleMessageReceiver::new_sphinx_receiver(
+        managed_keys.encryption_keypair(),
+        managed_keys.ack_key(),
+        mixnet_message_receiver,
+        ack_receiver,
+        processed_sender,
+        task_manager.subscribe(),
+    );
+    tokio::spawn(async move { receiver.run().await });
+
+    let mut tester = NodeTester::new(
+        OsRng,
+        topology,
+        Some(address),
+        PacketSize::default(),
+        AVERAGE_PACKET_DELAY,
+        AVERAGE_ACK_DELAY,
+        managed_keys.ack_key(),
+    );
+    let test_packets = match &target {
+        TestTarget::Mixnode(node) => {
+            tester.mixnode_test_packets(node, Empty, args.packets, None)?
+        }
+        TestTarget::Gateway(node) => {
+            tester.gateway_test_packets(node, Empty, args.packets, None)?
+        }
+    };
+
+    let mut test_run = TestRun::new(tested_node, &test_packets);
+    let mix_packets = test_packets.into_iter().map(|p| p.mix_packet).collect();
+
+    info!(
+        "sending {} test packets through {}",
+        test_run.sent_packets, test_run.tested_node
+    );
+    let sending_start = Instant::now();
+    gateway_client.batch_send_mix_packets(mix_packets).await?;
+
+    let timeout = tokio::time::sleep(Duration::from_secs(args.timeout_secs));
+    tokio::pin!(timeout);
+
+    loop {
+        tokio::select! {
+            _ = &mut timeout => {
+                warn!("reached the test timeout before receiving all packets");
+                break
+            }
+            received = processed_receiver.next() => {
+                let Some(received) = received else {
+                    warn!("the packet receiver has stopped processing results!");
+                    break
+                };
+                test_run.on_received(received, sending_start.elapsed());
+                if test_run.received_all() {
+                    break
+                }
+            }
+        }
+    }
+
+    task_manager.signal_shutdown().ok();
+    Ok(test_run.finish(gateway_identity.to_base58_string()))
+}
+
+pub async fn test_node(args: Args, client: QueryClientWithNyxd) -> Result<(), NodeTestError> {
+    let output = args.output;
+    let result = run_test(args, client).await?;
+    println!("{}", output.format(&result));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nym_node_tester_utils::TestMessage;
+
+    fn tested_node() -> TestableNode {
+        TestableNode::new_mixnode("mixnode-identity".to_string(), "owner".to_string(), 42)
+    }
+
+    fn frag_id(id: u8) -> FragmentIdentifier {
+        FragmentIdentifier::try_from_bytes([0, 0, 0, id, 0]).unwrap()
+    }
+
+    fn test_run(sent_packets: u32) -> TestRun {
+        TestRun {
+            tested_node: tested_node(),
+            sent_packets,
+            expected_acks: (0..sent_packets as u8).map(frag_id).collect(),
+            received_packets: Default::default(),
+            received_acks: Default::default(),
+            duplicate_packets: 0,
+            duplicate_acks: 0,
+            packet_latencies: Vec::new(),
+            ack_latencies: Vec::new(),
+        }
+    }
+
+    fn message(tested_node: TestableNode, msg_id: u32) -> Received<Empty> {
+        Received::Message(TestMessage {
+            tested_node,
+            msg_id,
+            total_msgs: 4,
+            ext: Empty,
+        })
+    }
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn loss_is_a_percentage_of_sent_packets() {
+        assert_eq!(loss(4, 4), 0.);
+        assert_eq!(loss(4, 3), 25.);
+        assert_eq!(loss(4, 0), 100.);
+    }
+
+    #[test]
+    fn received_packets_and_acks_are_recorded() {
+        let mut run = test_run(4);
+        run.on_received(message(tested_node(), 0), ms(10));
+        run.on_received(message(tested_node(), 1), ms(30));
+        run.on_received(Received::Ack(frag_id(0)), ms(20));
+
+        assert_eq!(run.received_packets.len(), 2);
+        assert_eq!(run.received_acks.len(), 1);
+        assert_eq!(run.packet_latencies, vec![ms(10), ms(30)]);
+        assert_eq!(run.ack_latencies, vec![ms(20)]);
+        assert!(!run.received_all());
+    }
+
+    #[test]
+    fn duplicates_are_counted_separately() {
+        let mut run = test_run(4);
+        run.on_received(message(tested_node(), 0), ms(10));
+        run.on_received(message(tested_node(), 0), ms(20));
+        run.on_received(Received::Ack(frag_id(1)), ms(10));
+        run.on_received(Received::Ack(frag_id(1)), ms(20));
+
+        assert_eq!(run.received_packets.len(), 1);
+        assert_eq!(run.received_acks.len(), 1);
+        assert_eq!(run.duplicate_packets, 1);
+        assert_eq!(run.duplicate_acks, 1);
+        assert_eq!(run.packet_latencies, vec![ms(10)]);
+        assert_eq!(run.ack_latencies, vec![ms(10)]);
+    }
+
+    #[test]
+    fn unrelated_packets_and_acks_are_ignored() {
+        let mut run = test_run(2);
+        let other_node =
+            TestableNode::new_gateway("gateway-identity".to_string(), "owner".to_string());
+        run.on_received(message(other_node, 0), ms(10));
+        run.on_received(Received::Ack(frag_id(5)), ms(10));
+
+        assert!(run.received_packets.is_empty());
+        assert!(run.received_acks.is_empty());
+        assert_eq!(run.duplicate_packets, 0);
+        assert_eq!(run.duplicate_acks, 0);
+        assert!(run.packet_latencies.is_empty());
+        assert!(run.ack_latencies.is_empty());
+    }
+
+    #[test]
+    fn run_is_complete_once_all_packets_and_acks_are_received() {
+        let mut run = test_run(2);
+        for id in 0..2 {
+            run.on_received(message(tested_node(), id), ms(10));
+            run.on_received(Received::Ack(frag_id(id as u8)), ms(10));
+        }
+        assert!(run.received_all());
+    }
+
+    #[test]
+    fn finished_run_reports_losses_and_latencies() {
+        let mut run = test_run(4);
+        run.on_received(message(tested_node(), 0), ms(10));
+        run.on_received(message(tested_node(), 1), ms(30));
+        run.on_received(message(tested_node(), 1), ms(40));
+        run.on_received(Received::Ack(frag_id(0)), ms(20));
+
+        let result = run.finish("gateway".to_string());
+        assert_eq!(result.tested_node, tested_node());
+        assert_eq!(result.gateway, "gateway");
+        assert_eq!(result.sent_packets, 4);
+        assert_eq!(result.received_packets, 2);
+        assert_eq!(result.received_acks, 1);
+        assert_eq!(result.duplicate_packets, 1);
+        assert_eq!(result.duplicate_acks, 0);
+        assert_eq!(result.packet_loss, 50.);
+        assert_eq!(result.ack_loss, 75.);
+        assert_eq!(result.average_packet_latency, Some(ms(20)));
+        assert_eq!(result.max_packet_latency, Some(ms(30)));
+        assert_eq!(result.average_ack_latency, Some(ms(20)));
+        assert_eq!(result.max_ack_latency, Some(ms(20)));
+    }
+
+    #[test]
+    fn finished_run_without_receipts_has_no_latencies() {
+        let result = test_run(3).finish("gateway".to_string());
+        assert_eq!(result.received_packets, 0);
+        assert_eq!(result.received_acks, 0);
+        assert_eq!(result.packet_loss, 100.);
+        assert_eq!(result.ack_loss, 100.);
+        assert_eq!(result.average_packet_latency, None);
+        assert_eq!(result.max_packet_latency, None);
+        assert_eq!(result.average_ack_latency, None);
+        assert_eq!(result.max_ack_latency, None);
+        assert_eq!(result.score(), 0.);
+    }
+
+    #[test]
+    fn score_accounts_for_duplicates() {
+        let mut run = test_run(4);
+        for id in 0..4 {
+            run.on_received(message(tested_node(), id), ms(10));
+            run.on_received(Received::Ack(frag_id(id as u8)), ms(10));
+        }
+        assert_eq!(run.finish("gateway".to_string()).score(), 100.);
+
+        let mut run = test_run(4);
+        run.on_received(message(tested_node(), 0), ms(10));
+        run.on_received(message(tested_node(), 1), ms(10));
+        run.on_received(message(tested_node(), 1), ms(10));
+        run.on_received(Received::Ack(frag_id(0)), ms(10));
+        run.on_received(Received::Ack(frag_id(1)), ms(10));
+        run.on_received(Received::Ack(frag_id(2)), ms(10));
+        // (2 packets + 3 acks - 1 duplicate) / 8
+        assert_eq!(run.finish("gateway".to_string()).score(), 50.);
+    }
+}
@@ -26,6 +26,12 @@ pub struct Args {
 
     #[clap(long)]
     pub coconut_dkg_contract_address: Option<AccountId>,
+
+    #[clap(
+        long,
+        help = "Allow voting members of the group to create proposals (required for operator-controlled multisigs)"
+    )]
+    pub member_proposals: bool,
 }
 
 pub async fn generate(args: Args) {
@@ -58,6 +64,7 @@ pub async fn generate(args: Args) {
         max_voting_period: Duration::Time(args.max_voting_period),
         coconut_bandwidth_contract_address: coconut_bandwidth_contract_address.to_string(),
         coconut_dkg_contract_address: coconut_dkg_contract_address.to_string(),
+        member_proposals: args.member_proposals,
     };
 
     debug!("instantiate_msg: {:?}", instantiate_msg);
@@ -7,8 +7,10 @@ pub mod rewards;
 
 pub mod delegate_to_mixnode;
 pub mod query_for_delegations;
+pub mod redelegate_to_mixnode;
 pub mod undelegate_from_mixnode;
 pub mod vesting_delegate_to_mixnode;
+pub mod vesting_redelegate_to_mixnode;
 pub mod vesting_undelegate_from_mixnode;
 
 #[derive(Debug, Args)]
@@ -32,4 +34,8 @@ pub enum MixnetDelegatorsCommands {
     DelegateVesting(vesting_delegate_to_mixnode::Args),
     /// Undelegate from a mixnode (when originally using locked tokens)
     UndelegateVesting(vesting_undelegate_from_mixnode::Args),
+    /// Move delegation (alongside its rewards) to another mixnode
+    Redelegate(redelegate_to_mixnode::Args),
+    /// Move delegation made with locked tokens (alongside its rewards) to another mixnode
+    RedelegateVesting(vesting_redelegate_to_mixnode::Args),
 }
@@ -0,0 +1,51 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use log::info;
+use nym_mixnet_contract_common::{Coin, MixId};
+use nym_validator_client::nyxd::VestingSigningClient;
+
+use crate::context::SigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Id of the mixnode the stake is currently delegated to
+    #[clap(long)]
+    pub from_mix_id: MixId,
+
+    /// Id of the mixnode the stake should be moved to
+    #[clap(long)]
+    pub to_mix_id: MixId,
+
+    /// Amount of the delegated stake to move. All accrued rewards are moved alongside it
+    #[clap(long)]
+    pub amount: u128,
+
+    #[clap(long)]
+    pub on_behalf_of: Option<String>,
+}
+
+pub async fn vesting_redelegate_to_mixnode(args: Args, client: SigningClient) {
+    let denom = client.current_chain_details().mix_denom.base.as_str();
+
+    info!(
+        "moving vesting stake from mixnode {} to mixnode {}",
+        args.from_mix_id, args.to_mix_id
+    );
+
+    let coin = Coin::new(args.amount, denom);
+
+    let res = client
+        .vesting_redelegate_to_mixnode(
+            args.from_mix_id,
+            args.to_mix_id,
+            coin.into(),
+            args.on_behalf_of,
+            None,
+        )
+        .await
+        .expect("failed to move vesting stake between mixnodes!");
+
+    info!("redelegating vesting stake: {:?}", res);
+}
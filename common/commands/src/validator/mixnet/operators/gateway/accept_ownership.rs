@@ -0,0 +1,25 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::context::SigningClient;
+use clap::Parser;
+use log::info;
+use nym_validator_client::nyxd::traits::MixnetSigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Identity key of the gateway whose ownership has been offered to this account
+    #[arg(long)]
+    pub identity_key: String,
+}
+
+pub async fn accept_gateway_ownership(args: Args, client: SigningClient) {
+    info!("Accepting ownership of gateway {}", args.identity_key);
+
+    let res = client
+        .accept_gateway_ownership(args.identity_key, None)
+        .await
+        .expect("failed to accept gateway ownership!");
+
+    info!("Ownership acceptance result: {:?}", res)
+}
@@ -5,7 +5,7 @@ use crate::context::SigningClient;
 use clap::Parser;
 use log::{info, warn};
 use nym_contracts_common::signing::MessageSignature;
-use nym_mixnet_contract_common::Coin;
+use nym_mixnet_contract_common::{Coin, Gateway};
 use nym_network_defaults::{DEFAULT_CLIENT_LISTENING_PORT, DEFAULT_MIX_LISTENING_PORT};
 use nym_validator_client::nyxd::traits::MixnetSigningClient;
 
@@ -23,6 +23,10 @@ pub struct Args {
     #[clap(long)]
     pub clients_port: Option<u16>,
 
+    /// Port on which the gateway is accepting client connections over TLS (i.e. `wss://`)
+    #[clap(long)]
+    pub clients_wss_port: Option<u16>,
+
     #[clap(long)]
     pub location: String,
 
@@ -45,6 +49,19 @@ pub struct Args {
     pub force: bool,
 }
 
+pub(crate) fn gateway_from_args(args: &Args) -> Gateway {
+    Gateway {
+        host: args.host.clone(),
+        mix_port: args.mix_port.unwrap_or(DEFAULT_MIX_LISTENING_PORT),
+        clients_port: args.clients_port.unwrap_or(DEFAULT_CLIENT_LISTENING_PORT),
+        clients_wss_port: args.clients_wss_port,
+        location: args.location.clone(),
+        sphinx_key: args.sphinx_key.clone(),
+        identity_key: args.identity_key.clone(),
+        version: args.version.clone(),
+    }
+}
+
 pub async fn bond_gateway(args: Args, client: SigningClient) {
     let denom = client.current_chain_details().mix_denom.base.as_str();
 
@@ -56,15 +73,7 @@ pub async fn bond_gateway(args: Args, client: SigningClient) {
         return;
     }
 
-    let gateway = nym_mixnet_contract_common::Gateway {
-        host: args.host,
-        mix_port: args.mix_port.unwrap_or(DEFAULT_MIX_LISTENING_PORT),
-        clients_port: args.clients_port.unwrap_or(DEFAULT_CLIENT_LISTENING_PORT),
-        location: args.location,
-        sphinx_key: args.sphinx_key,
-        identity_key: args.identity_key,
-        version: args.version,
-    };
+    let gateway = gateway_from_args(&args);
 
     let coin = Coin::new(args.amount, denom);
 
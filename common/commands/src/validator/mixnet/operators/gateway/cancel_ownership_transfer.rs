@@ -0,0 +1,21 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::context::SigningClient;
+use clap::Parser;
+use log::info;
+use nym_validator_client::nyxd::traits::MixnetSigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {}
+
+pub async fn cancel_gateway_ownership_transfer(_args: Args, client: SigningClient) {
+    info!("Cancelling pending gateway ownership transfer");
+
+    let res = client
+        .cancel_gateway_ownership_transfer(None)
+        .await
+        .expect("failed to cancel gateway ownership transfer!");
+
+    info!("Ownership transfer cancellation result: {:?}", res)
+}
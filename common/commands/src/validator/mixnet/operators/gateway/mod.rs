@@ -3,9 +3,13 @@
 
 use clap::{Args, Subcommand};
 
+pub mod accept_ownership;
 pub mod bond_gateway;
+pub mod cancel_ownership_transfer;
 pub mod gateway_bonding_sign_payload;
+pub mod rewards;
 pub mod settings;
+pub mod transfer_ownership;
 pub mod unbond_gateway;
 pub mod vesting_bond_gateway;
 pub mod vesting_unbond_gateway;
@@ -19,6 +23,8 @@ pub struct MixnetOperatorsGateway {
 
 #[derive(Debug, Subcommand)]
 pub enum MixnetOperatorsGatewayCommands {
+    /// Manage your gateway operator rewards
+    Rewards(rewards::MixnetOperatorsGatewayRewards),
     /// Manage your gateway settings stored in the directory
     Settings(settings::MixnetOperatorsGatewaySettings),
     /// Bond to a gateway
@@ -31,4 +37,10 @@ pub enum MixnetOperatorsGatewayCommands {
     VestingUnbond(vesting_unbond_gateway::Args),
     /// Create base58-encoded payload required for producing valid bonding signature.
     CreateGatewayBondingSignPayload(gateway_bonding_sign_payload::Args),
+    /// Propose transferring the ownership of your gateway to a different account
+    TransferOwnership(transfer_ownership::Args),
+    /// Accept the ownership of a gateway that has been offered to you
+    AcceptOwnership(accept_ownership::Args),
+    /// Cancel a pending ownership transfer of your gateway
+    CancelOwnershipTransfer(cancel_ownership_transfer::Args),
 }
@@ -0,0 +1,21 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::context::SigningClient;
+use clap::Parser;
+use log::info;
+use nym_validator_client::nyxd::traits::MixnetSigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {}
+
+pub async fn claim_gateway_operator_reward(_args: Args, client: SigningClient) {
+    info!("Claim gateway operator reward");
+
+    let res = client
+        .withdraw_gateway_operator_reward(None)
+        .await
+        .expect("failed to claim gateway operator reward");
+
+    info!("Claiming gateway operator reward: {:?}", res)
+}
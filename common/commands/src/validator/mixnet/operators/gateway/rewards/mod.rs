@@ -0,0 +1,22 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::{Args, Subcommand};
+
+pub mod claim_gateway_operator_reward;
+pub mod vesting_claim_gateway_operator_reward;
+
+#[derive(Debug, Args)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_required = true)]
+pub struct MixnetOperatorsGatewayRewards {
+    #[clap(subcommand)]
+    pub command: MixnetOperatorsGatewayRewardsCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MixnetOperatorsGatewayRewardsCommands {
+    /// Claim rewards
+    Claim(claim_gateway_operator_reward::Args),
+    /// Claim rewards for a gateway bonded with locked tokens
+    VestingClaim(vesting_claim_gateway_operator_reward::Args),
+}
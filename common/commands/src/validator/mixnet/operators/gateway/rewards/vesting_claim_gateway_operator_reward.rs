@@ -0,0 +1,21 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::context::SigningClient;
+use clap::Parser;
+use log::info;
+use nym_validator_client::nyxd::VestingSigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {}
+
+pub async fn vesting_claim_gateway_operator_reward(_args: Args, client: SigningClient) {
+    info!("Claim vesting gateway operator reward");
+
+    let res = client
+        .vesting_withdraw_gateway_operator_reward(None)
+        .await
+        .expect("failed to claim vesting gateway operator reward");
+
+    info!("Claiming vesting gateway operator reward: {:?}", res)
+}
@@ -19,6 +19,10 @@ pub struct Args {
     #[clap(long)]
     pub clients_port: Option<u16>,
 
+    /// Port on which the gateway is accepting client connections over TLS (i.e. `wss://`)
+    #[clap(long)]
+    pub clients_wss_port: Option<u16>,
+
     #[clap(long)]
     pub location: Option<String>,
 
@@ -48,6 +52,9 @@ pub async fn vesting_update_config(args: Args, client: SigningClient) {
         clients_port: args
             .clients_port
             .unwrap_or(current_details.gateway.clients_port),
+        clients_wss_port: args
+            .clients_wss_port
+            .or(current_details.gateway.clients_wss_port),
         location: args.location.unwrap_or(current_details.gateway.location),
         version: args.version.unwrap_or(current_details.gateway.version),
     };
@@ -23,6 +23,10 @@ pub struct Args {
     #[clap(long)]
     pub clients_port: Option<u16>,
 
+    /// Port on which the gateway is accepting client connections over TLS (i.e. `wss://`)
+    #[clap(long)]
+    pub clients_wss_port: Option<u16>,
+
     #[clap(long)]
     pub location: String,
 
@@ -60,6 +64,7 @@ pub async fn vesting_bond_gateway(args: Args, client: SigningClient) {
         host: args.host,
         mix_port: args.mix_port.unwrap_or(DEFAULT_MIX_LISTENING_PORT),
         clients_port: args.clients_port.unwrap_or(DEFAULT_CLIENT_LISTENING_PORT),
+        clients_wss_port: args.clients_wss_port,
         location: args.location,
         sphinx_key: args.sphinx_key,
         identity_key: args.identity_key,
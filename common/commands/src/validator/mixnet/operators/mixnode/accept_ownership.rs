@@ -0,0 +1,26 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::context::SigningClient;
+use clap::Parser;
+use log::info;
+use nym_mixnet_contract_common::MixId;
+use nym_validator_client::nyxd::traits::MixnetSigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Id of the mixnode whose ownership has been offered to this account
+    #[arg(long)]
+    pub mix_id: MixId,
+}
+
+pub async fn accept_mixnode_ownership(args: Args, client: SigningClient) {
+    info!("Accepting ownership of mixnode {}", args.mix_id);
+
+    let res = client
+        .accept_mixnode_ownership(args.mix_id, None)
+        .await
+        .expect("failed to accept mixnode ownership!");
+
+    info!("Ownership acceptance result: {:?}", res)
+}
@@ -6,7 +6,7 @@ use cosmwasm_std::Uint128;
 use log::{info, warn};
 
 use nym_contracts_common::signing::MessageSignature;
-use nym_mixnet_contract_common::{Coin, MixNodeCostParams, Percent};
+use nym_mixnet_contract_common::{Coin, MixNode, MixNodeCostParams, Percent};
 use nym_network_defaults::{
     DEFAULT_HTTP_API_LISTENING_PORT, DEFAULT_MIX_LISTENING_PORT, DEFAULT_VERLOC_LISTENING_PORT,
 };
@@ -60,32 +60,22 @@ pub struct Args {
     pub force: bool,
 }
 
-pub async fn bond_mixnode(args: Args, client: SigningClient) {
-    let denom = client.current_chain_details().mix_denom.base.as_str();
-
-    info!("Starting mixnode bonding!");
-
-    // if we're trying to bond less than 1 token
-    if args.amount < 1_000_000 && !args.force {
-        warn!("You're trying to bond only {}{} which is less than 1 full token. Are you sure that's what you want? If so, run with `--force` or `-f` flag", args.amount, denom);
-        return;
-    }
-
-    let mixnode = nym_mixnet_contract_common::MixNode {
-        host: args.host,
+pub(crate) fn mixnode_from_args(args: &Args) -> MixNode {
+    MixNode {
+        host: args.host.clone(),
         mix_port: args.mix_port.unwrap_or(DEFAULT_MIX_LISTENING_PORT),
         verloc_port: args.verloc_port.unwrap_or(DEFAULT_VERLOC_LISTENING_PORT),
         http_api_port: args
             .http_api_port
             .unwrap_or(DEFAULT_HTTP_API_LISTENING_PORT),
-        sphinx_key: args.sphinx_key,
-        identity_key: args.identity_key,
-        version: args.version,
-    };
-
-    let coin = Coin::new(args.amount, denom);
+        sphinx_key: args.sphinx_key.clone(),
+        identity_key: args.identity_key.clone(),
+        version: args.version.clone(),
+    }
+}
 
-    let cost_params = MixNodeCostParams {
+pub(crate) fn cost_params_from_args(args: &Args, denom: &str) -> MixNodeCostParams {
+    MixNodeCostParams {
         profit_margin_percent: Percent::from_percentage_value(
             args.profit_margin_percent.unwrap_or(10) as u64,
         )
@@ -94,7 +84,23 @@ pub async fn bond_mixnode(args: Args, client: SigningClient) {
             denom: denom.into(),
             amount: Uint128::new(args.interval_operating_cost.unwrap_or(40_000_000)),
         },
-    };
+    }
+}
+
+pub async fn bond_mixnode(args: Args, client: SigningClient) {
+    let denom = client.current_chain_details().mix_denom.base.as_str();
+
+    info!("Starting mixnode bonding!");
+
+    // if we're trying to bond less than 1 token
+    if args.amount < 1_000_000 && !args.force {
+        warn!("You're trying to bond only {}{} which is less than 1 full token. Are you sure that's what you want? If so, run with `--force` or `-f` flag", args.amount, denom);
+        return;
+    }
+
+    let mixnode = mixnode_from_args(&args);
+    let cost_params = cost_params_from_args(&args, denom);
+    let coin = Coin::new(args.amount, denom);
 
     let res = client
         .bond_mixnode(mixnode, cost_params, args.signature, coin.into(), None)
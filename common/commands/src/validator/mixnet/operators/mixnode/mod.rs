@@ -3,7 +3,9 @@
 
 use clap::{Args, Subcommand};
 
+pub mod accept_ownership;
 pub mod bond_mixnode;
+pub mod cancel_ownership_transfer;
 pub mod decrease_pledge;
 pub mod families;
 pub mod keys;
@@ -11,6 +13,7 @@ pub mod mixnode_bonding_sign_payload;
 pub mod pledge_more;
 pub mod rewards;
 pub mod settings;
+pub mod transfer_ownership;
 pub mod unbond_mixnode;
 pub mod vesting_bond_mixnode;
 pub mod vesting_decrease_pledge;
@@ -52,4 +55,10 @@ pub enum MixnetOperatorsMixnodeCommands {
     DecreasePledge(decrease_pledge::Args),
     /// Decrease pledge with locked tokens
     DecreasePledgeVesting(vesting_decrease_pledge::Args),
+    /// Propose transferring the ownership of your mixnode to a different account
+    TransferOwnership(transfer_ownership::Args),
+    /// Accept the ownership of a mixnode that has been offered to you
+    AcceptOwnership(accept_ownership::Args),
+    /// Cancel a pending ownership transfer of your mixnode
+    CancelOwnershipTransfer(cancel_ownership_transfer::Args),
 }
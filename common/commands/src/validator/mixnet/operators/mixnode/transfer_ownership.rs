@@ -0,0 +1,29 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::context::SigningClient;
+use clap::Parser;
+use cosmrs::AccountId;
+use log::info;
+use nym_validator_client::nyxd::traits::MixnetSigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Address of the account that is going to become the new owner of the mixnode once it accepts the transfer
+    #[arg(long)]
+    pub to_address: AccountId,
+}
+
+pub async fn transfer_mixnode_ownership(args: Args, client: SigningClient) {
+    info!(
+        "Proposing mixnode ownership transfer to {}",
+        args.to_address
+    );
+
+    let res = client
+        .transfer_mixnode_ownership(args.to_address, None)
+        .await
+        .expect("failed to propose mixnode ownership transfer!");
+
+    info!("Ownership transfer proposal result: {:?}", res)
+}
@@ -2,6 +2,8 @@ use clap::{Args, Subcommand};
 
 pub mod delete;
 pub mod register;
+pub mod renew;
+pub mod transfer;
 
 #[derive(Debug, Args)]
 #[clap(args_conflicts_with_subcommands = true, subcommand_required = true)]
@@ -16,4 +18,8 @@ pub enum MixnetOperatorsNameCommands {
     Register(register::Args),
     /// Delete name alias for a nym address
     Delete(delete::Args),
+    /// Renew the registration of a name alias
+    Renew(renew::Args),
+    /// Transfer ownership of a name alias to another account
+    Transfer(transfer::Args),
 }
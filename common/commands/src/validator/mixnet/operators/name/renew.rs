@@ -0,0 +1,25 @@
+use clap::Parser;
+use log::{error, info};
+use nym_name_service_common::NameId;
+use nym_validator_client::nyxd::{error::NyxdError, traits::NameServiceSigningClient};
+use tap::TapFallible;
+
+use crate::context::SigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(long)]
+    pub id: NameId,
+}
+
+pub async fn renew(args: Args, client: SigningClient) -> Result<(), NyxdError> {
+    info!("Renewing registered name alias with id {}", args.id);
+
+    let res = client
+        .renew_name(args.id, None)
+        .await
+        .tap_err(|err| error!("Failed to renew name: {err:#?}"))?;
+
+    info!("Renewed: {res:?}");
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use clap::Parser;
+use cosmrs::AccountId;
+use log::{error, info};
+use nym_name_service_common::NameId;
+use nym_validator_client::nyxd::{error::NyxdError, traits::NameServiceSigningClient};
+use tap::TapFallible;
+
+use crate::context::SigningClient;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(long)]
+    pub id: NameId,
+
+    /// Account that will become the new owner of the name
+    #[clap(long)]
+    pub to: AccountId,
+}
+
+pub async fn transfer(args: Args, client: SigningClient) -> Result<(), NyxdError> {
+    info!(
+        "Transferring registered name alias with id {} to {}",
+        args.id, args.to
+    );
+
+    let res = client
+        .transfer_name(args.id, args.to, None)
+        .await
+        .tap_err(|err| error!("Failed to transfer name: {err:#?}"))?;
+
+    info!("Transferred: {res:?}");
+    Ok(())
+}
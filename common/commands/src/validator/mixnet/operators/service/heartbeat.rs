@@ -0,0 +1,129 @@
+use clap::Parser;
+use cosmwasm_std::{Addr, StdError};
+use log::{error, info};
+use nym_contracts_common::signing::{MessageSignature, Nonce};
+use nym_crypto::asymmetric::identity;
+use nym_service_provider_directory_common::signing_types::construct_service_provider_heartbeat_sign_payload;
+use nym_service_provider_directory_common::ServiceId;
+use nym_validator_client::nyxd::error::NyxdError;
+use nym_validator_client::nyxd::traits::{SpDirectoryQueryClient, SpDirectorySigningClient};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::context::SigningClient;
+use crate::utils::account_id_to_cw_addr;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(long)]
+    pub id: ServiceId,
+
+    /// Heartbeat payload signed with the identity key of the service provider
+    #[clap(
+        long,
+        required_unless_present = "private_key",
+        conflicts_with = "private_key"
+    )]
+    pub signature: Option<MessageSignature>,
+
+    /// Path to the private identity key of the service provider (example: private_identity_key.pem),
+    /// used for signing the heartbeat payload
+    #[clap(long)]
+    pub private_key: Option<PathBuf>,
+
+    /// Keep sending heartbeats at the provided interval, in seconds.
+    /// The directory expects a heartbeat at least once an hour.
+    #[clap(long, requires = "private_key", value_parser = clap::value_parser!(u64).range(1..))]
+    pub interval_secs: Option<u64>,
+}
+
+fn sign_heartbeat_payload(
+    private_key: &identity::PrivateKey,
+    nonce: Nonce,
+    sender: Addr,
+    service_id: ServiceId,
+) -> Result<MessageSignature, StdError> {
+    let payload = construct_service_provider_heartbeat_sign_payload(nonce, sender, service_id);
+    let signature = private_key.sign(&payload.to_plaintext()?);
+    Ok(signature.to_bytes().to_vec().into())
+}
+
+async fn send_signed_heartbeat(
+    client: &SigningClient,
+    service_id: ServiceId,
+    private_key: &identity::PrivateKey,
+) -> Result<(), NyxdError> {
+    // the nonce changes with every heartbeat, so the payload has to be signed anew each time
+    let nonce = client.get_service_signing_nonce(client.address()).await?;
+    let sender = account_id_to_cw_addr(client.address());
+    let signature = sign_heartbeat_payload(private_key, nonce, sender, service_id)?;
+
+    let res = client
+        .send_service_provider_heartbeat(service_id, signature, None)
+        .await?;
+    info!("Sent heartbeat: {res:?}");
+    Ok(())
+}
+
+pub async fn heartbeat(args: Args, client: SigningClient) {
+    info!("Sending heartbeat for service provider with id {}", args.id);
+
+    let Some(private_key) = args.private_key else {
+        // clap guarantees the signature is present if the private key isn't
+        let signature = args.signature.expect("missing heartbeat signature");
+        let res = client
+            .send_service_provider_heartbeat(args.id, signature, None)
+            .await
+            .expect("Failed to send service provider heartbeat");
+        info!("Sent heartbeat: {res:?}");
+        return;
+    };
+
+    let private_key: identity::PrivateKey =
+        nym_pemstore::load_key(private_key).expect("failed to load key");
+
+    let Some(interval_secs) = args.interval_secs else {
+        send_signed_heartbeat(&client, args.id, &private_key)
+            .await
+            .expect("Failed to send service provider heartbeat");
+        return;
+    };
+
+    let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+    loop {
+        interval.tick().await;
+        // a single failure shouldn't stop the service from being marked alive later on
+        if let Err(err) = send_signed_heartbeat(&client, args.id, &private_key).await {
+            error!("Failed to send service provider heartbeat: {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heartbeat_payload_is_signed_with_the_identity_key() {
+        let mut rng = rand_07::rngs::OsRng;
+        let keys = identity::KeyPair::new(&mut rng);
+        let sender = Addr::unchecked("n1sender");
+
+        let signature = sign_heartbeat_payload(keys.private_key(), 42, sender.clone(), 7).unwrap();
+
+        let payload = construct_service_provider_heartbeat_sign_payload(42, sender.clone(), 7)
+            .to_plaintext()
+            .unwrap();
+        let signature = identity::Signature::from_bytes(signature.as_ref()).unwrap();
+        assert!(keys.public_key().verify(&payload, &signature).is_ok());
+
+        // the signature is only valid for the particular nonce
+        let stale_payload = construct_service_provider_heartbeat_sign_payload(41, sender, 7)
+            .to_plaintext()
+            .unwrap();
+        assert!(keys
+            .public_key()
+            .verify(&stale_payload, &signature)
+            .is_err());
+    }
+}
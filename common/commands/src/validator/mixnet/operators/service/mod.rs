@@ -3,6 +3,9 @@ use clap::{Args, Subcommand};
 pub mod announce;
 pub mod announce_sign_payload;
 pub mod delete;
+pub mod heartbeat;
+pub mod heartbeat_sign_payload;
+pub mod update_metadata;
 
 #[derive(Debug, Args)]
 #[clap(args_conflicts_with_subcommands = true, subcommand_required = true)]
@@ -18,6 +21,12 @@ pub enum MixnetOperatorsServiceCommands {
     Announce(announce::Args),
     /// Delete entry for service provider from the directory
     Delete(delete::Args),
+    /// Signal that the service provider is still alive
+    Heartbeat(heartbeat::Args),
+    /// Publish the capabilities of the service provider
+    UpdateMetadata(update_metadata::Args),
     /// Create base58-encoded payload required for producing valid announce signature.
     CreateServiceAnnounceSignPayload(announce_sign_payload::Args),
+    /// Create base58-encoded payload required for producing valid heartbeat signature.
+    CreateServiceHeartbeatSignPayload(heartbeat_sign_payload::Args),
 }
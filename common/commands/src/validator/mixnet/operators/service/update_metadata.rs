@@ -0,0 +1,65 @@
+use clap::{Parser, ValueEnum};
+use log::info;
+use nym_service_provider_directory_common::{AllowedHostsPolicy, ServiceId, ServiceMetadata};
+use nym_validator_client::nyxd::traits::SpDirectorySigningClient;
+
+use crate::context::SigningClient;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum AllowedHosts {
+    Unspecified,
+    Open,
+    StandardList,
+    Custom,
+}
+
+impl From<AllowedHosts> for AllowedHostsPolicy {
+    fn from(value: AllowedHosts) -> Self {
+        match value {
+            AllowedHosts::Unspecified => AllowedHostsPolicy::Unspecified,
+            AllowedHosts::Open => AllowedHostsPolicy::Open,
+            AllowedHosts::StandardList => AllowedHostsPolicy::StandardList,
+            AllowedHosts::Custom => AllowedHostsPolicy::Custom,
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(long)]
+    pub id: ServiceId,
+
+    /// Versions of the service provider interface supported by the service
+    #[clap(long, value_delimiter = ',')]
+    pub interface_versions: Vec<u8>,
+
+    /// Versions of the service protocol, e.g. socks5, supported by the service
+    #[clap(long, value_delimiter = ',')]
+    pub protocol_versions: Vec<u8>,
+
+    /// Policy used by the service for deciding which hosts it connects to
+    #[clap(long, value_enum, default_value = "unspecified")]
+    pub allowed_hosts: AllowedHosts,
+
+    /// Bandwidth the service is able to provide, in bytes per second
+    #[clap(long)]
+    pub bandwidth: Option<u64>,
+}
+
+pub async fn update_metadata(args: Args, client: SigningClient) {
+    info!("Updating metadata of service provider with id {}", args.id);
+
+    let metadata = ServiceMetadata {
+        supported_interface_versions: args.interface_versions,
+        supported_protocol_versions: args.protocol_versions,
+        allowed_hosts: args.allowed_hosts.into(),
+        declared_bandwidth: args.bandwidth,
+    };
+
+    let res = client
+        .update_service_provider_metadata(args.id, metadata, None)
+        .await
+        .expect("Failed to update service provider metadata");
+
+    info!("Updated metadata: {res:?}");
+}
@@ -5,6 +5,7 @@ pub mod account;
 pub mod block;
 pub mod cosmwasm;
 pub mod mixnet;
+pub mod multisig;
 pub mod signature;
 pub mod transactions;
 pub mod vesting;
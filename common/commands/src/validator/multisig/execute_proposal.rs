@@ -0,0 +1,34 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use log::info;
+use nym_validator_client::nyxd::traits::MultisigSigningClient;
+use nym_validator_client::nyxd::AccountId;
+
+use crate::context::SigningClient;
+use crate::utils::show_error;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(long)]
+    pub multisig: AccountId,
+
+    #[clap(long)]
+    pub proposal_id: u64,
+}
+
+pub async fn execute(args: Args, client: SigningClient) {
+    info!(
+        "Executing proposal {} of multisig {}",
+        args.proposal_id, args.multisig
+    );
+
+    match client
+        .execute_multisig_proposal(&args.multisig, args.proposal_id, None)
+        .await
+    {
+        Ok(res) => info!("Execution result: {:?}", res),
+        Err(err) => show_error(err),
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use comfy_table::Table;
+use cw3::Status;
+use log::info;
+use nym_validator_client::nyxd::traits::MultisigQueryClient;
+use nym_validator_client::nyxd::AccountId;
+
+use crate::context::QueryClient;
+use crate::utils::show_error;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(long)]
+    pub multisig: AccountId,
+
+    #[clap(
+        long,
+        help = "Show all proposals rather than only the ones that are still open or awaiting execution"
+    )]
+    pub all: bool,
+}
+
+pub async fn list(args: Args, client: QueryClient) {
+    info!("Getting proposals of multisig {}...", args.multisig);
+
+    let proposals = match client.get_all_multisig_proposals(&args.multisig).await {
+        Ok(proposals) => proposals,
+        Err(err) => return show_error(err),
+    };
+
+    let mut table = Table::new();
+    table.set_header(vec!["Id", "Title", "Status", "Expires"]);
+    for proposal in proposals
+        .into_iter()
+        .filter(|p| args.all || matches!(p.status, Status::Open | Status::Passed))
+    {
+        table.add_row(vec![
+            proposal.id.to_string(),
+            proposal.title,
+            format!("{:?}", proposal.status),
+            proposal.expires.to_string(),
+        ]);
+    }
+
+    println!("{table}");
+}
@@ -0,0 +1,31 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::{Args, Subcommand};
+
+pub mod execute_proposal;
+pub mod list_proposals;
+pub mod propose_mixnet;
+pub mod propose_vesting;
+pub mod vote;
+
+#[derive(Debug, Args)]
+#[clap(args_conflicts_with_subcommands = true, subcommand_required = true)]
+pub struct Multisig {
+    #[clap(subcommand)]
+    pub command: MultisigCommands,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum MultisigCommands {
+    /// Propose executing a mixnet contract message on behalf of the multisig
+    ProposeMixnet(propose_mixnet::Args),
+    /// Propose executing a vesting contract message on behalf of the multisig
+    ProposeVesting(propose_vesting::Args),
+    /// List proposals of the multisig
+    ListProposals(list_proposals::Args),
+    /// Vote on an open proposal
+    Vote(vote::Args),
+    /// Execute a proposal that has passed
+    Execute(execute_proposal::Args),
+}
@@ -0,0 +1,68 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use log::info;
+use nym_mixnet_contract_common::ExecuteMsg as MixnetExecuteMsg;
+use nym_validator_client::nyxd::traits::MultisigSigningClient;
+use nym_validator_client::nyxd::{AccountId, Coin};
+
+use crate::context::SigningClient;
+use crate::utils::show_error;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(long, help = "Address of the multisig contract controlling the node")]
+    pub multisig: AccountId,
+
+    #[clap(long)]
+    pub title: String,
+
+    #[clap(long, default_value = "")]
+    pub description: String,
+
+    #[clap(
+        long,
+        help = "JSON-encoded mixnet contract execute message, e.g. '{\"unbond_mixnode\":{}}'"
+    )]
+    pub msg: String,
+
+    #[clap(
+        long,
+        help = "Funds (in current DENOMINATION, e.g. 'unym') to attach to the message when the proposal is executed"
+    )]
+    pub funds: Option<u128>,
+}
+
+pub async fn propose(args: Args, client: SigningClient) {
+    let msg: MixnetExecuteMsg = match serde_json::from_str(&args.msg) {
+        Ok(msg) => msg,
+        Err(err) => return show_error(err),
+    };
+    let denom = client.current_chain_details().mix_denom.base.as_str();
+    let funds = args
+        .funds
+        .map(|amount| vec![Coin::new(amount, denom)])
+        .unwrap_or_default();
+
+    info!(
+        "Proposing execution of '{}' via multisig {}",
+        msg.default_memo(),
+        args.multisig
+    );
+
+    match client
+        .propose_mixnet_execution(
+            &args.multisig,
+            args.title,
+            args.description,
+            &msg,
+            funds,
+            None,
+        )
+        .await
+    {
+        Ok(res) => info!("Proposal result: {:?}", res),
+        Err(err) => show_error(err),
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use log::info;
+use nym_validator_client::nyxd::traits::MultisigSigningClient;
+use nym_validator_client::nyxd::AccountId;
+use nym_vesting_contract_common::ExecuteMsg as VestingExecuteMsg;
+
+use crate::context::SigningClient;
+use crate::utils::show_error;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(long, help = "Address of the multisig contract controlling the node")]
+    pub multisig: AccountId,
+
+    #[clap(long)]
+    pub title: String,
+
+    #[clap(long, default_value = "")]
+    pub description: String,
+
+    #[clap(
+        long,
+        help = "JSON-encoded vesting contract execute message, e.g. '{\"unbond_mixnode\":{}}'"
+    )]
+    pub msg: String,
+}
+
+pub async fn propose(args: Args, client: SigningClient) {
+    let msg: VestingExecuteMsg = match serde_json::from_str(&args.msg) {
+        Ok(msg) => msg,
+        Err(err) => return show_error(err),
+    };
+
+    info!(
+        "Proposing execution of '{}' via multisig {}",
+        msg.name(),
+        args.multisig
+    );
+
+    match client
+        .propose_vesting_execution(&args.multisig, args.title, args.description, &msg, None)
+        .await
+    {
+        Ok(res) => info!("Proposal result: {:?}", res),
+        Err(err) => show_error(err),
+    }
+}
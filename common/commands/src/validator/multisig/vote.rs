@@ -0,0 +1,42 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::Parser;
+use log::info;
+use nym_validator_client::nyxd::traits::MultisigSigningClient;
+use nym_validator_client::nyxd::AccountId;
+
+use crate::context::SigningClient;
+use crate::utils::show_error;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(long)]
+    pub multisig: AccountId,
+
+    #[clap(long)]
+    pub proposal_id: u64,
+
+    #[clap(long, conflicts_with = "no", required_unless_present = "no")]
+    pub yes: bool,
+
+    #[clap(long)]
+    pub no: bool,
+}
+
+pub async fn vote(args: Args, client: SigningClient) {
+    info!(
+        "Voting {} on proposal {} of multisig {}",
+        if args.yes { "yes" } else { "no" },
+        args.proposal_id,
+        args.multisig
+    );
+
+    match client
+        .vote_multisig_proposal(&args.multisig, args.proposal_id, args.yes, None)
+        .await
+    {
+        Ok(res) => info!("Vote result: {:?}", res),
+        Err(err) => show_error(err),
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::context::QueryClient;
+use crate::utils::show_error;
+use crate::validator::transactions::offline::{read_json_file, SignedTransaction};
+use clap::Parser;
+use log::{error, info};
+use serde_json::json;
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(value_parser)]
+    #[clap(help = "Path to the signed transaction created with `tx sign`")]
+    pub input: PathBuf,
+}
+
+pub async fn broadcast(args: Args, client: &QueryClient) {
+    let signed: SignedTransaction = match read_json_file(&args.input) {
+        Ok(signed) => signed,
+        Err(err) => return show_error(err),
+    };
+
+    match client.get_chain_id().await {
+        Ok(chain_id) if chain_id.as_str() != signed.chain_id => {
+            error!(
+                "The transaction has been signed for chain {}, but the node is running {chain_id}",
+                signed.chain_id
+            );
+            return;
+        }
+        Ok(_) => (),
+        Err(err) => return show_error(err),
+    }
+
+    let tx_bytes = match signed.decode_tx_bytes() {
+        Ok(tx_bytes) => tx_bytes,
+        Err(err) => return show_error(err),
+    };
+
+    info!(
+        "Broadcasting transaction signed by {}...",
+        signed.signer_address
+    );
+    match client.broadcast_signed_tx(tx_bytes).await {
+        Ok(res) => {
+            println!("{}", json!(res))
+        }
+        Err(e) => show_error(e),
+    }
+}
@@ -0,0 +1,496 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::context::QueryClient;
+use crate::utils::show_error;
+use crate::validator::mixnet::operators::gateway::bond_gateway;
+use crate::validator::mixnet::operators::mixnode::bond_mixnode;
+use crate::validator::transactions::offline::{
+    write_json_output, OfflineFee, OfflineMessage, UnsignedTransaction,
+};
+use clap::{Parser, Subcommand};
+use log::{info, warn};
+use nym_mixnet_contract_common::{ExecuteMsg as MixnetExecuteMsg, MixId};
+use nym_validator_client::nyxd::{AccountId, Coin, Gas, GasPrice};
+use nym_vesting_contract_common::ExecuteMsg as VestingExecuteMsg;
+use std::path::PathBuf;
+
+const DEFAULT_GAS_LIMIT: u64 = 300_000;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(
+        long,
+        help = "Address of the account that is going to sign the transaction"
+    )]
+    pub signer: AccountId,
+
+    #[clap(long, help = "Gas limit of the transaction")]
+    pub gas: Option<u64>,
+
+    #[clap(
+        long,
+        help = "fee in current DENOMINATION (so it would be 'unym', rather than 'nym'). If not provided, it is derived from the gas limit and the default gas price"
+    )]
+    pub fee: Option<u128>,
+
+    #[clap(long)]
+    pub memo: Option<String>,
+
+    #[clap(short, long, help = "File to save the unsigned transaction to")]
+    pub output: Option<PathBuf>,
+
+    #[clap(subcommand)]
+    pub operation: Operation,
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Subcommand)]
+pub enum Operation {
+    /// Delegate to a mixnode
+    Delegate {
+        #[clap(long)]
+        mix_id: MixId,
+
+        #[clap(long)]
+        amount: u128,
+    },
+    /// Undelegate from a mixnode
+    Undelegate {
+        #[clap(long)]
+        mix_id: MixId,
+    },
+    /// Delegate to a mixnode with locked tokens
+    VestingDelegate {
+        #[clap(long)]
+        mix_id: MixId,
+
+        #[clap(long)]
+        amount: u128,
+    },
+    /// Undelegate from a mixnode (when originally using locked tokens)
+    VestingUndelegate {
+        #[clap(long)]
+        mix_id: MixId,
+    },
+    /// Bond a mixnode
+    BondMixnode(bond_mixnode::Args),
+    /// Bond a mixnode with locked tokens
+    VestingBondMixnode(bond_mixnode::Args),
+    /// Unbond a mixnode
+    UnbondMixnode,
+    /// Unbond a mixnode (when originally using locked tokens)
+    VestingUnbondMixnode,
+    /// Bond a gateway
+    BondGateway(bond_gateway::Args),
+    /// Bond a gateway with locked tokens
+    VestingBondGateway(bond_gateway::Args),
+    /// Unbond a gateway
+    UnbondGateway,
+    /// Unbond a gateway (when originally using locked tokens)
+    VestingUnbondGateway,
+    /// Withdraw vested tokens
+    WithdrawVested {
+        #[clap(long)]
+        amount: u128,
+    },
+}
+
+/// Addresses of the contracts the generated messages are executed on.
+struct Contracts<'a> {
+    mixnet: &'a AccountId,
+    vesting: &'a AccountId,
+}
+
+fn mixnet_message(
+    contracts: &Contracts,
+    msg: MixnetExecuteMsg,
+    funds: Vec<Coin>,
+) -> Result<(OfflineMessage, String), serde_json::Error> {
+    let memo = msg.default_memo();
+    let message = OfflineMessage::new_execute(contracts.mixnet, &msg, funds)?;
+    Ok((message, memo))
+}
+
+fn vesting_message(
+    contracts: &Contracts,
+    msg: VestingExecuteMsg,
+) -> Result<(OfflineMessage, String), serde_json::Error> {
+    let memo = msg.name().to_owned();
+    let message = OfflineMessage::new_execute(contracts.vesting, &msg, vec![])?;
+    Ok((message, memo))
+}
+
+fn build_message(
+    operation: Operation,
+    contracts: &Contracts,
+    denom: &str,
+) -> Option<Result<(OfflineMessage, String), serde_json::Error>> {
+    let message = match operation {
+        Operation::Delegate { mix_id, amount } => mixnet_message(
+            contracts,
+            MixnetExecuteMsg::DelegateToMixnode { mix_id },
+            vec![Coin::new(amount, denom)],
+        ),
+        Operation::Undelegate { mix_id } => mixnet_message(
+            contracts,
+            MixnetExecuteMsg::UndelegateFromMixnode { mix_id },
+            vec![],
+        ),
+        Operation::VestingDelegate { mix_id, amount } => vesting_message(
+            contracts,
+            VestingExecuteMsg::DelegateToMixnode {
+                mix_id,
+                amount: Coin::new(amount, denom).into(),
+                on_behalf_of: None,
+            },
+        ),
+        Operation::VestingUndelegate { mix_id } => vesting_message(
+            contracts,
+            VestingExecuteMsg::UndelegateFromMixnode {
+                mix_id,
+                on_behalf_of: None,
+            },
+        ),
+        Operation::BondMixnode(args) | Operation::VestingBondMixnode(args)
+            if args.amount < 1_000_000 && !args.force =>
+        {
+            warn!("You're trying to bond only {}{} which is less than 1 full token. Are you sure that's what you want? If so, run with `--force` or `-f` flag", args.amount, denom);
+            return None;
+        }
+        Operation::BondMixnode(args) => mixnet_message(
+            contracts,
+            MixnetExecuteMsg::BondMixnode {
+                mix_node: bond_mixnode::mixnode_from_args(&args),
+                cost_params: bond_mixnode::cost_params_from_args(&args, denom),
+                owner_signature: args.signature,
+            },
+            vec![Coin::new(args.amount, denom)],
+        ),
+        Operation::VestingBondMixnode(args) => vesting_message(
+            contracts,
+            VestingExecuteMsg::BondMixnode {
+                mix_node: bond_mixnode::mixnode_from_args(&args),
+                cost_params: bond_mixnode::cost_params_from_args(&args, denom),
+                owner_signature: args.signature,
+                amount: Coin::new(args.amount, denom).into(),
+            },
+        ),
+        Operation::UnbondMixnode => {
+            mixnet_message(contracts, MixnetExecuteMsg::UnbondMixnode {}, vec![])
+        }
+        Operation::VestingUnbondMixnode => {
+            vesting_message(contracts, VestingExecuteMsg::UnbondMixnode {})
+        }
+        Operation::BondGateway(args) | Operation::VestingBondGateway(args)
+            if args.amount < 1_000_000 && !args.force =>
+        {
+            warn!("You're trying to bond only {}{} which is less than 1 full token. Are you sure that's what you want? If so, run with `--force` or `-f` flag", args.amount, denom);
+            return None;
+        }
+        Operation::BondGateway(args) => mixnet_message(
+            contracts,
+            MixnetExecuteMsg::BondGateway {
+                gateway: bond_gateway::gateway_from_args(&args),
+                owner_signature: args.signature,
+            },
+            vec![Coin::new(args.amount, denom)],
+        ),
+        Operation::VestingBondGateway(args) => vesting_message(
+            contracts,
+            VestingExecuteMsg::BondGateway {
+                gateway: bond_gateway::gateway_from_args(&args),
+                owner_signature: args.signature,
+                amount: Coin::new(args.amount, denom).into(),
+            },
+        ),
+        Operation::UnbondGateway => {
+            mixnet_message(contracts, MixnetExecuteMsg::UnbondGateway {}, vec![])
+        }
+        Operation::VestingUnbondGateway => {
+            vesting_message(contracts, VestingExecuteMsg::UnbondGateway {})
+        }
+        Operation::WithdrawVested { amount } => vesting_message(
+            contracts,
+            VestingExecuteMsg::WithdrawVestedCoins {
+                amount: Coin::new(amount, denom).into(),
+            },
+        ),
+    };
+    Some(message)
+}
+
+pub async fn generate(args: Args, client: &QueryClient) {
+    let denom = client.current_chain_details().mix_denom.base.clone();
+
+    let contracts = Contracts {
+        mixnet: client.mixnet_contract_address(),
+        vesting: client.vesting_contract_address(),
+    };
+    let (message, default_memo) = match build_message(args.operation, &contracts, &denom) {
+        Some(Ok(message)) => message,
+        Some(Err(err)) => return show_error(err),
+        None => return,
+    };
+
+    info!("Retrieving account details of {}...", args.signer);
+    let sequence = match client.get_account_sequence(&args.signer).await {
+        Ok(sequence) => sequence,
+        Err(err) => return show_error(err),
+    };
+    let chain_id = match client.get_chain_id().await {
+        Ok(chain_id) => chain_id,
+        Err(err) => return show_error(err),
+    };
+
+    let gas_limit = args.gas.unwrap_or(DEFAULT_GAS_LIMIT);
+    let fee_amount = match args.fee {
+        Some(fee) => Coin::new(fee, &denom),
+        None => match GasPrice::new_with_default_price(&denom) {
+            Ok(gas_price) => (&gas_price * Gas::from(gas_limit)).into(),
+            Err(err) => return show_error(err),
+        },
+    };
+
+    let unsigned = UnsignedTransaction {
+        chain_id: chain_id.to_string(),
+        signer_address: args.signer.to_string(),
+        account_number: sequence.account_number,
+        sequence: sequence.sequence,
+        fee: OfflineFee {
+            amount: vec![fee_amount],
+            gas_limit,
+        },
+        memo: args.memo.unwrap_or(default_memo),
+        messages: vec![message],
+    };
+
+    if let Err(err) = write_json_output(&unsigned, args.output.as_deref()) {
+        show_error(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmrs::cosmwasm::MsgExecuteContract;
+    use cosmrs::tx::Msg;
+    use nym_contracts_common::signing::MessageSignature;
+
+    const DENOM: &str = "unym";
+
+    fn test_address(byte: u8) -> AccountId {
+        AccountId::new("n", &[byte; 20]).unwrap()
+    }
+
+    fn mixnet_contract() -> AccountId {
+        test_address(2)
+    }
+
+    fn vesting_contract() -> AccountId {
+        test_address(3)
+    }
+
+    fn mixnode_args(amount: u128) -> bond_mixnode::Args {
+        bond_mixnode::Args {
+            host: "1.2.3.4".to_string(),
+            signature: MessageSignature::from(vec![42u8; 64]),
+            mix_port: None,
+            verloc_port: None,
+            http_api_port: None,
+            sphinx_key: "sphinx".to_string(),
+            identity_key: "identity".to_string(),
+            version: "1.1.23".to_string(),
+            profit_margin_percent: None,
+            interval_operating_cost: None,
+            amount,
+            force: false,
+        }
+    }
+
+    fn gateway_args(amount: u128) -> bond_gateway::Args {
+        bond_gateway::Args {
+            host: "1.2.3.4".to_string(),
+            signature: MessageSignature::from(vec![42u8; 64]),
+            mix_port: None,
+            clients_port: None,
+            clients_wss_port: Some(9001),
+            location: "Earth".to_string(),
+            sphinx_key: "sphinx".to_string(),
+            identity_key: "identity".to_string(),
+            version: "1.1.23".to_string(),
+            amount,
+            force: false,
+        }
+    }
+
+    fn execute(operation: Operation) -> MsgExecuteContract {
+        let mixnet = mixnet_contract();
+        let vesting = vesting_contract();
+        let contracts = Contracts {
+            mixnet: &mixnet,
+            vesting: &vesting,
+        };
+
+        let (message, _) = build_message(operation, &contracts, DENOM)
+            .expect("the message should have been built")
+            .unwrap();
+        let any = message.to_any(&test_address(1)).unwrap();
+        MsgExecuteContract::from_any(&any).unwrap()
+    }
+
+    fn mixnet_msg(operation: Operation, funds: Option<u128>) -> MixnetExecuteMsg {
+        let execute = execute(operation);
+        assert_eq!(execute.contract, mixnet_contract());
+        assert_eq!(
+            execute
+                .funds
+                .into_iter()
+                .map(|coin| Coin::from(coin).amount)
+                .collect::<Vec<_>>(),
+            funds.into_iter().collect::<Vec<_>>()
+        );
+        serde_json::from_slice(&execute.msg).unwrap()
+    }
+
+    fn vesting_msg(operation: Operation) -> VestingExecuteMsg {
+        let execute = execute(operation);
+        assert_eq!(execute.contract, vesting_contract());
+        assert!(execute.funds.is_empty());
+        serde_json::from_slice(&execute.msg).unwrap()
+    }
+
+    #[test]
+    fn delegation_messages() {
+        assert_eq!(
+            mixnet_msg(
+                Operation::Delegate {
+                    mix_id: 123,
+                    amount: 100
+                },
+                Some(100)
+            ),
+            MixnetExecuteMsg::DelegateToMixnode { mix_id: 123 }
+        );
+        assert_eq!(
+            mixnet_msg(Operation::Undelegate { mix_id: 123 }, None),
+            MixnetExecuteMsg::UndelegateFromMixnode { mix_id: 123 }
+        );
+        assert_eq!(
+            vesting_msg(Operation::VestingDelegate {
+                mix_id: 123,
+                amount: 100
+            }),
+            VestingExecuteMsg::DelegateToMixnode {
+                mix_id: 123,
+                amount: Coin::new(100, DENOM).into(),
+                on_behalf_of: None,
+            }
+        );
+        assert_eq!(
+            vesting_msg(Operation::VestingUndelegate { mix_id: 123 }),
+            VestingExecuteMsg::UndelegateFromMixnode {
+                mix_id: 123,
+                on_behalf_of: None,
+            }
+        );
+    }
+
+    #[test]
+    fn mixnode_messages() {
+        let args = mixnode_args(100_000_000);
+        assert_eq!(
+            mixnet_msg(
+                Operation::BondMixnode(mixnode_args(100_000_000)),
+                Some(100_000_000)
+            ),
+            MixnetExecuteMsg::BondMixnode {
+                mix_node: bond_mixnode::mixnode_from_args(&args),
+                cost_params: bond_mixnode::cost_params_from_args(&args, DENOM),
+                owner_signature: args.signature.clone(),
+            }
+        );
+        assert_eq!(
+            vesting_msg(Operation::VestingBondMixnode(mixnode_args(100_000_000))),
+            VestingExecuteMsg::BondMixnode {
+                mix_node: bond_mixnode::mixnode_from_args(&args),
+                cost_params: bond_mixnode::cost_params_from_args(&args, DENOM),
+                owner_signature: args.signature.clone(),
+                amount: Coin::new(100_000_000, DENOM).into(),
+            }
+        );
+        assert_eq!(
+            mixnet_msg(Operation::UnbondMixnode, None),
+            MixnetExecuteMsg::UnbondMixnode {}
+        );
+        assert_eq!(
+            vesting_msg(Operation::VestingUnbondMixnode),
+            VestingExecuteMsg::UnbondMixnode {}
+        );
+    }
+
+    #[test]
+    fn gateway_messages() {
+        let args = gateway_args(100_000_000);
+        assert_eq!(
+            mixnet_msg(
+                Operation::BondGateway(gateway_args(100_000_000)),
+                Some(100_000_000)
+            ),
+            MixnetExecuteMsg::BondGateway {
+                gateway: bond_gateway::gateway_from_args(&args),
+                owner_signature: args.signature.clone(),
+            }
+        );
+        assert_eq!(
+            vesting_msg(Operation::VestingBondGateway(gateway_args(100_000_000))),
+            VestingExecuteMsg::BondGateway {
+                gateway: bond_gateway::gateway_from_args(&args),
+                owner_signature: args.signature.clone(),
+                amount: Coin::new(100_000_000, DENOM).into(),
+            }
+        );
+        assert_eq!(
+            mixnet_msg(Operation::UnbondGateway, None),
+            MixnetExecuteMsg::UnbondGateway {}
+        );
+        assert_eq!(
+            vesting_msg(Operation::VestingUnbondGateway),
+            VestingExecuteMsg::UnbondGateway {}
+        );
+    }
+
+    #[test]
+    fn withdraw_vested_message() {
+        assert_eq!(
+            vesting_msg(Operation::WithdrawVested { amount: 100 }),
+            VestingExecuteMsg::WithdrawVestedCoins {
+                amount: Coin::new(100, DENOM).into(),
+            }
+        );
+    }
+
+    #[test]
+    fn bonding_less_than_a_token_requires_force() {
+        let contracts = Contracts {
+            mixnet: &mixnet_contract(),
+            vesting: &vesting_contract(),
+        };
+        assert!(build_message(
+            Operation::BondMixnode(mixnode_args(1000)),
+            &contracts,
+            DENOM
+        )
+        .is_none());
+        assert!(build_message(
+            Operation::VestingBondGateway(gateway_args(1000)),
+            &contracts,
+            DENOM
+        )
+        .is_none());
+
+        let mut args = gateway_args(1000);
+        args.force = true;
+        assert!(build_message(Operation::BondGateway(args), &contracts, DENOM).is_some());
+    }
+}
@@ -3,8 +3,12 @@
 
 use clap::{Args, Subcommand};
 
+pub mod broadcast_transaction;
+pub mod generate_transaction;
 pub mod get_transaction;
+pub mod offline;
 pub mod query_transactions;
+pub mod sign_transaction;
 
 #[derive(Debug, Args)]
 #[clap(args_conflicts_with_subcommands = true, subcommand_required = true)]
@@ -19,4 +23,10 @@ pub enum TransactionsCommands {
     Get(crate::validator::transactions::get_transaction::Args),
     /// Query for transactions
     Query(crate::validator::transactions::query_transactions::Args),
+    /// Generate an unsigned transaction, to be signed offline
+    Generate(crate::validator::transactions::generate_transaction::Args),
+    /// Sign a transaction generated with `generate`, without connecting to the chain
+    Sign(crate::validator::transactions::sign_transaction::Args),
+    /// Broadcast a transaction signed with `sign`
+    Broadcast(crate::validator::transactions::broadcast_transaction::Args),
 }
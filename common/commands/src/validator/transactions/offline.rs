@@ -0,0 +1,238 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types exchanged between the online machine that generates and broadcasts transactions
+//! and the air-gapped machine that holds the keys and signs them.
+
+use cosmrs::tx::{self, Msg};
+use cosmrs::{cosmwasm, AccountId, Any};
+use nym_validator_client::nyxd::Coin;
+use nym_validator_client::signing::direct_wallet::DirectSecp256k1HdWalletError;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum OfflineTransactionError {
+    #[error("failed to access the transaction file - {0}")]
+    IoError(#[from] std::io::Error),
+
+    #[error("failed to (de)serialize the transaction - {0}")]
+    SerdeJsonError(#[from] serde_json::Error),
+
+    #[error("{address} is not a valid address")]
+    MalformedAddress { address: String },
+
+    #[error("failed to serialize {typ}")]
+    SerializationError { typ: String },
+
+    #[error("{chain_id} is not a valid chain id")]
+    MalformedChainId { chain_id: String },
+
+    #[error("failed to sign the transaction as {signer}. Is the mnemonic correct? {source}")]
+    SigningFailure {
+        signer: String,
+        source: DirectSecp256k1HdWalletError,
+    },
+
+    #[error("the signed transaction bytes are not valid base64 - {0}")]
+    MalformedTransactionBytes(#[from] base64::DecodeError),
+}
+
+/// Message included in an unsigned transaction. It's kept in its human-readable form
+/// so that it could be inspected on the signing machine before being signed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum OfflineMessage {
+    ExecuteContract {
+        contract: String,
+        msg: serde_json::Value,
+        funds: Vec<Coin>,
+    },
+}
+
+impl OfflineMessage {
+    pub fn new_execute<M: Serialize>(
+        contract: &AccountId,
+        msg: &M,
+        funds: Vec<Coin>,
+    ) -> Result<Self, serde_json::Error> {
+        Ok(OfflineMessage::ExecuteContract {
+            contract: contract.to_string(),
+            msg: serde_json::to_value(msg)?,
+            funds,
+        })
+    }
+
+    pub fn to_any(&self, sender: &AccountId) -> Result<Any, OfflineTransactionError> {
+        match self {
+            OfflineMessage::ExecuteContract {
+                contract,
+                msg,
+                funds,
+            } => {
+                let execute = cosmwasm::MsgExecuteContract {
+                    sender: sender.clone(),
+                    contract: parse_address(contract)?,
+                    msg: serde_json::to_vec(msg)?,
+                    funds: funds.iter().cloned().map(Into::into).collect(),
+                };
+                execute
+                    .to_any()
+                    .map_err(|_| OfflineTransactionError::SerializationError {
+                        typ: "MsgExecuteContract".to_owned(),
+                    })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OfflineFee {
+    pub amount: Vec<Coin>,
+    pub gas_limit: u64,
+}
+
+impl From<OfflineFee> for tx::Fee {
+    fn from(fee: OfflineFee) -> Self {
+        tx::Fee {
+            amount: fee.amount.into_iter().map(Into::into).collect(),
+            gas_limit: fee.gas_limit.into(),
+            payer: None,
+            granter: None,
+        }
+    }
+}
+
+/// Transaction produced by `tx generate`, containing everything required for signing it
+/// without access to the chain.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnsignedTransaction {
+    pub chain_id: String,
+    pub signer_address: String,
+    pub account_number: u64,
+    pub sequence: u64,
+    pub fee: OfflineFee,
+    pub memo: String,
+    pub messages: Vec<OfflineMessage>,
+}
+
+/// Transaction produced by `tx sign`, ready to be broadcast by `tx broadcast`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignedTransaction {
+    pub chain_id: String,
+    pub signer_address: String,
+    /// base64-encoded raw transaction bytes
+    pub tx_bytes: String,
+}
+
+impl SignedTransaction {
+    pub fn decode_tx_bytes(&self) -> Result<Vec<u8>, OfflineTransactionError> {
+        Ok(base64::decode(&self.tx_bytes)?)
+    }
+}
+
+pub fn parse_address(address: &str) -> Result<AccountId, OfflineTransactionError> {
+    address
+        .parse()
+        .map_err(|_| OfflineTransactionError::MalformedAddress {
+            address: address.to_owned(),
+        })
+}
+
+pub fn read_json_file<T: for<'a> Deserialize<'a>>(
+    path: &Path,
+) -> Result<T, OfflineTransactionError> {
+    let content = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// Write the value to the specified file or, if none was provided, to stdout.
+pub fn write_json_output<T: Serialize>(
+    value: &T,
+    output: Option<&Path>,
+) -> Result<(), OfflineTransactionError> {
+    let json = serde_json::to_string_pretty(value)?;
+    match output {
+        Some(path) => {
+            fs::write(path, json)?;
+            eprintln!("Saved the transaction to {}", path.display());
+        }
+        None => println!("{json}"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_address(byte: u8) -> AccountId {
+        AccountId::new("n", &[byte; 20]).unwrap()
+    }
+
+    #[test]
+    fn unsigned_transaction_survives_json_round_trip() {
+        let unsigned = UnsignedTransaction {
+            chain_id: "nyx".to_string(),
+            signer_address: test_address(1).to_string(),
+            account_number: 42,
+            sequence: 7,
+            fee: OfflineFee {
+                amount: vec![Coin::new(5000, "unym")],
+                gas_limit: 200_000,
+            },
+            memo: "foomp".to_string(),
+            messages: vec![OfflineMessage::new_execute(
+                &test_address(2),
+                &serde_json::json!({ "delegate_to_mixnode": { "mix_id": 123 } }),
+                vec![Coin::new(100, "unym")],
+            )
+            .unwrap()],
+        };
+
+        let json = serde_json::to_string_pretty(&unsigned).unwrap();
+        let recovered: UnsignedTransaction = serde_json::from_str(&json).unwrap();
+        assert_eq!(unsigned, recovered);
+    }
+
+    #[test]
+    fn execute_message_is_converted_to_any() {
+        let sender = test_address(1);
+        let contract = test_address(2);
+        let msg = serde_json::json!({ "unbond_mixnode": {} });
+        let message =
+            OfflineMessage::new_execute(&contract, &msg, vec![Coin::new(100, "unym")]).unwrap();
+
+        let any = message.to_any(&sender).unwrap();
+        let execute = cosmwasm::MsgExecuteContract::from_any(&any).unwrap();
+        assert_eq!(execute.sender, sender);
+        assert_eq!(execute.contract, contract);
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&execute.msg).unwrap(),
+            msg
+        );
+        assert_eq!(
+            execute
+                .funds
+                .into_iter()
+                .map(Coin::from)
+                .collect::<Vec<_>>(),
+            vec![Coin::new(100, "unym")]
+        );
+    }
+
+    #[test]
+    fn malformed_contract_address_is_rejected() {
+        let message = OfflineMessage::ExecuteContract {
+            contract: "foomp".to_string(),
+            msg: serde_json::json!({}),
+            funds: vec![],
+        };
+        assert!(matches!(
+            message.to_any(&test_address(1)),
+            Err(OfflineTransactionError::MalformedAddress { .. })
+        ));
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::utils::show_error;
+use crate::validator::transactions::offline::{
+    parse_address, read_json_file, write_json_output, OfflineTransactionError, SignedTransaction,
+    UnsignedTransaction,
+};
+use clap::Parser;
+use log::{error, info};
+use nym_validator_client::nyxd::chain;
+use nym_validator_client::signing::direct_wallet::DirectSecp256k1HdWallet;
+use nym_validator_client::signing::tx_signer::TxSigner;
+use nym_validator_client::signing::SignerData;
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+pub struct Args {
+    #[clap(value_parser)]
+    #[clap(help = "Path to the unsigned transaction created with `tx generate`")]
+    pub input: PathBuf,
+
+    #[clap(short, long, help = "File to save the signed transaction to")]
+    pub output: Option<PathBuf>,
+}
+
+pub fn sign(args: Args, mnemonic: Option<bip39::Mnemonic>) {
+    let Some(mnemonic) = mnemonic else {
+        error!(
+            "Please provide the mnemonic as an argument or using the MNEMONIC environment variable"
+        );
+        return;
+    };
+
+    let unsigned: UnsignedTransaction = match read_json_file(&args.input) {
+        Ok(unsigned) => unsigned,
+        Err(err) => return show_error(err),
+    };
+    let signed = match sign_transaction(unsigned, mnemonic) {
+        Ok(signed) => signed,
+        Err(err) => return show_error(err),
+    };
+
+    if let Err(err) = write_json_output(&signed, args.output.as_deref()) {
+        show_error(err)
+    }
+}
+
+/// Signs the provided transaction with the key derived from the mnemonic.
+pub fn sign_transaction(
+    unsigned: UnsignedTransaction,
+    mnemonic: bip39::Mnemonic,
+) -> Result<SignedTransaction, OfflineTransactionError> {
+    let signer_address = parse_address(&unsigned.signer_address)?;
+    let chain_id = unsigned.chain_id.parse::<chain::Id>().map_err(|_| {
+        OfflineTransactionError::MalformedChainId {
+            chain_id: unsigned.chain_id.clone(),
+        }
+    })?;
+
+    let mut messages = Vec::with_capacity(unsigned.messages.len());
+    for message in &unsigned.messages {
+        info!(
+            "Signing message: {}",
+            serde_json::to_string(message).unwrap_or_default()
+        );
+        messages.push(message.to_any(&signer_address)?);
+    }
+
+    let wallet = DirectSecp256k1HdWallet::from_mnemonic(signer_address.prefix(), mnemonic);
+    let signer_data = SignerData::new(unsigned.account_number, unsigned.sequence, chain_id);
+    let tx_raw = TxSigner::new(wallet)
+        .sign_direct(
+            &signer_address,
+            messages,
+            unsigned.fee.into(),
+            unsigned.memo,
+            signer_data,
+        )
+        .map_err(|source| OfflineTransactionError::SigningFailure {
+            signer: signer_address.to_string(),
+            source,
+        })?;
+    let tx_bytes = tx_raw
+        .to_bytes()
+        .map_err(|_| OfflineTransactionError::SerializationError {
+            typ: "TxRaw".to_owned(),
+        })?;
+
+    Ok(SignedTransaction {
+        chain_id: unsigned.chain_id,
+        signer_address: unsigned.signer_address,
+        tx_bytes: base64::encode(tx_bytes),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::transactions::offline::{OfflineFee, OfflineMessage};
+    use cosmrs::cosmwasm::MsgExecuteContract;
+    use cosmrs::tx::{Msg, Raw};
+    use cosmrs::{AccountId, Tx};
+    use nym_validator_client::nyxd::Coin;
+
+    // BIP39 test vectors
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    const OTHER_MNEMONIC: &str =
+        "legal winner thank year wave sausage worth useful legal winner thank yellow";
+
+    fn mnemonic(raw: &str) -> bip39::Mnemonic {
+        bip39::Mnemonic::parse(raw).unwrap()
+    }
+
+    fn signer_address() -> AccountId {
+        DirectSecp256k1HdWallet::from_mnemonic("n", mnemonic(MNEMONIC))
+            .try_derive_accounts()
+            .unwrap()[0]
+            .address()
+            .clone()
+    }
+
+    fn unsigned_transaction() -> UnsignedTransaction {
+        let contract = AccountId::new("n", &[2u8; 20]).unwrap();
+        UnsignedTransaction {
+            chain_id: "nyx".to_string(),
+            signer_address: signer_address().to_string(),
+            account_number: 42,
+            sequence: 7,
+            fee: OfflineFee {
+                amount: vec![Coin::new(5000, "unym")],
+                gas_limit: 200_000,
+            },
+            memo: "foomp".to_string(),
+            messages: vec![OfflineMessage::new_execute(
+                &contract,
+                &serde_json::json!({ "unbond_mixnode": {} }),
+                vec![],
+            )
+            .unwrap()],
+        }
+    }
+
+    #[test]
+    fn signed_transaction_can_be_decoded() {
+        let unsigned = unsigned_transaction();
+        let signed = sign_transaction(unsigned.clone(), mnemonic(MNEMONIC)).unwrap();
+        assert_eq!(signed.chain_id, unsigned.chain_id);
+        assert_eq!(signed.signer_address, unsigned.signer_address);
+
+        let tx_bytes = signed.decode_tx_bytes().unwrap();
+        let raw = Raw::from_bytes(&tx_bytes).unwrap();
+        assert_eq!(raw.to_bytes().unwrap(), tx_bytes);
+
+        let tx = Tx::from_bytes(&tx_bytes).unwrap();
+        assert_eq!(tx.body.memo, unsigned.memo);
+        assert_eq!(tx.body.messages.len(), 1);
+        assert_eq!(
+            tx.body.messages[0],
+            unsigned.messages[0].to_any(&signer_address()).unwrap()
+        );
+        let execute = MsgExecuteContract::from_any(&tx.body.messages[0]).unwrap();
+        assert_eq!(execute.sender, signer_address());
+
+        assert_eq!(tx.auth_info.signer_infos.len(), 1);
+        assert_eq!(tx.auth_info.signer_infos[0].sequence, unsigned.sequence);
+        assert_eq!(tx.signatures.len(), 1);
+    }
+
+    #[test]
+    fn signing_with_a_different_mnemonic_fails() {
+        assert!(matches!(
+            sign_transaction(unsigned_transaction(), mnemonic(OTHER_MNEMONIC)),
+            Err(OfflineTransactionError::SigningFailure { .. })
+        ));
+    }
+
+    #[test]
+    fn malformed_signer_address_is_rejected() {
+        let mut unsigned = unsigned_transaction();
+        unsigned.signer_address = "foomp".to_string();
+        assert!(matches!(
+            sign_transaction(unsigned, mnemonic(MNEMONIC)),
+            Err(OfflineTransactionError::MalformedAddress { .. })
+        ));
+    }
+}
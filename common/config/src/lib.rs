@@ -15,6 +15,8 @@ pub use toml::de::Error as TomlDeError;
 pub mod defaults;
 pub mod helpers;
 pub mod legacy_helpers;
+#[cfg(feature = "secrets")]
+pub mod secrets;
 
 pub const NYM_DIR: &str = ".nym";
 pub const DEFAULT_CONFIG_DIR: &str = "config";
@@ -0,0 +1,319 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers for keeping secrets, such as cosmos mnemonics, outside of the main config files.
+//!
+//! A secret is resolved from the following sources, in order:
+//! 1. an environment variable containing the secret itself,
+//! 2. an environment variable containing a path to a (plaintext) file with the secret,
+//! 3. a secrets file, encrypted with a passphrase provided via yet another environment variable.
+//!
+//! The passphrase is mandatory whenever the secrets file has to be used.
+
+use nym_store_cipher::{Aes256Gcm, EncryptedData, KdfInfo, StoreCipher};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use zeroize::Zeroizing;
+
+#[derive(Debug, Error)]
+pub enum SecretsError {
+    #[error("failed to read the secret from {path}: {source}")]
+    ReadFailure {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("failed to write the secret to {path}: {source}")]
+    WriteFailure {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("the secrets file at {path} is malformed: {source}")]
+    MalformedSecretsFile {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("failed to encrypt or decrypt the secret (is the passphrase correct?): {source}")]
+    CipherFailure {
+        #[from]
+        source: nym_store_cipher::Error,
+    },
+
+    #[error("the decrypted secret is not valid utf8")]
+    MalformedSecret,
+
+    #[error("the passphrase for the secrets file has not been provided. Please set the '{env_var}' environment variable")]
+    MissingPassphrase { env_var: &'static str },
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredSecret {
+    kdf_info: KdfInfo,
+    secret: EncryptedData,
+}
+
+/// Names of the environment variables that can be used for providing a particular secret.
+#[derive(Debug, Clone, Copy)]
+pub struct SecretEnvVars {
+    /// Variable containing the secret itself.
+    pub value: &'static str,
+
+    /// Variable containing path to a plaintext file with the secret.
+    pub file: &'static str,
+
+    /// Variable containing the passphrase used for encrypting the secrets file.
+    pub passphrase: &'static str,
+}
+
+impl SecretEnvVars {
+    fn passphrase(&self) -> Result<Zeroizing<String>, SecretsError> {
+        match std::env::var(self.passphrase) {
+            Ok(passphrase) if !passphrase.is_empty() => Ok(Zeroizing::new(passphrase)),
+            _ => Err(SecretsError::MissingPassphrase {
+                env_var: self.passphrase,
+            }),
+        }
+    }
+
+    /// Attempts to resolve the secret from the environment, falling back to the encrypted
+    /// secrets file at the provided path.
+    pub fn load_secret<P: AsRef<Path>>(
+        &self,
+        secrets_file: P,
+    ) -> Result<Zeroizing<String>, SecretsError> {
+        if let Ok(secret) = std::env::var(self.value) {
+            return Ok(Zeroizing::new(secret.trim().to_string()));
+        }
+
+        if let Some(path) = std::env::var_os(self.file) {
+            let path = PathBuf::from(path);
+            let raw = Zeroizing::new(
+                fs::read_to_string(&path)
+                    .map_err(|source| SecretsError::ReadFailure { path, source })?,
+            );
+            return Ok(Zeroizing::new(raw.trim().to_string()));
+        }
+
+        load_encrypted_secret(secrets_file, self.passphrase()?.as_bytes())
+    }
+
+    /// Encrypts the secret with the passphrase from the environment and saves it at the provided path.
+    /// The file is left untouched if it already contains the same secret.
+    pub fn store_secret<P: AsRef<Path>>(
+        &self,
+        secrets_file: P,
+        secret: &str,
+    ) -> Result<(), SecretsError> {
+        let passphrase = self.passphrase()?;
+        if let Ok(existing) = load_encrypted_secret(&secrets_file, passphrase.as_bytes()) {
+            if existing.as_str() == secret {
+                log::debug!("the stored secret has not changed");
+                return Ok(());
+            }
+        }
+
+        store_encrypted_secret(secrets_file, secret, passphrase.as_bytes())
+    }
+}
+
+pub fn load_encrypted_secret<P: AsRef<Path>>(
+    path: P,
+    passphrase: &[u8],
+) -> Result<Zeroizing<String>, SecretsError> {
+    let path = path.as_ref();
+    log::trace!("trying to read secrets file from {}", path.display());
+
+    let file = File::open(path).map_err(|source| SecretsError::ReadFailure {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let stored: StoredSecret =
+        serde_json::from_reader(file).map_err(|source| SecretsError::MalformedSecretsFile {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+    let cipher = StoreCipher::<Aes256Gcm>::new(passphrase, stored.kdf_info)?;
+    let plaintext = cipher.decrypt_data(stored.secret)?;
+    String::from_utf8(plaintext)
+        .map(Zeroizing::new)
+        .map_err(|err| {
+            // make sure to not leave the plaintext bytes around
+            drop(Zeroizing::new(err.into_bytes()));
+            SecretsError::MalformedSecret
+        })
+}
+
+pub fn store_encrypted_secret<P: AsRef<Path>>(
+    path: P,
+    secret: &str,
+    passphrase: &[u8],
+) -> Result<(), SecretsError> {
+    let path = path.as_ref();
+    log::trace!("trying to save secrets file to {}", path.display());
+
+    let kdf_info = KdfInfo::new_with_default_settings()?;
+    let cipher = StoreCipher::<Aes256Gcm>::new(passphrase, kdf_info.clone())?;
+    let stored = StoredSecret {
+        kdf_info,
+        secret: cipher.encrypt_data(secret.as_bytes().to_vec())?,
+    };
+
+    let write_err = |source: io::Error| SecretsError::WriteFailure {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(write_err)?;
+    }
+    let file = File::create(path).map_err(write_err)?;
+
+    #[cfg(target_family = "unix")]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mut perms = file.metadata().map_err(write_err)?.permissions();
+        perms.set_mode(0o600);
+        file.set_permissions(perms).map_err(write_err)?;
+    }
+
+    serde_json::to_writer(file, &stored).map_err(|err| write_err(err.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // every test uses its own set of variables so that they wouldn't interfere with each other
+    const fn env_vars(
+        value: &'static str,
+        file: &'static str,
+        passphrase: &'static str,
+    ) -> SecretEnvVars {
+        SecretEnvVars {
+            value,
+            file,
+            passphrase,
+        }
+    }
+
+    #[test]
+    fn encrypted_secret_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("secret.json");
+
+        store_encrypted_secret(&path, "my secret", b"passphrase").unwrap();
+        let raw = fs::read_to_string(&path).unwrap();
+        assert!(!raw.contains("my secret"));
+
+        let secret = load_encrypted_secret(&path, b"passphrase").unwrap();
+        assert_eq!(secret.as_str(), "my secret");
+
+        #[cfg(target_family = "unix")]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn loading_with_wrong_passphrase_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secret.json");
+
+        store_encrypted_secret(&path, "my secret", b"passphrase").unwrap();
+        assert!(matches!(
+            load_encrypted_secret(&path, b"wrong passphrase"),
+            Err(SecretsError::CipherFailure { .. })
+        ));
+    }
+
+    #[test]
+    fn missing_passphrase_is_rejected() {
+        let vars = env_vars(
+            "NYM_TEST_MISSING_SECRET",
+            "NYM_TEST_MISSING_SECRET_FILE",
+            "NYM_TEST_MISSING_PASSPHRASE",
+        );
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secret.json");
+
+        assert!(matches!(
+            vars.store_secret(&path, "my secret"),
+            Err(SecretsError::MissingPassphrase { env_var }) if env_var == vars.passphrase
+        ));
+        assert!(!path.exists());
+
+        std::env::set_var(vars.passphrase, "");
+        assert!(matches!(
+            vars.load_secret(&path),
+            Err(SecretsError::MissingPassphrase { .. })
+        ));
+    }
+
+    #[test]
+    fn unchanged_secret_is_not_rewritten() {
+        let vars = env_vars(
+            "NYM_TEST_UNCHANGED_SECRET",
+            "NYM_TEST_UNCHANGED_SECRET_FILE",
+            "NYM_TEST_UNCHANGED_PASSPHRASE",
+        );
+        std::env::set_var(vars.passphrase, "passphrase");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secret.json");
+
+        vars.store_secret(&path, "my secret").unwrap();
+        let original = fs::read(&path).unwrap();
+
+        // every write uses fresh salt and nonce, so the content would have changed
+        vars.store_secret(&path, "my secret").unwrap();
+        assert_eq!(fs::read(&path).unwrap(), original);
+
+        vars.store_secret(&path, "new secret").unwrap();
+        assert_ne!(fs::read(&path).unwrap(), original);
+        assert_eq!(vars.load_secret(&path).unwrap().as_str(), "new secret");
+    }
+
+    #[test]
+    fn environment_takes_precedence_over_secrets_file() {
+        let vars = env_vars(
+            "NYM_TEST_PRECEDENCE_SECRET",
+            "NYM_TEST_PRECEDENCE_SECRET_FILE",
+            "NYM_TEST_PRECEDENCE_PASSPHRASE",
+        );
+        std::env::set_var(vars.passphrase, "passphrase");
+        let dir = tempfile::tempdir().unwrap();
+        let secrets_file = dir.path().join("secret.json");
+        let plaintext_file = dir.path().join("plaintext");
+
+        vars.store_secret(&secrets_file, "from secrets file")
+            .unwrap();
+        assert_eq!(
+            vars.load_secret(&secrets_file).unwrap().as_str(),
+            "from secrets file"
+        );
+
+        fs::write(&plaintext_file, "from plaintext file\n").unwrap();
+        std::env::set_var(vars.file, &plaintext_file);
+        assert_eq!(
+            vars.load_secret(&secrets_file).unwrap().as_str(),
+            "from plaintext file"
+        );
+
+        std::env::set_var(vars.value, " from variable ");
+        assert_eq!(
+            vars.load_secret(&secrets_file).unwrap().as_str(),
+            "from variable"
+        );
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright 2022-2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{EpochEventId, EpochState, IdentityKey, MixId};
+use crate::{EpochEventId, EpochId, EpochState, IdentityKey, MixId, Percent};
 use contracts_common::signing::verifier::ApiVerifierError;
 use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
 use thiserror::Error;
@@ -61,6 +61,12 @@ pub enum MixnetContractError {
     #[error("Gateway with this identity already exists. Its owner is {owner}")]
     DuplicateGateway { owner: Addr },
 
+    #[error("Bonds created with tokens from the vesting contract can't be transferred to a different owner")]
+    VestingBondOwnershipTransfer,
+
+    #[error("There is no matching pending ownership transfer of this node")]
+    NoPendingOwnershipTransfer,
+
     #[error("Unauthorized")]
     Unauthorized,
 
@@ -123,6 +129,18 @@ pub enum MixnetContractError {
     #[error("Mixnode {mix_id} is currently in the process of unbonding")]
     MixnodeIsUnbonding { mix_id: MixId },
 
+    #[error("Mixnode {mix_id} is jailed until epoch {released_at_epoch}")]
+    MixnodeJailed {
+        mix_id: MixId,
+        released_at_epoch: EpochId,
+    },
+
+    #[error("Mixnode can't be jailed for zero epochs")]
+    ZeroJailDuration,
+
+    #[error("Mixnode can't be slashed by {fraction} as the operator would have been left with zero pledge")]
+    InvalidSlashFraction { fraction: Percent },
+
     #[error("Mixnode {mix_id} has already unbonded")]
     MixnodeHasUnbonded { mix_id: MixId },
 
@@ -207,6 +225,21 @@ pub enum MixnetContractError {
     #[error("attempted to perform the operation with 0 coins. This is not allowed")]
     ZeroCoinAmount,
 
+    #[error("attempted to redelegate tokens from mixnode {mix_id} back to itself")]
+    RedelegationToSameMixnode { mix_id: MixId },
+
+    #[error(
+        "attempted to redelegate {requested} while the existing delegation is only {available}"
+    )]
+    RedelegationExceedsDelegation { requested: Coin, available: Coin },
+
+    #[error("attempted to redelegate {requested} which would leave {remaining} behind, below the minimum delegation of {minimum}. Either redelegate the whole amount or leave at least the minimum")]
+    RedelegationRemainderBelowMinimum {
+        requested: Coin,
+        remaining: Coin,
+        minimum: Coin,
+    },
+
     #[error("this validator ({current_validator}) is not the one responsible for advancing this epoch. It's responsibility of {chosen_validator}.")]
     RewardingValidatorMismatch {
         current_validator: Addr,
@@ -231,6 +264,9 @@ pub enum MixnetContractError {
     #[error("the epoch is currently not in the 'epoch advancement' state. (the state is {current_state})")]
     EpochNotInAdvancementState { current_state: EpochState },
 
+    #[error("the epoch transition has not yet begun. (the state is {current_state})")]
+    EpochTransitionNotStarted { current_state: EpochState },
+
     #[error("failed to parse {value} into a valid SemVer version: {error_message}")]
     SemVerFailure {
         value: String,
@@ -2,10 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::gateway::GatewayConfigUpdate;
+use crate::jailing::MisbehaviourReport;
 use crate::mixnode::{MixNodeConfigUpdate, MixNodeCostParams};
 use crate::reward_params::{IntervalRewardParams, IntervalRewardingParamsUpdate};
 use crate::rewarding::RewardDistribution;
-use crate::{BlockHeight, ContractStateParams, IdentityKeyRef, Interval, Layer, MixId};
+use crate::{
+    BlockHeight, ContractStateParams, EpochId, IdentityKeyRef, Interval, Layer, MixId, Percent,
+};
 pub use contracts_common::events::*;
 use cosmwasm_std::{Addr, Coin, Decimal, Event};
 
@@ -27,6 +30,12 @@ pub enum MixnetEventType {
     MixnodeRewarding,
     WithdrawDelegatorReward,
     WithdrawOperatorReward,
+    GatewayRewarding,
+    WithdrawGatewayOperatorReward,
+    RewardCompoundingUpdate,
+    CompoundDelegatorReward,
+    CompoundOperatorReward,
+    ExecuteRewardCompounding,
     PendingActiveSetUpdate,
     ActiveSetUpdate,
     PendingIntervalRewardingParamsUpdate,
@@ -36,6 +45,8 @@ pub enum MixnetEventType {
     Delegation,
     DelegationOnUnbonding,
     Undelegation,
+    PendingRedelegation,
+    Redelegation,
     ContractSettingsUpdate,
     RewardingValidatorUpdate,
     BeginEpochTransition,
@@ -46,6 +57,15 @@ pub enum MixnetEventType {
     PendingIntervalConfigUpdate,
     IntervalConfigUpdate,
     GatewayConfigUpdate,
+    MixnodeOwnershipTransferProposal,
+    MixnodeOwnershipTransferCancellation,
+    MixnodeOwnershipTransfer,
+    GatewayOwnershipTransferProposal,
+    GatewayOwnershipTransferCancellation,
+    GatewayOwnershipTransfer,
+    MixnodeJailing,
+    PendingMixnodeSlashing,
+    MixnodeSlashing,
 }
 
 impl From<MixnetEventType> for String {
@@ -72,6 +92,12 @@ impl ToString for MixnetEventType {
             MixnetEventType::MixnodeRewarding => "mix_rewarding",
             MixnetEventType::WithdrawDelegatorReward => "withdraw_delegator_reward",
             MixnetEventType::WithdrawOperatorReward => "withdraw_operator_reward",
+            MixnetEventType::GatewayRewarding => "gateway_rewarding",
+            MixnetEventType::WithdrawGatewayOperatorReward => "withdraw_gateway_operator_reward",
+            MixnetEventType::RewardCompoundingUpdate => "reward_compounding_update",
+            MixnetEventType::CompoundDelegatorReward => "compound_delegator_reward",
+            MixnetEventType::CompoundOperatorReward => "compound_operator_reward",
+            MixnetEventType::ExecuteRewardCompounding => "execute_reward_compounding",
             MixnetEventType::PendingActiveSetUpdate => "pending_active_set_update",
             MixnetEventType::ActiveSetUpdate => "active_set_update",
             MixnetEventType::PendingIntervalRewardingParamsUpdate => {
@@ -82,6 +108,8 @@ impl ToString for MixnetEventType {
             MixnetEventType::PendingUndelegation => "pending_undelegation",
             MixnetEventType::Delegation => "delegation",
             MixnetEventType::Undelegation => "undelegation",
+            MixnetEventType::PendingRedelegation => "pending_redelegation",
+            MixnetEventType::Redelegation => "redelegation",
             MixnetEventType::ContractSettingsUpdate => "settings_update",
             MixnetEventType::RewardingValidatorUpdate => "rewarding_validator_address_update",
             MixnetEventType::BeginEpochTransition => "beginning_epoch_transition",
@@ -93,6 +121,23 @@ impl ToString for MixnetEventType {
             MixnetEventType::IntervalConfigUpdate => "interval_config_update",
             MixnetEventType::DelegationOnUnbonding => "delegation_on_unbonding_node",
             MixnetEventType::GatewayConfigUpdate => "gateway_config_update",
+            MixnetEventType::MixnodeOwnershipTransferProposal => {
+                "mixnode_ownership_transfer_proposal"
+            }
+            MixnetEventType::MixnodeOwnershipTransferCancellation => {
+                "mixnode_ownership_transfer_cancellation"
+            }
+            MixnetEventType::MixnodeOwnershipTransfer => "mixnode_ownership_transfer",
+            MixnetEventType::GatewayOwnershipTransferProposal => {
+                "gateway_ownership_transfer_proposal"
+            }
+            MixnetEventType::GatewayOwnershipTransferCancellation => {
+                "gateway_ownership_transfer_cancellation"
+            }
+            MixnetEventType::GatewayOwnershipTransfer => "gateway_ownership_transfer",
+            MixnetEventType::MixnodeJailing => "mixnode_jailing",
+            MixnetEventType::PendingMixnodeSlashing => "pending_mixnode_slashing",
+            MixnetEventType::MixnodeSlashing => "mixnode_slashing",
         };
 
         format!("{EVENT_VERSION_PREFIX}{event_name}")
@@ -109,6 +154,8 @@ pub const PROXY_KEY: &str = "proxy";
 // delegation/undelegation
 pub const DELEGATOR_KEY: &str = "delegator";
 pub const DELEGATION_TARGET_KEY: &str = "delegation_target";
+pub const REDELEGATION_SOURCE_KEY: &str = "redelegation_source";
+pub const REDELEGATED_REWARD_KEY: &str = "redelegated_reward";
 pub const UNIT_REWARD_KEY: &str = "unit_reward";
 
 // bonding/unbonding
@@ -132,6 +179,10 @@ pub const UPDATED_MIXNODE_CONFIG_KEY: &str = "updated_mixnode_config";
 pub const UPDATED_GATEWAY_CONFIG_KEY: &str = "updated_gateway_config";
 pub const UPDATED_MIXNODE_COST_PARAMS_KEY: &str = "updated_mixnode_cost_params";
 
+// ownership transfer
+pub const PREVIOUS_OWNER_KEY: &str = "previous_owner";
+pub const NEW_OWNER_KEY: &str = "new_owner";
+
 // rewarding
 pub const INTERVAL_KEY: &str = "interval_details";
 pub const OPERATOR_REWARD_KEY: &str = "operator_reward";
@@ -142,9 +193,15 @@ pub const UPDATED_INTERVAL_REWARDING_PARAMS_KEY: &str = "updated_interval_reward
 pub const PRIOR_DELEGATES_KEY: &str = "prior_delegates";
 pub const PRIOR_UNIT_REWARD_KEY: &str = "prior_unit_reward";
 
+pub const COMPOUNDING_ENABLED_KEY: &str = "compounding_enabled";
+pub const REWARDS_COMPOUNDED_KEY: &str = "number_of_rewards_compounded";
+
 pub const NO_REWARD_REASON_KEY: &str = "no_reward_reason";
 pub const BOND_NOT_FOUND_VALUE: &str = "bond_not_found";
 pub const ZERO_PERFORMANCE_VALUE: &str = "zero_performance";
+pub const ALREADY_REWARDED_VALUE: &str = "already_rewarded";
+pub const JAILED_VALUE: &str = "jailed";
+pub const BUDGET_EXHAUSTED_VALUE: &str = "budget_exhausted";
 
 // rewarded set update
 pub const ACTIVE_SET_SIZE_KEY: &str = "active_set_size";
@@ -156,6 +213,14 @@ pub const NEW_CURRENT_EPOCH_KEY: &str = "new_current_epoch";
 pub const EVENTS_EXECUTED_KEY: &str = "number_of_events_executed";
 pub const EVENT_CREATION_HEIGHT_KEY: &str = "created_at";
 pub const REWARDED_SET_NODES_KEY: &str = "rewarded_set_nodes";
+
+// jailing / slashing
+pub const JAILED_AT_EPOCH_KEY: &str = "jailed_at_epoch";
+pub const RELEASED_AT_EPOCH_KEY: &str = "released_at_epoch";
+pub const OFFENCE_KEY: &str = "offence";
+pub const EVIDENCE_KEY: &str = "evidence";
+pub const SLASH_FRACTION_KEY: &str = "slash_fraction";
+pub const SLASHED_AMOUNT_KEY: &str = "slashed_amount";
 pub const NEW_EPOCHS_DURATION_SECS_KEY: &str = "new_epoch_durations_secs";
 pub const NEW_EPOCHS_IN_INTERVAL: &str = "new_epochs_in_interval";
 
@@ -213,6 +278,19 @@ pub fn new_withdraw_operator_reward_event(
         .add_attribute(MIX_ID_KEY, mix_id.to_string())
 }
 
+pub fn new_withdraw_gateway_operator_reward_event(
+    owner: &Addr,
+    proxy: &Option<Addr>,
+    amount: Coin,
+    identity: IdentityKeyRef<'_>,
+) -> Event {
+    Event::new(MixnetEventType::WithdrawGatewayOperatorReward)
+        .add_attribute(OWNER_KEY, owner.as_str())
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+}
+
 pub fn new_withdraw_delegator_reward_event(
     delegator: &Addr,
     proxy: &Option<Addr>,
@@ -226,6 +304,63 @@ pub fn new_withdraw_delegator_reward_event(
         .add_attribute(DELEGATION_TARGET_KEY, mix_id.to_string())
 }
 
+pub fn new_operator_reward_compounding_update_event(
+    owner: &Addr,
+    proxy: &Option<Addr>,
+    mix_id: MixId,
+    enabled: bool,
+) -> Event {
+    Event::new(MixnetEventType::RewardCompoundingUpdate)
+        .add_attribute(OWNER_KEY, owner.as_str())
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(MIX_ID_KEY, mix_id.to_string())
+        .add_attribute(COMPOUNDING_ENABLED_KEY, enabled.to_string())
+}
+
+pub fn new_delegator_reward_compounding_update_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    mix_id: MixId,
+    enabled: bool,
+) -> Event {
+    Event::new(MixnetEventType::RewardCompoundingUpdate)
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(DELEGATION_TARGET_KEY, mix_id.to_string())
+        .add_attribute(COMPOUNDING_ENABLED_KEY, enabled.to_string())
+}
+
+pub fn new_compound_operator_reward_event(
+    owner: &Addr,
+    proxy: &Option<Addr>,
+    amount: Coin,
+    mix_id: MixId,
+) -> Event {
+    Event::new(MixnetEventType::CompoundOperatorReward)
+        .add_attribute(OWNER_KEY, owner.as_str())
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(MIX_ID_KEY, mix_id.to_string())
+}
+
+pub fn new_compound_delegator_reward_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    amount: Coin,
+    mix_id: MixId,
+) -> Event {
+    Event::new(MixnetEventType::CompoundDelegatorReward)
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(DELEGATION_TARGET_KEY, mix_id.to_string())
+}
+
+pub fn new_reward_compounding_execution_event(compounded: u32) -> Event {
+    Event::new(MixnetEventType::ExecuteRewardCompounding)
+        .add_attribute(REWARDS_COMPOUNDED_KEY, compounded.to_string())
+}
+
 pub fn new_active_set_update_event(created_at: BlockHeight, new_size: u32) -> Event {
     Event::new(MixnetEventType::ActiveSetUpdate)
         .add_attribute(EVENT_CREATION_HEIGHT_KEY, created_at.to_string())
@@ -301,6 +436,53 @@ pub fn new_pending_undelegation_event(
         .add_attribute(MIX_ID_KEY, mix_id.to_string())
 }
 
+pub fn new_redelegation_event(
+    created_at: BlockHeight,
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    amount: &Coin,
+    reward: &Coin,
+) -> Event {
+    Event::new(MixnetEventType::Redelegation)
+        .add_attribute(EVENT_CREATION_HEIGHT_KEY, created_at.to_string())
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(REDELEGATION_SOURCE_KEY, from_mix_id.to_string())
+        .add_attribute(DELEGATION_TARGET_KEY, to_mix_id.to_string())
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+        .add_attribute(REDELEGATED_REWARD_KEY, reward.to_string())
+}
+
+pub fn new_redelegation_on_unbonded_node_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+) -> Event {
+    Event::new(MixnetEventType::Redelegation)
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(REDELEGATION_SOURCE_KEY, from_mix_id.to_string())
+        .add_attribute(DELEGATION_TARGET_KEY, to_mix_id.to_string())
+}
+
+pub fn new_pending_redelegation_event(
+    delegator: &Addr,
+    proxy: &Option<Addr>,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    amount: &Coin,
+) -> Event {
+    Event::new(MixnetEventType::PendingRedelegation)
+        .add_attribute(DELEGATOR_KEY, delegator)
+        .add_optional_attribute(PROXY_KEY, proxy.as_ref())
+        .add_attribute(REDELEGATION_SOURCE_KEY, from_mix_id.to_string())
+        .add_attribute(DELEGATION_TARGET_KEY, to_mix_id.to_string())
+        .add_attribute(AMOUNT_KEY, amount.to_string())
+}
+
 pub fn new_gateway_bonding_event(
     owner: &Addr,
     proxy: &Option<Addr>,
@@ -390,6 +572,41 @@ pub fn new_pending_mixnode_unbonding_event(
         .add_optional_attribute(PROXY_KEY, proxy.as_ref())
 }
 
+pub fn new_mixnode_jailing_event(report: &MisbehaviourReport, released_at_epoch: EpochId) -> Event {
+    let evidence = serde_json_wasm::to_string(&report.evidence)
+        .unwrap_or_else(|_| "serialisation failure".into());
+
+    Event::new(MixnetEventType::MixnodeJailing)
+        .add_attribute(MIX_ID_KEY, report.mix_id.to_string())
+        .add_attribute(OFFENCE_KEY, report.offence.to_string())
+        .add_attribute(JAILED_AT_EPOCH_KEY, report.reported_at_epoch.to_string())
+        .add_attribute(RELEASED_AT_EPOCH_KEY, released_at_epoch.to_string())
+        .add_attribute(EVIDENCE_KEY, evidence)
+        .add_optional_attribute(
+            SLASH_FRACTION_KEY,
+            report.slash_fraction.map(|f| f.to_string()),
+        )
+}
+
+pub fn new_pending_mixnode_slashing_event(mix_id: MixId, fraction: Percent) -> Event {
+    Event::new(MixnetEventType::PendingMixnodeSlashing)
+        .add_attribute(MIX_ID_KEY, mix_id.to_string())
+        .add_attribute(SLASH_FRACTION_KEY, fraction.to_string())
+}
+
+pub fn new_mixnode_slashing_event(
+    created_at: BlockHeight,
+    mix_id: MixId,
+    fraction: Percent,
+    slashed_amount: Decimal,
+) -> Event {
+    Event::new(MixnetEventType::MixnodeSlashing)
+        .add_attribute(EVENT_CREATION_HEIGHT_KEY, created_at.to_string())
+        .add_attribute(MIX_ID_KEY, mix_id.to_string())
+        .add_attribute(SLASH_FRACTION_KEY, fraction.to_string())
+        .add_attribute(SLASHED_AMOUNT_KEY, slashed_amount.to_string())
+}
+
 pub fn new_mixnode_config_update_event(
     mix_id: MixId,
     owner: &Addr,
@@ -414,6 +631,72 @@ pub fn new_gateway_config_update_event(
         .add_attribute(UPDATED_GATEWAY_CONFIG_KEY, update.to_inline_json())
 }
 
+pub fn new_mixnode_ownership_transfer_proposal_event(
+    mix_id: MixId,
+    owner: &Addr,
+    new_owner: &Addr,
+) -> Event {
+    Event::new(MixnetEventType::MixnodeOwnershipTransferProposal)
+        .add_attribute(MIX_ID_KEY, mix_id.to_string())
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(NEW_OWNER_KEY, new_owner)
+}
+
+pub fn new_mixnode_ownership_transfer_cancellation_event(
+    mix_id: MixId,
+    owner: &Addr,
+    new_owner: &Addr,
+) -> Event {
+    Event::new(MixnetEventType::MixnodeOwnershipTransferCancellation)
+        .add_attribute(MIX_ID_KEY, mix_id.to_string())
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(NEW_OWNER_KEY, new_owner)
+}
+
+pub fn new_mixnode_ownership_transfer_event(
+    mix_id: MixId,
+    previous_owner: &Addr,
+    new_owner: &Addr,
+) -> Event {
+    Event::new(MixnetEventType::MixnodeOwnershipTransfer)
+        .add_attribute(MIX_ID_KEY, mix_id.to_string())
+        .add_attribute(PREVIOUS_OWNER_KEY, previous_owner)
+        .add_attribute(NEW_OWNER_KEY, new_owner)
+}
+
+pub fn new_gateway_ownership_transfer_proposal_event(
+    identity: IdentityKeyRef<'_>,
+    owner: &Addr,
+    new_owner: &Addr,
+) -> Event {
+    Event::new(MixnetEventType::GatewayOwnershipTransferProposal)
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(NEW_OWNER_KEY, new_owner)
+}
+
+pub fn new_gateway_ownership_transfer_cancellation_event(
+    identity: IdentityKeyRef<'_>,
+    owner: &Addr,
+    new_owner: &Addr,
+) -> Event {
+    Event::new(MixnetEventType::GatewayOwnershipTransferCancellation)
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(NEW_OWNER_KEY, new_owner)
+}
+
+pub fn new_gateway_ownership_transfer_event(
+    identity: IdentityKeyRef<'_>,
+    previous_owner: &Addr,
+    new_owner: &Addr,
+) -> Event {
+    Event::new(MixnetEventType::GatewayOwnershipTransfer)
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(PREVIOUS_OWNER_KEY, previous_owner)
+        .add_attribute(NEW_OWNER_KEY, new_owner)
+}
+
 pub fn new_mixnode_pending_cost_params_update_event(
     mix_id: MixId,
     owner: &Addr,
@@ -510,6 +793,44 @@ pub fn new_zero_uptime_mix_operator_rewarding_event(interval: Interval, mix_id:
         .add_attribute(NO_REWARD_REASON_KEY, ZERO_PERFORMANCE_VALUE)
 }
 
+pub fn new_jailed_mix_operator_rewarding_event(interval: Interval, mix_id: MixId) -> Event {
+    Event::new(MixnetEventType::MixnodeRewarding)
+        .add_attribute(
+            INTERVAL_KEY,
+            interval.current_epoch_absolute_id().to_string(),
+        )
+        .add_attribute(MIX_ID_KEY, mix_id.to_string())
+        .add_attribute(NO_REWARD_REASON_KEY, JAILED_VALUE)
+}
+
+pub fn new_not_rewarded_gateway_rewarding_event(
+    interval: Interval,
+    identity: IdentityKeyRef<'_>,
+    reason: &str,
+) -> Event {
+    Event::new(MixnetEventType::GatewayRewarding)
+        .add_attribute(
+            INTERVAL_KEY,
+            interval.current_epoch_absolute_id().to_string(),
+        )
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(NO_REWARD_REASON_KEY, reason)
+}
+
+pub fn new_gateway_rewarding_event(
+    interval: Interval,
+    identity: IdentityKeyRef<'_>,
+    reward: Decimal,
+) -> Event {
+    Event::new(MixnetEventType::GatewayRewarding)
+        .add_attribute(
+            INTERVAL_KEY,
+            interval.current_epoch_absolute_id().to_string(),
+        )
+        .add_attribute(NODE_IDENTITY_KEY, identity)
+        .add_attribute(OPERATOR_REWARD_KEY, reward.to_string())
+}
+
 pub fn new_mix_rewarding_event(
     interval: Interval,
     mix_id: MixId,
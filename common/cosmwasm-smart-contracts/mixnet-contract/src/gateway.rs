@@ -4,8 +4,11 @@
 // due to code generated by JsonSchema
 #![allow(clippy::field_reassign_with_default)]
 
-use crate::{IdentityKey, SphinxKey};
-use cosmwasm_std::{Addr, Coin};
+use crate::error::MixnetContractError;
+use crate::helpers::IntoBaseDecimal;
+use crate::rewarding::helpers::truncate_reward;
+use crate::{EpochId, IdentityKey, SphinxKey};
+use cosmwasm_std::{Addr, Coin, Decimal};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
@@ -16,6 +19,9 @@ pub struct Gateway {
     pub host: String,
     pub mix_port: u16,
     pub clients_port: u16,
+    /// Port on which the gateway is accepting client connections over TLS (i.e. `wss://`), if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clients_wss_port: Option<u16>,
     pub location: String,
     pub sphinx_key: SphinxKey,
     /// Base58 encoded ed25519 EdDSA public key of the gateway used to derive shared keys with clients
@@ -122,6 +128,8 @@ pub struct GatewayConfigUpdate {
     pub host: String,
     pub mix_port: u16,
     pub clients_port: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clients_wss_port: Option<u16>,
     pub location: String,
     pub version: String,
 }
@@ -132,6 +140,31 @@ impl GatewayConfigUpdate {
     }
 }
 
+/// Rewarding information of a bonded gateway.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct GatewayRewarding {
+    /// Rewards earned by the gateway that have not yet been withdrawn by its operator.
+    pub unclaimed_reward: Decimal,
+
+    /// Absolute id of the last epoch the gateway has been rewarded for.
+    pub last_rewarded_epoch: EpochId,
+}
+
+impl GatewayRewarding {
+    pub fn add_reward(&mut self, reward: Decimal, absolute_epoch_id: EpochId) {
+        self.unclaimed_reward += reward;
+        self.last_rewarded_epoch = absolute_epoch_id;
+    }
+
+    /// Withdraws the whole part of the unclaimed reward. Any fractional leftover is kept
+    /// for future withdrawals.
+    pub fn withdraw_reward(&mut self, denom: &str) -> Result<Coin, MixnetContractError> {
+        let reward = truncate_reward(self.unclaimed_reward, denom);
+        self.unclaimed_reward -= reward.amount.into_base_decimal()?;
+        Ok(reward)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, JsonSchema)]
 pub struct PagedGatewayResponse {
     pub nodes: Vec<GatewayBond>,
@@ -174,6 +207,7 @@ mod tests {
             host: "1.1.1.1".to_string(),
             mix_port: 123,
             clients_port: 456,
+            clients_wss_port: None,
             location: "foomplandia".to_string(),
             sphinx_key: "sphinxkey".to_string(),
             identity_key: "identitykey".to_string(),
@@ -0,0 +1,100 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::reward_params::Performance;
+use crate::{BlockHeight, EpochId, MixId, Percent};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Number of the misbehaviour report submitted against particular mixnode,
+/// starting from 1 for the first report.
+pub type OffenceNumber = u32;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MisbehaviourEvidence {
+    /// The network monitor has consistently observed the node dropping packets.
+    PacketDropping {
+        /// Average performance of the node over the observation window.
+        observed_performance: Performance,
+
+        /// Number of epochs over which the performance has been observed.
+        epochs_observed: u32,
+    },
+
+    /// The node has registered an identity that is (or was) already used by another node.
+    DuplicateIdentity { duplicate_of: MixId },
+
+    /// Any other misbehaviour, described in free form.
+    Other { description: String },
+}
+
+/// Misbehaviour report recorded on chain at the time the node got jailed.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct MisbehaviourReport {
+    pub mix_id: MixId,
+
+    pub offence: OffenceNumber,
+
+    /// Block height at which the report has been submitted.
+    pub reported_at_height: BlockHeight,
+
+    /// Absolute id of the epoch during which the report has been submitted.
+    pub reported_at_epoch: EpochId,
+
+    pub evidence: MisbehaviourEvidence,
+
+    /// Number of epochs the node has been excluded from the rewarded set for.
+    pub jail_epochs: u32,
+
+    /// Fraction of the operator's stake (pledge and compounded rewards) that got slashed, if any.
+    pub slash_fraction: Option<Percent>,
+}
+
+/// Jailing status of a mixnode. A jailed node is not eligible for being included
+/// in the rewarded set until `released_at_epoch`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct JailedMixnode {
+    pub mix_id: MixId,
+
+    /// Absolute id of the epoch during which the node got most recently jailed.
+    pub jailed_at_epoch: EpochId,
+
+    /// Absolute id of the first epoch in which the node can be included in the rewarded set again.
+    pub released_at_epoch: EpochId,
+
+    /// Total number of offences the node has been jailed for.
+    pub offences: OffenceNumber,
+}
+
+impl JailedMixnode {
+    pub fn is_jailed_in(&self, absolute_epoch_id: EpochId) -> bool {
+        absolute_epoch_id < self.released_at_epoch
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct MixnodeJailStatusResponse {
+    pub mix_id: MixId,
+
+    /// Indicates whether the node is jailed in the current epoch.
+    pub currently_jailed: bool,
+
+    /// Details of the most recent jailing of this node, if it has ever been jailed.
+    pub jail: Option<JailedMixnode>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct PagedJailedMixnodesResponse {
+    pub nodes: Vec<JailedMixnode>,
+    pub per_page: usize,
+    pub start_next_after: Option<MixId>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, JsonSchema)]
+pub struct PagedMisbehaviourReportsResponse {
+    pub mix_id: MixId,
+    pub reports: Vec<MisbehaviourReport>,
+    pub per_page: usize,
+    pub start_next_after: Option<OffenceNumber>,
+}
@@ -12,6 +12,7 @@ pub mod families;
 pub mod gateway;
 pub mod helpers;
 mod interval;
+pub mod jailing;
 pub mod mixnode;
 mod msg;
 pub mod pending_events;
@@ -28,13 +29,17 @@ pub use delegation::{
 };
 pub use gateway::{
     Gateway, GatewayBond, GatewayBondResponse, GatewayConfigUpdate, GatewayOwnershipResponse,
-    PagedGatewayResponse,
+    GatewayRewarding, PagedGatewayResponse,
 };
 pub use interval::{
     CurrentIntervalResponse, EpochState, EpochStatus, Interval, NumberOfPendingEventsResponse,
     PendingEpochEventResponse, PendingEpochEventsResponse, PendingIntervalEventResponse,
     PendingIntervalEventsResponse,
 };
+pub use jailing::{
+    JailedMixnode, MisbehaviourEvidence, MisbehaviourReport, MixnodeJailStatusResponse,
+    PagedJailedMixnodesResponse, PagedMisbehaviourReportsResponse,
+};
 pub use mixnode::{
     Layer, MixNode, MixNodeBond, MixNodeConfigUpdate, MixNodeCostParams, MixNodeDetails,
     MixNodeRewarding, MixOwnershipResponse, MixnodeDetailsResponse, PagedMixnodeBondsResponse,
@@ -45,6 +50,8 @@ pub use pending_events::{
     PendingEpochEvent, PendingEpochEventData, PendingEpochEventKind, PendingIntervalEvent,
     PendingIntervalEventData, PendingIntervalEventKind,
 };
-pub use reward_params::{IntervalRewardParams, IntervalRewardingParamsUpdate, RewardingParams};
+pub use reward_params::{
+    GatewayRewardParams, IntervalRewardParams, IntervalRewardingParamsUpdate, RewardingParams,
+};
 pub use signing_types::*;
 pub use types::*;
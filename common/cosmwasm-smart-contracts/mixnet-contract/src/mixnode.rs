@@ -154,9 +154,7 @@ impl MixNodeRewarding {
     pub fn pending_detailed_operator_reward(&self, original_pledge: &Coin) -> StdResult<Decimal> {
         let initial_dec = original_pledge.amount.into_base_decimal()?;
         if initial_dec > self.operator {
-            panic!(
-                "seems slashing has occurred while it has not been implemented nor accounted for!"
-            )
+            panic!("operator stake is lower than the original pledge - slashing has not been accounted for correctly!")
         }
         Ok(self.operator - initial_dec)
     }
@@ -176,9 +174,7 @@ impl MixNodeRewarding {
     ) -> Result<Coin, MixnetContractError> {
         let initial_dec = original_pledge.amount.into_base_decimal()?;
         if initial_dec > self.operator {
-            panic!(
-                "seems slashing has occurred while it has not been implemented nor accounted for!"
-            )
+            panic!("operator stake is lower than the original pledge - slashing has not been accounted for correctly!")
         }
         let diff = self.operator - initial_dec;
         self.operator = initial_dec;
@@ -197,6 +193,28 @@ impl MixNodeRewarding {
         Ok(truncate_reward(reward, &delegation.amount.denom))
     }
 
+    /// Moves the accumulated operator reward into the pledge. It returns the compounded amount,
+    /// the caller is responsible for increasing the original pledge of the associated bond by it.
+    pub fn compound_operator_reward(
+        &mut self,
+        original_pledge: &Coin,
+    ) -> Result<Coin, MixnetContractError> {
+        let reward = self.withdraw_operator_reward(original_pledge)?;
+        self.increase_operator_uint128(reward.amount)?;
+        Ok(reward)
+    }
+
+    /// Moves the accumulated delegator reward into the delegation itself.
+    pub fn compound_delegator_reward(
+        &mut self,
+        delegation: &mut Delegation,
+    ) -> Result<Coin, MixnetContractError> {
+        let reward = self.withdraw_delegator_reward(delegation)?;
+        self.increase_delegates_uint128(reward.amount)?;
+        delegation.amount.amount += reward.amount;
+        Ok(reward)
+    }
+
     pub fn node_bond(&self) -> Decimal {
         self.operator + self.delegates
     }
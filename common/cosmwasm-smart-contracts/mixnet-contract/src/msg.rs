@@ -6,6 +6,7 @@ use crate::error::MixnetContractError;
 use crate::families::FamilyHead;
 use crate::gateway::GatewayConfigUpdate;
 use crate::helpers::IntoBaseDecimal;
+use crate::jailing::{MisbehaviourEvidence, OffenceNumber};
 use crate::mixnode::{MixNodeConfigUpdate, MixNodeCostParams};
 use crate::reward_params::{
     IntervalRewardParams, IntervalRewardingParamsUpdate, Performance, RewardingParams,
@@ -72,6 +73,7 @@ impl InitialRewardingParams {
             },
             rewarded_set_size: self.rewarded_set_size,
             active_set_size: self.active_set_size,
+            gateway: Default::default(),
         })
     }
 }
@@ -147,6 +149,15 @@ pub enum ExecuteMsg {
     ReconcileEpochEvents {
         limit: Option<u32>,
     },
+    /// Excludes the misbehaving mixnode from the rewarded set for the specified number of epochs,
+    /// records the evidence and optionally slashes (less than 100% of) the operator's stake
+    /// at the end of the current epoch, or immediately if the node is already unbonding.
+    JailMixnode {
+        mix_id: MixId,
+        jail_epochs: u32,
+        slash_fraction: Option<Percent>,
+        evidence: MisbehaviourEvidence,
+    },
 
     // mixnode-related:
     BondMixnode {
@@ -189,6 +200,13 @@ pub enum ExecuteMsg {
         new_config: MixNodeConfigUpdate,
         owner: String,
     },
+    TransferMixnodeOwnership {
+        to_address: String,
+    },
+    AcceptMixnodeOwnership {
+        mix_id: MixId,
+    },
+    CancelMixnodeOwnershipTransfer {},
 
     // gateway-related:
     BondGateway {
@@ -211,6 +229,13 @@ pub enum ExecuteMsg {
         new_config: GatewayConfigUpdate,
         owner: String,
     },
+    TransferGatewayOwnership {
+        to_address: String,
+    },
+    AcceptGatewayOwnership {
+        identity: IdentityKey,
+    },
+    CancelGatewayOwnershipTransfer {},
 
     // delegation-related:
     DelegateToMixnode {
@@ -227,6 +252,17 @@ pub enum ExecuteMsg {
         mix_id: MixId,
         delegate: String,
     },
+    RedelegateToMixnode {
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+    },
+    RedelegateToMixnodeOnBehalf {
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        delegate: String,
+    },
 
     // reward-related
     RewardMixnode {
@@ -244,6 +280,30 @@ pub enum ExecuteMsg {
         mix_id: MixId,
         owner: String,
     },
+    RewardGateway {
+        identity: IdentityKey,
+        performance: Performance,
+    },
+    WithdrawGatewayOperatorReward {},
+    WithdrawGatewayOperatorRewardOnBehalf {
+        owner: String,
+    },
+    SetOperatorRewardCompounding {
+        enabled: bool,
+    },
+    SetOperatorRewardCompoundingOnBehalf {
+        enabled: bool,
+        owner: String,
+    },
+    SetDelegatorRewardCompounding {
+        mix_id: MixId,
+        enabled: bool,
+    },
+    SetDelegatorRewardCompoundingOnBehalf {
+        mix_id: MixId,
+        enabled: bool,
+        owner: String,
+    },
 
     // testing-only
     #[cfg(feature = "contract-testing")]
@@ -299,6 +359,11 @@ impl ExecuteMsg {
             ExecuteMsg::BeginEpochTransition {} => "beginning epoch transition".into(),
             ExecuteMsg::AdvanceCurrentEpoch { .. } => "advancing current epoch".into(),
             ExecuteMsg::ReconcileEpochEvents { .. } => "reconciling epoch events".into(),
+            ExecuteMsg::JailMixnode {
+                mix_id,
+                jail_epochs,
+                ..
+            } => format!("jailing mixnode {mix_id} for {jail_epochs} epochs"),
             ExecuteMsg::BondMixnode { mix_node, .. } => {
                 format!("bonding mixnode {}", mix_node.identity_key)
             }
@@ -321,6 +386,15 @@ impl ExecuteMsg {
             ExecuteMsg::UpdateMixnodeConfigOnBehalf { .. } => {
                 "updating mixnode configuration on behalf".into()
             }
+            ExecuteMsg::TransferMixnodeOwnership { to_address } => {
+                format!("proposing transfer of mixnode ownership to {to_address}")
+            }
+            ExecuteMsg::AcceptMixnodeOwnership { mix_id } => {
+                format!("accepting ownership of mixnode {mix_id}")
+            }
+            ExecuteMsg::CancelMixnodeOwnershipTransfer {} => {
+                "cancelling mixnode ownership transfer".into()
+            }
             ExecuteMsg::BondGateway { gateway, .. } => {
                 format!("bonding gateway {}", gateway.identity_key)
             }
@@ -333,6 +407,15 @@ impl ExecuteMsg {
             ExecuteMsg::UpdateGatewayConfigOnBehalf { .. } => {
                 "updating gateway configuration on behalf".into()
             }
+            ExecuteMsg::TransferGatewayOwnership { to_address } => {
+                format!("proposing transfer of gateway ownership to {to_address}")
+            }
+            ExecuteMsg::AcceptGatewayOwnership { identity } => {
+                format!("accepting ownership of gateway {identity}")
+            }
+            ExecuteMsg::CancelGatewayOwnershipTransfer {} => {
+                "cancelling gateway ownership transfer".into()
+            }
             ExecuteMsg::DelegateToMixnode { mix_id } => format!("delegating to mixnode {mix_id}"),
             ExecuteMsg::DelegateToMixnodeOnBehalf { mix_id, .. } => {
                 format!("delegating to mixnode {mix_id} on behalf")
@@ -343,6 +426,19 @@ impl ExecuteMsg {
             ExecuteMsg::UndelegateFromMixnodeOnBehalf { mix_id, .. } => {
                 format!("removing delegation from mixnode {mix_id} on behalf")
             }
+            ExecuteMsg::RedelegateToMixnode {
+                from_mix_id,
+                to_mix_id,
+                amount,
+            } => format!("moving {amount} of delegation from mixnode {from_mix_id} to {to_mix_id}"),
+            ExecuteMsg::RedelegateToMixnodeOnBehalf {
+                from_mix_id,
+                to_mix_id,
+                amount,
+                ..
+            } => format!(
+                "moving {amount} of delegation from mixnode {from_mix_id} to {to_mix_id} on behalf"
+            ),
             ExecuteMsg::RewardMixnode {
                 mix_id,
                 performance,
@@ -357,6 +453,32 @@ impl ExecuteMsg {
             ExecuteMsg::WithdrawDelegatorRewardOnBehalf { mix_id, .. } => {
                 format!("withdrawing delegator reward from mixnode {mix_id} on behalf")
             }
+            ExecuteMsg::RewardGateway {
+                identity,
+                performance,
+            } => format!("rewarding gateway {identity} for performance {performance}"),
+            ExecuteMsg::WithdrawGatewayOperatorReward { .. } => {
+                "withdrawing gateway operator reward".into()
+            }
+            ExecuteMsg::WithdrawGatewayOperatorRewardOnBehalf { .. } => {
+                "withdrawing gateway operator reward on behalf".into()
+            }
+            ExecuteMsg::SetOperatorRewardCompounding { enabled } => {
+                format!("setting operator reward compounding to {enabled}")
+            }
+            ExecuteMsg::SetOperatorRewardCompoundingOnBehalf { enabled, .. } => {
+                format!("setting operator reward compounding to {enabled} on behalf")
+            }
+            ExecuteMsg::SetDelegatorRewardCompounding { mix_id, enabled } => {
+                format!("setting delegator reward compounding for mixnode {mix_id} to {enabled}")
+            }
+            ExecuteMsg::SetDelegatorRewardCompoundingOnBehalf {
+                mix_id, enabled, ..
+            } => {
+                format!(
+                    "setting delegator reward compounding for mixnode {mix_id} to {enabled} on behalf"
+                )
+            }
             #[cfg(feature = "contract-testing")]
             ExecuteMsg::TestingResolveAllPendingEvents { .. } => {
                 "resolving all pending events".into()
@@ -446,6 +568,18 @@ pub enum QueryMsg {
         mix_identity: IdentityKey,
     },
     GetLayerDistribution {},
+    GetMixnodeJailStatus {
+        mix_id: MixId,
+    },
+    GetJailedMixnodes {
+        limit: Option<u32>,
+        start_after: Option<MixId>,
+    },
+    GetMisbehaviourReports {
+        mix_id: MixId,
+        limit: Option<u32>,
+        start_after: Option<OffenceNumber>,
+    },
     // gateway-related:
     GetGateways {
         start_after: Option<IdentityKey>,
@@ -499,6 +633,22 @@ pub enum QueryMsg {
         mix_id: MixId,
         proxy: Option<String>,
     },
+    GetPendingGatewayOperatorReward {
+        address: String,
+    },
+    GetPendingGatewayReward {
+        identity: IdentityKey,
+    },
+    // whether rewards of the particular mixnode operator get automatically compounded
+    GetOperatorRewardCompounding {
+        mix_id: MixId,
+    },
+    // whether rewards of the particular delegation get automatically compounded
+    GetDelegatorRewardCompounding {
+        address: String,
+        mix_id: MixId,
+        proxy: Option<String>,
+    },
     // given the provided performance, estimate the reward at the end of the current epoch
     GetEstimatedCurrentEpochOperatorReward {
         mix_id: MixId,
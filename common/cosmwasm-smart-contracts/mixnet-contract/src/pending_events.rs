@@ -3,7 +3,7 @@
 
 use crate::mixnode::MixNodeCostParams;
 use crate::reward_params::IntervalRewardingParamsUpdate;
-use crate::{BlockHeight, EpochEventId, IntervalEventId, MixId};
+use crate::{BlockHeight, EpochEventId, IntervalEventId, MixId, Percent};
 use cosmwasm_std::{Addr, Coin};
 use serde::{Deserialize, Serialize};
 
@@ -34,6 +34,14 @@ pub enum PendingEpochEventKind {
         mix_id: MixId,
         proxy: Option<Addr>,
     },
+    // moves (part of) the delegation alongside all of its accrued rewards between two mixnodes
+    Redelegate {
+        owner: Addr,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        proxy: Option<Addr>,
+    },
     PledgeMore {
         mix_id: MixId,
         amount: Coin,
@@ -48,6 +56,12 @@ pub enum PendingEpochEventKind {
     UpdateActiveSetSize {
         new_size: u32,
     },
+    // removes the specified fraction of the operator's stake (pledge alongside any compounded rewards)
+    // and moves it back into the reward pool
+    SlashMixnode {
+        mix_id: MixId,
+        fraction: Percent,
+    },
 }
 
 impl PendingEpochEventKind {
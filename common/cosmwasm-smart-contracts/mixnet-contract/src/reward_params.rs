@@ -74,6 +74,25 @@ impl IntervalRewardParams {
     }
 }
 
+/// Parameters used for rewarding gateways for the bandwidth they provide to the clients.
+#[cfg_attr(feature = "generate-ts", derive(ts_rs::TS))]
+#[cfg_attr(
+    feature = "generate-ts",
+    ts(export_to = "ts-packages/types/src/types/rust/GatewayRewardParams.ts")
+)]
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, PartialOrd, Serialize, JsonSchema,
+)]
+pub struct GatewayRewardParams {
+    // default: 0, i.e. gateways are not rewarded unless explicitly enabled
+    /// Reward a gateway with perfect performance receives for a single epoch.
+    /// The actual reward is scaled by the gateway's measured performance.
+    /// Gateways are rewarded from whatever is left of the epoch budget after rewarding the mixnodes,
+    /// which is split evenly between all bonded gateways and caps the reward of each of them.
+    #[cfg_attr(feature = "generate-ts", ts(type = "string"))]
+    pub epoch_reward: Decimal,
+}
+
 #[cfg_attr(feature = "generate-ts", derive(ts_rs::TS))]
 #[cfg_attr(
     feature = "generate-ts",
@@ -90,9 +109,18 @@ pub struct RewardingParams {
     // very strongly related to each other.
     pub rewarded_set_size: u32,
     pub active_set_size: u32,
+
+    /// Parameters used for rewarding gateways.
+    // contracts deployed before gateway rewarding got introduced did not store this field
+    #[serde(default)]
+    pub gateway: GatewayRewardParams,
 }
 
 impl RewardingParams {
+    pub fn gateway_reward(&self, performance: Performance) -> Decimal {
+        self.gateway.epoch_reward * performance
+    }
+
     pub fn active_node_work(&self) -> Decimal {
         self.interval.active_set_work_factor * self.standby_node_work()
     }
@@ -208,6 +236,10 @@ impl RewardingParams {
             self.rewarded_set_size = rewarded_set_size;
         }
 
+        if let Some(gateway_epoch_reward) = updates.gateway_epoch_reward {
+            self.gateway.epoch_reward = gateway_epoch_reward;
+        }
+
         if recompute_epoch_budget {
             self.interval.epoch_reward_budget = self.interval.reward_pool
                 / epochs_in_interval.into_base_decimal()?
@@ -267,6 +299,9 @@ pub struct IntervalRewardingParamsUpdate {
     pub interval_pool_emission: Option<Percent>,
 
     pub rewarded_set_size: Option<u32>,
+
+    #[cfg_attr(feature = "generate-ts", ts(type = "string | null"))]
+    pub gateway_epoch_reward: Option<Decimal>,
 }
 
 impl IntervalRewardingParamsUpdate {
@@ -279,6 +314,7 @@ impl IntervalRewardingParamsUpdate {
             || self.active_set_work_factor.is_some()
             || self.interval_pool_emission.is_some()
             || self.rewarded_set_size.is_some()
+            || self.gateway_epoch_reward.is_some()
     }
 
     pub fn to_inline_json(&self) -> String {
@@ -46,6 +46,20 @@ pub struct PendingRewardResponse {
     pub mixnode_still_fully_bonded: bool,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq)]
+pub struct PendingGatewayRewardResponse {
+    pub amount_staked: Option<Coin>,
+    pub amount_earned: Option<Coin>,
+    pub amount_earned_detailed: Option<Decimal>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+pub struct RewardCompoundingResponse {
+    /// Indicates whether the earned rewards are automatically added to the stake
+    /// at the end of each epoch.
+    pub enabled: bool,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq)]
 pub struct EstimatedCurrentEpochRewardResponse {
     pub original_stake: Option<Coin>,
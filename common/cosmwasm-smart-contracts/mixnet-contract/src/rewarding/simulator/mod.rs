@@ -69,6 +69,7 @@ impl Simulator {
                 },
                 rewarded_set_size: self.system_rewarding_params.rewarded_set_size,
                 active_set_size: self.system_rewarding_params.active_set_size,
+                gateway: self.system_rewarding_params.gateway,
             };
 
             self.system_rewarding_params = updated_params;
@@ -128,6 +129,32 @@ impl Simulator {
         node.undelegate(delegator)
     }
 
+    pub fn set_operator_reward_compounding(
+        &mut self,
+        mix_id: MixId,
+        enabled: bool,
+    ) -> Result<(), MixnetContractError> {
+        let node = self
+            .nodes
+            .get_mut(&mix_id)
+            .ok_or(MixnetContractError::MixNodeBondNotFound { mix_id })?;
+        node.compound_operator_reward = enabled;
+        Ok(())
+    }
+
+    pub fn set_delegator_reward_compounding<S: Into<String>>(
+        &mut self,
+        delegator: S,
+        mix_id: MixId,
+        enabled: bool,
+    ) -> Result<(), MixnetContractError> {
+        let node = self
+            .nodes
+            .get_mut(&mix_id)
+            .ok_or(MixnetContractError::MixNodeBondNotFound { mix_id })?;
+        node.set_delegator_reward_compounding(delegator, enabled)
+    }
+
     pub fn simulate_epoch_single_node(
         &mut self,
         params: NodeRewardParams,
@@ -174,6 +201,9 @@ impl Simulator {
             self.pending_reward_pool_emission += reward_distribution.operator;
             self.pending_reward_pool_emission += reward_distribution.delegates;
 
+            // rewards get compounded as the final step of the epoch transition
+            node.compound_rewards()?;
+
             dist.insert(*mix_id, reward_distribution);
         }
 
@@ -265,6 +295,7 @@ mod tests {
                 },
                 rewarded_set_size,
                 active_set_size,
+                gateway: Default::default(),
             };
 
             let interval = Interval::init_interval(
@@ -525,6 +556,100 @@ mod tests {
             );
         }
 
+        #[test]
+        fn compounding_operator_reward() {
+            let original_pledge = coin(10000_000000, "unym");
+            let mut simulator = base_simulator(original_pledge.amount.u128());
+            let node_params =
+                NodeRewardParams::new(Percent::from_percentage_value(100).unwrap(), true);
+
+            simulator
+                .delegate("alice", Coin::new(18000_000000, "unym"), 0)
+                .unwrap();
+            simulator
+                .delegate("bob", Coin::new(4000_000000, "unym"), 0)
+                .unwrap();
+            simulator.set_operator_reward_compounding(0, true).unwrap();
+
+            // compounding doesn't affect the reward for the epoch itself
+            let rewards1 = simulator.simulate_epoch_single_node(node_params).unwrap();
+            let expected_operator1 = "1411087.1007647323".parse().unwrap();
+            let expected_delegator_reward1 = "2199961.032388664".parse().unwrap();
+            compare_decimals(rewards1.delegates, expected_delegator_reward1, None);
+            compare_decimals(rewards1.operator, expected_operator1, None);
+            check_rewarding_invariant(&simulator);
+
+            // but the reward is now part of the pledge
+            let node = &simulator.nodes[&0];
+            let expected_pledge =
+                original_pledge.amount + truncate_reward_amount(expected_operator1);
+            assert_eq!(node.original_pledge.amount, expected_pledge);
+            assert_eq!(
+                node.rewarding_details.operator,
+                Decimal::from_atomics(expected_pledge, 0).unwrap()
+            );
+            assert!(node
+                .rewarding_details
+                .pending_operator_reward(&node.original_pledge)
+                .amount
+                .is_zero());
+
+            simulator.simulate_epoch_single_node(node_params).unwrap();
+            check_rewarding_invariant(&simulator);
+            assert!(simulator.nodes[&0].original_pledge.amount > expected_pledge);
+        }
+
+        #[test]
+        fn compounding_delegator_reward() {
+            let mut simulator = base_simulator(10000_000000);
+            let node_params =
+                NodeRewardParams::new(Percent::from_percentage_value(100).unwrap(), true);
+
+            simulator
+                .delegate("alice", Coin::new(18000_000000, "unym"), 0)
+                .unwrap();
+            simulator
+                .delegate("bob", Coin::new(4000_000000, "unym"), 0)
+                .unwrap();
+            simulator
+                .set_delegator_reward_compounding("alice", 0, true)
+                .unwrap();
+            assert!(simulator
+                .set_delegator_reward_compounding("carol", 0, true)
+                .is_err());
+
+            let rewards1 = simulator.simulate_epoch_single_node(node_params).unwrap();
+            let expected_delegator_reward1 = "2199961.032388664".parse().unwrap();
+            compare_decimals(rewards1.delegates, expected_delegator_reward1, None);
+            check_rewarding_invariant(&simulator);
+
+            // alice's reward got folded into the delegation whilst bob's is still pending
+            let node = &simulator.nodes[&0];
+            let alice = &node.delegations["alice"];
+            let bob = &node.delegations["bob"];
+            let expected_del1_reward = "1799968.1174089068".parse().unwrap();
+            assert_eq!(
+                alice.amount.amount.u128(),
+                18000_000000 + truncate_reward_amount(expected_del1_reward).u128()
+            );
+            assert_eq!(
+                simulator.determine_delegation_reward(alice).unwrap(),
+                Decimal::zero()
+            );
+            assert_eq!(bob.amount.amount.u128(), 4000_000000);
+            assert_ne!(
+                simulator.determine_delegation_reward(bob).unwrap(),
+                Decimal::zero()
+            );
+
+            simulator.simulate_epoch_single_node(node_params).unwrap();
+            check_rewarding_invariant(&simulator);
+
+            // undelegating removes the compounding flag alongside the delegation
+            simulator.undelegate("alice", 0).unwrap();
+            assert!(simulator.nodes[&0].compounding_delegators.is_empty());
+        }
+
         #[test]
         fn simulating_multiple_epochs() {
             let mut simulator = base_simulator(10000_000000);
@@ -629,6 +754,7 @@ mod tests {
             },
             rewarded_set_size,
             active_set_size,
+            gateway: Default::default(),
         };
 
         let interval = Interval::init_interval(
@@ -3,7 +3,7 @@
 
 use crate::{Delegation, EpochId, MixId, MixNodeCostParams, MixNodeRewarding};
 use cosmwasm_std::{Addr, Coin};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::error::MixnetContractError;
 use crate::rewarding::helpers::truncate_reward;
@@ -11,7 +11,11 @@ use crate::rewarding::helpers::truncate_reward;
 pub struct SimulatedNode {
     pub mix_id: MixId,
     pub rewarding_details: MixNodeRewarding,
+    pub original_pledge: Coin,
     pub delegations: HashMap<String, Delegation>,
+
+    pub compound_operator_reward: bool,
+    pub compounding_delegators: HashSet<String>,
 }
 
 impl SimulatedNode {
@@ -28,7 +32,10 @@ impl SimulatedNode {
                 initial_pledge,
                 current_epoch,
             )?,
+            original_pledge: initial_pledge.clone(),
             delegations: HashMap::new(),
+            compound_operator_reward: false,
+            compounding_delegators: HashSet::new(),
         })
     }
 
@@ -59,6 +66,7 @@ impl SimulatedNode {
         delegator: S,
     ) -> Result<(Coin, Coin), MixnetContractError> {
         let delegator = delegator.into();
+        self.compounding_delegators.remove(&delegator);
         let delegation = self.delegations.remove(&delegator).ok_or(
             MixnetContractError::NoMixnodeDelegationFound {
                 mix_id: MixId::MAX,
@@ -78,4 +86,44 @@ impl SimulatedNode {
 
         Ok((delegation.amount, truncated_reward))
     }
+
+    pub fn set_delegator_reward_compounding<S: Into<String>>(
+        &mut self,
+        delegator: S,
+        enabled: bool,
+    ) -> Result<(), MixnetContractError> {
+        let delegator = delegator.into();
+        if !self.delegations.contains_key(&delegator) {
+            return Err(MixnetContractError::NoMixnodeDelegationFound {
+                mix_id: self.mix_id,
+                address: delegator,
+                proxy: None,
+            });
+        }
+
+        if enabled {
+            self.compounding_delegators.insert(delegator);
+        } else {
+            self.compounding_delegators.remove(&delegator);
+        }
+        Ok(())
+    }
+
+    // equivalent of what the contract does whilst reconciling epoch events
+    pub fn compound_rewards(&mut self) -> Result<(), MixnetContractError> {
+        if self.compound_operator_reward {
+            let reward = self
+                .rewarding_details
+                .compound_operator_reward(&self.original_pledge)?;
+            self.original_pledge.amount += reward.amount;
+        }
+
+        for delegator in &self.compounding_delegators {
+            if let Some(delegation) = self.delegations.get_mut(delegator) {
+                self.rewarding_details
+                    .compound_delegator_reward(delegation)?;
+            }
+        }
+        Ok(())
+    }
 }
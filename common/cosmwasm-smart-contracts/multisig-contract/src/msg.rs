@@ -17,6 +17,11 @@ pub struct InstantiateMsg {
     pub coconut_dkg_contract_address: String,
     pub threshold: Threshold,
     pub max_voting_period: Duration,
+    /// Allow members of the group to create proposals, rather than only the coconut contracts,
+    /// so that the multisig could be used for controlling any other operations,
+    /// such as operating shared mixnet infrastructure.
+    #[serde(default)]
+    pub member_proposals: bool,
 }
 
 // TODO: add some T variants? Maybe good enough as fixed Empty for now
@@ -1,9 +1,12 @@
-use cosmwasm_std::{Coin, Event};
+use cosmwasm_std::{Addr, Coin, Event};
 
 use crate::{NameId, RegisteredName};
 
 pub enum NameEventType {
     Register,
+    Renew,
+    Transfer,
+    Reclaim,
     DeleteId,
     DeleteName,
     UpdateDepositRequired,
@@ -13,6 +16,9 @@ impl std::fmt::Display for NameEventType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             NameEventType::Register => write!(f, "register"),
+            NameEventType::Renew => write!(f, "renew"),
+            NameEventType::Transfer => write!(f, "transfer"),
+            NameEventType::Reclaim => write!(f, "reclaim"),
             NameEventType::DeleteId => write!(f, "delete_id"),
             NameEventType::DeleteName => write!(f, "delete_name"),
             NameEventType::UpdateDepositRequired => write!(f, "update_deposit_required"),
@@ -31,6 +37,8 @@ pub const ACTION: &str = "action";
 pub const NAME_ID: &str = "name_id";
 pub const NAME: &str = "name";
 pub const OWNER: &str = "owner";
+pub const PREVIOUS_OWNER: &str = "previous_owner";
+pub const EXPIRES_AT: &str = "expires_at";
 
 pub const DEPOSIT_REQUIRED: &str = "deposit_required";
 
@@ -41,6 +49,32 @@ pub fn new_register_event(name_id: NameId, name: RegisteredName) -> Event {
         .add_attribute(NAME, name.name.to_string())
         .add_attribute(name.address.event_tag(), name.address.to_string())
         .add_attribute(OWNER, name.owner.to_string())
+        .add_attribute(EXPIRES_AT, name.expires_at.to_string())
+}
+
+pub fn new_renew_event(name_id: NameId, name: RegisteredName) -> Event {
+    Event::new(NameEventType::Renew)
+        .add_attribute(ACTION, NameEventType::Renew)
+        .add_attribute(NAME_ID, name_id.to_string())
+        .add_attribute(NAME, name.name.to_string())
+        .add_attribute(EXPIRES_AT, name.expires_at.to_string())
+}
+
+pub fn new_transfer_event(name_id: NameId, previous_owner: &Addr, name: RegisteredName) -> Event {
+    Event::new(NameEventType::Transfer)
+        .add_attribute(ACTION, NameEventType::Transfer)
+        .add_attribute(NAME_ID, name_id.to_string())
+        .add_attribute(NAME, name.name.to_string())
+        .add_attribute(PREVIOUS_OWNER, previous_owner.to_string())
+        .add_attribute(OWNER, name.owner.to_string())
+}
+
+pub fn new_reclaim_event(name_id: NameId, name: RegisteredName) -> Event {
+    Event::new(NameEventType::Reclaim)
+        .add_attribute(ACTION, NameEventType::Reclaim)
+        .add_attribute(NAME_ID, name_id.to_string())
+        .add_attribute(NAME, name.name.to_string())
+        .add_attribute(PREVIOUS_OWNER, name.owner.to_string())
 }
 
 pub fn new_delete_id_event(name_id: NameId, name: RegisteredName) -> Event {
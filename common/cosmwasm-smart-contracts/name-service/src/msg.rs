@@ -2,15 +2,37 @@ use crate::{Address, NameId, NymName};
 use cosmwasm_std::Coin;
 use serde::{Deserialize, Serialize};
 
+/// Default duration of a name registration (and of each renewal).
+pub const DEFAULT_REGISTRATION_PERIOD_SECS: u64 = 365 * 24 * 60 * 60;
+
+/// Default duration after the expiry during which only the owner can still renew the name.
+pub const DEFAULT_GRACE_PERIOD_SECS: u64 = 30 * 24 * 60 * 60;
+
+pub fn default_registration_period_secs() -> u64 {
+    DEFAULT_REGISTRATION_PERIOD_SECS
+}
+
+pub fn default_grace_period_secs() -> u64 {
+    DEFAULT_GRACE_PERIOD_SECS
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct InstantiateMsg {
     pub deposit_required: Coin,
+    #[serde(default = "default_registration_period_secs")]
+    pub registration_period_secs: u64,
+    #[serde(default = "default_grace_period_secs")]
+    pub grace_period_secs: u64,
 }
 
 impl InstantiateMsg {
     pub fn new(deposit_required: Coin) -> Self {
-        Self { deposit_required }
+        Self {
+            deposit_required,
+            registration_period_secs: DEFAULT_REGISTRATION_PERIOD_SECS,
+            grace_period_secs: DEFAULT_GRACE_PERIOD_SECS,
+        }
     }
 }
 
@@ -21,8 +43,13 @@ pub struct MigrateMsg {}
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecuteMsg {
-    /// Announcing a name pointing to a nym-address
+    /// Announcing a name pointing to a nym-address. If the name is already registered,
+    /// but its grace period has elapsed, the existing registration is reclaimed.
     Register { name: NymName, address: Address },
+    /// Extend the registration of the name by another registration period
+    Renew { name_id: NameId },
+    /// Transfer the ownership of the name (alongside its deposit) to a different account
+    Transfer { name_id: NameId, to_address: String },
     /// Delete a name entry by id
     DeleteId { name_id: NameId },
     /// Delete a name entry by name
@@ -41,6 +68,15 @@ impl ExecuteMsg {
             ExecuteMsg::Register { name, address } => {
                 format!("registering {address} as name: {name}")
             }
+            ExecuteMsg::Renew { name_id } => {
+                format!("renewing name with id {name_id}")
+            }
+            ExecuteMsg::Transfer {
+                name_id,
+                to_address,
+            } => {
+                format!("transferring name with id {name_id} to {to_address}")
+            }
             ExecuteMsg::DeleteId { name_id } => {
                 format!("deleting name with id {name_id}")
             }
@@ -75,6 +111,12 @@ pub enum QueryMsg {
         limit: Option<u32>,
         start_after: Option<NameId>,
     },
+    /// Query the names whose registration expires before the provided unix timestamp
+    ExpiringBefore {
+        timestamp: u64,
+        limit: Option<u32>,
+        start_after: Option<NameId>,
+    },
     Config {},
     GetContractVersion {},
     #[serde(rename = "get_cw2_contract_version")]
@@ -67,6 +67,8 @@ impl PagedNamesListResponse {
 #[serde(rename_all = "snake_case")]
 pub struct ConfigResponse {
     pub deposit_required: Coin,
+    pub registration_period_secs: u64,
+    pub grace_period_secs: u64,
 }
 
 impl From<RegisteredName> for ExecuteMsg {
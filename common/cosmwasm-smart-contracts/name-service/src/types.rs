@@ -19,6 +19,23 @@ pub struct RegisteredName {
     pub block_height: u64,
     /// The deposit used to announce the service.
     pub deposit: Coin,
+    /// Unix timestamp (in seconds) at which the registration expires unless it gets renewed.
+    // names registered before the introduction of expiry are assigned one upon migration
+    #[serde(default)]
+    pub expires_at: u64,
+}
+
+impl RegisteredName {
+    /// Returns whether the registration has expired at the provided unix timestamp.
+    pub fn is_expired(&self, now: u64) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Returns whether the grace period following the expiry has elapsed at the provided
+    /// unix timestamp, meaning anyone is allowed to claim the name.
+    pub fn is_reclaimable(&self, now: u64, grace_period_secs: u64) -> bool {
+        now >= self.expires_at.saturating_add(grace_period_secs)
+    }
 }
 
 /// String representation of a nym address, which is of the form
@@ -5,6 +5,8 @@ use crate::{Service, ServiceId};
 pub enum ServiceProviderEventType {
     Announce,
     DeleteId,
+    Heartbeat,
+    UpdateMetadata,
     DeleteNymAddress,
     UpdateDepositRequired,
 }
@@ -14,6 +16,8 @@ impl std::fmt::Display for ServiceProviderEventType {
         match self {
             ServiceProviderEventType::Announce => write!(f, "announce"),
             ServiceProviderEventType::DeleteId => write!(f, "delete_id"),
+            ServiceProviderEventType::Heartbeat => write!(f, "heartbeat"),
+            ServiceProviderEventType::UpdateMetadata => write!(f, "update_metadata"),
             ServiceProviderEventType::DeleteNymAddress => write!(f, "delete_nym_address"),
             ServiceProviderEventType::UpdateDepositRequired => write!(f, "update_deposit_required"),
         }
@@ -32,6 +36,8 @@ pub const SERVICE_ID: &str = "service_id";
 pub const SERVICE_TYPE: &str = "service_type";
 pub const NYM_ADDRESS: &str = "nym_address";
 pub const OWNER: &str = "owner";
+pub const TIMESTAMP: &str = "timestamp";
+pub const ALLOWED_HOSTS: &str = "allowed_hosts";
 
 pub const DEPOSIT_REQUIRED: &str = "deposit_required";
 
@@ -51,6 +57,20 @@ pub fn new_delete_id_event(service: Service) -> Event {
         .add_attribute(NYM_ADDRESS, service.service.nym_address.to_string())
 }
 
+pub fn new_heartbeat_event(service_id: ServiceId, timestamp: u64) -> Event {
+    Event::new(ServiceProviderEventType::Heartbeat)
+        .add_attribute(ACTION, ServiceProviderEventType::Heartbeat)
+        .add_attribute(SERVICE_ID, service_id.to_string())
+        .add_attribute(TIMESTAMP, timestamp.to_string())
+}
+
+pub fn new_update_metadata_event(service: Service) -> Event {
+    Event::new(ServiceProviderEventType::UpdateMetadata)
+        .add_attribute(ACTION, ServiceProviderEventType::UpdateMetadata)
+        .add_attribute(SERVICE_ID, service.service_id.to_string())
+        .add_attribute(ALLOWED_HOSTS, service.metadata.allowed_hosts.to_string())
+}
+
 pub fn new_update_deposit_required_event(deposit_required: Coin) -> Event {
     Event::new(ServiceProviderEventType::UpdateDepositRequired)
         .add_attribute(ACTION, ServiceProviderEventType::UpdateDepositRequired)
@@ -1,4 +1,4 @@
-use crate::{NymAddress, ServiceDetails, ServiceId};
+use crate::{NymAddress, ServiceDetails, ServiceId, ServiceMetadata};
 use cosmwasm_std::Coin;
 use nym_contracts_common::signing::MessageSignature;
 use serde::{Deserialize, Serialize};
@@ -29,6 +29,16 @@ pub enum ExecuteMsg {
     DeleteId {
         service_id: ServiceId,
     },
+    /// Signal that the service is alive. The signature has to be produced with the identity key
+    /// of the service.
+    Heartbeat {
+        service_id: ServiceId,
+        signature: MessageSignature,
+    },
+    UpdateMetadata {
+        service_id: ServiceId,
+        metadata: ServiceMetadata,
+    },
     DeleteNymAddress {
         nym_address: NymAddress,
     },
@@ -54,6 +64,12 @@ impl ExecuteMsg {
             ExecuteMsg::DeleteId { service_id } => {
                 format!("deleting service with service id {service_id}")
             }
+            ExecuteMsg::Heartbeat { service_id, .. } => {
+                format!("sending heartbeat for service with service id {service_id}")
+            }
+            ExecuteMsg::UpdateMetadata { service_id, .. } => {
+                format!("updating metadata of service with service id {service_id}")
+            }
             ExecuteMsg::DeleteNymAddress { nym_address } => {
                 format!("deleting service with nym address {nym_address}")
             }
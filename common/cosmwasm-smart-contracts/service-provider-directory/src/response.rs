@@ -30,6 +30,23 @@ impl From<&[Service]> for ServicesListResponse {
     }
 }
 
+/// A service alongside its liveness, as determined from the time of its most recent heartbeat.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub struct ServiceWithLiveness {
+    pub service: Service,
+    pub stale: bool,
+}
+
+impl ServiceWithLiveness {
+    pub fn new(service: Service, now: u64, max_heartbeat_age_secs: u64) -> Self {
+        ServiceWithLiveness {
+            stale: service.is_stale(now, max_heartbeat_age_secs),
+            service,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(rename_all = "snake_case")]
 pub struct PagedServicesListResponse {
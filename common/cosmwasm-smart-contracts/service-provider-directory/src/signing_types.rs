@@ -4,7 +4,7 @@ use nym_contracts_common::signing::{
 };
 use serde::Serialize;
 
-use crate::ServiceDetails;
+use crate::{ServiceDetails, ServiceId};
 
 pub type SignableServiceProviderAnnounceMsg =
     SignableMessage<ContractMessageContent<ServiceProviderAnnounce>>;
@@ -20,6 +20,20 @@ impl SigningPurpose for ServiceProviderAnnounce {
     }
 }
 
+pub type SignableServiceProviderHeartbeatMsg =
+    SignableMessage<ContractMessageContent<ServiceProviderHeartbeat>>;
+
+#[derive(Serialize)]
+pub struct ServiceProviderHeartbeat {
+    service_id: ServiceId,
+}
+
+impl SigningPurpose for ServiceProviderHeartbeat {
+    fn message_type() -> MessageType {
+        MessageType::new("service-provider-heartbeat")
+    }
+}
+
 pub fn construct_service_provider_announce_sign_payload(
     nonce: Nonce,
     sender: Addr,
@@ -31,3 +45,14 @@ pub fn construct_service_provider_announce_sign_payload(
     let content = ContractMessageContent::new(sender, proxy, vec![deposit], payload);
     SignableMessage::new(nonce, content)
 }
+
+pub fn construct_service_provider_heartbeat_sign_payload(
+    nonce: Nonce,
+    sender: Addr,
+    service_id: ServiceId,
+) -> SignableServiceProviderHeartbeatMsg {
+    let payload = ServiceProviderHeartbeat { service_id };
+    let proxy = None;
+    let content = ContractMessageContent::new(sender, proxy, vec![], payload);
+    SignableMessage::new(nonce, content)
+}
@@ -8,6 +8,12 @@ use serde::{Deserialize, Serialize};
 /// The directory of services are indexed by [`ServiceId`].
 pub type ServiceId = u32;
 
+/// How often service providers are expected to send a heartbeat to the directory.
+pub const DEFAULT_HEARTBEAT_INTERVAL_SECS: u64 = 60 * 60;
+
+/// How long after the last heartbeat a service is still considered to be alive.
+pub const DEFAULT_MAX_HEARTBEAT_AGE_SECS: u64 = 3 * DEFAULT_HEARTBEAT_INTERVAL_SECS;
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug, JsonSchema)]
 pub struct Service {
     /// Unique id assigned to the anounced service.
@@ -20,6 +26,23 @@ pub struct Service {
     pub block_height: u64,
     /// The deposit used to announce the service.
     pub deposit: Coin,
+    /// Capabilities published by the service.
+    #[serde(default)]
+    pub metadata: ServiceMetadata,
+    /// Unix timestamp, in seconds, of the block containing the most recent heartbeat.
+    #[serde(default)]
+    pub last_heartbeat: Option<u64>,
+}
+
+impl Service {
+    /// Returns whether the service has not sent a heartbeat within the provided window, counting
+    /// back from `now`. Services that never sent a heartbeat are always considered stale.
+    pub fn is_stale(&self, now: u64, max_heartbeat_age_secs: u64) -> bool {
+        match self.last_heartbeat {
+            Some(last_heartbeat) => now.saturating_sub(last_heartbeat) > max_heartbeat_age_secs,
+            None => true,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug, JsonSchema)]
@@ -32,6 +55,46 @@ pub struct ServiceDetails {
     pub identity_key: IdentityKey,
 }
 
+/// Structured information about the capabilities of a service, published by its announcer.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Default, JsonSchema)]
+pub struct ServiceMetadata {
+    /// Versions of the service provider interface the service understands.
+    pub supported_interface_versions: Vec<u8>,
+    /// Versions of the service-specific protocol, e.g. socks5, the service understands.
+    pub supported_protocol_versions: Vec<u8>,
+    /// Summary of the policy used to decide which hosts the service will connect to.
+    pub allowed_hosts: AllowedHostsPolicy,
+    /// Bandwidth declared by the operator, in bytes per second.
+    pub declared_bandwidth: Option<u64>,
+}
+
+/// Summary of the outbound request filtering used by a service.
+#[derive(Serialize, Deserialize, Copy, Clone, PartialEq, Eq, Debug, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AllowedHostsPolicy {
+    /// The announcer hasn't published the policy.
+    #[default]
+    Unspecified,
+    /// Requests to any host are allowed.
+    Open,
+    /// Only the standard allow list is used.
+    StandardList,
+    /// The standard allow list extended with a custom one.
+    Custom,
+}
+
+impl std::fmt::Display for AllowedHostsPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let policy = match self {
+            AllowedHostsPolicy::Unspecified => "unspecified",
+            AllowedHostsPolicy::Open => "open",
+            AllowedHostsPolicy::StandardList => "standard_list",
+            AllowedHostsPolicy::Custom => "custom",
+        };
+        write!(f, "{policy}")
+    }
+}
+
 /// The types of addresses supported.
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -79,9 +79,17 @@ pub enum ExecuteMsg {
         address: String,
     },
     ClaimOperatorReward {},
+    ClaimGatewayOperatorReward {},
     ClaimDelegatorReward {
         mix_id: MixId,
     },
+    SetOperatorRewardCompounding {
+        enabled: bool,
+    },
+    SetDelegatorRewardCompounding {
+        mix_id: MixId,
+        enabled: bool,
+    },
     UpdateMixnodeCostParams {
         new_costs: MixNodeCostParams,
     },
@@ -100,6 +108,12 @@ pub enum ExecuteMsg {
         mix_id: MixId,
         on_behalf_of: Option<String>,
     },
+    RedelegateToMixnode {
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        on_behalf_of: Option<String>,
+    },
     CreateAccount {
         owner_address: String,
         staking_address: Option<String>,
@@ -114,6 +128,12 @@ pub enum ExecuteMsg {
         mix_id: MixId,
         amount: Coin,
     },
+    TrackRedelegation {
+        owner: String,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+    },
     BondMixnode {
         mix_node: MixNode,
         cost_params: MixNodeCostParams,
@@ -169,7 +189,16 @@ impl ExecuteMsg {
             ExecuteMsg::KickFamilyMember { .. } => "VestingExecuteMsg::KickFamilyMember",
             ExecuteMsg::TrackReward { .. } => "VestingExecuteMsg::TrackReward",
             ExecuteMsg::ClaimOperatorReward { .. } => "VestingExecuteMsg::ClaimOperatorReward",
+            ExecuteMsg::ClaimGatewayOperatorReward { .. } => {
+                "VestingExecuteMsg::ClaimGatewayOperatorReward"
+            }
             ExecuteMsg::ClaimDelegatorReward { .. } => "VestingExecuteMsg::ClaimDelegatorReward",
+            ExecuteMsg::SetOperatorRewardCompounding { .. } => {
+                "VestingExecuteMsg::SetOperatorRewardCompounding"
+            }
+            ExecuteMsg::SetDelegatorRewardCompounding { .. } => {
+                "VestingExecuteMsg::SetDelegatorRewardCompounding"
+            }
             ExecuteMsg::UpdateMixnodeConfig { .. } => "VestingExecuteMsg::UpdateMixnodeConfig",
             ExecuteMsg::UpdateMixnodeCostParams { .. } => {
                 "VestingExecuteMsg::UpdateMixnodeCostParams"
@@ -177,9 +206,11 @@ impl ExecuteMsg {
             ExecuteMsg::UpdateMixnetAddress { .. } => "VestingExecuteMsg::UpdateMixnetAddress",
             ExecuteMsg::DelegateToMixnode { .. } => "VestingExecuteMsg::DelegateToMixnode",
             ExecuteMsg::UndelegateFromMixnode { .. } => "VestingExecuteMsg::UndelegateFromMixnode",
+            ExecuteMsg::RedelegateToMixnode { .. } => "VestingExecuteMsg::RedelegateToMixnode",
             ExecuteMsg::CreateAccount { .. } => "VestingExecuteMsg::CreateAccount",
             ExecuteMsg::WithdrawVestedCoins { .. } => "VestingExecuteMsg::WithdrawVestedCoins",
             ExecuteMsg::TrackUndelegation { .. } => "VestingExecuteMsg::TrackUndelegation",
+            ExecuteMsg::TrackRedelegation { .. } => "VestingExecuteMsg::TrackRedelegation",
             ExecuteMsg::BondMixnode { .. } => "VestingExecuteMsg::BondMixnode",
             ExecuteMsg::PledgeMore { .. } => "VestingExecuteMsg::PledgeMore",
             ExecuteMsg::DecreasePledge { .. } => "VestingExecuteMsg::DecreasePledge",
@@ -0,0 +1,46 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use nym_client_core::error::ClientCoreError;
+use nym_gateway::error::GatewayError;
+use std::io;
+use std::net::SocketAddr;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum LocalTestnetError {
+    #[error("experienced an io failure: {source}")]
+    IoError {
+        #[from]
+        source: io::Error,
+    },
+
+    #[error("the local network needs at least a single mixnode per layer")]
+    NoMixnodes,
+
+    #[error("failed to start the gateway: {source}")]
+    GatewayStartupFailure {
+        #[from]
+        source: GatewayError,
+    },
+
+    #[error("{node} did not start listening on {address} within {timeout:?}")]
+    ListenerTimeout {
+        node: String,
+        address: SocketAddr,
+        timeout: Duration,
+    },
+
+    #[error("failed to register the client with the local gateway: {source}")]
+    GatewayRegistrationFailure {
+        #[from]
+        source: ClientCoreError,
+    },
+
+    #[error("failed to connect the client to the local network: {source}")]
+    ClientFailure {
+        #[from]
+        source: nym_sdk::Error,
+    },
+}
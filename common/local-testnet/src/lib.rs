@@ -0,0 +1,417 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runs a complete, albeit tiny, Nym network inside the current process so that end-to-end
+//! behaviour could be tested without any external infrastructure.
+//!
+//! All nodes bind to random ports on localhost, keep their data in a temporary directory and
+//! never talk to the chain or nym-api: the gateway accepts clients without bandwidth credentials
+//! and the clients get their topology from a [`HardcodedTopologyProvider`].
+//!
+//! ```no_run
+//! use nym_local_testnet::LocalTestnetBuilder;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let testnet = LocalTestnetBuilder::new().start().await.unwrap();
+//!
+//!     let sender = testnet.connect_client().await.unwrap();
+//!     let mut receiver = testnet.connect_client().await.unwrap();
+//!
+//!     sender.send_str(*receiver.nym_address(), "hello").await;
+//!     let received = receiver.wait_for_messages().await.unwrap();
+//!     assert_eq!(received[0].message, b"hello");
+//! }
+//! ```
+
+use log::info;
+use nym_client_core::client::base_client::storage::{Ephemeral, MixnetClientStorage};
+use nym_client_core::config::DebugConfig;
+use nym_client_core::init::{setup_gateway_from, GatewaySetup};
+use nym_crypto::asymmetric::{encryption, identity};
+use nym_network_requester::core::NRServiceProviderBuilder;
+use nym_sdk::mixnet::{MixnetClient, MixnetClientBuilder, Recipient, Socks5, Socks5MixnetClient};
+use nym_task::TaskManager;
+use nym_topology::mix::Layer;
+use nym_topology::provider_trait::HardcodedTopologyProvider;
+use nym_topology::{gateway, mix, NetworkAddress, NymTopology};
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpListener};
+use std::path::Path;
+use std::time::Duration;
+use tempfile::TempDir;
+use tokio::task::JoinHandle;
+use url::Url;
+
+pub use crate::error::LocalTestnetError;
+
+mod error;
+
+const LOCALHOST: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+// owner of all of the local nodes. it's never checked against anything
+const LOCAL_OWNER: &str = "n1localtestnet";
+
+// nothing should ever be listening there, so any (optional) attempt of the nodes to reach
+// nym-api, like the verloc measurements, fails immediately rather than leaving the machine
+const UNREACHABLE_NYM_API: &str = "http://127.0.0.1:1";
+
+const LISTENER_STARTUP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Debug configuration of the local clients: the poisson-distributed sending and the cover traffic
+/// are disabled so that the only packets going through the network are the ones explicitly sent
+/// by the tests.
+pub fn default_client_debug_config() -> DebugConfig {
+    let mut debug_config = DebugConfig::default();
+    debug_config.traffic.average_packet_delay = Duration::from_millis(10);
+    debug_config
+        .traffic
+        .disable_main_poisson_packet_distribution = true;
+    debug_config.cover_traffic.disable_loop_cover_traffic_stream = true;
+    debug_config.acknowledgements.average_ack_delay = Duration::from_millis(10);
+    debug_config
+}
+
+fn free_port() -> Result<u16, LocalTestnetError> {
+    Ok(TcpListener::bind((LOCALHOST, 0))?.local_addr()?.port())
+}
+
+fn unreachable_nym_api() -> Url {
+    UNREACHABLE_NYM_API.parse().unwrap()
+}
+
+fn store_keys(
+    identity_keys: &identity::KeyPair,
+    sphinx_keys: &encryption::KeyPair,
+    identity_paths: nym_pemstore::KeyPairPath,
+    sphinx_paths: nym_pemstore::KeyPairPath,
+) -> Result<(), LocalTestnetError> {
+    nym_pemstore::store_keypair(identity_keys, &identity_paths)?;
+    nym_pemstore::store_keypair(sphinx_keys, &sphinx_paths)?;
+    Ok(())
+}
+
+// the nodes spawn their listeners in the background, so make sure they're actually up before
+// anyone attempts to use them
+async fn wait_for_listener(node: &str, address: SocketAddr) -> Result<(), LocalTestnetError> {
+    let wait = async {
+        while tokio::net::TcpStream::connect(address).await.is_err() {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    };
+
+    tokio::time::timeout(LISTENER_STARTUP_TIMEOUT, wait)
+        .await
+        .map_err(|_| LocalTestnetError::ListenerTimeout {
+            node: node.to_string(),
+            address,
+            timeout: LISTENER_STARTUP_TIMEOUT,
+        })
+}
+
+pub struct LocalTestnetBuilder {
+    mixnodes_per_layer: usize,
+    client_debug_config: DebugConfig,
+}
+
+impl Default for LocalTestnetBuilder {
+    fn default() -> Self {
+        LocalTestnetBuilder {
+            mixnodes_per_layer: 1,
+            client_debug_config: default_client_debug_config(),
+        }
+    }
+}
+
+impl LocalTestnetBuilder {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    #[must_use]
+    pub fn mixnodes_per_layer(mut self, mixnodes_per_layer: usize) -> Self {
+        self.mixnodes_per_layer = mixnodes_per_layer;
+        self
+    }
+
+    /// Debug configuration used by all of the clients created via the testnet.
+    #[must_use]
+    pub fn client_debug_config(mut self, client_debug_config: DebugConfig) -> Self {
+        self.client_debug_config = client_debug_config;
+        self
+    }
+
+    async fn start_mixnode(
+        data_dir: &Path,
+        mix_id: u32,
+        layer: Layer,
+    ) -> Result<(mix::Node, TaskManager), LocalTestnetError> {
+        let id = format!("mixnode-{mix_id}");
+        let node_dir = data_dir.join(&id);
+        std::fs::create_dir_all(&node_dir)?;
+
+        let mut config = nym_mixnode::config::Config::new(&id)
+            .with_listening_address(LOCALHOST)
+            .with_mix_port(free_port()?)
+            .with_verloc_port(free_port()?)
+            .with_http_api_port(free_port()?)
+            .with_custom_nym_apis(vec![unreachable_nym_api()]);
+        config.storage_paths =
+            nym_mixnode::config::persistence::paths::MixNodePaths::new_in_directory(&node_dir);
+        config.debug.use_noise_links = false;
+
+        let mut rng = rand::rngs::OsRng;
+        let identity_keys = identity::KeyPair::new(&mut rng);
+        let sphinx_keys = encryption::KeyPair::new(&mut rng);
+        store_keys(
+            &identity_keys,
+            &sphinx_keys,
+            nym_pemstore::KeyPairPath::new(
+                config.storage_paths.private_identity_key(),
+                config.storage_paths.public_identity_key(),
+            ),
+            nym_pemstore::KeyPairPath::new(
+                config.storage_paths.private_encryption_key(),
+                config.storage_paths.public_encryption_key(),
+            ),
+        )?;
+
+        let mix_host = SocketAddr::new(LOCALHOST, config.mixnode.mix_port);
+        let node = mix::Node {
+            mix_id,
+            owner: LOCAL_OWNER.to_string(),
+            host: NetworkAddress::IpAddr(LOCALHOST),
+            mix_host,
+            identity_key: *identity_keys.public_key(),
+            sphinx_key: *sphinx_keys.public_key(),
+            layer,
+            version: config.mixnode.version.clone(),
+        };
+
+        let shutdown = nym_mixnode::node::MixNode::new(config).start();
+        wait_for_listener(&id, mix_host).await?;
+
+        Ok((node, shutdown))
+    }
+
+    async fn start_gateway(
+        data_dir: &Path,
+    ) -> Result<(gateway::Node, TaskManager), LocalTestnetError> {
+        let id = "gateway";
+        let node_dir = data_dir.join(id);
+        std::fs::create_dir_all(&node_dir)?;
+
+        let mut config = nym_gateway::config::Config::new(id)
+            .with_listening_address(LOCALHOST)
+            .with_mix_port(free_port()?)
+            .with_clients_port(free_port()?)
+            .with_custom_nym_apis(vec![unreachable_nym_api()])
+            .with_only_coconut_credentials(false);
+        config.storage_paths =
+            nym_gateway::config::persistence::paths::GatewayPaths::new_in_directory(&node_dir);
+        config.debug.use_noise_links = false;
+
+        let mut rng = rand::rngs::OsRng;
+        let identity_keys = identity::KeyPair::new(&mut rng);
+        let sphinx_keys = encryption::KeyPair::new(&mut rng);
+        store_keys(
+            &identity_keys,
+            &sphinx_keys,
+            nym_pemstore::KeyPairPath::new(
+                config.storage_paths.private_identity_key(),
+                config.storage_paths.public_identity_key(),
+            ),
+            nym_pemstore::KeyPairPath::new(
+                config.storage_paths.private_encryption_key(),
+                config.storage_paths.public_encryption_key(),
+            ),
+        )?;
+        // the gateway always constructs a signing nyxd client, even though it will never be used here
+        config.store_cosmos_mnemonic(&bip39::Mnemonic::generate(24).unwrap())?;
+
+        let mix_host = SocketAddr::new(LOCALHOST, config.gateway.mix_port);
+        let clients_address = SocketAddr::new(LOCALHOST, config.gateway.clients_port);
+        let node = gateway::Node {
+            owner: LOCAL_OWNER.to_string(),
+            host: NetworkAddress::IpAddr(LOCALHOST),
+            mix_host,
+            clients_port: config.gateway.clients_port,
+            clients_wss_port: None,
+            identity_key: *identity_keys.public_key(),
+            sphinx_key: *sphinx_keys.public_key(),
+            version: config.gateway.version.clone(),
+        };
+
+        let shutdown = nym_gateway::node::create_gateway(config).await.start()?;
+        wait_for_listener(id, mix_host).await?;
+        wait_for_listener(id, clients_address).await?;
+
+        Ok((node, shutdown))
+    }
+
+    /// Starts all of the nodes of the network.
+    ///
+    /// Note that the gateway reads its chain details and the passphrase of its secrets file
+    /// from the environment, so if they're not already set, the mainnet defaults
+    /// and a throwaway passphrase are exported for the whole process.
+    pub async fn start(self) -> Result<LocalTestnet, LocalTestnetError> {
+        if self.mixnodes_per_layer == 0 {
+            return Err(LocalTestnetError::NoMixnodes);
+        }
+
+        nym_network_defaults::setup_env(None);
+        let passphrase_var = nym_gateway::config::COSMOS_MNEMONIC_ENV_VARS.passphrase;
+        if std::env::var_os(passphrase_var).is_none() {
+            std::env::set_var(passphrase_var, "local-testnet");
+        }
+
+        let data_dir = tempfile::tempdir()?;
+        let mut node_shutdowns = Vec::new();
+
+        let mut mixes = BTreeMap::new();
+        let mut mix_id = 0;
+        for layer in [Layer::One, Layer::Two, Layer::Three] {
+            let mut layer_nodes = Vec::with_capacity(self.mixnodes_per_layer);
+            for _ in 0..self.mixnodes_per_layer {
+                mix_id += 1;
+                let (node, shutdown) = Self::start_mixnode(data_dir.path(), mix_id, layer).await?;
+                layer_nodes.push(node);
+                node_shutdowns.push(shutdown);
+            }
+            mixes.insert(layer as u8, layer_nodes);
+        }
+
+        let (gateway, shutdown) = Self::start_gateway(data_dir.path()).await?;
+        node_shutdowns.push(shutdown);
+
+        let topology = NymTopology::new(mixes, vec![gateway.clone()]);
+        info!(
+            "started the local testnet with {} mixnodes and gateway {}",
+            topology.num_mixnodes(),
+            gateway.identity_key
+        );
+
+        Ok(LocalTestnet {
+            data_dir,
+            topology,
+            gateway,
+            client_debug_config: self.client_debug_config,
+            node_shutdowns,
+            service_providers: Vec::new(),
+        })
+    }
+}
+
+/// Handle to the running local network. Dropping it stops all of its nodes, apart from the http
+/// APIs of the mixnodes, which only get stopped together with the runtime.
+pub struct LocalTestnet {
+    data_dir: TempDir,
+    topology: NymTopology,
+    gateway: gateway::Node,
+    client_debug_config: DebugConfig,
+
+    // the nodes are running for as long as we're holding their task managers
+    node_shutdowns: Vec<TaskManager>,
+    service_providers: Vec<JoinHandle<()>>,
+}
+
+impl LocalTestnet {
+    pub fn topology(&self) -> &NymTopology {
+        &self.topology
+    }
+
+    pub fn topology_provider(&self) -> HardcodedTopologyProvider {
+        HardcodedTopologyProvider::new(self.topology.clone())
+    }
+
+    pub fn gateway(&self) -> &gateway::Node {
+        &self.gateway
+    }
+
+    /// Creates a builder of a client with ephemeral storage that's already registered with the
+    /// local gateway and uses the local topology, so that it could be further customised before
+    /// connecting.
+    pub async fn client_builder(
+        &self,
+    ) -> Result<MixnetClientBuilder<Ephemeral>, LocalTestnetError> {
+        let storage = Ephemeral::new();
+
+        // register with the gateway upfront, so that the client would never have to look it up
+        let setup = GatewaySetup::Specified {
+            gateway_identity: self.gateway.identity_key.to_base58_string(),
+        };
+        setup_gateway_from(
+            &setup,
+            storage.key_store(),
+            storage.gateway_details_store(),
+            false,
+            Some(&[self.gateway.clone()]),
+        )
+        .await?;
+
+        Ok(MixnetClientBuilder::new_with_storage(storage)
+            .debug_config(self.client_debug_config)
+            .custom_topology_provider(Box::new(self.topology_provider())))
+    }
+
+    pub async fn connect_client(&self) -> Result<MixnetClient, LocalTestnetError> {
+        Ok(self
+            .client_builder()
+            .await?
+            .build()
+            .await?
+            .connect_to_mixnet()
+            .await?)
+    }
+
+    /// Connects a socks5 client, listening on a random local port, that proxies all of its
+    /// connections through the provided service provider.
+    pub async fn connect_socks5_client(
+        &self,
+        provider: Recipient,
+    ) -> Result<Socks5MixnetClient, LocalTestnetError> {
+        let mut socks5_config = Socks5::new(provider.to_string());
+        socks5_config.listening_port = free_port()?;
+
+        Ok(self
+            .client_builder()
+            .await?
+            .socks5_config(socks5_config)
+            .build()
+            .await?
+            .connect_to_mixnet_via_socks5()
+            .await?)
+    }
+
+    /// Starts an open proxy network requester and returns its address.
+    pub async fn start_network_requester(&mut self) -> Result<Recipient, LocalTestnetError> {
+        let id = format!("network-requester-{}", self.service_providers.len() + 1);
+        let config = nym_network_requester::config::Config::new(&id)
+            .with_data_directory(self.data_dir.path().join(&id));
+
+        let mixnet_client = self.connect_client().await?;
+        let address = *mixnet_client.nym_address();
+
+        let network_requester = NRServiceProviderBuilder::new(config, true, false, None)
+            .await
+            .with_custom_mixnet_client(mixnet_client);
+        self.service_providers.push(tokio::spawn(async move {
+            if let Err(err) = network_requester.run_service_provider().await {
+                log::error!("the local network requester has failed: {err}");
+            }
+        }));
+
+        Ok(address)
+    }
+}
+
+impl Drop for LocalTestnet {
+    fn drop(&mut self) {
+        for service_provider in &self.service_providers {
+            service_provider.abort()
+        }
+        for shutdown in &self.node_shutdowns {
+            shutdown.signal_shutdown().ok();
+        }
+    }
+}
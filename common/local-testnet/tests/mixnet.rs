@@ -0,0 +1,107 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use nym_local_testnet::LocalTestnetBuilder;
+use nym_sdk::mixnet::{IncludedSurbs, InputMessage};
+use nym_task::connections::TransmissionLane;
+use std::time::Duration;
+use tokio::time::timeout;
+
+const TEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[tokio::test(flavor = "multi_thread")]
+async fn messages_are_delivered_through_the_local_network() {
+    let testnet = LocalTestnetBuilder::new().start().await.unwrap();
+
+    let mut sender = testnet.connect_client().await.unwrap();
+    let mut receiver = testnet.connect_client().await.unwrap();
+
+    sender
+        .send_str(*receiver.nym_address(), "hello local mixnet")
+        .await;
+
+    let received = timeout(TEST_TIMEOUT, receiver.wait_for_messages())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].message, b"hello local mixnet");
+
+    sender.disconnect().await;
+    receiver.disconnect().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn sent_packets_get_acknowledged() {
+    let testnet = LocalTestnetBuilder::new().start().await.unwrap();
+
+    let mut sender = testnet.connect_client().await.unwrap();
+    let mut receiver = testnet.connect_client().await.unwrap();
+    assert!(sender.ack_rtt_estimate().is_none());
+
+    sender.send_str(*receiver.nym_address(), "ack me").await;
+    timeout(TEST_TIMEOUT, receiver.wait_for_messages())
+        .await
+        .unwrap()
+        .unwrap();
+
+    // the ack has to travel all the way back, so it might arrive a bit after the message itself
+    timeout(TEST_TIMEOUT, async {
+        while sender.ack_rtt_estimate().is_none() {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .unwrap();
+
+    sender.disconnect().await;
+    receiver.disconnect().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn anonymous_senders_can_be_replied_to() {
+    let testnet = LocalTestnetBuilder::new()
+        .mixnodes_per_layer(2)
+        .start()
+        .await
+        .unwrap();
+
+    let mut sender = testnet.connect_client().await.unwrap();
+    let mut receiver = testnet.connect_client().await.unwrap();
+
+    sender
+        .send_bytes(
+            *receiver.nym_address(),
+            b"who am I?".to_vec(),
+            IncludedSurbs::new(5),
+        )
+        .await;
+
+    let received = timeout(TEST_TIMEOUT, receiver.wait_for_messages())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(received[0].message, b"who am I?");
+    let sender_tag = received[0]
+        .sender_tag
+        .expect("the message wasn't anonymous");
+
+    receiver
+        .sender()
+        .send_input_message(InputMessage::new_reply(
+            sender_tag,
+            b"no idea".to_vec(),
+            TransmissionLane::General,
+            None,
+        ))
+        .await;
+
+    let replies = timeout(TEST_TIMEOUT, sender.wait_for_messages())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(replies[0].message, b"no idea");
+
+    sender.disconnect().await;
+    receiver.disconnect().await;
+}
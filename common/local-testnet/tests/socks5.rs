@@ -0,0 +1,90 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use nym_local_testnet::LocalTestnetBuilder;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time::timeout;
+
+const TEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+async fn start_echo_server() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let (mut reader, mut writer) = stream.split();
+                tokio::io::copy(&mut reader, &mut writer).await.ok();
+            });
+        }
+    });
+
+    address
+}
+
+// minimal, unauthenticated, socks5 CONNECT to an ipv4 address
+async fn socks5_connect(proxy: SocketAddr, target: SocketAddr) -> TcpStream {
+    let SocketAddr::V4(target) = target else {
+        panic!("only ipv4 targets are supported")
+    };
+
+    // the socks5 listener is started in the background, so it might not be up just yet
+    let mut stream = loop {
+        match TcpStream::connect(proxy).await {
+            Ok(stream) => break stream,
+            Err(_) => tokio::time::sleep(Duration::from_millis(50)).await,
+        }
+    };
+
+    stream.write_all(&[0x05, 0x01, 0x00]).await.unwrap();
+    let mut method_selection = [0u8; 2];
+    stream.read_exact(&mut method_selection).await.unwrap();
+    assert_eq!(method_selection, [0x05, 0x00]);
+
+    let mut request = vec![0x05, 0x01, 0x00, 0x01];
+    request.extend_from_slice(&target.ip().octets());
+    request.extend_from_slice(&target.port().to_be_bytes());
+    stream.write_all(&request).await.unwrap();
+
+    // version, reply, reserved, address type, ipv4 address and the port
+    let mut reply = [0u8; 10];
+    stream.read_exact(&mut reply).await.unwrap();
+    assert_eq!(reply[1], 0x00, "the socks5 connection was rejected");
+
+    stream
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn data_is_proxied_through_the_network_requester() {
+    let mut testnet = LocalTestnetBuilder::new().start().await.unwrap();
+    let network_requester = testnet.start_network_requester().await.unwrap();
+
+    let mut socks5_client = testnet
+        .connect_socks5_client(network_requester)
+        .await
+        .unwrap();
+    let proxy: SocketAddr = socks5_client
+        .socks5_url()
+        .trim_start_matches("socks5h://")
+        .parse()
+        .unwrap();
+
+    let echo_server = start_echo_server().await;
+
+    timeout(TEST_TIMEOUT, async {
+        let mut stream = socks5_connect(proxy, echo_server).await;
+
+        stream.write_all(b"hello through the mixnet").await.unwrap();
+        let mut echoed = [0u8; 24];
+        stream.read_exact(&mut echoed).await.unwrap();
+        assert_eq!(&echoed, b"hello through the mixnet");
+    })
+    .await
+    .unwrap();
+
+    socks5_client.disconnect().await;
+}
@@ -1,5 +1,6 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
+pub mod noise_keys;
 pub mod packet_processor;
 pub mod verloc;
 
@@ -0,0 +1,177 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use log::*;
+use nym_bin_common::version_checker::parse_version;
+use nym_crypto::asymmetric::encryption;
+use nym_noise::{NoisePeer, NoisePeerKeys};
+use nym_task::TaskClient;
+use nym_validator_client::ValidatorClientError;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::time::Duration;
+use url::Url;
+
+// nodes running older versions do not understand the noise marker and would have dropped the link
+// upon receiving it, so they have to be treated as legacy peers
+const MINIMUM_NOISE_MIXNODE_VERSION: &str = "1.1.24";
+const MINIMUM_NOISE_GATEWAY_VERSION: &str = "1.1.23";
+
+fn supports_noise(version: &str, minimum_version: &str) -> bool {
+    let Ok(minimum_version) = parse_version(minimum_version) else {
+        return false;
+    };
+    parse_version(version)
+        .map(|version| version >= minimum_version)
+        .unwrap_or_default()
+}
+
+/// Periodically retrieves the current network topology from the nym-api and updates
+/// the noise keys of all known mixnodes and gateways, so that the links to them could be authenticated.
+pub struct NoiseKeysRefresher {
+    nym_api_urls: Vec<Url>,
+    currently_used_api: usize,
+    validator_client: nym_validator_client::NymApiClient,
+    peer_keys: NoisePeerKeys,
+    refresh_rate: Duration,
+    shutdown_listener: TaskClient,
+}
+
+impl NoiseKeysRefresher {
+    pub fn new(
+        mut nym_api_urls: Vec<Url>,
+        peer_keys: NoisePeerKeys,
+        refresh_rate: Duration,
+        shutdown_listener: TaskClient,
+    ) -> Self {
+        assert!(
+            !nym_api_urls.is_empty(),
+            "at least one nym api endpoint must be provided"
+        );
+        nym_api_urls.shuffle(&mut thread_rng());
+
+        NoiseKeysRefresher {
+            validator_client: nym_validator_client::NymApiClient::new(nym_api_urls[0].clone()),
+            nym_api_urls,
+            currently_used_api: 0,
+            peer_keys,
+            refresh_rate,
+            shutdown_listener,
+        }
+    }
+
+    fn use_next_nym_api(&mut self) {
+        if self.nym_api_urls.len() == 1 {
+            warn!("There's only a single validator API available - it won't be possible to use a different one");
+            return;
+        }
+
+        self.currently_used_api = (self.currently_used_api + 1) % self.nym_api_urls.len();
+        self.validator_client
+            .change_nym_api(self.nym_api_urls[self.currently_used_api].clone())
+    }
+
+    async fn resolve(host: &str, port: u16) -> Option<SocketAddr> {
+        // make sure to use the same address as the one that's going to end up in the sphinx headers,
+        // i.e. the first one resolved
+        tokio::net::lookup_host((host, port)).await.ok()?.next()
+    }
+
+    async fn refresh_keys(&mut self) -> Result<(), ValidatorClientError> {
+        let mixnodes = self.validator_client.get_cached_mixnodes().await?;
+        let gateways = self.validator_client.get_cached_gateways().await?;
+
+        let mut keys = HashMap::with_capacity(mixnodes.len() + gateways.len());
+        let nodes = mixnodes
+            .into_iter()
+            .map(|node| {
+                let mix_node = node.bond_information.mix_node;
+                let supports_noise =
+                    supports_noise(&mix_node.version, MINIMUM_NOISE_MIXNODE_VERSION);
+                (
+                    mix_node.identity_key,
+                    mix_node.host,
+                    mix_node.mix_port,
+                    mix_node.sphinx_key,
+                    supports_noise,
+                )
+            })
+            .chain(gateways.into_iter().map(|bond| {
+                let supports_noise =
+                    supports_noise(&bond.gateway.version, MINIMUM_NOISE_GATEWAY_VERSION);
+                (
+                    bond.gateway.identity_key,
+                    bond.gateway.host,
+                    bond.gateway.mix_port,
+                    bond.gateway.sphinx_key,
+                    supports_noise,
+                )
+            }));
+
+        for (identity, host, mix_port, sphinx_key, supports_noise) in nodes {
+            let Ok(noise_key) = encryption::PublicKey::from_base58_string(&sphinx_key) else {
+                debug!("{identity} has announced a malformed sphinx key");
+                continue;
+            };
+            let Some(mix_address) = Self::resolve(&host, mix_port).await else {
+                debug!("failed to resolve the address of {identity} ({host})");
+                continue;
+            };
+            // legacy nodes are still included so that we'd accept the noise links they initiate
+            // if they have upgraded before updating the version announced in their bonds
+            keys.insert(
+                identity,
+                NoisePeer {
+                    mix_address,
+                    noise_key,
+                    supports_noise,
+                },
+            );
+        }
+
+        debug!("obtained noise keys of {} nodes", keys.len());
+        self.peer_keys.replace(keys);
+        Ok(())
+    }
+
+    pub async fn run(&mut self) {
+        let mut refresh_interval = tokio::time::interval(self.refresh_rate);
+
+        while !self.shutdown_listener.is_shutdown() {
+            tokio::select! {
+                biased;
+                _ = self.shutdown_listener.recv() => {
+                    log::trace!("NoiseKeysRefresher: Received shutdown");
+                }
+                _ = refresh_interval.tick() => {
+                    if let Err(err) = self.refresh_keys().await {
+                        error!("failed to refresh the noise keys of the network nodes - {err}. Going to attempt to use another validator API in the next run");
+                        self.use_next_nym_api();
+                    }
+                }
+            }
+        }
+
+        log::trace!("NoiseKeysRefresher: Exiting");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn noise_support_is_determined_by_the_announced_version() {
+        assert!(supports_noise("1.1.24", MINIMUM_NOISE_MIXNODE_VERSION));
+        assert!(supports_noise("1.2.0", MINIMUM_NOISE_MIXNODE_VERSION));
+        assert!(!supports_noise("1.1.23", MINIMUM_NOISE_MIXNODE_VERSION));
+        assert!(supports_noise("1.1.23", MINIMUM_NOISE_GATEWAY_VERSION));
+        assert!(!supports_noise("1.1.22", MINIMUM_NOISE_GATEWAY_VERSION));
+
+        // if we can't tell, it's safer to assume the node is a legacy one
+        assert!(!supports_noise("", MINIMUM_NOISE_MIXNODE_VERSION));
+        assert!(!supports_noise("foomp", MINIMUM_NOISE_GATEWAY_VERSION));
+    }
+}
@@ -64,6 +64,16 @@ impl<T> NonExhaustiveDelayQueue<T> {
         key
     }
 
+    /// Returns the number of items currently in the queue.
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if there are no items in the queue.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
     // TODO: it seems like this one can cause panic in very rare edge cases, however,
     // I can't seem to be able to reproduce it at all.
     pub fn remove(&mut self, key: &QueueKey) -> Expired<T> {
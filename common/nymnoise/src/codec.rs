@@ -0,0 +1,120 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::handshake::{TransportState, MAX_PLAINTEXT_LEN};
+use bytes::{Buf, BufMut, BytesMut};
+use nym_crypto::asymmetric::encryption;
+use std::io;
+use tokio_util::codec::{Decoder, Encoder};
+
+const LENGTH_PREFIX_LEN: usize = 2;
+
+/// Codec wrapping another codec, such as `NymCodec`, so that all of its frames are sent as
+/// length-prefixed noise transport messages. If the link has been established without
+/// a noise handshake (i.e. with a legacy peer), the frames are passed through unchanged.
+pub struct NoiseCodec<C> {
+    inner: C,
+    transport: Option<TransportState>,
+
+    // decrypted bytes that haven't yet been consumed by the inner decoder
+    plaintext: BytesMut,
+
+    // bytes produced by the inner encoder before getting encrypted
+    scratch: BytesMut,
+}
+
+impl<C> NoiseCodec<C> {
+    pub(crate) fn new(inner: C, transport: TransportState) -> Self {
+        NoiseCodec {
+            inner,
+            transport: Some(transport),
+            plaintext: BytesMut::new(),
+            scratch: BytesMut::new(),
+        }
+    }
+
+    /// Creates a codec for a legacy link that does not perform any encryption.
+    pub fn new_legacy(inner: C) -> Self {
+        NoiseCodec {
+            inner,
+            transport: None,
+            plaintext: BytesMut::new(),
+            scratch: BytesMut::new(),
+        }
+    }
+
+    pub fn is_encrypted(&self) -> bool {
+        self.transport.is_some()
+    }
+
+    /// Static key of the remote, if it has been authenticated with a noise handshake.
+    pub fn remote_static_key(&self) -> Option<&encryption::PublicKey> {
+        self.transport.as_ref().map(|t| t.remote_static_key())
+    }
+}
+
+impl<C, I> Encoder<I> for NoiseCodec<C>
+where
+    C: Encoder<I>,
+    C::Error: From<io::Error>,
+{
+    type Error = C::Error;
+
+    fn encode(&mut self, item: I, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        let Some(transport) = self.transport.as_mut() else {
+            return self.inner.encode(item, dst);
+        };
+
+        self.scratch.clear();
+        self.inner.encode(item, &mut self.scratch)?;
+
+        for chunk in self.scratch.chunks(MAX_PLAINTEXT_LEN) {
+            let ciphertext = transport.encrypt(chunk).map_err(io::Error::from)?;
+
+            dst.reserve(LENGTH_PREFIX_LEN + ciphertext.len());
+            // the cast is fine as the chunk size guarantees the ciphertext fits in MAX_MESSAGE_LEN
+            dst.put_u16(ciphertext.len() as u16);
+            dst.put_slice(&ciphertext);
+        }
+        Ok(())
+    }
+}
+
+impl<C> Decoder for NoiseCodec<C>
+where
+    C: Decoder,
+    C::Error: From<io::Error>,
+{
+    type Item = C::Item;
+    type Error = C::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let Some(transport) = self.transport.as_mut() else {
+            return self.inner.decode(src);
+        };
+
+        loop {
+            // try to get the frame out of whatever we have already decrypted
+            if let Some(item) = self.inner.decode(&mut self.plaintext)? {
+                return Ok(Some(item));
+            }
+
+            if src.len() < LENGTH_PREFIX_LEN {
+                src.reserve(LENGTH_PREFIX_LEN);
+                return Ok(None);
+            }
+
+            let message_len = u16::from_be_bytes([src[0], src[1]]) as usize;
+            let total_len = LENGTH_PREFIX_LEN + message_len;
+            if src.len() < total_len {
+                src.reserve(total_len - src.len());
+                return Ok(None);
+            }
+
+            src.advance(LENGTH_PREFIX_LEN);
+            let ciphertext = src.split_to(message_len);
+            let plaintext = transport.decrypt(&ciphertext).map_err(io::Error::from)?;
+            self.plaintext.extend_from_slice(&plaintext);
+        }
+    }
+}
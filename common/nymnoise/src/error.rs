@@ -0,0 +1,56 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::keys::NodeIdentity;
+use nym_crypto::asymmetric::encryption::{self, KeyRecoveryError};
+use std::io;
+use std::net::SocketAddr;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum NoiseError {
+    #[error("encountered an IO error - {0}")]
+    IoError(#[from] io::Error),
+
+    #[error("the noise handshake did not complete within {0:?}")]
+    HandshakeTimeout(Duration),
+
+    #[error("received a malformed noise handshake message")]
+    MalformedHandshakeMessage,
+
+    #[error(
+        "the remote requested noise link version {received} whilst we only support {expected}"
+    )]
+    UnsupportedVersion { received: u8, expected: u8 },
+
+    #[error("the received static key is malformed - {0}")]
+    MalformedStaticKey(#[from] KeyRecoveryError),
+
+    #[error("noise protocol failure - {0}")]
+    ProtocolError(#[from] snow::Error),
+
+    #[error("the message of {0} bytes is too long to be sent as a single noise message")]
+    MessageTooLong(usize),
+
+    #[error("the noise key of {0} is not known and legacy links are not allowed")]
+    UnknownPeerKey(SocketAddr),
+
+    #[error("{0} does not support noise links and legacy links are not allowed")]
+    NoiseUnsupported(NodeIdentity),
+
+    #[error("the remote has attempted to establish a legacy link which is not allowed")]
+    LegacyLinkRejected,
+
+    #[error("the remote has authenticated with the static key {0} that does not belong to any known node")]
+    UnknownInitiatorKey(encryption::PublicKey),
+}
+
+impl From<NoiseError> for io::Error {
+    fn from(err: NoiseError) -> Self {
+        match err {
+            NoiseError::IoError(err) => err,
+            other => io::Error::new(io::ErrorKind::InvalidData, other),
+        }
+    }
+}
@@ -0,0 +1,229 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Thin wrapper around the `snow` implementation of the `Noise_XK_25519_ChaChaPoly_SHA256` handshake.
+//!
+//! ```text
+//! XK:
+//!   <- s
+//!   ...
+//!   -> e, es
+//!   <- e, ee
+//!   -> s, se
+//! ```
+//!
+//! The initiator has to know the static key of the responder upfront (in our case it's obtained
+//! from the network topology) whilst the responder learns the static key of the initiator
+//! during the final handshake message.
+
+use crate::error::NoiseError;
+use nym_crypto::asymmetric::encryption;
+
+const NOISE_PATTERN: &str = "Noise_XK_25519_ChaChaPoly_SHA256";
+
+pub(crate) const TAG_LEN: usize = 16;
+
+/// Maximum length of a single noise message, including the authentication tag.
+pub const MAX_MESSAGE_LEN: usize = 65535;
+
+/// Maximum length of a plaintext that can be sent in a single noise transport message.
+pub const MAX_PLAINTEXT_LEN: usize = MAX_MESSAGE_LEN - TAG_LEN;
+
+fn builder<'a>(
+    prologue: &'a [u8],
+    local_private_key: &'a [u8],
+) -> Result<snow::Builder<'a>, NoiseError> {
+    Ok(snow::Builder::new(NOISE_PATTERN.parse()?)
+        .prologue(prologue)
+        .local_private_key(local_private_key))
+}
+
+/// In-progress handshake. All handshake messages carry empty payloads.
+pub(crate) struct Handshake {
+    state: snow::HandshakeState,
+}
+
+impl Handshake {
+    pub(crate) fn new_initiator(
+        prologue: &[u8],
+        local_keys: &encryption::KeyPair,
+        remote_key: encryption::PublicKey,
+    ) -> Result<Self, NoiseError> {
+        let local_private_key = local_keys.private_key().to_bytes();
+        let remote_key = remote_key.to_bytes();
+        let state = builder(prologue, &local_private_key)?
+            .remote_public_key(&remote_key)
+            .build_initiator()?;
+
+        Ok(Handshake { state })
+    }
+
+    pub(crate) fn new_responder(
+        prologue: &[u8],
+        local_keys: &encryption::KeyPair,
+    ) -> Result<Self, NoiseError> {
+        let local_private_key = local_keys.private_key().to_bytes();
+        let state = builder(prologue, &local_private_key)?.build_responder()?;
+
+        Ok(Handshake { state })
+    }
+
+    pub(crate) fn write_message(&mut self) -> Result<Vec<u8>, NoiseError> {
+        let mut message = vec![0u8; MAX_MESSAGE_LEN];
+        let len = self.state.write_message(&[], &mut message)?;
+        message.truncate(len);
+        Ok(message)
+    }
+
+    pub(crate) fn read_message(&mut self, message: &[u8]) -> Result<(), NoiseError> {
+        let mut payload = vec![0u8; message.len()];
+        let len = self.state.read_message(message, &mut payload)?;
+        if len != 0 {
+            return Err(NoiseError::MalformedHandshakeMessage);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn into_transport(self) -> Result<TransportState, NoiseError> {
+        let remote_static = match self.state.get_remote_static() {
+            Some(key) => encryption::PublicKey::from_bytes(key)?,
+            // can't happen with the XK pattern once the handshake has completed
+            None => return Err(NoiseError::MalformedHandshakeMessage),
+        };
+
+        Ok(TransportState {
+            inner: self.state.into_transport_mode()?,
+            remote_static,
+        })
+    }
+}
+
+/// Cipher states established as the result of a successful handshake.
+pub struct TransportState {
+    inner: snow::TransportState,
+    remote_static: encryption::PublicKey,
+}
+
+impl TransportState {
+    /// Static key of the remote party authenticated during the handshake.
+    pub fn remote_static_key(&self) -> &encryption::PublicKey {
+        &self.remote_static
+    }
+
+    pub(crate) fn encrypt(&mut self, plaintext: &[u8]) -> Result<Vec<u8>, NoiseError> {
+        let mut ciphertext = vec![0u8; plaintext.len() + TAG_LEN];
+        let len = self.inner.write_message(plaintext, &mut ciphertext)?;
+        ciphertext.truncate(len);
+        Ok(ciphertext)
+    }
+
+    pub(crate) fn decrypt(&mut self, ciphertext: &[u8]) -> Result<Vec<u8>, NoiseError> {
+        let mut plaintext = vec![0u8; ciphertext.len()];
+        let len = self.inner.read_message(ciphertext, &mut plaintext)?;
+        plaintext.truncate(len);
+        Ok(plaintext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::OsRng;
+
+    fn complete_handshake(
+        initiator_keys: &encryption::KeyPair,
+        responder_keys: &encryption::KeyPair,
+        initiator_view_of_responder: encryption::PublicKey,
+    ) -> Result<(TransportState, TransportState), NoiseError> {
+        let mut initiator =
+            Handshake::new_initiator(b"prologue", initiator_keys, initiator_view_of_responder)?;
+        let mut responder = Handshake::new_responder(b"prologue", responder_keys)?;
+
+        let msg1 = initiator.write_message()?;
+        responder.read_message(&msg1)?;
+        let msg2 = responder.write_message()?;
+        initiator.read_message(&msg2)?;
+        let msg3 = initiator.write_message()?;
+        responder.read_message(&msg3)?;
+
+        Ok((initiator.into_transport()?, responder.into_transport()?))
+    }
+
+    #[test]
+    fn successful_handshake_establishes_matching_transport_keys() {
+        let mut rng = OsRng;
+        let initiator_keys = encryption::KeyPair::new(&mut rng);
+        let responder_keys = encryption::KeyPair::new(&mut rng);
+
+        let (mut initiator, mut responder) = complete_handshake(
+            &initiator_keys,
+            &responder_keys,
+            *responder_keys.public_key(),
+        )
+        .unwrap();
+
+        assert_eq!(initiator.remote_static_key(), responder_keys.public_key());
+        assert_eq!(responder.remote_static_key(), initiator_keys.public_key());
+
+        for i in 0..10u8 {
+            let msg = vec![i; 100];
+            let ciphertext = initiator.encrypt(&msg).unwrap();
+            assert_eq!(ciphertext.len(), msg.len() + TAG_LEN);
+            assert_ne!(ciphertext[..100], msg[..]);
+            assert_eq!(responder.decrypt(&ciphertext).unwrap(), msg);
+
+            let ciphertext = responder.encrypt(&msg).unwrap();
+            assert_eq!(initiator.decrypt(&ciphertext).unwrap(), msg);
+        }
+    }
+
+    #[test]
+    fn handshake_fails_if_initiator_expects_different_responder() {
+        let mut rng = OsRng;
+        let initiator_keys = encryption::KeyPair::new(&mut rng);
+        let responder_keys = encryption::KeyPair::new(&mut rng);
+        let impostor_keys = encryption::KeyPair::new(&mut rng);
+
+        assert!(matches!(
+            complete_handshake(
+                &initiator_keys,
+                &impostor_keys,
+                *responder_keys.public_key()
+            ),
+            Err(NoiseError::ProtocolError(_))
+        ));
+    }
+
+    #[test]
+    fn handshake_fails_on_prologue_mismatch() {
+        let mut rng = OsRng;
+        let initiator_keys = encryption::KeyPair::new(&mut rng);
+        let responder_keys = encryption::KeyPair::new(&mut rng);
+
+        let mut initiator =
+            Handshake::new_initiator(b"foo", &initiator_keys, *responder_keys.public_key())
+                .unwrap();
+        let mut responder = Handshake::new_responder(b"bar", &responder_keys).unwrap();
+
+        let msg1 = initiator.write_message().unwrap();
+        assert!(responder.read_message(&msg1).is_err());
+    }
+
+    #[test]
+    fn tampered_transport_messages_are_rejected() {
+        let mut rng = OsRng;
+        let initiator_keys = encryption::KeyPair::new(&mut rng);
+        let responder_keys = encryption::KeyPair::new(&mut rng);
+
+        let (mut initiator, mut responder) = complete_handshake(
+            &initiator_keys,
+            &responder_keys,
+            *responder_keys.public_key(),
+        )
+        .unwrap();
+
+        let mut ciphertext = initiator.encrypt(b"hello").unwrap();
+        ciphertext[0] ^= 1;
+        assert!(responder.decrypt(&ciphertext).is_err());
+    }
+}
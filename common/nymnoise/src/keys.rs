@@ -0,0 +1,172 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use nym_crypto::asymmetric::encryption;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, RwLock};
+
+/// Base58-encoded identity key of a network node.
+pub type NodeIdentity = String;
+
+/// Noise-related information announced by a network node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoisePeer {
+    /// Address on which the node is accepting mix packets.
+    pub mix_address: SocketAddr,
+
+    /// Static x25519 key used by the node during the noise handshakes.
+    pub noise_key: encryption::PublicKey,
+
+    /// Indicates whether the node is running a version that understands noise links.
+    /// Links to nodes that do not have to use the legacy framing instead.
+    pub supports_noise: bool,
+}
+
+#[derive(Default)]
+struct PeersInner {
+    peers: HashMap<NodeIdentity, NoisePeer>,
+
+    // lookup tables for finding the node when all we have is the address we're connecting to
+    // or the static key presented by the remote during the handshake
+    by_address: HashMap<SocketAddr, NodeIdentity>,
+    by_noise_key: HashMap<encryption::PublicKey, NodeIdentity>,
+}
+
+impl PeersInner {
+    fn insert(&mut self, identity: NodeIdentity, peer: NoisePeer) {
+        if let Some(old) = self.peers.remove(&identity) {
+            // make sure not to remove the entries that have already been claimed by other nodes
+            if self.by_address.get(&old.mix_address) == Some(&identity) {
+                self.by_address.remove(&old.mix_address);
+            }
+            if self.by_noise_key.get(&old.noise_key) == Some(&identity) {
+                self.by_noise_key.remove(&old.noise_key);
+            }
+        }
+
+        self.by_address.insert(peer.mix_address, identity.clone());
+        self.by_noise_key.insert(peer.noise_key, identity.clone());
+        self.peers.insert(identity, peer);
+    }
+}
+
+/// Noise details of the known network nodes, keyed by their identities.
+/// It is expected to be periodically refreshed from the current network topology.
+#[derive(Clone, Default)]
+pub struct NoisePeerKeys {
+    inner: Arc<RwLock<PeersInner>>,
+}
+
+impl NoisePeerKeys {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn get(&self, identity: &str) -> Option<NoisePeer> {
+        self.inner
+            .read()
+            .expect("noise peer keys lock got poisoned")
+            .peers
+            .get(identity)
+            .copied()
+    }
+
+    /// Returns the identity and the noise details of the node listening on the provided address.
+    pub fn get_by_address(&self, address: &SocketAddr) -> Option<(NodeIdentity, NoisePeer)> {
+        let guard = self
+            .inner
+            .read()
+            .expect("noise peer keys lock got poisoned");
+        let identity = guard.by_address.get(address)?;
+        let peer = guard.peers.get(identity)?;
+        Some((identity.clone(), *peer))
+    }
+
+    /// Returns the identity of the node that has announced the provided noise key.
+    pub fn identity_of(&self, noise_key: &encryption::PublicKey) -> Option<NodeIdentity> {
+        self.inner
+            .read()
+            .expect("noise peer keys lock got poisoned")
+            .by_noise_key
+            .get(noise_key)
+            .cloned()
+    }
+
+    pub fn insert(&self, identity: NodeIdentity, peer: NoisePeer) {
+        self.inner
+            .write()
+            .expect("noise peer keys lock got poisoned")
+            .insert(identity, peer);
+    }
+
+    pub fn replace(&self, peers: HashMap<NodeIdentity, NoisePeer>) {
+        let mut inner = PeersInner::default();
+        for (identity, peer) in peers {
+            inner.insert(identity, peer)
+        }
+
+        *self
+            .inner
+            .write()
+            .expect("noise peer keys lock got poisoned") = inner;
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner
+            .read()
+            .expect("noise peer keys lock got poisoned")
+            .peers
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::OsRng;
+
+    fn random_peer(address: &str) -> NoisePeer {
+        NoisePeer {
+            mix_address: address.parse().unwrap(),
+            noise_key: *encryption::KeyPair::new(&mut OsRng).public_key(),
+            supports_noise: true,
+        }
+    }
+
+    #[test]
+    fn peers_can_be_looked_up_by_address_and_key() {
+        let keys = NoisePeerKeys::new();
+        let peer = random_peer("1.2.3.4:1789");
+        keys.insert("node1".to_string(), peer);
+
+        assert_eq!(keys.get("node1"), Some(peer));
+        assert_eq!(
+            keys.get_by_address(&peer.mix_address),
+            Some(("node1".to_string(), peer))
+        );
+        assert_eq!(keys.identity_of(&peer.noise_key), Some("node1".to_string()));
+    }
+
+    #[test]
+    fn updating_peer_removes_its_stale_entries() {
+        let keys = NoisePeerKeys::new();
+        let old = random_peer("1.2.3.4:1789");
+        let new = random_peer("5.6.7.8:1789");
+        keys.insert("node1".to_string(), old);
+        keys.insert("node1".to_string(), new);
+
+        assert_eq!(keys.len(), 1);
+        assert!(keys.get_by_address(&old.mix_address).is_none());
+        assert!(keys.identity_of(&old.noise_key).is_none());
+        assert_eq!(keys.identity_of(&new.noise_key), Some("node1".to_string()));
+
+        keys.replace(HashMap::new());
+        assert!(keys.is_empty());
+        assert!(keys.get_by_address(&new.mix_address).is_none());
+    }
+}
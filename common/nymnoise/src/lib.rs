@@ -0,0 +1,481 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Authenticated and encrypted links between Nym nodes.
+//!
+//! Before any `FramedNymPacket` is exchanged, the connecting node performs a
+//! `Noise_XK_25519_ChaChaPoly_SHA256` handshake using the x25519 (sphinx) keys the nodes have
+//! announced in the network topology. Afterwards every frame is sent as a noise transport message.
+//! The responder only accepts the handshake if the static key presented by the initiator
+//! belongs to one of the nodes in the topology.
+//!
+//! During the rollout, links to and from nodes that do not understand noise yet can optionally
+//! fall back to the legacy unencrypted framing. The handshake is only ever initiated with the nodes
+//! that have announced support for noise (see [`NoisePeer::supports_noise`]). The initiator signals the noise link by sending
+//! [`NOISE_LINK_MARKER`] followed by the [`NOISE_LINK_VERSION`] before the first handshake message.
+//! The marker can never be the first byte of a legacy frame, as that is either the packet size
+//! or the packet version, neither of which is ever zero.
+
+use crate::handshake::Handshake;
+use log::*;
+use nym_crypto::asymmetric::encryption;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio_util::codec::{Framed, FramedParts};
+
+pub mod codec;
+pub mod error;
+pub mod handshake;
+pub mod keys;
+
+pub use codec::NoiseCodec;
+pub use error::NoiseError;
+pub use keys::{NodeIdentity, NoisePeer, NoisePeerKeys};
+
+pub const NOISE_LINK_MARKER: u8 = 0;
+pub const NOISE_LINK_VERSION: u8 = 1;
+
+pub const DEFAULT_HANDSHAKE_TIMEOUT: Duration = Duration::from_millis(5_000);
+
+#[derive(Clone)]
+pub struct NoiseConfig {
+    local_keys: Arc<encryption::KeyPair>,
+    peer_keys: NoisePeerKeys,
+    allow_legacy_links: bool,
+    handshake_timeout: Duration,
+}
+
+impl NoiseConfig {
+    pub fn new(local_keys: Arc<encryption::KeyPair>, peer_keys: NoisePeerKeys) -> Self {
+        NoiseConfig {
+            local_keys,
+            peer_keys,
+            allow_legacy_links: true,
+            handshake_timeout: DEFAULT_HANDSHAKE_TIMEOUT,
+        }
+    }
+
+    #[must_use]
+    pub fn with_legacy_links(mut self, allow_legacy_links: bool) -> Self {
+        self.allow_legacy_links = allow_legacy_links;
+        self
+    }
+
+    #[must_use]
+    pub fn with_handshake_timeout(mut self, handshake_timeout: Duration) -> Self {
+        self.handshake_timeout = handshake_timeout;
+        self
+    }
+
+    pub fn allows_legacy_links(&self) -> bool {
+        self.allow_legacy_links
+    }
+
+    pub fn peer_keys(&self) -> &NoisePeerKeys {
+        &self.peer_keys
+    }
+}
+
+fn prologue() -> [u8; 2] {
+    [NOISE_LINK_MARKER, NOISE_LINK_VERSION]
+}
+
+async fn write_handshake_message<S>(stream: &mut S, message: &[u8]) -> Result<(), NoiseError>
+where
+    S: AsyncWrite + Unpin,
+{
+    // handshake messages are tiny so the cast is always fine
+    stream.write_u16(message.len() as u16).await?;
+    stream.write_all(message).await?;
+    stream.flush().await?;
+    Ok(())
+}
+
+async fn read_handshake_message<S>(stream: &mut S) -> Result<Vec<u8>, NoiseError>
+where
+    S: AsyncRead + Unpin,
+{
+    let len = stream.read_u16().await? as usize;
+    if len == 0 {
+        return Err(NoiseError::MalformedHandshakeMessage);
+    }
+    let mut message = vec![0u8; len];
+    stream.read_exact(&mut message).await?;
+    Ok(message)
+}
+
+async fn perform_initiator_handshake<S, C>(
+    mut stream: S,
+    inner_codec: C,
+    local_keys: &encryption::KeyPair,
+    remote_key: encryption::PublicKey,
+) -> Result<Framed<S, NoiseCodec<C>>, NoiseError>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut handshake = Handshake::new_initiator(&prologue(), local_keys, remote_key)?;
+
+    stream.write_all(&prologue()).await?;
+    write_handshake_message(&mut stream, &handshake.write_message()?).await?;
+
+    let message_2 = read_handshake_message(&mut stream).await?;
+    handshake.read_message(&message_2)?;
+
+    write_handshake_message(&mut stream, &handshake.write_message()?).await?;
+
+    let transport = handshake.into_transport()?;
+    Ok(Framed::new(stream, NoiseCodec::new(inner_codec, transport)))
+}
+
+async fn perform_responder_handshake<S, C>(
+    mut stream: S,
+    inner_codec: C,
+    local_keys: &encryption::KeyPair,
+    peer_keys: &NoisePeerKeys,
+) -> Result<Framed<S, NoiseCodec<C>>, NoiseError>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    // we have already consumed the marker byte at this point
+    let version = stream.read_u8().await?;
+    if version != NOISE_LINK_VERSION {
+        return Err(NoiseError::UnsupportedVersion {
+            received: version,
+            expected: NOISE_LINK_VERSION,
+        });
+    }
+
+    let mut handshake = Handshake::new_responder(&prologue(), local_keys)?;
+
+    let message_1 = read_handshake_message(&mut stream).await?;
+    handshake.read_message(&message_1)?;
+
+    write_handshake_message(&mut stream, &handshake.write_message()?).await?;
+
+    let message_3 = read_handshake_message(&mut stream).await?;
+    handshake.read_message(&message_3)?;
+
+    // the handshake proves the initiator owns the key, but we also have to make sure
+    // it's actually one of the network nodes
+    let transport = handshake.into_transport()?;
+    let remote_key = *transport.remote_static_key();
+    let Some(identity) = peer_keys.identity_of(&remote_key) else {
+        return Err(NoiseError::UnknownInitiatorKey(remote_key));
+    };
+    trace!("established noise link with {identity}");
+
+    Ok(Framed::new(stream, NoiseCodec::new(inner_codec, transport)))
+}
+
+/// Establishes an outbound link to `remote`. If the noise key of the remote is known and it supports
+/// noise links, the noise handshake is performed, otherwise, if allowed, a legacy unencrypted link is used.
+pub async fn upgrade_noise_initiator<S, C>(
+    stream: S,
+    inner_codec: C,
+    config: &NoiseConfig,
+    remote: SocketAddr,
+) -> Result<Framed<S, NoiseCodec<C>>, NoiseError>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let Some((identity, peer)) = config.peer_keys.get_by_address(&remote) else {
+        if config.allow_legacy_links {
+            debug!("noise key of {remote} is unknown - using a legacy link");
+            return Ok(Framed::new(stream, NoiseCodec::new_legacy(inner_codec)));
+        }
+        return Err(NoiseError::UnknownPeerKey(remote));
+    };
+
+    // the legacy nodes would have treated the noise marker as the beginning of a frame
+    // and dropped the link, so we must not even attempt the handshake with them
+    if !peer.supports_noise {
+        if config.allow_legacy_links {
+            debug!("{identity} ({remote}) does not support noise yet - using a legacy link");
+            return Ok(Framed::new(stream, NoiseCodec::new_legacy(inner_codec)));
+        }
+        return Err(NoiseError::NoiseUnsupported(identity));
+    }
+
+    trace!("establishing noise link with {identity} ({remote})");
+    tokio::time::timeout(
+        config.handshake_timeout,
+        perform_initiator_handshake(stream, inner_codec, &config.local_keys, peer.noise_key),
+    )
+    .await
+    .map_err(|_| NoiseError::HandshakeTimeout(config.handshake_timeout))?
+}
+
+/// Accepts an inbound link. Depending on the first byte sent by the remote, either the noise
+/// handshake is performed, or, if allowed, the link is treated as a legacy unencrypted one.
+pub async fn upgrade_noise_responder<S, C>(
+    mut stream: S,
+    inner_codec: C,
+    config: &NoiseConfig,
+) -> Result<Framed<S, NoiseCodec<C>>, NoiseError>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let handshake = async move {
+        let first_byte = stream.read_u8().await?;
+        if first_byte == NOISE_LINK_MARKER {
+            return perform_responder_handshake(
+                stream,
+                inner_codec,
+                &config.local_keys,
+                &config.peer_keys,
+            )
+            .await;
+        }
+
+        if !config.allow_legacy_links {
+            return Err(NoiseError::LegacyLinkRejected);
+        }
+
+        // the byte we have read is the beginning of the first legacy frame, so put it back
+        let mut parts = FramedParts::new(stream, NoiseCodec::new_legacy(inner_codec));
+        parts.read_buf.extend_from_slice(&[first_byte]);
+        Ok(Framed::from_parts(parts))
+    };
+
+    tokio::time::timeout(config.handshake_timeout, handshake)
+        .await
+        .map_err(|_| NoiseError::HandshakeTimeout(config.handshake_timeout))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::{Buf, BufMut, BytesMut};
+    use futures::{SinkExt, StreamExt};
+    use rand::rngs::OsRng;
+    use std::io;
+    use tokio_util::codec::{Decoder, Encoder};
+
+    // simple length-prefixed codec standing in for the `NymCodec`
+    struct TestCodec;
+
+    impl Encoder<Vec<u8>> for TestCodec {
+        type Error = io::Error;
+
+        fn encode(&mut self, item: Vec<u8>, dst: &mut BytesMut) -> Result<(), Self::Error> {
+            dst.put_u32(item.len() as u32);
+            dst.put_slice(&item);
+            Ok(())
+        }
+    }
+
+    impl Decoder for TestCodec {
+        type Item = Vec<u8>;
+        type Error = io::Error;
+
+        fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+            if src.len() < 4 {
+                return Ok(None);
+            }
+            let len = u32::from_be_bytes([src[0], src[1], src[2], src[3]]) as usize;
+            if src.len() < 4 + len {
+                return Ok(None);
+            }
+            src.advance(4);
+            Ok(Some(src.split_to(len).to_vec()))
+        }
+    }
+
+    fn dummy_address() -> SocketAddr {
+        "1.2.3.4:1789".parse().unwrap()
+    }
+
+    fn configs(allow_legacy_links: bool) -> (NoiseConfig, NoiseConfig) {
+        let initiator_keys = Arc::new(encryption::KeyPair::new(&mut OsRng));
+        let responder_keys = Arc::new(encryption::KeyPair::new(&mut OsRng));
+
+        // both nodes are part of the same topology
+        let peers = NoisePeerKeys::new();
+        peers.insert(
+            "initiator".to_string(),
+            NoisePeer {
+                mix_address: "9.9.9.9:1789".parse().unwrap(),
+                noise_key: *initiator_keys.public_key(),
+                supports_noise: true,
+            },
+        );
+        peers.insert(
+            "responder".to_string(),
+            NoisePeer {
+                mix_address: dummy_address(),
+                noise_key: *responder_keys.public_key(),
+                supports_noise: true,
+            },
+        );
+
+        (
+            NoiseConfig::new(initiator_keys, peers.clone()).with_legacy_links(allow_legacy_links),
+            NoiseConfig::new(responder_keys, peers).with_legacy_links(allow_legacy_links),
+        )
+    }
+
+    #[tokio::test]
+    async fn noise_link_can_carry_frames_in_both_directions() {
+        let (initiator_cfg, responder_cfg) = configs(false);
+        let (client, server) = tokio::io::duplex(256 * 1024);
+
+        let initiator = upgrade_noise_initiator(client, TestCodec, &initiator_cfg, dummy_address());
+        let responder = upgrade_noise_responder(server, TestCodec, &responder_cfg);
+        let (initiator, responder) = tokio::join!(initiator, responder);
+        let mut initiator = initiator.unwrap();
+        let mut responder = responder.unwrap();
+
+        assert!(initiator.codec().is_encrypted());
+        assert_eq!(
+            responder.codec().remote_static_key(),
+            Some(initiator_cfg.local_keys.public_key())
+        );
+
+        // including frame bigger than a single noise message
+        let frames = vec![vec![1u8; 42], vec![2u8; 100_000], vec![3u8; 2048]];
+        for frame in &frames {
+            initiator.send(frame.clone()).await.unwrap();
+        }
+        for frame in &frames {
+            assert_eq!(&responder.next().await.unwrap().unwrap(), frame);
+        }
+
+        responder.send(vec![4u8; 10]).await.unwrap();
+        assert_eq!(initiator.next().await.unwrap().unwrap(), vec![4u8; 10]);
+    }
+
+    #[tokio::test]
+    async fn responder_rejects_initiator_outside_the_topology() {
+        let (_, responder_cfg) = configs(true);
+        let (client, server) = tokio::io::duplex(1024);
+
+        // the initiator knows the responder, but the responder doesn't know the initiator
+        let unknown_keys = Arc::new(encryption::KeyPair::new(&mut OsRng));
+        let unknown_key = *unknown_keys.public_key();
+        let unknown_initiator_cfg =
+            NoiseConfig::new(unknown_keys, responder_cfg.peer_keys().clone());
+
+        let initiator =
+            upgrade_noise_initiator(client, TestCodec, &unknown_initiator_cfg, dummy_address());
+        let responder = upgrade_noise_responder(server, TestCodec, &responder_cfg);
+        let (_, responder) = tokio::join!(initiator, responder);
+        assert!(matches!(
+            responder,
+            Err(NoiseError::UnknownInitiatorKey(key)) if key == unknown_key
+        ));
+    }
+
+    #[tokio::test]
+    async fn handshake_failure_with_known_peer_does_not_result_in_legacy_link() {
+        let (initiator_cfg, _) = configs(true);
+        let (client, server) = tokio::io::duplex(1024);
+
+        // somebody else is listening on the address of the known node
+        let impostor_cfg = NoiseConfig::new(
+            Arc::new(encryption::KeyPair::new(&mut OsRng)),
+            initiator_cfg.peer_keys().clone(),
+        );
+
+        let initiator = upgrade_noise_initiator(client, TestCodec, &initiator_cfg, dummy_address());
+        let responder = upgrade_noise_responder(server, TestCodec, &impostor_cfg);
+        let (initiator, responder) = tokio::join!(initiator, responder);
+        assert!(matches!(responder, Err(NoiseError::ProtocolError(_))));
+        assert!(initiator.is_err());
+    }
+
+    #[tokio::test]
+    async fn legacy_initiator_is_accepted_if_allowed() {
+        let (_, responder_cfg) = configs(true);
+        let (client, server) = tokio::io::duplex(1024);
+
+        let mut legacy_initiator = Framed::new(client, TestCodec);
+        legacy_initiator.send(vec![5u8; 16]).await.unwrap();
+
+        let mut responder = upgrade_noise_responder(server, TestCodec, &responder_cfg)
+            .await
+            .unwrap();
+        assert!(!responder.codec().is_encrypted());
+        assert_eq!(responder.next().await.unwrap().unwrap(), vec![5u8; 16]);
+    }
+
+    #[tokio::test]
+    async fn legacy_initiator_is_rejected_if_not_allowed() {
+        let (_, responder_cfg) = configs(false);
+        let (client, server) = tokio::io::duplex(1024);
+
+        let mut legacy_initiator = Framed::new(client, TestCodec);
+        legacy_initiator.send(vec![5u8; 16]).await.unwrap();
+
+        assert!(matches!(
+            upgrade_noise_responder(server, TestCodec, &responder_cfg).await,
+            Err(NoiseError::LegacyLinkRejected)
+        ));
+    }
+
+    #[tokio::test]
+    async fn upgraded_initiator_uses_legacy_link_with_legacy_listener() {
+        let (initiator_cfg, _) = configs(true);
+        let (client, server) = tokio::io::duplex(1024);
+
+        // the listener is a known network node, but it's running an older version
+        let mut legacy_peer = initiator_cfg.peer_keys().get("responder").unwrap();
+        legacy_peer.supports_noise = false;
+        initiator_cfg
+            .peer_keys()
+            .insert("responder".to_string(), legacy_peer);
+
+        let mut link = upgrade_noise_initiator(client, TestCodec, &initiator_cfg, dummy_address())
+            .await
+            .unwrap();
+        assert!(!link.codec().is_encrypted());
+
+        // and the legacy listener, which doesn't know anything about noise, can read the frames
+        let mut legacy_listener = Framed::new(server, TestCodec);
+        link.send(vec![6u8; 32]).await.unwrap();
+        assert_eq!(
+            legacy_listener.next().await.unwrap().unwrap(),
+            vec![6u8; 32]
+        );
+
+        legacy_listener.send(vec![7u8; 8]).await.unwrap();
+        assert_eq!(link.next().await.unwrap().unwrap(), vec![7u8; 8]);
+    }
+
+    #[tokio::test]
+    async fn legacy_peer_is_rejected_if_legacy_links_are_not_allowed() {
+        let (initiator_cfg, _) = configs(false);
+        let (client, _server) = tokio::io::duplex(1024);
+
+        let mut legacy_peer = initiator_cfg.peer_keys().get("responder").unwrap();
+        legacy_peer.supports_noise = false;
+        initiator_cfg
+            .peer_keys()
+            .insert("responder".to_string(), legacy_peer);
+
+        assert!(matches!(
+            upgrade_noise_initiator(client, TestCodec, &initiator_cfg, dummy_address()).await,
+            Err(NoiseError::NoiseUnsupported(identity)) if identity == "responder"
+        ));
+    }
+
+    #[tokio::test]
+    async fn unknown_peer_results_in_legacy_link_only_if_allowed() {
+        let (initiator_cfg, _) = configs(true);
+        let (client, _server) = tokio::io::duplex(1024);
+        let unknown: SocketAddr = "5.6.7.8:1789".parse().unwrap();
+
+        let link = upgrade_noise_initiator(client, TestCodec, &initiator_cfg, unknown)
+            .await
+            .unwrap();
+        assert!(!link.codec().is_encrypted());
+
+        let (initiator_cfg, _) = configs(false);
+        let (client, _server) = tokio::io::duplex(1024);
+        assert!(matches!(
+            upgrade_noise_initiator(client, TestCodec, &initiator_cfg, unknown).await,
+            Err(NoiseError::UnknownPeerKey(addr)) if addr == unknown
+        ));
+    }
+}
@@ -13,8 +13,8 @@ use nym_sphinx_params::packet_sizes::PacketSize;
 use nym_sphinx_params::{
     PacketEncryptionAlgorithm, PacketHkdfAlgorithm, PacketType, DEFAULT_NUM_MIX_HOPS,
 };
-use nym_sphinx_types::NymPacket;
-use nym_topology::{NymTopology, NymTopologyError};
+use nym_sphinx_types::{Destination, Node as SphinxNode, NymPacket};
+use nym_topology::{gateway, NymTopology, NymTopologyError};
 use rand::{CryptoRng, RngCore};
 use std::convert::TryFrom;
 use std::time;
@@ -22,6 +22,9 @@ use thiserror::Error;
 
 pub const LOOP_COVER_MESSAGE_PAYLOAD: &[u8] = b"The cake is a lie!";
 
+/// Prefix of the dummy messages pushed by gateways to their clients that requested receive cover.
+pub const RECEIVE_COVER_MESSAGE_PAYLOAD: &[u8] = b"This was a triumph!";
+
 #[derive(Debug, Error)]
 pub enum CoverMessageError {
     #[error("Could not construct cover message due to invalid topology - {0}")]
@@ -120,9 +123,85 @@ where
 
     let route =
         topology.random_route_to_gateway(rng, DEFAULT_NUM_MIX_HOPS, full_address.gateway())?;
-    let delays = nym_sphinx_routing::generate_hop_delays(average_packet_delay, route.len());
     let destination = full_address.as_sphinx_destination();
 
+    build_cover_packet(
+        packet_payload,
+        route,
+        destination,
+        average_packet_delay,
+        packet_size,
+        packet_type,
+    )
+}
+
+/// Generates a drop cover packet, i.e. a packet sent through the mix network to the provided gateway,
+/// which, upon noticing it's addressed to the gateway itself, is going to silently discard it.
+/// Unlike the loop cover messages, drop cover never comes back to the sender.
+#[allow(clippy::too_many_arguments)]
+pub fn generate_drop_cover_packet<R>(
+    rng: &mut R,
+    topology: &NymTopology,
+    ack_key: &AckKey,
+    full_address: &Recipient,
+    target_gateway: &gateway::Node,
+    average_ack_delay: time::Duration,
+    average_packet_delay: time::Duration,
+    packet_size: PacketSize,
+    packet_type: PacketType,
+) -> Result<MixPacket, CoverMessageError>
+where
+    R: RngCore + CryptoRng,
+{
+    // the ack is never going to be forwarded by the target gateway, however, it has to be
+    // well-formed so that the packet could be processed like any other
+    let (_, ack_bytes) = generate_loop_cover_surb_ack(
+        rng,
+        topology,
+        ack_key,
+        full_address,
+        average_ack_delay,
+        packet_type,
+    )?
+    .prepare_for_sending()?;
+
+    // nobody is ever going to look at the content, so it's sufficient to make it indistinguishable
+    // from an ephemeral key followed by the ciphertext
+    let mut content = vec![0u8; packet_size.plaintext_size() - ack_bytes.len()];
+    rng.fill_bytes(&mut content);
+
+    let packet_payload: Vec<_> = ack_bytes.into_iter().chain(content.into_iter()).collect();
+
+    let route = topology.random_route_to_gateway(
+        rng,
+        DEFAULT_NUM_MIX_HOPS,
+        &target_gateway.identity_key,
+    )?;
+    let destination = Destination::new(
+        target_gateway.identity_key.derive_destination_address(),
+        Default::default(),
+    );
+
+    build_cover_packet(
+        packet_payload,
+        route,
+        destination,
+        average_packet_delay,
+        packet_size,
+        packet_type,
+    )
+}
+
+fn build_cover_packet(
+    packet_payload: Vec<u8>,
+    route: Vec<SphinxNode>,
+    destination: Destination,
+    average_packet_delay: time::Duration,
+    packet_size: PacketSize,
+    packet_type: PacketType,
+) -> Result<MixPacket, CoverMessageError> {
+    let delays = nym_sphinx_routing::generate_hop_delays(average_packet_delay, route.len());
+
     let first_hop_address =
         NymNodeRoutingAddress::try_from(route.first().unwrap().address).unwrap();
 
@@ -171,9 +250,137 @@ pub fn is_cover(data: &[u8]) -> bool {
     true
 }
 
+/// Generates the content of a receive cover message that gateways push to their clients.
+/// Its length matches the payload of a regular packet after the gateway had removed the SURB-ACK,
+/// so that, once encrypted with the shared keys, it's indistinguishable from an actual pushed message.
+pub fn generate_receive_cover_payload(packet_size: PacketSize) -> Vec<u8> {
+    let ack_overhead = SurbAck::len(Some(PacketType::Mix));
+
+    RECEIVE_COVER_MESSAGE_PAYLOAD
+        .iter()
+        .cloned()
+        .chain(std::iter::repeat(0))
+        .take(packet_size.plaintext_size() - ack_overhead)
+        .collect()
+}
+
+/// Helper function used to determine if given message pushed by the gateway represents a receive cover message.
+pub fn is_receive_cover(data: &[u8]) -> bool {
+    data.starts_with(RECEIVE_COVER_MESSAGE_PAYLOAD)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use nym_crypto::asymmetric::{encryption, identity};
+    use nym_sphinx_types::{NymProcessedPacket, ProcessedPacket};
+    use nym_topology::mix;
+    use rand::rngs::OsRng;
+    use std::collections::BTreeMap;
+
+    fn test_keys(seed: u8) -> (identity::PublicKey, encryption::PrivateKey) {
+        let identity_key =
+            identity::PublicKey::from(&identity::PrivateKey::from_bytes(&[seed; 32]).unwrap());
+        let sphinx_key = encryption::PrivateKey::from_bytes(&[seed; 32]).unwrap();
+        (identity_key, sphinx_key)
+    }
+
+    fn test_mix(seed: u8, layer: mix::Layer) -> (mix::Node, encryption::PrivateKey) {
+        let (identity_key, private_key) = test_keys(seed);
+        let node = mix::Node {
+            mix_id: seed as u32,
+            owner: format!("owner{seed}"),
+            host: format!("10.0.0.{seed}").parse().unwrap(),
+            mix_host: format!("10.0.0.{seed}:1789").parse().unwrap(),
+            identity_key,
+            sphinx_key: (&private_key).into(),
+            layer,
+            version: "1.1.23".to_string(),
+        };
+        (node, private_key)
+    }
+
+    fn test_gateway(seed: u8) -> (gateway::Node, encryption::PrivateKey) {
+        let (identity_key, private_key) = test_keys(seed);
+        let node = gateway::Node {
+            owner: format!("owner{seed}"),
+            host: format!("10.0.0.{seed}").parse().unwrap(),
+            mix_host: format!("10.0.0.{seed}:1789").parse().unwrap(),
+            clients_port: 9000,
+            clients_wss_port: None,
+            identity_key,
+            sphinx_key: (&private_key).into(),
+            version: "1.1.23".to_string(),
+        };
+        (node, private_key)
+    }
+
+    #[test]
+    fn drop_cover_packet_is_addressed_to_the_target_gateway() {
+        let mut rng = OsRng;
+
+        let layers = [mix::Layer::One, mix::Layer::Two, mix::Layer::Three];
+        let mut mixes = BTreeMap::new();
+        let mut mix_keys = Vec::new();
+        for (i, layer) in layers.into_iter().enumerate() {
+            let (node, private_key) = test_mix(i as u8 + 1, layer);
+            mixes.insert(i as u8 + 1, vec![node]);
+            mix_keys.push(private_key);
+        }
+        let first_mix = mixes[&1][0].mix_host;
+
+        let (our_gateway, _) = test_gateway(10);
+        let (target_gateway, target_key) = test_gateway(11);
+        let topology = NymTopology::new(mixes, vec![our_gateway.clone(), target_gateway.clone()]);
+
+        let (client_identity, client_key) = test_keys(20);
+        let full_address = Recipient::new(
+            client_identity,
+            (&client_key).into(),
+            our_gateway.identity_key,
+        );
+
+        let packet = generate_drop_cover_packet(
+            &mut rng,
+            &topology,
+            &AckKey::new(&mut rng),
+            &full_address,
+            &target_gateway,
+            time::Duration::from_millis(10),
+            time::Duration::from_millis(10),
+            PacketSize::RegularPacket,
+            PacketType::Mix,
+        )
+        .unwrap();
+
+        assert_eq!(packet.packet().len(), PacketSize::RegularPacket.size());
+        assert_eq!(packet.next_hop(), NymNodeRoutingAddress::from(first_mix));
+
+        // unwrap all the layers to make sure the packet ends up at the specified gateway
+        let mut packet = packet.into_packet();
+        for mix_key in &mix_keys {
+            packet = match packet.process(&mix_key.into()).unwrap() {
+                NymProcessedPacket::Sphinx(ProcessedPacket::ForwardHop(packet, ..)) => {
+                    NymPacket::Sphinx(*packet)
+                }
+                _ => panic!("expected a forward hop packet"),
+            };
+        }
+
+        match packet.process(&(&target_key).into()).unwrap() {
+            NymProcessedPacket::Sphinx(ProcessedPacket::FinalHop(destination, ..)) => {
+                assert_eq!(
+                    destination,
+                    target_gateway.identity_key.derive_destination_address()
+                );
+                assert_ne!(
+                    destination,
+                    our_gateway.identity_key.derive_destination_address()
+                )
+            }
+            _ => panic!("expected a final hop packet"),
+        }
+    }
 
     #[test]
     fn is_cover_works_for_identical_input() {
@@ -213,4 +420,25 @@ mod tests {
         let empty = Vec::new();
         assert!(!is_cover(&empty))
     }
+
+    #[test]
+    fn generated_receive_cover_is_recognised() {
+        let payload = generate_receive_cover_payload(PacketSize::RegularPacket);
+        assert!(is_receive_cover(&payload));
+        assert!(!is_cover(&payload));
+    }
+
+    #[test]
+    fn generated_receive_cover_has_the_size_of_pushed_regular_message() {
+        let payload = generate_receive_cover_payload(PacketSize::RegularPacket);
+        assert_eq!(
+            payload.len(),
+            PacketSize::RegularPacket.plaintext_size() - SurbAck::len(Some(PacketType::Mix))
+        );
+    }
+
+    #[test]
+    fn is_receive_cover_returns_false_for_loop_cover() {
+        assert!(!is_receive_cover(LOOP_COVER_MESSAGE_PAYLOAD))
+    }
 }
@@ -32,8 +32,8 @@ pub enum NymCodecError {
     FromBytes,
 }
 
-// TODO: in the future it could be extended to have state containing symmetric encryption key
-// so that all data could be encrypted easily (alternatively we could just slap TLS)
+// note: the codec itself does not perform any encryption. links between nodes wrap it
+// in the `NoiseCodec` from the `nym-noise` crate once the noise handshake has been completed.
 pub struct NymCodec;
 
 impl Encoder<FramedNymPacket> for NymCodec {
@@ -19,7 +19,7 @@ use nym_sphinx_params::{
 use thiserror::Error;
 
 // TODO: should this live in this file?
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReconstructedMessage {
     /// The actual plaintext message that was received.
     pub message: Vec<u8>,
@@ -295,6 +295,7 @@ mod message_receiver {
                 host: "1.2.3.4".parse().unwrap(),
                 mix_host: "1.2.3.4:1789".parse().unwrap(),
                 clients_port: 9000,
+                clients_wss_port: None,
                 identity_key: identity::PublicKey::from_base58_string(
                     "FioFa8nMmPpQnYi7JyojoTuwGLeyNS8BF4ChPr29zUML",
                 )
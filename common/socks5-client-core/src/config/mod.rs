@@ -8,6 +8,7 @@ use nym_sphinx::addressing::clients::Recipient;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::str::FromStr;
+use std::time::Duration;
 
 pub mod old_config_v1_1_20_2;
 
@@ -17,6 +18,11 @@ pub use nym_socks5_requests::Socks5ProtocolVersion;
 const DEFAULT_CONNECTION_START_SURBS: u32 = 20;
 const DEFAULT_PER_REQUEST_SURBS: u32 = 3;
 
+const DEFAULT_PROVIDER_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+const DEFAULT_PROVIDER_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(20);
+const DEFAULT_PROVIDER_FAILOVER_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+const DEFAULT_MAX_FAILED_PROVIDER_HEALTH_CHECKS: u32 = 3;
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
@@ -53,6 +59,11 @@ impl Config {
         self
     }
 
+    pub fn with_automatic_provider_selection(mut self, automatic_provider_selection: bool) -> Self {
+        self.socks5.automatic_provider_selection = automatic_provider_selection;
+        self
+    }
+
     // poor man's 'builder' method
     pub fn with_base<F, T>(mut self, f: F, val: T) -> Self
     where
@@ -124,6 +135,12 @@ pub struct Socks5 {
     #[serde(default)]
     pub send_anonymously: bool,
 
+    /// Specifies whether the network requester should be chosen automatically, based on the service
+    /// provider directory and the health of the requesters, rather than always using `provider_mix_address`.
+    /// If enabled, the client will also switch to a different requester once the current one stops responding.
+    #[serde(default)]
+    pub automatic_provider_selection: bool,
+
     #[serde(default)]
     pub socks5_debug: Socks5Debug,
 }
@@ -136,6 +153,7 @@ impl Socks5 {
             provider_interface_version: ProviderInterfaceVersion::Legacy,
             socks5_protocol_version: Socks5ProtocolVersion::Legacy,
             send_anonymously: false,
+            automatic_provider_selection: false,
             socks5_debug: Default::default(),
         }
     }
@@ -144,6 +162,10 @@ impl Socks5 {
         Recipient::try_from_base58_string(&self.provider_mix_address)
             .expect("malformed provider address")
     }
+
+    pub fn try_get_provider_mix_address(&self) -> Option<Recipient> {
+        Recipient::try_from_base58_string(&self.provider_mix_address).ok()
+    }
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
@@ -154,6 +176,51 @@ pub struct Socks5Debug {
 
     /// Number of reply SURBs attached to each `Request::Send` message.
     pub per_request_surbs: u32,
+
+    /// Defines how often the health of the network requester is checked when using automatic
+    /// provider selection.
+    #[serde(
+        with = "humantime_serde",
+        default = "default_provider_health_check_interval"
+    )]
+    pub provider_health_check_interval: Duration,
+
+    /// Defines how long the client waits for the network requester to respond to a health check.
+    #[serde(
+        with = "humantime_serde",
+        default = "default_provider_health_check_timeout"
+    )]
+    pub provider_health_check_timeout: Duration,
+
+    /// Defines how long the client waits for each of the candidate network requesters to respond
+    /// to a health check when looking for a replacement for the current one.
+    /// The candidates are checked one at a time (as the health responses do not indicate their sender),
+    /// so this should be kept much shorter than `provider_health_check_timeout`.
+    #[serde(
+        with = "humantime_serde",
+        default = "default_provider_failover_health_check_timeout"
+    )]
+    pub provider_failover_health_check_timeout: Duration,
+
+    /// Number of consecutive failed health checks after which a different network requester is chosen.
+    #[serde(default = "default_max_failed_provider_health_checks")]
+    pub max_failed_provider_health_checks: u32,
+}
+
+fn default_provider_health_check_interval() -> Duration {
+    DEFAULT_PROVIDER_HEALTH_CHECK_INTERVAL
+}
+
+fn default_provider_health_check_timeout() -> Duration {
+    DEFAULT_PROVIDER_HEALTH_CHECK_TIMEOUT
+}
+
+fn default_provider_failover_health_check_timeout() -> Duration {
+    DEFAULT_PROVIDER_FAILOVER_HEALTH_CHECK_TIMEOUT
+}
+
+fn default_max_failed_provider_health_checks() -> u32 {
+    DEFAULT_MAX_FAILED_PROVIDER_HEALTH_CHECKS
 }
 
 impl Default for Socks5Debug {
@@ -161,6 +228,10 @@ impl Default for Socks5Debug {
         Socks5Debug {
             connection_start_surbs: DEFAULT_CONNECTION_START_SURBS,
             per_request_surbs: DEFAULT_PER_REQUEST_SURBS,
+            provider_health_check_interval: DEFAULT_PROVIDER_HEALTH_CHECK_INTERVAL,
+            provider_health_check_timeout: DEFAULT_PROVIDER_HEALTH_CHECK_TIMEOUT,
+            provider_failover_health_check_timeout: DEFAULT_PROVIDER_FAILOVER_HEALTH_CHECK_TIMEOUT,
+            max_failed_provider_health_checks: DEFAULT_MAX_FAILED_PROVIDER_HEALTH_CHECKS,
         }
     }
 }
@@ -53,6 +53,7 @@ impl From<Socks5V1_1_20_2> for Socks5 {
             provider_interface_version: value.provider_interface_version,
             socks5_protocol_version: value.socks5_protocol_version,
             send_anonymously: value.send_anonymously,
+            automatic_provider_selection: false,
             socks5_debug: value.socks5_debug.into(),
         }
     }
@@ -73,6 +74,7 @@ impl From<Socks5DebugV1_1_20_2> for Socks5Debug {
         Socks5Debug {
             connection_start_surbs: value.connection_start_surbs,
             per_request_surbs: value.per_request_surbs,
+            ..Default::default()
         }
     }
 }
@@ -18,6 +18,12 @@ pub enum Socks5ClientCoreError {
         connection_id: ConnectionId,
         error: String,
     },
+
+    #[error("automatic provider selection is enabled, but no network requester is available")]
+    NoProvidersAvailable,
+
+    #[error("no valid network requester has been configured. Either specify one with '--provider' or enable the automatic provider selection")]
+    NoProviderConfigured,
 }
 
 impl From<ConnectionError> for Socks5ClientCoreError {
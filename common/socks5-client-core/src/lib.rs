@@ -1,13 +1,16 @@
 // Copyright 2021-2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::config::Config;
+use crate::config::{Config, ProviderInterfaceVersion};
 use crate::error::Socks5ClientCoreError;
+use crate::provider_selection::{
+    PreferredProviders, ProviderMonitor, ProviderSelection, SharedProvider,
+};
 use crate::socks::{
     authentication::{AuthenticationMethods, Authenticator, User},
     server::NymSocksServer,
 };
-use futures::channel::mpsc;
+use futures::channel::{mpsc, oneshot};
 use futures::StreamExt;
 use log::*;
 use nym_client_core::client::base_client::non_wasm_helpers::default_query_dkg_client_from_config;
@@ -25,9 +28,11 @@ use nym_sphinx::addressing::clients::Recipient;
 use nym_sphinx::params::PacketType;
 use nym_task::{TaskClient, TaskManager};
 use std::error::Error;
+use std::path::PathBuf;
 
 pub mod config;
 pub mod error;
+pub mod provider_selection;
 pub mod socks;
 
 // Channels used to control the main task from outside
@@ -56,6 +61,10 @@ pub struct NymClient<S> {
     storage: S,
 
     setup_method: GatewaySetup,
+
+    /// Optional file used for remembering the network requesters that have worked before
+    /// when the automatic provider selection is enabled.
+    preferred_providers_store: Option<PathBuf>,
 }
 
 impl<S> NymClient<S>
@@ -72,6 +81,7 @@ where
             config,
             storage,
             setup_method: GatewaySetup::MustLoad,
+            preferred_providers_store: None,
         }
     }
 
@@ -80,6 +90,11 @@ where
         self
     }
 
+    pub fn with_preferred_providers_store<P: Into<PathBuf>>(mut self, store: P) -> Self {
+        self.preferred_providers_store = Some(store.into());
+        self
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn start_socks5_listener(
         socks5_config: &config::Socks5,
@@ -90,6 +105,7 @@ where
         self_address: Recipient,
         shutdown: TaskClient,
         packet_type: PacketType,
+        provider_selection: Option<ProviderSelection>,
     ) {
         info!("Starting socks5 listener...");
         let auth_methods = vec![AuthenticationMethods::NoAuth as u8];
@@ -114,11 +130,39 @@ where
             .secondary_packet_size
             .unwrap_or(base_debug.traffic.primary_packet_size);
 
+        let service_provider = SharedProvider::new(
+            provider_selection
+                .as_ref()
+                .and_then(|selection| selection.initial_provider())
+                .unwrap_or_else(|| socks5_config.get_provider_mix_address()),
+        );
+
+        let (control_response_sender, initial_provider_ready) = provider_selection
+            .map(|selection| {
+                let (control_response_sender, control_response_receiver) = mpsc::unbounded();
+                let (ready_sender, ready_receiver) = oneshot::channel();
+                let monitor = ProviderMonitor::new(
+                    service_provider.clone(),
+                    selection,
+                    input_sender.clone(),
+                    control_response_receiver,
+                    ready_sender,
+                    socks5_config.provider_interface_version,
+                    socks5_config.socks5_debug,
+                    socks5_config.send_anonymously,
+                    packet_type,
+                    shutdown.clone(),
+                );
+                tokio::spawn(monitor.run());
+                (control_response_sender, ready_receiver)
+            })
+            .unzip();
+
         let authenticator = Authenticator::new(auth_methods, allowed_users);
         let mut sphinx_socks = NymSocksServer::new(
             socks5_config.listening_port,
             authenticator,
-            socks5_config.get_provider_mix_address(),
+            service_provider,
             self_address,
             shared_lane_queue_lengths,
             socks::client::Config::new(
@@ -130,6 +174,8 @@ where
             ),
             shutdown.clone(),
             packet_type,
+            control_response_sender,
+            initial_provider_ready,
         );
         nym_task::spawn_with_report_error(
             async move {
@@ -201,7 +247,7 @@ where
         res
     }
 
-    pub async fn start(self) -> Result<StartedSocks5Client, Socks5ClientCoreError> {
+    pub async fn start(mut self) -> Result<StartedSocks5Client, Socks5ClientCoreError> {
         // don't create dkg client for the bandwidth controller if credentials are disabled
         let dkg_query_client = if self.config.base.client.disabled_credentials_mode {
             None
@@ -213,6 +259,31 @@ where
             BaseClientBuilder::new(&self.config.base, self.storage, dkg_query_client)
                 .with_gateway_setup(self.setup_method);
 
+        let provider_selection = if self.config.socks5.automatic_provider_selection {
+            // the health checks are sent as control requests, which the legacy interface does not
+            // support, while any requester announced in the directory understands the versioned one
+            if self.config.socks5.provider_interface_version.is_legacy() {
+                self.config.socks5.provider_interface_version =
+                    ProviderInterfaceVersion::new_current();
+            }
+            let mut selection = ProviderSelection::new(
+                self.config.socks5.try_get_provider_mix_address(),
+                PreferredProviders::load(self.preferred_providers_store),
+                self.config.base.client.nym_api_urls.clone(),
+            );
+            selection.refresh().await;
+            let Some(provider) = selection.initial_provider() else {
+                return Err(Socks5ClientCoreError::NoProvidersAvailable);
+            };
+            info!("Using network requester {provider}");
+            Some(selection)
+        } else {
+            if self.config.socks5.try_get_provider_mix_address().is_none() {
+                return Err(Socks5ClientCoreError::NoProviderConfigured);
+            }
+            None
+        };
+
         let packet_type = self.config.base.debug.traffic.packet_type;
         let mut started_client = base_builder.start_base().await?;
         let self_address = started_client.address;
@@ -231,6 +302,7 @@ where
             self_address,
             started_client.task_manager.subscribe(),
             packet_type,
+            provider_selection,
         );
 
         info!("Client startup finished!");
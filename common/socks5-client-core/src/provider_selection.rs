@@ -0,0 +1,636 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Automatic selection of the network requester, based on the service provider directory and
+//! health checks sent through the mixnet.
+
+use crate::config::Socks5Debug;
+use futures::channel::{mpsc, oneshot};
+use futures::StreamExt;
+use log::*;
+use nym_client_core::client::inbound_messages::{InputMessage, InputMessageSender};
+use nym_service_provider_directory_common::ServiceType;
+use nym_service_providers_common::interface::{
+    ControlRequest, ControlResponse, ProviderInterfaceVersion,
+};
+use nym_socks5_requests::Socks5ProviderRequest;
+use nym_sphinx::addressing::clients::Recipient;
+use nym_sphinx::params::PacketType;
+use nym_task::connections::TransmissionLane;
+use nym_task::TaskClient;
+use rand::seq::SliceRandom;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use url::Url;
+
+/// Maximum number of network requesters remembered across restarts.
+const MAX_PREFERRED_PROVIDERS: usize = 5;
+
+/// Number of reply SURBs attached to health checks when sending anonymously.
+const HEALTH_CHECK_REPLY_SURBS: u32 = 2;
+
+pub(crate) type ControlResponseSender = mpsc::UnboundedSender<ControlResponse>;
+pub(crate) type ControlResponseReceiver = mpsc::UnboundedReceiver<ControlResponse>;
+
+/// The network requester used for any new connections. It's shared between the socks5 server and
+/// the [`ProviderMonitor`], which replaces it once it stops responding.
+#[derive(Clone)]
+pub(crate) struct SharedProvider(Arc<RwLock<Recipient>>);
+
+impl SharedProvider {
+    pub(crate) fn new(provider: Recipient) -> Self {
+        SharedProvider(Arc::new(RwLock::new(provider)))
+    }
+
+    pub(crate) fn get(&self) -> Recipient {
+        match self.0.read() {
+            Ok(provider) => *provider,
+            Err(poisoned) => *poisoned.into_inner(),
+        }
+    }
+
+    fn set(&self, provider: Recipient) {
+        match self.0.write() {
+            Ok(mut guard) => *guard = provider,
+            Err(poisoned) => *poisoned.into_inner() = provider,
+        }
+    }
+}
+
+/// Network requesters that have successfully responded to this client before, most recent first.
+/// They're stored one per line so that they're tried first after a restart.
+#[derive(Debug, Default)]
+pub struct PreferredProviders {
+    store: Option<PathBuf>,
+    providers: Vec<Recipient>,
+}
+
+impl PreferredProviders {
+    pub fn load(store: Option<PathBuf>) -> Self {
+        let providers = store
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|content| {
+                content
+                    .lines()
+                    .filter_map(|line| Recipient::try_from_base58_string(line.trim()).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        PreferredProviders { store, providers }
+    }
+
+    pub fn providers(&self) -> &[Recipient] {
+        &self.providers
+    }
+
+    fn promote(&mut self, provider: Recipient) -> bool {
+        if self.providers.first() == Some(&provider) {
+            return false;
+        }
+        self.providers.retain(|preferred| preferred != &provider);
+        self.providers.insert(0, provider);
+        self.providers.truncate(MAX_PREFERRED_PROVIDERS);
+        true
+    }
+
+    fn persist(&self) {
+        let Some(store) = &self.store else {
+            return;
+        };
+        let content = self
+            .providers
+            .iter()
+            .map(|provider| provider.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        if let Err(err) = fs::write(store, content) {
+            warn!(
+                "failed to store the preferred network requesters in {}: {err}",
+                store.display()
+            );
+        }
+    }
+}
+
+/// Query the nym-api for network requesters that have recently sent a heartbeat to the service
+/// provider directory. The result is shuffled so that clients don't all pick the same requester.
+async fn query_live_providers(nym_api_urls: &[Url]) -> Vec<Recipient> {
+    for nym_api_url in nym_api_urls {
+        let client = nym_validator_client::NymApiClient::new(nym_api_url.clone());
+        match client.get_cached_service_providers_detailed().await {
+            Ok(services) => {
+                let mut providers = services
+                    .into_iter()
+                    .filter(|service| !service.stale)
+                    .filter(|service| {
+                        service.service.service.service_type == ServiceType::NetworkRequester
+                    })
+                    .filter_map(|service| {
+                        Recipient::try_from_base58_string(
+                            service.service.service.nym_address.as_str(),
+                        )
+                        .ok()
+                    })
+                    .collect::<Vec<_>>();
+                providers.shuffle(&mut rand::thread_rng());
+                return providers;
+            }
+            Err(err) => {
+                warn!("failed to query {nym_api_url} for the available network requesters: {err}")
+            }
+        }
+    }
+    Vec::new()
+}
+
+/// The network requesters the client may use, in order of preference: the one explicitly
+/// configured, the ones that worked before and finally the live ones from the directory.
+pub struct ProviderSelection {
+    candidates: Vec<Recipient>,
+    preferred: PreferredProviders,
+    nym_api_urls: Vec<Url>,
+}
+
+impl ProviderSelection {
+    pub fn new(
+        configured: Option<Recipient>,
+        preferred: PreferredProviders,
+        nym_api_urls: Vec<Url>,
+    ) -> Self {
+        let mut selection = ProviderSelection {
+            candidates: Vec::new(),
+            preferred,
+            nym_api_urls,
+        };
+        let known = configured
+            .into_iter()
+            .chain(selection.preferred.providers().iter().copied())
+            .collect::<Vec<_>>();
+        selection.add_candidates(known);
+        selection
+    }
+
+    fn add_candidates(&mut self, providers: impl IntoIterator<Item = Recipient>) {
+        for provider in providers {
+            if !self.candidates.contains(&provider) {
+                self.candidates.push(provider)
+            }
+        }
+    }
+
+    /// Add the network requesters currently marked as live by the nym-api to the candidates.
+    pub async fn refresh(&mut self) {
+        let live = query_live_providers(&self.nym_api_urls).await;
+        debug!("found {} live network requesters", live.len());
+        self.add_candidates(live);
+    }
+
+    pub fn initial_provider(&self) -> Option<Recipient> {
+        self.candidates.first().copied()
+    }
+
+    fn remember(&mut self, provider: Recipient) {
+        if self.preferred.promote(provider) {
+            self.preferred.persist();
+        }
+    }
+}
+
+/// Periodically checks the health of the current network requester and switches to a different
+/// one once it has failed to respond to too many consecutive checks.
+pub(crate) struct ProviderMonitor {
+    current: SharedProvider,
+    selection: ProviderSelection,
+    input_sender: InputMessageSender,
+    control_responses: ControlResponseReceiver,
+
+    /// Notifies the socks5 server once the initial network requester has been checked,
+    /// so that no connections would be made through an unresponsive one.
+    initial_provider_ready: Option<oneshot::Sender<()>>,
+
+    /// Identifier attached to the most recent health check, so that late responses to the previous
+    /// ones (possibly sent by a different provider) wouldn't be mistaken for a response to it.
+    last_health_check_id: u64,
+
+    provider_interface_version: ProviderInterfaceVersion,
+    socks5_debug: Socks5Debug,
+    send_anonymously: bool,
+    packet_type: PacketType,
+    shutdown: TaskClient,
+}
+
+impl ProviderMonitor {
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        current: SharedProvider,
+        selection: ProviderSelection,
+        input_sender: InputMessageSender,
+        control_responses: ControlResponseReceiver,
+        initial_provider_ready: oneshot::Sender<()>,
+        provider_interface_version: ProviderInterfaceVersion,
+        socks5_debug: Socks5Debug,
+        send_anonymously: bool,
+        packet_type: PacketType,
+        shutdown: TaskClient,
+    ) -> Self {
+        ProviderMonitor {
+            current,
+            selection,
+            input_sender,
+            control_responses,
+            initial_provider_ready: Some(initial_provider_ready),
+            last_health_check_id: 0,
+            provider_interface_version,
+            socks5_debug,
+            send_anonymously,
+            packet_type,
+            shutdown,
+        }
+    }
+
+    async fn check_health(&mut self, provider: Recipient, timeout: Duration) -> bool {
+        // providers predating the tagged health checks respond with untagged responses,
+        // so get rid of any late responses to previous checks
+        while let Ok(Some(_)) = self.control_responses.try_next() {}
+
+        self.last_health_check_id = self.last_health_check_id.wrapping_add(1);
+        let health_check_id = self.last_health_check_id;
+        let request = Socks5ProviderRequest::new_control(
+            self.provider_interface_version,
+            ControlRequest::TaggedHealth(health_check_id),
+        );
+        let input_message = if self.send_anonymously {
+            InputMessage::new_anonymous(
+                provider,
+                request.into_bytes(),
+                HEALTH_CHECK_REPLY_SURBS,
+                TransmissionLane::General,
+                Some(self.packet_type),
+            )
+        } else {
+            InputMessage::new_regular(
+                provider,
+                request.into_bytes(),
+                TransmissionLane::General,
+                Some(self.packet_type),
+            )
+        };
+        if self.input_sender.send(input_message).await.is_err() {
+            return false;
+        }
+
+        let control_responses = &mut self.control_responses;
+        let health_response = async {
+            while let Some(response) = control_responses.next().await {
+                match response {
+                    ControlResponse::TaggedHealth(id) if id == health_check_id => return true,
+                    ControlResponse::TaggedHealth(id) => {
+                        debug!("ignoring a late response to the health check {id}")
+                    }
+                    // the provider doesn't know about tagged health checks
+                    ControlResponse::Health => return true,
+                    other => debug!("ignoring an unexpected control response: {other:?}"),
+                }
+            }
+            false
+        };
+        tokio::time::timeout(timeout, health_response)
+            .await
+            .unwrap_or(false)
+    }
+
+    async fn check_current_health(&mut self, provider: Recipient) -> bool {
+        self.check_health(provider, self.socks5_debug.provider_health_check_timeout)
+            .await
+    }
+
+    // since the health responses of older providers do not indicate their sender, the candidates
+    // can't be probed concurrently. Instead each of them gets a much shorter timeout than
+    // the current provider.
+    async fn fail_over(&mut self, failed: Recipient) {
+        self.selection.refresh().await;
+
+        let candidates = self
+            .selection
+            .candidates
+            .iter()
+            .copied()
+            .filter(|candidate| *candidate != failed)
+            .collect::<Vec<_>>();
+
+        for candidate in candidates {
+            if self.shutdown.is_shutdown_poll() {
+                return;
+            }
+            let timeout = self.socks5_debug.provider_failover_health_check_timeout;
+            if self.check_health(candidate, timeout).await {
+                info!("switching network requester from {failed} to {candidate}");
+                self.current.set(candidate);
+                self.selection.remember(candidate);
+                return;
+            }
+            debug!("network requester {candidate} failed to respond to the health check");
+        }
+        warn!(
+            "none of the known network requesters responded to the health check. Keeping {failed}"
+        );
+    }
+
+    /// Makes sure the network requester we're starting with is responsive before the socks5 server
+    /// starts using it, replacing it straight away otherwise.
+    async fn check_initial_provider(&mut self) {
+        let provider = self.current.get();
+        if self.check_current_health(provider).await {
+            self.selection.remember(provider);
+        } else {
+            warn!("the initial network requester {provider} failed to respond to the health check");
+            self.fail_over(provider).await;
+        }
+
+        if let Some(ready) = self.initial_provider_ready.take() {
+            // the server might have already shut down
+            let _ = ready.send(());
+        }
+    }
+
+    pub(crate) async fn run(mut self) {
+        self.check_initial_provider().await;
+
+        let max_failed_checks = self.socks5_debug.max_failed_provider_health_checks;
+        let check_interval = self.socks5_debug.provider_health_check_interval;
+        // the initial provider has just been checked, so there's no need for an immediate tick
+        let mut health_check_interval =
+            tokio::time::interval_at(tokio::time::Instant::now() + check_interval, check_interval);
+        let mut failed_checks = 0;
+
+        while !self.shutdown.is_shutdown() {
+            tokio::select! {
+                _ = health_check_interval.tick() => {
+                    let provider = self.current.get();
+                    if self.check_current_health(provider).await {
+                        failed_checks = 0;
+                        self.selection.remember(provider);
+                    } else {
+                        failed_checks += 1;
+                        warn!("network requester {provider} failed to respond to the health check ({failed_checks}/{max_failed_checks})");
+                        if failed_checks >= max_failed_checks {
+                            self.fail_over(provider).await;
+                            failed_checks = 0;
+                        }
+                    }
+                }
+                _ = self.shutdown.recv() => {
+                    log::trace!("ProviderMonitor: Received shutdown");
+                }
+            }
+        }
+        self.shutdown.recv_timeout().await;
+        log::debug!("ProviderMonitor: Exiting");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nym_client_core::client::inbound_messages::InputMessageReceiver;
+    use nym_service_providers_common::interface::RequestContent;
+    use std::sync::Mutex;
+
+    const PROVIDER1: &str = "3KRydEpanwjFhq5GAraVjRUF1Tno7w7oc4EwJYTGNo5J.RgZ7uMJHruBQqD5hC9Ghi3sqiTn6NycfM5qCfJz6yoM@9Byd9VAtyYMnbVAcqdoQxJnq76XEg2dbxbiF5Aa5Jj9J";
+    const PROVIDER2: &str = "AN8eLxYWFitCkMn92zim3PrPszxJZDYyFFKP7qnnAAew.8UAxL3LwQBis6WpM3GGXaqKGaVdnLCpGJWumHT6KNdTH@77TSuVU8d1oXKbPzjec2xh4i3Wj5WwUyy9Lr36sm8gZm";
+    const PROVIDER3: &str = "CytBseW6yFXUMzz4SGAKdNLGR7q3sJLLYxyBGvutNEQV.4QXYyEVc5fUDjmmi8PrHN9tdUFV4PCvSJE1278cHyvoe@4sBbL1ngf1vtNqykydQKTFh26sQCw888GpUqvPvyNB4f";
+
+    fn provider(address: &str) -> Recipient {
+        Recipient::try_from_base58_string(address).unwrap()
+    }
+
+    #[test]
+    fn promoting_moves_provider_to_the_front() {
+        let mut preferred = PreferredProviders::default();
+        assert!(preferred.promote(provider(PROVIDER1)));
+        assert!(preferred.promote(provider(PROVIDER2)));
+        assert!(!preferred.promote(provider(PROVIDER2)));
+        assert!(preferred.promote(provider(PROVIDER1)));
+        assert_eq!(
+            preferred.providers(),
+            &[provider(PROVIDER1), provider(PROVIDER2)]
+        );
+    }
+
+    #[test]
+    fn configured_provider_is_tried_first() {
+        let mut preferred = PreferredProviders::default();
+        preferred.promote(provider(PROVIDER2));
+        preferred.promote(provider(PROVIDER1));
+
+        let selection = ProviderSelection::new(Some(provider(PROVIDER2)), preferred, Vec::new());
+        assert_eq!(selection.initial_provider(), Some(provider(PROVIDER2)));
+        assert_eq!(
+            selection.candidates,
+            vec![provider(PROVIDER2), provider(PROVIDER1)]
+        );
+
+        let selection = ProviderSelection::new(None, PreferredProviders::default(), Vec::new());
+        assert_eq!(selection.initial_provider(), None);
+    }
+
+    #[test]
+    fn preferred_providers_are_persisted() {
+        let store = std::env::temp_dir().join(format!(
+            "nym-socks5-preferred-providers-{}",
+            std::process::id()
+        ));
+        let preferred = PreferredProviders::load(Some(store.clone()));
+        assert!(preferred.providers().is_empty());
+
+        let mut selection = ProviderSelection::new(None, preferred, Vec::new());
+        selection.remember(provider(PROVIDER1));
+        selection.remember(provider(PROVIDER2));
+        selection.remember(provider(PROVIDER3));
+        selection.remember(provider(PROVIDER2));
+
+        let reloaded = PreferredProviders::load(Some(store.clone()));
+        assert_eq!(
+            reloaded.providers(),
+            &[
+                provider(PROVIDER2),
+                provider(PROVIDER3),
+                provider(PROVIDER1)
+            ]
+        );
+        fs::remove_file(store).unwrap();
+    }
+
+    /// Pretends to be the network requesters, answering the health checks of the ones that are
+    /// currently up after their response delay.
+    #[derive(Clone, Default)]
+    struct MockProviders {
+        up: Arc<Mutex<Vec<(Recipient, Duration)>>>,
+    }
+
+    impl MockProviders {
+        fn start(&self, provider: Recipient, response_delay: Duration) {
+            self.up.lock().unwrap().push((provider, response_delay))
+        }
+
+        fn stop(&self, provider: Recipient) {
+            self.up.lock().unwrap().retain(|(up, _)| *up != provider)
+        }
+
+        fn response_delay(&self, provider: Recipient) -> Option<Duration> {
+            self.up
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|(up, _)| *up == provider)
+                .map(|(_, delay)| *delay)
+        }
+
+        fn spawn(self, mut requests: InputMessageReceiver, responses: ControlResponseSender) {
+            tokio::spawn(async move {
+                while let Some(message) = requests.recv().await {
+                    let (recipient, data) = unwrap_input_message(message);
+                    let Some(delay) = self.response_delay(recipient) else {
+                        continue;
+                    };
+                    let request = Socks5ProviderRequest::try_from_bytes(&data).unwrap();
+                    let RequestContent::Control(ControlRequest::TaggedHealth(id)) = request.content
+                    else {
+                        panic!("unexpected request: {:?}", request.content);
+                    };
+                    let responses = responses.clone();
+                    tokio::spawn(async move {
+                        tokio::time::sleep(delay).await;
+                        responses
+                            .unbounded_send(ControlResponse::TaggedHealth(id))
+                            .unwrap();
+                    });
+                }
+            });
+        }
+    }
+
+    fn unwrap_input_message(message: InputMessage) -> (Recipient, Vec<u8>) {
+        match message {
+            InputMessage::Regular {
+                recipient, data, ..
+            }
+            | InputMessage::Anonymous {
+                recipient, data, ..
+            } => (recipient, data),
+            InputMessage::MessageWrapper { message, .. } => unwrap_input_message(*message),
+            other => panic!("unexpected input message: {other:?}"),
+        }
+    }
+
+    fn test_debug_config() -> Socks5Debug {
+        Socks5Debug {
+            provider_health_check_interval: Duration::from_secs(30),
+            provider_health_check_timeout: Duration::from_secs(10),
+            provider_failover_health_check_timeout: Duration::from_secs(2),
+            max_failed_provider_health_checks: 2,
+            ..Default::default()
+        }
+    }
+
+    fn test_monitor(
+        initial: Recipient,
+        selection: ProviderSelection,
+        providers: MockProviders,
+    ) -> (ProviderMonitor, SharedProvider, oneshot::Receiver<()>) {
+        let current = SharedProvider::new(initial);
+        let (input_sender, input_receiver) = tokio::sync::mpsc::channel(16);
+        let (control_response_sender, control_responses) = mpsc::unbounded();
+        let (ready_sender, ready_receiver) = oneshot::channel();
+        providers.spawn(input_receiver, control_response_sender);
+
+        let monitor = ProviderMonitor::new(
+            current.clone(),
+            selection,
+            input_sender,
+            control_responses,
+            ready_sender,
+            ProviderInterfaceVersion::new_current(),
+            test_debug_config(),
+            false,
+            PacketType::Mix,
+            TaskClient::dummy(),
+        );
+        (monitor, current, ready_receiver)
+    }
+
+    fn selection_of(providers: &[Recipient]) -> ProviderSelection {
+        let mut selection = ProviderSelection::new(None, PreferredProviders::default(), Vec::new());
+        selection.add_candidates(providers.iter().copied());
+        selection
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn health_check_requires_response_to_that_check() {
+        let providers = MockProviders::default();
+        providers.start(provider(PROVIDER1), Duration::from_secs(15));
+        providers.start(provider(PROVIDER3), Duration::from_secs(1));
+
+        let (mut monitor, _, _) = test_monitor(
+            provider(PROVIDER1),
+            selection_of(&[provider(PROVIDER1)]),
+            providers,
+        );
+        let timeout = Duration::from_secs(10);
+
+        // too slow to respond
+        assert!(!monitor.check_health(provider(PROVIDER1), timeout).await);
+        // the late response of the first provider arrives while this one is being checked
+        assert!(!monitor.check_health(provider(PROVIDER2), timeout).await);
+        assert!(monitor.check_health(provider(PROVIDER3), timeout).await);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn unresponsive_initial_provider_is_replaced_before_being_used() {
+        let providers = MockProviders::default();
+        providers.start(provider(PROVIDER3), Duration::from_secs(1));
+
+        let (monitor, current, ready) = test_monitor(
+            provider(PROVIDER1),
+            selection_of(&[
+                provider(PROVIDER1),
+                provider(PROVIDER2),
+                provider(PROVIDER3),
+            ]),
+            providers,
+        );
+        tokio::spawn(monitor.run());
+
+        ready.await.unwrap();
+        assert_eq!(current.get(), provider(PROVIDER3));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn fails_over_once_provider_stops_responding() {
+        let providers = MockProviders::default();
+        providers.start(provider(PROVIDER1), Duration::from_secs(1));
+        providers.start(provider(PROVIDER2), Duration::from_secs(1));
+
+        let (monitor, current, ready) = test_monitor(
+            provider(PROVIDER1),
+            selection_of(&[provider(PROVIDER1), provider(PROVIDER2)]),
+            providers.clone(),
+        );
+        tokio::spawn(monitor.run());
+
+        ready.await.unwrap();
+        assert_eq!(current.get(), provider(PROVIDER1));
+
+        // a single missed health check is tolerated
+        providers.stop(provider(PROVIDER1));
+        tokio::time::sleep(Duration::from_secs(45)).await;
+        assert_eq!(current.get(), provider(PROVIDER1));
+
+        tokio::time::sleep(Duration::from_secs(45)).await;
+        assert_eq!(current.get(), provider(PROVIDER2));
+    }
+}
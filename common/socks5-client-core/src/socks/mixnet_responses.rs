@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::error::Socks5ClientCoreError;
+use crate::provider_selection::ControlResponseSender;
 use futures::channel::mpsc;
 use futures::StreamExt;
 use log::*;
@@ -19,6 +20,7 @@ pub(crate) struct MixnetResponseListener {
     buffer_requester: ReceivedBufferRequestSender,
     mix_response_receiver: ReconstructedMessagesReceiver,
     controller_sender: ControllerSender,
+    control_response_sender: Option<ControlResponseSender>,
     shutdown: TaskClient,
 }
 
@@ -41,6 +43,7 @@ impl MixnetResponseListener {
     pub(crate) fn new(
         buffer_requester: ReceivedBufferRequestSender,
         controller_sender: ControllerSender,
+        control_response_sender: Option<ControlResponseSender>,
         shutdown: TaskClient,
     ) -> Self {
         let (mix_response_sender, mix_response_receiver) = mpsc::unbounded();
@@ -52,6 +55,7 @@ impl MixnetResponseListener {
             buffer_requester,
             mix_response_receiver,
             controller_sender,
+            control_response_sender,
             shutdown,
         }
     }
@@ -60,11 +64,19 @@ impl MixnetResponseListener {
         &self,
         control_response: ControlResponse,
     ) -> Result<(), Socks5ClientCoreError> {
+        if let Some(control_response_sender) = &self.control_response_sender {
+            if control_response_sender
+                .unbounded_send(control_response)
+                .is_err()
+            {
+                debug!("the provider monitor is no longer running - dropping the control response");
+            }
+            return Ok(());
+        }
+
         error!("received a control response which we don't know how to handle yet!");
         error!("got: {:?}", control_response);
 
-        // I guess we'd need another channel here to forward those to where they need to go
-
         Ok(())
     }
 
@@ -1,9 +1,11 @@
 use crate::error::Socks5ClientCoreError;
+use crate::provider_selection::{ControlResponseSender, SharedProvider};
 
 use super::{
     authentication::Authenticator, client::SocksClient, mixnet_responses::MixnetResponseListener,
 };
 use crate::socks::client;
+use futures::channel::oneshot;
 use log::*;
 use nym_client_core::client::{
     inbound_messages::InputMessageSender, received_buffer::ReceivedBufferRequestSender,
@@ -21,12 +23,14 @@ use tokio::net::TcpListener;
 pub struct NymSocksServer {
     authenticator: Authenticator,
     listening_address: SocketAddr,
-    service_provider: Recipient,
+    service_provider: SharedProvider,
     self_address: Recipient,
     client_config: client::Config,
     lane_queue_lengths: LaneQueueLengths,
     shutdown: TaskClient,
     packet_type: PacketType,
+    control_response_sender: Option<ControlResponseSender>,
+    initial_provider_ready: Option<oneshot::Receiver<()>>,
 }
 
 impl NymSocksServer {
@@ -35,12 +39,14 @@ impl NymSocksServer {
     pub(crate) fn new(
         port: u16,
         authenticator: Authenticator,
-        service_provider: Recipient,
+        service_provider: SharedProvider,
         self_address: Recipient,
         lane_queue_lengths: LaneQueueLengths,
         client_config: client::Config,
         shutdown: TaskClient,
         packet_type: PacketType,
+        control_response_sender: Option<ControlResponseSender>,
+        initial_provider_ready: Option<oneshot::Receiver<()>>,
     ) -> Self {
         // hardcode ip as we (presumably) ONLY want to listen locally. If we change it, we can
         // just modify the config
@@ -55,6 +61,8 @@ impl NymSocksServer {
             lane_queue_lengths,
             shutdown,
             packet_type,
+            control_response_sender,
+            initial_provider_ready,
         }
     }
 
@@ -85,16 +93,27 @@ impl NymSocksServer {
         let mut mixnet_response_listener = MixnetResponseListener::new(
             buffer_requester,
             controller_sender.clone(),
+            self.control_response_sender.take(),
             self.shutdown.clone(),
         );
         tokio::spawn(async move {
             mixnet_response_listener.run().await;
         });
 
-        // TODO:, if required, there should be another task here responsible for control requests.
-        // it should get `input_sender` to send actual requests into the mixnet
-        // and some channel that connects it from `MixnetResponseListener` to receive
-        // any control responses
+        // note: any control requests (i.e. the provider health checks) are sent by the
+        // `ProviderMonitor`, which receives the responses from the `MixnetResponseListener`
+        if let Some(initial_provider_ready) = self.initial_provider_ready.take() {
+            info!("Waiting for the network requester to respond to the health check...");
+            tokio::select! {
+                // if the monitor is gone, we just use whatever provider it has left us with
+                _ = initial_provider_ready => {}
+                _ = self.shutdown.recv() => {
+                    log::trace!("NymSocksServer: Received shutdown");
+                    log::debug!("NymSocksServer: Exiting");
+                    return Ok(());
+                }
+            }
+        }
 
         loop {
             tokio::select! {
@@ -104,7 +123,7 @@ impl NymSocksServer {
                         stream,
                         self.authenticator.clone(),
                         input_sender.clone(),
-                        &self.service_provider,
+                        &self.service_provider.get(),
                         controller_sender.clone(),
                         &self.self_address,
                         self.lane_queue_lengths.clone(),
@@ -36,6 +36,7 @@ pub struct Node {
     // hostname every time we want to construct a path via this node
     pub mix_host: SocketAddr,
     pub clients_port: u16,
+    pub clients_wss_port: Option<u16>,
     pub identity_key: identity::PublicKey,
     pub sphinx_key: encryption::PublicKey, // TODO: or nymsphinx::PublicKey? both are x25519
     pub version: String,
@@ -69,6 +70,24 @@ impl Node {
     pub fn clients_address(&self) -> String {
         format!("ws://{}:{}", self.host, self.clients_port)
     }
+
+    /// Address of the secure (`wss://`) client listener, if the gateway has announced one.
+    pub fn clients_address_tls(&self) -> Option<String> {
+        self.clients_wss_port
+            .map(|port| format!("wss://{}:{}", self.host, port))
+    }
+
+    /// Address the clients should be connecting to. The secure listener is preferred,
+    /// but only if the gateway is reachable under a hostname, as otherwise its certificate
+    /// could not have been validated.
+    pub fn preferred_clients_address(&self) -> String {
+        match self.host {
+            NetworkAddress::Hostname(_) => self
+                .clients_address_tls()
+                .unwrap_or_else(|| self.clients_address()),
+            NetworkAddress::IpAddr(_) => self.clients_address(),
+        }
+    }
 }
 
 impl fmt::Display for Node {
@@ -112,6 +131,7 @@ impl<'a> TryFrom<&'a GatewayBond> for Node {
             host,
             mix_host,
             clients_port: bond.gateway.clients_port,
+            clients_wss_port: bond.gateway.clients_wss_port,
             identity_key: identity::PublicKey::from_base58_string(&bond.gateway.identity_key)?,
             sphinx_key: encryption::PublicKey::from_base58_string(&bond.gateway.sphinx_key)?,
             version: bond.gateway.version.clone(),
@@ -126,3 +146,50 @@ impl TryFrom<GatewayBond> for Node {
         Node::try_from(&bond)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node_fixture(host: &str, clients_wss_port: Option<u16>) -> Node {
+        Node {
+            owner: "foomp".to_string(),
+            host: host.parse().unwrap(),
+            mix_host: "1.2.3.4:1789".parse().unwrap(),
+            clients_port: 9000,
+            clients_wss_port,
+            identity_key: identity::PublicKey::from_base58_string(
+                "FioFa8nMmPpQnYi7JyojoTuwGLeyNS8BF4ChPr29zUML",
+            )
+            .unwrap(),
+            sphinx_key: encryption::PublicKey::from_base58_string(
+                "EB42xvMFMD5rUCstE2CDazgQQJ22zLv8SPm1Luxni44c",
+            )
+            .unwrap(),
+            version: "1.1.22".to_string(),
+        }
+    }
+
+    #[test]
+    fn preferred_clients_address() {
+        let node = node_fixture("gateway.nymtech.net", None);
+        assert_eq!(
+            node.preferred_clients_address(),
+            "ws://gateway.nymtech.net:9000"
+        );
+
+        let node = node_fixture("gateway.nymtech.net", Some(443));
+        assert_eq!(
+            node.preferred_clients_address(),
+            "wss://gateway.nymtech.net:443"
+        );
+
+        // there's no point in attempting to validate a certificate issued for an ip address
+        let node = node_fixture("1.2.3.4", Some(443));
+        assert_eq!(node.preferred_clients_address(), "ws://1.2.3.4:9000");
+        assert_eq!(
+            node.clients_address_tls(),
+            Some("wss://1.2.3.4:443".to_string())
+        );
+    }
+}
@@ -17,6 +17,7 @@ pub struct Gateway {
     pub host: String,
     pub mix_port: u16,
     pub clients_port: u16,
+    pub clients_wss_port: Option<u16>,
     pub location: String,
     pub sphinx_key: String,
     /// Base58 encoded ed25519 EdDSA public key of the gateway used to derive shared keys with clients
@@ -30,6 +31,7 @@ impl From<MixnetContractGateway> for Gateway {
             host,
             mix_port,
             clients_port,
+            clients_wss_port,
             location,
             sphinx_key,
             identity_key,
@@ -40,6 +42,7 @@ impl From<MixnetContractGateway> for Gateway {
             host,
             mix_port,
             clients_port,
+            clients_wss_port,
             location,
             sphinx_key,
             identity_key,
@@ -85,6 +88,7 @@ pub struct GatewayNodeDetailsResponse {
     pub version: String,
     pub mix_port: u16,
     pub clients_port: u16,
+    pub clients_wss_port: Option<u16>,
     pub data_store: String,
 }
 
@@ -98,6 +102,9 @@ impl fmt::Display for GatewayNodeDetailsResponse {
             "Mix Port: {}, Clients port: {}",
             self.mix_port, self.clients_port
         )?;
+        if let Some(clients_wss_port) = self.clients_wss_port {
+            writeln!(f, "Secure (wss) clients port: {clients_wss_port}")?;
+        }
 
         writeln!(f, "Data store is at: {}", self.data_store)
     }
@@ -9,7 +9,7 @@ use nym_mixnet_contract_common::{
     PendingEpochEvent as MixnetContractPendingEpochEvent,
     PendingEpochEventKind as MixnetContractPendingEpochEventKind,
     PendingIntervalEvent as MixnetContractPendingIntervalEvent,
-    PendingIntervalEventKind as MixnetContractPendingIntervalEventKind,
+    PendingIntervalEventKind as MixnetContractPendingIntervalEventKind, Percent,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -57,6 +57,13 @@ pub enum PendingEpochEventData {
         mix_id: MixId,
         proxy: Option<String>,
     },
+    Redelegate {
+        owner: String,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: DecCoin,
+        proxy: Option<String>,
+    },
     PledgeMore {
         mix_id: MixId,
         amount: DecCoin,
@@ -71,6 +78,11 @@ pub enum PendingEpochEventData {
     UpdateActiveSetSize {
         new_size: u32,
     },
+    SlashMixnode {
+        mix_id: MixId,
+        #[cfg_attr(feature = "generate-ts", ts(type = "string"))]
+        fraction: Percent,
+    },
 }
 
 impl PendingEpochEventData {
@@ -99,6 +111,19 @@ impl PendingEpochEventData {
                 mix_id,
                 proxy: proxy.map(|p| p.into_string()),
             }),
+            MixnetContractPendingEpochEventKind::Redelegate {
+                owner,
+                from_mix_id,
+                to_mix_id,
+                amount,
+                proxy,
+            } => Ok(PendingEpochEventData::Redelegate {
+                owner: owner.into_string(),
+                from_mix_id,
+                to_mix_id,
+                amount: reg.attempt_convert_to_display_dec_coin(amount.into())?,
+                proxy: proxy.map(|p| p.into_string()),
+            }),
             MixnetContractPendingEpochEventKind::PledgeMore { mix_id, amount } => {
                 Ok(PendingEpochEventData::PledgeMore {
                     mix_id,
@@ -118,6 +143,9 @@ impl PendingEpochEventData {
             MixnetContractPendingEpochEventKind::UpdateActiveSetSize { new_size } => {
                 Ok(PendingEpochEventData::UpdateActiveSetSize { new_size })
             }
+            MixnetContractPendingEpochEventKind::SlashMixnode { mix_id, fraction } => {
+                Ok(PendingEpochEventData::SlashMixnode { mix_id, fraction })
+            }
         }
     }
 }
@@ -48,6 +48,7 @@ fn spend_credential_creates_proposal() {
         max_voting_period: Duration::Height(1000),
         coconut_bandwidth_contract_address: TEST_COCONUT_BANDWIDTH_CONTRACT_ADDRESS.to_string(),
         coconut_dkg_contract_address: TEST_COCONUT_DKG_CONTRACT_ADDRESS.to_string(),
+        member_proposals: false,
     };
     let multisig_contract_addr = app
         .instantiate_contract(
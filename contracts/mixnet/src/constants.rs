@@ -38,6 +38,12 @@ pub const REWARDED_SET_MAX_RETRIEVAL_LIMIT: u32 = 1000;
 pub const FAMILIES_DEFAULT_RETRIEVAL_LIMIT: u32 = 10;
 pub const FAMILIES_MAX_RETRIEVAL_LIMIT: u32 = 20;
 
+pub const JAILED_MIXNODES_DEFAULT_RETRIEVAL_LIMIT: u32 = 100;
+pub const JAILED_MIXNODES_MAX_RETRIEVAL_LIMIT: u32 = 150;
+
+pub const MISBEHAVIOUR_REPORTS_DEFAULT_RETRIEVAL_LIMIT: u32 = 50;
+pub const MISBEHAVIOUR_REPORTS_MAX_RETRIEVAL_LIMIT: u32 = 100;
+
 // storage keys
 pub const DELEGATION_PK_NAMESPACE: &str = "dl";
 pub const DELEGATION_OWNER_IDX_NAMESPACE: &str = "dlo";
@@ -45,6 +51,7 @@ pub const DELEGATION_MIXNODE_IDX_NAMESPACE: &str = "dlm";
 
 pub const GATEWAYS_PK_NAMESPACE: &str = "gt";
 pub const GATEWAYS_OWNER_IDX_NAMESPACE: &str = "gto";
+pub const PENDING_GATEWAY_OWNERSHIP_TRANSFERS_NAMESPACE: &str = "pgt";
 
 pub const REWARDED_SET_KEY: &str = "rs";
 pub const CURRENT_EPOCH_STATUS_KEY: &str = "ces";
@@ -62,6 +69,7 @@ pub const CONTRACT_STATE_KEY: &str = "state";
 pub const LAYER_DISTRIBUTION_KEY: &str = "layers";
 pub const NODE_ID_COUNTER_KEY: &str = "nic";
 pub const PENDING_MIXNODE_CHANGES_NAMESPACE: &str = "pmc";
+pub const PENDING_MIXNODE_OWNERSHIP_TRANSFERS_NAMESPACE: &str = "pmt";
 pub const MIXNODES_PK_NAMESPACE: &str = "mnn";
 pub const MIXNODES_OWNER_IDX_NAMESPACE: &str = "mno";
 pub const MIXNODES_IDENTITY_IDX_NAMESPACE: &str = "mni";
@@ -74,9 +82,17 @@ pub const UNBONDED_MIXNODES_IDENTITY_IDX_NAMESPACE: &str = "umi";
 pub const REWARDING_PARAMS_KEY: &str = "rparams";
 pub const PENDING_REWARD_POOL_KEY: &str = "prp";
 pub const MIXNODES_REWARDING_PK_NAMESPACE: &str = "mnr";
+pub const COMPOUNDING_OPERATORS_NAMESPACE: &str = "cop";
+pub const COMPOUNDING_DELEGATIONS_NAMESPACE: &str = "cdl";
+pub const COMPOUNDING_PROGRESS_KEY: &str = "cpr";
+pub const GATEWAYS_REWARDING_PK_NAMESPACE: &str = "gtr";
+pub const EPOCH_DISTRIBUTED_REWARDS_KEY: &str = "edr";
 
 pub const FAMILIES_INDEX_NAMESPACE: &str = "faml2";
 pub const FAMILIES_MAP_NAMESPACE: &str = "fam2";
 pub const MEMBERS_MAP_NAMESPACE: &str = "memb2";
 
 pub const SIGNING_NONCES_NAMESPACE: &str = "sn";
+
+pub const JAILED_MIXNODES_NAMESPACE: &str = "jmn";
+pub const MISBEHAVIOUR_REPORTS_NAMESPACE: &str = "mbr";
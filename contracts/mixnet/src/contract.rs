@@ -217,6 +217,20 @@ pub fn execute(
         ExecuteMsg::ReconcileEpochEvents { limit } => {
             crate::interval::transactions::try_reconcile_epoch_events(deps, env, info, limit)
         }
+        ExecuteMsg::JailMixnode {
+            mix_id,
+            jail_epochs,
+            slash_fraction,
+            evidence,
+        } => crate::jailing::transactions::try_jail_mixnode(
+            deps,
+            env,
+            info,
+            mix_id,
+            jail_epochs,
+            slash_fraction,
+            evidence,
+        ),
 
         // mixnode-related:
         ExecuteMsg::BondMixnode {
@@ -287,6 +301,17 @@ pub fn execute(
                 deps, info, new_config, owner,
             )
         }
+        ExecuteMsg::TransferMixnodeOwnership { to_address } => {
+            crate::mixnodes::transactions::try_propose_mixnode_ownership_transfer(
+                deps, info, to_address,
+            )
+        }
+        ExecuteMsg::AcceptMixnodeOwnership { mix_id } => {
+            crate::mixnodes::transactions::try_accept_mixnode_ownership(deps, info, mix_id)
+        }
+        ExecuteMsg::CancelMixnodeOwnershipTransfer {} => {
+            crate::mixnodes::transactions::try_cancel_mixnode_ownership_transfer(deps, info)
+        }
 
         // gateway-related:
         ExecuteMsg::BondGateway {
@@ -325,6 +350,17 @@ pub fn execute(
                 deps, info, new_config, owner,
             )
         }
+        ExecuteMsg::TransferGatewayOwnership { to_address } => {
+            crate::gateways::transactions::try_propose_gateway_ownership_transfer(
+                deps, info, to_address,
+            )
+        }
+        ExecuteMsg::AcceptGatewayOwnership { identity } => {
+            crate::gateways::transactions::try_accept_gateway_ownership(deps, info, identity)
+        }
+        ExecuteMsg::CancelGatewayOwnershipTransfer {} => {
+            crate::gateways::transactions::try_cancel_gateway_ownership_transfer(deps, info)
+        }
 
         // delegation-related:
         ExecuteMsg::DelegateToMixnode { mix_id } => {
@@ -345,6 +381,32 @@ pub fn execute(
                 deps, env, info, mix_id, delegate,
             )
         }
+        ExecuteMsg::RedelegateToMixnode {
+            from_mix_id,
+            to_mix_id,
+            amount,
+        } => crate::delegations::transactions::try_redelegate_to_mixnode(
+            deps,
+            env,
+            info,
+            from_mix_id,
+            to_mix_id,
+            amount,
+        ),
+        ExecuteMsg::RedelegateToMixnodeOnBehalf {
+            from_mix_id,
+            to_mix_id,
+            amount,
+            delegate,
+        } => crate::delegations::transactions::try_redelegate_to_mixnode_on_behalf(
+            deps,
+            env,
+            info,
+            from_mix_id,
+            to_mix_id,
+            amount,
+            delegate,
+        ),
 
         // reward-related
         ExecuteMsg::RewardMixnode {
@@ -366,6 +428,40 @@ pub fn execute(
                 deps, info, mix_id, owner,
             )
         }
+        ExecuteMsg::RewardGateway {
+            identity,
+            performance,
+        } => {
+            crate::rewards::transactions::try_reward_gateway(deps, env, info, identity, performance)
+        }
+        ExecuteMsg::WithdrawGatewayOperatorReward {} => {
+            crate::rewards::transactions::try_withdraw_gateway_operator_reward(deps, info)
+        }
+        ExecuteMsg::WithdrawGatewayOperatorRewardOnBehalf { owner } => {
+            crate::rewards::transactions::try_withdraw_gateway_operator_reward_on_behalf(
+                deps, info, owner,
+            )
+        }
+        ExecuteMsg::SetOperatorRewardCompounding { enabled } => {
+            crate::rewards::transactions::try_set_operator_reward_compounding(deps, info, enabled)
+        }
+        ExecuteMsg::SetOperatorRewardCompoundingOnBehalf { enabled, owner } => {
+            crate::rewards::transactions::try_set_operator_reward_compounding_on_behalf(
+                deps, info, enabled, owner,
+            )
+        }
+        ExecuteMsg::SetDelegatorRewardCompounding { mix_id, enabled } => {
+            crate::rewards::transactions::try_set_delegator_reward_compounding(
+                deps, info, mix_id, enabled,
+            )
+        }
+        ExecuteMsg::SetDelegatorRewardCompoundingOnBehalf {
+            mix_id,
+            enabled,
+            owner,
+        } => crate::rewards::transactions::try_set_delegator_reward_compounding_on_behalf(
+            deps, info, mix_id, enabled, owner,
+        ),
 
         // testing-only
         #[cfg(feature = "contract-testing")]
@@ -481,6 +577,22 @@ pub fn query(
         QueryMsg::GetLayerDistribution {} => {
             to_binary(&crate::mixnodes::queries::query_layer_distribution(deps)?)
         }
+        QueryMsg::GetMixnodeJailStatus { mix_id } => to_binary(
+            &crate::jailing::queries::query_mixnode_jail_status(deps, mix_id)?,
+        ),
+        QueryMsg::GetJailedMixnodes { limit, start_after } => to_binary(
+            &crate::jailing::queries::query_jailed_mixnodes_paged(deps, start_after, limit)?,
+        ),
+        QueryMsg::GetMisbehaviourReports {
+            mix_id,
+            limit,
+            start_after,
+        } => to_binary(&crate::jailing::queries::query_misbehaviour_reports_paged(
+            deps,
+            mix_id,
+            start_after,
+            limit,
+        )?),
 
         // gateway-related:
         QueryMsg::GetGateways { limit, start_after } => to_binary(
@@ -543,6 +655,24 @@ pub fn query(
         } => to_binary(&crate::rewards::queries::query_pending_delegator_reward(
             deps, address, mix_id, proxy,
         )?),
+        QueryMsg::GetPendingGatewayOperatorReward { address } => to_binary(
+            &crate::rewards::queries::query_pending_gateway_operator_reward(deps, address)?,
+        ),
+        QueryMsg::GetPendingGatewayReward { identity } => to_binary(
+            &crate::rewards::queries::query_pending_gateway_reward(deps, identity)?,
+        ),
+        QueryMsg::GetOperatorRewardCompounding { mix_id } => to_binary(
+            &crate::rewards::queries::query_operator_reward_compounding(deps, mix_id)?,
+        ),
+        QueryMsg::GetDelegatorRewardCompounding {
+            address,
+            mix_id,
+            proxy,
+        } => to_binary(
+            &crate::rewards::queries::query_delegator_reward_compounding(
+                deps, address, mix_id, proxy,
+            )?,
+        ),
         QueryMsg::GetEstimatedCurrentEpochOperatorReward {
             mix_id,
             estimated_performance,
@@ -717,6 +847,7 @@ mod tests {
             },
             rewarded_set_size: 543,
             active_set_size: 123,
+            gateway: Default::default(),
         };
 
         let state = mixnet_params_storage::CONTRACT_STATE
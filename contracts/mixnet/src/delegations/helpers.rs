@@ -3,10 +3,70 @@
 
 use crate::delegations::storage;
 use crate::rewards::storage as rewards_storage;
-use cosmwasm_std::{Coin, Storage};
+use cosmwasm_std::{Addr, Coin, Storage};
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::mixnode::MixNodeRewarding;
-use mixnet_contract_common::Delegation;
+use mixnet_contract_common::{BlockHeight, Delegation, MixId};
+
+/// Adds the provided tokens to the delegation of the owner towards the specified mixnode.
+/// If a delegation already exists, it is withdrawn alongside all of its earned rewards
+/// and a fresh one, containing the sum of both, is created in its place.
+///
+/// Returns the updated rewarding details of the mixnode.
+pub(crate) fn add_delegation(
+    store: &mut dyn Storage,
+    mut mix_rewarding: MixNodeRewarding,
+    owner: Addr,
+    mix_id: MixId,
+    amount: Coin,
+    proxy: Option<Addr>,
+    height: BlockHeight,
+) -> Result<MixNodeRewarding, MixnetContractError> {
+    // the delegation_amount might get increased if there's already a pre-existing delegation on this mixnode
+    // (in that case we just create a fresh delegation with the sum of both)
+    let mut stored_delegation_amount = amount;
+
+    // if there's an existing delegation, then withdraw the full reward and create a new delegation
+    // with the sum of both
+    let storage_key = Delegation::generate_storage_key(mix_id, &owner, proxy.as_ref());
+    let old_delegation = if let Some(existing_delegation) =
+        storage::delegations().may_load(store, storage_key.clone())?
+    {
+        // completely remove the delegation from the node
+        let og_with_reward = mix_rewarding.undelegate(&existing_delegation)?;
+
+        // and adjust the new value by the amount removed (which contains the original delegation
+        // alongside any earned rewards)
+        stored_delegation_amount.amount += og_with_reward.amount;
+
+        Some(existing_delegation)
+    } else {
+        None
+    };
+
+    // add the amount we're intending to delegate (whether it's fresh or we're adding to the existing one)
+    mix_rewarding.add_base_delegation(stored_delegation_amount.amount)?;
+
+    let delegation = Delegation::new(
+        owner,
+        mix_id,
+        mix_rewarding.total_unit_reward,
+        stored_delegation_amount,
+        height,
+        proxy,
+    );
+
+    // save on reading since `.save()` would have attempted to read old data that we already have on hand
+    storage::delegations().replace(
+        store,
+        storage_key,
+        Some(&delegation),
+        old_delegation.as_ref(),
+    )?;
+    rewards_storage::MIXNODE_REWARDING.save(store, mix_id, &mix_rewarding)?;
+
+    Ok(mix_rewarding)
+}
 
 pub(crate) fn undelegate(
     store: &mut dyn Storage,
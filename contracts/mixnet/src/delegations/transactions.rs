@@ -11,7 +11,7 @@ use crate::support::helpers::{
 use cosmwasm_std::{Addr, Coin, DepsMut, Env, MessageInfo, Response};
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
-    new_pending_delegation_event, new_pending_undelegation_event,
+    new_pending_delegation_event, new_pending_redelegation_event, new_pending_undelegation_event,
 };
 use mixnet_contract_common::pending_events::PendingEpochEventKind;
 use mixnet_contract_common::{Delegation, MixId};
@@ -139,6 +139,122 @@ pub(crate) fn _try_remove_delegation_from_mixnode(
     Ok(Response::new().add_event(cosmos_event))
 }
 
+pub(crate) fn try_redelegate_to_mixnode(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    amount: Coin,
+) -> Result<Response, MixnetContractError> {
+    _try_redelegate_to_mixnode(deps, env, from_mix_id, to_mix_id, amount, info.sender, None)
+}
+
+pub(crate) fn try_redelegate_to_mixnode_on_behalf(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    amount: Coin,
+    delegate: String,
+) -> Result<Response, MixnetContractError> {
+    ensure_sent_by_vesting_contract(&info, deps.storage)?;
+
+    let delegate = deps.api.addr_validate(&delegate)?;
+    _try_redelegate_to_mixnode(
+        deps,
+        env,
+        from_mix_id,
+        to_mix_id,
+        amount,
+        delegate,
+        Some(info.sender),
+    )
+}
+
+pub(crate) fn _try_redelegate_to_mixnode(
+    deps: DepsMut<'_>,
+    env: Env,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    amount: Coin,
+    delegate: Addr,
+    proxy: Option<Addr>,
+) -> Result<Response, MixnetContractError> {
+    // redelegation is only allowed if the epoch is currently not in the process of being advanced
+    ensure_epoch_in_progress_state(deps.storage)?;
+
+    if from_mix_id == to_mix_id {
+        return Err(MixnetContractError::RedelegationToSameMixnode {
+            mix_id: from_mix_id,
+        });
+    }
+
+    // the moved amount is subject to the same constraints as a fresh delegation
+    let contract_state = mixnet_params_storage::CONTRACT_STATE.load(deps.storage)?;
+    let amount = validate_delegation_stake(
+        vec![amount],
+        contract_state.params.minimum_mixnode_delegation,
+        contract_state.rewarding_denom,
+    )?;
+
+    // see if the delegation even exists and whether it's big enough
+    let storage_key = Delegation::generate_storage_key(from_mix_id, &delegate, proxy.as_ref());
+    let delegation = match storage::delegations().may_load(deps.storage, storage_key)? {
+        Some(delegation) => delegation,
+        None => {
+            return Err(MixnetContractError::NoMixnodeDelegationFound {
+                mix_id: from_mix_id,
+                address: delegate.into_string(),
+                proxy: proxy.map(Addr::into_string),
+            })
+        }
+    };
+    if delegation.amount.amount < amount.amount {
+        return Err(MixnetContractError::RedelegationExceedsDelegation {
+            requested: amount,
+            available: delegation.amount,
+        });
+    }
+
+    // and make sure whatever stays behind could have been delegated on its own
+    let remaining = delegation.amount.amount - amount.amount;
+    if let Some(minimum) = contract_state.params.minimum_mixnode_delegation {
+        if !remaining.is_zero() && remaining < minimum.amount {
+            return Err(MixnetContractError::RedelegationRemainderBelowMinimum {
+                remaining: Coin::new(remaining.u128(), &amount.denom),
+                requested: amount,
+                minimum,
+            });
+        }
+    }
+
+    // check if the target node actually exists and is still bonded
+    match mixnodes_storage::mixnode_bonds().may_load(deps.storage, to_mix_id)? {
+        None => return Err(MixnetContractError::MixNodeBondNotFound { mix_id: to_mix_id }),
+        Some(bond) if bond.is_unbonding => {
+            return Err(MixnetContractError::MixnodeIsUnbonding { mix_id: to_mix_id })
+        }
+        _ => (),
+    }
+
+    // push the event onto the queue and wait for it to be picked up at the end of the epoch
+    let cosmos_event =
+        new_pending_redelegation_event(&delegate, &proxy, from_mix_id, to_mix_id, &amount);
+
+    let epoch_event = PendingEpochEventKind::Redelegate {
+        owner: delegate,
+        from_mix_id,
+        to_mix_id,
+        amount,
+        proxy,
+    };
+    interval_storage::push_new_epoch_event(deps.storage, &env, epoch_event)?;
+
+    Ok(Response::new().add_event(cosmos_event))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -258,6 +374,65 @@ mod tests {
             assert!(res.is_ok())
         }
 
+        #[test]
+        fn cant_leave_remainder_below_minimum_delegation() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+
+            let owner = "delegator";
+            let from = test.add_dummy_mixnode("mix-owner1", None);
+            let to = test.add_dummy_mixnode("mix-owner2", None);
+            test.add_immediate_delegation(owner, 200_000_000u32, from);
+
+            let min_delegation = coin(50_000_000, TEST_COIN_DENOM);
+            let mut contract_state = mixnet_params_storage::CONTRACT_STATE
+                .load(test.deps().storage)
+                .unwrap();
+            contract_state.params.minimum_mixnode_delegation = Some(min_delegation.clone());
+            mixnet_params_storage::CONTRACT_STATE
+                .save(test.deps_mut().storage, &contract_state)
+                .unwrap();
+
+            let res = try_redelegate_to_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                from,
+                to,
+                coin(160_000_000, TEST_COIN_DENOM),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::RedelegationRemainderBelowMinimum {
+                    requested: coin(160_000_000, TEST_COIN_DENOM),
+                    remaining: coin(40_000_000, TEST_COIN_DENOM),
+                    minimum: min_delegation,
+                })
+            );
+
+            // leaving exactly the minimum is fine
+            let res = try_redelegate_to_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                from,
+                to,
+                coin(150_000_000, TEST_COIN_DENOM),
+            );
+            assert!(res.is_ok());
+
+            // and so is moving everything
+            let res = try_redelegate_to_mixnode(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                from,
+                to,
+                coin(200_000_000, TEST_COIN_DENOM),
+            );
+            assert!(res.is_ok());
+        }
+
         #[test]
         fn can_only_be_done_towards_fully_bonded_mixnode() {
             let mut test = TestSetup::new();
@@ -609,4 +784,188 @@ mod tests {
             )
         }
     }
+
+    #[cfg(test)]
+    mod redelegating_to_mixnode {
+        use super::*;
+        use crate::mixnodes::transactions::try_remove_mixnode;
+        use crate::support::tests::fixtures::TEST_COIN_DENOM;
+        use crate::support::tests::test_helpers::TestSetup;
+        use cosmwasm_std::coin;
+        use cosmwasm_std::testing::mock_info;
+
+        #[test]
+        fn cant_be_done_towards_the_same_mixnode() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+
+            let owner = "delegator";
+            let mix_id = test.add_dummy_mixnode("mix-owner", None);
+            test.add_immediate_delegation(owner, 100_000_000u32, mix_id);
+
+            let res = try_redelegate_to_mixnode(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                mix_id,
+                mix_id,
+                coin(100_000_000, TEST_COIN_DENOM),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::RedelegationToSameMixnode { mix_id })
+            )
+        }
+
+        #[test]
+        fn requires_existing_delegation() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+
+            let owner = "delegator";
+            let from = test.add_dummy_mixnode("mix-owner1", None);
+            let to = test.add_dummy_mixnode("mix-owner2", None);
+
+            let res = try_redelegate_to_mixnode(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                from,
+                to,
+                coin(100_000_000, TEST_COIN_DENOM),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::NoMixnodeDelegationFound {
+                    mix_id: from,
+                    address: owner.to_string(),
+                    proxy: None,
+                })
+            )
+        }
+
+        #[test]
+        fn cant_move_more_than_is_delegated() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+
+            let owner = "delegator";
+            let from = test.add_dummy_mixnode("mix-owner1", None);
+            let to = test.add_dummy_mixnode("mix-owner2", None);
+            test.add_immediate_delegation(owner, 100_000_000u32, from);
+
+            let res = try_redelegate_to_mixnode(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                from,
+                to,
+                coin(100_000_001, TEST_COIN_DENOM),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::RedelegationExceedsDelegation {
+                    requested: coin(100_000_001, TEST_COIN_DENOM),
+                    available: coin(100_000_000, TEST_COIN_DENOM),
+                })
+            )
+        }
+
+        #[test]
+        fn can_only_be_done_towards_fully_bonded_mixnode() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+
+            let owner = "delegator";
+            let from = test.add_dummy_mixnode("mix-owner1", None);
+            let to = test.add_dummy_mixnode("mix-owner2", None);
+            test.add_immediate_delegation(owner, 100_000_000u32, from);
+
+            let res = try_redelegate_to_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                from,
+                42,
+                coin(100_000_000, TEST_COIN_DENOM),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::MixNodeBondNotFound { mix_id: 42 })
+            );
+
+            try_remove_mixnode(test.deps_mut(), env.clone(), mock_info("mix-owner2", &[])).unwrap();
+            let res = try_redelegate_to_mixnode(
+                test.deps_mut(),
+                env,
+                mock_info(owner, &[]),
+                from,
+                to,
+                coin(100_000_000, TEST_COIN_DENOM),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::MixnodeIsUnbonding { mix_id: to })
+            );
+        }
+
+        #[test]
+        fn correctly_pushes_appropriate_epoch_event() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+
+            let owner = "delegator";
+            let from = test.add_dummy_mixnode("mix-owner1", None);
+            let to = test.add_dummy_mixnode("mix-owner2", None);
+            test.add_immediate_delegation(owner, 100_000_000u32, from);
+            test.add_immediate_delegation_with_legal_proxy(owner, 100_000_000u32, from);
+
+            let amount1 = coin(60_000_000, TEST_COIN_DENOM);
+            let amount2 = coin(100_000_000, TEST_COIN_DENOM);
+
+            try_redelegate_to_mixnode(
+                test.deps_mut(),
+                env.clone(),
+                mock_info(owner, &[]),
+                from,
+                to,
+                amount1.clone(),
+            )
+            .unwrap();
+            try_redelegate_to_mixnode_on_behalf(
+                test.deps_mut(),
+                env,
+                mock_info(test.vesting_contract().as_str(), &[]),
+                from,
+                to,
+                amount2.clone(),
+                owner.into(),
+            )
+            .unwrap();
+
+            let events = test.pending_epoch_events();
+
+            assert_eq!(
+                events[0].kind,
+                PendingEpochEventKind::Redelegate {
+                    owner: Addr::unchecked(owner),
+                    from_mix_id: from,
+                    to_mix_id: to,
+                    amount: amount1,
+                    proxy: None
+                }
+            );
+
+            assert_eq!(
+                events[1].kind,
+                PendingEpochEventKind::Redelegate {
+                    owner: Addr::unchecked(owner),
+                    from_mix_id: from,
+                    to_mix_id: to,
+                    amount: amount2,
+                    proxy: Some(test.vesting_contract())
+                }
+            );
+        }
+    }
 }
@@ -1,11 +1,18 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::constants::{GATEWAYS_OWNER_IDX_NAMESPACE, GATEWAYS_PK_NAMESPACE};
+use crate::constants::{
+    GATEWAYS_OWNER_IDX_NAMESPACE, GATEWAYS_PK_NAMESPACE,
+    PENDING_GATEWAY_OWNERSHIP_TRANSFERS_NAMESPACE,
+};
 use cosmwasm_std::Addr;
-use cw_storage_plus::{Index, IndexList, IndexedMap, UniqueIndex};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Map, UniqueIndex};
 use mixnet_contract_common::{GatewayBond, IdentityKeyRef};
 
+// keeps track of `gateway identity -> proposed new owner` of gateways whose ownership is being transferred
+pub(crate) const PENDING_OWNERSHIP_TRANSFERS: Map<IdentityKeyRef, Addr> =
+    Map::new(PENDING_GATEWAY_OWNERSHIP_TRANSFERS_NAMESPACE);
+
 pub(crate) struct GatewayBondIndex<'a> {
     pub(crate) owner: UniqueIndex<'a, Addr, GatewayBond>,
 }
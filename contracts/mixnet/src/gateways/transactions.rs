@@ -5,6 +5,7 @@ use super::helpers::must_get_gateway_bond_by_owner;
 use super::storage;
 use crate::gateways::signature_helpers::verify_gateway_bonding_signature;
 use crate::mixnet_contract_settings::storage as mixnet_params_storage;
+use crate::rewards::storage as rewards_storage;
 use crate::signing::storage as signing_storage;
 use crate::support::helpers::{
     ensure_no_existing_bond, ensure_proxy_match, ensure_sent_by_vesting_contract, validate_pledge,
@@ -12,10 +13,12 @@ use crate::support::helpers::{
 use cosmwasm_std::{wasm_execute, Addr, BankMsg, Coin, DepsMut, Env, MessageInfo, Response};
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
-    new_gateway_bonding_event, new_gateway_config_update_event, new_gateway_unbonding_event,
+    new_gateway_bonding_event, new_gateway_config_update_event,
+    new_gateway_ownership_transfer_cancellation_event, new_gateway_ownership_transfer_event,
+    new_gateway_ownership_transfer_proposal_event, new_gateway_unbonding_event,
 };
 use mixnet_contract_common::gateway::GatewayConfigUpdate;
-use mixnet_contract_common::{Gateway, GatewayBond};
+use mixnet_contract_common::{Gateway, GatewayBond, IdentityKey};
 use nym_contracts_common::signing::MessageSignature;
 use vesting_contract_common::messages::ExecuteMsg as VestingContractExecuteMsg;
 
@@ -164,21 +167,39 @@ pub(crate) fn _try_remove_gateway(
         });
     }
 
+    // alongside the pledge, return all the rewards the gateway has earned that haven't been withdrawn yet
+    let mut tokens = gateway_bond.pledge_amount();
+    if let Some(mut gateway_rewarding) =
+        rewards_storage::GATEWAY_REWARDING.may_load(deps.storage, gateway_bond.identity())?
+    {
+        tokens.amount += gateway_rewarding.withdraw_reward(&tokens.denom)?.amount;
+
+        // the fractional leftover can no longer be claimed by anyone, so return it to the pool
+        if !gateway_rewarding.unclaimed_reward.is_zero() {
+            rewards_storage::unclaimable_reward_accounting(
+                deps.storage,
+                gateway_rewarding.unclaimed_reward,
+            )?;
+        }
+    }
+
     // send bonded funds back to the bond owner
     let return_tokens = BankMsg::Send {
         to_address: proxy.as_ref().unwrap_or(&owner).to_string(),
-        amount: vec![gateway_bond.pledge_amount()],
+        amount: vec![tokens.clone()],
     };
 
     // remove the bond
     storage::gateways().remove(deps.storage, gateway_bond.identity())?;
+    storage::PENDING_OWNERSHIP_TRANSFERS.remove(deps.storage, gateway_bond.identity());
+    rewards_storage::GATEWAY_REWARDING.remove(deps.storage, gateway_bond.identity());
 
     let mut response = Response::new().add_message(return_tokens);
 
     if let Some(proxy) = &proxy {
         let msg = VestingContractExecuteMsg::TrackUnbondGateway {
             owner: owner.as_str().to_string(),
-            amount: gateway_bond.pledge_amount(),
+            amount: tokens,
         };
 
         let track_unbond_message = wasm_execute(proxy, &msg, vec![])?;
@@ -232,6 +253,7 @@ pub(crate) fn _try_update_gateway_config(
     updated_bond.gateway.host = new_config.host;
     updated_bond.gateway.mix_port = new_config.mix_port;
     updated_bond.gateway.clients_port = new_config.clients_port;
+    updated_bond.gateway.clients_wss_port = new_config.clients_wss_port;
     updated_bond.gateway.location = new_config.location;
     updated_bond.gateway.version = new_config.version;
 
@@ -245,14 +267,111 @@ pub(crate) fn _try_update_gateway_config(
     Ok(Response::new().add_event(cfg_update_event))
 }
 
+/// Proposes transferring the ownership of the sender's gateway to `to_address`.
+/// The transfer only takes place once the new owner accepts it. Proposing another transfer
+/// replaces the previous proposal.
+pub(crate) fn try_propose_gateway_ownership_transfer(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    to_address: String,
+) -> Result<Response, MixnetContractError> {
+    let owner = info.sender;
+    let new_owner = deps.api.addr_validate(&to_address)?;
+    let existing_bond = must_get_gateway_bond_by_owner(deps.storage, &owner)?;
+
+    // tokens pledged from a vesting account have to remain with that account
+    if existing_bond.proxy.is_some() {
+        return Err(MixnetContractError::VestingBondOwnershipTransfer);
+    }
+    ensure_no_existing_bond(&new_owner, deps.storage)?;
+
+    storage::PENDING_OWNERSHIP_TRANSFERS.save(
+        deps.storage,
+        existing_bond.identity(),
+        &new_owner,
+    )?;
+
+    Ok(
+        Response::new().add_event(new_gateway_ownership_transfer_proposal_event(
+            existing_bond.identity(),
+            &owner,
+            &new_owner,
+        )),
+    )
+}
+
+pub(crate) fn try_cancel_gateway_ownership_transfer(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+) -> Result<Response, MixnetContractError> {
+    let owner = info.sender;
+    let existing_bond = must_get_gateway_bond_by_owner(deps.storage, &owner)?;
+
+    let new_owner = storage::PENDING_OWNERSHIP_TRANSFERS
+        .may_load(deps.storage, existing_bond.identity())?
+        .ok_or(MixnetContractError::NoPendingOwnershipTransfer)?;
+    storage::PENDING_OWNERSHIP_TRANSFERS.remove(deps.storage, existing_bond.identity());
+
+    Ok(
+        Response::new().add_event(new_gateway_ownership_transfer_cancellation_event(
+            existing_bond.identity(),
+            &owner,
+            &new_owner,
+        )),
+    )
+}
+
+/// Finalises the ownership transfer of the specified gateway previously proposed to the sender.
+pub(crate) fn try_accept_gateway_ownership(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    identity: IdentityKey,
+) -> Result<Response, MixnetContractError> {
+    let new_owner = info.sender;
+    let proposed_owner = storage::PENDING_OWNERSHIP_TRANSFERS.may_load(deps.storage, &identity)?;
+    if proposed_owner.as_ref() != Some(&new_owner) {
+        return Err(MixnetContractError::NoPendingOwnershipTransfer);
+    }
+
+    // if the proposal still exists, so must the gateway
+    let existing_bond = storage::gateways().load(deps.storage, &identity)?;
+
+    // the new owner might have bonded something in the meantime
+    ensure_no_existing_bond(&new_owner, deps.storage)?;
+
+    let previous_owner = existing_bond.owner.clone();
+
+    // clippy beta 1.70.0-beta.1 false positive
+    #[allow(clippy::redundant_clone)]
+    let mut updated_bond = existing_bond.clone();
+    updated_bond.owner = new_owner.clone();
+    storage::gateways().replace(
+        deps.storage,
+        &identity,
+        Some(&updated_bond),
+        Some(&existing_bond),
+    )?;
+    storage::PENDING_OWNERSHIP_TRANSFERS.remove(deps.storage, &identity);
+
+    Ok(
+        Response::new().add_event(new_gateway_ownership_transfer_event(
+            &identity,
+            &previous_owner,
+            &new_owner,
+        )),
+    )
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
     use crate::contract::execute;
     use crate::gateways::queries;
     use crate::gateways::transactions::{
-        try_add_gateway, try_add_gateway_on_behalf, try_remove_gateway_on_behalf,
-        try_update_gateway_config, try_update_gateway_config_on_behalf,
+        try_accept_gateway_ownership, try_add_gateway, try_add_gateway_on_behalf,
+        try_cancel_gateway_ownership_transfer, try_propose_gateway_ownership_transfer,
+        try_remove_gateway, try_remove_gateway_on_behalf, try_update_gateway_config,
+        try_update_gateway_config_on_behalf,
     };
     use crate::interval::pending_events;
     use crate::mixnet_contract_settings::storage::minimum_gateway_pledge;
@@ -554,6 +673,7 @@ pub mod tests {
             host: "1.1.1.1:1234".to_string(),
             mix_port: 1234,
             clients_port: 1235,
+            clients_wss_port: Some(443),
             location: "home".to_string(),
             version: "v1.2.3".to_string(),
         };
@@ -595,6 +715,7 @@ pub mod tests {
         assert_eq!(bond.gateway.host, update.host);
         assert_eq!(bond.gateway.mix_port, update.mix_port);
         assert_eq!(bond.gateway.clients_port, update.clients_port);
+        assert_eq!(bond.gateway.clients_wss_port, update.clients_wss_port);
         assert_eq!(bond.gateway.location, update.location);
         assert_eq!(bond.gateway.version, update.version);
     }
@@ -613,6 +734,7 @@ pub mod tests {
             host: "1.1.1.1:1234".to_string(),
             mix_port: 1234,
             clients_port: 1235,
+            clients_wss_port: None,
             location: "at home".to_string(),
             version: "v1.2.3".to_string(),
         };
@@ -633,4 +755,95 @@ pub mod tests {
             }
         )
     }
+
+    #[test]
+    fn transferring_gateway_ownership() {
+        let mut test = TestSetup::new();
+
+        let owner = "alice";
+        let new_owner = "bob";
+        let identity = test.add_dummy_gateway(owner, None);
+
+        // nothing to accept or cancel yet
+        let res = try_accept_gateway_ownership(
+            test.deps_mut(),
+            mock_info(new_owner, &[]),
+            identity.clone(),
+        );
+        assert_eq!(res, Err(MixnetContractError::NoPendingOwnershipTransfer));
+        let res = try_cancel_gateway_ownership_transfer(test.deps_mut(), mock_info(owner, &[]));
+        assert_eq!(res, Err(MixnetContractError::NoPendingOwnershipTransfer));
+
+        // can't transfer to somebody who already has a bond
+        test.add_dummy_mixnode("mix-owner", None);
+        let res = try_propose_gateway_ownership_transfer(
+            test.deps_mut(),
+            mock_info(owner, &[]),
+            "mix-owner".to_string(),
+        );
+        assert_eq!(res, Err(MixnetContractError::AlreadyOwnsMixnode));
+
+        try_propose_gateway_ownership_transfer(
+            test.deps_mut(),
+            mock_info(owner, &[]),
+            new_owner.to_string(),
+        )
+        .unwrap();
+
+        // only the proposed owner can accept it
+        let res = try_accept_gateway_ownership(
+            test.deps_mut(),
+            mock_info("carol", &[]),
+            identity.clone(),
+        );
+        assert_eq!(res, Err(MixnetContractError::NoPendingOwnershipTransfer));
+
+        try_accept_gateway_ownership(test.deps_mut(), mock_info(new_owner, &[]), identity.clone())
+            .unwrap();
+
+        let bond = must_get_gateway_bond_by_owner(test.deps().storage, &Addr::unchecked(new_owner))
+            .unwrap();
+        assert_eq!(bond.identity(), &identity);
+        assert_eq!(bond.owner, Addr::unchecked(new_owner));
+        assert_eq!(
+            must_get_gateway_bond_by_owner(test.deps().storage, &Addr::unchecked(owner)),
+            Err(MixnetContractError::NoAssociatedGatewayBond {
+                owner: Addr::unchecked(owner)
+            })
+        );
+        assert!(!storage::PENDING_OWNERSHIP_TRANSFERS.has(test.deps().storage, &identity));
+    }
+
+    #[test]
+    fn transferring_gateway_ownership_is_not_allowed_for_vesting_bonds() {
+        let mut test = TestSetup::new();
+
+        let owner = "alice";
+        test.add_dummy_gateway_with_legal_proxy(owner, None);
+
+        let res = try_propose_gateway_ownership_transfer(
+            test.deps_mut(),
+            mock_info(owner, &[]),
+            "bob".to_string(),
+        );
+        assert_eq!(res, Err(MixnetContractError::VestingBondOwnershipTransfer));
+    }
+
+    #[test]
+    fn pending_gateway_ownership_transfer_is_removed_on_unbonding() {
+        let mut test = TestSetup::new();
+
+        let owner = "alice";
+        let identity = test.add_dummy_gateway(owner, None);
+
+        try_propose_gateway_ownership_transfer(
+            test.deps_mut(),
+            mock_info(owner, &[]),
+            "bob".to_string(),
+        )
+        .unwrap();
+        try_remove_gateway(test.deps_mut(), mock_info(owner, &[])).unwrap();
+
+        assert!(!storage::PENDING_OWNERSHIP_TRANSFERS.has(test.deps().storage, &identity));
+    }
 }
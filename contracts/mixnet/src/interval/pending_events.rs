@@ -1,13 +1,15 @@
 // Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use cosmwasm_std::{Addr, Coin, DepsMut, Env, Response};
+use cosmwasm_std::{Addr, Coin, Decimal, DepsMut, Env, Response};
 
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
     new_active_set_update_event, new_delegation_event, new_delegation_on_unbonded_node_event,
-    new_mixnode_cost_params_update_event, new_mixnode_unbonding_event, new_pledge_decrease_event,
-    new_pledge_increase_event, new_rewarding_params_update_event, new_undelegation_event,
+    new_mixnode_cost_params_update_event, new_mixnode_slashing_event, new_mixnode_unbonding_event,
+    new_pledge_decrease_event, new_pledge_increase_event, new_redelegation_event,
+    new_redelegation_on_unbonded_node_event, new_rewarding_params_update_event,
+    new_undelegation_event,
 };
 use mixnet_contract_common::mixnode::MixNodeCostParams;
 use mixnet_contract_common::pending_events::{
@@ -15,12 +17,13 @@ use mixnet_contract_common::pending_events::{
     PendingIntervalEventKind,
 };
 use mixnet_contract_common::reward_params::IntervalRewardingParamsUpdate;
-use mixnet_contract_common::{BlockHeight, Delegation, MixId};
+use mixnet_contract_common::{BlockHeight, Delegation, MixId, Percent};
 
 use crate::delegations;
 use crate::delegations::storage as delegations_storage;
 use crate::interval::helpers::change_interval_config;
 use crate::interval::storage;
+use crate::mixnet_contract_settings::storage as mixnet_params_storage;
 use crate::mixnodes::helpers::{cleanup_post_unbond_mixnode_storage, get_mixnode_details_by_id};
 use crate::mixnodes::storage as mixnodes_storage;
 use crate::rewards::storage as rewards_storage;
@@ -75,61 +78,25 @@ pub(crate) fn delegate(
         }
     };
 
-    let new_delegation_amount = amount.clone();
-    let mut mix_rewarding = mixnode_details.rewarding_details;
-
-    // the delegation_amount might get increased if there's already a pre-existing delegation on this mixnode
-    // (in that case we just create a fresh delegation with the sum of both)
-    let mut stored_delegation_amount = amount;
-
-    // if there's an existing delegation, then withdraw the full reward and create a new delegation
-    // with the sum of both
-    let storage_key = Delegation::generate_storage_key(mix_id, &owner, proxy.as_ref());
-    let old_delegation = if let Some(existing_delegation) =
-        delegations_storage::delegations().may_load(deps.storage, storage_key.clone())?
-    {
-        // completely remove the delegation from the node
-        let og_with_reward = mix_rewarding.undelegate(&existing_delegation)?;
-
-        // and adjust the new value by the amount removed (which contains the original delegation
-        // alongside any earned rewards)
-        stored_delegation_amount.amount += og_with_reward.amount;
-
-        Some(existing_delegation)
-    } else {
-        None
-    };
-
-    // add the amount we're intending to delegate (whether it's fresh or we're adding to the existing one)
-    mix_rewarding.add_base_delegation(stored_delegation_amount.amount)?;
+    let mix_rewarding = delegations::helpers::add_delegation(
+        deps.storage,
+        mixnode_details.rewarding_details,
+        owner.clone(),
+        mix_id,
+        amount.clone(),
+        proxy.clone(),
+        env.block.height,
+    )?;
 
     let cosmos_event = new_delegation_event(
         created_at,
         &owner,
         &proxy,
-        &new_delegation_amount,
+        &amount,
         mix_id,
         mix_rewarding.total_unit_reward,
     );
 
-    let delegation = Delegation::new(
-        owner,
-        mix_id,
-        mix_rewarding.total_unit_reward,
-        stored_delegation_amount,
-        env.block.height,
-        proxy,
-    );
-
-    // save on reading since `.save()` would have attempted to read old data that we already have on hand
-    delegations_storage::delegations().replace(
-        deps.storage,
-        storage_key,
-        Some(&delegation),
-        old_delegation.as_ref(),
-    )?;
-    rewards_storage::MIXNODE_REWARDING.save(deps.storage, mix_id, &mix_rewarding)?;
-
     Ok(Response::new().add_event(cosmos_event))
 }
 
@@ -143,10 +110,12 @@ pub(crate) fn undelegate(
     // see if the delegation still exists (in case of impatient user who decided to send multiple
     // undelegation requests in an epoch)
     let storage_key = Delegation::generate_storage_key(mix_id, &owner, proxy.as_ref());
-    let delegation = match delegations_storage::delegations().may_load(deps.storage, storage_key)? {
-        None => return Ok(Response::default()),
-        Some(delegation) => delegation,
-    };
+    let delegation =
+        match delegations_storage::delegations().may_load(deps.storage, storage_key.clone())? {
+            None => return Ok(Response::default()),
+            Some(delegation) => delegation,
+        };
+    rewards_storage::COMPOUNDING_DELEGATIONS.remove(deps.storage, storage_key);
     let mix_rewarding =
         rewards_storage::MIXNODE_REWARDING.may_load(deps.storage, mix_id)?.ok_or(MixnetContractError::inconsistent_state(
             "mixnode rewarding got removed from the storage whilst there's still an existing delegation",
@@ -171,6 +140,128 @@ pub(crate) fn undelegate(
     Ok(response)
 }
 
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn redelegate(
+    deps: DepsMut<'_>,
+    env: &Env,
+    created_at: BlockHeight,
+    owner: Addr,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    amount: Coin,
+    proxy: Option<Addr>,
+) -> Result<Response, MixnetContractError> {
+    // see if the delegation still exists (it might have been removed by an undelegation
+    // or another redelegation executed earlier in this epoch)
+    let storage_key = Delegation::generate_storage_key(from_mix_id, &owner, proxy.as_ref());
+    let delegation =
+        match delegations_storage::delegations().may_load(deps.storage, storage_key.clone())? {
+            None => return Ok(Response::default()),
+            Some(delegation) => delegation,
+        };
+
+    // check if the target node still exists (it might have unbonded between this event getting created
+    // and being executed). If that's the case, the delegation simply stays where it currently is
+    let target_details = match get_mixnode_details_by_id(deps.storage, to_mix_id)? {
+        Some(details)
+            if details.rewarding_details.still_bonded()
+                && !details.bond_information.is_unbonding =>
+        {
+            details
+        }
+        _ => {
+            return Ok(
+                Response::new().add_event(new_redelegation_on_unbonded_node_event(
+                    &owner,
+                    &proxy,
+                    from_mix_id,
+                    to_mix_id,
+                )),
+            )
+        }
+    };
+
+    let source_rewarding = rewards_storage::MIXNODE_REWARDING
+        .may_load(deps.storage, from_mix_id)?
+        .ok_or(MixnetContractError::inconsistent_state(
+            "mixnode rewarding got removed from the storage whilst there's still an existing delegation",
+        ))?;
+
+    // the delegation could have been modified since the event got created, so make sure we never
+    // move more than what's currently delegated
+    let principal = delegation.amount.amount;
+    let mut moved = amount.amount.min(principal);
+
+    // nor leave behind a dust delegation below the minimum (that might have been changed
+    // since the event got created), in that case just move everything
+    let minimum_delegation = mixnet_params_storage::minimum_delegation_stake(deps.storage)?;
+    if let Some(minimum) = minimum_delegation {
+        if principal - moved < minimum.amount {
+            moved = principal;
+        }
+    }
+    let remaining = principal - moved;
+
+    // completely remove the delegation from the source node. this also appropriately adjusts the storage
+    let with_reward = delegations::helpers::undelegate(deps.storage, delegation, source_rewarding)?;
+    let reward = Coin::new(
+        with_reward.amount.saturating_sub(principal).u128(),
+        &with_reward.denom,
+    );
+
+    // if only part of the delegation is getting moved, put the rest back onto the source node
+    // (note that all of the accrued rewards are moved to the target node)
+    if !remaining.is_zero() {
+        let source_rewarding =
+            rewards_storage::MIXNODE_REWARDING.load(deps.storage, from_mix_id)?;
+        delegations::helpers::add_delegation(
+            deps.storage,
+            source_rewarding,
+            owner.clone(),
+            from_mix_id,
+            Coin::new(remaining.u128(), &with_reward.denom),
+            proxy.clone(),
+            env.block.height,
+        )?;
+    } else {
+        // the source delegation no longer exists so there's nothing left to compound
+        rewards_storage::COMPOUNDING_DELEGATIONS.remove(deps.storage, storage_key);
+    }
+
+    let redelegated = Coin::new((moved + reward.amount).u128(), &with_reward.denom);
+    delegations::helpers::add_delegation(
+        deps.storage,
+        target_details.rewarding_details,
+        owner.clone(),
+        to_mix_id,
+        redelegated.clone(),
+        proxy.clone(),
+        env.block.height,
+    )?;
+
+    // the vesting contract only tracks the principal, the rewards are accounted for once they're withdrawn
+    let response = Response::new()
+        .add_event(new_redelegation_event(
+            created_at,
+            &owner,
+            &proxy,
+            from_mix_id,
+            to_mix_id,
+            &redelegated,
+            &reward,
+        ))
+        .maybe_add_track_vesting_redelegation_message(
+            deps.storage,
+            proxy,
+            owner.into_string(),
+            from_mix_id,
+            to_mix_id,
+            Coin::new(moved.u128(), &with_reward.denom),
+        )?;
+
+    Ok(response)
+}
+
 pub(crate) fn unbond_mixnode(
     deps: DepsMut<'_>,
     env: &Env,
@@ -340,6 +431,54 @@ pub(crate) fn decrease_pledge(
     Ok(response)
 }
 
+pub(crate) fn slash_mixnode(
+    deps: DepsMut<'_>,
+    created_at: BlockHeight,
+    mix_id: MixId,
+    fraction: Percent,
+) -> Result<Response, MixnetContractError> {
+    // unbonding nodes are slashed immediately rather than through the queue, so the node should
+    // still be bonded, but if it's not, there's nothing left to slash and we shouldn't fail the epoch transition
+    let mix_details = match get_mixnode_details_by_id(deps.storage, mix_id)? {
+        Some(details) if details.rewarding_details.still_bonded() => details,
+        _ => {
+            return Ok(Response::new().add_event(new_mixnode_slashing_event(
+                created_at,
+                mix_id,
+                fraction,
+                Decimal::zero(),
+            )))
+        }
+    };
+
+    let mut updated_bond = mix_details.bond_information.clone();
+    let mut updated_rewarding = mix_details.rewarding_details;
+
+    // slash the entire operator's stake, i.e. the pledge alongside all compounded and not yet withdrawn rewards
+    let slashed = updated_rewarding.operator * fraction.value();
+    updated_rewarding.decrease_operator_decimal(slashed)?;
+
+    // the original pledge has to be decreased proportionally (and rounded down) so that it would
+    // never exceed the remaining operator's stake
+    updated_bond.original_pledge.amount =
+        updated_bond.original_pledge.amount * (Decimal::one() - fraction.value());
+
+    mixnodes_storage::mixnode_bonds().replace(
+        deps.storage,
+        mix_id,
+        Some(&updated_bond),
+        Some(&mix_details.bond_information),
+    )?;
+    rewards_storage::MIXNODE_REWARDING.save(deps.storage, mix_id, &updated_rewarding)?;
+
+    // the slashed tokens stay in the contract and go back into the reward pool
+    rewards_storage::slashing_accounting(deps.storage, slashed)?;
+
+    Ok(Response::new().add_event(new_mixnode_slashing_event(
+        created_at, mix_id, fraction, slashed,
+    )))
+}
+
 impl ContractExecutableEvent for PendingEpochEventData {
     fn execute(self, deps: DepsMut<'_>, env: &Env) -> Result<Response, MixnetContractError> {
         // note that the basic validation on all those events was already performed before
@@ -356,6 +495,22 @@ impl ContractExecutableEvent for PendingEpochEventData {
                 mix_id,
                 proxy,
             } => undelegate(deps, self.created_at, owner, mix_id, proxy),
+            PendingEpochEventKind::Redelegate {
+                owner,
+                from_mix_id,
+                to_mix_id,
+                amount,
+                proxy,
+            } => redelegate(
+                deps,
+                env,
+                self.created_at,
+                owner,
+                from_mix_id,
+                to_mix_id,
+                amount,
+                proxy,
+            ),
             PendingEpochEventKind::PledgeMore { mix_id, amount } => {
                 increase_pledge(deps, self.created_at, mix_id, amount)
             }
@@ -369,6 +524,9 @@ impl ContractExecutableEvent for PendingEpochEventData {
             PendingEpochEventKind::UpdateActiveSetSize { new_size } => {
                 update_active_set_size(deps, self.created_at, new_size)
             }
+            PendingEpochEventKind::SlashMixnode { mix_id, fraction } => {
+                slash_mixnode(deps, self.created_at, mix_id, fraction)
+            }
         }
     }
 }
@@ -1112,6 +1270,239 @@ mod tests {
         }
     }
 
+    #[cfg(test)]
+    mod redelegating {
+        use cosmwasm_std::{coin, to_binary, CosmosMsg, WasmMsg};
+
+        use mixnet_contract_common::rewarding::helpers::truncate_reward_amount;
+
+        use crate::support::tests::fixtures::TEST_COIN_DENOM;
+        use crate::support::tests::test_helpers::get_bank_send_msg;
+
+        use super::*;
+
+        fn delegation_exists(test: &TestSetup, mix_id: MixId, owner: &str) -> bool {
+            let storage_key =
+                Delegation::generate_storage_key(mix_id, &Addr::unchecked(owner), None);
+            delegations_storage::delegations()
+                .may_load(test.deps().storage, storage_key)
+                .unwrap()
+                .is_some()
+        }
+
+        #[test]
+        fn doesnt_do_anything_if_delegation_doesnt_exist() {
+            let mut test = TestSetup::new();
+            let from = test.add_dummy_mixnode("mix-owner1", None);
+            let to = test.add_dummy_mixnode("mix-owner2", None);
+            let env = test.env();
+
+            let owner = Addr::unchecked("delegator");
+            let amount = coin(100_000_000, TEST_COIN_DENOM);
+
+            let res =
+                redelegate(test.deps_mut(), &env, 123, owner, from, to, amount, None).unwrap();
+            assert_eq!(res, Response::default());
+            assert!(!delegation_exists(&test, to, "delegator"));
+        }
+
+        #[test]
+        fn keeps_the_delegation_in_place_if_target_has_unbonded() {
+            let mut test = TestSetup::new();
+            let from = test.add_dummy_mixnode("mix-owner1", None);
+            let to = test.add_dummy_mixnode("mix-owner2", None);
+            let env = test.env();
+
+            let owner = "delegator";
+            let delegation = 120_000_000u128;
+            test.add_immediate_delegation(owner, delegation, from);
+            let before = test.delegation(from, owner, &None);
+
+            unbond_mixnode(test.deps_mut(), &env, 123, to).unwrap();
+
+            let res = redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from,
+                to,
+                coin(delegation, TEST_COIN_DENOM),
+                None,
+            )
+            .unwrap();
+            assert!(res.messages.is_empty());
+            assert_eq!(before, test.delegation(from, owner, &None));
+            assert!(!delegation_exists(&test, to, owner));
+        }
+
+        #[test]
+        fn moves_the_entire_delegation_alongside_earned_rewards() {
+            let mut test = TestSetup::new();
+            let from = test.add_dummy_mixnode("mix-owner1", Some(100_000_000_000u128.into()));
+            let to = test.add_dummy_mixnode("mix-owner2", None);
+
+            let owner = "delegator";
+            let delegation = 120_000_000u128;
+            test.add_immediate_delegation(owner, delegation, from);
+
+            test.force_change_rewarded_set(vec![from]);
+            test.skip_to_next_epoch_end();
+            let dist1 = test
+                .reward_with_distribution_with_state_bypass(from, test_helpers::performance(100.0));
+            test.skip_to_next_epoch_end();
+            let dist2 = test
+                .reward_with_distribution_with_state_bypass(from, test_helpers::performance(100.0));
+            let truncated_reward = truncate_reward_amount(dist1.delegates + dist2.delegates);
+
+            let env = test.env();
+            let res = redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from,
+                to,
+                coin(delegation, TEST_COIN_DENOM),
+                None,
+            )
+            .unwrap();
+
+            // nothing is returned to the delegator
+            assert!(get_bank_send_msg(&res).is_none());
+
+            // the delegation is gone from the source node
+            assert!(!delegation_exists(&test, from, owner));
+            let source_rewarding = test.mix_rewarding(from);
+            assert!(source_rewarding.delegates.is_zero());
+            assert_eq!(source_rewarding.unique_delegations, 0);
+
+            // and it has been recreated on the target node with all the rewards
+            let moved = test.delegation(to, owner, &None);
+            assert_eq!(
+                moved.amount.amount.u128(),
+                delegation + truncated_reward.u128()
+            );
+            assert_eq!(test.mix_rewarding(to).unique_delegations, 1);
+        }
+
+        #[test]
+        fn leaves_the_remaining_delegation_on_the_source_node() {
+            let mut test = TestSetup::new();
+            let from = test.add_dummy_mixnode("mix-owner1", None);
+            let to = test.add_dummy_mixnode("mix-owner2", None);
+            let env = test.env();
+
+            let owner = "delegator";
+            test.add_immediate_delegation(owner, 150_000_000u128, from);
+            test.add_immediate_delegation(owner, 10_000_000u128, to);
+
+            redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from,
+                to,
+                coin(100_000_000, TEST_COIN_DENOM),
+                None,
+            )
+            .unwrap();
+
+            let remaining = test.delegation(from, owner, &None);
+            assert_eq!(remaining.amount.amount.u128(), 50_000_000);
+
+            // existing delegation on the target node got merged with the moved tokens
+            let moved = test.delegation(to, owner, &None);
+            assert_eq!(moved.amount.amount.u128(), 110_000_000);
+        }
+
+        #[test]
+        fn moves_everything_if_remainder_would_be_below_minimum_delegation() {
+            let mut test = TestSetup::new();
+            let from = test.add_dummy_mixnode("mix-owner1", None);
+            let to = test.add_dummy_mixnode("mix-owner2", None);
+            let env = test.env();
+
+            let owner = "delegator";
+            test.add_immediate_delegation(owner, 150_000_000u128, from);
+
+            // the minimum got increased after the redelegation request had been created
+            let mut contract_state = mixnet_params_storage::CONTRACT_STATE
+                .load(test.deps().storage)
+                .unwrap();
+            contract_state.params.minimum_mixnode_delegation =
+                Some(coin(60_000_000, TEST_COIN_DENOM));
+            mixnet_params_storage::CONTRACT_STATE
+                .save(test.deps_mut().storage, &contract_state)
+                .unwrap();
+
+            redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from,
+                to,
+                coin(100_000_000, TEST_COIN_DENOM),
+                None,
+            )
+            .unwrap();
+
+            assert!(!delegation_exists(&test, from, owner));
+            let moved = test.delegation(to, owner, &None);
+            assert_eq!(moved.amount.amount.u128(), 150_000_000);
+        }
+
+        #[test]
+        fn attaches_vesting_contract_track_message() {
+            let mut test = TestSetup::new();
+            let from = test.add_dummy_mixnode("mix-owner1", None);
+            let to = test.add_dummy_mixnode("mix-owner2", None);
+            let env = test.env();
+
+            let owner = "delegator";
+            let amount = coin(100_000_000, TEST_COIN_DENOM);
+            let vesting_contract = test.vesting_contract();
+            test.add_immediate_delegation_with_legal_proxy(owner, 120_000_000u128, from);
+
+            let res = redelegate(
+                test.deps_mut(),
+                &env,
+                123,
+                Addr::unchecked(owner),
+                from,
+                to,
+                amount.clone(),
+                Some(vesting_contract.clone()),
+            )
+            .unwrap();
+
+            let mut found_track = false;
+            for msg in &res.messages {
+                if let CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr,
+                    msg,
+                    funds,
+                }) = &msg.msg
+                {
+                    found_track = true;
+                    assert_eq!(contract_addr, vesting_contract.as_str());
+                    let expected_msg = to_binary(&VestingContractExecuteMsg::TrackRedelegation {
+                        owner: owner.to_string(),
+                        from_mix_id: from,
+                        to_mix_id: to,
+                        amount: amount.clone(),
+                    })
+                    .unwrap();
+                    assert_eq!(&expected_msg, msg);
+                    assert!(funds.is_empty())
+                }
+            }
+            assert!(found_track);
+        }
+    }
+
     #[cfg(test)]
     mod mixnode_unbonding {
         use cosmwasm_std::{coin, to_binary, CosmosMsg, Uint128, WasmMsg};
@@ -2059,6 +2450,7 @@ mod tests {
             active_set_work_factor: None,
             interval_pool_emission: None,
             rewarded_set_size: None,
+            gateway_epoch_reward: None,
         };
 
         let res = update_rewarding_params(test.deps_mut(), 123, update);
@@ -2,24 +2,33 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::storage;
+use crate::delegations::storage as delegations_storage;
 use crate::interval::helpers::change_interval_config;
 use crate::interval::pending_events::ContractExecutableEvent;
 use crate::interval::storage::push_new_interval_event;
+use crate::jailing::storage as jailing_storage;
+use crate::mixnodes::helpers::get_mixnode_details_by_id;
+use crate::mixnodes::storage as mixnodes_storage;
 use crate::mixnodes::transactions::update_mixnode_layer;
 use crate::rewards;
+use crate::rewards::models::CompoundingStage;
 use crate::rewards::storage as rewards_storage;
 use crate::support::helpers::{
     ensure_can_advance_epoch, ensure_epoch_in_progress_state, ensure_is_authorized, ensure_is_owner,
 };
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Order, Response, Storage};
+use cosmwasm_std::{DepsMut, Env, Event, MessageInfo, Order, Response, StdResult, Storage};
+use cw_storage_plus::Bound;
+use mixnet_contract_common::delegation::StorageKey;
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
-    new_advance_epoch_event, new_epoch_transition_start_event,
+    new_advance_epoch_event, new_compound_delegator_reward_event,
+    new_compound_operator_reward_event, new_epoch_transition_start_event,
     new_pending_epoch_events_execution_event, new_pending_interval_config_update_event,
     new_pending_interval_events_execution_event, new_reconcile_pending_events,
+    new_reward_compounding_execution_event,
 };
 use mixnet_contract_common::pending_events::PendingIntervalEventKind;
-use mixnet_contract_common::{EpochState, EpochStatus, LayerAssignment, MixId};
+use mixnet_contract_common::{EpochId, EpochState, EpochStatus, LayerAssignment, MixId};
 use std::collections::BTreeSet;
 
 // those two should be called in separate tx (from advancing epoch),
@@ -111,6 +120,172 @@ pub(crate) fn perform_pending_interval_actions(
     Ok((response, last - last_executed))
 }
 
+// returns the batch of entries to process alongside the indication of whether
+// there are no more entries left after it
+fn next_compounding_batch<T>(
+    entries: impl Iterator<Item = StdResult<T>>,
+    limit: Option<u32>,
+) -> StdResult<(Vec<T>, bool)> {
+    match limit {
+        None => Ok((entries.collect::<StdResult<_>>()?, true)),
+        Some(limit) => {
+            let limit = limit as usize;
+            // grab a single additional entry to know whether we've reached the end
+            let mut batch = entries.take(limit + 1).collect::<StdResult<Vec<_>>>()?;
+            let exhausted = batch.len() <= limit;
+            batch.truncate(limit);
+            Ok((batch, exhausted))
+        }
+    }
+}
+
+fn compound_operator_reward(
+    storage: &mut dyn Storage,
+    mix_id: MixId,
+) -> Result<Option<Event>, MixnetContractError> {
+    let mix_details = match get_mixnode_details_by_id(storage, mix_id)? {
+        Some(mix_details) if !mix_details.is_unbonding() => mix_details,
+        // the entry is going to get removed once the node finishes unbonding
+        Some(_) => return Ok(None),
+        None => {
+            rewards_storage::COMPOUNDING_OPERATORS.remove(storage, mix_id);
+            return Ok(None);
+        }
+    };
+
+    let owner = mix_details.bond_information.owner.clone();
+    let proxy = mix_details.bond_information.proxy.clone();
+    let reward = rewards::helpers::compound_operator_reward(storage, mix_details)?;
+    if reward.amount.is_zero() {
+        return Ok(None);
+    }
+
+    Ok(Some(new_compound_operator_reward_event(
+        &owner, &proxy, reward, mix_id,
+    )))
+}
+
+fn compound_delegator_reward(
+    storage: &mut dyn Storage,
+    storage_key: StorageKey,
+) -> Result<Option<Event>, MixnetContractError> {
+    let delegation =
+        match delegations_storage::delegations().may_load(storage, storage_key.clone())? {
+            Some(delegation) => delegation,
+            None => {
+                rewards_storage::COMPOUNDING_DELEGATIONS.remove(storage, storage_key);
+                return Ok(None);
+            }
+        };
+    let mix_id = delegation.mix_id;
+
+    // there's no point in compounding rewards of nodes that are unbonding,
+    // and if the node has already unbonded, the only remaining option is to undelegate
+    match mixnodes_storage::mixnode_bonds().may_load(storage, mix_id)? {
+        Some(mix_bond) if mix_bond.is_unbonding => return Ok(None),
+        None => {
+            rewards_storage::COMPOUNDING_DELEGATIONS.remove(storage, storage_key);
+            return Ok(None);
+        }
+        _ => (),
+    };
+
+    let mix_rewarding =
+        rewards_storage::MIXNODE_REWARDING.may_load(storage, mix_id)?.ok_or(MixnetContractError::inconsistent_state(
+            "mixnode rewarding got removed from the storage whilst there's still an existing delegation"
+        ))?;
+
+    let owner = delegation.owner.clone();
+    let proxy = delegation.proxy.clone();
+    let reward = rewards::helpers::compound_delegator_reward(storage, delegation, mix_rewarding)?;
+    if reward.amount.is_zero() {
+        return Ok(None);
+    }
+
+    Ok(Some(new_compound_delegator_reward_event(
+        &owner, &proxy, reward, mix_id,
+    )))
+}
+
+// adds all rewards earned by operators and delegators that have opted into compounding to their stake.
+// similarly to the pending events, it might get split into multiple transactions if there are
+// a lot of entries to go through
+pub(crate) fn perform_reward_compounding(
+    deps: DepsMut<'_>,
+    epoch_id: EpochId,
+    limit: Option<u32>,
+) -> Result<(Response, u32), MixnetContractError> {
+    let mut progress = rewards_storage::compounding_progress(deps.storage, epoch_id)?;
+    let mut response = Response::new();
+    let mut compounded = 0;
+
+    while !progress.is_finished() {
+        let remaining = limit.map(|limit| limit - compounded);
+        let exhausted = match progress.stage {
+            CompoundingStage::Operators { start_after } => {
+                let entries = rewards_storage::COMPOUNDING_OPERATORS.keys(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                );
+                let (batch, exhausted) = next_compounding_batch(entries, remaining)?;
+                for mix_id in &batch {
+                    if let Some(event) = compound_operator_reward(deps.storage, *mix_id)? {
+                        response = response.add_event(event);
+                    }
+                }
+                compounded += batch.len() as u32;
+
+                progress.stage = if exhausted {
+                    CompoundingStage::Delegations { start_after: None }
+                } else {
+                    CompoundingStage::Operators {
+                        start_after: batch.last().copied().or(start_after),
+                    }
+                };
+                exhausted
+            }
+            CompoundingStage::Delegations { start_after } => {
+                let entries = rewards_storage::COMPOUNDING_DELEGATIONS.keys(
+                    deps.storage,
+                    start_after.clone().map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                );
+                let (batch, exhausted) = next_compounding_batch(entries, remaining)?;
+                for storage_key in &batch {
+                    if let Some(event) =
+                        compound_delegator_reward(deps.storage, storage_key.clone())?
+                    {
+                        response = response.add_event(event);
+                    }
+                }
+                compounded += batch.len() as u32;
+
+                progress.stage = if exhausted {
+                    CompoundingStage::Finished
+                } else {
+                    CompoundingStage::Delegations {
+                        start_after: batch.last().cloned().or(start_after),
+                    }
+                };
+                exhausted
+            }
+            CompoundingStage::Finished => true,
+        };
+
+        // if the stage wasn't exhausted, it means we have reached the limit
+        if !exhausted {
+            break;
+        }
+    }
+
+    rewards_storage::COMPOUNDING_PROGRESS.save(deps.storage, &progress)?;
+
+    Ok((response, compounded))
+}
+
 pub fn try_reconcile_epoch_events(
     mut deps: DepsMut<'_>,
     env: Env,
@@ -159,12 +334,14 @@ pub fn try_reconcile_epoch_events(
         response
             .events
             .push(new_pending_interval_events_execution_event(executed));
+
+        limit = limit.map(|l| l - executed)
     }
 
     // if there are no more events to clear, go into the next state
     let pending_events = super::queries::query_number_of_pending_events(deps.as_ref())?;
     // we can only progress if there are no epoch events AND if the interval has finished, that there are no interval events
-    let progress = if pending_events.epoch_events == 0 {
+    let events_cleared = if pending_events.epoch_events == 0 {
         if interval.is_current_interval_over(&env) {
             pending_events.interval_events == 0
         } else {
@@ -174,6 +351,24 @@ pub fn try_reconcile_epoch_events(
         false
     };
 
+    // rewards only get compounded once all the events got cleared so that they'd be applied
+    // onto the final state of the bonds and delegations
+    let progress = if events_cleared {
+        let epoch_id = interval.current_epoch_absolute_id();
+        let (mut sub_response, compounded) =
+            perform_reward_compounding(deps.branch(), epoch_id, limit)?;
+        response.messages.append(&mut sub_response.messages);
+        response.attributes.append(&mut sub_response.attributes);
+        response.events.append(&mut sub_response.events);
+        response
+            .events
+            .push(new_reward_compounding_execution_event(compounded));
+
+        rewards_storage::compounding_progress(deps.storage, epoch_id)?.is_finished()
+    } else {
+        false
+    };
+
     if progress {
         current_epoch_status.state = EpochState::AdvancingEpoch;
         storage::save_current_epoch_status(deps.storage, &current_epoch_status)?;
@@ -186,6 +381,7 @@ fn update_rewarded_set(
     storage: &mut dyn Storage,
     new_rewarded_set: Vec<MixId>,
     expected_active_set_size: u32,
+    new_absolute_epoch_id: EpochId,
 ) -> Result<(), MixnetContractError> {
     let reward_params = rewards_storage::REWARDING_PARAMS.load(storage)?;
 
@@ -213,6 +409,18 @@ fn update_rewarded_set(
         }
     }
 
+    // jailed nodes are not eligible for being included in the rewarded set
+    for node_id in &new_rewarded_set {
+        if let Some(jail) =
+            jailing_storage::jailed_in_epoch(storage, *node_id, new_absolute_epoch_id)?
+        {
+            return Err(MixnetContractError::MixnodeJailed {
+                mix_id: *node_id,
+                released_at_epoch: jail.released_at_epoch,
+            });
+        }
+    }
+
     Ok(storage::update_rewarded_set(
         storage,
         expected_active_set_size,
@@ -305,7 +513,12 @@ pub fn try_advance_epoch(
 
     // finally save updated interval and the rewarded set
     storage::save_interval(deps.storage, &updated_interval)?;
-    update_rewarded_set(deps.storage, new_rewarded_set, expected_active_set_size)?;
+    update_rewarded_set(
+        deps.storage,
+        new_rewarded_set,
+        expected_active_set_size,
+        updated_interval.current_epoch_absolute_id(),
+    )?;
 
     for a in layer_assignments {
         update_mixnode_layer(a.mix_id(), a.layer(), deps.storage)?;
@@ -1408,6 +1621,7 @@ mod tests {
             test.deps_mut().storage,
             vec![1, 2, 3],
             current_active_set - 10,
+            1,
         )
         .unwrap_err();
         assert_eq!(
@@ -1421,21 +1635,26 @@ mod tests {
         // number of nodes provided has to be equal or smaller than the current rewarded set size
 
         // fewer nodes
-        let res = update_rewarded_set(test.deps_mut().storage, vec![1, 2, 3], current_active_set);
+        let res = update_rewarded_set(
+            test.deps_mut().storage,
+            vec![1, 2, 3],
+            current_active_set,
+            1,
+        );
         assert!(res.is_ok());
 
         let exact_num = (1u32..)
             .take(current_rewarded_set as usize)
             .collect::<Vec<_>>();
-        let res = update_rewarded_set(test.deps_mut().storage, exact_num, current_active_set);
+        let res = update_rewarded_set(test.deps_mut().storage, exact_num, current_active_set, 1);
         assert!(res.is_ok());
 
         // one more
         let too_many = (1u32..)
             .take((current_rewarded_set + 1) as usize)
             .collect::<Vec<_>>();
-        let err =
-            update_rewarded_set(test.deps_mut().storage, too_many, current_active_set).unwrap_err();
+        let err = update_rewarded_set(test.deps_mut().storage, too_many, current_active_set, 1)
+            .unwrap_err();
         assert_eq!(
             err,
             MixnetContractError::UnexpectedRewardedSetSize {
@@ -1450,6 +1669,7 @@ mod tests {
             test.deps_mut().storage,
             nodes_with_duplicate,
             current_active_set,
+            1,
         )
         .unwrap_err();
         assert_eq!(
@@ -1461,6 +1681,7 @@ mod tests {
             test.deps_mut().storage,
             nodes_with_duplicate,
             current_active_set,
+            1,
         )
         .unwrap_err();
         assert_eq!(
@@ -1469,6 +1690,163 @@ mod tests {
         );
     }
 
+    #[cfg(test)]
+    mod compounding_rewards {
+        use super::*;
+        use crate::rewards::queries::{
+            query_pending_delegator_reward, query_pending_mixnode_operator_reward,
+        };
+        use crate::rewards::transactions::{
+            try_set_delegator_reward_compounding, try_set_operator_reward_compounding,
+        };
+        use crate::support::tests::test_helpers;
+        use cosmwasm_std::testing::mock_info;
+        use cosmwasm_std::Uint128;
+
+        fn setup_rewarded_node(test: &mut TestSetup) -> MixId {
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(1_000_000_000_000)));
+            test.add_immediate_delegation("alice", 100_000_000_000u128, mix_id);
+            test.add_immediate_delegation("bob", 100_000_000_000u128, mix_id);
+            test.add_immediate_delegation("carol", 100_000_000_000u128, mix_id);
+
+            try_set_operator_reward_compounding(test.deps_mut(), mock_info("mix-owner", &[]), true)
+                .unwrap();
+            for delegator in ["alice", "bob"] {
+                try_set_delegator_reward_compounding(
+                    test.deps_mut(),
+                    mock_info(delegator, &[]),
+                    mix_id,
+                    true,
+                )
+                .unwrap();
+            }
+
+            test.skip_to_next_epoch_end();
+            test.force_change_rewarded_set(vec![mix_id]);
+            test.start_epoch_transition();
+            test.reward_with_distribution(mix_id, test_helpers::performance(100.0));
+            mix_id
+        }
+
+        fn pending_delegator_reward(test: &TestSetup, delegator: &str, mix_id: MixId) -> Uint128 {
+            query_pending_delegator_reward(test.deps(), delegator.into(), mix_id, None)
+                .unwrap()
+                .amount_earned
+                .unwrap()
+                .amount
+        }
+
+        #[test]
+        fn adds_rewards_of_opted_in_operators_and_delegators_to_their_stake() {
+            let mut test = TestSetup::new();
+            let mix_id = setup_rewarded_node(&mut test);
+
+            let operator_reward = query_pending_mixnode_operator_reward(test.deps(), mix_id)
+                .unwrap()
+                .amount_earned
+                .unwrap()
+                .amount;
+            let alice_reward = pending_delegator_reward(&test, "alice", mix_id);
+            let carol_reward = pending_delegator_reward(&test, "carol", mix_id);
+            assert!(!operator_reward.is_zero());
+            assert!(!alice_reward.is_zero());
+
+            let env = test.env();
+            let rewarding_validator = test.rewarding_validator();
+            try_reconcile_epoch_events(test.deps_mut(), env, rewarding_validator, None).unwrap();
+
+            assert_eq!(
+                test.mix_bond(mix_id).original_pledge.amount,
+                Uint128::new(1_000_000_000_000) + operator_reward
+            );
+            assert_eq!(
+                test.delegation(mix_id, "alice", &None).amount.amount,
+                Uint128::new(100_000_000_000) + alice_reward
+            );
+            assert!(pending_delegator_reward(&test, "alice", mix_id).is_zero());
+
+            // carol hasn't opted in so her reward is still there to be withdrawn
+            assert_eq!(
+                test.delegation(mix_id, "carol", &None).amount.amount,
+                Uint128::new(100_000_000_000)
+            );
+            assert_eq!(
+                pending_delegator_reward(&test, "carol", mix_id),
+                carol_reward
+            );
+
+            assert_eq!(
+                storage::current_epoch_status(test.deps().storage)
+                    .unwrap()
+                    .state,
+                EpochState::AdvancingEpoch
+            );
+        }
+
+        #[test]
+        fn respects_the_limit() {
+            let mut test = TestSetup::new();
+            let mix_id = setup_rewarded_node(&mut test);
+            let env = test.env();
+            let rewarding_validator = test.rewarding_validator();
+
+            // operator + 2 delegations to go through
+            for _ in 0..2 {
+                try_reconcile_epoch_events(
+                    test.deps_mut(),
+                    env.clone(),
+                    rewarding_validator.clone(),
+                    Some(1),
+                )
+                .unwrap();
+                assert_eq!(
+                    storage::current_epoch_status(test.deps().storage)
+                        .unwrap()
+                        .state,
+                    EpochState::ReconcilingEvents
+                );
+            }
+            // alice got processed but bob is still waiting
+            assert!(pending_delegator_reward(&test, "alice", mix_id).is_zero());
+            assert!(!pending_delegator_reward(&test, "bob", mix_id).is_zero());
+
+            try_reconcile_epoch_events(test.deps_mut(), env, rewarding_validator, Some(1)).unwrap();
+            assert!(pending_delegator_reward(&test, "bob", mix_id).is_zero());
+            assert_eq!(
+                storage::current_epoch_status(test.deps().storage)
+                    .unwrap()
+                    .state,
+                EpochState::AdvancingEpoch
+            );
+        }
+
+        #[test]
+        fn happens_only_after_all_pending_events_are_cleared() {
+            let mut test = TestSetup::new();
+            let mix_id = setup_rewarded_node(&mut test);
+            push_n_dummy_epoch_actions(&mut test, 3);
+
+            let alice_reward = pending_delegator_reward(&test, "alice", mix_id);
+            let env = test.env();
+            let rewarding_validator = test.rewarding_validator();
+
+            try_reconcile_epoch_events(
+                test.deps_mut(),
+                env.clone(),
+                rewarding_validator.clone(),
+                Some(3),
+            )
+            .unwrap();
+            assert_eq!(
+                pending_delegator_reward(&test, "alice", mix_id),
+                alice_reward
+            );
+
+            try_reconcile_epoch_events(test.deps_mut(), env, rewarding_validator, None).unwrap();
+            assert!(pending_delegator_reward(&test, "alice", mix_id).is_zero());
+        }
+    }
+
     #[cfg(test)]
     mod advancing_epoch {
         use super::*;
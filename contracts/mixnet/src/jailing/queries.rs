@@ -0,0 +1,98 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::storage;
+use crate::constants::{
+    JAILED_MIXNODES_DEFAULT_RETRIEVAL_LIMIT, JAILED_MIXNODES_MAX_RETRIEVAL_LIMIT,
+    MISBEHAVIOUR_REPORTS_DEFAULT_RETRIEVAL_LIMIT, MISBEHAVIOUR_REPORTS_MAX_RETRIEVAL_LIMIT,
+};
+use crate::interval::storage as interval_storage;
+use cosmwasm_std::{Deps, Order, StdResult};
+use cw_storage_plus::Bound;
+use mixnet_contract_common::jailing::{
+    JailedMixnode, MisbehaviourReport, MixnodeJailStatusResponse, OffenceNumber,
+    PagedJailedMixnodesResponse, PagedMisbehaviourReportsResponse,
+};
+use mixnet_contract_common::MixId;
+
+pub fn query_mixnode_jail_status(
+    deps: Deps<'_>,
+    mix_id: MixId,
+) -> StdResult<MixnodeJailStatusResponse> {
+    let current_epoch =
+        interval_storage::current_interval(deps.storage)?.current_epoch_absolute_id();
+    let jail = storage::JAILED_MIXNODES.may_load(deps.storage, mix_id)?;
+
+    Ok(MixnodeJailStatusResponse {
+        mix_id,
+        currently_jailed: jail
+            .as_ref()
+            .map(|jail| jail.is_jailed_in(current_epoch))
+            .unwrap_or_default(),
+        jail,
+    })
+}
+
+/// Returns mixnodes that are jailed in the current epoch, i.e. nodes that are not eligible
+/// for being included in the rewarded set.
+pub fn query_jailed_mixnodes_paged(
+    deps: Deps<'_>,
+    start_after: Option<MixId>,
+    limit: Option<u32>,
+) -> StdResult<PagedJailedMixnodesResponse> {
+    let limit = limit
+        .unwrap_or(JAILED_MIXNODES_DEFAULT_RETRIEVAL_LIMIT)
+        .min(JAILED_MIXNODES_MAX_RETRIEVAL_LIMIT) as usize;
+
+    let current_epoch =
+        interval_storage::current_interval(deps.storage)?.current_epoch_absolute_id();
+    let start = start_after.map(Bound::exclusive);
+
+    let nodes = storage::JAILED_MIXNODES
+        .range(deps.storage, start, None, Order::Ascending)
+        .filter(|res| {
+            res.as_ref()
+                .map(|(_, jail)| jail.is_jailed_in(current_epoch))
+                .unwrap_or(true)
+        })
+        .take(limit)
+        .map(|res| res.map(|item| item.1))
+        .collect::<StdResult<Vec<JailedMixnode>>>()?;
+
+    let start_next_after = nodes.last().map(|node| node.mix_id);
+
+    Ok(PagedJailedMixnodesResponse {
+        nodes,
+        per_page: limit,
+        start_next_after,
+    })
+}
+
+pub fn query_misbehaviour_reports_paged(
+    deps: Deps<'_>,
+    mix_id: MixId,
+    start_after: Option<OffenceNumber>,
+    limit: Option<u32>,
+) -> StdResult<PagedMisbehaviourReportsResponse> {
+    let limit = limit
+        .unwrap_or(MISBEHAVIOUR_REPORTS_DEFAULT_RETRIEVAL_LIMIT)
+        .min(MISBEHAVIOUR_REPORTS_MAX_RETRIEVAL_LIMIT) as usize;
+
+    let start = start_after.map(Bound::exclusive);
+
+    let reports = storage::MISBEHAVIOUR_REPORTS
+        .prefix(mix_id)
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|res| res.map(|item| item.1))
+        .collect::<StdResult<Vec<MisbehaviourReport>>>()?;
+
+    let start_next_after = reports.last().map(|report| report.offence);
+
+    Ok(PagedMisbehaviourReportsResponse {
+        mix_id,
+        reports,
+        per_page: limit,
+        start_next_after,
+    })
+}
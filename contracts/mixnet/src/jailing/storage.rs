@@ -0,0 +1,27 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::constants::{JAILED_MIXNODES_NAMESPACE, MISBEHAVIOUR_REPORTS_NAMESPACE};
+use cosmwasm_std::{StdResult, Storage};
+use cw_storage_plus::Map;
+use mixnet_contract_common::jailing::{JailedMixnode, MisbehaviourReport, OffenceNumber};
+use mixnet_contract_common::{EpochId, MixId};
+
+// keeps track of the most recent jailing of given mixnode. the entries are not removed
+// once the jail period is over so that the offence counter would be preserved
+pub(crate) const JAILED_MIXNODES: Map<MixId, JailedMixnode> = Map::new(JAILED_MIXNODES_NAMESPACE);
+
+// evidence submitted against given mixnode, keyed by the consecutive offence number
+pub(crate) const MISBEHAVIOUR_REPORTS: Map<(MixId, OffenceNumber), MisbehaviourReport> =
+    Map::new(MISBEHAVIOUR_REPORTS_NAMESPACE);
+
+/// Returns the jailing information of the mixnode if it's jailed during the provided epoch.
+pub(crate) fn jailed_in_epoch(
+    storage: &dyn Storage,
+    mix_id: MixId,
+    absolute_epoch_id: EpochId,
+) -> StdResult<Option<JailedMixnode>> {
+    Ok(JAILED_MIXNODES
+        .may_load(storage, mix_id)?
+        .filter(|jail| jail.is_jailed_in(absolute_epoch_id)))
+}
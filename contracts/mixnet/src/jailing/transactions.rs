@@ -0,0 +1,388 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use super::storage;
+use crate::interval::pending_events::slash_mixnode;
+use crate::interval::storage as interval_storage;
+use crate::interval::storage::push_new_epoch_event;
+use crate::mixnodes::storage as mixnodes_storage;
+use crate::support::helpers::{ensure_epoch_in_progress_state, ensure_is_authorized};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use mixnet_contract_common::error::MixnetContractError;
+use mixnet_contract_common::events::{
+    new_mixnode_jailing_event, new_pending_mixnode_slashing_event,
+};
+use mixnet_contract_common::jailing::{JailedMixnode, MisbehaviourEvidence, MisbehaviourReport};
+use mixnet_contract_common::pending_events::PendingEpochEventKind;
+use mixnet_contract_common::{MixId, Percent};
+
+/// Jails the misbehaving mixnode, so that it's not rewarded for the current epoch and can't be
+/// included in the rewarded set for the following `jail_epochs` epochs. If the node is already
+/// jailed, the jail period is extended.
+/// Optionally, a fraction (below 100%) of the operator's stake is going to get slashed once the current
+/// epoch is over, or immediately if the node is already unbonding.
+/// Note that only the operator is being penalised, delegators only lose on the rewards.
+pub(crate) fn try_jail_mixnode(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    mix_id: MixId,
+    jail_epochs: u32,
+    slash_fraction: Option<Percent>,
+    evidence: MisbehaviourEvidence,
+) -> Result<Response, MixnetContractError> {
+    // only the rewarding validator, that's observing the network, can jail nodes
+    ensure_is_authorized(&info.sender, deps.storage)?;
+
+    // the potential slashing is pushed onto the epoch event queue, so we can't be in the middle of the epoch transition
+    ensure_epoch_in_progress_state(deps.storage)?;
+
+    if jail_epochs == 0 {
+        return Err(MixnetContractError::ZeroJailDuration);
+    }
+
+    // slashing the entire stake would have left the node bonded with zero pledge
+    if let Some(fraction) = slash_fraction {
+        if fraction >= Percent::hundred() {
+            return Err(MixnetContractError::InvalidSlashFraction { fraction });
+        }
+    }
+
+    let bond = mixnodes_storage::mixnode_bonds()
+        .may_load(deps.storage, mix_id)?
+        .ok_or(MixnetContractError::MixNodeBondNotFound { mix_id })?;
+
+    let current_epoch =
+        interval_storage::current_interval(deps.storage)?.current_epoch_absolute_id();
+    let released_at_epoch = current_epoch + 1 + jail_epochs;
+
+    let jail = match storage::JAILED_MIXNODES.may_load(deps.storage, mix_id)? {
+        Some(previous) => JailedMixnode {
+            mix_id,
+            jailed_at_epoch: current_epoch,
+            released_at_epoch: released_at_epoch.max(previous.released_at_epoch),
+            offences: previous.offences + 1,
+        },
+        None => JailedMixnode {
+            mix_id,
+            jailed_at_epoch: current_epoch,
+            released_at_epoch,
+            offences: 1,
+        },
+    };
+
+    let slash_fraction = slash_fraction.filter(|fraction| !fraction.is_zero());
+    let report = MisbehaviourReport {
+        mix_id,
+        offence: jail.offences,
+        reported_at_height: env.block.height,
+        reported_at_epoch: current_epoch,
+        evidence,
+        jail_epochs,
+        slash_fraction,
+    };
+
+    storage::JAILED_MIXNODES.save(deps.storage, mix_id, &jail)?;
+    storage::MISBEHAVIOUR_REPORTS.save(deps.storage, (mix_id, report.offence), &report)?;
+
+    let mut response =
+        Response::new().add_event(new_mixnode_jailing_event(&report, jail.released_at_epoch));
+
+    if let Some(fraction) = slash_fraction {
+        if bond.is_unbonding {
+            // the unbonding event is already in the queue ahead of anything we could push now,
+            // so the node has to be slashed before its stake gets returned.
+            // note that unbonding nodes can't have any pending pledge changes that could
+            // have been invalidated by this
+            let slashing = slash_mixnode(deps, env.block.height, mix_id, fraction)?;
+            response = response.add_events(slashing.events);
+        } else {
+            let slashing = PendingEpochEventKind::SlashMixnode { mix_id, fraction };
+            push_new_epoch_event(deps.storage, &env, slashing)?;
+            response = response.add_event(new_pending_mixnode_slashing_event(mix_id, fraction));
+        }
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interval::transactions::try_advance_epoch;
+    use crate::jailing::queries::query_mixnode_jail_status;
+    use crate::rewards::storage as rewards_storage;
+    use crate::rewards::transactions::try_reward_mixnode;
+    use crate::support::tests::fixtures::TEST_COIN_DENOM;
+    use crate::support::tests::test_helpers::{find_attribute, performance, TestSetup};
+    use cosmwasm_std::testing::mock_info;
+    use cosmwasm_std::{BankMsg, Coin, CosmosMsg, Decimal, Uint128};
+    use mixnet_contract_common::events::{MixnetEventType, JAILED_VALUE, NO_REWARD_REASON_KEY};
+    use mixnet_contract_common::{Layer, LayerAssignment};
+
+    fn dropping_evidence() -> MisbehaviourEvidence {
+        MisbehaviourEvidence::PacketDropping {
+            observed_performance: Percent::from_percentage_value(10).unwrap(),
+            epochs_observed: 24,
+        }
+    }
+
+    fn jail(test: &mut TestSetup, mix_id: MixId, jail_epochs: u32, slash: Option<Percent>) {
+        let env = test.env();
+        let sender = test.rewarding_validator();
+        try_jail_mixnode(
+            test.deps_mut(),
+            env,
+            sender,
+            mix_id,
+            jail_epochs,
+            slash,
+            dropping_evidence(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn can_only_be_performed_by_the_rewarding_validator() {
+        let mut test = TestSetup::new();
+        let mix_id = test.add_dummy_mixnode("mix-owner", None);
+
+        let env = test.env();
+        let res = try_jail_mixnode(
+            test.deps_mut(),
+            env,
+            mock_info("mix-owner", &[]),
+            mix_id,
+            10,
+            None,
+            dropping_evidence(),
+        );
+        assert_eq!(res, Err(MixnetContractError::Unauthorized));
+    }
+
+    #[test]
+    fn requires_non_zero_jail_duration() {
+        let mut test = TestSetup::new();
+        let mix_id = test.add_dummy_mixnode("mix-owner", None);
+
+        let env = test.env();
+        let sender = test.rewarding_validator();
+        let res = try_jail_mixnode(
+            test.deps_mut(),
+            env,
+            sender,
+            mix_id,
+            0,
+            None,
+            dropping_evidence(),
+        );
+        assert_eq!(res, Err(MixnetContractError::ZeroJailDuration));
+    }
+
+    #[test]
+    fn records_the_evidence_and_extends_existing_jail() {
+        let mut test = TestSetup::new();
+        let mix_id = test.add_dummy_mixnode("mix-owner", None);
+        let current_epoch = test.current_interval().current_epoch_absolute_id();
+
+        jail(&mut test, mix_id, 10, None);
+        let status = query_mixnode_jail_status(test.deps(), mix_id).unwrap();
+        assert!(status.currently_jailed);
+        let jailed = status.jail.unwrap();
+        assert_eq!(jailed.released_at_epoch, current_epoch + 11);
+        assert_eq!(jailed.offences, 1);
+
+        // shorter jail doesn't shorten the existing one
+        jail(&mut test, mix_id, 2, None);
+        let jailed = storage::JAILED_MIXNODES
+            .load(test.deps().storage, mix_id)
+            .unwrap();
+        assert_eq!(jailed.released_at_epoch, current_epoch + 11);
+        assert_eq!(jailed.offences, 2);
+
+        let report = storage::MISBEHAVIOUR_REPORTS
+            .load(test.deps().storage, (mix_id, 2))
+            .unwrap();
+        assert_eq!(report.evidence, dropping_evidence());
+        assert_eq!(report.jail_epochs, 2);
+        assert!(report.slash_fraction.is_none());
+        assert!(test.pending_epoch_events().is_empty());
+    }
+
+    #[test]
+    fn jailed_node_is_not_rewarded_nor_can_be_put_in_the_rewarded_set() {
+        let mut test = TestSetup::new();
+        let mix_id = test.add_dummy_mixnode("mix-owner", None);
+        test.force_change_rewarded_set(vec![mix_id]);
+
+        jail(&mut test, mix_id, 1, None);
+
+        test.skip_to_current_epoch_end();
+        test.start_epoch_transition();
+        let env = test.env();
+        let sender = test.rewarding_validator();
+        let res =
+            try_reward_mixnode(test.deps_mut(), env, sender, mix_id, performance(100.0)).unwrap();
+        assert_eq!(
+            find_attribute(
+                Some(MixnetEventType::MixnodeRewarding.to_string()),
+                NO_REWARD_REASON_KEY,
+                &res
+            ),
+            JAILED_VALUE
+        );
+
+        test.set_epoch_advancement_state();
+        let current_active_set = test.rewarding_params().active_set_size;
+        let env = test.env();
+        let sender = test.rewarding_validator();
+        let res = try_advance_epoch(
+            test.deps_mut(),
+            env,
+            sender,
+            vec![LayerAssignment::new(mix_id, Layer::One)],
+            current_active_set,
+        );
+        assert!(matches!(
+            res,
+            Err(MixnetContractError::MixnodeJailed { mix_id: id, .. }) if id == mix_id
+        ));
+    }
+
+    #[test]
+    fn epoch_can_be_advanced_with_jailed_node_still_bonded() {
+        let mut test = TestSetup::new();
+        let jailed_id = test.add_dummy_mixnode("jailed-owner", None);
+        let other_id = test.add_dummy_mixnode("other-owner", None);
+        test.force_change_rewarded_set(vec![jailed_id, other_id]);
+
+        jail(&mut test, jailed_id, 1, None);
+
+        test.skip_to_current_epoch_end();
+        test.set_epoch_advancement_state();
+        let current_active_set = test.rewarding_params().active_set_size;
+        let env = test.env();
+        let sender = test.rewarding_validator();
+        try_advance_epoch(
+            test.deps_mut(),
+            env,
+            sender,
+            vec![LayerAssignment::new(other_id, Layer::One)],
+            current_active_set,
+        )
+        .unwrap();
+
+        let rewarded_set = test.rewarded_set();
+        assert_eq!(rewarded_set.len(), 1);
+        assert_eq!(rewarded_set[0].0, other_id);
+
+        // the node is still bonded, it just got excluded from the set
+        let bond = mixnodes_storage::mixnode_bonds()
+            .load(test.deps().storage, jailed_id)
+            .unwrap();
+        assert!(!bond.is_unbonding);
+        assert!(
+            query_mixnode_jail_status(test.deps(), jailed_id)
+                .unwrap()
+                .currently_jailed
+        );
+    }
+
+    #[test]
+    fn slashing_is_applied_to_the_operator_stake_at_the_end_of_the_epoch() {
+        let mut test = TestSetup::new();
+        let pledge = Uint128::new(100_000_000_000);
+        let mix_id = test.add_dummy_mixnode("mix-owner", Some(pledge));
+
+        let fraction = Percent::from_percentage_value(10).unwrap();
+        jail(&mut test, mix_id, 5, Some(fraction));
+        assert_eq!(test.pending_epoch_events().len(), 1);
+
+        // nothing has changed yet
+        assert_eq!(test.mix_bond(mix_id).original_pledge.amount, pledge);
+
+        test.execute_all_pending_events();
+        let expected_remaining = Uint128::new(90_000_000_000);
+        assert_eq!(
+            test.mix_bond(mix_id).original_pledge.amount,
+            expected_remaining
+        );
+        assert_eq!(
+            test.mix_rewarding(mix_id).operator,
+            Decimal::from_atomics(expected_remaining, 0).unwrap()
+        );
+        let pool_change = rewards_storage::PENDING_REWARD_POOL_CHANGE
+            .load(test.deps().storage)
+            .unwrap();
+        assert_eq!(
+            pool_change.added,
+            Decimal::from_atomics(10_000_000_000u128, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn slash_fraction_must_be_below_hundred_percent() {
+        let mut test = TestSetup::new();
+        let mix_id = test.add_dummy_mixnode("mix-owner", None);
+
+        let env = test.env();
+        let sender = test.rewarding_validator();
+        let res = try_jail_mixnode(
+            test.deps_mut(),
+            env,
+            sender,
+            mix_id,
+            10,
+            Some(Percent::hundred()),
+            dropping_evidence(),
+        );
+        assert_eq!(
+            res,
+            Err(MixnetContractError::InvalidSlashFraction {
+                fraction: Percent::hundred()
+            })
+        );
+        assert!(test.pending_epoch_events().is_empty());
+    }
+
+    #[test]
+    fn unbonding_node_is_slashed_before_its_stake_is_returned() {
+        let mut test = TestSetup::new();
+        let pledge = Uint128::new(100_000_000_000);
+        let mix_id = test.add_dummy_mixnode("mix-owner", Some(pledge));
+
+        // the unbonding is queued up before the node gets jailed
+        test.start_unbonding_mixnode(mix_id);
+        let fraction = Percent::from_percentage_value(10).unwrap();
+        jail(&mut test, mix_id, 5, Some(fraction));
+
+        // the slashing didn't go through the queue, it got applied straight away
+        assert_eq!(test.pending_epoch_events().len(), 1);
+        let expected_remaining = Uint128::new(90_000_000_000);
+        assert_eq!(
+            test.mix_bond(mix_id).original_pledge.amount,
+            expected_remaining
+        );
+        assert_eq!(
+            test.mix_rewarding(mix_id).operator,
+            Decimal::from_atomics(expected_remaining, 0).unwrap()
+        );
+
+        // so the operator only gets the remaining part back
+        let env = test.env();
+        let res = crate::interval::pending_events::unbond_mixnode(
+            test.deps_mut(),
+            &env,
+            env.block.height,
+            mix_id,
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: "mix-owner".to_string(),
+                amount: vec![Coin::new(expected_remaining.u128(), TEST_COIN_DENOM)],
+            })
+        );
+    }
+}
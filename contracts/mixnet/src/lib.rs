@@ -10,6 +10,7 @@ mod delegations;
 mod families;
 mod gateways;
 mod interval;
+mod jailing;
 mod mixnet_contract_settings;
 mod mixnodes;
 mod queued_migrations;
@@ -28,7 +28,6 @@ pub(crate) fn minimum_gateway_pledge(storage: &dyn Storage) -> Result<Coin, Mixn
         .map(|state| state.params.minimum_gateway_pledge)?)
 }
 
-#[allow(unused)]
 pub(crate) fn minimum_delegation_stake(
     storage: &dyn Storage,
 ) -> Result<Option<Coin>, MixnetContractError> {
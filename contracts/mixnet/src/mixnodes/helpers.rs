@@ -154,6 +154,12 @@ pub(crate) fn cleanup_post_unbond_mixnode_storage(
         rewards_storage::MIXNODE_REWARDING.save(storage, mix_id, &zeroed)?;
     }
 
+    // there's no longer any pledge to compound the rewards into
+    rewards_storage::COMPOUNDING_OPERATORS.remove(storage, mix_id);
+
+    // nor anything left to transfer to a different owner
+    storage::PENDING_OWNERSHIP_TRANSFERS.remove(storage, mix_id);
+
     let identity = current_details.bond_information.identity().to_owned();
     let owner = current_details.bond_information.owner().to_owned();
     let proxy = current_details.bond_information.proxy.to_owned();
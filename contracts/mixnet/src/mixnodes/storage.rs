@@ -4,8 +4,9 @@
 use crate::constants::{
     LAYER_DISTRIBUTION_KEY, MIXNODES_IDENTITY_IDX_NAMESPACE, MIXNODES_OWNER_IDX_NAMESPACE,
     MIXNODES_PK_NAMESPACE, MIXNODES_SPHINX_IDX_NAMESPACE, NODE_ID_COUNTER_KEY,
-    PENDING_MIXNODE_CHANGES_NAMESPACE, UNBONDED_MIXNODES_IDENTITY_IDX_NAMESPACE,
-    UNBONDED_MIXNODES_OWNER_IDX_NAMESPACE, UNBONDED_MIXNODES_PK_NAMESPACE,
+    PENDING_MIXNODE_CHANGES_NAMESPACE, PENDING_MIXNODE_OWNERSHIP_TRANSFERS_NAMESPACE,
+    UNBONDED_MIXNODES_IDENTITY_IDX_NAMESPACE, UNBONDED_MIXNODES_OWNER_IDX_NAMESPACE,
+    UNBONDED_MIXNODES_PK_NAMESPACE,
 };
 use cosmwasm_std::{StdResult, Storage};
 use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex, UniqueIndex};
@@ -19,6 +20,10 @@ pub const MIXNODE_ID_COUNTER: Item<MixId> = Item::new(NODE_ID_COUNTER_KEY);
 pub const PENDING_MIXNODE_CHANGES: Map<MixId, PendingMixNodeChanges> =
     Map::new(PENDING_MIXNODE_CHANGES_NAMESPACE);
 
+// keeps track of `node_id -> proposed new owner` of mixnodes whose ownership is being transferred
+pub const PENDING_OWNERSHIP_TRANSFERS: Map<MixId, Addr> =
+    Map::new(PENDING_MIXNODE_OWNERSHIP_TRANSFERS_NAMESPACE);
+
 // keeps track of `node_id -> IdentityKey, Owner, unbonding_height` so we'd known a bit more about past mixnodes
 // if we ever decide it's too bloaty, we can deprecate it and start removing all data in
 // subsequent migrations
@@ -6,8 +6,10 @@ use cosmwasm_std::{coin, Addr, Coin, DepsMut, Env, MessageInfo, Response, Storag
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
     new_mixnode_bonding_event, new_mixnode_config_update_event,
-    new_mixnode_pending_cost_params_update_event, new_pending_mixnode_unbonding_event,
-    new_pending_pledge_decrease_event, new_pending_pledge_increase_event,
+    new_mixnode_ownership_transfer_cancellation_event, new_mixnode_ownership_transfer_event,
+    new_mixnode_ownership_transfer_proposal_event, new_mixnode_pending_cost_params_update_event,
+    new_pending_mixnode_unbonding_event, new_pending_pledge_decrease_event,
+    new_pending_pledge_increase_event,
 };
 use mixnet_contract_common::mixnode::{MixNodeConfigUpdate, MixNodeCostParams};
 use mixnet_contract_common::pending_events::{PendingEpochEventKind, PendingIntervalEventKind};
@@ -22,11 +24,12 @@ use crate::mixnodes::helpers::{
     get_mixnode_details_by_owner, must_get_mixnode_bond_by_owner, save_new_mixnode,
 };
 use crate::mixnodes::signature_helpers::verify_mixnode_bonding_signature;
+use crate::rewards::storage as rewards_storage;
 use crate::signing::storage as signing_storage;
 use crate::support::helpers::{
     ensure_bonded, ensure_epoch_in_progress_state, ensure_is_authorized, ensure_no_existing_bond,
-    ensure_no_pending_pledge_changes, ensure_proxy_match, ensure_sent_by_vesting_contract,
-    validate_pledge,
+    ensure_no_pending_pledge_changes, ensure_not_jailed, ensure_proxy_match,
+    ensure_sent_by_vesting_contract, validate_pledge,
 };
 
 use super::storage;
@@ -268,6 +271,9 @@ pub fn _try_decrease_pledge(
     ensure_bonded(&mix_details.bond_information)?;
     ensure_no_pending_pledge_changes(&pending_changes)?;
 
+    // jailed operators can't withdraw their pledge as it might still be subject to slashing
+    ensure_not_jailed(deps.storage, mix_id)?;
+
     let minimum_pledge = mixnet_params_storage::minimum_mixnode_pledge(deps.storage)?;
 
     // check that the denomination is correct
@@ -494,6 +500,116 @@ pub(crate) fn _try_update_mixnode_cost_params(
     Ok(Response::new().add_event(cosmos_event))
 }
 
+/// Proposes transferring the ownership of the sender's mixnode to `to_address`.
+/// The transfer only takes place once the new owner accepts it. Proposing another transfer
+/// replaces the previous proposal.
+pub(crate) fn try_propose_mixnode_ownership_transfer(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    to_address: String,
+) -> Result<Response, MixnetContractError> {
+    let owner = info.sender;
+    let new_owner = deps.api.addr_validate(&to_address)?;
+    let existing_bond = must_get_mixnode_bond_by_owner(deps.storage, &owner)?;
+
+    // tokens pledged from a vesting account have to remain with that account
+    if existing_bond.proxy.is_some() {
+        return Err(MixnetContractError::VestingBondOwnershipTransfer);
+    }
+    ensure_bonded(&existing_bond)?;
+    ensure_no_existing_bond(&new_owner, deps.storage)?;
+
+    storage::PENDING_OWNERSHIP_TRANSFERS.save(deps.storage, existing_bond.mix_id, &new_owner)?;
+
+    Ok(
+        Response::new().add_event(new_mixnode_ownership_transfer_proposal_event(
+            existing_bond.mix_id,
+            &owner,
+            &new_owner,
+        )),
+    )
+}
+
+pub(crate) fn try_cancel_mixnode_ownership_transfer(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+) -> Result<Response, MixnetContractError> {
+    let owner = info.sender;
+    let existing_bond = must_get_mixnode_bond_by_owner(deps.storage, &owner)?;
+
+    let new_owner = storage::PENDING_OWNERSHIP_TRANSFERS
+        .may_load(deps.storage, existing_bond.mix_id)?
+        .ok_or(MixnetContractError::NoPendingOwnershipTransfer)?;
+    storage::PENDING_OWNERSHIP_TRANSFERS.remove(deps.storage, existing_bond.mix_id);
+
+    Ok(
+        Response::new().add_event(new_mixnode_ownership_transfer_cancellation_event(
+            existing_bond.mix_id,
+            &owner,
+            &new_owner,
+        )),
+    )
+}
+
+/// Finalises the ownership transfer of the specified mixnode previously proposed to the sender.
+///
+/// The pledge (alongside any unclaimed operator rewards) and all delegations stay with the node.
+/// Family membership is tied to the node's identity rather than to its owner, so the node
+/// also remains in (or at the head of) its current family.
+pub(crate) fn try_accept_mixnode_ownership(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    mix_id: MixId,
+) -> Result<Response, MixnetContractError> {
+    let new_owner = info.sender;
+    let proposed_owner = storage::PENDING_OWNERSHIP_TRANSFERS.may_load(deps.storage, mix_id)?;
+    if proposed_owner.as_ref() != Some(&new_owner) {
+        return Err(MixnetContractError::NoPendingOwnershipTransfer);
+    }
+
+    let existing_bond = storage::mixnode_bonds()
+        .may_load(deps.storage, mix_id)?
+        .ok_or(MixnetContractError::MixNodeBondNotFound { mix_id })?;
+    let pending_changes = storage::PENDING_MIXNODE_CHANGES
+        .may_load(deps.storage, mix_id)?
+        .unwrap_or_default();
+
+    // changing the owner is only allowed if the epoch is currently not in the process of being advanced
+    ensure_epoch_in_progress_state(deps.storage)?;
+    ensure_bonded(&existing_bond)?;
+
+    // any pending pledge change has been requested by (and would be settled with) the current owner
+    ensure_no_pending_pledge_changes(&pending_changes)?;
+
+    // the new owner might have bonded something in the meantime
+    ensure_no_existing_bond(&new_owner, deps.storage)?;
+
+    let previous_owner = existing_bond.owner.clone();
+
+    // clippy beta 1.70.0-beta.1 false positive
+    #[allow(clippy::redundant_clone)]
+    let mut updated_bond = existing_bond.clone();
+    updated_bond.owner = new_owner.clone();
+    storage::mixnode_bonds().replace(
+        deps.storage,
+        mix_id,
+        Some(&updated_bond),
+        Some(&existing_bond),
+    )?;
+    storage::PENDING_OWNERSHIP_TRANSFERS.remove(deps.storage, mix_id);
+
+    // the new owner has to explicitly opt into compounding their rewards
+    rewards_storage::COMPOUNDING_OPERATORS.remove(deps.storage, mix_id);
+
+    Ok(
+        Response::new().add_event(new_mixnode_ownership_transfer_event(
+            mix_id,
+            &previous_owner,
+            &new_owner,
+        )),
+    )
+}
+
 #[cfg(test)]
 pub mod tests {
     use cosmwasm_std::testing::mock_info;
@@ -1853,4 +1969,229 @@ pub mod tests {
             )
         }
     }
+
+    mod transferring_mixnode_ownership {
+        use cosmwasm_std::Empty;
+        use mixnet_contract_common::{EpochState, EpochStatus};
+
+        use crate::interval::pending_events;
+        use crate::support::tests::test_helpers::TestSetup;
+
+        use super::*;
+
+        fn pending_transfer(test: &TestSetup, mix_id: MixId) -> Option<Addr> {
+            storage::PENDING_OWNERSHIP_TRANSFERS
+                .may_load(test.deps().storage, mix_id)
+                .unwrap()
+        }
+
+        #[test]
+        fn is_not_allowed_for_bonds_made_with_vesting_tokens() {
+            let mut test = TestSetup::new();
+            let owner = "mix-owner";
+            test.add_dummy_mixnode_with_legal_proxy(owner, None);
+
+            let res = try_propose_mixnode_ownership_transfer(
+                test.deps_mut(),
+                mock_info(owner, &[]),
+                "new-owner".to_string(),
+            );
+            assert_eq!(res, Err(MixnetContractError::VestingBondOwnershipTransfer));
+        }
+
+        #[test]
+        fn is_not_allowed_if_new_owner_already_has_a_bond() {
+            let mut test = TestSetup::new();
+            let owner = "mix-owner";
+            test.add_dummy_mixnode(owner, None);
+            test.add_dummy_mixnode("other-mix-owner", None);
+            test.add_dummy_gateway("gateway-owner", None);
+
+            let res = try_propose_mixnode_ownership_transfer(
+                test.deps_mut(),
+                mock_info(owner, &[]),
+                "other-mix-owner".to_string(),
+            );
+            assert_eq!(res, Err(MixnetContractError::AlreadyOwnsMixnode));
+
+            let res = try_propose_mixnode_ownership_transfer(
+                test.deps_mut(),
+                mock_info(owner, &[]),
+                "gateway-owner".to_string(),
+            );
+            assert_eq!(res, Err(MixnetContractError::AlreadyOwnsGateway));
+        }
+
+        #[test]
+        fn can_only_be_accepted_by_the_proposed_owner() {
+            let mut test = TestSetup::new();
+            let owner = "mix-owner";
+            let mix_id = test.add_dummy_mixnode(owner, None);
+
+            // nothing has been proposed yet
+            let res =
+                try_accept_mixnode_ownership(test.deps_mut(), mock_info("new-owner", &[]), mix_id);
+            assert_eq!(res, Err(MixnetContractError::NoPendingOwnershipTransfer));
+
+            try_propose_mixnode_ownership_transfer(
+                test.deps_mut(),
+                mock_info(owner, &[]),
+                "new-owner".to_string(),
+            )
+            .unwrap();
+
+            let res =
+                try_accept_mixnode_ownership(test.deps_mut(), mock_info("random-guy", &[]), mix_id);
+            assert_eq!(res, Err(MixnetContractError::NoPendingOwnershipTransfer));
+            assert_eq!(test.mix_bond(mix_id).owner, Addr::unchecked(owner));
+        }
+
+        #[test]
+        fn cant_be_accepted_if_epoch_transition_is_in_progress() {
+            let mut test = TestSetup::new();
+            let owner = "mix-owner";
+            let mix_id = test.add_dummy_mixnode(owner, None);
+
+            try_propose_mixnode_ownership_transfer(
+                test.deps_mut(),
+                mock_info(owner, &[]),
+                "new-owner".to_string(),
+            )
+            .unwrap();
+
+            let mut status = EpochStatus::new(test.rewarding_validator().sender);
+            status.state = EpochState::ReconcilingEvents;
+            interval_storage::save_current_epoch_status(test.deps_mut().storage, &status).unwrap();
+
+            let res =
+                try_accept_mixnode_ownership(test.deps_mut(), mock_info("new-owner", &[]), mix_id);
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::EpochAdvancementInProgress { .. })
+            ));
+        }
+
+        #[test]
+        fn cant_be_accepted_if_there_are_pending_pledge_changes() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "mix-owner";
+            let mix_id = test.add_dummy_mixnode(owner, None);
+
+            try_propose_mixnode_ownership_transfer(
+                test.deps_mut(),
+                mock_info(owner, &[]),
+                "new-owner".to_string(),
+            )
+            .unwrap();
+            try_increase_pledge(test.deps_mut(), env, mock_info(owner, &[test.coin(1000)]))
+                .unwrap();
+
+            let res =
+                try_accept_mixnode_ownership(test.deps_mut(), mock_info("new-owner", &[]), mix_id);
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::PendingPledgeChange { .. })
+            ));
+        }
+
+        #[test]
+        fn can_be_cancelled_by_the_current_owner() {
+            let mut test = TestSetup::new();
+            let owner = "mix-owner";
+            let mix_id = test.add_dummy_mixnode(owner, None);
+
+            let res = try_cancel_mixnode_ownership_transfer(test.deps_mut(), mock_info(owner, &[]));
+            assert_eq!(res, Err(MixnetContractError::NoPendingOwnershipTransfer));
+
+            try_propose_mixnode_ownership_transfer(
+                test.deps_mut(),
+                mock_info(owner, &[]),
+                "new-owner".to_string(),
+            )
+            .unwrap();
+            assert_eq!(
+                pending_transfer(&test, mix_id),
+                Some(Addr::unchecked("new-owner"))
+            );
+
+            try_cancel_mixnode_ownership_transfer(test.deps_mut(), mock_info(owner, &[])).unwrap();
+            assert!(pending_transfer(&test, mix_id).is_none());
+
+            let res =
+                try_accept_mixnode_ownership(test.deps_mut(), mock_info("new-owner", &[]), mix_id);
+            assert_eq!(res, Err(MixnetContractError::NoPendingOwnershipTransfer));
+        }
+
+        #[test]
+        fn pending_proposal_is_removed_once_mixnode_unbonds() {
+            let mut test = TestSetup::new();
+            let env = test.env();
+            let owner = "mix-owner";
+            let mix_id = test.add_dummy_mixnode(owner, None);
+
+            try_propose_mixnode_ownership_transfer(
+                test.deps_mut(),
+                mock_info(owner, &[]),
+                "new-owner".to_string(),
+            )
+            .unwrap();
+            pending_events::unbond_mixnode(test.deps_mut(), &env, 123, mix_id).unwrap();
+            assert!(pending_transfer(&test, mix_id).is_none());
+        }
+
+        #[test]
+        fn moves_the_bond_to_the_new_owner() {
+            let mut test = TestSetup::new();
+            let owner = "mix-owner";
+            let new_owner = "new-owner";
+            let mix_id = test.add_dummy_mixnode(owner, None);
+            test.add_immediate_delegation("delegator", 100_000_000u128, mix_id);
+            rewards_storage::COMPOUNDING_OPERATORS
+                .save(test.deps_mut().storage, mix_id, &Empty {})
+                .unwrap();
+
+            let before = test.mix_bond(mix_id);
+
+            try_propose_mixnode_ownership_transfer(
+                test.deps_mut(),
+                mock_info(owner, &[]),
+                new_owner.to_string(),
+            )
+            .unwrap();
+            try_accept_mixnode_ownership(test.deps_mut(), mock_info(new_owner, &[]), mix_id)
+                .unwrap();
+
+            let after = test.mix_bond(mix_id);
+            assert_eq!(after.owner, Addr::unchecked(new_owner));
+            assert_eq!(after.original_pledge, before.original_pledge);
+            assert_eq!(after.mix_node, before.mix_node);
+
+            // the owner index got updated
+            assert_eq!(
+                must_get_mixnode_bond_by_owner(test.deps().storage, &Addr::unchecked(new_owner))
+                    .unwrap()
+                    .mix_id,
+                mix_id
+            );
+            assert_eq!(
+                must_get_mixnode_bond_by_owner(test.deps().storage, &Addr::unchecked(owner)),
+                Err(MixnetContractError::NoAssociatedMixNodeBond {
+                    owner: Addr::unchecked(owner)
+                })
+            );
+
+            // delegations are unaffected
+            assert_eq!(
+                test.delegation(mix_id, "delegator", &None).amount.amount,
+                Uint128::new(100_000_000)
+            );
+
+            assert!(pending_transfer(&test, mix_id).is_none());
+            assert!(!rewards_storage::COMPOUNDING_OPERATORS.has(test.deps().storage, mix_id));
+
+            // and the previous owner is free to bond a new node
+            test.add_dummy_mixnode(owner, None);
+        }
+    }
 }
@@ -4,6 +4,7 @@
 use super::storage;
 use crate::delegations::storage as delegations_storage;
 use crate::interval::storage as interval_storage;
+use crate::mixnodes::storage as mixnodes_storage;
 use cosmwasm_std::{Coin, Storage};
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::helpers::IntoBaseDecimal;
@@ -86,6 +87,46 @@ pub(crate) fn withdraw_delegator_reward(
     Ok(reward)
 }
 
+pub(crate) fn compound_operator_reward(
+    store: &mut dyn Storage,
+    mix_details: MixNodeDetails,
+) -> Result<Coin, MixnetContractError> {
+    let mix_id = mix_details.mix_id();
+    let mut mix_rewarding = mix_details.rewarding_details;
+    let mut bond = mix_details.bond_information;
+    let reward = mix_rewarding.compound_operator_reward(&bond.original_pledge)?;
+
+    if !reward.amount.is_zero() {
+        let old_bond = bond.clone();
+        bond.original_pledge.amount += reward.amount;
+        mixnodes_storage::mixnode_bonds().replace(store, mix_id, Some(&bond), Some(&old_bond))?;
+    }
+
+    // save updated rewarding info
+    storage::MIXNODE_REWARDING.save(store, mix_id, &mix_rewarding)?;
+    Ok(reward)
+}
+
+pub(crate) fn compound_delegator_reward(
+    store: &mut dyn Storage,
+    delegation: Delegation,
+    mut mix_rewarding: MixNodeRewarding,
+) -> Result<Coin, MixnetContractError> {
+    let mix_id = delegation.mix_id;
+    let mut updated_delegation = delegation.clone();
+    let reward = mix_rewarding.compound_delegator_reward(&mut updated_delegation)?;
+
+    // save updated delegation and mix rewarding info
+    delegations_storage::delegations().replace(
+        store,
+        delegation.storage_key(),
+        Some(&updated_delegation),
+        Some(&delegation),
+    )?;
+    storage::MIXNODE_REWARDING.save(store, mix_id, &mix_rewarding)?;
+    Ok(reward)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
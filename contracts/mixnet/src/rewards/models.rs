@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use cosmwasm_std::Decimal;
+use mixnet_contract_common::delegation::StorageKey;
+use mixnet_contract_common::{EpochId, MixId};
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, Eq, PartialEq)]
@@ -10,9 +12,62 @@ pub(crate) struct RewardPoolChange {
     /// upon the current interval finishing.
     pub removed: Decimal,
 
-    // this will be used once coconut credentials are in use;
     /// Indicates amount that shall get added to the reward pool and not touch the staking supply
-    /// upon the current interval finishing.
-    #[allow(unused)]
+    /// upon the current interval finishing, for example tokens slashed from misbehaving operators.
     pub added: Decimal,
 }
+
+/// Total amount of rewards distributed to mixnodes and gateways in a particular epoch.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) struct EpochDistributedRewards {
+    /// Absolute id of the epoch in which the rewards got distributed.
+    pub epoch_id: EpochId,
+
+    pub amount: Decimal,
+
+    /// Maximum reward a single gateway can receive in this epoch, i.e. the part of the budget
+    /// left after rewarding the mixnodes split evenly between all bonded gateways.
+    /// It gets determined upon rewarding the first gateway in the epoch.
+    #[serde(default)]
+    pub gateway_share: Option<Decimal>,
+}
+
+impl EpochDistributedRewards {
+    pub fn new(epoch_id: EpochId) -> Self {
+        EpochDistributedRewards {
+            epoch_id,
+            amount: Decimal::zero(),
+            gateway_share: None,
+        }
+    }
+}
+
+/// Keeps track of how far the reward compounding got in the particular epoch,
+/// so that it could be split across multiple `ReconcileEpochEvents` transactions.
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub(crate) struct CompoundingProgress {
+    /// Absolute id of the epoch for which the rewards are being compounded.
+    pub epoch_id: EpochId,
+
+    pub stage: CompoundingStage,
+}
+
+impl CompoundingProgress {
+    pub(crate) fn new(epoch_id: EpochId) -> Self {
+        CompoundingProgress {
+            epoch_id,
+            stage: CompoundingStage::Operators { start_after: None },
+        }
+    }
+
+    pub(crate) fn is_finished(&self) -> bool {
+        self.stage == CompoundingStage::Finished
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+pub(crate) enum CompoundingStage {
+    Operators { start_after: Option<MixId> },
+    Delegations { start_after: Option<StorageKey> },
+    Finished,
+}
@@ -3,6 +3,7 @@
 
 use super::storage;
 use crate::delegations::storage as delegations_storage;
+use crate::gateways::storage as gateways_storage;
 use crate::interval::storage as interval_storage;
 use crate::mixnodes;
 use crate::mixnodes::storage as mixnodes_storage;
@@ -12,9 +13,10 @@ use mixnet_contract_common::mixnode::MixNodeDetails;
 use mixnet_contract_common::reward_params::{NodeRewardParams, Performance, RewardingParams};
 use mixnet_contract_common::rewarding::helpers::truncate_reward;
 use mixnet_contract_common::rewarding::{
-    EstimatedCurrentEpochRewardResponse, PendingRewardResponse,
+    EstimatedCurrentEpochRewardResponse, PendingGatewayRewardResponse, PendingRewardResponse,
+    RewardCompoundingResponse,
 };
-use mixnet_contract_common::{Delegation, MixId};
+use mixnet_contract_common::{Delegation, GatewayBond, IdentityKey, MixId};
 
 pub(crate) fn query_rewarding_params(deps: Deps<'_>) -> StdResult<RewardingParams> {
     storage::REWARDING_PARAMS.load(deps.storage)
@@ -55,6 +57,49 @@ pub fn query_pending_mixnode_operator_reward(
     pending_operator_reward(mix_details)
 }
 
+fn pending_gateway_reward(
+    deps: Deps<'_>,
+    gateway_bond: Option<GatewayBond>,
+) -> StdResult<PendingGatewayRewardResponse> {
+    let gateway_bond = match gateway_bond {
+        Some(gateway_bond) => gateway_bond,
+        None => return Ok(PendingGatewayRewardResponse::default()),
+    };
+
+    let detailed_reward = storage::GATEWAY_REWARDING
+        .may_load(deps.storage, gateway_bond.identity())?
+        .unwrap_or_default()
+        .unclaimed_reward;
+    let reward = truncate_reward(detailed_reward, &gateway_bond.pledge_amount.denom);
+
+    Ok(PendingGatewayRewardResponse {
+        amount_staked: Some(gateway_bond.pledge_amount),
+        amount_earned: Some(reward),
+        amount_earned_detailed: Some(detailed_reward),
+    })
+}
+
+pub fn query_pending_gateway_operator_reward(
+    deps: Deps<'_>,
+    owner: String,
+) -> StdResult<PendingGatewayRewardResponse> {
+    let owner_address = deps.api.addr_validate(&owner)?;
+    let gateway_bond = gateways_storage::gateways()
+        .idx
+        .owner
+        .item(deps.storage, owner_address)?
+        .map(|record| record.1);
+    pending_gateway_reward(deps, gateway_bond)
+}
+
+pub fn query_pending_gateway_reward(
+    deps: Deps<'_>,
+    identity: IdentityKey,
+) -> StdResult<PendingGatewayRewardResponse> {
+    let gateway_bond = gateways_storage::gateways().may_load(deps.storage, &identity)?;
+    pending_gateway_reward(deps, gateway_bond)
+}
+
 pub fn query_pending_delegator_reward(
     deps: Deps,
     owner: String,
@@ -91,6 +136,32 @@ pub fn query_pending_delegator_reward(
     })
 }
 
+pub(crate) fn query_operator_reward_compounding(
+    deps: Deps<'_>,
+    mix_id: MixId,
+) -> StdResult<RewardCompoundingResponse> {
+    Ok(RewardCompoundingResponse {
+        enabled: storage::COMPOUNDING_OPERATORS.has(deps.storage, mix_id),
+    })
+}
+
+pub(crate) fn query_delegator_reward_compounding(
+    deps: Deps<'_>,
+    owner: String,
+    mix_id: MixId,
+    proxy: Option<String>,
+) -> StdResult<RewardCompoundingResponse> {
+    let owner_address = deps.api.addr_validate(&owner)?;
+    let proxy = proxy
+        .map(|proxy| deps.api.addr_validate(&proxy))
+        .transpose()?;
+
+    let storage_key = Delegation::generate_storage_key(mix_id, &owner_address, proxy.as_ref());
+    Ok(RewardCompoundingResponse {
+        enabled: storage::COMPOUNDING_DELEGATIONS.has(deps.storage, storage_key),
+    })
+}
+
 fn zero_reward(
     original_stake: Coin,
     current_value: Decimal,
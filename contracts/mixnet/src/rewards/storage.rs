@@ -2,15 +2,20 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::constants::{
+    COMPOUNDING_DELEGATIONS_NAMESPACE, COMPOUNDING_OPERATORS_NAMESPACE, COMPOUNDING_PROGRESS_KEY,
+    EPOCH_DISTRIBUTED_REWARDS_KEY, GATEWAYS_REWARDING_PK_NAMESPACE,
     MIXNODES_REWARDING_PK_NAMESPACE, PENDING_REWARD_POOL_KEY, REWARDING_PARAMS_KEY,
 };
-use crate::rewards::models::RewardPoolChange;
-use cosmwasm_std::{Decimal, StdResult, Storage};
+use crate::gateways::storage as gateways_storage;
+use crate::rewards::models::{CompoundingProgress, EpochDistributedRewards, RewardPoolChange};
+use cosmwasm_std::{Decimal, Empty, Order, StdResult, Storage};
 use cw_storage_plus::{Item, Map};
+use mixnet_contract_common::delegation::StorageKey;
 use mixnet_contract_common::error::MixnetContractError;
+use mixnet_contract_common::gateway::GatewayRewarding;
 use mixnet_contract_common::mixnode::MixNodeRewarding;
 use mixnet_contract_common::reward_params::RewardingParams;
-use mixnet_contract_common::MixId;
+use mixnet_contract_common::{EpochId, IdentityKeyRef, MixId};
 
 // current parameters used for rewarding purposes
 pub(crate) const REWARDING_PARAMS: Item<'_, RewardingParams> = Item::new(REWARDING_PARAMS_KEY);
@@ -20,6 +25,38 @@ pub(crate) const PENDING_REWARD_POOL_CHANGE: Item<'_, RewardPoolChange> =
 pub const MIXNODE_REWARDING: Map<MixId, MixNodeRewarding> =
     Map::new(MIXNODES_REWARDING_PK_NAMESPACE);
 
+// rewards earned by gateways that haven't yet been withdrawn by their operators
+pub(crate) const GATEWAY_REWARDING: Map<IdentityKeyRef, GatewayRewarding> =
+    Map::new(GATEWAYS_REWARDING_PK_NAMESPACE);
+
+// rewards distributed in the most recently rewarded epoch, so that the gateway rewards
+// could be deducted from the same budget as the mixnode rewards
+pub(crate) const EPOCH_DISTRIBUTED_REWARDS: Item<'_, EpochDistributedRewards> =
+    Item::new(EPOCH_DISTRIBUTED_REWARDS_KEY);
+
+// mixnodes whose operators opted into having their rewards automatically added to their pledge
+pub(crate) const COMPOUNDING_OPERATORS: Map<MixId, Empty> =
+    Map::new(COMPOUNDING_OPERATORS_NAMESPACE);
+
+// delegations whose owners opted into having their rewards automatically added to the delegation
+pub(crate) const COMPOUNDING_DELEGATIONS: Map<StorageKey, Empty> =
+    Map::new(COMPOUNDING_DELEGATIONS_NAMESPACE);
+
+pub(crate) const COMPOUNDING_PROGRESS: Item<'_, CompoundingProgress> =
+    Item::new(COMPOUNDING_PROGRESS_KEY);
+
+/// Loads the compounding progress for the specified epoch or starts a fresh one
+/// if the rewards haven't been compounded in it yet.
+pub(crate) fn compounding_progress(
+    storage: &dyn Storage,
+    epoch_id: EpochId,
+) -> StdResult<CompoundingProgress> {
+    match COMPOUNDING_PROGRESS.may_load(storage)? {
+        Some(progress) if progress.epoch_id == epoch_id => Ok(progress),
+        _ => Ok(CompoundingProgress::new(epoch_id)),
+    }
+}
+
 pub fn reward_accounting(
     storage: &mut dyn Storage,
     amount: Decimal,
@@ -30,6 +67,88 @@ pub fn reward_accounting(
     Ok(PENDING_REWARD_POOL_CHANGE.save(storage, &pending_changes)?)
 }
 
+fn epoch_distributed_rewards(
+    storage: &dyn Storage,
+    epoch_id: EpochId,
+) -> StdResult<EpochDistributedRewards> {
+    match EPOCH_DISTRIBUTED_REWARDS.may_load(storage)? {
+        Some(distributed) if distributed.epoch_id == epoch_id => Ok(distributed),
+        _ => Ok(EpochDistributedRewards::new(epoch_id)),
+    }
+}
+
+/// Returns the total amount of rewards distributed in the specified epoch so far.
+pub(crate) fn distributed_epoch_rewards(
+    storage: &dyn Storage,
+    epoch_id: EpochId,
+) -> StdResult<Decimal> {
+    Ok(epoch_distributed_rewards(storage, epoch_id)?.amount)
+}
+
+/// Adds the specified amount to the total rewards distributed in the particular epoch.
+pub(crate) fn epoch_reward_accounting(
+    storage: &mut dyn Storage,
+    epoch_id: EpochId,
+    amount: Decimal,
+) -> StdResult<()> {
+    let mut distributed = epoch_distributed_rewards(storage, epoch_id)?;
+    distributed.amount += amount;
+    EPOCH_DISTRIBUTED_REWARDS.save(storage, &distributed)
+}
+
+/// Returns the maximum reward a single gateway can receive in the specified epoch.
+/// Upon the first call in the epoch, whatever is left of the epoch budget after rewarding
+/// the mixnodes gets split evenly between all currently bonded gateways, so that the order
+/// in which they're rewarded wouldn't matter.
+pub(crate) fn gateway_epoch_share(
+    storage: &mut dyn Storage,
+    epoch_id: EpochId,
+    epoch_budget: Decimal,
+) -> StdResult<Decimal> {
+    let mut distributed = epoch_distributed_rewards(storage, epoch_id)?;
+    if let Some(share) = distributed.gateway_share {
+        return Ok(share);
+    }
+
+    let remaining = if distributed.amount >= epoch_budget {
+        Decimal::zero()
+    } else {
+        epoch_budget - distributed.amount
+    };
+    let bonded_gateways = gateways_storage::gateways()
+        .keys(storage, None, None, Order::Ascending)
+        .count()
+        .max(1);
+    let share = remaining * Decimal::from_ratio(1u64, bonded_gateways as u64);
+
+    distributed.gateway_share = Some(share);
+    EPOCH_DISTRIBUTED_REWARDS.save(storage, &distributed)?;
+    Ok(share)
+}
+
+/// Marks the slashed tokens to be moved back into the reward pool upon the current interval finishing.
+pub fn slashing_accounting(
+    storage: &mut dyn Storage,
+    amount: Decimal,
+) -> Result<(), MixnetContractError> {
+    let mut pending_changes = PENDING_REWARD_POOL_CHANGE.load(storage)?;
+    pending_changes.added += amount;
+
+    Ok(PENDING_REWARD_POOL_CHANGE.save(storage, &pending_changes)?)
+}
+
+/// Marks the rewards that can no longer be claimed by anyone (such as the fractional leftovers
+/// of unbonded gateways) to be moved back into the reward pool upon the current interval finishing.
+pub(crate) fn unclaimable_reward_accounting(
+    storage: &mut dyn Storage,
+    amount: Decimal,
+) -> Result<(), MixnetContractError> {
+    let mut pending_changes = PENDING_REWARD_POOL_CHANGE.load(storage)?;
+    pending_changes.added += amount;
+
+    Ok(PENDING_REWARD_POOL_CHANGE.save(storage, &pending_changes)?)
+}
+
 pub(crate) fn initialise_storage(
     storage: &mut dyn Storage,
     reward_params: RewardingParams,
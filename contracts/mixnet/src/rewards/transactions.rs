@@ -1,28 +1,34 @@
 // Copyright 2021-2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use cosmwasm_std::{wasm_execute, Addr, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{wasm_execute, Addr, DepsMut, Empty, Env, MessageInfo, Response};
 
 use mixnet_contract_common::error::MixnetContractError;
 use mixnet_contract_common::events::{
-    new_active_set_update_event, new_mix_rewarding_event,
-    new_not_found_mix_operator_rewarding_event, new_pending_active_set_update_event,
+    new_active_set_update_event, new_delegator_reward_compounding_update_event,
+    new_gateway_rewarding_event, new_jailed_mix_operator_rewarding_event, new_mix_rewarding_event,
+    new_not_found_mix_operator_rewarding_event, new_not_rewarded_gateway_rewarding_event,
+    new_operator_reward_compounding_update_event, new_pending_active_set_update_event,
     new_pending_rewarding_params_update_event, new_rewarding_params_update_event,
-    new_withdraw_delegator_reward_event, new_withdraw_operator_reward_event,
-    new_zero_uptime_mix_operator_rewarding_event,
+    new_withdraw_delegator_reward_event, new_withdraw_gateway_operator_reward_event,
+    new_withdraw_operator_reward_event, new_zero_uptime_mix_operator_rewarding_event,
+    ALREADY_REWARDED_VALUE, BOND_NOT_FOUND_VALUE, BUDGET_EXHAUSTED_VALUE, ZERO_PERFORMANCE_VALUE,
 };
 use mixnet_contract_common::pending_events::{PendingEpochEventKind, PendingIntervalEventKind};
 use mixnet_contract_common::reward_params::{
     IntervalRewardingParamsUpdate, NodeRewardParams, Performance,
 };
-use mixnet_contract_common::{Delegation, EpochState, MixId};
+use mixnet_contract_common::{Delegation, EpochState, IdentityKey, MixId};
 use vesting_contract_common::messages::ExecuteMsg as VestingContractExecuteMsg;
 
 use crate::delegations::storage as delegations_storage;
+use crate::gateways::helpers::must_get_gateway_bond_by_owner;
+use crate::gateways::storage as gateways_storage;
 use crate::interval::storage as interval_storage;
 use crate::interval::storage::{push_new_epoch_event, push_new_interval_event};
+use crate::jailing::storage as jailing_storage;
 use crate::mixnet_contract_settings::storage as mixnet_params_storage;
-use crate::mixnodes::helpers::get_mixnode_details_by_owner;
+use crate::mixnodes::helpers::{get_mixnode_details_by_owner, must_get_mixnode_bond_by_owner};
 use crate::mixnodes::storage as mixnodes_storage;
 use crate::rewards::helpers;
 use crate::rewards::helpers::update_and_save_last_rewarded;
@@ -99,6 +105,16 @@ pub(crate) fn try_reward_mixnode(
             absolute_epoch_id,
         })?;
 
+    // jailed nodes don't get any rewards for the epoch during which they got jailed.
+    // similarly to the zero performance case, we still need to update last_rewarded_epoch field
+    if jailing_storage::jailed_in_epoch(deps.storage, mix_id, absolute_epoch_id)?.is_some() {
+        mix_rewarding.last_rewarded_epoch = absolute_epoch_id;
+        storage::MIXNODE_REWARDING.save(deps.storage, mix_id, &mix_rewarding)?;
+        return Ok(
+            Response::new().add_event(new_jailed_mix_operator_rewarding_event(interval, mix_id))
+        );
+    }
+
     // no need to calculate anything as rewards are going to be 0 for everything
     // however, we still need to update last_rewarded_epoch field
     if node_performance.is_zero() {
@@ -126,6 +142,7 @@ pub(crate) fn try_reward_mixnode(
     // persist changes happened to the storage
     storage::MIXNODE_REWARDING.save(deps.storage, mix_id, &mix_rewarding)?;
     storage::reward_accounting(deps.storage, node_reward)?;
+    storage::epoch_reward_accounting(deps.storage, absolute_epoch_id, node_reward)?;
 
     Ok(Response::new().add_event(new_mix_rewarding_event(
         interval,
@@ -136,6 +153,111 @@ pub(crate) fn try_reward_mixnode(
     )))
 }
 
+pub(crate) fn try_reward_gateway(
+    deps: DepsMut<'_>,
+    env: Env,
+    info: MessageInfo,
+    identity: IdentityKey,
+    performance: Performance,
+) -> Result<Response, MixnetContractError> {
+    // gateways are rewarded by the same validator that is responsible for advancing the epoch
+    let current_epoch_status = ensure_can_advance_epoch(&info.sender, deps.storage)?;
+
+    // see if the epoch has finished
+    let interval = interval_storage::current_interval(deps.storage)?;
+    if !interval.is_current_epoch_over(&env) {
+        return Err(MixnetContractError::EpochInProgress {
+            current_block_time: env.block.time.seconds(),
+            epoch_start: interval.current_epoch_start_unix_timestamp(),
+            epoch_end: interval.current_epoch_end_unix_timestamp(),
+        });
+    }
+
+    // and that the validator has actually begun the epoch transition
+    if current_epoch_status.is_in_progress() {
+        return Err(MixnetContractError::EpochTransitionNotStarted {
+            current_state: current_epoch_status.state,
+        });
+    }
+    let absolute_epoch_id = interval.current_epoch_absolute_id();
+
+    // the gateway might have unbonded before the rewards got distributed. in that case
+    // don't fail the transaction so that the rest of the rewarding could carry on
+    if gateways_storage::gateways()
+        .may_load(deps.storage, &identity)?
+        .is_none()
+    {
+        return Ok(
+            Response::new().add_event(new_not_rewarded_gateway_rewarding_event(
+                interval,
+                &identity,
+                BOND_NOT_FOUND_VALUE,
+            )),
+        );
+    }
+
+    let mut gateway_rewarding = storage::GATEWAY_REWARDING
+        .may_load(deps.storage, &identity)?
+        .unwrap_or_default();
+
+    // the rewarding validator might have retried the transaction after it has already been executed
+    if gateway_rewarding.last_rewarded_epoch == absolute_epoch_id {
+        return Ok(
+            Response::new().add_event(new_not_rewarded_gateway_rewarding_event(
+                interval,
+                &identity,
+                ALREADY_REWARDED_VALUE,
+            )),
+        );
+    }
+
+    // no need to calculate anything as the reward is going to be 0,
+    // however, we still need to update last_rewarded_epoch field
+    if performance.is_zero() {
+        gateway_rewarding.last_rewarded_epoch = absolute_epoch_id;
+        storage::GATEWAY_REWARDING.save(deps.storage, &identity, &gateway_rewarding)?;
+        return Ok(
+            Response::new().add_event(new_not_rewarded_gateway_rewarding_event(
+                interval,
+                &identity,
+                ZERO_PERFORMANCE_VALUE,
+            )),
+        );
+    }
+
+    // gateways are rewarded from whatever is left of the epoch budget after rewarding the mixnodes
+    // (which has already happened by now). it's split evenly between all of them,
+    // so that the rewards wouldn't depend on the order of rewarding
+    let rewarding_params = storage::REWARDING_PARAMS.load(deps.storage)?;
+    let gateway_share = storage::gateway_epoch_share(
+        deps.storage,
+        absolute_epoch_id,
+        rewarding_params.interval.epoch_reward_budget,
+    )?;
+    if gateway_share.is_zero() {
+        gateway_rewarding.last_rewarded_epoch = absolute_epoch_id;
+        storage::GATEWAY_REWARDING.save(deps.storage, &identity, &gateway_rewarding)?;
+        return Ok(
+            Response::new().add_event(new_not_rewarded_gateway_rewarding_event(
+                interval,
+                &identity,
+                BUDGET_EXHAUSTED_VALUE,
+            )),
+        );
+    }
+
+    let reward = rewarding_params
+        .gateway_reward(performance)
+        .min(gateway_share);
+    gateway_rewarding.add_reward(reward, absolute_epoch_id);
+
+    storage::GATEWAY_REWARDING.save(deps.storage, &identity, &gateway_rewarding)?;
+    storage::reward_accounting(deps.storage, reward)?;
+    storage::epoch_reward_accounting(deps.storage, absolute_epoch_id, reward)?;
+
+    Ok(Response::new().add_event(new_gateway_rewarding_event(interval, &identity, reward)))
+}
+
 pub(crate) fn try_withdraw_operator_reward(
     deps: DepsMut<'_>,
     info: MessageInfo,
@@ -201,6 +323,70 @@ pub(crate) fn _try_withdraw_operator_reward(
     )))
 }
 
+pub(crate) fn try_withdraw_gateway_operator_reward(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+) -> Result<Response, MixnetContractError> {
+    _try_withdraw_gateway_operator_reward(deps, info.sender, None)
+}
+
+pub(crate) fn try_withdraw_gateway_operator_reward_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    owner: String,
+) -> Result<Response, MixnetContractError> {
+    ensure_sent_by_vesting_contract(&info, deps.storage)?;
+
+    let proxy = info.sender;
+    let owner = deps.api.addr_validate(&owner)?;
+    _try_withdraw_gateway_operator_reward(deps, owner, Some(proxy))
+}
+
+pub(crate) fn _try_withdraw_gateway_operator_reward(
+    deps: DepsMut<'_>,
+    owner: Addr,
+    proxy: Option<Addr>,
+) -> Result<Response, MixnetContractError> {
+    let gateway_bond = must_get_gateway_bond_by_owner(deps.storage, &owner)?;
+    let identity = gateway_bond.identity();
+
+    ensure_proxy_match(&proxy, &gateway_bond.proxy)?;
+
+    let mut gateway_rewarding = storage::GATEWAY_REWARDING
+        .may_load(deps.storage, identity)?
+        .unwrap_or_default();
+    let reward = gateway_rewarding.withdraw_reward(&gateway_bond.pledge_amount.denom)?;
+    let mut response = Response::new();
+
+    // if the reward is zero, don't track or send anything - there's no point
+    if !reward.amount.is_zero() {
+        storage::GATEWAY_REWARDING.save(deps.storage, identity, &gateway_rewarding)?;
+
+        let return_tokens = send_to_proxy_or_owner(&proxy, &owner, vec![reward.clone()]);
+        response = response.add_message(return_tokens);
+
+        if let Some(proxy) = &proxy {
+            // we can only attempt to send the message to the vesting contract if the proxy IS the vesting contract
+            // otherwise, we don't care
+            let vesting_contract = mixnet_params_storage::vesting_contract_address(deps.storage)?;
+            if proxy == &vesting_contract {
+                let msg = VestingContractExecuteMsg::TrackReward {
+                    amount: reward.clone(),
+                    address: owner.clone().into_string(),
+                };
+                let track_reward_message = wasm_execute(proxy, &msg, vec![])?;
+                response = response.add_message(track_reward_message);
+            }
+        }
+    }
+
+    Ok(
+        response.add_event(new_withdraw_gateway_operator_reward_event(
+            &owner, &proxy, reward, identity,
+        )),
+    )
+}
+
 pub(crate) fn try_withdraw_delegator_reward(
     deps: DepsMut<'_>,
     info: MessageInfo,
@@ -287,6 +473,114 @@ pub(crate) fn _try_withdraw_delegator_reward(
     )))
 }
 
+pub(crate) fn try_set_operator_reward_compounding(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    enabled: bool,
+) -> Result<Response, MixnetContractError> {
+    _try_set_operator_reward_compounding(deps, info.sender, enabled, None)
+}
+
+pub(crate) fn try_set_operator_reward_compounding_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    enabled: bool,
+    owner: String,
+) -> Result<Response, MixnetContractError> {
+    ensure_sent_by_vesting_contract(&info, deps.storage)?;
+
+    let proxy = info.sender;
+    let owner = deps.api.addr_validate(&owner)?;
+    _try_set_operator_reward_compounding(deps, owner, enabled, Some(proxy))
+}
+
+pub(crate) fn _try_set_operator_reward_compounding(
+    deps: DepsMut<'_>,
+    owner: Addr,
+    enabled: bool,
+    proxy: Option<Addr>,
+) -> Result<Response, MixnetContractError> {
+    // changing the set while the rewards are being compounded could result in some entries being skipped
+    ensure_epoch_in_progress_state(deps.storage)?;
+
+    let existing_bond = must_get_mixnode_bond_by_owner(deps.storage, &owner)?;
+
+    ensure_proxy_match(&proxy, &existing_bond.proxy)?;
+    ensure_bonded(&existing_bond)?;
+
+    let mix_id = existing_bond.mix_id;
+    if enabled {
+        storage::COMPOUNDING_OPERATORS.save(deps.storage, mix_id, &Empty {})?;
+    } else {
+        storage::COMPOUNDING_OPERATORS.remove(deps.storage, mix_id);
+    }
+
+    Ok(
+        Response::new().add_event(new_operator_reward_compounding_update_event(
+            &owner, &proxy, mix_id, enabled,
+        )),
+    )
+}
+
+pub(crate) fn try_set_delegator_reward_compounding(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    mix_id: MixId,
+    enabled: bool,
+) -> Result<Response, MixnetContractError> {
+    _try_set_delegator_reward_compounding(deps, mix_id, info.sender, enabled, None)
+}
+
+pub(crate) fn try_set_delegator_reward_compounding_on_behalf(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    mix_id: MixId,
+    enabled: bool,
+    owner: String,
+) -> Result<Response, MixnetContractError> {
+    ensure_sent_by_vesting_contract(&info, deps.storage)?;
+
+    let proxy = info.sender;
+    let owner = deps.api.addr_validate(&owner)?;
+    _try_set_delegator_reward_compounding(deps, mix_id, owner, enabled, Some(proxy))
+}
+
+pub(crate) fn _try_set_delegator_reward_compounding(
+    deps: DepsMut<'_>,
+    mix_id: MixId,
+    owner: Addr,
+    enabled: bool,
+    proxy: Option<Addr>,
+) -> Result<Response, MixnetContractError> {
+    // changing the set while the rewards are being compounded could result in some entries being skipped
+    ensure_epoch_in_progress_state(deps.storage)?;
+
+    // see if the delegation even exists
+    let storage_key = Delegation::generate_storage_key(mix_id, &owner, proxy.as_ref());
+    if delegations_storage::delegations()
+        .may_load(deps.storage, storage_key.clone())?
+        .is_none()
+    {
+        return Err(MixnetContractError::NoMixnodeDelegationFound {
+            mix_id,
+            address: owner.into_string(),
+            proxy: proxy.map(Addr::into_string),
+        });
+    }
+
+    if enabled {
+        storage::COMPOUNDING_DELEGATIONS.save(deps.storage, storage_key, &Empty {})?;
+    } else {
+        storage::COMPOUNDING_DELEGATIONS.remove(deps.storage, storage_key);
+    }
+
+    Ok(
+        Response::new().add_event(new_delegator_reward_compounding_update_event(
+            &owner, &proxy, mix_id, enabled,
+        )),
+    )
+}
+
 pub(crate) fn try_update_active_set_size(
     deps: DepsMut<'_>,
     env: Env,
@@ -1948,6 +2242,641 @@ pub mod tests {
         }
     }
 
+    #[cfg(test)]
+    mod gateway_rewarding {
+        use cosmwasm_std::{BankMsg, CosmosMsg, Decimal, Uint128};
+
+        use crate::gateways::transactions::try_remove_gateway;
+        use crate::support::tests::test_helpers::TestSetup;
+
+        use super::*;
+
+        fn set_gateway_epoch_reward(test: &mut TestSetup, epoch_reward: u128) {
+            let mut params = test.rewarding_params();
+            params.gateway.epoch_reward = Decimal::from_atomics(epoch_reward, 0).unwrap();
+            storage::REWARDING_PARAMS
+                .save(test.deps_mut().storage, &params)
+                .unwrap();
+        }
+
+        fn unclaimed_reward(test: &TestSetup, identity: &str) -> Decimal {
+            storage::GATEWAY_REWARDING
+                .load(test.deps().storage, identity)
+                .unwrap()
+                .unclaimed_reward
+        }
+
+        #[test]
+        fn can_only_be_performed_by_the_rewarding_validator() {
+            let mut test = TestSetup::new();
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+
+            test.skip_to_next_epoch_end();
+            test.start_epoch_transition();
+
+            let env = test.env();
+            let res = try_reward_gateway(
+                test.deps_mut(),
+                env,
+                mock_info("random-guy", &[]),
+                identity,
+                test_helpers::performance(100.0),
+            );
+            assert_eq!(res, Err(MixnetContractError::Unauthorized));
+        }
+
+        #[test]
+        fn can_only_be_performed_once_the_epoch_transition_has_begun() {
+            let mut test = TestSetup::new();
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+            let performance = test_helpers::performance(100.0);
+
+            // epoch is still in progress
+            let env = test.env();
+            let sender = test.rewarding_validator();
+            let res = try_reward_gateway(
+                test.deps_mut(),
+                env,
+                sender.clone(),
+                identity.clone(),
+                performance,
+            );
+            assert!(matches!(
+                res,
+                Err(MixnetContractError::EpochInProgress { .. })
+            ));
+
+            // epoch is over, but the transition hasn't started
+            test.skip_to_next_epoch_end();
+            let env = test.env();
+            let res = try_reward_gateway(
+                test.deps_mut(),
+                env,
+                sender.clone(),
+                identity.clone(),
+                performance,
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::EpochTransitionNotStarted {
+                    current_state: EpochState::InProgress
+                })
+            );
+
+            test.start_epoch_transition();
+            let env = test.env();
+            let res = try_reward_gateway(test.deps_mut(), env, sender, identity, performance);
+            assert!(res.is_ok());
+        }
+
+        #[test]
+        fn rewards_are_scaled_by_performance() {
+            let mut test = TestSetup::new();
+            set_gateway_epoch_reward(&mut test, 1000);
+            let gateway1 = test.add_dummy_gateway("gateway-owner1", None);
+            let gateway2 = test.add_dummy_gateway("gateway-owner2", None);
+            let gateway3 = test.add_dummy_gateway("gateway-owner3", None);
+
+            test.skip_to_next_epoch_end();
+            test.start_epoch_transition();
+
+            let sender = test.rewarding_validator();
+            for (identity, performance) in [(&gateway1, 100.0), (&gateway2, 50.0), (&gateway3, 0.0)]
+            {
+                let env = test.env();
+                try_reward_gateway(
+                    test.deps_mut(),
+                    env,
+                    sender.clone(),
+                    identity.clone(),
+                    test_helpers::performance(performance),
+                )
+                .unwrap();
+            }
+
+            assert_eq!(
+                unclaimed_reward(&test, &gateway1),
+                Decimal::from_atomics(1000u32, 0).unwrap()
+            );
+            assert_eq!(
+                unclaimed_reward(&test, &gateway2),
+                Decimal::from_atomics(500u32, 0).unwrap()
+            );
+            assert_eq!(unclaimed_reward(&test, &gateway3), Decimal::zero());
+
+            let pending_pool_change = storage::PENDING_REWARD_POOL_CHANGE
+                .load(test.deps().storage)
+                .unwrap();
+            assert_eq!(
+                pending_pool_change.removed,
+                Decimal::from_atomics(1500u32, 0).unwrap()
+            );
+        }
+
+        #[test]
+        fn gateways_are_rewarded_at_most_once_per_epoch() {
+            let mut test = TestSetup::new();
+            set_gateway_epoch_reward(&mut test, 1000);
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+
+            test.skip_to_next_epoch_end();
+            test.start_epoch_transition();
+
+            let sender = test.rewarding_validator();
+            for _ in 0..3 {
+                let env = test.env();
+                try_reward_gateway(
+                    test.deps_mut(),
+                    env,
+                    sender.clone(),
+                    identity.clone(),
+                    test_helpers::performance(100.0),
+                )
+                .unwrap();
+            }
+
+            assert_eq!(
+                unclaimed_reward(&test, &identity),
+                Decimal::from_atomics(1000u32, 0).unwrap()
+            );
+        }
+
+        #[test]
+        fn unbonded_gateways_are_skipped() {
+            let mut test = TestSetup::new();
+            set_gateway_epoch_reward(&mut test, 1000);
+
+            test.skip_to_next_epoch_end();
+            test.start_epoch_transition();
+
+            let env = test.env();
+            let sender = test.rewarding_validator();
+            let res = try_reward_gateway(
+                test.deps_mut(),
+                env,
+                sender,
+                "non-existent-gateway".to_string(),
+                test_helpers::performance(100.0),
+            );
+            assert!(res.is_ok());
+            assert!(storage::GATEWAY_REWARDING
+                .may_load(test.deps().storage, "non-existent-gateway")
+                .unwrap()
+                .is_none());
+        }
+
+        #[test]
+        fn remaining_epoch_budget_is_split_evenly_between_gateways() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", None);
+            test.force_change_rewarded_set(vec![mix_id]);
+            let gateway1 = test.add_dummy_gateway("gateway-owner1", None);
+            let gateway2 = test.add_dummy_gateway("gateway-owner2", None);
+
+            // make sure a single gateway could take the whole budget
+            let mut params = test.rewarding_params();
+            let epoch_budget = params.interval.epoch_reward_budget;
+            params.gateway.epoch_reward = epoch_budget;
+            storage::REWARDING_PARAMS
+                .save(test.deps_mut().storage, &params)
+                .unwrap();
+
+            test.skip_to_next_epoch_end();
+            test.start_epoch_transition();
+            test.reward_with_distribution(mix_id, test_helpers::performance(100.0));
+
+            let absolute_epoch_id = test.current_interval().current_epoch_absolute_id();
+            let mix_reward =
+                storage::distributed_epoch_rewards(test.deps().storage, absolute_epoch_id).unwrap();
+            assert!(!mix_reward.is_zero());
+
+            // the order of rewarding doesn't matter
+            let sender = test.rewarding_validator();
+            for identity in [&gateway2, &gateway1] {
+                let env = test.env();
+                try_reward_gateway(
+                    test.deps_mut(),
+                    env,
+                    sender.clone(),
+                    identity.clone(),
+                    test_helpers::performance(100.0),
+                )
+                .unwrap();
+            }
+
+            let expected = (epoch_budget - mix_reward) * Decimal::from_ratio(1u32, 2u32);
+            assert_eq!(unclaimed_reward(&test, &gateway1), expected);
+            assert_eq!(unclaimed_reward(&test, &gateway2), expected);
+            assert!(
+                storage::distributed_epoch_rewards(test.deps().storage, absolute_epoch_id).unwrap()
+                    <= epoch_budget
+            );
+        }
+
+        #[test]
+        fn gateway_reward_is_not_increased_to_its_share() {
+            let mut test = TestSetup::new();
+            set_gateway_epoch_reward(&mut test, 1000);
+            let gateway1 = test.add_dummy_gateway("gateway-owner1", None);
+            let gateway2 = test.add_dummy_gateway("gateway-owner2", None);
+
+            test.skip_to_next_epoch_end();
+            test.start_epoch_transition();
+            let sender = test.rewarding_validator();
+            for identity in [&gateway1, &gateway2] {
+                let env = test.env();
+                try_reward_gateway(
+                    test.deps_mut(),
+                    env,
+                    sender.clone(),
+                    identity.clone(),
+                    test_helpers::performance(50.0),
+                )
+                .unwrap();
+            }
+
+            // the share only caps the reward, whatever is unused stays in the pool
+            assert_eq!(
+                unclaimed_reward(&test, &gateway1),
+                Decimal::from_atomics(500u32, 0).unwrap()
+            );
+            assert_eq!(
+                unclaimed_reward(&test, &gateway2),
+                Decimal::from_atomics(500u32, 0).unwrap()
+            );
+        }
+
+        #[test]
+        fn epoch_budget_is_reset_in_the_next_epoch() {
+            let mut test = TestSetup::new();
+            let identity = test.add_dummy_gateway("gateway-owner", None);
+
+            let mut params = test.rewarding_params();
+            let epoch_budget = params.interval.epoch_reward_budget;
+            params.gateway.epoch_reward = epoch_budget + epoch_budget;
+            storage::REWARDING_PARAMS
+                .save(test.deps_mut().storage, &params)
+                .unwrap();
+
+            for _ in 0..2 {
+                test.skip_to_next_epoch_end();
+                test.start_epoch_transition();
+                let env = test.env();
+                let sender = test.rewarding_validator();
+                try_reward_gateway(
+                    test.deps_mut(),
+                    env,
+                    sender,
+                    identity.clone(),
+                    test_helpers::performance(100.0),
+                )
+                .unwrap();
+            }
+
+            assert_eq!(
+                unclaimed_reward(&test, &identity),
+                epoch_budget + epoch_budget
+            );
+        }
+
+        #[test]
+        fn withdrawing_sends_the_whole_part_of_the_reward() {
+            let mut test = TestSetup::new();
+            set_gateway_epoch_reward(&mut test, 1001);
+            let owner = "gateway-owner";
+            let identity = test.add_dummy_gateway(owner, None);
+
+            // nothing has been earned yet
+            let res = try_withdraw_gateway_operator_reward(test.deps_mut(), mock_info(owner, &[]))
+                .unwrap();
+            assert!(res.messages.is_empty());
+
+            test.skip_to_next_epoch_end();
+            test.start_epoch_transition();
+            let env = test.env();
+            let sender = test.rewarding_validator();
+            try_reward_gateway(
+                test.deps_mut(),
+                env,
+                sender,
+                identity.clone(),
+                test_helpers::performance(50.0),
+            )
+            .unwrap();
+
+            let res = try_withdraw_gateway_operator_reward(test.deps_mut(), mock_info(owner, &[]))
+                .unwrap();
+            assert!(matches!(
+                &res.messages[0].msg,
+                CosmosMsg::Bank(BankMsg::Send { to_address, amount }) if to_address == owner && amount[0].amount == Uint128::new(500)
+            ));
+
+            // the fractional part is kept for later
+            assert_eq!(
+                unclaimed_reward(&test, &identity),
+                Decimal::from_atomics(5u32, 1).unwrap()
+            );
+        }
+
+        #[test]
+        fn withdrawing_requires_a_bonded_gateway() {
+            let mut test = TestSetup::new();
+            let sender = mock_info("random-guy", &[]);
+
+            let res = try_withdraw_gateway_operator_reward(test.deps_mut(), sender.clone());
+            assert_eq!(
+                res,
+                Err(MixnetContractError::NoAssociatedGatewayBond {
+                    owner: sender.sender
+                })
+            )
+        }
+
+        #[test]
+        fn unclaimed_rewards_are_returned_upon_unbonding() {
+            let mut test = TestSetup::new();
+            set_gateway_epoch_reward(&mut test, 1000);
+            let owner = "gateway-owner";
+            let pledge = Uint128::new(100_000_000);
+            let identity = test.add_dummy_gateway(owner, Some(pledge));
+
+            test.skip_to_next_epoch_end();
+            test.start_epoch_transition();
+            let env = test.env();
+            let sender = test.rewarding_validator();
+            try_reward_gateway(
+                test.deps_mut(),
+                env,
+                sender,
+                identity.clone(),
+                test_helpers::performance(100.0),
+            )
+            .unwrap();
+
+            let res = try_remove_gateway(test.deps_mut(), mock_info(owner, &[])).unwrap();
+            let (receiver, sent_amount) = test_helpers::get_bank_send_msg(&res).unwrap();
+            assert_eq!(receiver, owner);
+            assert_eq!(sent_amount[0].amount, pledge + Uint128::new(1000));
+
+            assert!(storage::GATEWAY_REWARDING
+                .may_load(test.deps().storage, &identity)
+                .unwrap()
+                .is_none());
+        }
+
+        #[test]
+        fn fractional_reward_leftover_is_returned_to_the_pool_upon_unbonding() {
+            let mut test = TestSetup::new();
+            set_gateway_epoch_reward(&mut test, 1000);
+            let owner = "gateway-owner";
+            let pledge = Uint128::new(100_000_000);
+            let identity = test.add_dummy_gateway(owner, Some(pledge));
+
+            test.skip_to_next_epoch_end();
+            test.start_epoch_transition();
+            let env = test.env();
+            let sender = test.rewarding_validator();
+            try_reward_gateway(
+                test.deps_mut(),
+                env,
+                sender,
+                identity.clone(),
+                test_helpers::performance(99.99),
+            )
+            .unwrap();
+
+            let unclaimed = unclaimed_reward(&test, &identity);
+            let leftover = unclaimed - Decimal::from_atomics(999u32, 0).unwrap();
+            assert!(!leftover.is_zero());
+
+            let pool_change_before = storage::PENDING_REWARD_POOL_CHANGE
+                .load(test.deps().storage)
+                .unwrap();
+
+            let res = try_remove_gateway(test.deps_mut(), mock_info(owner, &[])).unwrap();
+            let (_, sent_amount) = test_helpers::get_bank_send_msg(&res).unwrap();
+            assert_eq!(sent_amount[0].amount, pledge + Uint128::new(999));
+
+            let pool_change_after = storage::PENDING_REWARD_POOL_CHANGE
+                .load(test.deps().storage)
+                .unwrap();
+            assert_eq!(pool_change_after.removed, pool_change_before.removed);
+            assert_eq!(pool_change_after.added, pool_change_before.added + leftover);
+        }
+    }
+
+    #[cfg(test)]
+    mod setting_reward_compounding {
+        use cosmwasm_std::Uint128;
+
+        use crate::rewards::queries::{
+            query_delegator_reward_compounding, query_operator_reward_compounding,
+        };
+        use crate::support::tests::test_helpers::TestSetup;
+
+        use super::*;
+
+        #[test]
+        fn for_operator_can_only_be_done_if_bond_exists() {
+            let mut test = TestSetup::new();
+            test.add_dummy_mixnode("mix-owner", Some(Uint128::new(1_000_000_000_000)));
+
+            let sender = mock_info("random-guy", &[]);
+            let res = try_set_operator_reward_compounding(test.deps_mut(), sender.clone(), true);
+            assert_eq!(
+                res,
+                Err(MixnetContractError::NoAssociatedMixNodeBond {
+                    owner: sender.sender
+                })
+            )
+        }
+
+        #[test]
+        fn for_operator_can_be_toggled() {
+            let mut test = TestSetup::new();
+            let owner = "mix-owner";
+            let mix_id = test.add_dummy_mixnode(owner, Some(Uint128::new(1_000_000_000_000)));
+            let sender = mock_info(owner, &[]);
+
+            let res = query_operator_reward_compounding(test.deps(), mix_id).unwrap();
+            assert!(!res.enabled);
+
+            try_set_operator_reward_compounding(test.deps_mut(), sender.clone(), true).unwrap();
+            let res = query_operator_reward_compounding(test.deps(), mix_id).unwrap();
+            assert!(res.enabled);
+
+            try_set_operator_reward_compounding(test.deps_mut(), sender, false).unwrap();
+            let res = query_operator_reward_compounding(test.deps(), mix_id).unwrap();
+            assert!(!res.enabled);
+        }
+
+        #[test]
+        fn for_operator_can_only_be_done_for_fully_bonded_nodes() {
+            let mut test = TestSetup::new();
+            let owner = "mix-owner";
+            let mix_id = test.add_dummy_mixnode(owner, Some(Uint128::new(1_000_000_000_000)));
+            test.start_unbonding_mixnode(mix_id);
+
+            let res =
+                try_set_operator_reward_compounding(test.deps_mut(), mock_info(owner, &[]), true);
+            assert_eq!(res, Err(MixnetContractError::MixnodeIsUnbonding { mix_id }))
+        }
+
+        #[test]
+        fn for_delegator_can_only_be_done_if_delegation_exists() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(1_000_000_000_000)));
+
+            let delegator = "delegator";
+            let res = try_set_delegator_reward_compounding(
+                test.deps_mut(),
+                mock_info(delegator, &[]),
+                mix_id,
+                true,
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::NoMixnodeDelegationFound {
+                    mix_id,
+                    address: delegator.to_string(),
+                    proxy: None,
+                })
+            );
+
+            // delegation made via the vesting contract is a different one
+            test.add_immediate_delegation_with_legal_proxy(delegator, 100_000_000u128, mix_id);
+            let res = try_set_delegator_reward_compounding(
+                test.deps_mut(),
+                mock_info(delegator, &[]),
+                mix_id,
+                true,
+            );
+            assert!(res.is_err());
+
+            let vesting_contract = test.vesting_contract();
+            try_set_delegator_reward_compounding_on_behalf(
+                test.deps_mut(),
+                mock_info(vesting_contract.as_str(), &[]),
+                mix_id,
+                true,
+                delegator.to_string(),
+            )
+            .unwrap();
+
+            let res = query_delegator_reward_compounding(
+                test.deps(),
+                delegator.to_string(),
+                mix_id,
+                Some(vesting_contract.into_string()),
+            )
+            .unwrap();
+            assert!(res.enabled);
+            let res = query_delegator_reward_compounding(
+                test.deps(),
+                delegator.to_string(),
+                mix_id,
+                None,
+            )
+            .unwrap();
+            assert!(!res.enabled);
+        }
+
+        #[test]
+        fn for_delegator_gets_removed_upon_undelegation() {
+            let mut test = TestSetup::new();
+            let mix_id = test.add_dummy_mixnode("mix-owner", Some(Uint128::new(1_000_000_000_000)));
+
+            let delegator = "delegator";
+            test.add_immediate_delegation(delegator, 100_000_000u128, mix_id);
+            try_set_delegator_reward_compounding(
+                test.deps_mut(),
+                mock_info(delegator, &[]),
+                mix_id,
+                true,
+            )
+            .unwrap();
+
+            test.remove_immediate_delegation(delegator, mix_id);
+            let res = query_delegator_reward_compounding(
+                test.deps(),
+                delegator.to_string(),
+                mix_id,
+                None,
+            )
+            .unwrap();
+            assert!(!res.enabled);
+        }
+
+        #[test]
+        fn on_behalf_fails_for_illegal_proxy() {
+            let mut test = TestSetup::new();
+            let illegal_proxy = Addr::unchecked("not-vesting-contract");
+            let vesting_contract = test.vesting_contract();
+
+            let res = try_set_operator_reward_compounding_on_behalf(
+                test.deps_mut(),
+                mock_info(illegal_proxy.as_str(), &[]),
+                true,
+                "owner".to_string(),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::SenderIsNotVestingContract {
+                    received: illegal_proxy.clone(),
+                    vesting_contract: vesting_contract.clone(),
+                })
+            );
+
+            let res = try_set_delegator_reward_compounding_on_behalf(
+                test.deps_mut(),
+                mock_info(illegal_proxy.as_str(), &[]),
+                42,
+                true,
+                "owner".to_string(),
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::SenderIsNotVestingContract {
+                    received: illegal_proxy,
+                    vesting_contract,
+                })
+            );
+        }
+
+        #[test]
+        fn cant_be_changed_during_epoch_transition() {
+            let mut test = TestSetup::new();
+            let owner = "mix-owner";
+            let mix_id = test.add_dummy_mixnode(owner, Some(Uint128::new(1_000_000_000_000)));
+            test.add_immediate_delegation("delegator", 100_000_000u128, mix_id);
+
+            test.skip_to_current_epoch_end();
+            test.set_epoch_reconciliation_state();
+
+            let res =
+                try_set_operator_reward_compounding(test.deps_mut(), mock_info(owner, &[]), true);
+            assert_eq!(
+                res,
+                Err(MixnetContractError::EpochAdvancementInProgress {
+                    current_state: EpochState::ReconcilingEvents
+                })
+            );
+
+            let res = try_set_delegator_reward_compounding(
+                test.deps_mut(),
+                mock_info("delegator", &[]),
+                mix_id,
+                true,
+            );
+            assert_eq!(
+                res,
+                Err(MixnetContractError::EpochAdvancementInProgress {
+                    current_state: EpochState::ReconcilingEvents
+                })
+            );
+        }
+    }
+
     #[cfg(test)]
     mod updating_active_set {
         use mixnet_contract_common::{EpochState, EpochStatus};
@@ -2182,6 +3111,7 @@ pub mod tests {
                 active_set_work_factor: None,
                 interval_pool_emission: None,
                 rewarded_set_size: Some(123),
+                gateway_epoch_reward: None,
             };
 
             for bad_state in bad_states {
@@ -2229,6 +3159,7 @@ pub mod tests {
                 active_set_work_factor: None,
                 interval_pool_emission: None,
                 rewarded_set_size: Some(123),
+                gateway_epoch_reward: None,
             };
 
             let env = test.env();
@@ -2262,6 +3193,7 @@ pub mod tests {
                 active_set_work_factor: None,
                 interval_pool_emission: None,
                 rewarded_set_size: None,
+                gateway_epoch_reward: None,
             };
 
             let env = test.env();
@@ -2282,6 +3214,7 @@ pub mod tests {
                 active_set_work_factor: None,
                 interval_pool_emission: None,
                 rewarded_set_size: Some(123),
+                gateway_epoch_reward: None,
             };
 
             let old = storage::REWARDING_PARAMS.load(test.deps().storage).unwrap();
@@ -2317,6 +3250,7 @@ pub mod tests {
                 active_set_work_factor: None,
                 interval_pool_emission: None,
                 rewarded_set_size: Some(123),
+                gateway_epoch_reward: None,
             };
 
             let old = storage::REWARDING_PARAMS.load(test.deps().storage).unwrap();
@@ -2341,6 +3275,7 @@ pub mod tests {
                 active_set_work_factor: None,
                 interval_pool_emission: None,
                 rewarded_set_size: Some(123),
+                gateway_epoch_reward: None,
             };
 
             let old = storage::REWARDING_PARAMS.load(test.deps().storage).unwrap();
@@ -2382,6 +3317,7 @@ pub mod tests {
                 active_set_work_factor: None,
                 interval_pool_emission: None,
                 rewarded_set_size: None,
+                gateway_epoch_reward: None,
             };
 
             let env = test.env();
@@ -40,6 +40,16 @@ where
         amount: Coin,
     ) -> Result<Self, MixnetContractError>;
 
+    fn maybe_add_track_vesting_redelegation_message(
+        self,
+        storage: &dyn Storage,
+        proxy: Option<Addr>,
+        owner: String,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+    ) -> Result<Self, MixnetContractError>;
+
     fn maybe_add_track_vesting_unbond_mixnode_message(
         self,
         storage: &dyn Storage,
@@ -97,6 +107,43 @@ impl VestingTracking for Response {
         }
     }
 
+    fn maybe_add_track_vesting_redelegation_message(
+        self,
+        storage: &dyn Storage,
+        proxy: Option<Addr>,
+        owner: String,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+    ) -> Result<Self, MixnetContractError> {
+        // if there's a proxy set (i.e. the vesting contract), send the track message
+        if let Some(proxy) = proxy {
+            let vesting_contract = mixnet_params_storage::vesting_contract_address(storage)?;
+
+            // Note: this can INTENTIONALLY cause epoch progression halt if the proxy is not the vesting contract
+            // (see the comment in `maybe_add_track_vesting_undelegation_message` for more details)
+            if proxy != vesting_contract {
+                return Err(MixnetContractError::ProxyIsNotVestingContract {
+                    received: proxy,
+                    vesting_contract,
+                });
+            }
+
+            let msg = VestingContractExecuteMsg::TrackRedelegation {
+                owner,
+                from_mix_id,
+                to_mix_id,
+                amount,
+            };
+
+            let track_redelegate_message = wasm_execute(proxy, &msg, vec![])?;
+            Ok(self.add_message(track_redelegate_message))
+        } else {
+            // there's no proxy so nothing to do
+            Ok(self)
+        }
+    }
+
     fn maybe_add_track_vesting_unbond_mixnode_message(
         self,
         storage: &dyn Storage,
@@ -378,6 +425,21 @@ pub(crate) fn ensure_bonded(bond: &MixNodeBond) -> Result<(), MixnetContractErro
     Ok(())
 }
 
+pub(crate) fn ensure_not_jailed(
+    storage: &dyn Storage,
+    mix_id: MixId,
+) -> Result<(), MixnetContractError> {
+    let current_epoch =
+        crate::interval::storage::current_interval(storage)?.current_epoch_absolute_id();
+    if let Some(jail) = crate::jailing::storage::jailed_in_epoch(storage, mix_id, current_epoch)? {
+        return Err(MixnetContractError::MixnodeJailed {
+            mix_id,
+            released_at_epoch: jail.released_at_epoch,
+        });
+    }
+    Ok(())
+}
+
 pub(crate) fn ensure_no_pending_pledge_changes(
     pending_changes: &PendingMixNodeChanges,
 ) -> Result<(), MixnetContractError> {
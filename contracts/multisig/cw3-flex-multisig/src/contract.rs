@@ -54,6 +54,7 @@ pub fn instantiate(
         group_addr,
         coconut_bandwidth_addr,
         coconut_dkg_addr,
+        member_proposals: msg.member_proposals,
     };
     CONFIG.save(deps.storage, &cfg)?;
 
@@ -105,12 +106,20 @@ pub fn execute_propose(
     // only members of the multisig can create a proposal
     let cfg = CONFIG.load(deps.storage)?;
 
-    // Only the coconut bandwidth or dkg contracts can create proposals
-    if info.sender != cfg.coconut_bandwidth_addr && info.sender != cfg.coconut_dkg_addr {
-        return Err(ContractError::Unauthorized {});
-    }
-    // The contract doesn't have any say in the voting outcome
-    let vote_power = 0;
+    // Only the coconut bandwidth or dkg contracts can create proposals,
+    // unless the multisig explicitly allows proposals from its members
+    let vote_power =
+        if info.sender == cfg.coconut_bandwidth_addr || info.sender == cfg.coconut_dkg_addr {
+            // The contract doesn't have any say in the voting outcome
+            0
+        } else if cfg.member_proposals {
+            // The proposing member automatically votes in favour
+            cfg.group_addr
+                .is_member(&deps.querier, &info.sender, None)?
+                .ok_or(ContractError::Unauthorized {})?
+        } else {
+            return Err(ContractError::Unauthorized {});
+        };
 
     // max expires also used as default
     let max_expires = cfg.max_voting_period.after(&env.block);
@@ -544,6 +553,7 @@ mod tests {
             max_voting_period,
             coconut_bandwidth_contract_address: TEST_COCONUT_BANDWIDTH_CONTRACT_ADDRESS.to_string(),
             coconut_dkg_contract_address: TEST_COCONUT_DKG_CONTRACT_ADDRESS.to_string(),
+            member_proposals: false,
         };
         app.instantiate_contract(flex_id, Addr::unchecked(OWNER), &msg, &[], "flex", None)
             .unwrap()
@@ -661,6 +671,7 @@ mod tests {
             max_voting_period,
             coconut_bandwidth_contract_address: TEST_COCONUT_BANDWIDTH_CONTRACT_ADDRESS.to_string(),
             coconut_dkg_contract_address: TEST_COCONUT_DKG_CONTRACT_ADDRESS.to_string(),
+            member_proposals: false,
         };
         let err = app
             .instantiate_contract(
@@ -684,6 +695,7 @@ mod tests {
             max_voting_period,
             coconut_bandwidth_contract_address: TEST_COCONUT_BANDWIDTH_CONTRACT_ADDRESS.to_string(),
             coconut_dkg_contract_address: TEST_COCONUT_DKG_CONTRACT_ADDRESS.to_string(),
+            member_proposals: false,
         };
         let err = app
             .instantiate_contract(
@@ -707,6 +719,7 @@ mod tests {
             max_voting_period,
             coconut_bandwidth_contract_address: TEST_COCONUT_BANDWIDTH_CONTRACT_ADDRESS.to_string(),
             coconut_dkg_contract_address: TEST_COCONUT_DKG_CONTRACT_ADDRESS.to_string(),
+            member_proposals: false,
         };
         let flex_addr = app
             .instantiate_contract(
@@ -869,6 +882,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_member_proposals() {
+        let init_funds = coins(10, "BTC");
+        let mut app = mock_app(&init_funds);
+
+        let group_addr = instantiate_group(
+            &mut app,
+            vec![member(VOTER1, 1), member(VOTER2, 2), member(VOTER3, 3)],
+        );
+        app.update_block(next_block);
+
+        let flex_id = app.store_code(contract_flex());
+        let msg = InstantiateMsg {
+            group_addr: group_addr.to_string(),
+            threshold: Threshold::AbsoluteCount { weight: 4 },
+            max_voting_period: Duration::Time(2000000),
+            coconut_bandwidth_contract_address: TEST_COCONUT_BANDWIDTH_CONTRACT_ADDRESS.to_string(),
+            coconut_dkg_contract_address: TEST_COCONUT_DKG_CONTRACT_ADDRESS.to_string(),
+            member_proposals: true,
+        };
+        let flex_addr = app
+            .instantiate_contract(flex_id, Addr::unchecked(OWNER), &msg, &[], "flex", None)
+            .unwrap();
+        app.send_tokens(Addr::unchecked(OWNER), flex_addr.clone(), &init_funds)
+            .unwrap();
+        app.update_block(next_block);
+
+        let proposal = pay_somebody_proposal();
+
+        // non-members still can't propose
+        let err = app
+            .execute_contract(Addr::unchecked(SOMEBODY), flex_addr.clone(), &proposal, &[])
+            .unwrap_err();
+        assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+        // but members can, and their weight is counted towards the proposal
+        let res = app
+            .execute_contract(Addr::unchecked(VOTER3), flex_addr.clone(), &proposal, &[])
+            .unwrap();
+        assert_eq!(
+            res.custom_attrs(1),
+            [
+                ("action", "propose"),
+                ("sender", VOTER3),
+                ("proposal_id", "1"),
+                ("status", "Open"),
+            ],
+        );
+        assert_eq!(get_tally(&app, flex_addr.as_str(), 1), 3);
+
+        let yes_vote = ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: Vote::Yes,
+        };
+        app.execute_contract(Addr::unchecked(VOTER1), flex_addr.clone(), &yes_vote, &[])
+            .unwrap();
+
+        let execution = ExecuteMsg::Execute { proposal_id: 1 };
+        app.execute_contract(Addr::unchecked(SOMEBODY), flex_addr, &execution, &[])
+            .unwrap();
+        assert_eq!(
+            app.wrap().query_balance(SOMEBODY, "BTC").unwrap(),
+            coin(1, "BTC")
+        );
+    }
+
     #[test]
     fn test_proposal_queries() {
         let init_funds = coins(10, "BTC");
@@ -14,6 +14,9 @@ pub struct Config {
     pub group_addr: Cw4Contract,
     pub coconut_bandwidth_addr: Addr,
     pub coconut_dkg_addr: Addr,
+    // Whether members of the group are allowed to create proposals
+    #[serde(default)]
+    pub member_proposals: bool,
 }
 
 // unique items
@@ -23,6 +23,8 @@ pub fn instantiate(
 
     let config = Config {
         deposit_required: msg.deposit_required,
+        registration_period_secs: msg.registration_period_secs,
+        grace_period_secs: msg.grace_period_secs,
     };
     cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     state::save_config(deps.storage, &config)?;
@@ -34,7 +36,7 @@ pub fn instantiate(
 
 pub fn migrate(
     deps: DepsMut<'_>,
-    _env: Env,
+    env: Env,
     _msg: MigrateMsg,
 ) -> Result<Response, NameServiceError> {
     // Note: don't remove this particular bit of code as we have to ALWAYS check whether we have to
@@ -62,6 +64,15 @@ pub fn migrate(
         // should occur here, for example anything from `crate::queued_migrations::`
     }
 
+    // names registered before the introduction of expiry get a full registration period.
+    // note: this has to run regardless of the stored version, as the deployed contract predating
+    // expiry has got the same version. It only ever touches names without any expiry.
+    let config = state::load_config(deps.storage)?;
+    state::names::assign_missing_expiry(
+        deps.storage,
+        env.block.time.seconds() + config.registration_period_secs,
+    )?;
+
     Ok(Response::new())
 }
 
@@ -73,6 +84,11 @@ pub fn execute(
 ) -> Result<Response, NameServiceError> {
     match msg {
         ExecuteMsg::Register { name, address } => execute::register(deps, env, info, name, address),
+        ExecuteMsg::Renew { name_id } => execute::renew(deps, env, info, name_id),
+        ExecuteMsg::Transfer {
+            name_id,
+            to_address,
+        } => execute::transfer(deps, env, info, name_id, to_address),
         ExecuteMsg::DeleteId { name_id } => execute::delete_id(deps, info, name_id),
         ExecuteMsg::DeleteName { name } => execute::delete_name(deps, info, name),
         ExecuteMsg::UpdateDepositRequired { deposit_required } => {
@@ -90,6 +106,16 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary> {
         QueryMsg::All { limit, start_after } => {
             to_binary(&query::query_all_paged(deps, limit, start_after)?)
         }
+        QueryMsg::ExpiringBefore {
+            timestamp,
+            limit,
+            start_after,
+        } => to_binary(&query::query_expiring_paged(
+            deps,
+            timestamp,
+            limit,
+            start_after,
+        )?),
         QueryMsg::Config {} => to_binary(&query::query_config(deps)?),
         QueryMsg::GetContractVersion {} => to_binary(&query::query_contract_version()),
         QueryMsg::GetCW2ContractVersion {} => to_binary(&cw2::get_contract_version(deps.storage)?),
@@ -101,6 +127,7 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary> {
 mod tests {
     use super::*;
 
+    use crate::constants::{CONFIG_KEY, NAMES_PK_NAMESPACE};
     use crate::test_helpers::{
         assert::{assert_config, assert_empty, assert_name, assert_names, assert_not_found},
         fixture::name_fixture,
@@ -109,18 +136,21 @@ mod tests {
 
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        Addr, Coin,
+        Addr, Coin, Storage,
     };
-    use nym_name_service_common::{msg::ExecuteMsg, NameEntry, NameId};
+    use cw_storage_plus::Map;
+    use nym_name_service_common::{
+        msg::{ExecuteMsg, DEFAULT_GRACE_PERIOD_SECS, DEFAULT_REGISTRATION_PERIOD_SECS},
+        Address, NameEntry, NameId, NymName,
+    };
+    use serde::Serialize;
 
     const DENOM: &str = "unym";
 
     #[test]
     fn instantiate_contract() {
         let mut deps = mock_dependencies();
-        let msg = InstantiateMsg {
-            deposit_required: Coin::new(100u128, DENOM),
-        };
+        let msg = InstantiateMsg::new(Coin::new(100u128, DENOM));
         let info = mock_info("creator", &[]);
         let admin = info.sender.clone();
 
@@ -130,7 +160,13 @@ mod tests {
 
         // Check that it worked by querying the config, and checking that the list of names is
         // empty
-        assert_config(deps.as_ref(), &admin, Coin::new(100u128, DENOM));
+        assert_config(
+            deps.as_ref(),
+            &admin,
+            Coin::new(100u128, DENOM),
+            DEFAULT_REGISTRATION_PERIOD_SECS,
+            DEFAULT_GRACE_PERIOD_SECS,
+        );
         assert_empty(deps.as_ref());
     }
 
@@ -175,7 +211,13 @@ mod tests {
             }
         );
 
-        assert_config(deps.as_ref(), &admin, Coin::new(100, DENOM));
+        assert_config(
+            deps.as_ref(),
+            &admin,
+            Coin::new(100, DENOM),
+            DEFAULT_REGISTRATION_PERIOD_SECS,
+            DEFAULT_GRACE_PERIOD_SECS,
+        );
         assert_empty(deps.as_ref());
     }
 
@@ -269,4 +311,75 @@ mod tests {
         assert_names(deps.as_ref(), &[]);
         assert_not_found(deps.as_ref(), expected_id);
     }
+
+    // the shape of the names stored before the introduction of expiry
+    #[derive(Serialize)]
+    struct LegacyRegisteredName {
+        name: NymName,
+        address: Address,
+        owner: Addr,
+        block_height: u64,
+        deposit: Coin,
+    }
+
+    #[test]
+    fn migrate_assigns_expiry_to_names_registered_before_it_existed() {
+        let mut deps = mock_dependencies();
+        let admin = Addr::unchecked("creator");
+
+        // storage as left behind by the deployed contract, which has got the same version
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, CONTRACT_VERSION).unwrap();
+        state::set_admin(deps.as_mut(), admin.clone()).unwrap();
+        deps.storage.set(
+            CONFIG_KEY.as_bytes(),
+            br#"{"deposit_required":{"denom":"unym","amount":"100"}}"#,
+        );
+        let name = name_fixture();
+        let name_id = state::names::save(deps.as_mut().storage, &name).unwrap();
+        Map::<NameId, LegacyRegisteredName>::new(NAMES_PK_NAMESPACE)
+            .save(
+                deps.as_mut().storage,
+                name_id,
+                &LegacyRegisteredName {
+                    name: name.name.clone(),
+                    address: name.address.clone(),
+                    owner: name.owner.clone(),
+                    block_height: name.block_height,
+                    deposit: name.deposit.clone(),
+                },
+            )
+            .unwrap();
+        assert_eq!(
+            state::names::load_id(deps.as_ref().storage, name_id)
+                .unwrap()
+                .expires_at,
+            0
+        );
+
+        let env = mock_env();
+        migrate(deps.as_mut(), env.clone(), MigrateMsg {}).unwrap();
+
+        let expected_expiry = env.block.time.seconds() + DEFAULT_REGISTRATION_PERIOD_SECS;
+        let migrated = state::names::load_id(deps.as_ref().storage, name_id).unwrap();
+        assert_eq!(migrated.expires_at, expected_expiry);
+        assert!(!migrated.is_reclaimable(env.block.time.seconds(), DEFAULT_GRACE_PERIOD_SECS));
+        assert_config(
+            deps.as_ref(),
+            &admin,
+            Coin::new(100, DENOM),
+            DEFAULT_REGISTRATION_PERIOD_SECS,
+            DEFAULT_GRACE_PERIOD_SECS,
+        );
+
+        // migrating again later on doesn't touch the names that already expire
+        let mut later_env = mock_env();
+        later_env.block.time = later_env.block.time.plus_seconds(1000);
+        migrate(deps.as_mut(), later_env, MigrateMsg {}).unwrap();
+        assert_eq!(
+            state::names::load_id(deps.as_ref().storage, name_id)
+                .unwrap()
+                .expires_at,
+            expected_expiry
+        );
+    }
 }
@@ -3,11 +3,13 @@ use crate::{
     error::{NameServiceError, Result},
     state,
 };
-use cosmwasm_std::{Addr, BankMsg, Coin, Deps, DepsMut, Env, MessageInfo, Response, Uint128};
+use cosmwasm_std::{
+    Addr, BankMsg, Coin, Deps, DepsMut, Env, Event, MessageInfo, Response, Storage, Uint128,
+};
 use nym_name_service_common::{
     events::{
-        new_delete_id_event, new_delete_name_event, new_register_event,
-        new_update_deposit_required_event,
+        new_delete_id_event, new_delete_name_event, new_reclaim_event, new_register_event,
+        new_renew_event, new_transfer_event, new_update_deposit_required_event,
     },
     Address, NameId, NymName, RegisteredName,
 };
@@ -60,10 +62,9 @@ fn ensure_name_exists(deps: Deps, name_id: NameId) -> Result<()> {
     }
 }
 
-fn ensure_name_not_exists(deps: Deps, name: &NymName) -> Result<()> {
-    if state::names::has_name(deps.storage, name) {
-        println!("name already exists");
-        Err(NameServiceError::NameAlreadyRegistered { name: name.clone() })
+fn ensure_not_expired(name_id: NameId, name: &RegisteredName, env: &Env) -> Result<()> {
+    if name.is_expired(env.block.time.seconds()) {
+        Err(NameServiceError::NameExpired { name_id })
     } else {
         Ok(())
     }
@@ -86,6 +87,32 @@ fn return_deposit(name_to_delete: &RegisteredName) -> BankMsg {
     }
 }
 
+/// If the name is already registered, remove the existing registration as long as its grace
+/// period has elapsed. The deposit is returned to the previous owner.
+fn reclaim_if_expired(
+    store: &mut dyn Storage,
+    env: &Env,
+    grace_period_secs: u64,
+    name: &NymName,
+) -> Result<Option<(BankMsg, Event)>> {
+    if !state::names::has_name(store, name) {
+        return Ok(None);
+    }
+
+    let (name_id, existing) = state::names::load_name_entry(store, name)?;
+
+    if !existing.is_reclaimable(env.block.time.seconds(), grace_period_secs) {
+        return Err(NameServiceError::NameAlreadyRegistered { name: name.clone() });
+    }
+
+    state::names::remove_id(store, name_id)?;
+    let return_deposit_msg = return_deposit(&existing);
+    Ok(Some((
+        return_deposit_msg,
+        new_reclaim_event(name_id, existing),
+    )))
+}
+
 /// Register a new name. It will be assigned a new name id.
 pub fn register(
     deps: DepsMut,
@@ -94,11 +121,12 @@ pub fn register(
     name: NymName,
     address: Address,
 ) -> Result<Response> {
-    ensure_name_not_exists(deps.as_ref(), &name)?;
+    let config = state::load_config(deps.storage)?;
+    let reclaimed = reclaim_if_expired(deps.storage, &env, config.grace_period_secs, &name)?;
     ensure_max_names_per_owner(deps.as_ref(), info.sender.clone())?;
     ensure_max_names_per_address(deps.as_ref(), address.clone())?;
 
-    let deposit_required = state::deposit_required(deps.storage)?;
+    let deposit_required = config.deposit_required;
     let denom = deposit_required.denom.clone();
     let will_deposit = cw_utils::must_pay(&info, &denom)
         .map_err(|err| NameServiceError::DepositRequired { source: err })?;
@@ -110,10 +138,62 @@ pub fn register(
         owner: info.sender,
         block_height: env.block.height,
         deposit: Coin::new(will_deposit.u128(), denom),
+        expires_at: env.block.time.seconds() + config.registration_period_secs,
     };
     let name_id = state::names::save(deps.storage, &new_name)?;
 
-    Ok(Response::new().add_event(new_register_event(name_id, new_name)))
+    let mut response = Response::new();
+    if let Some((return_deposit_msg, reclaim_event)) = reclaimed {
+        response = response
+            .add_message(return_deposit_msg)
+            .add_event(reclaim_event);
+    }
+    Ok(response.add_event(new_register_event(name_id, new_name)))
+}
+
+/// Extend the registration of an existing name by another registration period, counting from now.
+pub(crate) fn renew(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name_id: NameId,
+) -> Result<Response> {
+    let name = state::names::load_id(deps.storage, name_id)?;
+    ensure_sender_authorized(info, &name)?;
+
+    let config = state::load_config(deps.storage)?;
+    let renewed_expiry = env.block.time.seconds() + config.registration_period_secs;
+    let renewed_name = RegisteredName {
+        // renewing should never shorten the registration
+        expires_at: renewed_expiry.max(name.expires_at),
+        ..name.clone()
+    };
+    state::names::update(deps.storage, name_id, &renewed_name, &name)?;
+
+    Ok(Response::new().add_event(new_renew_event(name_id, renewed_name)))
+}
+
+/// Transfer an existing, not expired, name to a new owner. The deposit is transferred alongside it.
+pub(crate) fn transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name_id: NameId,
+    to_address: String,
+) -> Result<Response> {
+    let new_owner = deps.api.addr_validate(&to_address)?;
+    let name = state::names::load_id(deps.storage, name_id)?;
+    ensure_sender_authorized(info, &name)?;
+    ensure_not_expired(name_id, &name, &env)?;
+    ensure_max_names_per_owner(deps.as_ref(), new_owner.clone())?;
+
+    let transferred_name = RegisteredName {
+        owner: new_owner,
+        ..name.clone()
+    };
+    state::names::update(deps.storage, name_id, &transferred_name, &name)?;
+
+    Ok(Response::new().add_event(new_transfer_event(name_id, &name.owner, transferred_name)))
 }
 
 /// Delete an exsisting name.
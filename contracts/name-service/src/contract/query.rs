@@ -44,6 +44,20 @@ pub fn query_all_paged(
     Ok(PagedNamesListResponse::new(names, limit, start_next_after))
 }
 
+pub fn query_expiring_paged(
+    deps: Deps,
+    timestamp: u64,
+    limit: Option<u32>,
+    start_after: Option<NameId>,
+) -> Result<PagedNamesListResponse> {
+    let PagedLoad {
+        names,
+        limit,
+        start_next_after,
+    } = state::names::load_expiring_paged(deps.storage, timestamp, limit, start_after)?;
+    Ok(PagedNamesListResponse::new(names, limit, start_next_after))
+}
+
 pub fn query_config(deps: Deps) -> Result<ConfigResponse> {
     let config = state::load_config(deps.storage)?;
     Ok(config.into())
@@ -52,6 +52,9 @@ pub enum NameServiceError {
 
     #[error("name already registered: {name}")]
     NameAlreadyRegistered { name: NymName },
+
+    #[error("registration of name with id {name_id} has expired")]
+    NameExpired { name_id: NameId },
 }
 
 pub(crate) type Result<T, E = NameServiceError> = std::result::Result<T, E>;
@@ -2,6 +2,7 @@
 
 use cosmwasm_std::Addr;
 use nym_name_service_common::{
+    msg::{DEFAULT_GRACE_PERIOD_SECS, DEFAULT_REGISTRATION_PERIOD_SECS},
     response::{ConfigResponse, PagedNamesListResponse},
     Address, NameEntry, NymName, RegisteredName,
 };
@@ -9,7 +10,11 @@ use nym_name_service_common::{
 use crate::{
     constants::NAME_DEFAULT_RETRIEVAL_LIMIT,
     error::NameServiceError,
-    test_helpers::{fixture::name_entry, helpers::nyms, test_setup::TestSetup},
+    test_helpers::{
+        fixture::{expiry_fixture, name_entry},
+        helpers::nyms,
+        test_setup::TestSetup,
+    },
 };
 
 #[test]
@@ -23,6 +28,8 @@ fn query_config() {
         TestSetup::new().query_config(),
         ConfigResponse {
             deposit_required: nyms(100),
+            registration_period_secs: DEFAULT_REGISTRATION_PERIOD_SECS,
+            grace_period_secs: DEFAULT_GRACE_PERIOD_SECS,
         }
     );
 }
@@ -63,6 +70,7 @@ fn register_and_query_name() {
                     owner: owner.clone(),
                     block_height: 12345,
                     deposit: nyms(100),
+                    expires_at: expiry_fixture(),
                 },
             }],
             per_page: NAME_DEFAULT_RETRIEVAL_LIMIT as usize,
@@ -81,6 +89,7 @@ fn register_and_query_name() {
                 owner: owner.clone(),
                 block_height: 12345,
                 deposit: nyms(100),
+                expires_at: expiry_fixture(),
             },
         }
     );
@@ -443,3 +452,198 @@ fn name_id_is_not_resused_when_deleting_and_then_adding_a_new_names() {
         ]
     );
 }
+
+#[test]
+fn renewing_extends_expiry() {
+    let mut setup = TestSetup::new();
+    setup.register(
+        NymName::new("myname").unwrap(),
+        Address::new("nymAddress"),
+        Addr::unchecked("owner"),
+    );
+    assert_eq!(setup.query_id(1).name.expires_at, expiry_fixture());
+
+    setup.advance_time(1000);
+    setup.renew(1, Addr::unchecked("owner"));
+    assert_eq!(setup.query_id(1).name.expires_at, expiry_fixture() + 1000);
+
+    // Renewing is free
+    assert_eq!(setup.contract_balance(), nyms(100));
+    assert_eq!(setup.balance("owner"), nyms(150));
+}
+
+#[test]
+fn expired_name_can_be_renewed_before_it_is_reclaimed() {
+    let mut setup = TestSetup::new();
+    setup.register(
+        NymName::new("myname").unwrap(),
+        Address::new("nymAddress"),
+        Addr::unchecked("owner"),
+    );
+    setup.advance_time(DEFAULT_REGISTRATION_PERIOD_SECS + 10);
+    assert!(setup.query_id(1).name.is_expired(setup.block_time()));
+
+    setup.renew(1, Addr::unchecked("owner"));
+    assert_eq!(
+        setup.query_id(1).name.expires_at,
+        setup.block_time() + DEFAULT_REGISTRATION_PERIOD_SECS
+    );
+}
+
+#[test]
+fn only_owner_can_renew_name() {
+    let mut setup = TestSetup::new();
+    setup.register(
+        NymName::new("myname").unwrap(),
+        Address::new("nymAddress"),
+        Addr::unchecked("owner"),
+    );
+    setup.advance_time(1000);
+
+    let res = setup
+        .try_renew(1, Addr::unchecked("not_owner"))
+        .unwrap_err();
+    assert_eq!(
+        res.downcast::<NameServiceError>().unwrap(),
+        NameServiceError::Unauthorized {
+            sender: Addr::unchecked("not_owner")
+        }
+    );
+    assert_eq!(setup.query_id(1).name.expires_at, expiry_fixture());
+}
+
+#[test]
+fn transfer_name() {
+    let mut setup = TestSetup::new();
+    setup.register(
+        NymName::new("myname").unwrap(),
+        Address::new("nymAddress"),
+        Addr::unchecked("owner1"),
+    );
+    setup.transfer(1, Addr::unchecked("owner2"), Addr::unchecked("owner1"));
+    assert_eq!(setup.query_id(1).name.owner, Addr::unchecked("owner2"));
+
+    // The previous owner can no longer manage the name
+    let res = setup.try_delete(1, Addr::unchecked("owner1")).unwrap_err();
+    assert_eq!(
+        res.downcast::<NameServiceError>().unwrap(),
+        NameServiceError::Unauthorized {
+            sender: Addr::unchecked("owner1")
+        }
+    );
+
+    // The deposit moved with the name
+    setup.delete(1, Addr::unchecked("owner2"));
+    assert_eq!(setup.contract_balance(), nyms(0));
+    assert_eq!(setup.balance("owner1"), nyms(150));
+    assert_eq!(setup.balance("owner2"), nyms(350));
+}
+
+#[test]
+fn only_owner_can_transfer_name() {
+    let mut setup = TestSetup::new();
+    setup.register(
+        NymName::new("myname").unwrap(),
+        Address::new("nymAddress"),
+        Addr::unchecked("owner"),
+    );
+
+    let res = setup
+        .try_transfer(1, Addr::unchecked("user"), Addr::unchecked("user"))
+        .unwrap_err();
+    assert_eq!(
+        res.downcast::<NameServiceError>().unwrap(),
+        NameServiceError::Unauthorized {
+            sender: Addr::unchecked("user")
+        }
+    );
+    assert_eq!(setup.query_id(1).name.owner, Addr::unchecked("owner"));
+}
+
+#[test]
+fn cant_transfer_expired_name() {
+    let mut setup = TestSetup::new();
+    setup.register(
+        NymName::new("myname").unwrap(),
+        Address::new("nymAddress"),
+        Addr::unchecked("owner"),
+    );
+    setup.advance_time(DEFAULT_REGISTRATION_PERIOD_SECS);
+
+    let res = setup
+        .try_transfer(1, Addr::unchecked("user"), Addr::unchecked("owner"))
+        .unwrap_err();
+    assert_eq!(
+        res.downcast::<NameServiceError>().unwrap(),
+        NameServiceError::NameExpired { name_id: 1 }
+    );
+}
+
+#[test]
+fn expired_name_can_only_be_reclaimed_after_grace_period() {
+    let mut setup = TestSetup::new();
+    setup.register(
+        NymName::new("myname").unwrap(),
+        Address::new("nymAddress1"),
+        Addr::unchecked("owner1"),
+    );
+    setup.advance_time(DEFAULT_REGISTRATION_PERIOD_SECS);
+
+    let res = setup
+        .try_register(
+            NymName::new("myname").unwrap(),
+            Address::new("nymAddress2"),
+            Addr::unchecked("owner2"),
+        )
+        .unwrap_err();
+    assert_eq!(
+        res.downcast::<NameServiceError>().unwrap(),
+        NameServiceError::NameAlreadyRegistered {
+            name: NymName::new("myname").unwrap()
+        }
+    );
+
+    setup.advance_time(DEFAULT_GRACE_PERIOD_SECS);
+    setup.register(
+        NymName::new("myname").unwrap(),
+        Address::new("nymAddress2"),
+        Addr::unchecked("owner2"),
+    );
+
+    // The previous registration is gone and its deposit returned
+    let names = setup.query_all().names;
+    assert_eq!(names.len(), 1);
+    assert_eq!(names[0].name_id, 2);
+    assert_eq!(names[0].name.owner, Addr::unchecked("owner2"));
+    assert_eq!(setup.balance("owner1"), nyms(250));
+    assert_eq!(setup.balance("owner2"), nyms(150));
+    assert_eq!(setup.contract_balance(), nyms(100));
+}
+
+#[test]
+fn query_names_expiring_before() {
+    let mut setup = TestSetup::new();
+    setup.register(
+        NymName::new("myname1").unwrap(),
+        Address::new("nymAddress1"),
+        Addr::unchecked("owner1"),
+    );
+    setup.advance_time(1000);
+    setup.register(
+        NymName::new("myname2").unwrap(),
+        Address::new("nymAddress2"),
+        Addr::unchecked("owner2"),
+    );
+
+    assert!(setup
+        .query_expiring_before(expiry_fixture())
+        .names
+        .is_empty());
+
+    let expiring = setup.query_expiring_before(expiry_fixture() + 1);
+    assert_eq!(expiring.names.len(), 1);
+    assert_eq!(expiring.names[0].name_id, 1);
+
+    let expiring = setup.query_expiring_before(expiry_fixture() + 1001);
+    assert_eq!(expiring.names.len(), 2);
+}
@@ -1,6 +1,9 @@
 use cosmwasm_std::{Coin, Storage};
 use cw_storage_plus::Item;
-use nym_name_service_common::response::ConfigResponse;
+use nym_name_service_common::{
+    msg::{default_grace_period_secs, default_registration_period_secs},
+    response::ConfigResponse,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::{constants::CONFIG_KEY, error::Result};
@@ -10,12 +13,19 @@ const CONFIG: Item<Config> = Item::new(CONFIG_KEY);
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub(crate) struct Config {
     pub deposit_required: Coin,
+    // contracts instantiated before the introduction of name expiry did not store those
+    #[serde(default = "default_registration_period_secs")]
+    pub registration_period_secs: u64,
+    #[serde(default = "default_grace_period_secs")]
+    pub grace_period_secs: u64,
 }
 
 impl From<Config> for ConfigResponse {
     fn from(config: Config) -> Self {
         ConfigResponse {
             deposit_required: config.deposit_required,
+            registration_period_secs: config.registration_period_secs,
+            grace_period_secs: config.grace_period_secs,
         }
     }
 }
@@ -27,8 +37,3 @@ pub(crate) fn save_config(store: &mut dyn Storage, config: &Config) -> Result<()
 pub(crate) fn load_config(store: &dyn Storage) -> Result<Config> {
     Ok(CONFIG.load(store)?)
 }
-
-/// Return the deposit required to register a name.
-pub(crate) fn deposit_required(store: &dyn Storage) -> Result<Coin> {
-    Ok(CONFIG.load(store).map(|config| config.deposit_required)?)
-}
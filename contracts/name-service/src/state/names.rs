@@ -124,6 +124,15 @@ pub fn load_owner(store: &dyn Storage, owner: Addr) -> Result<Vec<(NameId, Regis
         .map_err(NameServiceError::from)
 }
 
+pub fn update(
+    store: &mut dyn Storage,
+    name_id: NameId,
+    updated_name: &RegisteredName,
+    old_name: &RegisteredName,
+) -> Result<()> {
+    Ok(names().replace(store, name_id, Some(updated_name), Some(old_name))?)
+}
+
 pub fn remove_id(store: &mut dyn Storage, name_id: NameId) -> Result<()> {
     Ok(names().remove(store, name_id)?)
 }
@@ -167,6 +176,51 @@ pub fn load_all_paged(
     })
 }
 
+/// Load the names expiring before the provided unix timestamp.
+pub fn load_expiring_paged(
+    store: &dyn Storage,
+    timestamp: u64,
+    limit: Option<u32>,
+    start_after: Option<NameId>,
+) -> Result<PagedLoad> {
+    let limit = limit
+        .unwrap_or(NAME_DEFAULT_RETRIEVAL_LIMIT)
+        .min(NAME_MAX_RETRIEVAL_LIMIT) as usize;
+
+    let start = start_after.map(Bound::exclusive);
+
+    let names = names()
+        .range(store, start, None, Order::Ascending)
+        .filter(|entry| !matches!(entry, Ok((_, name)) if name.expires_at >= timestamp))
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let start_next_after = names.last().map(|name| name.0);
+
+    Ok(PagedLoad {
+        names,
+        limit,
+        start_next_after,
+    })
+}
+
+/// Assign expiry to all names registered before it got introduced.
+pub fn assign_missing_expiry(store: &mut dyn Storage, expires_at: u64) -> Result<()> {
+    let without_expiry = names()
+        .range(store, None, None, Order::Ascending)
+        .filter(|entry| !matches!(entry, Ok((_, name)) if name.expires_at != 0))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (name_id, name) in without_expiry {
+        let updated = RegisteredName {
+            expires_at,
+            ..name.clone()
+        };
+        update(store, name_id, &updated, &name)?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::iter::zip;
@@ -689,6 +743,41 @@ mod tests {
         );
     }
 
+    #[rstest]
+    fn load_expiring_paged_applies_limit_to_expiring_names(mut deps: TestDeps) {
+        let names = [200, 100, 300, 100]
+            .into_iter()
+            .enumerate()
+            .map(|(i, expires_at)| RegisteredName {
+                expires_at,
+                ..name_fixture_full(&format!("name{i}"), &format!("address{i}"), "owner")
+            })
+            .collect::<Vec<_>>();
+        save_all(deps.as_mut().storage, &names).unwrap();
+
+        let page = load_expiring_paged(&deps.storage, 150, Some(1), None).unwrap();
+        assert_eq!(page.names, vec![(2, names[1].clone())]);
+        assert_eq!(page.start_next_after, Some(2));
+
+        let page = load_expiring_paged(&deps.storage, 150, Some(1), Some(2)).unwrap();
+        assert_eq!(page.names, vec![(4, names[3].clone())]);
+        assert_eq!(page.start_next_after, Some(4));
+
+        let page = load_expiring_paged(&deps.storage, 150, Some(1), Some(4)).unwrap();
+        assert!(page.names.is_empty());
+        assert_eq!(page.start_next_after, None);
+
+        let page = load_expiring_paged(&deps.storage, 250, None, None).unwrap();
+        assert_eq!(
+            page.names,
+            vec![
+                (1, names[0].clone()),
+                (2, names[1].clone()),
+                (4, names[3].clone())
+            ]
+        );
+    }
+
     #[test]
     #[ignore]
     fn max_page_limit_is_applied() {
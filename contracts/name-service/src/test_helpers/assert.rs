@@ -7,11 +7,24 @@ use nym_name_service_common::{
 
 use crate::{constants::NAME_DEFAULT_RETRIEVAL_LIMIT, error::NameServiceError};
 
-pub fn assert_config(deps: Deps, admin: &Addr, deposit_required: Coin) {
+pub fn assert_config(
+    deps: Deps,
+    admin: &Addr,
+    deposit_required: Coin,
+    registration_period_secs: u64,
+    grace_period_secs: u64,
+) {
     crate::state::assert_admin(deps, admin).unwrap();
     let res = crate::contract::query(deps, mock_env(), QueryMsg::Config {}).unwrap();
     let config: ConfigResponse = from_binary(&res).unwrap();
-    assert_eq!(config, ConfigResponse { deposit_required });
+    assert_eq!(
+        config,
+        ConfigResponse {
+            deposit_required,
+            registration_period_secs,
+            grace_period_secs,
+        }
+    );
 }
 
 pub fn assert_names(deps: Deps, expected_names: &[NameEntry]) {
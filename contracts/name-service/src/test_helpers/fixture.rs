@@ -1,8 +1,15 @@
-use cosmwasm_std::Addr;
-use nym_name_service_common::{Address, NameEntry, NameId, NymName, RegisteredName};
+use cosmwasm_std::{testing::mock_env, Addr};
+use nym_name_service_common::{
+    msg::DEFAULT_REGISTRATION_PERIOD_SECS, Address, NameEntry, NameId, NymName, RegisteredName,
+};
 
 use super::helpers::nyms;
 
+/// Expiry of names registered at the time of the default mock environment.
+pub fn expiry_fixture() -> u64 {
+    mock_env().block.time.seconds() + DEFAULT_REGISTRATION_PERIOD_SECS
+}
+
 pub fn name_fixture_full(name: &str, nym_address: &str, owner: &str) -> RegisteredName {
     RegisteredName {
         name: NymName::new(name).unwrap(),
@@ -10,6 +17,7 @@ pub fn name_fixture_full(name: &str, nym_address: &str, owner: &str) -> Register
         owner: Addr::unchecked(owner),
         block_height: 12345,
         deposit: nyms(100),
+        expires_at: expiry_fixture(),
     }
 }
 
@@ -73,9 +73,7 @@ pub fn get_app_attributes(response: &AppResponse, event_type: &str, key: &str) -
 
 pub fn instantiate_test_contract() -> OwnedDeps<MemoryStorage, MockApi, MockQuerier> {
     let mut deps = mock_dependencies();
-    let msg = InstantiateMsg {
-        deposit_required: coin(100, "unym"),
-    };
+    let msg = InstantiateMsg::new(coin(100, "unym"));
     let env = mock_env();
     let info = mock_info("creator", &[]);
     let res = crate::instantiate(deps.as_mut(), env, info, msg).unwrap();
@@ -51,9 +51,7 @@ impl TestSetup {
         app.instantiate_contract(
             code_id,
             Addr::unchecked("admin"),
-            &InstantiateMsg {
-                deposit_required: Coin::new(100, DENOM),
-            },
+            &InstantiateMsg::new(Coin::new(100, DENOM)),
             &[],
             "contract_label",
             None,
@@ -92,6 +90,23 @@ impl TestSetup {
         self.query(&QueryMsg::All { limit, start_after })
     }
 
+    pub fn query_expiring_before(&self, timestamp: u64) -> PagedNamesListResponse {
+        self.query(&QueryMsg::ExpiringBefore {
+            timestamp,
+            limit: None,
+            start_after: None,
+        })
+    }
+
+    pub fn block_time(&self) -> u64 {
+        self.app.block_info().time.seconds()
+    }
+
+    pub fn advance_time(&mut self, secs: u64) {
+        self.app
+            .update_block(|block| block.time = block.time.plus_seconds(secs));
+    }
+
     pub fn try_register(
         &mut self,
         name: NymName,
@@ -147,6 +162,47 @@ impl TestSetup {
             .unwrap()
     }
 
+    pub fn try_renew(&mut self, name_id: NameId, owner: Addr) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            owner,
+            self.addr.clone(),
+            &ExecuteMsg::Renew { name_id },
+            &[],
+        )
+    }
+
+    pub fn renew(&mut self, name_id: NameId, owner: Addr) -> AppResponse {
+        let resp = self.try_renew(name_id, owner).unwrap();
+        assert_eq!(get_app_attribute(&resp, "wasm-renew", "action"), "renew");
+        resp
+    }
+
+    pub fn try_transfer(
+        &mut self,
+        name_id: NameId,
+        to_address: Addr,
+        owner: Addr,
+    ) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            owner,
+            self.addr.clone(),
+            &ExecuteMsg::Transfer {
+                name_id,
+                to_address: to_address.to_string(),
+            },
+            &[],
+        )
+    }
+
+    pub fn transfer(&mut self, name_id: NameId, to_address: Addr, owner: Addr) -> AppResponse {
+        let resp = self.try_transfer(name_id, to_address, owner).unwrap();
+        assert_eq!(
+            get_app_attribute(&resp, "wasm-transfer", "action"),
+            "transfer"
+        );
+        resp
+    }
+
     pub fn balance(&self, address: impl Into<String>) -> Coin {
         self.app.wrap().query_balance(address, DENOM).unwrap()
     }
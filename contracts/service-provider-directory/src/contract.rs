@@ -79,6 +79,14 @@ pub fn execute(
             owner_signature,
         } => execute::announce(deps, env, info, service, owner_signature),
         ExecuteMsg::DeleteId { service_id } => execute::delete_id(deps, info, service_id),
+        ExecuteMsg::Heartbeat {
+            service_id,
+            signature,
+        } => execute::heartbeat(deps, env, info, service_id, signature),
+        ExecuteMsg::UpdateMetadata {
+            service_id,
+            metadata,
+        } => execute::update_metadata(deps, info, service_id, metadata),
         ExecuteMsg::DeleteNymAddress { nym_address } => {
             execute::delete_nym_address(deps, info, nym_address)
         }
@@ -289,6 +297,8 @@ mod tests {
             announcer: Addr::unchecked("steve"),
             block_height: 12345,
             deposit,
+            metadata: Default::default(),
+            last_heartbeat: None,
         };
         assert_services(deps.as_ref(), &[expected_service.clone()]);
         assert_service(deps.as_ref(), &expected_service);
@@ -323,6 +333,8 @@ mod tests {
             announcer: Addr::unchecked(steve),
             block_height: 12345,
             deposit,
+            metadata: Default::default(),
+            last_heartbeat: None,
         };
         assert_services(deps.as_ref(), &[expected_service]);
 
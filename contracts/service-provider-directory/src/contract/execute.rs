@@ -8,9 +8,15 @@ use nym_contracts_common::{
     IdentityKey,
 };
 use nym_service_provider_directory_common::{
-    events::{new_announce_event, new_delete_id_event, new_update_deposit_required_event},
-    signing_types::construct_service_provider_announce_sign_payload,
-    NymAddress, Service, ServiceDetails, ServiceId,
+    events::{
+        new_announce_event, new_delete_id_event, new_heartbeat_event,
+        new_update_deposit_required_event, new_update_metadata_event,
+    },
+    signing_types::{
+        construct_service_provider_announce_sign_payload,
+        construct_service_provider_heartbeat_sign_payload,
+    },
+    NymAddress, Service, ServiceDetails, ServiceId, ServiceMetadata,
 };
 
 use super::query;
@@ -100,6 +106,27 @@ fn verify_announce_signature(
     }
 }
 
+fn verify_heartbeat_signature(
+    deps: Deps<'_>,
+    sender: Addr,
+    service: &Service,
+    signature: MessageSignature,
+) -> Result<()> {
+    // recover the public key
+    let public_key = decode_ed25519_identity_key(&service.service.identity_key)?;
+
+    // reconstruct the payload
+    let nonce = state::get_signing_nonce(deps.storage, sender.clone())?;
+
+    let msg = construct_service_provider_heartbeat_sign_payload(nonce, sender, service.service_id);
+
+    if deps.api.verify_message(msg, signature, &public_key)? {
+        Ok(())
+    } else {
+        Err(SpContractError::InvalidEd25519Signature)
+    }
+}
+
 fn decode_ed25519_identity_key(encoded: &IdentityKey) -> Result<[u8; 32]> {
     let mut public_key = [0u8; 32];
     let used = bs58::decode(encoded)
@@ -152,12 +179,52 @@ pub fn announce(
         announcer: info.sender,
         block_height: env.block.height,
         deposit,
+        metadata: ServiceMetadata::default(),
+        last_heartbeat: None,
     };
     state::save(deps.storage, &new_service)?;
 
     Ok(Response::new().add_event(new_announce_event(service_id, new_service)))
 }
 
+/// Record that the service is alive. The heartbeat has to be signed with the identity key of the
+/// service, so that it can't be produced without the service provider itself.
+pub(crate) fn heartbeat(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    service_id: ServiceId,
+    signature: MessageSignature,
+) -> Result<Response> {
+    let mut service = state::load_id(deps.storage, service_id)?;
+    ensure_sender_authorized(info.clone(), &service)?;
+
+    verify_heartbeat_signature(deps.as_ref(), info.sender.clone(), &service, signature)?;
+    state::increment_signing_nonce(deps.storage, info.sender)?;
+
+    let timestamp = env.block.time.seconds();
+    service.last_heartbeat = Some(timestamp);
+    state::save(deps.storage, &service)?;
+
+    Ok(Response::new().add_event(new_heartbeat_event(service_id, timestamp)))
+}
+
+/// Replace the metadata describing the capabilities of the service.
+pub(crate) fn update_metadata(
+    deps: DepsMut,
+    info: MessageInfo,
+    service_id: ServiceId,
+    metadata: ServiceMetadata,
+) -> Result<Response> {
+    let mut service = state::load_id(deps.storage, service_id)?;
+    ensure_sender_authorized(info, &service)?;
+
+    service.metadata = metadata;
+    state::save(deps.storage, &service)?;
+
+    Ok(Response::new().add_event(new_update_metadata_event(service)))
+}
+
 /// Delete an exsisting service.
 pub fn delete_id(deps: DepsMut, info: MessageInfo, service_id: ServiceId) -> Result<Response> {
     ensure_service_exists(deps.as_ref(), service_id)?;
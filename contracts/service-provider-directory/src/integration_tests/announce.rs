@@ -61,6 +61,8 @@ fn basic_announce(mut setup: TestSetup) {
                 announcer: announcer.clone(),
                 block_height: 12345,
                 deposit: nyms(100),
+                metadata: Default::default(),
+                last_heartbeat: None,
             }],
             per_page: SERVICE_DEFAULT_RETRIEVAL_LIMIT as usize,
             start_next_after: Some(1),
@@ -76,6 +78,8 @@ fn basic_announce(mut setup: TestSetup) {
             announcer: announcer.clone(),
             block_height: 12345,
             deposit: nyms(100),
+            metadata: Default::default(),
+            last_heartbeat: None,
         }
     );
 
@@ -0,0 +1,136 @@
+use cosmwasm_std::Addr;
+use nym_service_provider_directory_common::{
+    AllowedHostsPolicy, NymAddress, ServiceMetadata, DEFAULT_MAX_HEARTBEAT_AGE_SECS,
+};
+use rstest::rstest;
+
+use crate::{test_helpers::helpers::nyms, SpContractError};
+
+use super::test_setup::TestSetup;
+
+#[rstest::fixture]
+fn setup() -> TestSetup {
+    TestSetup::new()
+}
+
+#[rstest]
+fn announced_service_is_stale_until_first_heartbeat(mut setup: TestSetup) {
+    let announcer = Addr::unchecked("announcer");
+    setup.sign_and_announce_net_req(&NymAddress::new("nymAddress"), &announcer, &nyms(100));
+
+    let service = setup.query_id(1);
+    assert_eq!(service.last_heartbeat, None);
+    assert!(service.is_stale(setup.block_time(), DEFAULT_MAX_HEARTBEAT_AGE_SECS));
+}
+
+#[rstest]
+fn heartbeat_records_block_time(mut setup: TestSetup) {
+    let announcer = Addr::unchecked("announcer");
+    let service =
+        setup.sign_and_announce_net_req(&NymAddress::new("nymAddress"), &announcer, &nyms(100));
+    assert_eq!(setup.query_signing_nonce(announcer.to_string()), 1);
+
+    setup.advance_time(100);
+    setup.heartbeat(&service, 1, &announcer);
+
+    let now = setup.block_time();
+    let stored = setup.query_id(1);
+    assert_eq!(stored.last_heartbeat, Some(now));
+    assert!(!stored.is_stale(now, DEFAULT_MAX_HEARTBEAT_AGE_SECS));
+
+    // The heartbeat consumes a signing nonce
+    assert_eq!(setup.query_signing_nonce(announcer.to_string()), 2);
+
+    setup.advance_time(DEFAULT_MAX_HEARTBEAT_AGE_SECS + 1);
+    assert!(setup
+        .query_id(1)
+        .is_stale(setup.block_time(), DEFAULT_MAX_HEARTBEAT_AGE_SECS));
+}
+
+#[rstest]
+fn heartbeat_signed_by_other_key_is_rejected(mut setup: TestSetup) {
+    let announcer = Addr::unchecked("announcer");
+    let _ = setup.sign_and_announce_net_req(&NymAddress::new("nymAddress"), &announcer, &nyms(100));
+    let other =
+        setup.sign_and_announce_net_req(&NymAddress::new("otherAddress"), &announcer, &nyms(100));
+
+    let payload = setup.heartbeat_payload_to_sign(&announcer, 1);
+    let signature = other.sign_heartbeat(payload);
+    let err = setup.try_heartbeat(1, signature, &announcer).unwrap_err();
+    assert_eq!(
+        err.downcast::<SpContractError>().unwrap(),
+        SpContractError::InvalidEd25519Signature
+    );
+    assert_eq!(setup.query_id(1).last_heartbeat, None);
+}
+
+#[rstest]
+fn heartbeat_signature_cant_be_replayed(mut setup: TestSetup) {
+    let announcer = Addr::unchecked("announcer");
+    let service =
+        setup.sign_and_announce_net_req(&NymAddress::new("nymAddress"), &announcer, &nyms(100));
+
+    let payload = setup.heartbeat_payload_to_sign(&announcer, 1);
+    let signature = service.sign_heartbeat(payload);
+    setup
+        .try_heartbeat(1, signature.clone(), &announcer)
+        .unwrap();
+
+    let err = setup.try_heartbeat(1, signature, &announcer).unwrap_err();
+    assert_eq!(
+        err.downcast::<SpContractError>().unwrap(),
+        SpContractError::InvalidEd25519Signature
+    );
+}
+
+#[rstest]
+fn only_announcer_can_send_heartbeat(mut setup: TestSetup) {
+    let announcer = Addr::unchecked("announcer");
+    let service =
+        setup.sign_and_announce_net_req(&NymAddress::new("nymAddress"), &announcer, &nyms(100));
+
+    let not_announcer = Addr::unchecked("timmy");
+    let payload = setup.heartbeat_payload_to_sign(&not_announcer, 1);
+    let signature = service.sign_heartbeat(payload);
+    let err = setup
+        .try_heartbeat(1, signature, &not_announcer)
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<SpContractError>().unwrap(),
+        SpContractError::Unauthorized {
+            sender: not_announcer
+        }
+    );
+}
+
+#[rstest]
+fn update_metadata(mut setup: TestSetup) {
+    let announcer = Addr::unchecked("announcer");
+    setup.sign_and_announce_net_req(&NymAddress::new("nymAddress"), &announcer, &nyms(100));
+    assert_eq!(setup.query_id(1).metadata, ServiceMetadata::default());
+
+    let metadata = ServiceMetadata {
+        supported_interface_versions: vec![1],
+        supported_protocol_versions: vec![3, 4],
+        allowed_hosts: AllowedHostsPolicy::StandardList,
+        declared_bandwidth: Some(1_000_000),
+    };
+
+    let err = setup
+        .try_update_metadata(1, metadata.clone(), &Addr::unchecked("timmy"))
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<SpContractError>().unwrap(),
+        SpContractError::Unauthorized {
+            sender: Addr::unchecked("timmy")
+        }
+    );
+
+    setup
+        .try_update_metadata(1, metadata.clone(), &announcer)
+        .unwrap();
+    assert_eq!(setup.query_id(1).metadata, metadata);
+
+    // The service can still be found through the nym address index
+    assert_eq!(setup.query_all().services.len(), 1);
+}
@@ -2,6 +2,7 @@
 
 mod announce;
 mod delete;
+mod heartbeat;
 mod query;
 mod service_id;
 mod test_service;
@@ -1,7 +1,8 @@
 use nym_contracts_common::{signing::MessageSignature, IdentityKey};
 use nym_crypto::asymmetric::identity;
 use nym_service_provider_directory_common::{
-    signing_types::SignableServiceProviderAnnounceMsg, NymAddress, ServiceDetails, ServiceType,
+    signing_types::{SignableServiceProviderAnnounceMsg, SignableServiceProviderHeartbeatMsg},
+    NymAddress, ServiceDetails, ServiceType,
 };
 use rand_chacha::ChaCha20Rng;
 
@@ -66,6 +67,10 @@ impl SignedTestService {
     pub fn details(&self) -> &ServiceDetails {
         &self.service
     }
+
+    pub fn sign_heartbeat(&self, payload: SignableServiceProviderHeartbeatMsg) -> MessageSignature {
+        ed25519_sign_message(payload, self.keys.private_key())
+    }
 }
 
 impl From<SignedTestService> for ServiceDetails {
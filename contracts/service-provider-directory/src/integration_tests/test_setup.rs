@@ -1,14 +1,16 @@
 use anyhow::Result;
 use cosmwasm_std::{coins, Addr, Coin, Uint128};
 use cw_multi_test::{App, AppBuilder, AppResponse, ContractWrapper, Executor};
-use nym_contracts_common::signing::Nonce;
+use nym_contracts_common::signing::{MessageSignature, Nonce};
 use nym_service_provider_directory_common::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     response::{ConfigResponse, PagedServicesListResponse},
     signing_types::{
-        construct_service_provider_announce_sign_payload, SignableServiceProviderAnnounceMsg,
+        construct_service_provider_announce_sign_payload,
+        construct_service_provider_heartbeat_sign_payload, SignableServiceProviderAnnounceMsg,
+        SignableServiceProviderHeartbeatMsg,
     },
-    NymAddress, Service, ServiceDetails, ServiceId,
+    NymAddress, Service, ServiceDetails, ServiceId, ServiceMetadata,
 };
 use rand_chacha::ChaCha20Rng;
 use serde::de::DeserializeOwned;
@@ -227,6 +229,78 @@ impl TestSetup {
             .unwrap()
     }
 
+    pub fn block_time(&self) -> u64 {
+        self.app.block_info().time.seconds()
+    }
+
+    pub fn advance_time(&mut self, secs: u64) {
+        self.app
+            .update_block(|block| block.time = block.time.plus_seconds(secs));
+    }
+
+    // Create heartbeat payload for the service operator to sign
+    pub fn heartbeat_payload_to_sign(
+        &mut self,
+        announcer: &Addr,
+        service_id: ServiceId,
+    ) -> SignableServiceProviderHeartbeatMsg {
+        let nonce = self.query_signing_nonce(announcer.to_string());
+        construct_service_provider_heartbeat_sign_payload(nonce, announcer.clone(), service_id)
+    }
+
+    pub fn try_heartbeat(
+        &mut self,
+        service_id: ServiceId,
+        signature: MessageSignature,
+        announcer: &Addr,
+    ) -> Result<AppResponse> {
+        self.app.execute_contract(
+            announcer.clone(),
+            self.addr.clone(),
+            &ExecuteMsg::Heartbeat {
+                service_id,
+                signature,
+            },
+            &[],
+        )
+    }
+
+    // Convenience function for signing a heartbeat and sending it
+    pub fn heartbeat(
+        &mut self,
+        service: &SignedTestService,
+        service_id: ServiceId,
+        announcer: &Addr,
+    ) -> AppResponse {
+        let payload = self.heartbeat_payload_to_sign(announcer, service_id);
+        let signature = service.sign_heartbeat(payload);
+        let resp = self
+            .try_heartbeat(service_id, signature, announcer)
+            .unwrap();
+        assert_eq!(
+            get_app_attribute(&resp, "wasm-heartbeat", "action"),
+            "heartbeat"
+        );
+        resp
+    }
+
+    pub fn try_update_metadata(
+        &mut self,
+        service_id: ServiceId,
+        metadata: ServiceMetadata,
+        announcer: &Addr,
+    ) -> Result<AppResponse> {
+        self.app.execute_contract(
+            announcer.clone(),
+            self.addr.clone(),
+            &ExecuteMsg::UpdateMetadata {
+                service_id,
+                metadata,
+            },
+            &[],
+        )
+    }
+
     pub fn balance(&self, address: impl Into<String>) -> Coin {
         self.app.wrap().query_balance(address, DENOM).unwrap()
     }
@@ -46,6 +46,8 @@ mod tests {
                     announcer: Addr::unchecked("timmy"),
                     block_height: 12345,
                     deposit: nyms(100),
+                    metadata: Default::default(),
+                    last_heartbeat: None,
                 },
                 Service {
                     service_id: 2,
@@ -53,6 +55,8 @@ mod tests {
                     announcer: Addr::unchecked("timmy"),
                     block_height: 12345,
                     deposit: nyms(100),
+                    metadata: Default::default(),
+                    last_heartbeat: None,
                 },
                 Service {
                     service_id: 3,
@@ -60,6 +64,8 @@ mod tests {
                     announcer: Addr::unchecked("timmy"),
                     block_height: 12345,
                     deposit: nyms(100),
+                    metadata: Default::default(),
+                    last_heartbeat: None,
                 },
             ],
         );
@@ -84,6 +90,8 @@ mod tests {
                 announcer: Addr::unchecked("timmy"),
                 block_height: 12345,
                 deposit: nyms(100),
+                metadata: Default::default(),
+                last_heartbeat: None,
             }],
         );
 
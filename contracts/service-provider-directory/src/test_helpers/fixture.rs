@@ -27,6 +27,8 @@ pub fn new_service(
         announcer: announcer.clone(),
         block_height: 12345,
         deposit: nyms(100),
+        metadata: Default::default(),
+        last_heartbeat: None,
     }
 }
 
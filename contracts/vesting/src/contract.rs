@@ -102,9 +102,16 @@ pub fn execute(
             try_track_reward(deps, info, amount, &address)
         }
         ExecuteMsg::ClaimOperatorReward {} => try_claim_operator_reward(deps, info),
+        ExecuteMsg::ClaimGatewayOperatorReward {} => try_claim_gateway_operator_reward(deps, info),
         ExecuteMsg::ClaimDelegatorReward { mix_id } => {
             try_claim_delegator_reward(deps, info, mix_id)
         }
+        ExecuteMsg::SetOperatorRewardCompounding { enabled } => {
+            try_set_operator_reward_compounding(deps, info, enabled)
+        }
+        ExecuteMsg::SetDelegatorRewardCompounding { mix_id, enabled } => {
+            try_set_delegator_reward_compounding(deps, info, mix_id, enabled)
+        }
         ExecuteMsg::UpdateMixnodeConfig { new_config } => {
             try_update_mixnode_config(new_config, info, deps)
         }
@@ -123,6 +130,12 @@ pub fn execute(
             mix_id,
             on_behalf_of,
         } => try_undelegate_from_mixnode(mix_id, on_behalf_of, info, deps),
+        ExecuteMsg::RedelegateToMixnode {
+            from_mix_id,
+            to_mix_id,
+            amount,
+            on_behalf_of,
+        } => try_redelegate_to_mixnode(from_mix_id, to_mix_id, amount, on_behalf_of, info, deps),
         ExecuteMsg::CreateAccount {
             owner_address,
             staking_address,
@@ -145,6 +158,12 @@ pub fn execute(
             mix_id,
             amount,
         } => try_track_undelegation(&owner, mix_id, amount, info, deps),
+        ExecuteMsg::TrackRedelegation {
+            owner,
+            from_mix_id,
+            to_mix_id,
+            amount,
+        } => try_track_redelegation(&owner, from_mix_id, to_mix_id, amount, info, env, deps),
         ExecuteMsg::BondMixnode {
             mix_node,
             cost_params,
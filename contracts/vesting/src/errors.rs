@@ -49,6 +49,13 @@ pub enum ContractError {
     #[error("VESTING ({}): No delegations found for account {0}, mix_identity {1}", line!())]
     NoSuchDelegation(Addr, MixId),
 
+    #[error("VESTING ({}): Attempted to redelegate {requested} from mixnode {mix_id} while only {available} has been delegated to it", line!())]
+    InsufficientDelegation {
+        mix_id: MixId,
+        requested: Coin,
+        available: Uint128,
+    },
+
     #[error("VESTING ({}): Only mixnet contract can perform this operation, got {0}", line!())]
     NotMixnetContract(Addr),
 
@@ -10,6 +10,12 @@ use mixnet_contract_common::{
 pub trait MixnodeBondingAccount {
     fn try_claim_operator_reward(&self, storage: &dyn Storage) -> Result<Response, ContractError>;
 
+    fn try_set_operator_reward_compounding(
+        &self,
+        enabled: bool,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError>;
+
     fn try_bond_mixnode(
         &self,
         mix_node: MixNode,
@@ -61,6 +67,11 @@ pub trait MixnodeBondingAccount {
 }
 
 pub trait GatewayBondingAccount {
+    fn try_claim_gateway_operator_reward(
+        &self,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError>;
+
     fn try_bond_gateway(
         &self,
         gateway: Gateway,
@@ -9,6 +9,13 @@ pub trait DelegatingAccount {
         storage: &dyn Storage,
     ) -> Result<Response, ContractError>;
 
+    fn try_set_delegator_reward_compounding(
+        &self,
+        mix_id: MixId,
+        enabled: bool,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError>;
+
     fn try_delegate_to_mixnode(
         &self,
         mix_id: MixId,
@@ -23,6 +30,14 @@ pub trait DelegatingAccount {
         storage: &dyn Storage,
     ) -> Result<Response, ContractError>;
 
+    fn try_redelegate_to_mixnode(
+        &self,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError>;
+
     // track_delegation performs internal vesting accounting necessary when
     // delegating from a vesting account. It accepts the current block height, the
     // delegation amount and balance of all coins whose denomination exists in
@@ -44,4 +59,16 @@ pub trait DelegatingAccount {
         amount: Coin,
         storage: &mut dyn Storage,
     ) -> Result<(), ContractError>;
+
+    // track_redelegation performs internal vesting accounting necessary when a
+    // vesting account moves (part of) its delegation between mixnodes.
+    // The account balance is not affected.
+    fn track_redelegation(
+        &self,
+        block_timestamp_secs: u64,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError>;
 }
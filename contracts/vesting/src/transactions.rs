@@ -20,7 +20,8 @@ use vesting_contract_common::events::{
     new_ownership_transfer_event, new_periodic_vesting_account_event,
     new_staking_address_update_event, new_track_gateway_unbond_event,
     new_track_mixnode_pledge_decrease_event, new_track_mixnode_unbond_event,
-    new_track_reward_event, new_track_undelegation_event, new_vested_coins_withdraw_event,
+    new_track_redelegation_event, new_track_reward_event, new_track_undelegation_event,
+    new_vested_coins_withdraw_event,
 };
 use vesting_contract_common::messages::VestingSpecification;
 use vesting_contract_common::PledgeCap;
@@ -360,6 +361,31 @@ pub fn try_track_undelegation(
     Ok(Response::new().add_event(new_track_undelegation_event()))
 }
 
+/// Track redelegation, invoked by the mixnet contract after sucessful redelegation, message contains the moved delegation principal.
+pub fn try_track_redelegation(
+    address: &str,
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    amount: Coin,
+    info: MessageInfo,
+    env: Env,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    if info.sender != MIXNET_CONTRACT_ADDRESS.load(deps.storage)? {
+        return Err(ContractError::NotMixnetContract(info.sender));
+    }
+    let account = account_from_address(address, deps.storage, deps.api)?;
+
+    account.track_redelegation(
+        env.block.time.seconds(),
+        from_mix_id,
+        to_mix_id,
+        amount,
+        deps.storage,
+    )?;
+    Ok(Response::new().add_event(new_track_redelegation_event()))
+}
+
 /// Delegate to mixnode, sends [mixnet_contract_common::ExecuteMsg::DelegateToMixnodeOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS]..
 pub fn try_delegate_to_mixnode(
     mix_id: MixId,
@@ -402,6 +428,15 @@ pub fn try_claim_operator_reward(
     account.try_claim_operator_reward(deps.storage)
 }
 
+/// Claims gateway operator reward, sends [mixnet_contract_common::ExecuteMsg::WithdrawGatewayOperatorRewardOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS].
+pub fn try_claim_gateway_operator_reward(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    account.try_claim_gateway_operator_reward(deps.storage)
+}
+
 /// Claims delegator reward, sends [mixnet_contract_common::ExecuteMsg::ClaimDelegatorRewardOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS].
 pub fn try_claim_delegator_reward(
     deps: DepsMut<'_>,
@@ -413,6 +448,27 @@ pub fn try_claim_delegator_reward(
     account.try_claim_delegator_reward(mix_id, deps.storage)
 }
 
+/// Sets operator reward compounding, sends [mixnet_contract_common::ExecuteMsg::SetOperatorRewardCompoundingOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS].
+pub fn try_set_operator_reward_compounding(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    account.try_set_operator_reward_compounding(enabled, deps.storage)
+}
+
+/// Sets delegator reward compounding, sends [mixnet_contract_common::ExecuteMsg::SetDelegatorRewardCompoundingOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS].
+pub fn try_set_delegator_reward_compounding(
+    deps: DepsMut<'_>,
+    info: MessageInfo,
+    mix_id: MixId,
+    enabled: bool,
+) -> Result<Response, ContractError> {
+    let account = account_from_address(info.sender.as_str(), deps.storage, deps.api)?;
+    account.try_set_delegator_reward_compounding(mix_id, enabled, deps.storage)
+}
+
 /// Undelegates from a mixnode, sends [mixnet_contract_common::ExecuteMsg::UndelegateFromMixnodeOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS].
 pub fn try_undelegate_from_mixnode(
     mix_id: MixId,
@@ -433,6 +489,31 @@ pub fn try_undelegate_from_mixnode(
     account.try_undelegate_from_mixnode(mix_id, deps.storage)
 }
 
+/// Moves delegation between mixnodes, sends [mixnet_contract_common::ExecuteMsg::RedelegateToMixnodeOnBehalf] to [crate::storage::MIXNET_CONTRACT_ADDRESS].
+pub fn try_redelegate_to_mixnode(
+    from_mix_id: MixId,
+    to_mix_id: MixId,
+    amount: Coin,
+    on_behalf_of: Option<String>,
+    info: MessageInfo,
+    deps: DepsMut<'_>,
+) -> Result<Response, ContractError> {
+    let mix_denom = MIX_DENOM.load(deps.storage)?;
+    let amount = validate_funds(&[amount], mix_denom)?;
+
+    let account = match on_behalf_of {
+        Some(account_owner) => {
+            let account = account_from_address(&account_owner, deps.storage, deps.api)?;
+            ensure_staking_permission(&info.sender, &account)?;
+            account
+        }
+        // you're the owner, you can do what you want
+        None => account_from_address(info.sender.as_str(), deps.storage, deps.api)?,
+    };
+
+    account.try_redelegate_to_mixnode(from_mix_id, to_mix_id, amount, deps.storage)
+}
+
 /// Creates a new periodic vesting account, and deposits funds to vest into the contract.
 ///
 /// Callable by ADMIN only, see [instantiate].
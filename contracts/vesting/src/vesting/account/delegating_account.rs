@@ -7,7 +7,7 @@ use cosmwasm_std::{wasm_execute, Coin, Env, Response, Storage, Uint128};
 use mixnet_contract_common::ExecuteMsg as MixnetExecuteMsg;
 use mixnet_contract_common::MixId;
 use vesting_contract_common::events::{
-    new_vesting_delegation_event, new_vesting_undelegation_event,
+    new_vesting_delegation_event, new_vesting_redelegation_event, new_vesting_undelegation_event,
 };
 
 use super::Account;
@@ -29,6 +29,26 @@ impl DelegatingAccount for Account {
         Ok(Response::new().add_message(compound_delegator_reward_msg))
     }
 
+    // note: the vesting contract doesn't need to track compounded rewards,
+    // they're accounted for alongside the rest of the delegation upon undelegating
+    fn try_set_delegator_reward_compounding(
+        &self,
+        mix_id: MixId,
+        enabled: bool,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError> {
+        let msg = MixnetExecuteMsg::SetDelegatorRewardCompoundingOnBehalf {
+            mix_id,
+            enabled,
+            owner: self.owner_address().into_string(),
+        };
+
+        let set_compounding_msg =
+            wasm_execute(MIXNET_CONTRACT_ADDRESS.load(storage)?, &msg, vec![])?;
+
+        Ok(Response::new().add_message(set_compounding_msg))
+    }
+
     fn try_delegate_to_mixnode(
         &self,
         mix_id: MixId,
@@ -95,6 +115,56 @@ impl DelegatingAccount for Account {
             .add_event(new_vesting_undelegation_event()))
     }
 
+    fn try_redelegate_to_mixnode(
+        &self,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError> {
+        if !self.any_delegation_for_mix(from_mix_id, storage) {
+            return Err(ContractError::NoSuchDelegation(
+                self.owner_address(),
+                from_mix_id,
+            ));
+        }
+
+        let available = self.total_delegations_for_mix(from_mix_id, storage)?;
+        if available < amount.amount {
+            return Err(ContractError::InsufficientDelegation {
+                mix_id: from_mix_id,
+                requested: amount,
+                available,
+            });
+        }
+
+        // the tracked entries are going to get created for the target node upon the redelegation
+        // getting executed so make sure we wouldn't exceed the limit
+        let num_subdelegations = self.num_subdelegations_for_mix(to_mix_id, storage);
+        if num_subdelegations >= MAX_PER_MIX_DELEGATIONS {
+            return Err(ContractError::TooManyDelegations {
+                address: self.owner_address.clone(),
+                acc_id: self.storage_key(),
+                mix_id: to_mix_id,
+                num: num_subdelegations,
+                cap: MAX_PER_MIX_DELEGATIONS,
+            });
+        }
+
+        let msg = MixnetExecuteMsg::RedelegateToMixnodeOnBehalf {
+            from_mix_id,
+            to_mix_id,
+            amount,
+            delegate: self.owner_address().into_string(),
+        };
+        let redelegate_to_mixnode =
+            wasm_execute(MIXNET_CONTRACT_ADDRESS.load(storage)?, &msg, vec![])?;
+
+        Ok(Response::new()
+            .add_message(redelegate_to_mixnode)
+            .add_event(new_vesting_redelegation_event()))
+    }
+
     fn track_delegation(
         &self,
         block_timestamp_secs: u64,
@@ -124,4 +194,35 @@ impl DelegatingAccount for Account {
         self.save_balance(new_balance, storage)?;
         Ok(())
     }
+
+    fn track_redelegation(
+        &self,
+        block_timestamp_secs: u64,
+        from_mix_id: MixId,
+        to_mix_id: MixId,
+        amount: Coin,
+        storage: &mut dyn Storage,
+    ) -> Result<(), ContractError> {
+        // the mixnet contract never moves more than what has been delegated,
+        // but make sure we don't end up with inconsistent tracking regardless
+        let total = self.total_delegations_for_mix(from_mix_id, storage)?;
+        let moved = amount.amount.min(total);
+        let remaining = total - moved;
+
+        // collapse all the entries for the source node into (at most) a single one
+        self.remove_delegations_for_mix(from_mix_id, storage)?;
+        if !remaining.is_zero() {
+            save_delegation(
+                (self.storage_key(), from_mix_id, block_timestamp_secs),
+                remaining,
+                storage,
+            )?;
+        }
+        save_delegation(
+            (self.storage_key(), to_mix_id, block_timestamp_secs),
+            moved,
+            storage,
+        )?;
+        Ok(())
+    }
 }
@@ -15,6 +15,20 @@ use vesting_contract_common::events::{
 use super::Account;
 
 impl GatewayBondingAccount for Account {
+    fn try_claim_gateway_operator_reward(
+        &self,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError> {
+        let msg = MixnetExecuteMsg::WithdrawGatewayOperatorRewardOnBehalf {
+            owner: self.owner_address().into_string(),
+        };
+
+        let claim_gateway_operator_reward_msg =
+            wasm_execute(MIXNET_CONTRACT_ADDRESS.load(storage)?, &msg, vec![])?;
+
+        Ok(Response::new().add_message(claim_gateway_operator_reward_msg))
+    }
+
     fn try_bond_gateway(
         &self,
         gateway: Gateway,
@@ -29,6 +29,24 @@ impl MixnodeBondingAccount for Account {
         Ok(Response::new().add_message(compound_operator_reward_msg))
     }
 
+    // note: the vesting contract doesn't need to track compounded rewards,
+    // they're accounted for alongside the rest of the pledge upon unbonding
+    fn try_set_operator_reward_compounding(
+        &self,
+        enabled: bool,
+        storage: &dyn Storage,
+    ) -> Result<Response, ContractError> {
+        let msg = MixnetExecuteMsg::SetOperatorRewardCompoundingOnBehalf {
+            enabled,
+            owner: self.owner_address().into_string(),
+        };
+
+        let set_compounding_msg =
+            wasm_execute(MIXNET_CONTRACT_ADDRESS.load(storage)?, &msg, vec![])?;
+
+        Ok(Response::new().add_message(set_compounding_msg))
+    }
+
     fn try_bond_mixnode(
         &self,
         mix_node: MixNode,
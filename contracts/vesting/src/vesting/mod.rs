@@ -49,9 +49,9 @@ mod tests {
     use crate::vesting::{populate_vesting_periods, Account};
     use contracts_common::signing::MessageSignature;
     use cosmwasm_std::testing::{mock_env, mock_info};
-    use cosmwasm_std::{coin, coins, Addr, Coin, Timestamp, Uint128};
+    use cosmwasm_std::{coin, coins, Addr, Coin, DepsMut, Env, Timestamp, Uint128};
     use mixnet_contract_common::mixnode::MixNodeCostParams;
-    use mixnet_contract_common::{Gateway, MixNode, Percent};
+    use mixnet_contract_common::{Gateway, MixId, MixNode, Percent};
     use vesting_contract_common::messages::{ExecuteMsg, VestingSpecification};
     use vesting_contract_common::Period;
     use vesting_contract_common::PledgeCap;
@@ -730,6 +730,7 @@ mod tests {
             host: "1.1.1.1".to_string(),
             mix_port: 1789,
             clients_port: 9000,
+            clients_wss_port: None,
             location: "Sweden".to_string(),
             sphinx_key: "sphinx".to_string(),
             identity_key: "identity".to_string(),
@@ -851,4 +852,217 @@ mod tests {
             }
         );
     }
+
+    fn account_with_delegation(
+        mut deps: DepsMut<'_>,
+        env: &Env,
+        mix_id: MixId,
+        amount: u128,
+    ) -> Account {
+        let msg = ExecuteMsg::CreateAccount {
+            owner_address: "owner".to_string(),
+            staking_address: Some("staking".to_string()),
+            vesting_spec: None,
+            cap: Some(PledgeCap::Absolute(Uint128::from(100_000_000_000u128))),
+        };
+        let info = mock_info("admin", &coins(1_000_000_000_000, TEST_COIN_DENOM));
+        execute(deps.branch(), env.clone(), info, msg).unwrap();
+        let account = load_account(Addr::unchecked("owner"), deps.storage)
+            .unwrap()
+            .unwrap();
+
+        account
+            .try_delegate_to_mixnode(mix_id, coin(amount, TEST_COIN_DENOM), env, deps.storage)
+            .unwrap();
+        account
+    }
+
+    #[test]
+    fn test_redelegation_request() {
+        let mut deps = init_contract();
+        let env = mock_env();
+        let account = account_with_delegation(deps.as_mut(), &env, 1, 90_000_000_000);
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            ExecuteMsg::RedelegateToMixnode {
+                from_mix_id: 1,
+                to_mix_id: 2,
+                amount: coin(60_000_000_000, TEST_COIN_DENOM),
+                on_behalf_of: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+
+        // nothing is tracked until the mixnet contract actually executes the redelegation
+        assert_eq!(
+            account.total_delegations_for_mix(1, &deps.storage).unwrap(),
+            Uint128::new(90_000_000_000)
+        );
+        assert!(!account.any_delegation_for_mix(2, &deps.storage));
+
+        // there's no delegation towards mixnode 2
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            ExecuteMsg::RedelegateToMixnode {
+                from_mix_id: 2,
+                to_mix_id: 1,
+                amount: coin(60_000_000_000, TEST_COIN_DENOM),
+                on_behalf_of: None,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NoSuchDelegation(Addr::unchecked("owner"), 2)
+        );
+
+        // and we can't move more than what has been delegated
+        let err = execute(
+            deps.as_mut(),
+            env,
+            mock_info("owner", &[]),
+            ExecuteMsg::RedelegateToMixnode {
+                from_mix_id: 1,
+                to_mix_id: 2,
+                amount: coin(90_000_000_001, TEST_COIN_DENOM),
+                on_behalf_of: None,
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InsufficientDelegation {
+                mix_id: 1,
+                requested: coin(90_000_000_001, TEST_COIN_DENOM),
+                available: Uint128::new(90_000_000_000),
+            }
+        );
+    }
+
+    #[test]
+    fn test_redelegation_tracking() {
+        let mut deps = init_contract();
+        let mut env = mock_env();
+        let account = account_with_delegation(deps.as_mut(), &env, 1, 90_000_000_000);
+        env.block.time = env.block.time.plus_seconds(42);
+        account
+            .try_delegate_to_mixnode(
+                1,
+                coin(5_000_000_000, TEST_COIN_DENOM),
+                &env,
+                &mut deps.storage,
+            )
+            .unwrap();
+        let balance = account.load_balance(&deps.storage).unwrap();
+
+        account
+            .track_redelegation(
+                env.block.time.seconds(),
+                1,
+                2,
+                coin(60_000_000_000, TEST_COIN_DENOM),
+                &mut deps.storage,
+            )
+            .unwrap();
+
+        // the remaining entries got collapsed into a single one
+        assert_eq!(
+            account.total_delegations_for_mix(1, &deps.storage).unwrap(),
+            Uint128::new(35_000_000_000)
+        );
+        assert_eq!(account.num_subdelegations_for_mix(1, &deps.storage), 1);
+        assert_eq!(
+            account.total_delegations_for_mix(2, &deps.storage).unwrap(),
+            Uint128::new(60_000_000_000)
+        );
+        // and the tokens never left the delegations
+        assert_eq!(account.load_balance(&deps.storage).unwrap(), balance);
+
+        // we never move more than what has been tracked
+        account
+            .track_redelegation(
+                env.block.time.seconds(),
+                1,
+                2,
+                coin(100_000_000_000, TEST_COIN_DENOM),
+                &mut deps.storage,
+            )
+            .unwrap();
+        assert!(!account.any_delegation_for_mix(1, &deps.storage));
+        assert_eq!(
+            account.total_delegations_for_mix(2, &deps.storage).unwrap(),
+            Uint128::new(95_000_000_000)
+        );
+        assert_eq!(account.load_balance(&deps.storage).unwrap(), balance);
+    }
+
+    #[test]
+    fn test_redelegation_tracking_rollback() {
+        let mut deps = init_contract();
+        let env = mock_env();
+        let account = account_with_delegation(deps.as_mut(), &env, 1, 90_000_000_000);
+
+        let track =
+            |amount: u128, from_mix_id: MixId, to_mix_id: MixId| ExecuteMsg::TrackRedelegation {
+                owner: "owner".to_string(),
+                from_mix_id,
+                to_mix_id,
+                amount: coin(amount, TEST_COIN_DENOM),
+            };
+
+        // only the mixnet contract is allowed to update the tracking, anyone else is rejected
+        // without anything getting modified
+        let err = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("owner", &[]),
+            track(60_000_000_000, 1, 2),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::NotMixnetContract(Addr::unchecked("owner"))
+        );
+        assert_eq!(
+            account.total_delegations_for_mix(1, &deps.storage).unwrap(),
+            Uint128::new(90_000_000_000)
+        );
+        assert!(!account.any_delegation_for_mix(2, &deps.storage));
+
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("test", &[]),
+            track(60_000_000_000, 1, 2),
+        )
+        .unwrap();
+        assert_eq!(
+            account.total_delegations_for_mix(1, &deps.storage).unwrap(),
+            Uint128::new(30_000_000_000)
+        );
+
+        // moving the tokens back restores the original tracking
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info("test", &[]),
+            track(60_000_000_000, 2, 1),
+        )
+        .unwrap();
+        assert_eq!(
+            account.total_delegations_for_mix(1, &deps.storage).unwrap(),
+            Uint128::new(90_000_000_000)
+        );
+        assert!(!account.any_delegation_for_mix(2, &deps.storage));
+        assert_eq!(
+            account.load_balance(&deps.storage).unwrap(),
+            Uint128::new(910_000_000_000)
+        );
+    }
 }
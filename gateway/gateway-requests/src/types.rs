@@ -138,6 +138,11 @@ pub enum ClientControlRequest {
         iv: Vec<u8>,
     },
     ClaimFreeTestnetBandwidth,
+    /// Requests the gateway to start pushing dummy messages to the client, on average,
+    /// every `average_delay_ms`, so that its receive pattern would not reveal its actual traffic.
+    ReceiveCoverTraffic {
+        average_delay_ms: u64,
+    },
 }
 
 impl ClientControlRequest {
@@ -224,9 +229,22 @@ pub enum ServerResponse {
     Send {
         remaining_bandwidth: i64,
     },
+    /// Confirms the receive cover traffic has been enabled with the specified average delay,
+    /// which might be higher than the requested one.
+    ReceiveCoverTraffic {
+        average_delay_ms: u64,
+    },
     Error {
         message: String,
     },
+    /// Indicates the gateway is about to go offline (for example, due to planned maintenance)
+    /// and it's no longer accepting new registrations.
+    /// Any messages for the client received from now on will be put in its inbox storage.
+    ShuttingDown {
+        /// Optional identity key of a gateway the clients are recommended to move to.
+        #[serde(default)]
+        suggested_gateway: Option<String>,
+    },
 }
 
 impl ServerResponse {
@@ -236,10 +254,18 @@ impl ServerResponse {
         }
     }
 
+    pub fn new_shutting_down(suggested_gateway: Option<String>) -> Self {
+        ServerResponse::ShuttingDown { suggested_gateway }
+    }
+
     pub fn is_error(&self) -> bool {
         matches!(self, ServerResponse::Error { .. })
     }
 
+    pub fn is_shutting_down(&self) -> bool {
+        matches!(self, ServerResponse::ShuttingDown { .. })
+    }
+
     pub fn implies_successful_authentication(&self) -> bool {
         match self {
             ServerResponse::Authenticate { status, .. } => *status,
@@ -412,4 +438,28 @@ mod tests {
             _ => unreachable!("this branch shouldn't have been reached!"),
         }
     }
+
+    #[test]
+    fn shutting_down_response_can_be_deserialized_without_suggested_gateway() {
+        let serialized = r#"{"type":"shuttingDown"}"#.to_string();
+        let deserialized = ServerResponse::try_from(serialized).unwrap();
+
+        match deserialized {
+            ServerResponse::ShuttingDown { suggested_gateway } => {
+                assert!(suggested_gateway.is_none())
+            }
+            _ => unreachable!("this branch shouldn't have been reached!"),
+        }
+
+        let response = ServerResponse::new_shutting_down(Some("foomp".to_string()));
+        let serialized = serde_json::to_string(&response).unwrap();
+        let deserialized = ServerResponse::try_from(serialized).unwrap();
+
+        match deserialized {
+            ServerResponse::ShuttingDown { suggested_gateway } => {
+                assert_eq!(suggested_gateway, Some("foomp".to_string()))
+            }
+            _ => unreachable!("this branch shouldn't have been reached!"),
+        }
+    }
 }
@@ -32,6 +32,18 @@ pub struct Init {
     #[clap(long)]
     clients_port: Option<u16>,
 
+    /// The port on which the gateway will be listening for clients gateway-requests over TLS (i.e. `wss://`)
+    #[clap(long)]
+    clients_wss_port: Option<u16>,
+
+    /// Path to the PEM encoded certificate chain used by the secure clients listener
+    #[clap(long)]
+    tls_certificate: Option<PathBuf>,
+
+    /// Path to the PEM encoded private key of the certificate used by the secure clients listener
+    #[clap(long)]
+    tls_private_key: Option<PathBuf>,
+
     /// Path to sqlite database containing all gateway persistent data
     #[clap(long)]
     datastore: Option<PathBuf>,
@@ -52,7 +64,9 @@ pub struct Init {
     // the alias here is included for backwards compatibility (1.1.4 and before)
     nyxd_urls: Option<Vec<url::Url>>,
 
-    /// Cosmos wallet mnemonic needed for double spending protection
+    /// Cosmos wallet mnemonic needed for double spending protection.
+    /// It is going to be stored in a separate, encrypted, secrets file rather than in the config.
+    /// If not provided, a fresh mnemonic is generated instead.
     #[clap(long)]
     mnemonic: Option<bip39::Mnemonic>,
 
@@ -79,6 +93,9 @@ impl From<Init> for OverrideConfig {
             host: Some(init_config.host),
             mix_port: init_config.mix_port,
             clients_port: init_config.clients_port,
+            clients_wss_port: init_config.clients_wss_port,
+            tls_certificate: init_config.tls_certificate,
+            tls_private_key: init_config.tls_private_key,
             datastore: init_config.datastore,
             nym_apis: init_config.nym_apis,
             mnemonic: init_config.mnemonic,
@@ -117,6 +134,15 @@ pub async fn execute(args: Init) -> Result<(), Box<dyn Error + Send + Sync>> {
     // Initialising the config structure is just overriding a default constructed one
     let config = override_config(Config::new(&args.id), override_config_fields)?;
 
+    // the gateway can't start without having a cosmos mnemonic available
+    if args.mnemonic.is_none() && !config.storage_paths.cosmos_bip39_mnemonic.exists() {
+        config.store_cosmos_mnemonic(&bip39::Mnemonic::generate(24).unwrap())?;
+        eprintln!(
+            "Saved a freshly generated cosmos mnemonic to {}",
+            config.storage_paths.cosmos_bip39_mnemonic.display()
+        );
+    }
+
     // if gateway was already initialised, don't generate new keys
     if !already_init {
         let mut rng = rand::rngs::OsRng;
@@ -165,17 +191,18 @@ pub async fn execute(args: Init) -> Result<(), Box<dyn Error + Send + Sync>> {
 mod tests {
     use nym_network_defaults::var_names::BECH32_PREFIX;
 
-    use crate::node::{storage::InMemStorage, Gateway};
-
     use super::*;
 
-    #[tokio::test]
-    async fn create_gateway_with_in_mem_storage() {
+    #[test]
+    fn init_arguments_override_the_default_config() {
         let args = Init {
             id: "foo-id".to_string(),
             host: "1.1.1.1".parse().unwrap(),
             mix_port: Some(42),
             clients_port: Some(43),
+            clients_wss_port: None,
+            tls_certificate: None,
+            tls_private_key: None,
             datastore: Some("/foo-datastore".parse().unwrap()),
             nym_apis: None,
             mnemonic: None,
@@ -190,17 +217,12 @@ mod tests {
         let config = Config::new(&args.id);
         let config = override_config(config, OverrideConfig::from(args.clone())).unwrap();
 
-        let (identity_keys, sphinx_keys) = {
-            let mut rng = rand::rngs::OsRng;
-            (
-                identity::KeyPair::new(&mut rng),
-                encryption::KeyPair::new(&mut rng),
-            )
-        };
-
-        // The test is really if this instantiates with InMemStorage without panics
-        let _gateway =
-            Gateway::new_from_keys_and_storage(config, identity_keys, sphinx_keys, InMemStorage)
-                .await;
+        assert_eq!(config.gateway.listening_address, args.host);
+        assert_eq!(config.gateway.mix_port, 42);
+        assert_eq!(config.gateway.clients_port, 43);
+        assert_eq!(
+            config.storage_paths.clients_storage,
+            PathBuf::from("/foo-datastore")
+        );
     }
 }
@@ -1,8 +1,10 @@
 // Copyright 2020-2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::config::default_config_filepath;
 use crate::config::old_config_v1_1_20::ConfigV1_1_20;
+use crate::config::old_config_v1_1_22::ConfigV1_1_22;
+use crate::config::persistence::paths::DEFAULT_COSMOS_MNEMONIC_FILENAME;
+use crate::config::{default_config_filepath, default_data_directory};
 use crate::error::GatewayError;
 use crate::{config::Config, Cli};
 use clap::CommandFactory;
@@ -54,6 +56,9 @@ pub(crate) struct OverrideConfig {
     host: Option<IpAddr>,
     mix_port: Option<u16>,
     clients_port: Option<u16>,
+    clients_wss_port: Option<u16>,
+    tls_certificate: Option<PathBuf>,
+    tls_private_key: Option<PathBuf>,
     datastore: Option<PathBuf>,
     enabled_statistics: Option<bool>,
     statistics_service_url: Option<url::Url>,
@@ -86,6 +91,9 @@ pub(crate) fn override_config(
         .with_optional(Config::with_listening_address, args.host)
         .with_optional(Config::with_mix_port, args.mix_port)
         .with_optional(Config::with_clients_port, args.clients_port)
+        .with_optional(Config::with_clients_wss_port, args.clients_wss_port)
+        .with_optional(Config::with_tls_certificate_path, args.tls_certificate)
+        .with_optional(Config::with_tls_private_key_path, args.tls_private_key)
         .with_optional_custom_env(
             Config::with_custom_nym_apis,
             args.nym_apis,
@@ -99,7 +107,6 @@ pub(crate) fn override_config(
             STATISTICS_SERVICE_DOMAIN_ADDRESS,
         )
         .with_optional(Config::with_custom_persistent_store, args.datastore)
-        .with_optional(Config::with_cosmos_mnemonic, args.mnemonic)
         .with_optional_custom_env(
             Config::with_custom_validator_nyxd,
             args.nyxd_urls,
@@ -111,6 +118,11 @@ pub(crate) fn override_config(
             args.only_coconut_credentials,
         );
 
+    // the mnemonic is not part of the config file, so it has to be persisted separately
+    if let Some(mnemonic) = args.mnemonic {
+        config.store_cosmos_mnemonic(&mnemonic)?;
+    }
+
     Ok(config)
 }
 
@@ -153,6 +165,37 @@ pub(crate) fn ensure_config_version_compatibility(cfg: &Config) -> Result<(), Ga
     }
 }
 
+// moves the mnemonic out of the config file into the secrets file at the provided location
+fn migrate_v1_1_22_config(
+    old_config: ConfigV1_1_22,
+    mnemonic_path: PathBuf,
+) -> Result<Config, GatewayError> {
+    let mnemonic = old_config.gateway.cosmos_mnemonic.clone();
+    let mut updated: Config = old_config.into();
+    updated.storage_paths.cosmos_bip39_mnemonic = mnemonic_path;
+
+    updated.store_cosmos_mnemonic(&mnemonic)?;
+    info!(
+        "The cosmos mnemonic got moved to {}",
+        updated.storage_paths.cosmos_bip39_mnemonic.display()
+    );
+    Ok(updated)
+}
+
+fn upgrade_from_v1_1_22_config(id: &str, old_config: ConfigV1_1_22) -> Result<(), GatewayError> {
+    // make sure the mnemonic is safely stored before overwriting the config file
+    let mnemonic_path = default_data_directory(id).join(DEFAULT_COSMOS_MNEMONIC_FILENAME);
+    let updated = migrate_v1_1_22_config(old_config, mnemonic_path)?;
+
+    updated
+        .save_to_default_location()
+        .map_err(|err| GatewayError::ConfigSaveFailure {
+            path: default_config_filepath(id),
+            id: id.to_string(),
+            source: err,
+        })
+}
+
 fn try_upgrade_v1_1_20_config(id: &str) -> Result<(), GatewayError> {
     use nym_config::legacy_helpers::nym_config::MigrationNymConfig;
 
@@ -165,18 +208,27 @@ fn try_upgrade_v1_1_20_config(id: &str) -> Result<(), GatewayError> {
     info!("It seems the gateway is using <= v1.1.20 config template.");
     info!("It is going to get updated to the current specification.");
 
-    let updated: Config = old_config.into();
-    updated
-        .save_to_default_location()
-        .map_err(|err| GatewayError::ConfigSaveFailure {
-            path: default_config_filepath(id),
-            id: id.to_string(),
-            source: err,
-        })
+    upgrade_from_v1_1_22_config(id, old_config.into())
+}
+
+fn try_upgrade_v1_1_22_config(id: &str) -> Result<(), GatewayError> {
+    use nym_config::legacy_helpers::nym_config::MigrationNymConfig;
+
+    // explicitly load it as v1.1.22 (which still contained the mnemonic in the config file)
+    let Ok(old_config) = ConfigV1_1_22::load_from_file(id) else {
+        // if we failed to load it, there might have been nothing to upgrade
+        // or maybe it was an even older file. in either way. just ignore it and carry on with our day
+        return Ok(());
+    };
+    info!("It seems the gateway is using <= v1.1.22 config template.");
+    info!("It is going to get updated to the current specification.");
+
+    upgrade_from_v1_1_22_config(id, old_config)
 }
 
 pub(crate) fn try_load_current_config(id: &str) -> Result<Config, GatewayError> {
     try_upgrade_v1_1_20_config(id)?;
+    try_upgrade_v1_1_22_config(id)?;
 
     Config::read_from_default_path(id).map_err(|err| {
         error!(
@@ -199,4 +251,48 @@ mod tests {
     fn verify_cli() {
         Cli::command().debug_assert();
     }
+
+    #[test]
+    fn v1_1_22_config_migration_moves_the_mnemonic_out_of_the_config() {
+        use crate::config::old_config_v1_1_22::{GatewayPathsV1_1_22, GatewayV1_1_22};
+        use crate::config::COSMOS_MNEMONIC_ENV_VARS;
+
+        std::env::set_var(COSMOS_MNEMONIC_ENV_VARS.passphrase, "migration-passphrase");
+        let dir = tempfile::tempdir().unwrap();
+        let mnemonic_path = dir.path().join(DEFAULT_COSMOS_MNEMONIC_FILENAME);
+
+        let mnemonic = bip39::Mnemonic::generate(24).unwrap();
+        let current = Config::new("migrated-gateway");
+        let old_config = ConfigV1_1_22 {
+            gateway: GatewayV1_1_22 {
+                version: current.gateway.version.clone(),
+                id: current.gateway.id.clone(),
+                only_coconut_credentials: current.gateway.only_coconut_credentials,
+                listening_address: current.gateway.listening_address,
+                mix_port: current.gateway.mix_port,
+                clients_port: current.gateway.clients_port,
+                clients_wss_port: None,
+                tls_certificate_path: None,
+                tls_private_key_path: None,
+                enabled_statistics: current.gateway.enabled_statistics,
+                statistics_service_url: current.gateway.statistics_service_url.clone(),
+                nym_api_urls: current.gateway.nym_api_urls.clone(),
+                nyxd_urls: current.gateway.nyxd_urls.clone(),
+                cosmos_mnemonic: mnemonic.clone(),
+            },
+            storage_paths: GatewayPathsV1_1_22 {
+                keys: current.storage_paths.keys.clone(),
+                clients_storage: current.storage_paths.clients_storage.clone(),
+            },
+            logging: Default::default(),
+            debug: Default::default(),
+        };
+
+        let migrated = migrate_v1_1_22_config(old_config, mnemonic_path.clone()).unwrap();
+        assert_eq!(migrated.storage_paths.cosmos_bip39_mnemonic, mnemonic_path);
+
+        let stored = std::fs::read_to_string(&mnemonic_path).unwrap();
+        assert!(!stored.contains(&mnemonic.to_string()));
+        assert_eq!(migrated.get_cosmos_mnemonic().unwrap(), mnemonic);
+    }
 }
@@ -28,6 +28,18 @@ pub struct Run {
     #[clap(long)]
     clients_port: Option<u16>,
 
+    /// The port on which the gateway will be listening for clients gateway-requests over TLS (i.e. `wss://`)
+    #[clap(long)]
+    clients_wss_port: Option<u16>,
+
+    /// Path to the PEM encoded certificate chain used by the secure clients listener
+    #[clap(long)]
+    tls_certificate: Option<PathBuf>,
+
+    /// Path to the PEM encoded private key of the certificate used by the secure clients listener
+    #[clap(long)]
+    tls_private_key: Option<PathBuf>,
+
     /// Path to sqlite database containing all gateway persistent data
     #[clap(long)]
     datastore: Option<PathBuf>,
@@ -48,7 +60,7 @@ pub struct Run {
     // the alias here is included for backwards compatibility (1.1.4 and before)
     nyxd_urls: Option<Vec<url::Url>>,
 
-    /// Cosmos wallet mnemonic
+    /// Cosmos wallet mnemonic. It is going to replace the one stored in the encrypted secrets file
     #[clap(long)]
     mnemonic: Option<bip39::Mnemonic>,
 
@@ -65,6 +77,11 @@ pub struct Run {
     #[clap(long)]
     statistics_service_url: Option<url::Url>,
 
+    /// Identity key of the gateway that is going to be suggested to all connected clients
+    /// once this gateway starts shutting down
+    #[clap(long)]
+    replacement_gateway: Option<String>,
+
     #[clap(short, long, default_value_t = OutputFormat::default())]
     output: OutputFormat,
 }
@@ -75,6 +92,9 @@ impl From<Run> for OverrideConfig {
             host: run_config.host,
             mix_port: run_config.mix_port,
             clients_port: run_config.clients_port,
+            clients_wss_port: run_config.clients_wss_port,
+            tls_certificate: run_config.tls_certificate,
+            tls_private_key: run_config.tls_private_key,
             datastore: run_config.datastore,
             nym_apis: run_config.nym_apis,
             mnemonic: run_config.mnemonic,
@@ -102,6 +122,7 @@ pub async fn execute(args: Run) -> Result<(), Box<dyn Error + Send + Sync>> {
     eprintln!("Starting gateway {id}...");
 
     let output = args.output;
+    let replacement_gateway = args.replacement_gateway.clone();
     let config = build_config(id, args)?;
     ensure_config_version_compatibility(&config)?;
 
@@ -109,7 +130,9 @@ pub async fn execute(args: Run) -> Result<(), Box<dyn Error + Send + Sync>> {
         show_binding_warning(&config.gateway.listening_address.to_string());
     }
 
-    let mut gateway = crate::node::create_gateway(config).await;
+    let mut gateway = crate::node::create_gateway(config)
+        .await
+        .with_replacement_gateway(replacement_gateway);
     eprintln!(
         "\nTo bond your gateway you will need to install the Nym wallet, go to https://nymtech.net/get-involved and select the Download button.\n\
          Select the correct version and install it to your machine. You will need to provide the following: \n ");
@@ -1,6 +1,7 @@
 // Copyright 2020 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::commands::try_load_current_config;
 use crate::config::Config;
 use clap::Args;
 use nym_bin_common::version_checker::Version;
@@ -140,7 +141,8 @@ fn do_upgrade(mut config: Config, args: &Upgrade, package_version: Version) {
 pub async fn execute(args: &Upgrade) {
     let package_version = parse_package_version();
 
-    let existing_config = Config::read_from_default_path(&args.id).unwrap_or_else(|err| {
+    // this also migrates any legacy config files, such as the ones still containing the mnemonic
+    let existing_config = try_load_current_config(&args.id).unwrap_or_else(|err| {
         eprintln!("failed to load existing config file! - {err}");
         process::exit(1)
     });
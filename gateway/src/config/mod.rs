@@ -3,9 +3,11 @@
 
 use crate::config::persistence::paths::GatewayPaths;
 use crate::config::template::CONFIG_TEMPLATE;
+use crate::error::GatewayError;
 use nym_bin_common::logging::LoggingSettings;
 use nym_config::defaults::{DEFAULT_CLIENT_LISTENING_PORT, DEFAULT_MIX_LISTENING_PORT};
 use nym_config::helpers::inaddr_any;
+use nym_config::secrets::SecretEnvVars;
 use nym_config::{
     must_get_home, read_config_from_toml_file, save_formatted_config_to_file, NymConfigTemplate,
     DEFAULT_CONFIG_DIR, DEFAULT_CONFIG_FILENAME, DEFAULT_DATA_DIR, NYM_DIR,
@@ -17,9 +19,10 @@ use std::net::IpAddr;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use url::Url;
-use zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::Zeroizing;
 
-pub(crate) mod old_config_v1_1_20;
+pub mod old_config_v1_1_20;
+pub mod old_config_v1_1_22;
 pub mod persistence;
 mod template;
 
@@ -32,10 +35,28 @@ const DEFAULT_PACKET_FORWARDING_INITIAL_BACKOFF: Duration = Duration::from_milli
 const DEFAULT_PACKET_FORWARDING_MAXIMUM_BACKOFF: Duration = Duration::from_millis(300_000);
 const DEFAULT_INITIAL_CONNECTION_TIMEOUT: Duration = Duration::from_millis(1_500);
 const DEFAULT_MAXIMUM_CONNECTION_BUFFER_SIZE: usize = 2000;
+const DEFAULT_CLIENT_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+const DEFAULT_MAXIMUM_CONNECTIONS_PER_IP: u32 = 60;
+const DEFAULT_CONNECTION_RATE_WINDOW: Duration = Duration::from_secs(60);
+const DEFAULT_MAXIMUM_CLIENT_PACKETS_PER_SECOND: u32 = 500;
+const DEFAULT_FREE_BANDWIDTH_CLAIM_COOLDOWN: Duration = Duration::from_secs(60);
+const DEFAULT_AUTHENTICATION_FAILURES_BEFORE_BAN: u32 = 10;
+const DEFAULT_AUTHENTICATION_FAILURE_BAN_DURATION: Duration = Duration::from_secs(600);
+const DEFAULT_MINIMUM_RECEIVE_COVER_AVERAGE_DELAY: Duration = Duration::from_millis(100);
+const DEFAULT_NOISE_HANDSHAKE_TIMEOUT: Duration = Duration::from_millis(5_000);
+const DEFAULT_NOISE_KEYS_REFRESH_RATE: Duration = Duration::from_secs(60 * 5);
 
 const DEFAULT_STORED_MESSAGE_FILENAME_LENGTH: u16 = 16;
 const DEFAULT_MESSAGE_RETRIEVAL_LIMIT: i64 = 100;
 
+/// Environment variables that can be used for providing the cosmos mnemonic
+/// instead of (or for decrypting) the secrets file.
+pub const COSMOS_MNEMONIC_ENV_VARS: SecretEnvVars = SecretEnvVars {
+    value: "NYM_GATEWAY_MNEMONIC",
+    file: "NYM_GATEWAY_MNEMONIC_FILE",
+    passphrase: "NYM_GATEWAY_SECRETS_PASSPHRASE",
+};
+
 /// Derive default path to gateway's config directory.
 /// It should get resolved to `$HOME/.nym/gateways/<id>/config`
 pub fn default_config_directory<P: AsRef<Path>>(id: P) -> PathBuf {
@@ -134,11 +155,6 @@ impl Config {
         self
     }
 
-    pub fn with_cosmos_mnemonic(mut self, cosmos_mnemonic: bip39::Mnemonic) -> Self {
-        self.gateway.cosmos_mnemonic = cosmos_mnemonic;
-        self
-    }
-
     pub fn with_listening_address(mut self, listening_address: IpAddr) -> Self {
         self.gateway.listening_address = listening_address;
         self
@@ -154,6 +170,21 @@ impl Config {
         self
     }
 
+    pub fn with_clients_wss_port(mut self, port: u16) -> Self {
+        self.gateway.clients_wss_port = Some(port);
+        self
+    }
+
+    pub fn with_tls_certificate_path(mut self, path: PathBuf) -> Self {
+        self.gateway.tls_certificate_path = Some(path);
+        self
+    }
+
+    pub fn with_tls_private_key_path(mut self, path: PathBuf) -> Self {
+        self.gateway.tls_private_key_path = Some(path);
+        self
+    }
+
     pub fn with_custom_persistent_store(mut self, store_dir: PathBuf) -> Self {
         self.storage_paths.clients_storage = store_dir;
         self
@@ -164,6 +195,18 @@ impl Config {
         self
     }
 
+    /// Port of the secure clients listener, if it has been fully configured,
+    /// i.e. alongside the TLS certificate and its private key.
+    pub fn get_advertised_clients_wss_port(&self) -> Option<u16> {
+        if self.gateway.tls_certificate_path.is_some()
+            && self.gateway.tls_private_key_path.is_some()
+        {
+            self.gateway.clients_wss_port
+        } else {
+            None
+        }
+    }
+
     pub fn get_statistics_service_url(&self) -> Url {
         self.gateway.statistics_service_url.clone()
     }
@@ -176,13 +219,23 @@ impl Config {
         self.gateway.nyxd_urls.clone()
     }
 
-    pub fn get_cosmos_mnemonic(&self) -> bip39::Mnemonic {
-        self.gateway.cosmos_mnemonic.clone()
+    pub fn get_cosmos_mnemonic(&self) -> Result<bip39::Mnemonic, GatewayError> {
+        let raw = COSMOS_MNEMONIC_ENV_VARS
+            .load_secret(&self.storage_paths.cosmos_bip39_mnemonic)
+            .map_err(|source| GatewayError::CosmosMnemonicLoadFailure { source })?;
+        bip39::Mnemonic::parse(raw.as_str())
+            .map_err(|source| GatewayError::MalformedCosmosMnemonic { source })
+    }
+
+    pub fn store_cosmos_mnemonic(&self, mnemonic: &bip39::Mnemonic) -> Result<(), GatewayError> {
+        let raw = Zeroizing::new(mnemonic.to_string());
+        COSMOS_MNEMONIC_ENV_VARS
+            .store_secret(&self.storage_paths.cosmos_bip39_mnemonic, &raw)
+            .map_err(|source| GatewayError::CosmosMnemonicSaveFailure { source })
     }
 }
 
-// we only really care about the mnemonic being zeroized
-#[derive(Debug, Deserialize, PartialEq, Eq, Serialize, Zeroize, ZeroizeOnDrop)]
+#[derive(Debug, Deserialize, PartialEq, Eq, Serialize)]
 pub struct Gateway {
     /// Version of the gateway for which this configuration was created.
     pub version: String,
@@ -196,7 +249,6 @@ pub struct Gateway {
     pub only_coconut_credentials: bool,
 
     /// Address to which this mixnode will bind to and will be listening for packets.
-    #[zeroize(skip)]
     pub listening_address: IpAddr,
 
     /// Port used for listening for all mixnet traffic.
@@ -207,27 +259,33 @@ pub struct Gateway {
     /// (default: 9000)
     pub clients_port: u16,
 
+    /// If specified, port used for listening for client-related traffic over TLS (i.e. `wss://`).
+    /// It requires both `tls_certificate_path` and `tls_private_key_path` to be set,
+    /// otherwise the gateway is going to refuse to start.
+    #[serde(default)]
+    pub clients_wss_port: Option<u16>,
+
+    /// Path to the PEM encoded certificate chain presented to the clients connecting over TLS.
+    #[serde(default)]
+    pub tls_certificate_path: Option<PathBuf>,
+
+    /// Path to the PEM encoded private key corresponding to the TLS certificate.
+    #[serde(default)]
+    pub tls_private_key_path: Option<PathBuf>,
+
     /// Whether gateway collects and sends anonymized statistics
     pub enabled_statistics: bool,
 
     /// Domain address of the statistics service
-    #[zeroize(skip)]
     pub statistics_service_url: Url,
 
     /// Addresses to APIs from which the node gets the view of the network.
     #[serde(alias = "validator_api_urls")]
-    #[zeroize(skip)]
     pub nym_api_urls: Vec<Url>,
 
     /// Addresses to validators which the node uses to check for double spending of ERC20 tokens.
     #[serde(alias = "validator_nymd_urls")]
-    #[zeroize(skip)]
     pub nyxd_urls: Vec<Url>,
-
-    /// Mnemonic of a cosmos wallet used in checking for double spending.
-    // #[deprecated(note = "move to storage")]
-    // TODO: I don't think this should be stored directly in the config...
-    pub cosmos_mnemonic: bip39::Mnemonic,
 }
 
 impl Gateway {
@@ -239,13 +297,15 @@ impl Gateway {
             listening_address: inaddr_any(),
             mix_port: DEFAULT_MIX_LISTENING_PORT,
             clients_port: DEFAULT_CLIENT_LISTENING_PORT,
+            clients_wss_port: None,
+            tls_certificate_path: None,
+            tls_private_key_path: None,
             enabled_statistics: false,
             statistics_service_url: mainnet::STATISTICS_SERVICE_DOMAIN_ADDRESS
                 .parse()
                 .expect("Invalid default statistics service URL"),
             nym_api_urls: vec![mainnet::NYM_API.parse().expect("Invalid default API URL")],
             nyxd_urls: vec![mainnet::NYXD_URL.parse().expect("Invalid default nyxd URL")],
-            cosmos_mnemonic: bip39::Mnemonic::generate(24).unwrap(),
         }
     }
 }
@@ -285,6 +345,60 @@ pub struct Debug {
     // existing nodes whilst everyone else is upgrading and getting the code for handling the new field.
     // It shall be disabled in the subsequent releases.
     pub use_legacy_framed_packet_version: bool,
+
+    /// Maximum amount of time the gateway is going to wait, upon receiving a shutdown signal,
+    /// for the connected clients to disconnect before it exits.
+    #[serde(with = "humantime_serde")]
+    pub client_drain_timeout: Duration,
+
+    /// Maximum number of websocket connection attempts accepted from a single IP address
+    /// within `connection_rate_window`. Setting it to 0 disables the limit.
+    pub maximum_connections_per_ip: u32,
+
+    /// Length of the window used for limiting the number of connection attempts from a single IP address.
+    #[serde(with = "humantime_serde")]
+    pub connection_rate_window: Duration,
+
+    /// Maximum sustained number of sphinx packets a single client is allowed to send per second.
+    /// Setting it to 0 disables the limit.
+    pub maximum_client_packets_per_second: u32,
+
+    /// Minimum amount of time between subsequent free testnet bandwidth claims of a single client.
+    /// Only applicable if the gateway is not running in the coconut-credentials-only mode.
+    #[serde(with = "humantime_serde")]
+    pub free_bandwidth_claim_cooldown: Duration,
+
+    /// Number of failed authentication attempts after which the remote IP address gets temporarily banned.
+    /// Setting it to 0 disables the bans.
+    pub authentication_failures_before_ban: u32,
+
+    /// Duration of the temporary ban issued after repeated authentication failures.
+    #[serde(with = "humantime_serde")]
+    pub authentication_failure_ban_duration: Duration,
+
+    /// The lowest average delay between subsequent receive cover messages the gateway is willing
+    /// to push to a single client. Requests for more frequent cover traffic get bounded by this value.
+    /// Setting it to 0 disables receive cover traffic altogether.
+    #[serde(with = "humantime_serde")]
+    pub minimum_receive_cover_average_delay: Duration,
+
+    /// Specifies whether the links with the mixnodes should be authenticated and encrypted
+    /// with the noise protocol.
+    pub use_noise_links: bool,
+
+    /// Specifies whether the gateway should still establish and accept unencrypted links with
+    /// mixnodes that do not support noise.
+    // it's set to true by default in order to preserve compatibility with the existing nodes
+    // whilst everyone else is upgrading. It shall be disabled in the subsequent releases.
+    pub allow_legacy_links: bool,
+
+    /// Timeout for completing the noise handshake with a mixnode.
+    #[serde(with = "humantime_serde")]
+    pub noise_handshake_timeout: Duration,
+
+    /// Delay between subsequent refreshes of the noise keys of other nodes in the network.
+    #[serde(with = "humantime_serde")]
+    pub noise_keys_refresh_rate: Duration,
 }
 
 impl Default for Debug {
@@ -299,6 +413,18 @@ impl Default for Debug {
             message_retrieval_limit: DEFAULT_MESSAGE_RETRIEVAL_LIMIT,
             // TODO: remember to change it in one of future releases!!
             use_legacy_framed_packet_version: true,
+            client_drain_timeout: DEFAULT_CLIENT_DRAIN_TIMEOUT,
+            maximum_connections_per_ip: DEFAULT_MAXIMUM_CONNECTIONS_PER_IP,
+            connection_rate_window: DEFAULT_CONNECTION_RATE_WINDOW,
+            maximum_client_packets_per_second: DEFAULT_MAXIMUM_CLIENT_PACKETS_PER_SECOND,
+            free_bandwidth_claim_cooldown: DEFAULT_FREE_BANDWIDTH_CLAIM_COOLDOWN,
+            authentication_failures_before_ban: DEFAULT_AUTHENTICATION_FAILURES_BEFORE_BAN,
+            authentication_failure_ban_duration: DEFAULT_AUTHENTICATION_FAILURE_BAN_DURATION,
+            minimum_receive_cover_average_delay: DEFAULT_MINIMUM_RECEIVE_COVER_AVERAGE_DELAY,
+            use_noise_links: true,
+            allow_legacy_links: true,
+            noise_handshake_timeout: DEFAULT_NOISE_HANDSHAKE_TIMEOUT,
+            noise_keys_refresh_rate: DEFAULT_NOISE_KEYS_REFRESH_RATE,
         }
     }
 }
@@ -1,8 +1,9 @@
 // Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::config::persistence::paths::{GatewayPaths, KeysPaths};
-use crate::config::{Config, Debug, Gateway};
+use crate::config::old_config_v1_1_22::{ConfigV1_1_22, GatewayPathsV1_1_22, GatewayV1_1_22};
+use crate::config::persistence::paths::KeysPaths;
+use crate::config::Debug;
 use nym_bin_common::logging::LoggingSettings;
 use nym_config::legacy_helpers::nym_config::MigrationNymConfig;
 use nym_validator_client::nyxd;
@@ -42,23 +43,26 @@ pub struct ConfigV1_1_20 {
     debug: DebugV1_1_20,
 }
 
-impl From<ConfigV1_1_20> for Config {
+impl From<ConfigV1_1_20> for ConfigV1_1_22 {
     fn from(value: ConfigV1_1_20) -> Self {
-        Config {
-            gateway: Gateway {
+        ConfigV1_1_22 {
+            gateway: GatewayV1_1_22 {
                 version: value.gateway.version,
                 id: value.gateway.id,
                 only_coconut_credentials: value.gateway.only_coconut_credentials,
                 listening_address: value.gateway.listening_address,
                 mix_port: value.gateway.mix_port,
                 clients_port: value.gateway.clients_port,
+                clients_wss_port: None,
+                tls_certificate_path: None,
+                tls_private_key_path: None,
                 enabled_statistics: value.gateway.enabled_statistics,
                 nym_api_urls: value.gateway.nym_api_urls,
                 nyxd_urls: value.gateway.nyxd_urls,
                 statistics_service_url: value.gateway.statistics_service_url,
                 cosmos_mnemonic: value.gateway.cosmos_mnemonic,
             },
-            storage_paths: GatewayPaths {
+            storage_paths: GatewayPathsV1_1_22 {
                 keys: KeysPaths {
                     private_identity_key_file: value.gateway.private_identity_key_file,
                     public_identity_key_file: value.gateway.public_identity_key_file,
@@ -175,6 +179,7 @@ impl From<DebugV1_1_20> for Debug {
             stored_messages_filename_length: value.stored_messages_filename_length,
             message_retrieval_limit: value.message_retrieval_limit,
             use_legacy_framed_packet_version: value.use_legacy_framed_packet_version,
+            ..Debug::default()
         }
     }
 }
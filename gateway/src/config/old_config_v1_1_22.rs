@@ -0,0 +1,100 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::persistence::paths::{
+    GatewayPaths, KeysPaths, DEFAULT_COSMOS_MNEMONIC_FILENAME,
+};
+use crate::config::{default_data_directory, Config, Debug, Gateway};
+use nym_bin_common::logging::LoggingSettings;
+use nym_config::legacy_helpers::nym_config::MigrationNymConfig;
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use std::path::PathBuf;
+use url::Url;
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigV1_1_22 {
+    pub gateway: GatewayV1_1_22,
+
+    pub storage_paths: GatewayPathsV1_1_22,
+
+    #[serde(default)]
+    pub logging: LoggingSettings,
+
+    #[serde(default)]
+    pub debug: Debug,
+}
+
+// the mnemonic is not part of the current config, so it has to be extracted (and stored separately)
+// before the conversion happens
+impl From<ConfigV1_1_22> for Config {
+    fn from(value: ConfigV1_1_22) -> Self {
+        Config {
+            gateway: Gateway {
+                version: value.gateway.version,
+                id: value.gateway.id.clone(),
+                only_coconut_credentials: value.gateway.only_coconut_credentials,
+                listening_address: value.gateway.listening_address,
+                mix_port: value.gateway.mix_port,
+                clients_port: value.gateway.clients_port,
+                clients_wss_port: value.gateway.clients_wss_port,
+                tls_certificate_path: value.gateway.tls_certificate_path,
+                tls_private_key_path: value.gateway.tls_private_key_path,
+                enabled_statistics: value.gateway.enabled_statistics,
+                statistics_service_url: value.gateway.statistics_service_url,
+                nym_api_urls: value.gateway.nym_api_urls,
+                nyxd_urls: value.gateway.nyxd_urls,
+            },
+            storage_paths: GatewayPaths {
+                keys: value.storage_paths.keys,
+                clients_storage: value.storage_paths.clients_storage,
+                cosmos_bip39_mnemonic: default_data_directory(&value.gateway.id)
+                    .join(DEFAULT_COSMOS_MNEMONIC_FILENAME),
+            },
+            logging: value.logging,
+            debug: value.debug,
+        }
+    }
+}
+
+impl MigrationNymConfig for ConfigV1_1_22 {
+    fn default_root_directory() -> PathBuf {
+        dirs::home_dir()
+            .expect("Failed to evaluate $HOME value")
+            .join(".nym")
+            .join("gateways")
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct GatewayV1_1_22 {
+    pub version: String,
+    pub id: String,
+    #[serde(default)]
+    pub only_coconut_credentials: bool,
+    pub listening_address: IpAddr,
+    pub mix_port: u16,
+    pub clients_port: u16,
+    #[serde(default)]
+    pub clients_wss_port: Option<u16>,
+    #[serde(default)]
+    pub tls_certificate_path: Option<PathBuf>,
+    #[serde(default)]
+    pub tls_private_key_path: Option<PathBuf>,
+    pub enabled_statistics: bool,
+    pub statistics_service_url: Url,
+    #[serde(alias = "validator_api_urls")]
+    pub nym_api_urls: Vec<Url>,
+    #[serde(alias = "validator_nymd_urls")]
+    pub nyxd_urls: Vec<Url>,
+    pub cosmos_mnemonic: bip39::Mnemonic,
+}
+
+#[derive(Debug, Clone, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GatewayPathsV1_1_22 {
+    pub keys: KeysPaths,
+    #[serde(alias = "persistent_storage")]
+    pub clients_storage: PathBuf,
+}
@@ -12,6 +12,8 @@ pub const DEFAULT_PUBLIC_SPHINX_KEY_FILENAME: &str = "public_sphinx.pem";
 
 pub const DEFAULT_CLIENTS_STORAGE_FILENAME: &str = "db.sqlite";
 
+pub const DEFAULT_COSMOS_MNEMONIC_FILENAME: &str = "cosmos_mnemonic.json";
+
 // pub const DEFAULT_DESCRIPTION_FILENAME: &str = "description.toml";
 
 #[derive(Debug, Clone, Deserialize, PartialEq, Eq, Serialize)]
@@ -23,17 +25,30 @@ pub struct GatewayPaths {
     /// derived shared keys and available client bandwidths.
     #[serde(alias = "persistent_storage")]
     pub clients_storage: PathBuf,
-    // pub node_description: PathBuf,
 
-    // pub cosmos_bip39_mnemonic: PathBuf,
+    /// Path to the encrypted file containing the mnemonic of the cosmos wallet used in checking
+    /// for double spending.
+    pub cosmos_bip39_mnemonic: PathBuf,
+    // pub node_description: PathBuf,
 }
 
 impl GatewayPaths {
     pub fn new_default<P: AsRef<Path>>(id: P) -> Self {
+        let data_dir = default_data_directory(id.as_ref());
+
         GatewayPaths {
             keys: KeysPaths::new_default(id.as_ref()),
-            clients_storage: default_data_directory(id).join(DEFAULT_CLIENTS_STORAGE_FILENAME),
+            clients_storage: data_dir.join(DEFAULT_CLIENTS_STORAGE_FILENAME),
             // node_description: default_config_filepath(id).join(DEFAULT_DESCRIPTION_FILENAME),
+            cosmos_bip39_mnemonic: data_dir.join(DEFAULT_COSMOS_MNEMONIC_FILENAME),
+        }
+    }
+
+    pub fn new_in_directory<P: AsRef<Path>>(data_dir: P) -> Self {
+        GatewayPaths {
+            keys: KeysPaths::new_in_directory(data_dir.as_ref()),
+            clients_storage: data_dir.as_ref().join(DEFAULT_CLIENTS_STORAGE_FILENAME),
+            cosmos_bip39_mnemonic: data_dir.as_ref().join(DEFAULT_COSMOS_MNEMONIC_FILENAME),
         }
     }
 
@@ -71,7 +86,11 @@ pub struct KeysPaths {
 
 impl KeysPaths {
     pub fn new_default<P: AsRef<Path>>(id: P) -> Self {
-        let data_dir = default_data_directory(id);
+        Self::new_in_directory(default_data_directory(id))
+    }
+
+    pub fn new_in_directory<P: AsRef<Path>>(data_dir: P) -> Self {
+        let data_dir = data_dir.as_ref();
 
         KeysPaths {
             private_identity_key_file: data_dir.join(DEFAULT_PRIVATE_IDENTITY_KEY_FILENAME),
@@ -33,6 +33,21 @@ mix_port = {{ gateway.mix_port }}
 # (default: 9000)
 clients_port = {{ gateway.clients_port }}
 
+{{#if gateway.clients_wss_port }}
+# Port used for listening for client websocket traffic over TLS (i.e. `wss://`).
+clients_wss_port = {{ gateway.clients_wss_port }}
+{{/if}}
+
+{{#if gateway.tls_certificate_path }}
+# Path to the PEM encoded certificate chain presented to the clients connecting over TLS.
+tls_certificate_path = '{{ gateway.tls_certificate_path }}'
+{{/if}}
+
+{{#if gateway.tls_private_key_path }}
+# Path to the PEM encoded private key corresponding to the TLS certificate.
+tls_private_key_path = '{{ gateway.tls_private_key_path }}'
+{{/if}}
+
 # Wheather gateway collects and sends anonymized statistics
 enabled_statistics = {{ gateway.enabled_statistics }}
 
@@ -53,8 +68,6 @@ nyxd_urls = [
     {{/each}}
 ]
 
-cosmos_mnemonic = "{{ gateway.cosmos_mnemonic }}"
-
 [storage_paths] 
 
 # Path to file containing private identity key.
@@ -73,6 +86,13 @@ keys.public_sphinx_key_file = '{{ storage_paths.keys.public_sphinx_key_file }}'
 # derived shared keys and available client bandwidths.
 clients_storage = '{{ storage_paths.clients_storage }}'
 
+# Path to the encrypted file containing the mnemonic of the cosmos wallet used in checking
+# for double spending.
+# The mnemonic can also be provided directly via the `NYM_GATEWAY_MNEMONIC` environment variable
+# or via a path to a plaintext file set in the `NYM_GATEWAY_MNEMONIC_FILE` environment variable.
+# The passphrase of the encrypted file is read from the `NYM_GATEWAY_SECRETS_PASSPHRASE` environment variable.
+cosmos_bip39_mnemonic = '{{ storage_paths.cosmos_bip39_mnemonic }}'
+
 ##### logging configuration options #####
 
 [logging]
@@ -1,6 +1,7 @@
 // Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use nym_config::secrets::SecretsError;
 use nym_validator_client::nyxd::AccountId;
 use nym_validator_client::ValidatorClientError;
 use std::io;
@@ -8,7 +9,7 @@ use std::path::PathBuf;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
-pub(crate) enum GatewayError {
+pub enum GatewayError {
     #[error(
         "failed to load config file for id {id} using path {path}. detailed message: {source}"
     )]
@@ -29,6 +30,24 @@ pub(crate) enum GatewayError {
         source: io::Error,
     },
 
+    #[error("failed to load the cosmos mnemonic: {source}")]
+    CosmosMnemonicLoadFailure {
+        #[source]
+        source: SecretsError,
+    },
+
+    #[error("failed to save the cosmos mnemonic: {source}")]
+    CosmosMnemonicSaveFailure {
+        #[source]
+        source: SecretsError,
+    },
+
+    #[error("the provided cosmos mnemonic is malformed: {source}")]
+    MalformedCosmosMnemonic {
+        #[source]
+        source: bip39::Error,
+    },
+
     #[error("the configured version of the gateway ({config_version}) is incompatible with the binary version ({binary_version})")]
     LocalVersionCheckFailure {
         binary_version: String,
@@ -47,4 +66,33 @@ pub(crate) enum GatewayError {
         expected_prefix: String,
         actual_prefix: String,
     },
+
+    #[error("the secure clients port ({port}) has been specified, but the TLS certificate and its private key have not been provided")]
+    MissingTlsCredentials { port: u16 },
+
+    #[error("failed to load the TLS certificate chain from {path}: {source}")]
+    TlsCertificateLoadFailure {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("{path} does not contain any PEM encoded certificates")]
+    NoTlsCertificates { path: PathBuf },
+
+    #[error("failed to load the TLS private key from {path}: {source}")]
+    TlsPrivateKeyLoadFailure {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("{path} does not contain any supported PEM encoded private key")]
+    NoTlsPrivateKey { path: PathBuf },
+
+    #[error("the provided TLS certificate and private key could not be used: {source}")]
+    InvalidTlsConfiguration {
+        #[from]
+        source: tokio_rustls::rustls::Error,
+    },
 }
@@ -11,10 +11,10 @@ use nym_bin_common::output_format::OutputFormat;
 use nym_network_defaults::setup_env;
 use std::error::Error;
 
+// the gateway internals live in the library so that they could also be run in-process
+use nym_gateway::{config, error, node};
+
 mod commands;
-mod config;
-pub(crate) mod error;
-mod node;
 pub(crate) mod support;
 
 lazy_static! {
@@ -0,0 +1,31 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use tokio::sync::watch;
+
+/// Channel used for announcing to all client handlers that the gateway has entered the drain mode.
+pub(crate) type DrainSender = watch::Sender<Option<DrainNotice>>;
+
+/// Channel used by client handlers for getting notified the gateway has entered the drain mode.
+pub(crate) type DrainReceiver = watch::Receiver<Option<DrainNotice>>;
+
+/// Details of the upcoming shutdown that are announced to all connected clients.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct DrainNotice {
+    /// Optional identity key of a gateway the clients are recommended to move to.
+    pub(crate) suggested_gateway: Option<String>,
+}
+
+impl DrainNotice {
+    pub(crate) fn new(suggested_gateway: Option<String>) -> Self {
+        DrainNotice { suggested_gateway }
+    }
+}
+
+pub(crate) fn drain_channel() -> (DrainSender, DrainReceiver) {
+    watch::channel(None)
+}
+
+pub(crate) fn is_draining(drain: &DrainReceiver) -> bool {
+    drain.borrow().is_some()
+}
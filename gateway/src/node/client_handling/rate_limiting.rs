@@ -0,0 +1,489 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::Debug;
+use dashmap::DashMap;
+use log::{debug, info, warn};
+use nym_sphinx::DestinationAddressBytes;
+use nym_task::TaskClient;
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Error)]
+pub(crate) enum RateLimitError {
+    #[error("{ip} is temporarily banned for the next {remaining:?}")]
+    Banned { ip: IpAddr, remaining: Duration },
+
+    #[error(
+        "{ip} has exceeded the maximum number of connection attempts ({limit} per {window:?})"
+    )]
+    TooManyConnections {
+        ip: IpAddr,
+        limit: u32,
+        window: Duration,
+    },
+}
+
+/// Limits imposed on the clients connecting to this gateway.
+/// Unless stated otherwise, any limit set to zero is disabled.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RateLimits {
+    /// Maximum number of connection (and thus handshake) attempts from a single IP address
+    /// within `connection_window`.
+    pub(crate) connections_per_window: u32,
+
+    /// Length of the window used for limiting the connection attempts.
+    pub(crate) connection_window: Duration,
+
+    /// Maximum sustained number of sphinx packets a single client is allowed to send per second.
+    pub(crate) client_packets_per_second: u32,
+
+    /// Minimum amount of time between subsequent free testnet bandwidth claims of a single client.
+    pub(crate) bandwidth_claim_cooldown: Duration,
+
+    /// Number of failed authentication attempts (invalid MACs, failed handshakes, etc.)
+    /// after which the remote IP address gets temporarily banned.
+    pub(crate) failures_before_ban: u32,
+
+    /// Duration of the temporary ban.
+    pub(crate) ban_duration: Duration,
+
+    /// The lowest average delay between receive cover messages pushed to a single client.
+    /// Setting it to zero disables receive cover traffic rather than removing the bound.
+    pub(crate) receive_cover_minimum_delay: Duration,
+}
+
+impl From<&Debug> for RateLimits {
+    fn from(debug: &Debug) -> Self {
+        RateLimits {
+            connections_per_window: debug.maximum_connections_per_ip,
+            connection_window: debug.connection_rate_window,
+            client_packets_per_second: debug.maximum_client_packets_per_second,
+            bandwidth_claim_cooldown: debug.free_bandwidth_claim_cooldown,
+            failures_before_ban: debug.authentication_failures_before_ban,
+            ban_duration: debug.authentication_failure_ban_duration,
+            receive_cover_minimum_delay: debug.minimum_receive_cover_average_delay,
+        }
+    }
+}
+
+/// Snapshot of the number of requests rejected due to the imposed limits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct RejectedRequests {
+    pub(crate) banned_connections: u64,
+    pub(crate) rate_limited_connections: u64,
+    pub(crate) rate_limited_packets: u64,
+    pub(crate) rate_limited_bandwidth_claims: u64,
+    pub(crate) authentication_failures: u64,
+    pub(crate) issued_bans: u64,
+}
+
+impl RejectedRequests {
+    fn is_empty(&self) -> bool {
+        *self == Default::default()
+    }
+}
+
+#[derive(Debug, Default)]
+struct RejectionCounters {
+    banned_connections: AtomicU64,
+    rate_limited_connections: AtomicU64,
+    rate_limited_packets: AtomicU64,
+    rate_limited_bandwidth_claims: AtomicU64,
+    authentication_failures: AtomicU64,
+    issued_bans: AtomicU64,
+}
+
+impl RejectionCounters {
+    fn increment(counter: &AtomicU64) {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> RejectedRequests {
+        RejectedRequests {
+            banned_connections: self.banned_connections.load(Ordering::Relaxed),
+            rate_limited_connections: self.rate_limited_connections.load(Ordering::Relaxed),
+            rate_limited_packets: self.rate_limited_packets.load(Ordering::Relaxed),
+            rate_limited_bandwidth_claims: self
+                .rate_limited_bandwidth_claims
+                .load(Ordering::Relaxed),
+            authentication_failures: self.authentication_failures.load(Ordering::Relaxed),
+            issued_bans: self.issued_bans.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RemoteState {
+    window_start: Instant,
+    connections: u32,
+    failures: u32,
+    banned_until: Option<Instant>,
+}
+
+impl RemoteState {
+    fn new(now: Instant) -> Self {
+        RemoteState {
+            window_start: now,
+            connections: 0,
+            failures: 0,
+            banned_until: None,
+        }
+    }
+
+    fn is_stale(&self, now: Instant, window: Duration) -> bool {
+        let ban_expired = self.banned_until.map(|until| until <= now).unwrap_or(true);
+        ban_expired && now.saturating_duration_since(self.window_start) > window
+    }
+}
+
+/// Shared limiter keeping track of connection attempts and authentication failures of all remotes
+/// as well as of the free bandwidth claims of all clients.
+#[derive(Clone)]
+pub(crate) struct ClientRateLimiter {
+    limits: RateLimits,
+    remotes: Arc<DashMap<IpAddr, RemoteState>>,
+    bandwidth_claims: Arc<DashMap<DestinationAddressBytes, Instant>>,
+    counters: Arc<RejectionCounters>,
+}
+
+impl ClientRateLimiter {
+    pub(crate) fn new(limits: RateLimits) -> Self {
+        ClientRateLimiter {
+            limits,
+            remotes: Arc::new(DashMap::new()),
+            bandwidth_claims: Arc::new(DashMap::new()),
+            counters: Arc::new(RejectionCounters::default()),
+        }
+    }
+
+    /// Checks whether a new connection from the specified address should be accepted.
+    pub(crate) fn check_connection(&self, ip: IpAddr) -> Result<(), RateLimitError> {
+        let now = Instant::now();
+        let mut remote = self
+            .remotes
+            .entry(ip)
+            .or_insert_with(|| RemoteState::new(now));
+
+        if let Some(banned_until) = remote.banned_until {
+            if banned_until > now {
+                RejectionCounters::increment(&self.counters.banned_connections);
+                return Err(RateLimitError::Banned {
+                    ip,
+                    remaining: banned_until - now,
+                });
+            }
+            remote.banned_until = None;
+            remote.failures = 0;
+        }
+
+        if self.limits.connections_per_window == 0 {
+            return Ok(());
+        }
+
+        if now.saturating_duration_since(remote.window_start) > self.limits.connection_window {
+            remote.window_start = now;
+            remote.connections = 0;
+        }
+
+        if remote.connections >= self.limits.connections_per_window {
+            RejectionCounters::increment(&self.counters.rate_limited_connections);
+            return Err(RateLimitError::TooManyConnections {
+                ip,
+                limit: self.limits.connections_per_window,
+                window: self.limits.connection_window,
+            });
+        }
+
+        remote.connections += 1;
+        Ok(())
+    }
+
+    /// Records failed authentication attempt of the specified address.
+    /// Returns a boolean indicating whether the address got banned as a result.
+    pub(crate) fn record_authentication_failure(&self, ip: IpAddr) -> bool {
+        RejectionCounters::increment(&self.counters.authentication_failures);
+        if self.limits.failures_before_ban == 0 {
+            return false;
+        }
+
+        let now = Instant::now();
+        let mut remote = self
+            .remotes
+            .entry(ip)
+            .or_insert_with(|| RemoteState::new(now));
+
+        remote.failures += 1;
+        if remote.failures >= self.limits.failures_before_ban {
+            warn!(
+                "{ip} has failed to authenticate {} times - banning it for {:?}",
+                remote.failures, self.limits.ban_duration
+            );
+            RejectionCounters::increment(&self.counters.issued_bans);
+            remote.banned_until = Some(now + self.limits.ban_duration);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Resets the authentication failures of the specified address.
+    pub(crate) fn record_authentication_success(&self, ip: IpAddr) {
+        if let Some(mut remote) = self.remotes.get_mut(&ip) {
+            remote.failures = 0;
+        }
+    }
+
+    /// Checks whether the client is allowed to claim more free testnet bandwidth.
+    pub(crate) fn try_claim_bandwidth(&self, client: DestinationAddressBytes) -> bool {
+        if self.limits.bandwidth_claim_cooldown.is_zero() {
+            return true;
+        }
+
+        let now = Instant::now();
+        if let Some(mut last_claim) = self.bandwidth_claims.get_mut(&client) {
+            if now.saturating_duration_since(*last_claim) < self.limits.bandwidth_claim_cooldown {
+                RejectionCounters::increment(&self.counters.rate_limited_bandwidth_claims);
+                return false;
+            }
+            *last_claim = now;
+            return true;
+        }
+
+        self.bandwidth_claims.insert(client, now);
+        true
+    }
+
+    /// Creates new packet limiter for a freshly authenticated client.
+    pub(crate) fn new_packet_limiter(&self) -> PacketRateLimiter {
+        PacketRateLimiter::new(
+            self.limits.client_packets_per_second,
+            Arc::clone(&self.counters),
+        )
+    }
+
+    /// Bounds the average delay between receive cover messages requested by a client.
+    /// Returns `None` if the receive cover traffic is disabled on this gateway.
+    pub(crate) fn receive_cover_average_delay(&self, requested: Duration) -> Option<Duration> {
+        if self.limits.receive_cover_minimum_delay.is_zero() {
+            return None;
+        }
+        Some(requested.max(self.limits.receive_cover_minimum_delay))
+    }
+
+    pub(crate) fn rejected_requests(&self) -> RejectedRequests {
+        self.counters.snapshot()
+    }
+
+    fn remove_stale_entries(&self) {
+        let now = Instant::now();
+        let window = self.limits.connection_window;
+        let cooldown = self.limits.bandwidth_claim_cooldown;
+
+        self.remotes
+            .retain(|_, remote| !remote.is_stale(now, window));
+        self.bandwidth_claims
+            .retain(|_, last_claim| now.saturating_duration_since(*last_claim) < cooldown);
+    }
+
+    /// Periodically removes expired entries and logs the number of rejected requests.
+    pub(crate) async fn run_cleanup(&self, mut shutdown: TaskClient) {
+        let mut interval = tokio::time::interval(CLEANUP_INTERVAL);
+        let mut last_reported = RejectedRequests::default();
+
+        while !shutdown.is_shutdown() {
+            tokio::select! {
+                _ = shutdown.recv() => {
+                    log::trace!("ClientRateLimiter: received shutdown");
+                }
+                _ = interval.tick() => {
+                    self.remove_stale_entries();
+
+                    let rejected = self.rejected_requests();
+                    if !rejected.is_empty() && rejected != last_reported {
+                        info!("rejected client requests so far: {rejected:?}");
+                        last_reported = rejected;
+                    }
+                }
+            }
+        }
+        debug!("ClientRateLimiter: exiting");
+    }
+
+    pub(crate) fn start_cleanup(self, shutdown: TaskClient) {
+        tokio::spawn(async move { self.run_cleanup(shutdown).await });
+    }
+}
+
+/// Token bucket limiting the rate of packets sent by a single authenticated client.
+/// It allows bursts of up to a second worth of packets.
+pub(crate) struct PacketRateLimiter {
+    rate: f64,
+    available: f64,
+    last_refill: Instant,
+    counters: Arc<RejectionCounters>,
+}
+
+impl PacketRateLimiter {
+    fn new(packets_per_second: u32, counters: Arc<RejectionCounters>) -> Self {
+        PacketRateLimiter {
+            rate: packets_per_second as f64,
+            available: packets_per_second as f64,
+            last_refill: Instant::now(),
+            counters,
+        }
+    }
+
+    /// Attempts to consume a single packet allowance. Returns false if the client is sending too fast.
+    pub(crate) fn try_consume(&mut self) -> bool {
+        if self.rate == 0.0 {
+            return true;
+        }
+
+        let now = Instant::now();
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.available = (self.available + elapsed * self.rate).min(self.rate);
+        self.last_refill = now;
+
+        if self.available >= 1.0 {
+            self.available -= 1.0;
+            true
+        } else {
+            RejectionCounters::increment(&self.counters.rate_limited_packets);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    fn test_limits() -> RateLimits {
+        RateLimits {
+            connections_per_window: 3,
+            connection_window: Duration::from_secs(60),
+            client_packets_per_second: 5,
+            bandwidth_claim_cooldown: Duration::from_secs(60),
+            failures_before_ban: 2,
+            ban_duration: Duration::from_secs(60),
+            receive_cover_minimum_delay: Duration::from_millis(100),
+        }
+    }
+
+    const REMOTE: IpAddr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+
+    #[test]
+    fn connections_above_the_limit_are_rejected() {
+        let limiter = ClientRateLimiter::new(test_limits());
+        for _ in 0..3 {
+            assert!(limiter.check_connection(REMOTE).is_ok());
+        }
+        assert!(matches!(
+            limiter.check_connection(REMOTE),
+            Err(RateLimitError::TooManyConnections { .. })
+        ));
+
+        // other remotes are unaffected
+        assert!(limiter
+            .check_connection(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)))
+            .is_ok());
+        assert_eq!(limiter.rejected_requests().rate_limited_connections, 1);
+    }
+
+    #[test]
+    fn repeated_authentication_failures_result_in_a_ban() {
+        let limiter = ClientRateLimiter::new(test_limits());
+        assert!(!limiter.record_authentication_failure(REMOTE));
+        assert!(limiter.record_authentication_failure(REMOTE));
+        assert!(matches!(
+            limiter.check_connection(REMOTE),
+            Err(RateLimitError::Banned { .. })
+        ));
+
+        let rejected = limiter.rejected_requests();
+        assert_eq!(rejected.authentication_failures, 2);
+        assert_eq!(rejected.issued_bans, 1);
+        assert_eq!(rejected.banned_connections, 1);
+    }
+
+    #[test]
+    fn successful_authentication_resets_failures() {
+        let limiter = ClientRateLimiter::new(test_limits());
+        assert!(!limiter.record_authentication_failure(REMOTE));
+        limiter.record_authentication_success(REMOTE);
+        assert!(!limiter.record_authentication_failure(REMOTE));
+        assert!(limiter.check_connection(REMOTE).is_ok());
+    }
+
+    #[test]
+    fn bandwidth_can_only_be_claimed_once_per_cooldown() {
+        let limiter = ClientRateLimiter::new(test_limits());
+        let client = DestinationAddressBytes::from_bytes([42; 32]);
+        assert!(limiter.try_claim_bandwidth(client));
+        assert!(!limiter.try_claim_bandwidth(client));
+        assert_eq!(limiter.rejected_requests().rate_limited_bandwidth_claims, 1);
+    }
+
+    #[test]
+    fn packet_limiter_allows_bursts_up_to_the_rate() {
+        let limiter = ClientRateLimiter::new(test_limits());
+        let mut packet_limiter = limiter.new_packet_limiter();
+        for _ in 0..5 {
+            assert!(packet_limiter.try_consume());
+        }
+        assert!(!packet_limiter.try_consume());
+        assert_eq!(limiter.rejected_requests().rate_limited_packets, 1);
+    }
+
+    #[test]
+    fn zero_limits_are_disabled() {
+        let limiter = ClientRateLimiter::new(RateLimits {
+            connections_per_window: 0,
+            client_packets_per_second: 0,
+            bandwidth_claim_cooldown: Duration::ZERO,
+            failures_before_ban: 0,
+            ..test_limits()
+        });
+        let client = DestinationAddressBytes::from_bytes([42; 32]);
+        let mut packet_limiter = limiter.new_packet_limiter();
+        for _ in 0..100 {
+            assert!(limiter.check_connection(REMOTE).is_ok());
+            assert!(!limiter.record_authentication_failure(REMOTE));
+            assert!(limiter.try_claim_bandwidth(client));
+            assert!(packet_limiter.try_consume());
+        }
+    }
+
+    #[test]
+    fn receive_cover_delay_is_bounded_from_below() {
+        let limiter = ClientRateLimiter::new(test_limits());
+        assert_eq!(
+            limiter.receive_cover_average_delay(Duration::ZERO),
+            Some(Duration::from_millis(100))
+        );
+        assert_eq!(
+            limiter.receive_cover_average_delay(Duration::from_secs(1)),
+            Some(Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn zero_receive_cover_delay_disables_receive_cover() {
+        let limiter = ClientRateLimiter::new(RateLimits {
+            receive_cover_minimum_delay: Duration::ZERO,
+            ..test_limits()
+        });
+        assert!(limiter
+            .receive_cover_average_delay(Duration::from_secs(1))
+            .is_none());
+    }
+}
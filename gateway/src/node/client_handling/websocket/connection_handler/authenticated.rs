@@ -1,6 +1,7 @@
 // Copyright 2021 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::node::client_handling::rate_limiting::PacketRateLimiter;
 use crate::node::client_handling::websocket::connection_handler::{ClientDetails, FreshHandler};
 use crate::node::client_handling::websocket::message_receiver::MixMessageReceiver;
 use crate::node::storage::error::StorageError;
@@ -10,12 +11,18 @@ use log::*;
 use nym_gateway_requests::iv::IVConversionError;
 use nym_gateway_requests::types::{BinaryRequest, ServerResponse};
 use nym_gateway_requests::{ClientControlRequest, GatewayRequestsError};
+use nym_sphinx::cover::generate_receive_cover_payload;
 use nym_sphinx::forwarding::packet::MixPacket;
+use nym_sphinx::params::PacketSize;
+use nym_sphinx::utils::sample_poisson_duration;
 use rand::{CryptoRng, Rng};
 use std::convert::TryFrom;
+use std::pin::Pin;
 use std::process;
+use std::time::Duration;
 use thiserror::Error;
 use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::time::{sleep, Instant, Sleep};
 use tokio_tungstenite::tungstenite::protocol::Message;
 
 use crate::node::client_handling::bandwidth::Bandwidth;
@@ -49,6 +56,12 @@ pub(crate) enum RequestHandlingError {
     #[error("This gateway is only accepting coconut credentials for bandwidth")]
     OnlyCoconutCredentials,
 
+    #[error("The client is sending packets too quickly. The packet has been dropped")]
+    RateLimited,
+
+    #[error("This gateway does not provide receive cover traffic")]
+    ReceiveCoverDisabled,
+
     #[error("Nyxd Error - {0}")]
     NyxdError(#[from] nym_validator_client::nyxd::error::NyxdError),
 
@@ -89,10 +102,43 @@ impl IntoWSMessage for Result<ServerResponse, RequestHandlingError> {
     }
 }
 
+/// State of the dummy messages pushed to the client, if it requested them.
+struct ReceiveCover {
+    average_delay: Duration,
+    next_delay: Pin<Box<Sleep>>,
+}
+
+impl ReceiveCover {
+    fn new<R: Rng>(rng: &mut R, average_delay: Duration) -> Self {
+        ReceiveCover {
+            average_delay,
+            next_delay: Box::pin(sleep(sample_poisson_duration(rng, average_delay))),
+        }
+    }
+
+    fn reset<R: Rng>(&mut self, rng: &mut R) {
+        let next = sample_poisson_duration(rng, self.average_delay);
+        self.next_delay.as_mut().reset(Instant::now() + next);
+    }
+}
+
+async fn next_receive_cover(receive_cover: &mut Option<ReceiveCover>) {
+    match receive_cover {
+        Some(receive_cover) => receive_cover.next_delay.as_mut().await,
+        None => futures::future::pending().await,
+    }
+}
+
 pub(crate) struct AuthenticatedHandler<R, S, St> {
     inner: FreshHandler<R, S, St>,
     client: ClientDetails,
     mix_receiver: MixMessageReceiver,
+    packet_limiter: PacketRateLimiter,
+    receive_cover: Option<ReceiveCover>,
+
+    /// Indicates whether the remote got banned due to repeated authentication failures
+    /// and thus the connection should get closed.
+    banned: bool,
 }
 
 // explicitly remove handle from the global store upon being dropped
@@ -125,9 +171,12 @@ where
         mix_receiver: MixMessageReceiver,
     ) -> Self {
         AuthenticatedHandler {
+            packet_limiter: fresh.rate_limiter.new_packet_limiter(),
             inner: fresh,
             client,
             mix_receiver,
+            receive_cover: None,
+            banned: false,
         }
     }
 
@@ -264,6 +313,20 @@ where
             return Err(RequestHandlingError::OnlyCoconutCredentials);
         }
 
+        if !self
+            .inner
+            .rate_limiter
+            .try_claim_bandwidth(self.client.address)
+        {
+            // don't fail the request, just don't give out any more bandwidth
+            debug!(
+                "{} has attempted to claim free bandwidth too soon",
+                self.client.address.as_base58_string()
+            );
+            let available_total = self.get_available_bandwidth().await?;
+            return Ok(ServerResponse::Bandwidth { available_total });
+        }
+
         self.increase_bandwidth(FREE_TESTNET_BANDWIDTH_VALUE)
             .await?;
         let available_total = self.get_available_bandwidth().await?;
@@ -301,31 +364,94 @@ where
         })
     }
 
+    /// Tries to handle the request to start pushing receive cover messages to the client.
+    ///
+    /// The requested average delay is bounded from below by the configured minimum
+    /// so that a single client could not make us flood it with dummy messages.
+    /// Furthermore, each pushed message is charged against the client's bandwidth
+    /// in the same way as the packets it sends.
+    ///
+    /// # Arguments
+    ///
+    /// * `average_delay_ms`: requested average delay between subsequent receive cover messages.
+    fn handle_receive_cover_traffic(
+        &mut self,
+        average_delay_ms: u64,
+    ) -> Result<ServerResponse, RequestHandlingError> {
+        let Some(average_delay) = self
+            .inner
+            .rate_limiter
+            .receive_cover_average_delay(Duration::from_millis(average_delay_ms))
+        else {
+            return Err(RequestHandlingError::ReceiveCoverDisabled);
+        };
+
+        debug!(
+            "{} requested receive cover traffic with average delay of {}ms",
+            self.client.address.as_base58_string(),
+            average_delay.as_millis()
+        );
+        self.receive_cover = Some(ReceiveCover::new(&mut self.inner.rng, average_delay));
+
+        Ok(ServerResponse::ReceiveCoverTraffic {
+            average_delay_ms: average_delay.as_millis() as u64,
+        })
+    }
+
+    /// Charges the client for a single receive cover message.
+    /// Returns a boolean indicating whether the client had enough bandwidth available.
+    async fn charge_receive_cover(&self) -> Result<bool, RequestHandlingError> {
+        let consumed_bandwidth = PacketSize::RegularPacket.size() as i64;
+
+        let available_bandwidth = self.get_available_bandwidth().await?;
+        if available_bandwidth < consumed_bandwidth {
+            return Ok(false);
+        }
+
+        self.consume_bandwidth(consumed_bandwidth).await?;
+        Ok(true)
+    }
+
     /// Attempts to handle a binary data frame websocket message.
     ///
     /// # Arguments
     ///
     /// * `bin_msg`: raw message to handle.
-    async fn handle_binary(&self, bin_msg: Vec<u8>) -> Message {
+    async fn handle_binary(&mut self, bin_msg: Vec<u8>) -> Message {
         // this function decrypts the request and checks the MAC
         match BinaryRequest::try_from_encrypted_tagged_bytes(bin_msg, &self.client.shared_keys) {
             Err(e) => {
                 error!("{e}");
+                // the request could not have been produced by the owner of the shared keys
+                if matches!(
+                    e,
+                    GatewayRequestsError::TooShortRequest | GatewayRequestsError::InvalidMac
+                ) && self
+                    .inner
+                    .rate_limiter
+                    .record_authentication_failure(self.inner.remote_address)
+                {
+                    self.banned = true;
+                }
                 RequestHandlingError::InvalidBinaryRequest(e).into_error_message()
             }
             Ok(request) => match request {
                 // currently only a single type exists
-                BinaryRequest::ForwardSphinx(mix_packet) => self
-                    .handle_forward_sphinx(mix_packet)
-                    .await
-                    .into_ws_message(),
+                BinaryRequest::ForwardSphinx(mix_packet) => {
+                    if !self.packet_limiter.try_consume() {
+                        return RequestHandlingError::RateLimited.into_error_message();
+                    }
+                    self.handle_forward_sphinx(mix_packet)
+                        .await
+                        .into_ws_message()
+                }
             },
         }
     }
 
     /// Attempts to handle a text data frame websocket message.
     ///
-    /// Currently the bandwidth credential request is the only one we can receive after authentication.
+    /// Currently the bandwidth and receive cover requests are the only ones we can receive after authentication.
     ///
     /// # Arguments
     ///
@@ -342,6 +468,9 @@ where
                     .handle_claim_testnet_bandwidth()
                     .await
                     .into_ws_message(),
+                ClientControlRequest::ReceiveCoverTraffic { average_delay_ms } => self
+                    .handle_receive_cover_traffic(average_delay_ms)
+                    .into_ws_message(),
                 _ => RequestHandlingError::IllegalRequest.into_error_message(),
             },
         }
@@ -373,11 +502,34 @@ where
     {
         trace!("Started listening for ALL incoming requests...");
 
+        let mut drain = self.inner.drain.clone();
+        let mut drain_announced = false;
+
         while !shutdown.is_shutdown() {
             tokio::select! {
                 _ = shutdown.recv() => {
                     log::trace!("client_handling::AuthenticatedHandler: received shutdown");
                 }
+                changed = drain.changed(), if !drain_announced => {
+                    if changed.is_err() {
+                        // the sender got dropped, so there's nothing more to wait for
+                        drain_announced = true;
+                        continue;
+                    }
+                    let Some(notice) = drain.borrow_and_update().clone() else {
+                        continue
+                    };
+                    drain_announced = true;
+
+                    debug!("informing {} about the upcoming shutdown", self.client.address.as_base58_string());
+                    let response = ServerResponse::new_shutting_down(notice.suggested_gateway);
+                    if let Err(err) = self.inner.send_websocket_message(response.into()).await {
+                        warn!(
+                            "Failed to send message over websocket: {err}. Assuming the connection is dead.",
+                        );
+                        break;
+                    }
+                }
                 socket_msg = self.inner.read_websocket_message() => {
                     let socket_msg = match socket_msg {
                         None => break,
@@ -400,6 +552,11 @@ where
                             break;
                         }
                     }
+
+                    if self.banned {
+                        debug!("{} got banned - closing the connection", self.inner.remote_address);
+                        break;
+                    }
                 },
                 mix_messages = self.mix_receiver.next() => {
                     let mix_messages = mix_messages.expect("sender was unexpectedly closed! this shouldn't have ever happened!");
@@ -408,10 +565,175 @@ where
                         break;
                     }
                 }
+                _ = next_receive_cover(&mut self.receive_cover) => {
+                    match self.charge_receive_cover().await {
+                        Ok(true) => (),
+                        Ok(false) => {
+                            debug!("{} has run out of bandwidth - stopping its receive cover traffic", self.client.address.as_base58_string());
+                            self.receive_cover = None;
+                            continue;
+                        }
+                        Err(err) => {
+                            error!("failed to charge {} for receive cover traffic - {err}. Stopping it", self.client.address.as_base58_string());
+                            self.receive_cover = None;
+                            continue;
+                        }
+                    }
+
+                    if let Some(receive_cover) = &mut self.receive_cover {
+                        receive_cover.reset(&mut self.inner.rng);
+                    }
+
+                    // the payload is indistinguishable from a pushed regular packet once encrypted
+                    let cover = generate_receive_cover_payload(PacketSize::RegularPacket);
+                    if let Err(err) = self.inner.push_packets_to_client(&self.client.shared_keys, vec![cover]).await {
+                        warn!("failed to send receive cover message to the client - {err}, assuming the connection is dead");
+                        break;
+                    }
+                }
             }
         }
 
+        self.flush_pending_messages().await;
         self.disconnect();
         trace!("The stream was closed!");
     }
+
+    /// Moves all mix messages that got received, but were not yet pushed to the client,
+    /// into the persistent storage so that they could be retrieved upon the client reconnecting.
+    async fn flush_pending_messages(&mut self) {
+        self.mix_receiver.close();
+
+        let mut stored = 0;
+        while let Ok(Some(mix_messages)) = self.mix_receiver.try_next() {
+            for message in mix_messages {
+                if let Err(err) = self
+                    .inner
+                    .storage
+                    .store_message(self.client.address, message)
+                    .await
+                {
+                    error!(
+                        "failed to store pending message for {}: {err}",
+                        self.client.address.as_base58_string()
+                    );
+                } else {
+                    stored += 1;
+                }
+            }
+        }
+
+        if stored > 0 {
+            debug!(
+                "stored {stored} pending messages for {} before closing the connection",
+                self.client.address.as_base58_string()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Debug;
+    use crate::node::client_handling::active_clients::ActiveClientsStore;
+    use crate::node::client_handling::drain::drain_channel;
+    use crate::node::client_handling::rate_limiting::{ClientRateLimiter, RateLimits};
+    use crate::node::client_handling::websocket::connection_handler::coconut::CoconutVerifier;
+    use crate::node::storage::InMemStorage;
+    use futures::channel::mpsc;
+    use nym_crypto::asymmetric::identity;
+    use nym_gateway_requests::generic_array::typenum::Unsigned;
+    use nym_gateway_requests::registration::handshake::{SharedKeySize, SharedKeys};
+    use nym_network_defaults::NymNetworkDetails;
+    use nym_sphinx::DestinationAddressBytes;
+    use rand::rngs::OsRng;
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::sync::Arc;
+
+    const REMOTE: IpAddr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+
+    fn shared_keys() -> SharedKeys {
+        SharedKeys::try_from_bytes(&vec![42u8; SharedKeySize::to_usize()]).unwrap()
+    }
+
+    fn coconut_verifier() -> CoconutVerifier {
+        // note: constructing the client does not perform any network requests
+        let network_details = NymNetworkDetails::new_mainnet();
+        let client_config =
+            nym_validator_client::Config::try_from_nym_network_details(&network_details).unwrap();
+        let nyxd_client = nym_validator_client::Client::new_signing(
+            client_config,
+            bip39::Mnemonic::generate(24).unwrap(),
+        )
+        .unwrap();
+        CoconutVerifier::new(nyxd_client)
+    }
+
+    fn test_handler(
+        rate_limiter: ClientRateLimiter,
+    ) -> AuthenticatedHandler<OsRng, (), InMemStorage> {
+        let (outbound_mix_sender, _) = mpsc::unbounded();
+        let (_, drain) = drain_channel();
+        let fresh = FreshHandler::new(
+            OsRng,
+            (),
+            false,
+            outbound_mix_sender,
+            Arc::new(identity::KeyPair::new(&mut OsRng)),
+            InMemStorage,
+            ActiveClientsStore::new(),
+            Arc::new(coconut_verifier()),
+            drain,
+            REMOTE,
+            rate_limiter,
+        );
+
+        let client = ClientDetails::new(
+            DestinationAddressBytes::from_bytes([42u8; 32]),
+            shared_keys(),
+        );
+        let (_, mix_receiver) = mpsc::unbounded();
+        AuthenticatedHandler::upgrade(fresh, client, mix_receiver)
+    }
+
+    fn test_rate_limiter() -> ClientRateLimiter {
+        ClientRateLimiter::new(RateLimits {
+            failures_before_ban: 2,
+            ..RateLimits::from(&Debug::default())
+        })
+    }
+
+    #[tokio::test]
+    async fn requests_with_invalid_mac_count_as_authentication_failures() {
+        let rate_limiter = test_rate_limiter();
+        let mut handler = test_handler(rate_limiter.clone());
+
+        // correct length, but it was not tagged with our keys
+        let bogus_request = vec![1u8; 128];
+
+        handler.handle_binary(bogus_request.clone()).await;
+        assert_eq!(rate_limiter.rejected_requests().authentication_failures, 1);
+        assert!(!handler.banned);
+
+        handler.handle_binary(bogus_request).await;
+        assert_eq!(rate_limiter.rejected_requests().authentication_failures, 2);
+        assert!(handler.banned);
+        assert!(rate_limiter.check_connection(REMOTE).is_err());
+    }
+
+    #[tokio::test]
+    async fn authenticated_malformed_requests_are_not_authentication_failures() {
+        let rate_limiter = test_rate_limiter();
+        let mut handler = test_handler(rate_limiter.clone());
+
+        // the request is properly tagged, it just does not contain a valid mix packet
+        let malformed_request = shared_keys().encrypt_and_tag(b"foomp", None);
+
+        handler.handle_binary(malformed_request.clone()).await;
+        handler.handle_binary(malformed_request).await;
+        assert_eq!(rate_limiter.rejected_requests().authentication_failures, 0);
+        assert!(!handler.banned);
+        assert!(rate_limiter.check_connection(REMOTE).is_ok());
+    }
 }
@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::node::client_handling::active_clients::ActiveClientsStore;
+use crate::node::client_handling::drain::{is_draining, DrainReceiver};
+use crate::node::client_handling::rate_limiting::ClientRateLimiter;
 use crate::node::client_handling::websocket::connection_handler::coconut::CoconutVerifier;
 use crate::node::client_handling::websocket::connection_handler::{
     AuthenticatedHandler, ClientDetails, InitialAuthResult, SocketStream,
@@ -23,6 +25,7 @@ use nym_mixnet_client::forwarder::MixForwardingSender;
 use nym_sphinx::DestinationAddressBytes;
 use rand::{CryptoRng, Rng};
 use std::convert::TryFrom;
+use std::net::IpAddr;
 use std::sync::Arc;
 use thiserror::Error;
 use tokio::io::{AsyncRead, AsyncWrite};
@@ -57,6 +60,9 @@ pub(crate) enum InitialAuthenticationError {
 
     #[error("Attempted to negotiate connection with client using incompatible protocol version. Ours is {current} and the client reports {client:?}")]
     IncompatibleProtocol { client: Option<u8>, current: u8 },
+
+    #[error("Failed to authenticate too many times")]
+    Banned,
 }
 
 impl InitialAuthenticationError {
@@ -64,10 +70,21 @@ impl InitialAuthenticationError {
     fn into_error_message(self) -> Message {
         ServerResponse::new_error(self.to_string()).into()
     }
+
+    /// Indicates whether this error is a result of the client presenting invalid cryptographic material,
+    /// as opposed to, for example, a connection issue.
+    fn is_authentication_failure(&self) -> bool {
+        matches!(
+            self,
+            InitialAuthenticationError::HandshakeError(_)
+                | InitialAuthenticationError::MalformedEncryptedAddress(_)
+                | InitialAuthenticationError::MalformedIV(_)
+        )
+    }
 }
 
 pub(crate) struct FreshHandler<R, S, St> {
-    rng: R,
+    pub(crate) rng: R,
     local_identity: Arc<identity::KeyPair>,
     pub(crate) only_coconut_credentials: bool,
     pub(crate) active_clients_store: ActiveClientsStore,
@@ -75,6 +92,9 @@ pub(crate) struct FreshHandler<R, S, St> {
     pub(crate) socket_connection: SocketStream<S>,
     pub(crate) storage: St,
     pub(crate) coconut_verifier: Arc<CoconutVerifier>,
+    pub(crate) drain: DrainReceiver,
+    pub(crate) remote_address: IpAddr,
+    pub(crate) rate_limiter: ClientRateLimiter,
 }
 
 impl<R, S, St> FreshHandler<R, S, St>
@@ -96,6 +116,9 @@ where
         storage: St,
         active_clients_store: ActiveClientsStore,
         coconut_verifier: Arc<CoconutVerifier>,
+        drain: DrainReceiver,
+        remote_address: IpAddr,
+        rate_limiter: ClientRateLimiter,
     ) -> Self {
         FreshHandler {
             rng,
@@ -106,7 +129,28 @@ where
             local_identity,
             storage,
             coconut_verifier,
+            drain,
+            remote_address,
+            rate_limiter,
+        }
+    }
+
+    /// If the gateway is being drained, it's no longer accepting any new registrations
+    /// and instead informs the client about the upcoming shutdown.
+    fn drain_response(&self) -> Option<InitialAuthResult> {
+        if !is_draining(&self.drain) {
+            return None;
         }
+        let suggested_gateway = self
+            .drain
+            .borrow()
+            .as_ref()
+            .and_then(|notice| notice.suggested_gateway.clone());
+
+        Some(InitialAuthResult::new(
+            None,
+            ServerResponse::new_shutting_down(suggested_gateway),
+        ))
     }
 
     /// Attempts to perform websocket handshake with the remote and upgrades the raw TCP socket
@@ -426,6 +470,13 @@ where
             .authenticate_client(address, encrypted_address, iv)
             .await?;
         let status = shared_keys.is_some();
+        if !status
+            && self
+                .rate_limiter
+                .record_authentication_failure(self.remote_address)
+        {
+            return Err(InitialAuthenticationError::Banned);
+        }
         let bandwidth_remaining = self
             .storage
             .get_available_bandwidth(address)
@@ -548,7 +599,15 @@ where
                 ClientControlRequest::RegisterHandshakeInitRequest {
                     protocol_version,
                     data,
-                } => self.handle_register(protocol_version, data).await,
+                } => {
+                    // existing clients can still re-authenticate to retrieve their stored messages,
+                    // but we're not going to take on any new ones
+                    if let Some(drain_response) = self.drain_response() {
+                        debug!("rejecting the client registration request as we're shutting down");
+                        return Ok(drain_response);
+                    }
+                    self.handle_register(protocol_version, data).await
+                }
                 // won't accept anything else (like bandwidth) without prior authentication
                 _ => Err(InitialAuthenticationError::InvalidRequest),
             }
@@ -589,12 +648,27 @@ where
                     let (mix_sender, mix_receiver) = mpsc::unbounded();
                     match self.handle_initial_authentication_request(text_msg).await {
                         Err(err) => {
+                            // the failures of otherwise valid 'Authenticate' requests have already
+                            // been recorded while handling them
+                            let banned = matches!(err, InitialAuthenticationError::Banned)
+                                || (err.is_authentication_failure()
+                                    && self
+                                        .rate_limiter
+                                        .record_authentication_failure(self.remote_address));
+
                             if let Err(err) =
                                 self.send_websocket_message(err.into_error_message()).await
                             {
                                 debug!("Failed to send authentication error response - {err}");
                                 return None;
                             }
+                            if banned {
+                                debug!(
+                                    "{} got banned - closing the connection",
+                                    self.remote_address
+                                );
+                                return None;
+                            }
                         }
                         Ok(auth_result) => {
                             if let Err(err) = self
@@ -606,6 +680,8 @@ where
                             }
 
                             return if let Some(client_details) = auth_result.client_details {
+                                self.rate_limiter
+                                    .record_authentication_success(self.remote_address);
                                 self.active_clients_store
                                     .insert(client_details.address, mix_sender);
                                 Some(AuthenticatedHandler::upgrade(
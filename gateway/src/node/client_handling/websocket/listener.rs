@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::node::client_handling::active_clients::ActiveClientsStore;
+use crate::node::client_handling::drain::DrainReceiver;
+use crate::node::client_handling::rate_limiting::ClientRateLimiter;
 use crate::node::client_handling::websocket::connection_handler::coconut::CoconutVerifier;
 use crate::node::client_handling::websocket::connection_handler::FreshHandler;
 use crate::node::storage::Storage;
@@ -12,13 +14,22 @@ use rand::rngs::OsRng;
 use std::net::SocketAddr;
 use std::process;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::task::JoinHandle;
+use tokio_rustls::TlsAcceptor;
+
+// maximum amount of time a client has for completing the TLS handshake
+// before we give up on its connection
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub(crate) struct Listener {
     address: SocketAddr,
     local_identity: Arc<identity::KeyPair>,
     only_coconut_credentials: bool,
     pub(crate) coconut_verifier: Arc<CoconutVerifier>,
+    drain: DrainReceiver,
+    rate_limiter: ClientRateLimiter,
+    tls_acceptor: Option<TlsAcceptor>,
 }
 
 impl Listener {
@@ -27,15 +38,27 @@ impl Listener {
         local_identity: Arc<identity::KeyPair>,
         only_coconut_credentials: bool,
         coconut_verifier: Arc<CoconutVerifier>,
+        drain: DrainReceiver,
+        rate_limiter: ClientRateLimiter,
     ) -> Self {
         Listener {
             address,
             local_identity,
             only_coconut_credentials,
             coconut_verifier,
+            drain,
+            rate_limiter,
+            tls_acceptor: None,
         }
     }
 
+    /// Makes the listener require all incoming connections to be established over TLS,
+    /// i.e. to serve `wss://` rather than `ws://`.
+    pub(crate) fn with_tls_acceptor(mut self, tls_acceptor: TlsAcceptor) -> Self {
+        self.tls_acceptor = Some(tls_acceptor);
+        self
+    }
+
     // TODO: change the signature to pub(crate) async fn run(&self, handler: Handler)
 
     pub(crate) async fn run<St>(
@@ -47,7 +70,11 @@ impl Listener {
     ) where
         St: Storage + Clone + 'static,
     {
-        info!("Starting websocket listener at {}", self.address);
+        if self.tls_acceptor.is_some() {
+            info!("Starting secure websocket listener at {}", self.address);
+        } else {
+            info!("Starting websocket listener at {}", self.address);
+        }
         let tcp_listener = match tokio::net::TcpListener::bind(self.address).await {
             Ok(listener) => listener,
             Err(err) => {
@@ -66,6 +93,23 @@ impl Listener {
                     match connection {
                         Ok((socket, remote_addr)) => {
                             trace!("received a socket connection from {remote_addr}");
+                            if let Err(err) = self.rate_limiter.check_connection(remote_addr.ip()) {
+                                debug!("rejecting the connection: {err}");
+                                continue;
+                            }
+                            if let Some(tls_acceptor) = &self.tls_acceptor {
+                                self.handle_tls_connection(
+                                    tls_acceptor.clone(),
+                                    socket,
+                                    remote_addr,
+                                    outbound_mix_sender.clone(),
+                                    storage.clone(),
+                                    active_clients_store.clone(),
+                                    shutdown.clone(),
+                                );
+                                continue;
+                            }
+
                             // TODO: I think we *REALLY* need a mechanism for having a maximum number of connected
                             // clients or spawned tokio tasks -> perhaps a worker system?
                             let handle = FreshHandler::new(
@@ -77,6 +121,9 @@ impl Listener {
                                 storage.clone(),
                                 active_clients_store.clone(),
                                 Arc::clone(&self.coconut_verifier),
+                                self.drain.clone(),
+                                remote_addr.ip(),
+                                self.rate_limiter.clone(),
                             );
                             let shutdown = shutdown.clone();
                             tokio::spawn(async move { handle.start_handling(shutdown).await });
@@ -89,6 +136,62 @@ impl Listener {
         }
     }
 
+    // the TLS handshake is performed in its own task so that a slow (or malicious) client
+    // would not be able to stall the accept loop
+    #[allow(clippy::too_many_arguments)]
+    fn handle_tls_connection<St>(
+        &self,
+        tls_acceptor: TlsAcceptor,
+        socket: tokio::net::TcpStream,
+        remote_addr: SocketAddr,
+        outbound_mix_sender: MixForwardingSender,
+        storage: St,
+        active_clients_store: ActiveClientsStore,
+        shutdown: nym_task::TaskClient,
+    ) where
+        St: Storage + Clone + 'static,
+    {
+        let only_coconut_credentials = self.only_coconut_credentials;
+        let local_identity = Arc::clone(&self.local_identity);
+        let coconut_verifier = Arc::clone(&self.coconut_verifier);
+        let drain = self.drain.clone();
+        let rate_limiter = self.rate_limiter.clone();
+
+        tokio::spawn(async move {
+            let tls_stream = match tokio::time::timeout(
+                TLS_HANDSHAKE_TIMEOUT,
+                tls_acceptor.accept(socket),
+            )
+            .await
+            {
+                Ok(Ok(tls_stream)) => tls_stream,
+                Ok(Err(err)) => {
+                    debug!("failed to complete the TLS handshake with {remote_addr}: {err}");
+                    return;
+                }
+                Err(_timeout) => {
+                    debug!("timed out while waiting for the TLS handshake with {remote_addr}");
+                    return;
+                }
+            };
+
+            let handle = FreshHandler::new(
+                OsRng,
+                tls_stream,
+                only_coconut_credentials,
+                outbound_mix_sender,
+                local_identity,
+                storage,
+                active_clients_store,
+                coconut_verifier,
+                drain,
+                remote_addr.ip(),
+                rate_limiter,
+            );
+            handle.start_handling(shutdown).await
+        });
+    }
+
     pub(crate) fn start<St>(
         mut self,
         outbound_mix_sender: MixForwardingSender,
@@ -0,0 +1,67 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::error::GatewayError;
+use rustls_pemfile::Item;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::path::Path;
+use std::sync::Arc;
+use tokio_rustls::rustls::{Certificate, PrivateKey, ServerConfig};
+use tokio_rustls::TlsAcceptor;
+
+fn load_certificates(path: &Path) -> io::Result<Vec<Certificate>> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let certs = rustls_pemfile::certs(&mut reader)?;
+    Ok(certs.into_iter().map(Certificate).collect())
+}
+
+fn load_private_key(path: &Path) -> io::Result<Option<PrivateKey>> {
+    let mut reader = BufReader::new(File::open(path)?);
+
+    // use the first key we find, regardless of its encoding
+    for item in rustls_pemfile::read_all(&mut reader)? {
+        match item {
+            Item::RSAKey(key) | Item::PKCS8Key(key) | Item::ECKey(key) => {
+                return Ok(Some(PrivateKey(key)))
+            }
+            _ => continue,
+        }
+    }
+    Ok(None)
+}
+
+/// Creates the acceptor used by the secure (`wss://`) client listener out of the
+/// PEM encoded certificate chain and its private key.
+pub(crate) fn load_tls_acceptor(
+    certificate_path: &Path,
+    private_key_path: &Path,
+) -> Result<TlsAcceptor, GatewayError> {
+    let certs = load_certificates(certificate_path).map_err(|source| {
+        GatewayError::TlsCertificateLoadFailure {
+            path: certificate_path.to_path_buf(),
+            source,
+        }
+    })?;
+    if certs.is_empty() {
+        return Err(GatewayError::NoTlsCertificates {
+            path: certificate_path.to_path_buf(),
+        });
+    }
+
+    let key = load_private_key(private_key_path)
+        .map_err(|source| GatewayError::TlsPrivateKeyLoadFailure {
+            path: private_key_path.to_path_buf(),
+            source,
+        })?
+        .ok_or_else(|| GatewayError::NoTlsPrivateKey {
+            path: private_key_path.to_path_buf(),
+        })?;
+
+    let config = ServerConfig::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)?;
+
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
@@ -10,6 +10,7 @@ use futures::StreamExt;
 use log::*;
 use nym_mixnet_client::forwarder::MixForwardingSender;
 use nym_mixnode_common::packet_processor::processor::ProcessedFinalHop;
+use nym_noise::{upgrade_noise_responder, NoiseCodec, NoiseConfig};
 use nym_sphinx::forwarding::packet::MixPacket;
 use nym_sphinx::framing::codec::NymCodec;
 use nym_sphinx::framing::packet::FramedNymPacket;
@@ -23,6 +24,10 @@ use tokio_util::codec::Framed;
 pub(crate) struct ConnectionHandler<St: Storage> {
     packet_processor: PacketProcessor,
 
+    /// Destination address derived from our own identity key. Packets sent to it are drop cover
+    /// traffic of clients and are meant to be discarded.
+    own_address: DestinationAddressBytes,
+
     // TODO: investigate performance trade-offs for whether this cache even makes sense
     // at this point.
     // keep the following in mind: each action on ActiveClientsStore requires going through RwLock
@@ -31,6 +36,7 @@ pub(crate) struct ConnectionHandler<St: Storage> {
     active_clients_store: ActiveClientsStore,
     storage: St,
     ack_sender: MixForwardingSender,
+    noise_config: Option<NoiseConfig>,
 }
 
 impl<St: Storage + Clone> Clone for ConnectionHandler<St> {
@@ -45,10 +51,12 @@ impl<St: Storage + Clone> Clone for ConnectionHandler<St> {
 
         ConnectionHandler {
             packet_processor: self.packet_processor.clone(),
+            own_address: self.own_address,
             clients_store_cache,
             active_clients_store: self.active_clients_store.clone(),
             storage: self.storage.clone(),
             ack_sender: self.ack_sender.clone(),
+            noise_config: self.noise_config.clone(),
         }
     }
 }
@@ -56,16 +64,20 @@ impl<St: Storage + Clone> Clone for ConnectionHandler<St> {
 impl<St: Storage> ConnectionHandler<St> {
     pub(crate) fn new(
         packet_processor: PacketProcessor,
+        own_address: DestinationAddressBytes,
         storage: St,
         ack_sender: MixForwardingSender,
         active_clients_store: ActiveClientsStore,
+        noise_config: Option<NoiseConfig>,
     ) -> Self {
         ConnectionHandler {
             packet_processor,
+            own_address,
             clients_store_cache: HashMap::new(),
             storage,
             active_clients_store,
             ack_sender,
+            noise_config,
         }
     }
 
@@ -136,6 +148,13 @@ impl<St: Storage> ConnectionHandler<St> {
         let message = processed_final_hop.message;
         let forward_ack = processed_final_hop.forward_ack;
 
+        // drop cover has served its purpose by reaching us. there's nobody to deliver it to
+        // and its ack must not be sent back into the network
+        if client_address == self.own_address {
+            trace!("Discarding received drop cover packet");
+            return;
+        }
+
         // we failed to push message directly to the client - it's probably offline.
         // we should store it on the disk instead.
         match self.try_push_message_to_client(client_address, message) {
@@ -182,7 +201,18 @@ impl<St: Storage> ConnectionHandler<St> {
     ) {
         debug!("Starting connection handler for {:?}", remote);
         shutdown.mark_as_success();
-        let mut framed_conn = Framed::new(conn, NymCodec);
+        let mut framed_conn = match &self.noise_config {
+            Some(noise_config) => {
+                match upgrade_noise_responder(conn, NymCodec, noise_config).await {
+                    Ok(framed_conn) => framed_conn,
+                    Err(err) => {
+                        debug!("Failed to establish the link with {remote:?} - {err}");
+                        return;
+                    }
+                }
+            }
+            None => Framed::new(conn, NoiseCodec::new_legacy(NymCodec)),
+        };
         while !shutdown.is_shutdown() {
             tokio::select! {
                 biased;
@@ -218,3 +248,104 @@ impl<St: Storage> ConnectionHandler<St> {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::storage::InMemStorage;
+    use futures::channel::mpsc;
+    use nym_crypto::asymmetric::{encryption, identity};
+    use nym_sphinx::addressing::nodes::NymNodeRoutingAddress;
+    use nym_sphinx::params::{PacketSize, PacketType};
+    use nym_sphinx::{
+        crypto, Delay, Destination, Node, NodeAddressBytes, NymPacket, DESTINATION_ADDRESS_LENGTH,
+        IDENTIFIER_LENGTH, NODE_ADDRESS_LENGTH,
+    };
+
+    fn dummy_ack() -> MixPacket {
+        let (_, node_pk) = crypto::keygen();
+        let node = Node::new(
+            NodeAddressBytes::from_bytes([5u8; NODE_ADDRESS_LENGTH]),
+            node_pk,
+        );
+        let destination = Destination::new(
+            DestinationAddressBytes::from_bytes([3u8; DESTINATION_ADDRESS_LENGTH]),
+            [4u8; IDENTIFIER_LENGTH],
+        );
+        let packet = NymPacket::sphinx_build(
+            PacketSize::AckPacket.payload_size(),
+            b"foomp",
+            &[node],
+            &destination,
+            &[Delay::new_from_nanos(42)],
+        )
+        .unwrap();
+
+        MixPacket::new(
+            NymNodeRoutingAddress::from("1.2.3.4:1789".parse::<SocketAddr>().unwrap()),
+            packet,
+            PacketType::Mix,
+        )
+    }
+
+    fn test_handler(
+        active_clients_store: ActiveClientsStore,
+    ) -> (
+        ConnectionHandler<InMemStorage>,
+        mpsc::UnboundedReceiver<MixPacket>,
+    ) {
+        let mut rng = rand::rngs::OsRng;
+        let identity_keys = identity::KeyPair::new(&mut rng);
+        let sphinx_keys = encryption::KeyPair::new(&mut rng);
+        let (ack_sender, ack_receiver) = mpsc::unbounded();
+
+        let handler = ConnectionHandler::new(
+            PacketProcessor::new(sphinx_keys.private_key()),
+            identity_keys.public_key().derive_destination_address(),
+            InMemStorage,
+            ack_sender,
+            active_clients_store,
+            None,
+        );
+        (handler, ack_receiver)
+    }
+
+    #[tokio::test]
+    async fn drop_cover_addressed_to_us_is_discarded() {
+        let (mut handler, mut ack_receiver) = test_handler(ActiveClientsStore::new());
+
+        // note: `InMemStorage` would have panicked had we attempted to store the message
+        handler
+            .handle_processed_packet(ProcessedFinalHop {
+                destination: handler.own_address,
+                forward_ack: Some(dummy_ack()),
+                message: b"drop cover".to_vec(),
+            })
+            .await;
+
+        assert!(ack_receiver.try_next().is_err());
+    }
+
+    #[tokio::test]
+    async fn packets_for_connected_clients_are_pushed_and_acked() {
+        let active_clients_store = ActiveClientsStore::new();
+        let client = DestinationAddressBytes::from_bytes([42u8; DESTINATION_ADDRESS_LENGTH]);
+        let (client_sender, mut client_receiver) = mpsc::unbounded();
+        active_clients_store.insert(client, client_sender);
+
+        let (mut handler, mut ack_receiver) = test_handler(active_clients_store);
+        handler
+            .handle_processed_packet(ProcessedFinalHop {
+                destination: client,
+                forward_ack: Some(dummy_ack()),
+                message: b"foomp".to_vec(),
+            })
+            .await;
+
+        assert_eq!(
+            client_receiver.try_next().unwrap().unwrap(),
+            vec![b"foomp".to_vec()]
+        );
+        assert!(ack_receiver.try_next().unwrap().is_some());
+    }
+}
@@ -5,8 +5,11 @@ use self::storage::PersistentStorage;
 use crate::config::Config;
 use crate::error::GatewayError;
 use crate::node::client_handling::active_clients::ActiveClientsStore;
+use crate::node::client_handling::drain::{drain_channel, DrainNotice, DrainReceiver, DrainSender};
+use crate::node::client_handling::rate_limiting::{ClientRateLimiter, RateLimits};
 use crate::node::client_handling::websocket;
 use crate::node::client_handling::websocket::connection_handler::coconut::CoconutVerifier;
+use crate::node::client_handling::websocket::tls::load_tls_acceptor;
 use crate::node::mixnet_handling::receiver::connection_handler::ConnectionHandler;
 use crate::node::statistics::collector::GatewayStatisticsCollector;
 use crate::node::storage::Storage;
@@ -14,7 +17,9 @@ use log::*;
 use nym_bin_common::output_format::OutputFormat;
 use nym_crypto::asymmetric::{encryption, identity};
 use nym_mixnet_client::forwarder::{MixForwardingSender, PacketForwarder};
+use nym_mixnode_common::noise_keys::NoiseKeysRefresher;
 use nym_network_defaults::NymNetworkDetails;
+use nym_noise::{NoiseConfig, NoisePeerKeys};
 use nym_statistics_common::collector::StatisticsSender;
 use nym_task::{TaskClient, TaskManager};
 use nym_validator_client::Client;
@@ -23,6 +28,8 @@ use rand::thread_rng;
 use std::error::Error;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio_rustls::TlsAcceptor;
 
 pub(crate) mod client_handling;
 pub(crate) mod mixnet_handling;
@@ -30,7 +37,7 @@ pub(crate) mod statistics;
 pub(crate) mod storage;
 
 /// Wire up and create Gateway instance
-pub(crate) async fn create_gateway(config: Config) -> Gateway<PersistentStorage> {
+pub async fn create_gateway(config: Config) -> Gateway<PersistentStorage> {
     let storage = initialise_storage(&config).await;
     Gateway::new(config, storage).await
 }
@@ -44,13 +51,18 @@ async fn initialise_storage(config: &Config) -> PersistentStorage {
     }
 }
 
-pub(crate) struct Gateway<St> {
+pub struct Gateway<St> {
     config: Config,
     /// ed25519 keypair used to assert one's identity.
     identity_keypair: Arc<identity::KeyPair>,
     /// x25519 keypair used for Diffie-Hellman. Currently only used for sphinx key derivation.
     sphinx_keypair: Arc<encryption::KeyPair>,
     storage: St,
+    /// Identity key of the gateway that is going to be suggested to the connected clients
+    /// once this gateway starts shutting down.
+    replacement_gateway: Option<String>,
+    /// Noise keys of other nodes in the network used for authenticating the mix links.
+    noise_peer_keys: NoisePeerKeys,
 }
 
 impl<St> Gateway<St> {
@@ -61,9 +73,16 @@ impl<St> Gateway<St> {
             identity_keypair: Arc::new(Self::load_identity_keys(&config)),
             sphinx_keypair: Arc::new(Self::load_sphinx_keys(&config)),
             config,
+            replacement_gateway: None,
+            noise_peer_keys: NoisePeerKeys::new(),
         }
     }
 
+    pub fn with_replacement_gateway(mut self, replacement_gateway: Option<String>) -> Self {
+        self.replacement_gateway = replacement_gateway;
+        self
+    }
+
     #[cfg(test)]
     pub async fn new_from_keys_and_storage(
         config: Config,
@@ -76,11 +95,13 @@ impl<St> Gateway<St> {
             identity_keypair: Arc::new(identity_keypair),
             sphinx_keypair: Arc::new(sphinx_keypair),
             storage,
+            replacement_gateway: None,
+            noise_peer_keys: NoisePeerKeys::new(),
         }
     }
 
     /// Loads identity keys stored on disk
-    pub(crate) fn load_identity_keys(config: &Config) -> identity::KeyPair {
+    pub fn load_identity_keys(config: &Config) -> identity::KeyPair {
         let identity_keypair: identity::KeyPair =
             nym_pemstore::load_keypair(&nym_pemstore::KeyPairPath::new(
                 config.storage_paths.keys.private_identity_key(),
@@ -101,7 +122,7 @@ impl<St> Gateway<St> {
         sphinx_keypair
     }
 
-    pub(crate) fn print_node_details(&self, output: OutputFormat) {
+    pub fn print_node_details(&self, output: OutputFormat) {
         let node_details = nym_types::gateway::GatewayNodeDetailsResponse {
             identity_key: self.identity_keypair.public_key().to_base58_string(),
             sphinx_key: self.sphinx_keypair.public_key().to_base58_string(),
@@ -109,6 +130,7 @@ impl<St> Gateway<St> {
             version: self.config.gateway.version.clone(),
             mix_port: self.config.gateway.mix_port,
             clients_port: self.config.gateway.clients_port,
+            clients_wss_port: self.config.get_advertised_clients_wss_port(),
             data_store: self
                 .config
                 .storage_paths
@@ -120,6 +142,34 @@ impl<St> Gateway<St> {
         println!("{}", output.format(&node_details));
     }
 
+    // the sphinx key is used as the static noise key as that's the x25519 key announced in the topology
+    fn noise_config(&self) -> Option<NoiseConfig> {
+        if !self.config.debug.use_noise_links {
+            return None;
+        }
+
+        Some(
+            NoiseConfig::new(
+                Arc::clone(&self.sphinx_keypair),
+                self.noise_peer_keys.clone(),
+            )
+            .with_legacy_links(self.config.debug.allow_legacy_links)
+            .with_handshake_timeout(self.config.debug.noise_handshake_timeout),
+        )
+    }
+
+    fn start_noise_keys_refresher(&self, shutdown: TaskClient) {
+        info!("Starting noise keys refresher...");
+
+        let mut refresher = NoiseKeysRefresher::new(
+            self.config.get_nym_api_endpoints(),
+            self.noise_peer_keys.clone(),
+            self.config.debug.noise_keys_refresh_rate,
+            shutdown,
+        );
+        tokio::spawn(async move { refresher.run().await });
+    }
+
     fn start_mix_socket_listener(
         &self,
         ack_sender: MixForwardingSender,
@@ -135,9 +185,13 @@ impl<St> Gateway<St> {
 
         let connection_handler = ConnectionHandler::new(
             packet_processor,
+            self.identity_keypair
+                .public_key()
+                .derive_destination_address(),
             self.storage.clone(),
             ack_sender,
             active_clients_store,
+            self.noise_config(),
         );
 
         let listening_address = SocketAddr::new(
@@ -154,7 +208,10 @@ impl<St> Gateway<St> {
         active_clients_store: ActiveClientsStore,
         shutdown: TaskClient,
         coconut_verifier: Arc<CoconutVerifier>,
-    ) where
+        drain: DrainReceiver,
+        rate_limiter: ClientRateLimiter,
+    ) -> Result<(), GatewayError>
+    where
         St: Storage + Clone + 'static,
     {
         info!("Starting client [web]socket listener...");
@@ -164,11 +221,31 @@ impl<St> Gateway<St> {
             self.config.gateway.clients_port,
         );
 
+        if let Some((secure_listening_address, tls_acceptor)) = self.secure_clients_listener()? {
+            websocket::Listener::new(
+                secure_listening_address,
+                Arc::clone(&self.identity_keypair),
+                self.config.gateway.only_coconut_credentials,
+                Arc::clone(&coconut_verifier),
+                drain.clone(),
+                rate_limiter.clone(),
+            )
+            .with_tls_acceptor(tls_acceptor)
+            .start(
+                forwarding_channel.clone(),
+                self.storage.clone(),
+                active_clients_store.clone(),
+                shutdown.clone(),
+            );
+        }
+
         websocket::Listener::new(
             listening_address,
             Arc::clone(&self.identity_keypair),
             self.config.gateway.only_coconut_credentials,
             coconut_verifier,
+            drain,
+            rate_limiter,
         )
         .start(
             forwarding_channel,
@@ -176,6 +253,30 @@ impl<St> Gateway<St> {
             active_clients_store,
             shutdown,
         );
+
+        Ok(())
+    }
+
+    fn secure_clients_listener(&self) -> Result<Option<(SocketAddr, TlsAcceptor)>, GatewayError> {
+        let gateway_cfg = &self.config.gateway;
+        let Some(clients_wss_port) = gateway_cfg.clients_wss_port else {
+            return Ok(None);
+        };
+
+        let (Some(certificate_path), Some(private_key_path)) = (
+            &gateway_cfg.tls_certificate_path,
+            &gateway_cfg.tls_private_key_path,
+        ) else {
+            // the port would have been announced to the clients, so refuse to start rather than
+            // silently not listening on it
+            return Err(GatewayError::MissingTlsCredentials {
+                port: clients_wss_port,
+            });
+        };
+
+        let tls_acceptor = load_tls_acceptor(certificate_path, private_key_path)?;
+        let address = SocketAddr::new(gateway_cfg.listening_address, clients_wss_port);
+        Ok(Some((address, tls_acceptor)))
     }
 
     fn start_packet_forwarder(&self, shutdown: TaskClient) -> MixForwardingSender {
@@ -187,6 +288,7 @@ impl<St> Gateway<St> {
             self.config.debug.initial_connection_timeout,
             self.config.debug.maximum_connection_buffer_size,
             self.config.debug.use_legacy_framed_packet_version,
+            self.noise_config(),
             shutdown,
         );
 
@@ -194,13 +296,77 @@ impl<St> Gateway<St> {
         packet_sender
     }
 
+    /// Informs all connected clients about the upcoming shutdown and waits until they disconnect
+    /// (or until the configured timeout elapses) so that they would have a chance of retrieving
+    /// all of their pending messages.
+    async fn drain_clients(&self, drain: DrainSender, active_clients_store: &ActiveClientsStore) {
+        let drain_timeout = self.config.debug.client_drain_timeout;
+        if drain_timeout.is_zero() {
+            return;
+        }
+
+        let connected = active_clients_store.size();
+        if connected == 0 {
+            debug!("there are no connected clients to drain");
+            return;
+        }
+        info!(
+            "Draining {connected} connected client(s) for up to {drain_timeout:?}... (Press ctrl-c to skip)"
+        );
+
+        let notice = DrainNotice::new(self.replacement_gateway.clone());
+        if drain.send(Some(notice)).is_err() {
+            warn!("there are no client listeners to notify about the shutdown");
+            return;
+        }
+
+        let deadline = tokio::time::sleep(drain_timeout);
+        tokio::pin!(deadline);
+        let mut check_interval = tokio::time::interval(Duration::from_millis(250));
+
+        loop {
+            tokio::select! {
+                _ = &mut deadline => {
+                    warn!(
+                        "{} client(s) still connected after the drain timeout",
+                        active_clients_store.size()
+                    );
+                    break;
+                }
+                _ = tokio::signal::ctrl_c() => {
+                    info!("Received SIGINT - skipping the rest of the client drain");
+                    break;
+                }
+                _ = check_interval.tick() => {
+                    if active_clients_store.size() == 0 {
+                        info!("All clients have disconnected");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
     async fn wait_for_interrupt(
         &self,
-        shutdown: TaskManager,
+        mut shutdown: TaskManager,
+        drain: DrainSender,
+        active_clients_store: ActiveClientsStore,
     ) -> Result<(), Box<dyn Error + Send + Sync>> {
-        let res = shutdown.catch_interrupt().await;
+        let res = nym_task::wait_for_signal_and_error(&mut shutdown).await;
+
+        // only bother with draining the clients if we're stopping gracefully
+        if res.is_ok() {
+            self.drain_clients(drain, &active_clients_store).await;
+        }
+
         log::info!("Stopping nym gateway");
-        res
+        shutdown.signal_shutdown().ok();
+
+        log::info!("Waiting for tasks to finish... (Press ctrl-c to force)");
+        shutdown.wait_for_shutdown().await;
+
+        res.map_err(Into::into)
     }
 
     fn random_api_client(&self) -> nym_validator_client::NymApiClient {
@@ -214,7 +380,10 @@ impl<St> Gateway<St> {
 
     fn random_nyxd_client(
         &self,
-    ) -> nym_validator_client::Client<nym_validator_client::nyxd::DirectSigningNyxdClient> {
+    ) -> Result<
+        nym_validator_client::Client<nym_validator_client::nyxd::DirectSigningNyxdClient>,
+        GatewayError,
+    > {
         let endpoints = self.config.get_nyxd_urls();
         let validator_nyxd = endpoints
             .choose(&mut thread_rng())
@@ -226,12 +395,12 @@ impl<St> Gateway<St> {
         )
         .expect("failed to construct valid validator client config with the provided network");
 
-        let mut client = Client::new_signing(client_config, self.config.get_cosmos_mnemonic())
+        let mut client = Client::new_signing(client_config, self.config.get_cosmos_mnemonic()?)
             .expect("Could not connect with mnemonic");
         client
             .change_nyxd(validator_nyxd.clone())
             .expect("Could not use the random nyxd URL");
-        client
+        Ok(client)
     }
 
     async fn check_if_bonded(&self) -> Result<bool, GatewayError> {
@@ -251,23 +420,22 @@ impl<St> Gateway<St> {
         }))
     }
 
-    pub async fn run(&mut self) -> Result<(), Box<dyn Error + Send + Sync>>
+    fn start_tasks(&self) -> Result<(TaskManager, DrainSender, ActiveClientsStore), GatewayError>
     where
         St: Storage + Clone + 'static,
     {
-        info!("Starting nym gateway!");
-
-        if self.check_if_bonded().await? {
-            warn!("You seem to have bonded your gateway before starting it - that's highly unrecommended as in the future it might result in slashing");
-        }
-
         let shutdown = TaskManager::new(10);
+        let (drain_sender, drain_receiver) = drain_channel();
 
         let coconut_verifier = {
-            let nyxd_client = self.random_nyxd_client();
+            let nyxd_client = self.random_nyxd_client()?;
             CoconutVerifier::new(nyxd_client)
         };
 
+        if self.config.debug.use_noise_links {
+            self.start_noise_keys_refresher(shutdown.subscribe());
+        }
+
         let mix_forwarding_channel = self.start_packet_forwarder(shutdown.subscribe());
 
         let active_clients_store = ActiveClientsStore::new();
@@ -290,15 +458,155 @@ impl<St> Gateway<St> {
             });
         }
 
+        let rate_limiter = ClientRateLimiter::new(RateLimits::from(&self.config.debug));
+        rate_limiter.clone().start_cleanup(shutdown.subscribe());
+
         self.start_client_websocket_listener(
             mix_forwarding_channel,
-            active_clients_store,
+            active_clients_store.clone(),
             shutdown.subscribe(),
             Arc::new(coconut_verifier),
-        );
+            drain_receiver,
+            rate_limiter,
+        )?;
 
         info!("Finished nym gateway startup procedure - it should now be able to receive mix and client traffic!");
 
-        self.wait_for_interrupt(shutdown).await
+        Ok((shutdown, drain_sender, active_clients_store))
+    }
+}
+
+impl Gateway<PersistentStorage> {
+    /// Starts all of the gateway tasks without checking the bonding status or waiting for
+    /// an interrupt, i.e. so that the gateway could be embedded in another process.
+    /// Dropping the returned [`TaskManager`] is going to stop the gateway, however, without
+    /// draining the connected clients first.
+    pub fn start(&self) -> Result<TaskManager, GatewayError> {
+        let (shutdown, _, _) = self.start_tasks()?;
+        Ok(shutdown)
+    }
+
+    pub async fn run(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        info!("Starting nym gateway!");
+
+        if self.check_if_bonded().await? {
+            warn!("You seem to have bonded your gateway before starting it - that's highly unrecommended as in the future it might result in slashing");
+        }
+
+        let (shutdown, drain_sender, active_clients_store) = self.start_tasks()?;
+        self.wait_for_interrupt(shutdown, drain_sender, active_clients_store)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::node::storage::InMemStorage;
+    use futures::channel::mpsc;
+    use nym_sphinx::{DestinationAddressBytes, DESTINATION_ADDRESS_LENGTH};
+
+    #[tokio::test]
+    async fn create_gateway_with_in_mem_storage() {
+        let config = Config::new("foo-id")
+            .with_listening_address("1.1.1.1".parse().unwrap())
+            .with_mix_port(42)
+            .with_clients_port(43);
+
+        let (identity_keys, sphinx_keys) = {
+            let mut rng = rand::rngs::OsRng;
+            (
+                identity::KeyPair::new(&mut rng),
+                encryption::KeyPair::new(&mut rng),
+            )
+        };
+
+        // The test is really if this instantiates with InMemStorage without panics
+        let _gateway =
+            Gateway::new_from_keys_and_storage(config, identity_keys, sphinx_keys, InMemStorage)
+                .await;
+    }
+
+    #[tokio::test]
+    async fn secure_listener_is_not_started_without_tls_credentials() {
+        let config = Config::new("foo-id")
+            .with_listening_address("1.1.1.1".parse().unwrap())
+            .with_clients_wss_port(443);
+
+        let mut rng = rand::rngs::OsRng;
+        let gateway = Gateway::new_from_keys_and_storage(
+            config,
+            identity::KeyPair::new(&mut rng),
+            encryption::KeyPair::new(&mut rng),
+            InMemStorage,
+        )
+        .await;
+
+        // the port must be neither silently ignored nor announced to the clients
+        assert!(matches!(
+            gateway.secure_clients_listener(),
+            Err(GatewayError::MissingTlsCredentials { port: 443 })
+        ));
+        assert!(gateway.config.get_advertised_clients_wss_port().is_none());
+
+        let config = Config::new("foo-id")
+            .with_clients_wss_port(443)
+            .with_tls_certificate_path("/foo/cert.pem".into())
+            .with_tls_private_key_path("/foo/key.pem".into());
+        assert_eq!(config.get_advertised_clients_wss_port(), Some(443));
+    }
+
+    async fn test_gateway() -> Gateway<InMemStorage> {
+        let mut rng = rand::rngs::OsRng;
+        Gateway::new_from_keys_and_storage(
+            Config::new("foo-id"),
+            identity::KeyPair::new(&mut rng),
+            encryption::KeyPair::new(&mut rng),
+            InMemStorage,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn drain_returns_immediately_without_connected_clients() {
+        let gateway = test_gateway().await;
+        let (drain_sender, drain_receiver) = drain_channel();
+
+        tokio::time::timeout(
+            Duration::from_secs(1),
+            gateway.drain_clients(drain_sender, &ActiveClientsStore::new()),
+        )
+        .await
+        .expect("the drain has waited for non-existent clients");
+
+        // there was nobody to notify
+        assert!(drain_receiver.borrow().is_none());
+    }
+
+    #[tokio::test]
+    async fn drain_finishes_once_all_clients_disconnect() {
+        let gateway = test_gateway().await;
+        let (drain_sender, drain_receiver) = drain_channel();
+
+        let active_clients = ActiveClientsStore::new();
+        let client = DestinationAddressBytes::from_bytes([42u8; DESTINATION_ADDRESS_LENGTH]);
+        let (client_sender, _client_receiver) = mpsc::unbounded();
+        active_clients.insert(client, client_sender);
+
+        let disconnecting = active_clients.clone();
+        let mut notified = drain_receiver.clone();
+        tokio::spawn(async move {
+            notified.changed().await.unwrap();
+            disconnecting.disconnect(client);
+        });
+
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            gateway.drain_clients(drain_sender, &active_clients),
+        )
+        .await
+        .expect("the drain has not noticed the client disconnecting");
+
+        assert_eq!(*drain_receiver.borrow(), Some(DrainNotice::new(None)));
     }
 }
@@ -138,7 +138,7 @@ pub(crate) trait Storage: Send + Sync {
 
 // note that clone here is fine as upon cloning the same underlying pool will be used
 #[derive(Clone)]
-pub(crate) struct PersistentStorage {
+pub struct PersistentStorage {
     shared_key_manager: SharedKeysManager,
     inbox_manager: InboxManager,
     bandwidth_manager: BandwidthManager,
@@ -151,7 +151,7 @@ impl PersistentStorage {
     ///
     /// * `database_path`: path to the database.
     /// * `message_retrieval_limit`: maximum number of stored client messages that can be retrieved at once.
-    pub async fn init<P: AsRef<Path> + Send>(
+    pub(crate) async fn init<P: AsRef<Path> + Send>(
         database_path: P,
         message_retrieval_limit: i64,
     ) -> Result<Self, StorageError> {
@@ -21,7 +21,7 @@ use std::str::FromStr;
 use std::time::Duration;
 use url::Url;
 
-pub(crate) mod old_config_v1_1_21;
+pub mod old_config_v1_1_21;
 pub mod persistence;
 mod template;
 
@@ -43,6 +43,10 @@ const DEFAULT_PACKET_FORWARDING_INITIAL_BACKOFF: Duration = Duration::from_milli
 const DEFAULT_PACKET_FORWARDING_MAXIMUM_BACKOFF: Duration = Duration::from_millis(300_000);
 const DEFAULT_INITIAL_CONNECTION_TIMEOUT: Duration = Duration::from_millis(1_500);
 const DEFAULT_MAXIMUM_CONNECTION_BUFFER_SIZE: usize = 2000;
+const DEFAULT_MAXIMUM_PACKET_DELAY: Duration = Duration::from_secs(60);
+const DEFAULT_MAXIMUM_DELAY_QUEUE_SIZE: usize = 100_000;
+const DEFAULT_NOISE_HANDSHAKE_TIMEOUT: Duration = Duration::from_millis(5_000);
+const DEFAULT_NOISE_KEYS_REFRESH_RATE: Duration = Duration::from_secs(60 * 5);
 
 /// Derive default path to mixnodes's config directory.
 /// It should get resolved to `$HOME/.nym/mixnodes/<id>/config`
@@ -276,6 +280,33 @@ pub struct Debug {
     // existing nodes whilst everyone else is upgrading and getting the code for handling the new field.
     // It shall be disabled in the subsequent releases.
     pub use_legacy_framed_packet_version: bool,
+
+    /// Maximum delay a sender is allowed to request for a single packet.
+    /// Packets requesting longer delays are dropped.
+    #[serde(with = "humantime_serde")]
+    pub maximum_packet_delay: Duration,
+
+    /// Maximum number of packets that can be delayed at once.
+    /// Packets received while the delay queue is full are dropped.
+    pub maximum_delay_queue_size: usize,
+
+    /// Specifies whether the links with other nodes should be authenticated and encrypted
+    /// with the noise protocol.
+    pub use_noise_links: bool,
+
+    /// Specifies whether the node should still establish and accept unencrypted links with nodes
+    /// that do not support noise.
+    // it's set to true by default in order to preserve compatibility with the existing nodes
+    // whilst everyone else is upgrading. It shall be disabled in the subsequent releases.
+    pub allow_legacy_links: bool,
+
+    /// Timeout for completing the noise handshake with another node.
+    #[serde(with = "humantime_serde")]
+    pub noise_handshake_timeout: Duration,
+
+    /// Delay between subsequent refreshes of the noise keys of other nodes in the network.
+    #[serde(with = "humantime_serde")]
+    pub noise_keys_refresh_rate: Duration,
 }
 
 impl Default for Debug {
@@ -289,6 +320,12 @@ impl Default for Debug {
             maximum_connection_buffer_size: DEFAULT_MAXIMUM_CONNECTION_BUFFER_SIZE,
             // TODO: remember to change it in one of future releases!!
             use_legacy_framed_packet_version: true,
+            maximum_packet_delay: DEFAULT_MAXIMUM_PACKET_DELAY,
+            maximum_delay_queue_size: DEFAULT_MAXIMUM_DELAY_QUEUE_SIZE,
+            use_noise_links: true,
+            allow_legacy_links: true,
+            noise_handshake_timeout: DEFAULT_NOISE_HANDSHAKE_TIMEOUT,
+            noise_keys_refresh_rate: DEFAULT_NOISE_KEYS_REFRESH_RATE,
         }
     }
 }
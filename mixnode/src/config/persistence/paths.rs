@@ -28,6 +28,14 @@ impl MixNodePaths {
         }
     }
 
+    /// Keeps all of the node's files, including its description, in the provided directory.
+    pub fn new_in_directory<P: AsRef<Path>>(dir: P) -> Self {
+        MixNodePaths {
+            keys: KeysPaths::new_in_directory(dir.as_ref()),
+            node_description: dir.as_ref().join(DEFAULT_DESCRIPTION_FILENAME),
+        }
+    }
+
     pub fn private_identity_key(&self) -> &Path {
         self.keys.private_identity_key()
     }
@@ -62,7 +70,11 @@ pub struct KeysPaths {
 
 impl KeysPaths {
     pub fn new_default<P: AsRef<Path>>(id: P) -> Self {
-        let data_dir = default_data_directory(id);
+        Self::new_in_directory(default_data_directory(id))
+    }
+
+    pub fn new_in_directory<P: AsRef<Path>>(data_dir: P) -> Self {
+        let data_dir = data_dir.as_ref();
 
         KeysPaths {
             private_identity_key_file: data_dir.join(DEFAULT_PRIVATE_IDENTITY_KEY_FILENAME),
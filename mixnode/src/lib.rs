@@ -0,0 +1,8 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+extern crate rocket;
+
+pub mod config;
+pub mod node;
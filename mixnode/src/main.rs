@@ -16,9 +16,11 @@ use nym_bin_common::setup_tracing;
 use nym_mixnode_common::measure;
 #[cfg(feature = "cpucycles")]
 use tracing::instrument;
+
+// the mixnode internals live in the library so that they could also be run in-process
+use nym_mixnode::{config, node};
+
 mod commands;
-mod config;
-mod node;
 
 lazy_static! {
     pub static ref PRETTY_BUILD_INFORMATION: String =
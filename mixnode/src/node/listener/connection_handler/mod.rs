@@ -8,6 +8,7 @@ use crate::node::packet_delayforwarder::PacketDelayForwardSender;
 use crate::node::TaskClient;
 use futures::StreamExt;
 use nym_mixnode_common::measure;
+use nym_noise::{upgrade_noise_responder, NoiseCodec, NoiseConfig};
 use nym_sphinx::forwarding::packet::MixPacket;
 use nym_sphinx::framing::codec::NymCodec;
 use nym_sphinx::framing::packet::FramedNymPacket;
@@ -25,16 +26,19 @@ pub(crate) mod packet_processing;
 pub(crate) struct ConnectionHandler {
     packet_processor: PacketProcessor,
     delay_forwarding_channel: PacketDelayForwardSender,
+    noise_config: Option<NoiseConfig>,
 }
 
 impl ConnectionHandler {
     pub(crate) fn new(
         packet_processor: PacketProcessor,
         delay_forwarding_channel: PacketDelayForwardSender,
+        noise_config: Option<NoiseConfig>,
     ) -> Self {
         ConnectionHandler {
             packet_processor,
             delay_forwarding_channel,
+            noise_config,
         }
     }
 
@@ -86,7 +90,18 @@ impl ConnectionHandler {
     ) {
         debug!("Starting connection handler for {:?}", remote);
         shutdown.mark_as_success();
-        let mut framed_conn = Framed::new(conn, NymCodec);
+        let mut framed_conn = match &self.noise_config {
+            Some(noise_config) => {
+                match upgrade_noise_responder(conn, NymCodec, noise_config).await {
+                    Ok(framed_conn) => framed_conn,
+                    Err(err) => {
+                        debug!("Failed to establish the link with {remote:?} - {err}");
+                        return;
+                    }
+                }
+            }
+            None => Framed::new(conn, NoiseCodec::new_legacy(NymCodec)),
+        };
         while !shutdown.is_shutdown() {
             tokio::select! {
                 biased;
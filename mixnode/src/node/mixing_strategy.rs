@@ -0,0 +1,162 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use futures::Stream;
+use nym_nonexhaustive_delayqueue::NonExhaustiveDelayQueue;
+use nym_sphinx::forwarding::packet::MixPacket;
+use rand::Rng;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+use tokio::time::{Instant, Sleep};
+
+/// Mixing discipline used by the `DelayForwarder` for deciding when received packets should get
+/// released into the network.
+///
+/// The stream of released packets must never terminate, i.e. it should return `Poll::Pending`
+/// rather than `Poll::Ready(None)` if there are no packets to release.
+pub(crate) trait MixingStrategy: Stream<Item = MixPacket> + Unpin {
+    /// Schedules the received packet for future release. `release_at` is the time requested
+    /// by the sender via the sphinx delay encoded in the packet (if any).
+    ///
+    /// If the packet should be forwarded immediately, it is returned back to the caller.
+    fn schedule(&mut self, packet: MixPacket, release_at: Option<Instant>) -> Option<MixPacket>;
+
+    /// Indicates whether a packet with the provided release time would be returned straight away
+    /// by `schedule` rather than being held back.
+    fn releases_immediately(&self, release_at: Option<Instant>) -> bool;
+
+    /// Returns the number of packets currently held back by the strategy.
+    fn len(&self) -> usize;
+}
+
+/// Default mixing discipline that releases each packet exactly after the delay chosen by its sender.
+#[derive(Default)]
+pub(crate) struct SenderSpecifiedDelays {
+    delay_queue: NonExhaustiveDelayQueue<MixPacket>,
+}
+
+impl SenderSpecifiedDelays {
+    pub(crate) fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl MixingStrategy for SenderSpecifiedDelays {
+    fn schedule(&mut self, packet: MixPacket, release_at: Option<Instant>) -> Option<MixPacket> {
+        match release_at {
+            Some(release_at) if !self.releases_immediately(Some(release_at)) => {
+                self.delay_queue.insert_at(packet, release_at);
+                None
+            }
+            _ => Some(packet),
+        }
+    }
+
+    fn releases_immediately(&self, release_at: Option<Instant>) -> bool {
+        // in case of a zero delay packet, or if the delay has already expired, don't bother
+        // putting it through the delay queue only to retrieve it immediately. Just forward it.
+        match release_at {
+            None => true,
+            Some(release_at) => release_at.checked_duration_since(Instant::now()).is_none(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.delay_queue.len()
+    }
+}
+
+impl Stream for SenderSpecifiedDelays {
+    type Item = MixPacket;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.delay_queue)
+            .poll_next(cx)
+            .map(|expired| expired.map(|expired| expired.into_inner()))
+    }
+}
+
+/// Experimental continuous-time pool mix. It ignores the delays chosen by the senders and instead
+/// keeps all received packets in a pool from which, at exponentially distributed intervals,
+/// it releases a uniformly chosen packet as long as the pool holds more than `minimum_pool_size` packets.
+// it's not used by default, but it's available for experimenting with alternative mixing disciplines
+#[allow(dead_code)]
+pub(crate) struct ContinuousTimePool<R> {
+    pool: Vec<MixPacket>,
+    minimum_pool_size: usize,
+    mean_release_interval: Duration,
+    next_release: Pin<Box<Sleep>>,
+    rng: R,
+    waker: Option<Waker>,
+}
+
+#[allow(dead_code)]
+impl<R: Rng> ContinuousTimePool<R> {
+    pub(crate) fn new(
+        mut rng: R,
+        minimum_pool_size: usize,
+        mean_release_interval: Duration,
+    ) -> Self {
+        let first_release = Self::sample_interval(&mut rng, mean_release_interval);
+        ContinuousTimePool {
+            pool: Vec::new(),
+            minimum_pool_size,
+            mean_release_interval,
+            next_release: Box::pin(tokio::time::sleep(first_release)),
+            rng,
+            waker: None,
+        }
+    }
+
+    fn sample_interval(rng: &mut R, mean: Duration) -> Duration {
+        // inverse transform sampling of the exponential distribution
+        let uniform: f64 = rng.gen_range(f64::EPSILON, 1.0);
+        mean.mul_f64(-uniform.ln())
+    }
+}
+
+impl<R: Rng + Unpin> MixingStrategy for ContinuousTimePool<R> {
+    fn schedule(&mut self, packet: MixPacket, _release_at: Option<Instant>) -> Option<MixPacket> {
+        self.pool.push(packet);
+        if self.pool.len() > self.minimum_pool_size {
+            if let Some(waker) = self.waker.take() {
+                waker.wake()
+            }
+        }
+        None
+    }
+
+    fn releases_immediately(&self, _release_at: Option<Instant>) -> bool {
+        false
+    }
+
+    fn len(&self) -> usize {
+        self.pool.len()
+    }
+}
+
+impl<R: Rng + Unpin> Stream for ContinuousTimePool<R> {
+    type Item = MixPacket;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.pool.len() <= self.minimum_pool_size {
+            // we'll need to keep the waker to notify the executor once the pool fills up
+            self.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+
+        match self.next_release.as_mut().poll(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(_) => {
+                let this = &mut *self;
+                let next = Self::sample_interval(&mut this.rng, this.mean_release_interval);
+                this.next_release.as_mut().reset(Instant::now() + next);
+
+                let index = this.rng.gen_range(0, this.pool.len());
+                Poll::Ready(Some(this.pool.swap_remove(index)))
+            }
+        }
+    }
+}
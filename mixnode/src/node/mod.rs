@@ -14,11 +14,13 @@ use crate::node::listener::connection_handler::ConnectionHandler;
 use crate::node::listener::Listener;
 use crate::node::node_description::NodeDescription;
 use crate::node::node_statistics::SharedNodeStats;
-use crate::node::packet_delayforwarder::{DelayForwarder, PacketDelayForwardSender};
+use crate::node::packet_delayforwarder::{DelayForwarder, DelayLimits, PacketDelayForwardSender};
 use nym_bin_common::output_format::OutputFormat;
 use nym_bin_common::version_checker::parse_version;
 use nym_crypto::asymmetric::{encryption, identity};
+use nym_mixnode_common::noise_keys::NoiseKeysRefresher;
 use nym_mixnode_common::verloc::{self, AtomicVerlocResult, VerlocMeasurer};
+use nym_noise::{NoiseConfig, NoisePeerKeys};
 use nym_task::{TaskClient, TaskManager};
 use rand::seq::SliceRandom;
 use rand::thread_rng;
@@ -31,7 +33,8 @@ use tracing::{error, info, warn};
 
 mod http;
 mod listener;
-pub(crate) mod node_description;
+mod mixing_strategy;
+pub mod node_description;
 mod node_statistics;
 mod packet_delayforwarder;
 
@@ -41,6 +44,7 @@ pub struct MixNode {
     descriptor: NodeDescription,
     identity_keypair: Arc<identity::KeyPair>,
     sphinx_keypair: Arc<encryption::KeyPair>,
+    noise_peer_keys: NoisePeerKeys,
 }
 
 impl MixNode {
@@ -49,6 +53,7 @@ impl MixNode {
             descriptor: Self::load_node_description(&config),
             identity_keypair: Arc::new(Self::load_identity_keys(&config)),
             sphinx_keypair: Arc::new(Self::load_sphinx_keys(&config)),
+            noise_peer_keys: NoisePeerKeys::new(),
             config,
         }
     }
@@ -58,7 +63,7 @@ impl MixNode {
     }
 
     /// Loads identity keys stored on disk
-    pub(crate) fn load_identity_keys(config: &Config) -> identity::KeyPair {
+    pub fn load_identity_keys(config: &Config) -> identity::KeyPair {
         let identity_keypair: identity::KeyPair =
             nym_pemstore::load_keypair(&nym_pemstore::KeyPairPath::new(
                 config.storage_paths.keys.private_identity_key(),
@@ -80,7 +85,7 @@ impl MixNode {
     }
 
     /// Prints relevant node details to the console
-    pub(crate) fn print_node_details(&self, output: OutputFormat) {
+    pub fn print_node_details(&self, output: OutputFormat) {
         let node_details = nym_types::mixnode::MixnodeNodeDetailsResponse {
             identity_key: self.identity_keypair.public_key().to_base58_string(),
             sphinx_key: self.sphinx_keypair.public_key().to_base58_string(),
@@ -142,6 +147,34 @@ impl MixNode {
         (node_stats_pointer, update_sender)
     }
 
+    // the sphinx key is used as the static noise key as that's the x25519 key announced in the topology
+    fn noise_config(&self) -> Option<NoiseConfig> {
+        if !self.config.debug.use_noise_links {
+            return None;
+        }
+
+        Some(
+            NoiseConfig::new(
+                Arc::clone(&self.sphinx_keypair),
+                self.noise_peer_keys.clone(),
+            )
+            .with_legacy_links(self.config.debug.allow_legacy_links)
+            .with_handshake_timeout(self.config.debug.noise_handshake_timeout),
+        )
+    }
+
+    fn start_noise_keys_refresher(&self, shutdown: TaskClient) {
+        info!("Starting noise keys refresher...");
+
+        let mut refresher = NoiseKeysRefresher::new(
+            self.config.get_nym_api_endpoints(),
+            self.noise_peer_keys.clone(),
+            self.config.debug.noise_keys_refresh_rate,
+            shutdown,
+        );
+        tokio::spawn(async move { refresher.run().await });
+    }
+
     fn start_socket_listener(
         &self,
         node_stats_update_sender: node_statistics::UpdateSender,
@@ -153,7 +186,11 @@ impl MixNode {
         let packet_processor =
             PacketProcessor::new(self.sphinx_keypair.private_key(), node_stats_update_sender);
 
-        let connection_handler = ConnectionHandler::new(packet_processor, delay_forwarding_channel);
+        let connection_handler = ConnectionHandler::new(
+            packet_processor,
+            delay_forwarding_channel,
+            self.noise_config(),
+        );
 
         let listening_address = SocketAddr::new(
             self.config.mixnode.listening_address,
@@ -176,10 +213,17 @@ impl MixNode {
             self.config.debug.initial_connection_timeout,
             self.config.debug.maximum_connection_buffer_size,
             self.config.debug.use_legacy_framed_packet_version,
+            self.noise_config(),
         );
 
+        let delay_limits = DelayLimits {
+            maximum_packet_delay: self.config.debug.maximum_packet_delay,
+            maximum_queue_size: self.config.debug.maximum_delay_queue_size,
+        };
+
         let mut packet_forwarder = DelayForwarder::new(
             nym_mixnet_client::Client::new(client_config),
+            delay_limits,
             node_stats_update_sender,
             shutdown,
         );
@@ -265,17 +309,17 @@ impl MixNode {
         log::info!("Stopping nym mixnode");
     }
 
-    pub async fn run(&mut self) {
-        info!("Starting nym mixnode");
-
-        if self.check_if_bonded().await {
-            warn!("You seem to have bonded your mixnode before starting it - that's highly unrecommended as in the future it might result in slashing");
-        }
-
+    /// Starts all of the mixnode tasks without checking the bonding status or waiting for
+    /// an interrupt, i.e. so that the node could be embedded in another process.
+    /// Dropping the returned [`TaskManager`] is going to stop the node.
+    pub fn start(&mut self) -> TaskManager {
         let shutdown = TaskManager::default();
 
         let (node_stats_pointer, node_stats_update_sender) =
             self.start_node_stats_controller(shutdown.subscribe());
+        if self.config.debug.use_noise_links {
+            self.start_noise_keys_refresher(shutdown.subscribe());
+        }
         let delay_forwarding_channel = self
             .start_packet_delay_forwarder(node_stats_update_sender.clone(), shutdown.subscribe());
         self.start_socket_listener(
@@ -291,6 +335,17 @@ impl MixNode {
         self.start_http_api(atomic_verloc_results, node_stats_pointer);
 
         info!("Finished nym mixnode startup procedure - it should now be able to receive mix traffic!");
+        shutdown
+    }
+
+    pub async fn run(&mut self) {
+        info!("Starting nym mixnode");
+
+        if self.check_if_bonded().await {
+            warn!("You seem to have bonded your mixnode before starting it - that's highly unrecommended as in the future it might result in slashing");
+        }
+
+        let shutdown = self.start();
         self.wait_for_interrupt(shutdown).await
     }
 }
@@ -1,12 +1,13 @@
 // Copyright 2020 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::node::mixing_strategy::{MixingStrategy, SenderSpecifiedDelays};
 use crate::node::node_statistics::UpdateSender;
 use futures::channel::mpsc;
 use futures::StreamExt;
-use nym_nonexhaustive_delayqueue::{Expired, NonExhaustiveDelayQueue};
 use nym_sphinx::forwarding::packet::MixPacket;
 use std::io;
+use std::time::Duration;
 use tokio::time::Instant;
 
 use super::TaskClient;
@@ -18,12 +19,25 @@ use super::TaskClient;
 pub(crate) type PacketDelayForwardSender = mpsc::UnboundedSender<(MixPacket, Option<Instant>)>;
 type PacketDelayForwardReceiver = mpsc::UnboundedReceiver<(MixPacket, Option<Instant>)>;
 
+/// Bounds imposed on the packets received by the `DelayForwarder`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DelayLimits {
+    /// Maximum delay a sender is allowed to request for a packet. Packets exceeding it are dropped.
+    pub(crate) maximum_packet_delay: Duration,
+
+    /// Maximum number of packets that can be held back at once. Packets received while
+    /// the queue is full are dropped.
+    pub(crate) maximum_queue_size: usize,
+}
+
 /// Entity responsible for delaying received sphinx packet and forwarding it to next node.
-pub(crate) struct DelayForwarder<C>
+pub(crate) struct DelayForwarder<C, M = SenderSpecifiedDelays>
 where
     C: nym_mixnet_client::SendWithoutResponse,
+    M: MixingStrategy,
 {
-    delay_queue: NonExhaustiveDelayQueue<MixPacket>,
+    mixing_strategy: M,
+    limits: DelayLimits,
     mixnet_client: C,
     packet_sender: PacketDelayForwardSender,
     packet_receiver: PacketDelayForwardReceiver,
@@ -37,13 +51,37 @@ where
 {
     pub(crate) fn new(
         client: C,
+        limits: DelayLimits,
         node_stats_update_sender: UpdateSender,
         shutdown: TaskClient,
     ) -> DelayForwarder<C> {
+        DelayForwarder::new_with_strategy(
+            client,
+            SenderSpecifiedDelays::new(),
+            limits,
+            node_stats_update_sender,
+            shutdown,
+        )
+    }
+}
+
+impl<C, M> DelayForwarder<C, M>
+where
+    C: nym_mixnet_client::SendWithoutResponse,
+    M: MixingStrategy,
+{
+    pub(crate) fn new_with_strategy(
+        client: C,
+        mixing_strategy: M,
+        limits: DelayLimits,
+        node_stats_update_sender: UpdateSender,
+        shutdown: TaskClient,
+    ) -> DelayForwarder<C, M> {
         let (packet_sender, packet_receiver) = mpsc::unbounded();
 
-        DelayForwarder::<C> {
-            delay_queue: NonExhaustiveDelayQueue::new(),
+        DelayForwarder {
+            mixing_strategy,
+            limits,
             mixnet_client: client,
             packet_sender,
             packet_receiver,
@@ -83,24 +121,48 @@ where
     }
 
     /// Upon packet being finished getting delayed, forward it to the mixnet.
-    fn handle_done_delaying(&mut self, packet: Expired<MixPacket>) {
-        let delayed_packet = packet.into_inner();
-        self.forward_packet(delayed_packet)
+    fn handle_done_delaying(&mut self, packet: MixPacket) {
+        self.forward_packet(packet)
     }
 
-    fn handle_new_packet(&mut self, new_packet: (MixPacket, Option<Instant>)) {
-        // in case of a zero delay packet, don't bother putting it in the delay queue,
-        // just forward it immediately
-        if let Some(instant) = new_packet.1 {
-            // check if the delay has already expired, if so, don't bother putting it through
-            // the delay queue only to retrieve it immediately. Just forward it.
-            if instant.checked_duration_since(Instant::now()).is_none() {
-                self.forward_packet(new_packet.0)
-            } else {
-                self.delay_queue.insert_at(new_packet.0, instant);
+    /// Checks whether the received packet is within the configured bounds.
+    fn within_limits(&self, forward_at: Option<Instant>) -> bool {
+        if self.mixing_strategy.len() >= self.limits.maximum_queue_size {
+            log::debug!("the delay queue is full - dropping the packet");
+            return false;
+        }
+
+        if let Some(forward_at) = forward_at {
+            let delay = forward_at.saturating_duration_since(Instant::now());
+            if delay > self.limits.maximum_packet_delay {
+                log::debug!(
+                    "the packet requested delay of {delay:?} which is higher than the allowed maximum - dropping it"
+                );
+                return false;
             }
-        } else {
-            self.forward_packet(new_packet.0)
+        }
+
+        true
+    }
+
+    fn handle_new_packet(&mut self, new_packet: (MixPacket, Option<Instant>)) {
+        let (packet, forward_at) = new_packet;
+
+        // packets that are not going to be delayed never take up any space in the queue,
+        // so they must go through even if it's full
+        if self.mixing_strategy.releases_immediately(forward_at) {
+            self.forward_packet(packet);
+            return;
+        }
+
+        if !self.within_limits(forward_at) {
+            self.node_stats_update_sender
+                .report_dropped(packet.next_hop().to_string());
+            return;
+        }
+
+        if let Some(packet) = self.mixing_strategy.schedule(packet, forward_at) {
+            self.forward_packet(packet)
         }
     }
 
@@ -108,7 +170,8 @@ where
         log::trace!("Starting DelayForwarder");
         loop {
             tokio::select! {
-                delayed = self.delay_queue.next() => {
+                delayed = self.mixing_strategy.next() => {
+                    // the mixing strategies never terminate their streams
                     self.handle_done_delaying(delayed.unwrap());
                 }
                 new_packet = self.packet_receiver.next() => {
@@ -129,6 +192,9 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::node::mixing_strategy::ContinuousTimePool;
+    use crate::node::node_statistics::PacketEvent;
+    use rand::rngs::OsRng;
 
     use std::net::{IpAddr, Ipv4Addr, SocketAddr};
     use std::sync::{Arc, Mutex};
@@ -145,6 +211,17 @@ mod tests {
         DESTINATION_ADDRESS_LENGTH, IDENTIFIER_LENGTH, NODE_ADDRESS_LENGTH,
     };
 
+    fn test_limits() -> DelayLimits {
+        DelayLimits {
+            maximum_packet_delay: Duration::from_secs(10),
+            maximum_queue_size: 100,
+        }
+    }
+
+    fn test_next_hop() -> NymNodeRoutingAddress {
+        NymNodeRoutingAddress::from(SocketAddr::new(IpAddr::V4(Ipv4Addr::new(1, 2, 3, 4)), 42))
+    }
+
     #[derive(Default)]
     struct TestClient {
         pub packets_sent: Arc<Mutex<Vec<(NymNodeRoutingAddress, NymPacket, PacketType)>>>,
@@ -239,8 +316,12 @@ mod tests {
         let client = TestClient::default();
         let client_packets_sent = client.packets_sent.clone();
         let shutdown = TaskManager::default();
-        let mut delay_forwarder =
-            DelayForwarder::new(client, node_stats_update_sender, shutdown.subscribe());
+        let mut delay_forwarder = DelayForwarder::new(
+            client,
+            test_limits(),
+            node_stats_update_sender,
+            shutdown.subscribe(),
+        );
         let packet_sender = delay_forwarder.sender();
 
         // Spawn the worker, listening on packet_sender channel
@@ -282,8 +363,12 @@ mod tests {
         let client = TestClient::default();
         let client_packets_sent = client.packets_sent.clone();
         let shutdown = TaskManager::default();
-        let mut delay_forwarder =
-            DelayForwarder::new(client, node_stats_update_sender, shutdown.subscribe());
+        let mut delay_forwarder = DelayForwarder::new(
+            client,
+            test_limits(),
+            node_stats_update_sender,
+            shutdown.subscribe(),
+        );
         let packet_sender = delay_forwarder.sender();
 
         // Spawn the worker, listening on packet_sender channel
@@ -316,4 +401,148 @@ mod tests {
             vec![next_hop]
         );
     }
+
+    #[tokio::test]
+    async fn packets_exceeding_maximum_delay_are_dropped() {
+        let (stats_sender, mut stats_receiver) = mpsc::unbounded();
+        let node_stats_update_sender = UpdateSender::new(stats_sender);
+        let client = TestClient::default();
+        let client_packets_sent = client.packets_sent.clone();
+        let shutdown = TaskManager::default();
+        let mut delay_forwarder = DelayForwarder::new(
+            client,
+            test_limits(),
+            node_stats_update_sender,
+            shutdown.subscribe(),
+        );
+
+        let mix_packet = MixPacket::new(
+            test_next_hop(),
+            make_valid_sphinx_packet(PacketSize::default()),
+            PacketType::default(),
+        );
+        let forward_instant = Some(Instant::now() + Duration::from_secs(3600));
+        delay_forwarder.handle_new_packet((mix_packet, forward_instant));
+
+        assert_eq!(delay_forwarder.mixing_strategy.len(), 0);
+        assert!(client_packets_sent.lock().unwrap().is_empty());
+        assert!(matches!(
+            stats_receiver.try_next(),
+            Ok(Some(PacketEvent::Dropped(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn packets_received_with_full_queue_are_dropped() {
+        let (stats_sender, mut stats_receiver) = mpsc::unbounded();
+        let node_stats_update_sender = UpdateSender::new(stats_sender);
+        let client = TestClient::default();
+        let shutdown = TaskManager::default();
+        let limits = DelayLimits {
+            maximum_queue_size: 2,
+            ..test_limits()
+        };
+        let mut delay_forwarder = DelayForwarder::new(
+            client,
+            limits,
+            node_stats_update_sender,
+            shutdown.subscribe(),
+        );
+
+        for _ in 0..3 {
+            let mix_packet = MixPacket::new(
+                test_next_hop(),
+                make_valid_sphinx_packet(PacketSize::default()),
+                PacketType::default(),
+            );
+            let forward_instant = Some(Instant::now() + Duration::from_secs(5));
+            delay_forwarder.handle_new_packet((mix_packet, forward_instant));
+        }
+
+        assert_eq!(delay_forwarder.mixing_strategy.len(), 2);
+        assert!(matches!(
+            stats_receiver.try_next(),
+            Ok(Some(PacketEvent::Dropped(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn zero_delay_packets_are_forwarded_with_full_queue() {
+        let (stats_sender, mut stats_receiver) = mpsc::unbounded();
+        let node_stats_update_sender = UpdateSender::new(stats_sender);
+        let client = TestClient::default();
+        let client_packets_sent = client.packets_sent.clone();
+        let shutdown = TaskManager::default();
+        let limits = DelayLimits {
+            maximum_queue_size: 1,
+            ..test_limits()
+        };
+        let mut delay_forwarder = DelayForwarder::new(
+            client,
+            limits,
+            node_stats_update_sender,
+            shutdown.subscribe(),
+        );
+
+        let delayed_packet = MixPacket::new(
+            test_next_hop(),
+            make_valid_sphinx_packet(PacketSize::default()),
+            PacketType::default(),
+        );
+        let forward_instant = Some(Instant::now() + Duration::from_secs(5));
+        delay_forwarder.handle_new_packet((delayed_packet, forward_instant));
+        assert_eq!(delay_forwarder.mixing_strategy.len(), 1);
+
+        for forward_instant in [None, Some(Instant::now() - Duration::from_secs(1))] {
+            let mix_packet = MixPacket::new(
+                test_next_hop(),
+                make_valid_sphinx_packet(PacketSize::default()),
+                PacketType::default(),
+            );
+            delay_forwarder.handle_new_packet((mix_packet, forward_instant));
+        }
+
+        assert_eq!(delay_forwarder.mixing_strategy.len(), 1);
+        assert_eq!(client_packets_sent.lock().unwrap().len(), 2);
+        for _ in 0..2 {
+            assert!(matches!(
+                stats_receiver.try_next(),
+                Ok(Some(PacketEvent::Sent(_)))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn continuous_time_pool_keeps_minimum_number_of_packets() {
+        let (stats_sender, _stats_receiver) = mpsc::unbounded();
+        let node_stats_update_sender = UpdateSender::new(stats_sender);
+        let client = TestClient::default();
+        let client_packets_sent = client.packets_sent.clone();
+        let shutdown = TaskManager::default();
+        let pool = ContinuousTimePool::new(OsRng, 2, Duration::from_millis(1));
+        let mut delay_forwarder = DelayForwarder::new_with_strategy(
+            client,
+            pool,
+            test_limits(),
+            node_stats_update_sender,
+            shutdown.subscribe(),
+        );
+        let packet_sender = delay_forwarder.sender();
+        tokio::spawn(async move { delay_forwarder.run().await });
+
+        for _ in 0..5 {
+            let mix_packet = MixPacket::new(
+                test_next_hop(),
+                make_valid_sphinx_packet(PacketSize::default()),
+                PacketType::default(),
+            );
+            packet_sender.unbounded_send((mix_packet, None)).unwrap();
+        }
+
+        // Give the the worker a chance to act
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        // everything above the minimum pool size should have been released
+        assert_eq!(client_packets_sent.lock().unwrap().len(), 3);
+    }
 }
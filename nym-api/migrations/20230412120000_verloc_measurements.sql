@@ -0,0 +1,23 @@
+/*
+ * Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+CREATE TABLE verloc_measurement
+(
+    id                        INTEGER PRIMARY KEY AUTOINCREMENT,
+    source_identity           VARCHAR NOT NULL,
+    target_identity           VARCHAR NOT NULL,
+    -- unix timestamp of when the source node finished its measurement run
+    run_finished              INTEGER NOT NULL,
+    minimum_micros            INTEGER NOT NULL,
+    mean_micros               INTEGER NOT NULL,
+    maximum_micros            INTEGER NOT NULL,
+    standard_deviation_micros INTEGER NOT NULL,
+
+    UNIQUE (source_identity, target_identity, run_finished)
+);
+
+CREATE INDEX verloc_measurement_run_finished ON verloc_measurement(`run_finished`);
+CREATE INDEX verloc_measurement_source ON verloc_measurement(`source_identity`);
+CREATE INDEX verloc_measurement_target ON verloc_measurement(`target_identity`);
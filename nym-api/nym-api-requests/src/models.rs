@@ -337,3 +337,50 @@ pub struct CirculatingSupplyResponse {
     pub vesting_tokens: Coin,
     pub circulating_supply: Coin,
 }
+
+/// Single verloc (round-trip time) measurement performed by the source mixnode towards the target mixnode.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct VerlocLatency {
+    pub minimum_micros: u64,
+    pub mean_micros: u64,
+    pub maximum_micros: u64,
+    pub standard_deviation_micros: u64,
+
+    /// Unix timestamp of when the source node has finished its measurement run.
+    pub measured_at: i64,
+}
+
+/// Node-to-node latency matrix constructed from the most recent verloc measurements of all mixnodes.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct LatencyMatrixResponse {
+    /// Identity keys of all nodes included in the matrix. They define the ordering of the rows and columns.
+    pub nodes: Vec<IdentityKey>,
+
+    /// `latencies[i][j]` is the latest measurement performed by `nodes[i]` towards `nodes[j]` (if any).
+    pub latencies: Vec<Vec<Option<VerlocLatency>>>,
+
+    /// Only measurements performed after this unix timestamp were considered.
+    pub since: i64,
+}
+
+/// Aggregated verloc measurements associated with a single mixnode for a particular measurement run.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct VerlocTrendPoint {
+    /// Unix timestamp of when the measurement run has finished.
+    pub timestamp: i64,
+
+    /// Number of nodes this node has successfully measured in this run.
+    pub measured_nodes: u32,
+
+    /// Average of the mean round-trip times towards all measured nodes.
+    pub average_mean_micros: u64,
+
+    /// The lowest round-trip time observed towards any node.
+    pub minimum_micros: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct VerlocHistoryResponse {
+    pub identity: IdentityKey,
+    pub history: Vec<VerlocTrendPoint>,
+}
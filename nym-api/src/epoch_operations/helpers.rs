@@ -1,10 +1,14 @@
 // Copyright 2022 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::epoch_operations::error::RewardingError;
 use crate::epoch_operations::RewardedSetUpdater;
+use crate::node_status_api::models::Uptime;
 use cosmwasm_std::{Decimal, Fraction};
+use nym_mixnet_contract_common::jailing::{JailedMixnode, MisbehaviourEvidence};
 use nym_mixnet_contract_common::reward_params::Performance;
-use nym_mixnet_contract_common::{ExecuteMsg, Interval, MixId};
+use nym_mixnet_contract_common::{EpochId, ExecuteMsg, IdentityKey, Interval, MixId, Percent};
+use std::collections::HashSet;
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct MixnodeWithPerformance {
@@ -22,6 +26,44 @@ impl From<MixnodeWithPerformance> for ExecuteMsg {
     }
 }
 
+#[derive(Debug, Clone)]
+pub(crate) struct GatewayWithPerformance {
+    pub(crate) identity: IdentityKey,
+
+    pub(crate) performance: Performance,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MixnodeToJail {
+    pub(crate) mix_id: MixId,
+
+    pub(crate) jail_epochs: u32,
+
+    pub(crate) slash_fraction: Option<Percent>,
+
+    pub(crate) evidence: MisbehaviourEvidence,
+}
+
+impl From<MixnodeToJail> for ExecuteMsg {
+    fn from(jailing: MixnodeToJail) -> Self {
+        ExecuteMsg::JailMixnode {
+            mix_id: jailing.mix_id,
+            jail_epochs: jailing.jail_epochs,
+            slash_fraction: jailing.slash_fraction,
+            evidence: jailing.evidence,
+        }
+    }
+}
+
+impl From<GatewayWithPerformance> for ExecuteMsg {
+    fn from(gateway_reward: GatewayWithPerformance) -> Self {
+        ExecuteMsg::RewardGateway {
+            identity: gateway_reward.identity,
+            performance: gateway_reward.performance,
+        }
+    }
+}
+
 pub(super) fn stake_to_f64(stake: Decimal) -> f64 {
     let max = f64::MAX.round() as u128;
 
@@ -36,6 +78,19 @@ pub(super) fn stake_to_f64(stake: Decimal) -> f64 {
     }
 }
 
+/// Returns ids of all nodes that are going to remain jailed during the provided epoch
+/// and thus can't be included in its rewarded set.
+pub(super) fn jailed_in_epoch(
+    jailed: &[JailedMixnode],
+    absolute_epoch_id: EpochId,
+) -> HashSet<MixId> {
+    jailed
+        .iter()
+        .filter(|jail| jail.is_jailed_in(absolute_epoch_id))
+        .map(|jail| jail.mix_id)
+        .collect()
+}
+
 impl RewardedSetUpdater {
     pub(crate) async fn load_performance(
         &self,
@@ -68,6 +123,28 @@ impl RewardedSetUpdater {
         }
         with_performance
     }
+
+    pub(crate) async fn load_gateways_performance(
+        &self,
+        interval: &Interval,
+        gateways: &HashSet<IdentityKey>,
+    ) -> Result<Vec<GatewayWithPerformance>, RewardingError> {
+        let reliabilities = self
+            .storage
+            .get_all_avg_gateway_reliability_in_last_24hr(
+                interval.current_epoch_end_unix_timestamp(),
+            )
+            .await?;
+
+        Ok(reliabilities
+            .into_iter()
+            .filter(|reliability| gateways.contains(reliability.identity()))
+            .map(|reliability| GatewayWithPerformance {
+                identity: reliability.identity().to_string(),
+                performance: Uptime::new(reliability.value()).into(),
+            })
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -118,4 +195,34 @@ mod tests {
             compare_large_floats(expected_f64, stake_to_f64(decimal))
         }
     }
+
+    #[test]
+    fn jailed_nodes_are_excluded_until_their_release() {
+        let jailed = vec![
+            JailedMixnode {
+                mix_id: 1,
+                jailed_at_epoch: 10,
+                released_at_epoch: 12,
+                offences: 1,
+            },
+            JailedMixnode {
+                mix_id: 2,
+                jailed_at_epoch: 5,
+                released_at_epoch: 11,
+                offences: 3,
+            },
+            // jail period that's already over
+            JailedMixnode {
+                mix_id: 3,
+                jailed_at_epoch: 1,
+                released_at_epoch: 3,
+                offences: 1,
+            },
+        ];
+
+        assert_eq!(jailed_in_epoch(&jailed, 10), HashSet::from([1, 2]));
+        assert_eq!(jailed_in_epoch(&jailed, 11), HashSet::from([1]));
+        assert!(jailed_in_epoch(&jailed, 12).is_empty());
+        assert!(jailed_in_epoch(&[], 10).is_empty());
+    }
 }
@@ -0,0 +1,343 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::epoch_operations::error::RewardingError;
+use crate::epoch_operations::helpers::{jailed_in_epoch, MixnodeToJail};
+use crate::node_status_api::models::Uptime;
+use crate::node_status_api::ONE_DAY;
+use crate::RewardedSetUpdater;
+use nym_mixnet_contract_common::jailing::MisbehaviourEvidence;
+use nym_mixnet_contract_common::mixnode::MixNodeBond;
+use nym_mixnet_contract_common::{Addr, Interval, MixId, Percent, UnbondedMixnode};
+use std::collections::{HashMap, HashSet};
+
+// TODO: Make thresholds configurable
+// nodes whose average reliability over the last 24h, as observed by the network monitor,
+// did not exceed this value are considered to be dropping packets
+const PACKET_DROPPING_RELIABILITY_THRESHOLD: f32 = 10.0;
+
+// the node has to have been measured in at least this fraction of all network monitor runs
+// during the observation window, so that the nodes that have only just bonded wouldn't get jailed
+const MINIMUM_MEASUREMENT_COVERAGE: f32 = 0.9;
+
+// and we need a reasonable amount of data in the first place, for example if the nym-api
+// has only just been started
+const MINIMUM_MONITOR_RUNS: usize = 24;
+
+const PACKET_DROPPING_JAIL_EPOCHS: u32 = 24;
+
+const DUPLICATE_IDENTITY_JAIL_EPOCHS: u32 = 720;
+const DUPLICATE_IDENTITY_SLASH_PERCENTAGE: u64 = 5;
+
+/// Network monitor measurements of particular mixnode during the observation window.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct MixnodeObservation {
+    pub(super) mix_id: MixId,
+
+    /// Average reliability of the node over all of its measurements.
+    pub(super) reliability: f32,
+
+    /// Number of network monitor runs in which the node has been measured.
+    pub(super) measurements: usize,
+}
+
+/// Determines whether the observed node has been consistently dropping packets
+/// and if so, returns the details of its jailing alongside the evidence.
+pub(super) fn packet_dropping_offence(
+    observation: MixnodeObservation,
+    monitor_runs: usize,
+    epochs_observed: u32,
+) -> Option<MixnodeToJail> {
+    if monitor_runs < MINIMUM_MONITOR_RUNS {
+        return None;
+    }
+
+    if (observation.measurements as f32) < monitor_runs as f32 * MINIMUM_MEASUREMENT_COVERAGE {
+        return None;
+    }
+
+    if observation.reliability > PACKET_DROPPING_RELIABILITY_THRESHOLD {
+        return None;
+    }
+
+    Some(MixnodeToJail {
+        mix_id: observation.mix_id,
+        jail_epochs: PACKET_DROPPING_JAIL_EPOCHS,
+        // the network monitor is not infallible, so we only exclude the node from the rewarded set
+        slash_fraction: None,
+        evidence: MisbehaviourEvidence::PacketDropping {
+            observed_performance: Uptime::new(observation.reliability).into(),
+            epochs_observed,
+        },
+    })
+}
+
+/// Finds the bonded mixnodes that are using an identity that has already been registered
+/// by a (now unbonded) mixnode of a different owner.
+pub(super) fn duplicate_identity_offences(
+    bonded: &[MixNodeBond],
+    unbonded: &[(MixId, UnbondedMixnode)],
+) -> Vec<MixnodeToJail> {
+    let mut previous_registrations: HashMap<&str, Vec<(MixId, &Addr)>> = HashMap::new();
+    for (mix_id, node) in unbonded {
+        previous_registrations
+            .entry(node.identity_key.as_str())
+            .or_default()
+            .push((*mix_id, &node.owner));
+    }
+
+    bonded
+        .iter()
+        .filter_map(|bond| {
+            // point at the earliest registration of the identity
+            let duplicate_of = previous_registrations
+                .get(bond.identity())?
+                .iter()
+                .filter(|(mix_id, owner)| *mix_id < bond.mix_id && *owner != &bond.owner)
+                .map(|(mix_id, _)| *mix_id)
+                .min()?;
+
+            Some(MixnodeToJail {
+                mix_id: bond.mix_id,
+                jail_epochs: DUPLICATE_IDENTITY_JAIL_EPOCHS,
+                slash_fraction: Some(
+                    Percent::from_percentage_value(DUPLICATE_IDENTITY_SLASH_PERCENTAGE)
+                        .expect("the slash percentage is valid"),
+                ),
+                evidence: MisbehaviourEvidence::DuplicateIdentity { duplicate_of },
+            })
+        })
+        .collect()
+}
+
+impl RewardedSetUpdater {
+    /// Jails the bonded mixnodes that the network monitor has seen consistently dropping packets
+    /// or that have registered an identity already used by another node.
+    /// Note: it has to happen before the epoch transition begins as the contract only allows
+    /// jailing while the epoch is in progress. It also means the jailed nodes won't get rewarded for it.
+    pub(super) async fn jail_misbehaving_mixnodes(
+        &self,
+        interval: Interval,
+    ) -> Result<(), RewardingError> {
+        // no point in jailing the same node again every single epoch
+        let jailed = self.nyxd_client.get_jailed_mixnodes().await?;
+        let jailed = jailed_in_epoch(&jailed, interval.current_epoch_absolute_id());
+
+        let candidates = self
+            .nym_contract_cache
+            .mixnodes_all()
+            .await
+            .into_iter()
+            .map(|node| node.bond_information)
+            .filter(|bond| !bond.is_unbonding && !jailed.contains(&bond.mix_id))
+            .collect::<Vec<_>>();
+
+        let mut to_jail = self
+            .packet_dropping_offences(&interval, &candidates)
+            .await?;
+
+        let unbonded = self.nyxd_client.get_unbonded_mixnodes().await?;
+        for offence in duplicate_identity_offences(&candidates, &unbonded) {
+            // the duplicate identity carries the harsher penalty, so it takes precedence
+            to_jail.retain(|jail| jail.mix_id != offence.mix_id);
+            to_jail.push(offence);
+        }
+
+        if to_jail.is_empty() {
+            log::info!("There are no misbehaving mixnodes to jail in this epoch");
+            return Ok(());
+        }
+
+        to_jail.sort_by_key(|jail| jail.mix_id);
+        for jail in &to_jail {
+            log::info!(
+                "Jailing mixnode {} for {} epochs. evidence: {:?}",
+                jail.mix_id,
+                jail.jail_epochs,
+                jail.evidence
+            );
+        }
+
+        if let Err(err) = self.nyxd_client.send_jailing_messages(&to_jail).await {
+            error!(
+                "failed to jail misbehaving mixnodes in epoch {}! Error encountered: {err}",
+                interval.current_epoch_absolute_id(),
+            );
+            return Err(err.into());
+        }
+
+        log::info!("jailed {} mixnodes...", to_jail.len());
+
+        Ok(())
+    }
+
+    async fn packet_dropping_offences(
+        &self,
+        interval: &Interval,
+        candidates: &[MixNodeBond],
+    ) -> Result<Vec<MixnodeToJail>, RewardingError> {
+        let end = interval.current_epoch_end_unix_timestamp();
+        let start = end - ONE_DAY.as_secs() as i64;
+
+        let monitor_runs = self.storage.get_monitor_runs_count(start, end).await?;
+        let epochs_observed = (ONE_DAY.as_secs() / interval.epoch_length_secs()) as u32;
+
+        let candidates = candidates
+            .iter()
+            .map(|bond| bond.mix_id)
+            .collect::<HashSet<_>>();
+        let reliabilities = self
+            .storage
+            .get_all_avg_mix_reliability_in_last_24hr(end)
+            .await?;
+
+        let mut offences = Vec::new();
+        for reliability in reliabilities {
+            // don't bother looking up the measurements of the nodes that are performing fine
+            if !candidates.contains(&reliability.mix_id())
+                || reliability.value() > PACKET_DROPPING_RELIABILITY_THRESHOLD
+            {
+                continue;
+            }
+
+            let measurements = self
+                .storage
+                .get_mixnode_statuses(reliability.mix_id(), start)
+                .await?
+                .into_iter()
+                .filter(|status| status.timestamp() <= end)
+                .count();
+
+            let observation = MixnodeObservation {
+                mix_id: reliability.mix_id(),
+                reliability: reliability.value(),
+                measurements,
+            };
+            offences.extend(packet_dropping_offence(
+                observation,
+                monitor_runs,
+                epochs_observed,
+            ));
+        }
+
+        Ok(offences)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nym_mixnet_contract_common::{Coin, Layer, MixNode};
+
+    fn observation(reliability: f32, measurements: usize) -> MixnodeObservation {
+        MixnodeObservation {
+            mix_id: 42,
+            reliability,
+            measurements,
+        }
+    }
+
+    fn bond(mix_id: MixId, identity: &str, owner: &str) -> MixNodeBond {
+        MixNodeBond::new(
+            mix_id,
+            Addr::unchecked(owner),
+            Coin::new(100_000_000, "unym"),
+            Layer::One,
+            MixNode {
+                host: "1.2.3.4".to_string(),
+                mix_port: 1789,
+                verloc_port: 1790,
+                http_api_port: 8000,
+                sphinx_key: format!("sphinx-{mix_id}"),
+                identity_key: identity.to_string(),
+                version: "1.1.24".to_string(),
+            },
+            None,
+            12345,
+        )
+    }
+
+    fn unbonded(mix_id: MixId, identity: &str, owner: &str) -> (MixId, UnbondedMixnode) {
+        (
+            mix_id,
+            UnbondedMixnode {
+                identity_key: identity.to_string(),
+                owner: Addr::unchecked(owner),
+                proxy: None,
+                unbonding_height: 23456,
+            },
+        )
+    }
+
+    #[test]
+    fn consistently_dropping_node_is_jailed_with_the_evidence() {
+        let jail = packet_dropping_offence(observation(5.0, 96), 96, 24).unwrap();
+        assert_eq!(
+            jail,
+            MixnodeToJail {
+                mix_id: 42,
+                jail_epochs: PACKET_DROPPING_JAIL_EPOCHS,
+                slash_fraction: None,
+                evidence: MisbehaviourEvidence::PacketDropping {
+                    observed_performance: Percent::from_percentage_value(5).unwrap(),
+                    epochs_observed: 24,
+                },
+            }
+        );
+
+        // the threshold itself is still considered to be dropping
+        assert!(packet_dropping_offence(
+            observation(PACKET_DROPPING_RELIABILITY_THRESHOLD, 96),
+            96,
+            24
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn node_above_the_reliability_threshold_is_not_jailed() {
+        assert!(packet_dropping_offence(observation(10.5, 96), 96, 24).is_none());
+        assert!(packet_dropping_offence(observation(80.0, 96), 96, 24).is_none());
+    }
+
+    #[test]
+    fn node_is_not_jailed_without_sufficient_measurements() {
+        // only measured in a fraction of the runs, for example because it has only just bonded
+        assert!(packet_dropping_offence(observation(0.0, 20), 96, 24).is_none());
+        assert!(packet_dropping_offence(observation(0.0, 86), 96, 24).is_none());
+        assert!(packet_dropping_offence(observation(0.0, 87), 96, 24).is_some());
+
+        // the network monitor hasn't been running for long enough
+        let runs = MINIMUM_MONITOR_RUNS - 1;
+        assert!(packet_dropping_offence(observation(0.0, runs), runs, 24).is_none());
+    }
+
+    #[test]
+    fn reusing_identity_of_another_operator_is_detected() {
+        let bonded = vec![
+            bond(5, "reused", "impostor"),
+            bond(6, "rebonded", "honest-operator"),
+            bond(7, "fresh", "another-operator"),
+        ];
+        let unbonded = vec![
+            unbonded(1, "reused", "original-operator"),
+            unbonded(3, "reused", "yet-another-operator"),
+            // the same operator bonding their node again is perfectly fine
+            unbonded(2, "rebonded", "honest-operator"),
+        ];
+
+        assert_eq!(
+            duplicate_identity_offences(&bonded, &unbonded),
+            vec![MixnodeToJail {
+                mix_id: 5,
+                jail_epochs: DUPLICATE_IDENTITY_JAIL_EPOCHS,
+                slash_fraction: Some(
+                    Percent::from_percentage_value(DUPLICATE_IDENTITY_SLASH_PERCENTAGE).unwrap()
+                ),
+                evidence: MisbehaviourEvidence::DuplicateIdentity { duplicate_of: 1 },
+            }]
+        );
+
+        assert!(duplicate_identity_offences(&bonded, &[]).is_empty());
+    }
+}
@@ -17,7 +17,7 @@ use crate::nym_contract_cache::cache::NymContractCache;
 use crate::support::nyxd::Client;
 use crate::support::storage::NymApiStorage;
 use error::RewardingError;
-pub(crate) use helpers::MixnodeWithPerformance;
+pub(crate) use helpers::{GatewayWithPerformance, MixnodeToJail, MixnodeWithPerformance};
 use nym_mixnet_contract_common::{CurrentIntervalResponse, Interval};
 use nym_task::{TaskClient, TaskManager};
 use std::collections::HashSet;
@@ -27,6 +27,7 @@ use tokio::time::sleep;
 pub(crate) mod error;
 mod event_reconciliation;
 mod helpers;
+mod jailing;
 mod rewarded_set_assignment;
 mod rewarding;
 mod transition_beginning;
@@ -60,7 +61,11 @@ impl RewardedSetUpdater {
     /// Upon each epoch having finished the following actions are executed by this nym-api:
     /// 1. it queries the mixnet contract to check the current `EpochState` in order to figure out whether
     ///     a different nym-api has already started epoch transition (not yet applicable)
-    /// 2. it sends a `BeginEpochTransition` message to the mixnet contract causing the following to happen:
+    /// 2. if the epoch transition hasn't begun yet, it sends (in a single batch) `JailMixnode` message
+    ///    for each bonded mixnode that the network monitor has consistently seen dropping packets
+    ///    or that has registered an identity already used by a different operator, alongside the evidence.
+    ///    This way they're also not going to be rewarded for the epoch that has just finished.
+    /// 3. it sends a `BeginEpochTransition` message to the mixnet contract causing the following to happen:
     ///     - if successful, the address of the this validator is going to be saved as being responsible for progressing this epoch.
     ///     What it means in practice is that once we have multiple instances of nym-api running,
     ///     only this one will try to perform the rest of the actions. It will also allow it to
@@ -70,20 +75,23 @@ impl RewardedSetUpdater {
     ///     until that is done.
     ///     - ability to send transactions (by other users) that get resolved once given epoch/interval rolls over,
     ///     such as `BondMixnode` or `DelegateToMixnode` will temporarily be frozen until the entire procedure is finished.
-    /// 3. it obtains the current rewarded set and for each node in there (**SORTED BY MIX_ID!!**),
+    /// 4. it obtains the current rewarded set and for each node in there (**SORTED BY MIX_ID!!**),
     ///    it sends (in a single batch) `RewardMixnode` message with the measured performance.
     ///    Once the final message gets executed, the mixnet contract automatically transitions
     ///    the state to `ReconcilingEvents`.
-    /// 4. it obtains the number of pending epoch and interval events and repeatedly sends
+    /// 5. it obtains the list of all currently bonded gateways and (in a single batch) sends
+    ///    `RewardGateway` message for each of them with their measured performance.
+    ///    Gateways that have already been rewarded in this epoch are skipped by the contract.
+    /// 6. it obtains the number of pending epoch and interval events and repeatedly sends
     ///    `ReconcileEpochEvents` transaction until all of them are resolved.
     ///    At this point the mixnet contract automatically transitions the state to `AdvancingEpoch`.
-    /// 5. it obtains the list of all nodes on the network and pseudorandomly (but weighted by total stake)
+    /// 7. it obtains the list of all nodes on the network (excluding the jailed ones) and pseudorandomly (but weighted by total stake)
     ///    determines the new rewarded set. It then assigns layers to the provided nodes taking
     ///    family information into consideration. Finally it sends `AdvanceCurrentEpoch` message
     ///    containing the set and layer information thus rolling over the epoch and changing the state
     ///    to `InProgress`.
-    /// 6. it purges old (older than 48h) measurement data
-    /// 7. the whole process repeats once the new epoch finishes
+    /// 8. it purges old (older than 48h) measurement data
+    /// 9. the whole process repeats once the new epoch finishes
     async fn perform_epoch_operations(&self, interval: Interval) -> Result<(), RewardingError> {
         log::info!("The current epoch has finished.");
         log::info!(
@@ -120,6 +128,13 @@ impl RewardedSetUpdater {
                 warn!("we seem to have crashed mid-epoch advancement...");
             }
         } else {
+            // this can only be done while the epoch is still in progress
+            log::info!("Jailing misbehaving mixnodes...");
+            if let Err(err) = self.jail_misbehaving_mixnodes(interval).await {
+                // it's not a good enough reason to stop the epoch from being advanced
+                error!("failed to jail misbehaving mixnodes - {err}");
+            }
+
             let should_continue = self.begin_epoch_transition().await?;
             if !should_continue {
                 return Ok(());
@@ -130,6 +145,10 @@ impl RewardedSetUpdater {
         log::info!("Rewarding the current rewarded set...");
         self.reward_current_rewarded_set(interval).await?;
 
+        // Reward all the currently bonded gateways based on their measured performance
+        log::info!("Rewarding the bonded gateways...");
+        self.reward_gateways(interval).await?;
+
         // note: those operations don't really have to be atomic, so it's fine to send them
         // as separate transactions
         self.reconcile_epoch_events().await?;
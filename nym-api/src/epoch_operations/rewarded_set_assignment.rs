@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::epoch_operations::error::RewardingError;
-use crate::epoch_operations::helpers::stake_to_f64;
+use crate::epoch_operations::helpers::{jailed_in_epoch, stake_to_f64};
 use crate::RewardedSetUpdater;
 use cosmwasm_std::Decimal;
 use nym_mixnet_contract_common::families::FamilyHead;
@@ -159,9 +159,24 @@ impl RewardedSetUpdater {
         match epoch_status.state {
             EpochState::AdvancingEpoch => {
                 log::info!("Advancing epoch and updating the rewarded set...");
-                let nodes_with_performance = self
-                    .attach_performance(current_interval, all_mixnodes)
-                    .await;
+
+                // the contract rejects any rewarded set containing nodes that are jailed
+                // in the upcoming epoch, so they must not be considered during the selection
+                let next_epoch = current_interval.advance_epoch().current_epoch_absolute_id();
+                let jailed = self.nyxd_client.get_jailed_mixnodes().await?;
+                let jailed = jailed_in_epoch(&jailed, next_epoch);
+                if !jailed.is_empty() {
+                    log::info!("Excluding jailed mixnodes from the rewarded set: {jailed:?}");
+                }
+
+                let eligible = all_mixnodes
+                    .iter()
+                    .filter(|mix| !jailed.contains(&mix.mix_id()))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                let nodes_with_performance =
+                    self.attach_performance(current_interval, &eligible).await;
 
                 if let Err(err) = self
                     ._update_rewarded_set_and_advance_epoch(nodes_with_performance)
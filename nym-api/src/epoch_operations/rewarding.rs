@@ -5,6 +5,7 @@ use crate::epoch_operations::error::RewardingError;
 use crate::epoch_operations::helpers::MixnodeWithPerformance;
 use crate::RewardedSetUpdater;
 use nym_mixnet_contract_common::{EpochState, Interval, MixId};
+use std::collections::HashSet;
 
 impl RewardedSetUpdater {
     pub(super) async fn reward_current_rewarded_set(
@@ -67,6 +68,48 @@ impl RewardedSetUpdater {
         Ok(())
     }
 
+    pub(super) async fn reward_gateways(
+        &self,
+        current_interval: Interval,
+    ) -> Result<(), RewardingError> {
+        // only bother rewarding gateways that are still bonded according to our most recent view
+        let bonded_gateways = self
+            .nym_contract_cache
+            .gateways_all()
+            .await
+            .into_iter()
+            .map(|bond| bond.gateway.identity_key)
+            .collect::<HashSet<_>>();
+
+        let mut to_reward = self
+            .load_gateways_performance(&current_interval, &bonded_gateways)
+            .await?;
+        // note: the contract splits the remaining epoch budget evenly between all bonded gateways
+        // upon rewarding the first one, so the ordering here doesn't affect the rewards
+        to_reward.sort_by(|a, b| a.identity.cmp(&b.identity));
+
+        if to_reward.is_empty() {
+            log::info!("There are no gateways to reward in this epoch");
+            return Ok(());
+        }
+
+        if let Err(err) = self
+            .nyxd_client
+            .send_gateway_rewarding_messages(&to_reward)
+            .await
+        {
+            error!(
+                "failed to perform gateway rewarding for epoch {}! Error encountered: {err}",
+                current_interval.current_epoch_absolute_id(),
+            );
+            return Err(err.into());
+        }
+
+        log::info!("rewarded {} gateways...", to_reward.len());
+
+        Ok(())
+    }
+
     async fn nodes_to_reward(&self, interval: Interval) -> Vec<MixnodeWithPerformance> {
         // try to get current up to date view of the network bypassing the cache
         // in case the epochs were significantly shortened for the purposes of testing
@@ -33,6 +33,7 @@ mod network_monitor;
 pub(crate) mod node_status_api;
 pub(crate) mod nym_contract_cache;
 pub(crate) mod support;
+mod verloc_collector;
 
 struct ShutdownHandles {
     task_manager_handle: TaskManager,
@@ -107,6 +108,17 @@ async fn start_nym_api_tasks(
         &shutdown,
     );
 
+    if config.verloc_collector.enabled {
+        // if verloc collector is enabled, the storage MUST BE available
+        let storage = maybe_storage.unwrap();
+        verloc_collector::start(
+            &config.verloc_collector,
+            nym_contract_cache_state,
+            storage,
+            &shutdown,
+        );
+    }
+
     // start dkg task
     if config.coconut_signer.enabled {
         DkgController::start(
@@ -14,10 +14,14 @@ use nym_mixnet_contract_common::{
 };
 
 use nym_name_service_common::response::NamesListResponse;
-use nym_service_provider_directory_common::response::ServicesListResponse;
+use nym_service_provider_directory_common::{
+    response::{ServiceWithLiveness, ServicesListResponse},
+    DEFAULT_MAX_HEARTBEAT_AGE_SECS,
+};
 use rocket::{serde::json::Json, State};
 use rocket_okapi::openapi;
 use std::collections::HashSet;
+use time::OffsetDateTime;
 
 #[openapi(tag = "contract-cache")]
 #[get("/mixnodes")]
@@ -135,6 +139,21 @@ pub async fn get_services(cache: &State<NymContractCache>) -> Json<ServicesListR
     Json(services.as_slice().into())
 }
 
+#[openapi(tag = "contract-cache")]
+#[get("/services/detailed")]
+pub async fn get_services_detailed(
+    cache: &State<NymContractCache>,
+) -> Json<Vec<ServiceWithLiveness>> {
+    let now = OffsetDateTime::now_utc().unix_timestamp() as u64;
+    let services = cache.services().await.value;
+    Json(
+        services
+            .into_iter()
+            .map(|service| ServiceWithLiveness::new(service, now, DEFAULT_MAX_HEARTBEAT_AGE_SECS))
+            .collect(),
+    )
+}
+
 #[openapi(tag = "contract-cache")]
 #[get("/names")]
 pub async fn get_registered_names(cache: &State<NymContractCache>) -> Json<NamesListResponse> {
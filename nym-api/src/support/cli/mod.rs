@@ -67,7 +67,8 @@ pub(crate) struct CliArgs {
     #[clap(long)]
     pub(crate) vesting_contract: Option<nyxd::AccountId>,
 
-    /// Mnemonic of the network monitor used for rewarding operators
+    /// Mnemonic of the network monitor used for rewarding operators.
+    /// It is going to be stored in a separate, encrypted, secrets file rather than in the config
     // even though we're currently converting the mnemonic to string (and then back to the concrete type)
     // at least we're getting immediate validation when passing the arguments
     #[clap(long)]
@@ -107,8 +108,13 @@ pub(crate) struct CliArgs {
     pub(crate) enable_coconut: Option<bool>,
 }
 
-pub(crate) fn override_config(config: Config, args: CliArgs) -> Config {
-    config
+pub(crate) fn override_config(config: Config, args: CliArgs) -> Result<Config> {
+    // the mnemonic is not part of the config file, so it has to be persisted separately
+    if let Some(mnemonic) = &args.mnemonic {
+        config.store_mnemonic(mnemonic)?;
+    }
+
+    Ok(config
         .with_optional(Config::with_custom_nyxd_validator, args.nyxd_validator)
         .with_optional_env(
             Config::with_custom_mixnet_contract,
@@ -120,7 +126,6 @@ pub(crate) fn override_config(config: Config, args: CliArgs) -> Config {
             args.vesting_contract,
             VESTING_CONTRACT_ADDRESS,
         )
-        .with_optional(Config::with_mnemonic, args.mnemonic)
         .with_optional(
             Config::with_minimum_interval_monitor_threshold,
             args.monitor_threshold,
@@ -140,7 +145,7 @@ pub(crate) fn override_config(config: Config, args: CliArgs) -> Config {
             args.enabled_credentials_mode.map(|b| !b),
         )
         .with_optional(Config::with_announce_address, args.announce_address)
-        .with_optional(Config::with_coconut_signer_enabled, args.enable_coconut)
+        .with_optional(Config::with_coconut_signer_enabled, args.enable_coconut))
 }
 
 pub(crate) fn build_config(args: CliArgs) -> Result<Config> {
@@ -160,7 +165,5 @@ pub(crate) fn build_config(args: CliArgs) -> Result<Config> {
         }
     };
 
-    let config = override_config(config, args);
-
-    Ok(config)
+    override_config(config, args)
 }
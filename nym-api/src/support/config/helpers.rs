@@ -2,10 +2,35 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::support::config::old_config_v1_1_21::ConfigV1_1_21;
+use crate::support::config::old_config_v1_1_23::ConfigV1_1_23;
+use crate::support::config::persistence::DEFAULT_MNEMONIC_FILENAME;
 use crate::support::config::{default_config_directory, default_data_directory, Config};
 use anyhow::Result;
+use std::path::PathBuf;
 use std::{fs, io};
 
+// moves the mnemonic out of the config file into the secrets file at the provided location
+fn migrate_v1_1_23_config(old_config: ConfigV1_1_23, mnemonic_path: PathBuf) -> Result<Config> {
+    let mnemonic = old_config.base.mnemonic.clone();
+    let mut updated: Config = old_config.into();
+    updated.base.mnemonic_path = mnemonic_path;
+
+    updated.store_mnemonic(&mnemonic)?;
+    info!(
+        "The mnemonic got moved to {}",
+        updated.base.mnemonic_path.display()
+    );
+    Ok(updated)
+}
+
+fn upgrade_from_v1_1_23_config(old_config: ConfigV1_1_23) -> Result<()> {
+    // make sure the mnemonic is safely stored before overwriting the config file
+    let mnemonic_path = default_data_directory(&old_config.base.id).join(DEFAULT_MNEMONIC_FILENAME);
+    let updated = migrate_v1_1_23_config(old_config, mnemonic_path)?;
+
+    Ok(updated.save_to_default_location()?)
+}
+
 fn try_upgrade_v1_1_21_config(id: &str) -> Result<()> {
     use nym_config::legacy_helpers::nym_config::MigrationNymConfig;
 
@@ -18,8 +43,22 @@ fn try_upgrade_v1_1_21_config(id: &str) -> Result<()> {
     info!("It seems the nym-api is using <= v1.1.21 config template.");
     info!("It is going to get updated to the current specification.");
 
-    let updated: Config = old_config.into();
-    Ok(updated.save_to_default_location()?)
+    upgrade_from_v1_1_23_config(old_config.into())
+}
+
+fn try_upgrade_v1_1_23_config(id: &str) -> Result<()> {
+    use nym_config::legacy_helpers::nym_config::MigrationNymConfig;
+
+    // explicitly load it as v1.1.23 (which still contained the mnemonic in the config file)
+    let Ok(old_config) = ConfigV1_1_23::load_from_file(id) else {
+        // if we failed to load it, there might have been nothing to upgrade
+        // or maybe it was an even older file. in either way. just ignore it and carry on with our day
+        return Ok(());
+    };
+    info!("It seems the nym-api is using <= v1.1.23 config template.");
+    info!("It is going to get updated to the current specification.");
+
+    upgrade_from_v1_1_23_config(old_config)
 }
 
 fn init_paths(id: &str) -> io::Result<()> {
@@ -31,11 +70,58 @@ pub(crate) fn initialise_new(id: &str) -> Result<Config> {
     let config = Config::new(id);
     init_paths(id)?;
     crate::coconut::dkg::controller::init_keypair(&config.coconut_signer)?;
+
+    // the nyxd client can't be constructed without a mnemonic,
+    // so generate a fresh one in case it's not going to be provided
+    if !config.base.mnemonic_path.exists() {
+        config.store_mnemonic(&bip39::Mnemonic::generate(24).unwrap())?;
+    }
     Ok(config)
 }
 
 pub(crate) fn try_load_current_config(id: &str) -> Result<Config> {
     try_upgrade_v1_1_21_config(id)?;
+    try_upgrade_v1_1_23_config(id)?;
 
     Ok(Config::read_from_default_path(id)?)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::support::config::old_config_v1_1_23::BaseV1_1_23;
+    use crate::support::config::MNEMONIC_ENV_VARS;
+
+    #[test]
+    fn v1_1_23_config_migration_moves_the_mnemonic_out_of_the_config() {
+        std::env::set_var(MNEMONIC_ENV_VARS.passphrase, "migration-passphrase");
+        let dir = tempfile::tempdir().unwrap();
+        let mnemonic_path = dir.path().join(DEFAULT_MNEMONIC_FILENAME);
+
+        let mnemonic = bip39::Mnemonic::generate(24).unwrap();
+        let current = Config::new("migrated-nym-api");
+        let old_config = ConfigV1_1_23 {
+            base: BaseV1_1_23 {
+                id: current.base.id.clone(),
+                local_validator: current.base.local_validator.clone(),
+                mixnet_contract_address: current.base.mixnet_contract_address.clone(),
+                vesting_contract_address: current.base.vesting_contract_address.clone(),
+                mnemonic: mnemonic.clone(),
+            },
+            network_monitor: current.network_monitor,
+            node_status_api: current.node_status_api,
+            topology_cacher: current.topology_cacher,
+            circulating_supply_cacher: current.circulating_supply_cacher,
+            verloc_collector: current.verloc_collector,
+            rewarding: current.rewarding,
+            coconut_signer: current.coconut_signer,
+        };
+
+        let migrated = migrate_v1_1_23_config(old_config, mnemonic_path.clone()).unwrap();
+        assert_eq!(migrated.base.mnemonic_path, mnemonic_path);
+
+        let stored = fs::read_to_string(&mnemonic_path).unwrap();
+        assert!(!stored.contains(&mnemonic.to_string()));
+        assert_eq!(migrated.get_mnemonic().unwrap(), mnemonic);
+    }
+}
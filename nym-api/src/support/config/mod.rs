@@ -2,10 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::support::config::persistence::{
-    CoconutSignerPaths, NetworkMonitorPaths, NodeStatusAPIPaths,
+    CoconutSignerPaths, NetworkMonitorPaths, NodeStatusAPIPaths, DEFAULT_MNEMONIC_FILENAME,
 };
 use crate::support::config::template::CONFIG_TEMPLATE;
+use anyhow::Context;
 use nym_config::defaults::mainnet;
+use nym_config::secrets::SecretEnvVars;
 use nym_config::{
     must_get_home, read_config_from_toml_file, save_formatted_config_to_file, NymConfigTemplate,
     DEFAULT_CONFIG_DIR, DEFAULT_CONFIG_FILENAME, DEFAULT_DATA_DIR, NYM_DIR,
@@ -16,10 +18,11 @@ use std::io;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 use url::Url;
-use zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::Zeroizing;
 
 pub(crate) mod helpers;
 pub(crate) mod old_config_v1_1_21;
+pub(crate) mod old_config_v1_1_23;
 mod persistence;
 mod template;
 
@@ -50,10 +53,22 @@ const DEFAULT_PER_NODE_TEST_PACKETS: usize = 3;
 const DEFAULT_TOPOLOGY_CACHE_INTERVAL: Duration = Duration::from_secs(30);
 const DEFAULT_NODE_STATUS_CACHE_INTERVAL: Duration = Duration::from_secs(120);
 const DEFAULT_CIRCULATING_SUPPLY_CACHE_INTERVAL: Duration = Duration::from_secs(3600);
+const DEFAULT_VERLOC_COLLECTION_INTERVAL: Duration = Duration::from_secs(3600);
+const DEFAULT_VERLOC_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+const DEFAULT_VERLOC_MAX_CONCURRENT_REQUESTS: usize = 32;
+const DEFAULT_VERLOC_RETENTION: Duration = Duration::from_secs(30 * 24 * 60 * 60);
 const DEFAULT_MONITOR_THRESHOLD: u8 = 60;
 const DEFAULT_MIN_MIXNODE_RELIABILITY: u8 = 50;
 const DEFAULT_MIN_GATEWAY_RELIABILITY: u8 = 20;
 
+/// Environment variables that can be used for providing the mnemonic
+/// instead of (or for decrypting) the secrets file.
+pub const MNEMONIC_ENV_VARS: SecretEnvVars = SecretEnvVars {
+    value: "NYM_API_MNEMONIC",
+    file: "NYM_API_MNEMONIC_FILE",
+    passphrase: "NYM_API_SECRETS_PASSPHRASE",
+};
+
 /// Derive default path to nym-api's config directory.
 /// It should get resolved to `$HOME/.nym/nym-api/<id>/config`
 pub fn default_config_directory<P: AsRef<Path>>(id: P) -> PathBuf {
@@ -93,6 +108,9 @@ pub struct Config {
 
     pub circulating_supply_cacher: CirculatingSupplyCacher,
 
+    #[serde(default)]
+    pub verloc_collector: VerlocCollector,
+
     pub rewarding: Rewarding,
 
     pub coconut_signer: CoconutSigner,
@@ -113,6 +131,7 @@ impl Config {
             node_status_api: NodeStatusAPI::new_default(&base_data_dir),
             topology_cacher: Default::default(),
             circulating_supply_cacher: Default::default(),
+            verloc_collector: Default::default(),
             rewarding: Default::default(),
             coconut_signer: CoconutSigner::new_default(base_data_dir),
         }
@@ -175,11 +194,6 @@ impl Config {
         self
     }
 
-    pub fn with_mnemonic(mut self, mnemonic: bip39::Mnemonic) -> Self {
-        self.base.mnemonic = mnemonic;
-        self
-    }
-
     pub fn with_minimum_interval_monitor_threshold(mut self, threshold: u8) -> Self {
         self.rewarding.debug.minimum_interval_monitor_threshold = threshold;
         self
@@ -207,46 +221,51 @@ impl Config {
         self.base.vesting_contract_address.clone()
     }
 
-    pub fn get_mnemonic(&self) -> bip39::Mnemonic {
-        self.base.mnemonic.clone()
+    pub fn get_mnemonic(&self) -> anyhow::Result<bip39::Mnemonic> {
+        let raw = MNEMONIC_ENV_VARS
+            .load_secret(&self.base.mnemonic_path)
+            .context("failed to load the mnemonic")?;
+        Ok(bip39::Mnemonic::parse(raw.as_str())?)
+    }
+
+    pub fn store_mnemonic(&self, mnemonic: &bip39::Mnemonic) -> anyhow::Result<()> {
+        let raw = Zeroizing::new(mnemonic.to_string());
+        MNEMONIC_ENV_VARS
+            .store_secret(&self.base.mnemonic_path, &raw)
+            .context("failed to save the mnemonic")
     }
 }
 
-// we only really care about the mnemonic being zeroized
-#[derive(Debug, Deserialize, PartialEq, Eq, Serialize, Zeroize, ZeroizeOnDrop)]
+#[derive(Debug, Deserialize, PartialEq, Eq, Serialize)]
 pub struct Base {
     /// ID specifies the human readable ID of this particular nym-api.
     id: String,
 
-    #[zeroize(skip)]
     local_validator: Url,
 
     /// Address of the validator contract managing the network
-    #[zeroize(skip)]
     mixnet_contract_address: nyxd::AccountId,
 
     /// Address of the vesting contract holding locked tokens
-    #[zeroize(skip)]
     vesting_contract_address: nyxd::AccountId,
 
-    /// Mnemonic used for rewarding and/or multisig operations
-    // TODO: similarly to the note in gateway, this should get moved to a separate file
-    mnemonic: bip39::Mnemonic,
+    /// Path to the encrypted file containing the mnemonic used for rewarding and/or multisig operations
+    mnemonic_path: PathBuf,
 }
 
 impl Base {
     pub fn new_default<S: Into<String>>(id: S) -> Self {
+        let id = id.into();
         let default_validator: Url = DEFAULT_LOCAL_VALIDATOR
             .parse()
             .expect("default local validator is malformed!");
 
         Base {
-            id: id.into(),
+            mnemonic_path: default_data_directory(&id).join(DEFAULT_MNEMONIC_FILENAME),
+            id,
             local_validator: default_validator,
             mixnet_contract_address: mainnet::MIXNET_CONTRACT_ADDRESS.parse().unwrap(),
             vesting_contract_address: mainnet::VESTING_CONTRACT_ADDRESS.parse().unwrap(),
-            // this this doesn't make any sense since you really have a mnemonic beforehand...
-            mnemonic: bip39::Mnemonic::generate(24).unwrap(),
         }
     }
 }
@@ -443,6 +462,47 @@ impl Default for CirculatingSupplyCacherDebug {
     }
 }
 
+#[derive(Debug, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct VerlocCollector {
+    /// Specifies whether verloc results of all bonded mixnodes should be periodically collected
+    /// and stored for the purposes of serving historical latency data.
+    pub enabled: bool,
+
+    #[serde(default)]
+    pub debug: VerlocCollectorDebug,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct VerlocCollectorDebug {
+    /// Specifies the interval at which verloc results are going to be collected from the mixnodes.
+    #[serde(with = "humantime_serde")]
+    pub collection_interval: Duration,
+
+    /// Specifies the maximum time the collector is going to wait for a mixnode to respond.
+    #[serde(with = "humantime_serde")]
+    pub request_timeout: Duration,
+
+    /// Specifies the maximum number of mixnodes that are going to be queried concurrently.
+    pub max_concurrent_requests: usize,
+
+    /// Specifies for how long the collected measurements are going to be retained.
+    #[serde(with = "humantime_serde")]
+    pub retention: Duration,
+}
+
+impl Default for VerlocCollectorDebug {
+    fn default() -> Self {
+        VerlocCollectorDebug {
+            collection_interval: DEFAULT_VERLOC_COLLECTION_INTERVAL,
+            request_timeout: DEFAULT_VERLOC_REQUEST_TIMEOUT,
+            max_concurrent_requests: DEFAULT_VERLOC_MAX_CONCURRENT_REQUESTS,
+            retention: DEFAULT_VERLOC_RETENTION,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default)]
 pub struct Rewarding {
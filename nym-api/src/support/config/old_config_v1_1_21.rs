@@ -1,12 +1,13 @@
 // Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::support::config::old_config_v1_1_23::{BaseV1_1_23, ConfigV1_1_23};
 use crate::support::config::persistence::{
     CoconutSignerPaths, NetworkMonitorPaths, NodeStatusAPIPaths,
 };
 use crate::support::config::{
-    Base, CirculatingSupplyCacher, CirculatingSupplyCacherDebug, CoconutSigner, CoconutSignerDebug,
-    Config, NetworkMonitor, NetworkMonitorDebug, NodeStatusAPI, NodeStatusAPIDebug, Rewarding,
+    CirculatingSupplyCacher, CirculatingSupplyCacherDebug, CoconutSigner, CoconutSignerDebug,
+    NetworkMonitor, NetworkMonitorDebug, NodeStatusAPI, NodeStatusAPIDebug, Rewarding,
     RewardingDebug, TopologyCacher, TopologyCacherDebug,
 };
 use nym_config::legacy_helpers::nym_config::MigrationNymConfig;
@@ -75,7 +76,7 @@ pub struct ConfigV1_1_21 {
     coconut_signer: CoconutSignerV1_1_21,
 }
 
-impl From<ConfigV1_1_21> for Config {
+impl From<ConfigV1_1_21> for ConfigV1_1_23 {
     fn from(value: ConfigV1_1_21) -> Self {
         // this value was never properly saved (probably a bug)
         // so explicitly set it to the default
@@ -83,8 +84,8 @@ impl From<ConfigV1_1_21> for Config {
         let dkg_persistent_state_path =
             CoconutSignerV1_1_21::default_dkg_persistent_state_path(&value.base.id);
 
-        Config {
-            base: Base {
+        ConfigV1_1_23 {
+            base: BaseV1_1_23 {
                 id: value.base.id,
                 local_validator: value.base.local_validator,
                 mixnet_contract_address: value.base.mixnet_contract_address,
@@ -134,6 +135,7 @@ impl From<ConfigV1_1_21> for Config {
                     caching_interval: value.circulating_supply_cacher.caching_interval,
                 },
             },
+            verloc_collector: Default::default(),
             rewarding: Rewarding {
                 enabled: value.rewarding.enabled,
                 debug: RewardingDebug {
@@ -0,0 +1,75 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::support::config::persistence::DEFAULT_MNEMONIC_FILENAME;
+use crate::support::config::{
+    default_data_directory, Base, CirculatingSupplyCacher, CoconutSigner, Config, NetworkMonitor,
+    NodeStatusAPI, Rewarding, TopologyCacher, VerlocCollector,
+};
+use nym_config::legacy_helpers::nym_config::MigrationNymConfig;
+use nym_validator_client::nyxd;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use url::Url;
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct ConfigV1_1_23 {
+    pub base: BaseV1_1_23,
+
+    pub network_monitor: NetworkMonitor,
+
+    pub node_status_api: NodeStatusAPI,
+
+    pub topology_cacher: TopologyCacher,
+
+    pub circulating_supply_cacher: CirculatingSupplyCacher,
+
+    #[serde(default)]
+    pub verloc_collector: VerlocCollector,
+
+    pub rewarding: Rewarding,
+
+    pub coconut_signer: CoconutSigner,
+}
+
+// the mnemonic is not part of the current config, so it has to be extracted (and stored separately)
+// before the conversion happens
+impl From<ConfigV1_1_23> for Config {
+    fn from(value: ConfigV1_1_23) -> Self {
+        Config {
+            base: Base {
+                mnemonic_path: default_data_directory(&value.base.id)
+                    .join(DEFAULT_MNEMONIC_FILENAME),
+                id: value.base.id,
+                local_validator: value.base.local_validator,
+                mixnet_contract_address: value.base.mixnet_contract_address,
+                vesting_contract_address: value.base.vesting_contract_address,
+            },
+            network_monitor: value.network_monitor,
+            node_status_api: value.node_status_api,
+            topology_cacher: value.topology_cacher,
+            circulating_supply_cacher: value.circulating_supply_cacher,
+            verloc_collector: value.verloc_collector,
+            rewarding: value.rewarding,
+            coconut_signer: value.coconut_signer,
+        }
+    }
+}
+
+impl MigrationNymConfig for ConfigV1_1_23 {
+    fn default_root_directory() -> PathBuf {
+        dirs::home_dir()
+            .expect("Failed to evaluate $HOME value")
+            .join(".nym")
+            .join("nym-api")
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub struct BaseV1_1_23 {
+    pub id: String,
+    pub local_validator: Url,
+    pub mixnet_contract_address: nyxd::AccountId,
+    pub vesting_contract_address: nyxd::AccountId,
+    pub mnemonic: bip39::Mnemonic,
+}
@@ -5,6 +5,8 @@ use crate::support::config::default_data_directory;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
+pub const DEFAULT_MNEMONIC_FILENAME: &str = "mnemonic.json";
+
 pub const DEFAULT_NETWORK_MONITOR_CREDENTIALS_DATABASE_FILENAME: &str = "credentials_database.db";
 
 pub const DEFAULT_NODE_STATUS_API_DATABASE_FILENAME: &str = "db.sqlite";
@@ -21,8 +21,11 @@ mixnet_contract_address = '{{ base.mixnet_contract_address }}'
 # Address of the vesting contract holding locked tokens
 vesting_contract_address = '{{ base.vesting_contract_address }}'
 
-# Mnemonic used for rewarding and validator interaction
-mnemonic = '{{ base.mnemonic }}'
+# Path to the encrypted file containing the mnemonic used for rewarding and validator interaction.
+# The mnemonic can also be provided directly via the `NYM_API_MNEMONIC` environment variable
+# or via a path to a plaintext file set in the `NYM_API_MNEMONIC_FILE` environment variable.
+# The passphrase of the encrypted file is read from the `NYM_API_SECRETS_PASSPHRASE` environment variable.
+mnemonic_path = '{{ base.mnemonic_path }}'
 
 
 ##### network monitor config options #####
@@ -91,6 +94,22 @@ enabled = {{ circulating_supply_cacher.enabled }}
 caching_interval = '{{ circulating_supply_cacher.debug.caching_interval }}'
 
 
+##### verloc collector config options #####
+
+[verloc_collector]
+
+# Specifies whether verloc results of all bonded mixnodes should be periodically collected
+# and stored for the purposes of serving historical latency data.
+enabled = {{ verloc_collector.enabled }}
+
+[verloc_collector.debug]
+
+collection_interval = '{{ verloc_collector.debug.collection_interval }}'
+request_timeout = '{{ verloc_collector.debug.request_timeout }}'
+max_concurrent_requests = {{ verloc_collector.debug.max_concurrent_requests }}
+retention = '{{ verloc_collector.debug.retention }}'
+
+
 ##### rewarding config options #####
 
 [rewarding]
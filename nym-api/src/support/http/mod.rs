@@ -7,7 +7,7 @@ use crate::node_status_api::{self, NodeStatusCache};
 use crate::nym_contract_cache::cache::NymContractCache;
 use crate::support::config::Config;
 use crate::support::{nyxd, storage};
-use crate::{circulating_supply_api, nym_contract_cache};
+use crate::{circulating_supply_api, nym_contract_cache, verloc_collector};
 use anyhow::Result;
 use rocket::http::Method;
 use rocket::{Ignite, Rocket};
@@ -34,6 +34,7 @@ pub(crate) async fn setup_rocket(
         "" => circulating_supply_api::circulating_supply_routes(&openapi_settings),
         "" => nym_contract_cache::nym_contract_cache_routes(&openapi_settings),
         "/status" => node_status_api::node_status_routes(&openapi_settings, config.network_monitor.enabled),
+        "/status" => verloc_collector::verloc_routes(&openapi_settings, config.verloc_collector.enabled),
     }
 
     let rocket = rocket
@@ -45,7 +46,10 @@ pub(crate) async fn setup_rocket(
 
     // This is not a very nice approach. A lazy value would be more suitable, but that's still
     // a nightly feature: https://github.com/rust-lang/rust/issues/74465
-    let storage = if config.coconut_signer.enabled || config.network_monitor.enabled {
+    let storage = if config.coconut_signer.enabled
+        || config.network_monitor.enabled
+        || config.verloc_collector.enabled
+    {
         Some(
             storage::NymApiStorage::init(&config.node_status_api.storage_paths.database_path)
                 .await?,
@@ -67,8 +71,8 @@ pub(crate) async fn setup_rocket(
         rocket
     };
 
-    // see if we should start up network monitor
-    let rocket = if config.network_monitor.enabled {
+    // see if we should start up network monitor or the verloc collector
+    let rocket = if config.network_monitor.enabled || config.verloc_collector.enabled {
         rocket.attach(storage::NymApiStorage::stage(storage.unwrap()))
     } else {
         rocket
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::coconut::error::CoconutError;
-use crate::epoch_operations::MixnodeWithPerformance;
+use crate::epoch_operations::{GatewayWithPerformance, MixnodeToJail, MixnodeWithPerformance};
 use crate::support::config::Config;
 use anyhow::Result;
 use async_trait::async_trait;
@@ -19,11 +19,12 @@ use nym_coconut_dkg_common::{
 use nym_config::defaults::{ChainDetails, NymNetworkDetails, DEFAULT_NYM_API_PORT};
 use nym_contracts_common::dealings::ContractSafeBytes;
 use nym_mixnet_contract_common::families::{Family, FamilyHead};
+use nym_mixnet_contract_common::jailing::JailedMixnode;
 use nym_mixnet_contract_common::mixnode::MixNodeDetails;
 use nym_mixnet_contract_common::reward_params::RewardingParams;
 use nym_mixnet_contract_common::{
     CurrentIntervalResponse, EpochStatus, ExecuteMsg, GatewayBond, IdentityKey, LayerAssignment,
-    MixId, RewardedSetNodeStatus,
+    MixId, RewardedSetNodeStatus, UnbondedMixnode,
 };
 use nym_name_service_common::msg::QueryMsg as NameServiceQueryMsg;
 use nym_service_provider_directory_common::msg::QueryMsg as SpQueryMsg;
@@ -76,7 +77,9 @@ impl Client {
             .expect("failed to construct valid validator client config with the provided network")
             .with_urls(nyxd_url, api_url);
 
-        let mnemonic = config.get_mnemonic();
+        let mnemonic = config
+            .get_mnemonic()
+            .expect("Failed to load the nym-api mnemonic!");
 
         let inner = nym_validator_client::Client::new_signing(client_config, mnemonic)
             .expect("Failed to connect to nyxd!");
@@ -227,6 +230,18 @@ impl Client {
         self.0.read().await.get_all_family_members().await
     }
 
+    pub(crate) async fn get_jailed_mixnodes(
+        &self,
+    ) -> Result<Vec<JailedMixnode>, ValidatorClientError> {
+        self.0.read().await.get_all_jailed_mixnodes().await
+    }
+
+    pub(crate) async fn get_unbonded_mixnodes(
+        &self,
+    ) -> Result<Vec<(MixId, UnbondedMixnode)>, ValidatorClientError> {
+        self.0.read().await.get_all_nyxd_unbonded_mixnodes().await
+    }
+
     pub(crate) async fn get_pending_events_count(&self) -> Result<u32, ValidatorClientError> {
         let pending = self.0.read().await.get_number_of_pending_events().await?;
         Ok(pending.epoch_events + pending.interval_events)
@@ -283,6 +298,72 @@ impl Client {
         Ok(())
     }
 
+    pub(crate) async fn send_gateway_rewarding_messages(
+        &self,
+        gateways: &[GatewayWithPerformance],
+    ) -> Result<(), ValidatorClientError> {
+        #[inline]
+        #[allow(unused_variables)]
+        fn generate_reward_messages(
+            eligible_gateways: &[GatewayWithPerformance],
+        ) -> Vec<(ExecuteMsg, Vec<Coin>)> {
+            cfg_if::cfg_if! {
+                if #[cfg(feature = "no-reward")] {
+                    vec![]
+                } else {
+                    eligible_gateways
+                        .iter()
+                        .map(|gateway| gateway.clone().into())
+                        .zip(std::iter::repeat(Vec::new()))
+                        .collect()
+                }
+            }
+        }
+
+        let contract = self.0.read().await.get_mixnet_contract_address();
+
+        let msgs = generate_reward_messages(gateways);
+
+        self.0
+            .write()
+            .await
+            .nyxd
+            .execute_multiple(
+                &contract,
+                msgs,
+                Default::default(),
+                format!("rewarding {} gateways", gateways.len()),
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn send_jailing_messages(
+        &self,
+        nodes: &[MixnodeToJail],
+    ) -> Result<(), ValidatorClientError> {
+        let contract = self.0.read().await.get_mixnet_contract_address();
+
+        let msgs: Vec<(ExecuteMsg, Vec<Coin>)> = nodes
+            .iter()
+            .map(|node| node.clone().into())
+            .zip(std::iter::repeat(Vec::new()))
+            .collect();
+
+        self.0
+            .write()
+            .await
+            .nyxd
+            .execute_multiple(
+                &contract,
+                msgs,
+                Default::default(),
+                format!("jailing {} mixnodes", nodes.len()),
+            )
+            .await?;
+        Ok(())
+    }
+
     pub(crate) async fn advance_current_epoch(
         &self,
         new_rewarded_set: Vec<LayerAssignment>,
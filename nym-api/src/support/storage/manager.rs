@@ -4,7 +4,8 @@ use crate::network_monitor::monitor::summary_producer::{GatewayResult, MixnodeRe
 use crate::node_status_api::models::{HistoricalUptime, Uptime};
 use crate::node_status_api::utils::{ActiveGatewayStatuses, ActiveMixnodeStatuses};
 use crate::support::storage::models::{
-    ActiveGateway, ActiveMixnode, NodeStatus, RewardingReport, TestingRoute,
+    ActiveGateway, ActiveMixnode, NodeStatus, RewardingReport, TestingRoute, VerlocMeasurement,
+    VerlocRunSummary,
 };
 use nym_mixnet_contract_common::{EpochId, IdentityKey, MixId};
 use std::convert::TryFrom;
@@ -1013,4 +1014,123 @@ impl StorageManager {
 
         Ok(blinded_signature_response)
     }
+
+    /// Inserts the provided verloc measurements into the database.
+    /// Measurements that have already been stored before are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `measurements`: verloc measurements to insert.
+    pub(crate) async fn insert_verloc_measurements(
+        &self,
+        measurements: &[VerlocMeasurement],
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.connection_pool.begin().await?;
+        for measurement in measurements {
+            sqlx::query!(
+                r#"
+                    INSERT OR IGNORE INTO verloc_measurement
+                    (source_identity, target_identity, run_finished, minimum_micros, mean_micros, maximum_micros, standard_deviation_micros)
+                    VALUES (?, ?, ?, ?, ?, ?, ?)
+                "#,
+                measurement.source_identity,
+                measurement.target_identity,
+                measurement.run_finished,
+                measurement.minimum_micros,
+                measurement.mean_micros,
+                measurement.maximum_micros,
+                measurement.standard_deviation_micros,
+            )
+            .execute(&mut tx)
+            .await?;
+        }
+
+        tx.commit().await
+    }
+
+    /// Obtains all measurements from the latest verloc run of every node that has finished
+    /// after the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `since`: unix timestamp indicating the lower bound of the selection.
+    pub(crate) async fn get_latest_verloc_measurements(
+        &self,
+        since: i64,
+    ) -> Result<Vec<VerlocMeasurement>, sqlx::Error> {
+        sqlx::query_as!(
+            VerlocMeasurement,
+            r#"
+                SELECT
+                    m.source_identity,
+                    m.target_identity,
+                    m.run_finished,
+                    m.minimum_micros,
+                    m.mean_micros,
+                    m.maximum_micros,
+                    m.standard_deviation_micros
+                FROM verloc_measurement m
+                JOIN (
+                    SELECT source_identity, MAX(run_finished) AS latest
+                    FROM verloc_measurement
+                    WHERE run_finished >= ?
+                    GROUP BY source_identity
+                ) l
+                ON m.source_identity = l.source_identity AND m.run_finished = l.latest
+            "#,
+            since
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Obtains summaries of all verloc runs performed by the particular node
+    /// that have finished after the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `identity`: identity key of the node.
+    /// * `since`: unix timestamp indicating the lower bound of the selection.
+    pub(crate) async fn get_verloc_run_summaries(
+        &self,
+        identity: &str,
+        since: i64,
+    ) -> Result<Vec<VerlocRunSummary>, sqlx::Error> {
+        sqlx::query_as!(
+            VerlocRunSummary,
+            r#"
+                SELECT
+                    run_finished,
+                    COUNT(*) as "measured_nodes!: i64",
+                    AVG(mean_micros) as "average_mean_micros!: f64",
+                    MIN(minimum_micros) as "minimum_micros!: i64"
+                FROM verloc_measurement
+                WHERE source_identity = ? AND run_finished >= ?
+                GROUP BY run_finished
+                ORDER BY run_finished
+            "#,
+            identity,
+            since
+        )
+        .fetch_all(&self.connection_pool)
+        .await
+    }
+
+    /// Removes all verloc measurements that are older than the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `until`: timestamp specifying the purge cutoff.
+    pub(crate) async fn purge_old_verloc_measurements(
+        &self,
+        until: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "DELETE FROM verloc_measurement WHERE run_finished < ?",
+            until
+        )
+        .execute(&self.connection_pool)
+        .await?;
+        Ok(())
+    }
 }
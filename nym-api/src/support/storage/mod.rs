@@ -9,7 +9,7 @@ use crate::node_status_api::models::{
 };
 use crate::node_status_api::{ONE_DAY, ONE_HOUR};
 use crate::storage::manager::StorageManager;
-use crate::storage::models::{NodeStatus, TestingRoute};
+use crate::storage::models::{NodeStatus, TestingRoute, VerlocMeasurement, VerlocRunSummary};
 use nym_mixnet_contract_common::MixId;
 use rocket::fairing::AdHoc;
 use sqlx::ConnectOptions;
@@ -121,7 +121,7 @@ impl NymApiStorage {
     ///
     /// * `mix_id`: mix-id (as assigned by the smart contract) of the mixnode to query.
     /// * `since`: unix timestamp indicating the lower bound interval of the selection.
-    async fn get_mixnode_statuses(
+    pub(crate) async fn get_mixnode_statuses(
         &self,
         mix_id: MixId,
         since: i64,
@@ -737,4 +737,56 @@ impl NymApiStorage {
             .await
             .map_err(|err| err.into())
     }
+
+    pub(crate) async fn insert_verloc_measurements(
+        &self,
+        measurements: &[VerlocMeasurement],
+    ) -> Result<(), NymApiStorageError> {
+        self.manager
+            .insert_verloc_measurements(measurements)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Obtains measurements from the most recent verloc run of every mixnode
+    /// that has finished after the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `since`: unix timestamp indicating the lower bound of the selection.
+    pub(crate) async fn get_latest_verloc_measurements(
+        &self,
+        since: i64,
+    ) -> Result<Vec<VerlocMeasurement>, NymApiStorageError> {
+        self.manager
+            .get_latest_verloc_measurements(since)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    pub(crate) async fn get_verloc_run_summaries(
+        &self,
+        identity: &str,
+        since: i64,
+    ) -> Result<Vec<VerlocRunSummary>, NymApiStorageError> {
+        self.manager
+            .get_verloc_run_summaries(identity, since)
+            .await
+            .map_err(|err| err.into())
+    }
+
+    /// Removes all verloc measurements that are older than the provided timestamp.
+    ///
+    /// # Arguments
+    ///
+    /// * `until`: timestamp specifying the purge cutoff.
+    pub(crate) async fn purge_old_verloc_measurements(
+        &self,
+        until: i64,
+    ) -> Result<(), NymApiStorageError> {
+        self.manager
+            .purge_old_verloc_measurements(until)
+            .await
+            .map_err(|err| err.into())
+    }
 }
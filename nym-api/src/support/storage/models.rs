@@ -49,3 +49,20 @@ pub(crate) struct RewardingReport {
 
     pub(crate) eligible_mixnodes: u32,
 }
+
+pub(crate) struct VerlocMeasurement {
+    pub(crate) source_identity: String,
+    pub(crate) target_identity: String,
+    pub(crate) run_finished: i64,
+    pub(crate) minimum_micros: i64,
+    pub(crate) mean_micros: i64,
+    pub(crate) maximum_micros: i64,
+    pub(crate) standard_deviation_micros: i64,
+}
+
+pub(crate) struct VerlocRunSummary {
+    pub(crate) run_finished: i64,
+    pub(crate) measured_nodes: i64,
+    pub(crate) average_mean_micros: f64,
+    pub(crate) minimum_micros: i64,
+}
@@ -0,0 +1,144 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::node_status_api::models::NymApiStorageError;
+use crate::nym_contract_cache::cache::NymContractCache;
+use crate::support::config::VerlocCollectorDebug;
+use crate::support::storage::models::VerlocMeasurement;
+use crate::support::storage::NymApiStorage;
+use crate::verloc_collector::models::MixnodeVerlocResult;
+use futures::{stream, StreamExt};
+use nym_mixnet_contract_common::MixNodeBond;
+use nym_task::TaskClient;
+use std::time::{Duration, UNIX_EPOCH};
+use time::OffsetDateTime;
+
+pub(crate) struct VerlocCollector {
+    nym_contract_cache: NymContractCache,
+    storage: NymApiStorage,
+    client: reqwest::Client,
+    collection_interval: Duration,
+    max_concurrent_requests: usize,
+    retention: Duration,
+}
+
+impl VerlocCollector {
+    pub(crate) fn new(
+        config: &VerlocCollectorDebug,
+        nym_contract_cache: NymContractCache,
+        storage: NymApiStorage,
+    ) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(config.request_timeout)
+            .build()
+            .expect("failed to build the http client");
+
+        VerlocCollector {
+            nym_contract_cache,
+            storage,
+            client,
+            collection_interval: config.collection_interval,
+            max_concurrent_requests: config.max_concurrent_requests,
+            retention: config.retention,
+        }
+    }
+
+    async fn query_mixnode(&self, mixnode: &MixNodeBond) -> Option<Vec<VerlocMeasurement>> {
+        let node = &mixnode.mix_node;
+        let url = format!("http://{}:{}/verloc", node.host, node.http_api_port);
+
+        let result = match self.client.get(&url).send().await {
+            Ok(response) => response.json::<MixnodeVerlocResult>().await,
+            Err(err) => Err(err),
+        };
+
+        let result = match result {
+            Ok(result) => result,
+            Err(err) => {
+                debug!(
+                    "Failed to obtain verloc results of {}: {err}",
+                    node.identity_key
+                );
+                return None;
+            }
+        };
+
+        // if the node is currently in the middle of its measurements, the results are incomplete
+        let run_finished = result.run_finished?;
+        let run_finished = run_finished.duration_since(UNIX_EPOCH).ok()?.as_secs() as i64;
+
+        let measurements = result
+            .results
+            .into_iter()
+            .filter_map(|verloc| {
+                verloc
+                    .latest_measurement
+                    .map(|measurement| VerlocMeasurement {
+                        source_identity: node.identity_key.clone(),
+                        target_identity: verloc.identity,
+                        run_finished,
+                        minimum_micros: measurement.minimum.as_micros() as i64,
+                        mean_micros: measurement.mean.as_micros() as i64,
+                        maximum_micros: measurement.maximum.as_micros() as i64,
+                        standard_deviation_micros: measurement.standard_deviation.as_micros()
+                            as i64,
+                    })
+            })
+            .collect();
+
+        Some(measurements)
+    }
+
+    async fn collect(&self) -> Result<(), NymApiStorageError> {
+        let mixnodes = self.nym_contract_cache.mixnodes_basic().await;
+        if mixnodes.is_empty() {
+            debug!("there are no bonded mixnodes to collect verloc results from");
+            return Ok(());
+        }
+
+        let results = stream::iter(mixnodes.iter())
+            .map(|mixnode| self.query_mixnode(mixnode))
+            .buffer_unordered(self.max_concurrent_requests)
+            .filter_map(|measurements| async move { measurements })
+            .collect::<Vec<_>>()
+            .await;
+
+        info!(
+            "obtained verloc results from {} out of {} mixnodes",
+            results.len(),
+            mixnodes.len()
+        );
+
+        let measurements = results.into_iter().flatten().collect::<Vec<_>>();
+        self.storage
+            .insert_verloc_measurements(&measurements)
+            .await?;
+
+        let cutoff = (OffsetDateTime::now_utc() - self.retention).unix_timestamp();
+        self.storage.purge_old_verloc_measurements(cutoff).await
+    }
+
+    pub(crate) async fn run(&self, mut shutdown: TaskClient) {
+        let mut interval = tokio::time::interval(self.collection_interval);
+        while !shutdown.is_shutdown() {
+            tokio::select! {
+                _ = interval.tick() => {
+                    tokio::select! {
+                        biased;
+                        _ = shutdown.recv() => {
+                            trace!("VerlocCollector: Received shutdown");
+                        }
+                        ret = self.collect() => {
+                            if let Err(err) = ret {
+                                error!("Failed to collect verloc results - {err}");
+                            }
+                        }
+                    }
+                }
+                _ = shutdown.recv() => {
+                    trace!("VerlocCollector: Received shutdown");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use nym_task::TaskManager;
+use okapi::openapi3::OpenApi;
+use rocket::Route;
+use rocket_okapi::{openapi_get_routes_spec, settings::OpenApiSettings};
+
+use crate::nym_contract_cache::cache::NymContractCache;
+use crate::support::{config, storage::NymApiStorage};
+
+use self::collector::VerlocCollector;
+
+pub(crate) mod collector;
+pub(crate) mod models;
+pub(crate) mod routes;
+
+/// Merges the routes with http information and returns it to Rocket for serving
+pub(crate) fn verloc_routes(settings: &OpenApiSettings, enabled: bool) -> (Vec<Route>, OpenApi) {
+    if enabled {
+        openapi_get_routes_spec![
+            settings: routes::get_latency_matrix,
+            routes::get_mixnode_verloc_history
+        ]
+    } else {
+        // without the collector we wouldn't have any data to serve
+        (Vec::new(), OpenApi::default())
+    }
+}
+
+/// Spawn the verloc collector that periodically gathers verloc results of all bonded mixnodes.
+pub(crate) fn start(
+    config: &config::VerlocCollector,
+    nym_contract_cache_state: &NymContractCache,
+    storage: &NymApiStorage,
+    shutdown: &TaskManager,
+) {
+    let collector = VerlocCollector::new(
+        &config.debug,
+        nym_contract_cache_state.to_owned(),
+        storage.to_owned(),
+    );
+    let shutdown_listener = shutdown.subscribe();
+    tokio::spawn(async move { collector.run(shutdown_listener).await });
+}
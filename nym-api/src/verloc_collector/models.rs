@@ -0,0 +1,36 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+use std::time::{Duration, SystemTime};
+
+// mirrors of the types exposed by the mixnodes on their `/verloc` endpoint
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct MixnodeVerlocResult {
+    #[serde(with = "humantime_serde")]
+    pub(crate) run_finished: Option<SystemTime>,
+
+    pub(crate) results: Vec<MixnodeVerloc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct MixnodeVerloc {
+    pub(crate) identity: String,
+    pub(crate) latest_measurement: Option<MixnodeMeasurement>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct MixnodeMeasurement {
+    #[serde(with = "humantime_serde")]
+    pub(crate) minimum: Duration,
+
+    #[serde(with = "humantime_serde")]
+    pub(crate) mean: Duration,
+
+    #[serde(with = "humantime_serde")]
+    pub(crate) maximum: Duration,
+
+    #[serde(with = "humantime_serde")]
+    pub(crate) standard_deviation: Duration,
+}
@@ -0,0 +1,147 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::node_status_api::models::ErrorResponse;
+use crate::node_status_api::ONE_DAY;
+use crate::support::storage::models::VerlocMeasurement;
+use crate::support::storage::NymApiStorage;
+use nym_api_requests::models::{
+    LatencyMatrixResponse, VerlocHistoryResponse, VerlocLatency, VerlocTrendPoint,
+};
+use rocket::http::Status;
+use rocket::serde::json::Json;
+use rocket::State;
+use rocket_okapi::openapi;
+use std::collections::{BTreeSet, HashMap};
+use time::OffsetDateTime;
+
+fn default_since() -> i64 {
+    (OffsetDateTime::now_utc() - ONE_DAY).unix_timestamp()
+}
+
+fn to_latency(measurement: &VerlocMeasurement) -> VerlocLatency {
+    VerlocLatency {
+        minimum_micros: measurement.minimum_micros as u64,
+        mean_micros: measurement.mean_micros as u64,
+        maximum_micros: measurement.maximum_micros as u64,
+        standard_deviation_micros: measurement.standard_deviation_micros as u64,
+        measured_at: measurement.run_finished,
+    }
+}
+
+fn build_latency_matrix(measurements: Vec<VerlocMeasurement>, since: i64) -> LatencyMatrixResponse {
+    let nodes = measurements
+        .iter()
+        .flat_map(|m| [m.source_identity.clone(), m.target_identity.clone()])
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let indices = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, identity)| (identity.as_str(), i))
+        .collect::<HashMap<_, _>>();
+
+    let mut latencies = vec![vec![None; nodes.len()]; nodes.len()];
+    for measurement in &measurements {
+        let source = indices[measurement.source_identity.as_str()];
+        let target = indices[measurement.target_identity.as_str()];
+        latencies[source][target] = Some(to_latency(measurement));
+    }
+
+    LatencyMatrixResponse {
+        nodes,
+        latencies,
+        since,
+    }
+}
+
+#[openapi(tag = "verloc")]
+#[get("/verloc/latency-matrix?<since>")]
+pub(crate) async fn get_latency_matrix(
+    storage: &State<NymApiStorage>,
+    since: Option<i64>,
+) -> Result<Json<LatencyMatrixResponse>, ErrorResponse> {
+    let since = since.unwrap_or_else(default_since);
+    let measurements = storage
+        .get_latest_verloc_measurements(since)
+        .await
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::InternalServerError))?;
+
+    Ok(Json(build_latency_matrix(measurements, since)))
+}
+
+#[openapi(tag = "verloc")]
+#[get("/verloc/mixnode/<identity>/history?<since>")]
+pub(crate) async fn get_mixnode_verloc_history(
+    storage: &State<NymApiStorage>,
+    identity: &str,
+    since: Option<i64>,
+) -> Result<Json<VerlocHistoryResponse>, ErrorResponse> {
+    let since = since.unwrap_or_else(default_since);
+    let summaries = storage
+        .get_verloc_run_summaries(identity, since)
+        .await
+        .map_err(|err| ErrorResponse::new(err.to_string(), Status::InternalServerError))?;
+
+    if summaries.is_empty() {
+        return Err(ErrorResponse::new(
+            "no verloc measurements found for the provided mixnode",
+            Status::NotFound,
+        ));
+    }
+
+    let history = summaries
+        .into_iter()
+        .map(|summary| VerlocTrendPoint {
+            timestamp: summary.run_finished,
+            measured_nodes: summary.measured_nodes as u32,
+            average_mean_micros: summary.average_mean_micros as u64,
+            minimum_micros: summary.minimum_micros as u64,
+        })
+        .collect();
+
+    Ok(Json(VerlocHistoryResponse {
+        identity: identity.to_string(),
+        history,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(source: &str, target: &str, mean_micros: i64) -> VerlocMeasurement {
+        VerlocMeasurement {
+            source_identity: source.to_string(),
+            target_identity: target.to_string(),
+            run_finished: 100,
+            minimum_micros: mean_micros,
+            mean_micros,
+            maximum_micros: mean_micros,
+            standard_deviation_micros: 0,
+        }
+    }
+
+    #[test]
+    fn latency_matrix_includes_all_sources_and_targets() {
+        let measurements = vec![
+            measurement("a", "b", 10),
+            measurement("a", "c", 20),
+            measurement("b", "a", 30),
+        ];
+
+        let matrix = build_latency_matrix(measurements, 42);
+        assert_eq!(matrix.since, 42);
+        assert_eq!(matrix.nodes, vec!["a", "b", "c"]);
+
+        let mean = |i: usize, j: usize| matrix.latencies[i][j].map(|l| l.mean_micros);
+        assert_eq!(mean(0, 1), Some(10));
+        assert_eq!(mean(0, 2), Some(20));
+        assert_eq!(mean(1, 0), Some(30));
+        assert_eq!(mean(1, 2), None);
+        assert_eq!(mean(2, 0), None);
+        assert_eq!(mean(0, 0), None);
+    }
+}
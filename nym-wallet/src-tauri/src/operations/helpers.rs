@@ -299,6 +299,7 @@ mod tests {
             host: "1.2.3.4".to_string(),
             mix_port: 1234,
             clients_port: 2345,
+            clients_wss_port: None,
             location: "whatever".to_string(),
             sphinx_key: "totally-legit-sphinx-key".to_string(),
             identity_key: identity_keypair.public_key().to_base58_string(),
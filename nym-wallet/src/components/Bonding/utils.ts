@@ -30,6 +30,7 @@ export function gatewayToTauri(data: GatewayData): Gateway {
     version: data.version,
     mix_port: data.mixPort,
     clients_port: data.clientsPort,
+    clients_wss_port: null,
     sphinx_key: data.sphinxKey,
     identity_key: data.identityKey,
     location: data.location,
@@ -0,0 +1,61 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+// Connects to the mixnet, sends a message to itself, waits for it to arrive and disconnects.
+
+#include <stdio.h>
+#include <string.h>
+#include <unistd.h>
+
+#include "mixnet_client_c.h"
+
+static void on_message(void *user_data, uint8_t const *message, size_t message_len, char const *sender_tag) {
+    volatile int *received = user_data;
+
+    printf("received '%.*s'", (int) message_len, (char const *) message);
+    if (sender_tag != NULL) {
+        printf(" from anonymous sender %s", sender_tag);
+    }
+    printf("\n");
+    *received = 1;
+}
+
+int main(int argc, char **argv) {
+    // if provided, the first argument is the directory used for storing the client keys
+    char const *storage_directory = argc > 1 ? argv[1] : NULL;
+
+    nym_client_initialise_logger();
+
+    if (nym_client_connect(storage_directory) != MIXNET_STATUS_CODE_SUCCESS) {
+        fprintf(stderr, "failed to connect to the mixnet\n");
+        return 1;
+    }
+
+    volatile int received = 0;
+    char *address = nym_client_get_self_address();
+    printf("our address is %s\n", address);
+
+    if (nym_client_set_receive_callback(on_message, (void *) &received) != MIXNET_STATUS_CODE_SUCCESS) {
+        fprintf(stderr, "failed to register the receive callback\n");
+        nym_client_free_string(address);
+        nym_client_disconnect();
+        return 1;
+    }
+
+    char const *content = "hello from C!";
+    slice_ref_uint8_t message = {
+        .ptr = (uint8_t const *) content,
+        .len = strlen(content),
+    };
+    if (nym_client_send_message(address, message) != MIXNET_STATUS_CODE_SUCCESS) {
+        fprintf(stderr, "failed to send the message\n");
+    }
+    nym_client_free_string(address);
+
+    for (int i = 0; i < 60 && !received; i++) {
+        sleep(1);
+    }
+
+    nym_client_disconnect();
+    return received ? 0 : 1;
+}
@@ -0,0 +1,185 @@
+/*! \file */
+/*******************************************
+ *                                         *
+ *  File auto-generated by `::safer_ffi`.  *
+ *                                         *
+ *  Do not manually edit this file.        *
+ *                                         *
+ *******************************************/
+
+#ifndef __RUST_NYM_MIXNET_CLIENT_FFI__
+#define __RUST_NYM_MIXNET_CLIENT_FFI__
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+
+#include <stddef.h>
+#include <stdint.h>
+
+/** <No documentation available> */
+/** \remark Has the same ABI as `uint8_t` **/
+#ifdef DOXYGEN
+typedef
+#endif
+enum MixnetClientState {
+    /** <No documentation available> */
+    MIXNET_CLIENT_STATE_UNINITIALISED,
+    /** <No documentation available> */
+    MIXNET_CLIENT_STATE_CONNECTED,
+    /** <No documentation available> */
+    MIXNET_CLIENT_STATE_DISCONNECTED,
+}
+#ifndef DOXYGEN
+; typedef uint8_t
+#endif
+MixnetClientState_t;
+
+/** <No documentation available> */
+/** \remark Has the same ABI as `uint8_t` **/
+#ifdef DOXYGEN
+typedef
+#endif
+enum MixnetStatusCode {
+    /** <No documentation available> */
+    MIXNET_STATUS_CODE_SUCCESS,
+    /** <No documentation available> */
+    MIXNET_STATUS_CODE_ALREADY_CONNECTED,
+    /** <No documentation available> */
+    MIXNET_STATUS_CODE_NOT_CONNECTED,
+    /** <No documentation available> */
+    MIXNET_STATUS_CODE_INVALID_ARGUMENT,
+    /** <No documentation available> */
+    MIXNET_STATUS_CODE_CLIENT_FAILURE,
+    /** \brief
+     *  The operation is not allowed from within the receive callback.
+     */
+    MIXNET_STATUS_CODE_CALLED_FROM_RECEIVE_CALLBACK,
+}
+#ifndef DOXYGEN
+; typedef uint8_t
+#endif
+MixnetStatusCode_t;
+
+/** \brief
+ *  Blocks until the client connects to the mixnet. If the storage directory is provided, the keys
+ *  and the gateway details are persisted in (and loaded from) it, otherwise ephemeral ones are used.
+ */
+MixnetStatusCode_t
+nym_client_connect (
+    char const * storage_directory);
+
+/** \brief
+ *  Signals the client to disconnect and blocks until all of its tasks have finished.
+ *  As that includes the delivery of the received messages, it can't be called from within
+ *  the receive callback.
+ */
+MixnetStatusCode_t
+nym_client_disconnect (void);
+
+/** <No documentation available> */
+void
+nym_client_free_string (
+    char * string);
+
+/** <No documentation available> */
+char *
+nym_client_get_self_address (void);
+
+/** <No documentation available> */
+MixnetClientState_t
+nym_client_get_state (void);
+
+/** <No documentation available> */
+void
+nym_client_initialise_logger (void);
+
+/** \brief
+ *  Makes the client use the provided topology rather than the one obtained from the nym-api.
+ *  It has to be a JSON object with `mixnodes` and `gateways` fields in the same format as
+ *  the responses of the nym-api, i.e. lists of mixnode details and gateway bonds.
+ */
+MixnetStatusCode_t
+nym_client_overwrite_topology (
+    char const * topology_json);
+
+/** \brief
+ *  Restores the default behaviour of periodically refreshing the topology from the nym-api.
+ */
+MixnetStatusCode_t
+nym_client_restore_automatic_topology (void);
+
+/** \brief
+ *  `&'lt [T]` but with a guaranteed `#[repr(C)]` layout.
+ *
+ *  # C layout (for some given type T)
+ *
+ *  ```c
+ *  typedef struct {
+ *  // Cannot be NULL
+ *  T * ptr;
+ *  size_t len;
+ *  } slice_T;
+ *  ```
+ *
+ *  # Nullable pointer?
+ *
+ *  If you want to support the above typedef, but where the `ptr` field is
+ *  allowed to be `NULL` (with the contents of `len` then being undefined)
+ *  use the `Option< slice_ptr<_> >` type.
+ */
+typedef struct slice_ref_uint8 {
+    /** \brief
+     *  Pointer to the first element (if any).
+     */
+    uint8_t const * ptr;
+
+    /** \brief
+     *  Element count
+     */
+    size_t len;
+} slice_ref_uint8_t;
+
+/** \brief
+ *  Sends the message to the provided recipient without revealing our own address.
+ *  Instead, the specified number of reply SURBs is attached for it to be able to respond.
+ */
+MixnetStatusCode_t
+nym_client_send_anonymous_message (
+    char const * recipient,
+    slice_ref_uint8_t message,
+    uint32_t reply_surbs);
+
+/** \brief
+ *  Sends the message to the provided recipient while revealing our own address to it.
+ */
+MixnetStatusCode_t
+nym_client_send_message (
+    char const * recipient,
+    slice_ref_uint8_t message);
+
+/** \brief
+ *  Replies to the anonymous sender identified by the provided (base58-encoded) sender tag.
+ */
+MixnetStatusCode_t
+nym_client_send_reply (
+    char const * sender_tag,
+    slice_ref_uint8_t message);
+
+/** \brief
+ *  Registers the callback invoked with all messages received from the mixnet, alongside
+ *  the user data passed back to it on every call. Until it's set (or after it's unset with NULL),
+ *  the received messages are buffered by the client.
+ *  The callback is invoked from a dedicated thread, so the user data has to be safe to use from it.
+ */
+MixnetStatusCode_t
+nym_client_set_receive_callback (
+    void (*callback)(void *, uint8_t const *, size_t, char const *),
+    void * user_data);
+
+
+#ifdef __cplusplus
+} /* extern \"C\" */
+#endif
+
+#endif /* __RUST_NYM_MIXNET_CLIENT_FFI__ */
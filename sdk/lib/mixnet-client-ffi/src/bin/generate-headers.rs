@@ -0,0 +1,6 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+fn main() -> ::std::io::Result<()> {
+    ::nym_mixnet_client_ffi::generate_headers()
+}
@@ -0,0 +1,470 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use ::safer_ffi::prelude::*;
+use anyhow::{anyhow, Result};
+use lazy_static::lazy_static;
+use log::{info, warn};
+use nym_bin_common::logging::setup_logging;
+use nym_config_common::defaults::setup_env;
+use nym_mixnet_contract_common::{GatewayBond, MixNodeDetails};
+use nym_sdk::mixnet::{
+    InputMessage, MixnetClient, MixnetClientBuilder, MixnetClientSender, NymTopology, Recipient,
+    ReconstructedMessage, StoragePaths,
+};
+use nym_sphinx::anonymous_replies::requests::AnonymousSenderTag;
+use nym_task::connections::TransmissionLane;
+use serde::Deserialize;
+use std::cell::Cell;
+use std::ffi::{c_void, CString};
+use std::os::raw::c_char;
+use std::path::PathBuf;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tokio::runtime::Runtime;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+
+/// Callback invoked with every message received from the mixnet, i.e. the user data it was
+/// registered with, the message content and, if it was sent anonymously, the base58-encoded
+/// sender tag to use for replying to it (or NULL otherwise). Neither the message nor the sender tag
+/// pointers remain valid after the callback returns.
+/// The callback is allowed to send messages, but it can't disconnect the client.
+pub type ReceiveCallback = Option<
+    extern "C" fn(
+        user_data: *mut c_void,
+        message: *const u8,
+        message_len: usize,
+        sender_tag: *const c_char,
+    ),
+>;
+
+#[derive(Clone, Copy)]
+struct ReceiveHandler {
+    callback: extern "C" fn(*mut c_void, *const u8, usize, *const c_char),
+    user_data: *mut c_void,
+}
+
+// the user data is opaque to us, it's up to the caller to make sure it can be used
+// from the thread invoking the callback
+unsafe impl Send for ReceiveHandler {}
+
+thread_local! {
+    // set on the thread invoking the receive callback for the duration of the delivery
+    static DELIVERING_MESSAGES: Cell<bool> = Cell::new(false);
+}
+
+enum ClientCommand {
+    SetReceiveHandler(Option<ReceiveHandler>),
+    OverwriteTopology(NymTopology),
+    RestoreTopologyRefreshing,
+}
+
+struct ActiveClient {
+    address: Recipient,
+    sender: MixnetClientSender,
+
+    // closing the channel signals the client to disconnect
+    commands: mpsc::UnboundedSender<ClientCommand>,
+    handle: JoinHandle<()>,
+}
+
+// it's the same approach as in the socks5 listener, i.e. there's only a single client per process
+lazy_static! {
+    static ref ACTIVE_CLIENT: Mutex<Option<ActiveClient>> = Mutex::new(None);
+    static ref RUNTIME: Runtime = Runtime::new().unwrap();
+}
+static ENV_SET: AtomicBool = AtomicBool::new(false);
+
+fn set_default_env() {
+    if !ENV_SET.swap(true, Ordering::SeqCst) {
+        setup_env(None);
+    }
+}
+
+#[derive_ReprC]
+#[ffi_export]
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug)]
+pub enum MixnetClientState {
+    Uninitialised,
+    Connected,
+    Disconnected,
+}
+
+#[derive_ReprC]
+#[ffi_export]
+#[repr(u8)]
+#[derive(Eq, PartialEq, Debug)]
+pub enum MixnetStatusCode {
+    Success,
+    AlreadyConnected,
+    NotConnected,
+    InvalidArgument,
+    ClientFailure,
+
+    /// The operation is not allowed from within the receive callback.
+    CalledFromReceiveCallback,
+}
+
+// to be used with the strings returned by this library, such as the client address
+#[ffi_export]
+pub fn nym_client_free_string(string: char_p::Box) {
+    drop(string)
+}
+
+#[ffi_export]
+pub fn nym_client_initialise_logger() {
+    setup_logging();
+    info!("logger initialised");
+}
+
+#[ffi_export]
+pub fn nym_client_get_state() -> MixnetClientState {
+    if !ENV_SET.load(Ordering::Relaxed) {
+        MixnetClientState::Uninitialised
+    } else if RUNTIME.block_on(ACTIVE_CLIENT.lock()).is_some() {
+        MixnetClientState::Connected
+    } else {
+        MixnetClientState::Disconnected
+    }
+}
+
+/// Blocks until the client connects to the mixnet. If the storage directory is provided, the keys
+/// and the gateway details are persisted in (and loaded from) it, otherwise ephemeral ones are used.
+#[ffi_export]
+pub fn nym_client_connect(storage_directory: Option<char_p::Ref<'_>>) -> MixnetStatusCode {
+    let storage_dir = storage_directory.map(|dir| PathBuf::from(dir.to_str()));
+    RUNTIME.block_on(async move {
+        let mut guard = ACTIVE_CLIENT.lock().await;
+        if guard.is_some() {
+            warn!("could not connect the client as it's already running");
+            return MixnetStatusCode::AlreadyConnected;
+        }
+
+        match _async_connect_client(storage_dir).await {
+            Ok(client) => {
+                *guard = Some(client);
+                MixnetStatusCode::Success
+            }
+            Err(err) => {
+                warn!("failed to connect the client to the mixnet: {err}");
+                MixnetStatusCode::ClientFailure
+            }
+        }
+    })
+}
+
+/// Signals the client to disconnect and blocks until all of its tasks have finished.
+/// As that includes the delivery of the received messages, it can't be called from within
+/// the receive callback.
+#[ffi_export]
+pub fn nym_client_disconnect() -> MixnetStatusCode {
+    // the client would wait for the callback to return, which in turn would wait for the client
+    if DELIVERING_MESSAGES.with(Cell::get) {
+        warn!("could not disconnect the client from within the receive callback");
+        return MixnetStatusCode::CalledFromReceiveCallback;
+    }
+
+    RUNTIME.block_on(async move {
+        let Some(client) = ACTIVE_CLIENT.lock().await.take() else {
+            warn!("could not disconnect the client as it's not running");
+            return MixnetStatusCode::NotConnected;
+        };
+
+        // dropping the command sender makes the client task shut everything down
+        drop(client.commands);
+        if client.handle.await.is_err() {
+            return MixnetStatusCode::ClientFailure;
+        }
+        MixnetStatusCode::Success
+    })
+}
+
+#[ffi_export]
+pub fn nym_client_get_self_address() -> Option<char_p::Box> {
+    let guard = RUNTIME.block_on(ACTIVE_CLIENT.lock());
+    guard
+        .as_ref()
+        .map(|client| client.address.to_string().try_into().unwrap())
+}
+
+/// Sends the message to the provided recipient while revealing our own address to it.
+#[ffi_export]
+pub fn nym_client_send_message(
+    recipient: char_p::Ref<'_>,
+    message: c_slice::Ref<'_, u8>,
+) -> MixnetStatusCode {
+    let Ok(recipient) = Recipient::try_from_base58_string(recipient.to_str()) else {
+        return MixnetStatusCode::InvalidArgument;
+    };
+    send_input_message(InputMessage::new_regular(
+        recipient,
+        message.as_slice().to_vec(),
+        TransmissionLane::General,
+        None,
+    ))
+}
+
+/// Sends the message to the provided recipient without revealing our own address.
+/// Instead, the specified number of reply SURBs is attached for it to be able to respond.
+#[ffi_export]
+pub fn nym_client_send_anonymous_message(
+    recipient: char_p::Ref<'_>,
+    message: c_slice::Ref<'_, u8>,
+    reply_surbs: u32,
+) -> MixnetStatusCode {
+    let Ok(recipient) = Recipient::try_from_base58_string(recipient.to_str()) else {
+        return MixnetStatusCode::InvalidArgument;
+    };
+    send_input_message(InputMessage::new_anonymous(
+        recipient,
+        message.as_slice().to_vec(),
+        reply_surbs,
+        TransmissionLane::General,
+        None,
+    ))
+}
+
+/// Replies to the anonymous sender identified by the provided (base58-encoded) sender tag.
+#[ffi_export]
+pub fn nym_client_send_reply(
+    sender_tag: char_p::Ref<'_>,
+    message: c_slice::Ref<'_, u8>,
+) -> MixnetStatusCode {
+    let Ok(sender_tag) = AnonymousSenderTag::try_from_base58_string(sender_tag.to_str()) else {
+        return MixnetStatusCode::InvalidArgument;
+    };
+    send_input_message(InputMessage::new_reply(
+        sender_tag,
+        message.as_slice().to_vec(),
+        TransmissionLane::General,
+        None,
+    ))
+}
+
+/// Registers the callback invoked with all messages received from the mixnet, alongside
+/// the user data passed back to it on every call. Until it's set (or after it's unset with NULL),
+/// the received messages are buffered by the client.
+/// The callback is invoked from a dedicated thread, so the user data has to be safe to use from it.
+#[ffi_export]
+pub fn nym_client_set_receive_callback(
+    callback: ReceiveCallback,
+    user_data: *mut c_void,
+) -> MixnetStatusCode {
+    let handler = callback.map(|callback| ReceiveHandler {
+        callback,
+        user_data,
+    });
+    send_command(ClientCommand::SetReceiveHandler(handler))
+}
+
+/// Makes the client use the provided topology rather than the one obtained from the nym-api.
+/// It has to be a JSON object with `mixnodes` and `gateways` fields in the same format as
+/// the responses of the nym-api, i.e. lists of mixnode details and gateway bonds.
+#[ffi_export]
+pub fn nym_client_overwrite_topology(topology_json: char_p::Ref<'_>) -> MixnetStatusCode {
+    match parse_topology(topology_json.to_str()) {
+        Ok(topology) => send_command(ClientCommand::OverwriteTopology(topology)),
+        Err(err) => {
+            warn!("failed to parse the provided topology: {err}");
+            MixnetStatusCode::InvalidArgument
+        }
+    }
+}
+
+/// Restores the default behaviour of periodically refreshing the topology from the nym-api.
+#[ffi_export]
+pub fn nym_client_restore_automatic_topology() -> MixnetStatusCode {
+    send_command(ClientCommand::RestoreTopologyRefreshing)
+}
+
+#[derive(Deserialize)]
+struct TopologyOverride {
+    mixnodes: Vec<MixNodeDetails>,
+    gateways: Vec<GatewayBond>,
+}
+
+fn parse_topology(raw: &str) -> Result<NymTopology> {
+    let topology: TopologyOverride = serde_json::from_str(raw)?;
+    let topology = nym_topology::nym_topology_from_detailed(topology.mixnodes, topology.gateways);
+    if topology.gateways().is_empty() {
+        return Err(anyhow!(
+            "the provided topology does not contain any valid gateways"
+        ));
+    }
+    Ok(topology)
+}
+
+fn send_input_message(message: InputMessage) -> MixnetStatusCode {
+    RUNTIME.block_on(async move {
+        let mut guard = ACTIVE_CLIENT.lock().await;
+        let Some(client) = guard.as_mut() else {
+            return MixnetStatusCode::NotConnected;
+        };
+        client.sender.send_input_message(message).await;
+        MixnetStatusCode::Success
+    })
+}
+
+fn send_command(command: ClientCommand) -> MixnetStatusCode {
+    let guard = RUNTIME.block_on(ACTIVE_CLIENT.lock());
+    let Some(client) = guard.as_ref() else {
+        return MixnetStatusCode::NotConnected;
+    };
+    if client.commands.send(command).is_err() {
+        return MixnetStatusCode::ClientFailure;
+    }
+    MixnetStatusCode::Success
+}
+
+fn deliver_messages(handler: ReceiveHandler, messages: Vec<ReconstructedMessage>) {
+    DELIVERING_MESSAGES.with(|delivering| delivering.set(true));
+    for message in messages {
+        invoke_callback(handler, message)
+    }
+    DELIVERING_MESSAGES.with(|delivering| delivering.set(false));
+}
+
+fn invoke_callback(handler: ReceiveHandler, message: ReconstructedMessage) {
+    // base58 encoding never contains NUL bytes
+    let sender_tag = message
+        .sender_tag
+        .map(|tag| CString::new(tag.to_base58_string()).unwrap());
+    let sender_tag_ptr = sender_tag.as_ref().map_or(ptr::null(), |tag| tag.as_ptr());
+
+    (handler.callback)(
+        handler.user_data,
+        message.message.as_ptr(),
+        message.message.len(),
+        sender_tag_ptr,
+    )
+}
+
+async fn _async_connect_client(storage_dir: Option<PathBuf>) -> Result<ActiveClient> {
+    set_default_env();
+
+    let client = match storage_dir {
+        Some(storage_dir) => {
+            std::fs::create_dir_all(&storage_dir)?;
+            let storage_paths = StoragePaths::new_from_dir(&storage_dir)?;
+            MixnetClientBuilder::new_with_default_storage(storage_paths)
+                .await?
+                .build()
+                .await?
+                .connect_to_mixnet()
+                .await?
+        }
+        None => MixnetClient::connect_new().await?,
+    };
+
+    let (commands, command_receiver) = mpsc::unbounded_channel();
+    let address = *client.nym_address();
+    let sender = client.sender();
+    let handle = RUNTIME.spawn(run_client(client, command_receiver));
+
+    Ok(ActiveClient {
+        address,
+        sender,
+        commands,
+        handle,
+    })
+}
+
+async fn run_client(
+    mut client: MixnetClient,
+    mut commands: mpsc::UnboundedReceiver<ClientCommand>,
+) {
+    let mut handler = None;
+
+    loop {
+        tokio::select! {
+            command = commands.recv() => match command {
+                Some(ClientCommand::SetReceiveHandler(new_handler)) => handler = new_handler,
+                Some(ClientCommand::OverwriteTopology(topology)) => {
+                    client.manually_overwrite_topology(topology).await
+                }
+                Some(ClientCommand::RestoreTopologyRefreshing) => {
+                    client.restore_automatic_topology_refreshing()
+                }
+                None => break,
+            },
+            // only pull the messages out of the client once there's somebody to hand them to
+            messages = client.wait_for_messages(), if handler.is_some() => {
+                let (Some(messages), Some(handler)) = (messages, handler) else {
+                    warn!("the client has stopped receiving messages");
+                    break;
+                };
+
+                // the callback is foreign code that might very well call back into this library
+                // (and thus block on the runtime), so it must not be executed on an async worker
+                let delivery =
+                    tokio::task::spawn_blocking(move || deliver_messages(handler, messages));
+                if delivery.await.is_err() {
+                    warn!("the receive callback has panicked");
+                }
+            }
+        }
+    }
+
+    client.disconnect().await;
+    info!("the client has disconnected");
+}
+
+#[cfg(feature = "headers")] // c.f. the `Cargo.toml` section
+pub fn generate_headers() -> ::std::io::Result<()> {
+    ::safer_ffi::headers::builder()
+        .to_file("mixnet_client_c.h")?
+        .generate()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topology_without_gateways_is_rejected() {
+        assert!(parse_topology(r#"{"mixnodes":[],"gateways":[]}"#).is_err());
+        assert!(parse_topology("not a topology").is_err());
+    }
+
+    #[test]
+    fn commands_require_connected_client() {
+        assert_eq!(
+            nym_client_restore_automatic_topology(),
+            MixnetStatusCode::NotConnected
+        );
+        assert_eq!(nym_client_disconnect(), MixnetStatusCode::NotConnected);
+        assert!(nym_client_get_self_address().is_none());
+    }
+
+    extern "C" fn disconnect_on_message(
+        user_data: *mut c_void,
+        _message: *const u8,
+        _message_len: usize,
+        _sender_tag: *const c_char,
+    ) {
+        let status = user_data as *mut MixnetStatusCode;
+        unsafe { *status = nym_client_disconnect() }
+    }
+
+    #[test]
+    fn disconnecting_from_receive_callback_is_rejected() {
+        let mut status = MixnetStatusCode::Success;
+        let handler = ReceiveHandler {
+            callback: disconnect_on_message,
+            user_data: &mut status as *mut MixnetStatusCode as *mut c_void,
+        };
+        deliver_messages(
+            handler,
+            vec![ReconstructedMessage {
+                message: b"hello".to_vec(),
+                sender_tag: None,
+            }],
+        );
+        assert_eq!(status, MixnetStatusCode::CalledFromReceiveCallback);
+
+        // and it's only rejected during the delivery
+        assert_eq!(nym_client_disconnect(), MixnetStatusCode::NotConnected);
+    }
+}
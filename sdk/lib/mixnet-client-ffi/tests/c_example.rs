@@ -0,0 +1,50 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+// makes sure the C example compiles against the header and links with the built library.
+// it's not executed as it requires a connection to the actual network.
+
+#![cfg(unix)]
+
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+// integration tests live in `<target>/<profile>/deps`, while the library artifacts
+// are placed in `<target>/<profile>`
+fn library_directory() -> PathBuf {
+    let mut dir = env::current_exe().unwrap();
+    dir.pop();
+    if dir.ends_with("deps") {
+        dir.pop();
+    }
+    dir
+}
+
+#[test]
+fn c_example_builds() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let library_dir = library_directory();
+    let output = library_dir.join("mixnet-client-c-example");
+
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let result = Command::new(compiler)
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(&manifest_dir)
+        .arg(manifest_dir.join("examples").join("example.c"))
+        .arg("-L")
+        .arg(&library_dir)
+        .arg("-lnym_mixnet_client_ffi")
+        .arg("-o")
+        .arg(&output)
+        .output()
+        .expect("failed to invoke the C compiler");
+
+    assert!(
+        result.status.success(),
+        "failed to build the C example:\n{}",
+        String::from_utf8_lossy(&result.stderr)
+    );
+}
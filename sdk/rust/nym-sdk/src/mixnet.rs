@@ -49,6 +49,7 @@ pub use nym_client_core::{
             persistence::{InMemEphemeralKeys, KeyStore, OnDiskKeys},
             KeyManager,
         },
+        real_messages_control::{RttEstimate, TrafficShapingStats},
         replies::reply_storage::{
             fs_backend::Backend as ReplyStorage, CombinedReplyStorage, Empty as EmptyReplyStorage,
             ReplyStorageBackend,
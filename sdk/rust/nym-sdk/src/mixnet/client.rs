@@ -439,6 +439,7 @@ where
             nym_address,
             started_client.task_manager.subscribe(),
             packet_type,
+            None,
         );
         started_client
             .task_manager
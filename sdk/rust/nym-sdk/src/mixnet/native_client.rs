@@ -1,6 +1,7 @@
 use nym_client_core::client::{
     base_client::{ClientInput, ClientOutput, ClientState},
     inbound_messages::InputMessage,
+    real_messages_control::{RttEstimate, TrafficShapingStats},
     received_buffer::ReconstructedMessagesReceiver,
 };
 use nym_sphinx::addressing::clients::Recipient;
@@ -92,6 +93,22 @@ impl MixnetClient {
         self.client_state.shared_lane_queue_lengths.clone()
     }
 
+    /// Get the current estimate of the acknowledgement round trip time, on top of the expected
+    /// packet delays, observed through the gateway used by this client. It is used for deriving
+    /// adaptive retransmission timeouts. Returns `None` if no acknowledgements were received yet.
+    pub fn ack_rtt_estimate(&self) -> Option<RttEstimate> {
+        self.client_state
+            .ack_rtt_estimates
+            .get(&self.nym_address.gateway().to_base58_string())
+    }
+
+    /// Get a shallow clone of [`TrafficShapingStats`], which keeps track of, among other things,
+    /// how often the constant rate traffic shaping had to delay real packets.
+    /// The values only get updated if traffic shaping is enabled in the debug config.
+    pub fn traffic_shaping_stats(&self) -> TrafficShapingStats {
+        self.client_state.traffic_shaping_stats.clone()
+    }
+
     /// Change the network topology used by this client for constructing sphinx packets into the
     /// provided one.
     pub async fn manually_overwrite_topology(&self, new_topology: NymTopology) {
@@ -19,6 +19,18 @@ export interface Acknowledgements {
    * until the packet reaches its destination.
    */
   average_ack_delay_ms: bigint;
+  /**
+   * Controls whether the ack timeouts should be derived purely from the static
+   * `ack_wait_multiplier` and `ack_wait_addition` rather than from the observed round trip times.
+   * When adaptive timeouts are used, the static values act as the ceiling of the timeout.
+   */
+  disable_adaptive_timeouts: boolean;
+  /**
+   * When adaptive timeouts are used, specifies the minimum value added to the expected
+   * round trip time of an acknowledgement packet before it is assumed it was lost,
+   * regardless of how fast the network appears to be.
+   */
+  minimum_ack_wait_addition_ms: bigint;
 }
 
 export interface CoverTraffic {
@@ -8,6 +8,12 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug)]
 pub enum ControlRequest {
     Health,
+
+    /// [`Health`] request carrying an identifier that is echoed back in the response,
+    /// so that it could be matched with the request that triggered it.
+    /// It shares the tag with [`Health`] so that providers unaware of it still respond
+    /// (with an untagged [`ControlResponse::Health`]).
+    TaggedHealth(u64),
     BinaryInfo,
     SupportedRequestVersions,
 }
@@ -41,8 +47,14 @@ impl Serializable for ControlRequest {
     type Error = ServiceProviderMessagingError;
 
     fn into_bytes(self) -> Vec<u8> {
-        // current variants do not require sending any data apart from the tag
-        vec![self.tag() as u8]
+        // apart from the tagged health request, current variants do not require sending
+        // any data apart from the tag
+        match self {
+            ControlRequest::TaggedHealth(id) => std::iter::once(self.tag() as u8)
+                .chain(id.to_be_bytes())
+                .collect(),
+            _ => vec![self.tag() as u8],
+        }
     }
 
     fn try_from_bytes(b: &[u8]) -> Result<Self, ServiceProviderMessagingError> {
@@ -52,7 +64,10 @@ impl Serializable for ControlRequest {
 
         let request_tag = ControlRequestTag::try_from(b[0])?;
         match request_tag {
-            ControlRequestTag::Health => Ok(ControlRequest::Health),
+            ControlRequestTag::Health => Ok(match try_parse_health_id(&b[1..]) {
+                Some(id) => ControlRequest::TaggedHealth(id),
+                None => ControlRequest::Health,
+            }),
             ControlRequestTag::BinaryInfo => Ok(ControlRequest::BinaryInfo),
             ControlRequestTag::RequestVersions => Ok(ControlRequest::SupportedRequestVersions),
         }
@@ -62,13 +77,19 @@ impl Serializable for ControlRequest {
 impl ControlRequest {
     fn tag(&self) -> ControlRequestTag {
         match self {
-            ControlRequest::Health => ControlRequestTag::Health,
+            ControlRequest::Health | ControlRequest::TaggedHealth(_) => ControlRequestTag::Health,
             ControlRequest::BinaryInfo => ControlRequestTag::BinaryInfo,
             ControlRequest::SupportedRequestVersions => ControlRequestTag::RequestVersions,
         }
     }
 }
 
+// health requests and responses with any other payload are treated as untagged,
+// the same way providers predating the tagged variants treat them
+fn try_parse_health_id(payload: &[u8]) -> Option<u64> {
+    payload.try_into().ok().map(u64::from_be_bytes)
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BinaryInformation {
     pub binary_name: String,
@@ -89,6 +110,9 @@ pub struct ErrorResponse {
 #[derive(Debug, Serialize)]
 pub enum ControlResponse {
     Health,
+
+    /// Response to the [`ControlRequest::TaggedHealth`] carrying the identifier of the request.
+    TaggedHealth(u64),
     BinaryInfo(Box<BinaryInformation>),
     SupportedRequestVersions(SupportedVersions),
     Error(ErrorResponse),
@@ -141,7 +165,10 @@ impl Serializable for ControlResponse {
 
         let response_tag = ControlResponseTag::try_from(b[0])?;
         match response_tag {
-            ControlResponseTag::Health => Ok(ControlResponse::Health),
+            ControlResponseTag::Health => Ok(match try_parse_health_id(&b[1..]) {
+                Some(id) => ControlResponse::TaggedHealth(id),
+                None => ControlResponse::Health,
+            }),
             ControlResponseTag::BinaryInfo => match serde_json::from_slice(&b[1..]) {
                 Ok(binary_info) => Ok(ControlResponse::BinaryInfo(binary_info)),
                 Err(source) => Err(
@@ -169,7 +196,9 @@ impl Serializable for ControlResponse {
 impl ControlResponse {
     fn tag(&self) -> ControlResponseTag {
         match self {
-            ControlResponse::Health => ControlResponseTag::Health,
+            ControlResponse::Health | ControlResponse::TaggedHealth(_) => {
+                ControlResponseTag::Health
+            }
             ControlResponse::BinaryInfo(_) => ControlResponseTag::BinaryInfo,
             ControlResponse::SupportedRequestVersions(_) => {
                 ControlResponseTag::SupportedRequestVersions
@@ -181,6 +210,7 @@ impl ControlResponse {
     fn serialize_inner(self) -> Vec<u8> {
         match self {
             ControlResponse::Health => Vec::new(),
+            ControlResponse::TaggedHealth(id) => id.to_be_bytes().to_vec(),
             // TODO: is serde_json the right choice for this?
             ControlResponse::BinaryInfo(info) => {
                 // As per serde_json documentation:
@@ -202,3 +232,36 @@ impl ControlResponse {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tagged_health_requests_keep_their_id() {
+        let bytes = ControlRequest::TaggedHealth(42).into_bytes();
+        assert_eq!(bytes[0], ControlRequestTag::Health as u8);
+        assert!(matches!(
+            ControlRequest::try_from_bytes(&bytes).unwrap(),
+            ControlRequest::TaggedHealth(42)
+        ));
+        assert!(matches!(
+            ControlRequest::try_from_bytes(&ControlRequest::Health.into_bytes()).unwrap(),
+            ControlRequest::Health
+        ));
+    }
+
+    #[test]
+    fn tagged_health_responses_keep_their_id() {
+        let bytes = ControlResponse::TaggedHealth(42).into_bytes();
+        assert_eq!(bytes[0], ControlResponseTag::Health as u8);
+        assert!(matches!(
+            ControlResponse::try_from_bytes(&bytes).unwrap(),
+            ControlResponse::TaggedHealth(42)
+        ));
+        assert!(matches!(
+            ControlResponse::try_from_bytes(&ControlResponse::Health.into_bytes()).unwrap(),
+            ControlResponse::Health
+        ));
+    }
+}
@@ -80,6 +80,11 @@ where
                     self.handle_health_control_request().await?;
                     Some(ControlResponse::Health)
                 }
+                // shares the tag with `Health`, so it's still a version 3 request
+                ControlRequest::TaggedHealth(id) => {
+                    self.handle_health_control_request().await?;
+                    Some(ControlResponse::TaggedHealth(id))
+                }
                 ControlRequest::BinaryInfo => {
                     let info = self.handle_binary_info_control_request().await?;
                     Some(ControlResponse::BinaryInfo(Box::new(info)))
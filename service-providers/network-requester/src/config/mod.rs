@@ -107,6 +107,11 @@ impl Config {
         save_formatted_config_to_file(self, config_save_location)
     }
 
+    pub fn with_data_directory<P: AsRef<Path>>(mut self, data_directory: P) -> Self {
+        self.storage_paths = NetworkRequesterPaths::new_default(data_directory);
+        self
+    }
+
     pub fn validate(&self) -> bool {
         // no other sections have explicit requirements (yet)
         self.base.validate()
@@ -57,6 +57,7 @@ pub struct NRServiceProviderBuilder {
     stats_provider_addr: Option<Recipient>,
     standard_list: StandardList,
     allowed_hosts: StoredAllowedHosts,
+    custom_mixnet_client: Option<nym_sdk::mixnet::MixnetClient>,
 }
 
 struct NRServiceProvider {
@@ -180,15 +181,31 @@ impl NRServiceProviderBuilder {
             stats_provider_addr,
             standard_list,
             allowed_hosts,
+            custom_mixnet_client: None,
         }
     }
 
+    /// Use the provided, already connected, mixnet client instead of creating one out of the
+    /// client section of the config.
+    #[must_use]
+    pub fn with_custom_mixnet_client(
+        mut self,
+        mixnet_client: nym_sdk::mixnet::MixnetClient,
+    ) -> Self {
+        self.custom_mixnet_client = Some(mixnet_client);
+        self
+    }
+
     /// Start all subsystems
     pub async fn run_service_provider(self) -> Result<(), NetworkRequesterError> {
         // Connect to the mixnet
-        let mixnet_client =
-            create_mixnet_client(&self.config.base, &self.config.storage_paths.common_paths)
-                .await?;
+        let mixnet_client = match self.custom_mixnet_client {
+            Some(mixnet_client) => mixnet_client,
+            None => {
+                create_mixnet_client(&self.config.base, &self.config.storage_paths.common_paths)
+                    .await?
+            }
+        };
 
         // channels responsible for managing messages that are to be sent to the mix network. The receiver is
         // going to be used by `mixnet_response_listener`
@@ -236,15 +253,17 @@ impl NRServiceProviderBuilder {
             .await;
         });
 
-        // start the standard list updater
-        StandardListUpdater::new(
-            self.config
-                .network_requester_debug
-                .standard_list_update_interval,
-            self.standard_list,
-            shutdown.subscribe(),
-        )
-        .start();
+        // start the standard list updater (open proxies never consult it, so don't bother fetching it)
+        if !self.open_proxy {
+            StandardListUpdater::new(
+                self.config
+                    .network_requester_debug
+                    .standard_list_update_interval,
+                self.standard_list,
+                shutdown.subscribe(),
+            )
+            .start();
+        }
 
         // start the allowed.list watcher and updater
         start_allowed_list_reloader(self.allowed_hosts, shutdown.subscribe()).await;
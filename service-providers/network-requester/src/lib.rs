@@ -0,0 +1,10 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+mod allowed_hosts;
+pub mod config;
+pub mod core;
+pub mod error;
+mod reply;
+mod socks5;
+mod statistics;
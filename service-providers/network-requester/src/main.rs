@@ -7,14 +7,10 @@ use nym_network_defaults::setup_env;
 
 use error::NetworkRequesterError;
 
-mod allowed_hosts;
+// the network requester internals live in the library so that they could also be run in-process
+use nym_network_requester::{config, core, error};
+
 mod cli;
-mod config;
-mod core;
-mod error;
-mod reply;
-mod socks5;
-mod statistics;
 
 #[tokio::main]
 async fn main() -> Result<(), NetworkRequesterError> {
@@ -8,6 +8,7 @@ use nym_cli_commands::context::{get_network_details, ClientArgs};
 use nym_validator_client::nyxd::AccountId;
 
 mod completion;
+mod node;
 mod validator;
 
 #[derive(Debug, Parser)]
@@ -65,12 +66,16 @@ pub(crate) enum Commands {
     Block(nym_cli_commands::validator::block::Block),
     /// Manage and execute WASM smart contracts
     Cosmwasm(nym_cli_commands::validator::cosmwasm::Cosmwasm),
-    /// Query for transactions
+    /// Query for transactions, or generate, sign and broadcast them separately (e.g. for offline signing)
     Tx(nym_cli_commands::validator::transactions::Transactions),
     /// Create and query for a vesting schedule
     VestingSchedule(nym_cli_commands::validator::vesting::VestingSchedule),
     /// Manage your mixnet infrastructure, delegate stake or query the directory
     Mixnet(nym_cli_commands::validator::mixnet::Mixnet),
+    /// Propose, vote on and execute actions of a multisig controlling your mixnet infrastructure
+    Multisig(nym_cli_commands::validator::multisig::Multisig),
+    /// Test the performance of mixnodes and gateways by sending packets through them
+    Node(nym_cli_commands::node::Node),
     /// Generates shell completion
     GenerateFig,
 }
@@ -106,7 +111,7 @@ async fn execute(cli: Cli) -> anyhow::Result<()> {
             validator::cosmwasm::execute(args, cosmwasm, &network_details).await?
         }
         Commands::Tx(transactions) => {
-            validator::transactions::execute(transactions, &network_details).await?
+            validator::transactions::execute(transactions, &network_details, mnemonic).await?
         }
         Commands::VestingSchedule(vesting) => {
             validator::vesting::execute(args, vesting, &network_details).await?
@@ -114,6 +119,10 @@ async fn execute(cli: Cli) -> anyhow::Result<()> {
         Commands::Mixnet(mixnet) => {
             validator::mixnet::execute(args, mixnet, &network_details).await?
         }
+        Commands::Multisig(multisig) => {
+            validator::multisig::execute(args, multisig, &network_details).await?
+        }
+        Commands::Node(node) => node::execute(node, &network_details).await?,
         Commands::GenerateFig => {
             let mut cmd = Cli::command();
             completion::print_fig(&mut cmd);
@@ -0,0 +1,22 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use nym_cli_commands::context::create_query_client_with_nym_api;
+use nym_network_defaults::NymNetworkDetails;
+
+pub(crate) async fn execute(
+    node: nym_cli_commands::node::Node,
+    network_details: &NymNetworkDetails,
+) -> anyhow::Result<()> {
+    match node.command {
+        Some(nym_cli_commands::node::NodeCommands::Test(args)) => {
+            nym_cli_commands::node::test::test_node(
+                args,
+                create_query_client_with_nym_api(network_details)?,
+            )
+            .await?
+        }
+        _ => unreachable!(),
+    }
+    Ok(())
+}
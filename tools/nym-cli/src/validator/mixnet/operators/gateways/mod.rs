@@ -4,6 +4,7 @@
 use nym_cli_commands::context::{create_signing_client, ClientArgs};
 use nym_network_defaults::NymNetworkDetails;
 
+pub(crate) mod rewards;
 pub(crate) mod settings;
 
 pub(crate) async fn execute(
@@ -21,6 +22,9 @@ pub(crate) async fn execute(
         nym_cli_commands::validator::mixnet::operators::gateway::MixnetOperatorsGatewayCommands::CreateGatewayBondingSignPayload(args) => {
             nym_cli_commands::validator::mixnet::operators::gateway::gateway_bonding_sign_payload::create_payload(args,create_signing_client(global_args, network_details)?).await
         }
+        nym_cli_commands::validator::mixnet::operators::gateway::MixnetOperatorsGatewayCommands::Rewards(rewards) => {
+            rewards::execute(global_args, rewards, network_details).await?
+        }
         nym_cli_commands::validator::mixnet::operators::gateway::MixnetOperatorsGatewayCommands::Settings(settings) => {
             settings::execute(global_args, settings, network_details).await?
         }
@@ -31,6 +35,15 @@ pub(crate) async fn execute(
             nym_cli_commands::validator::mixnet::operators::gateway::vesting_unbond_gateway::vesting_unbond_gateway(create_signing_client(global_args, network_details)?).await
 
         }
+        nym_cli_commands::validator::mixnet::operators::gateway::MixnetOperatorsGatewayCommands::TransferOwnership(args) => {
+            nym_cli_commands::validator::mixnet::operators::gateway::transfer_ownership::transfer_gateway_ownership(args, create_signing_client(global_args, network_details)?).await
+        }
+        nym_cli_commands::validator::mixnet::operators::gateway::MixnetOperatorsGatewayCommands::AcceptOwnership(args) => {
+            nym_cli_commands::validator::mixnet::operators::gateway::accept_ownership::accept_gateway_ownership(args, create_signing_client(global_args, network_details)?).await
+        }
+        nym_cli_commands::validator::mixnet::operators::gateway::MixnetOperatorsGatewayCommands::CancelOwnershipTransfer(args) => {
+            nym_cli_commands::validator::mixnet::operators::gateway::cancel_ownership_transfer::cancel_gateway_ownership_transfer(args, create_signing_client(global_args, network_details)?).await
+        }
     }
     Ok(())
 }
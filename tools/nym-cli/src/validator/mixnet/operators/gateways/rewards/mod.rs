@@ -0,0 +1,21 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use nym_cli_commands::context::{create_signing_client, ClientArgs};
+use nym_network_defaults::NymNetworkDetails;
+
+pub(crate) async fn execute(
+    global_args: ClientArgs,
+    rewards: nym_cli_commands::validator::mixnet::operators::gateway::rewards::MixnetOperatorsGatewayRewards,
+    network_details: &NymNetworkDetails,
+) -> anyhow::Result<()> {
+    match rewards.command {
+        nym_cli_commands::validator::mixnet::operators::gateway::rewards::MixnetOperatorsGatewayRewardsCommands::Claim(args) => {
+            nym_cli_commands::validator::mixnet::operators::gateway::rewards::claim_gateway_operator_reward::claim_gateway_operator_reward(args, create_signing_client(global_args, network_details)?).await
+        }
+        nym_cli_commands::validator::mixnet::operators::gateway::rewards::MixnetOperatorsGatewayRewardsCommands::VestingClaim(args) => {
+            nym_cli_commands::validator::mixnet::operators::gateway::rewards::vesting_claim_gateway_operator_reward::vesting_claim_gateway_operator_reward(args, create_signing_client(global_args, network_details)?).await
+        }
+    }
+    Ok(())
+}
@@ -48,6 +48,15 @@ pub(crate) async fn execute(
         nym_cli_commands::validator::mixnet::operators::mixnode::MixnetOperatorsMixnodeCommands::DecreasePledgeVesting(args) => {
             nym_cli_commands::validator::mixnet::operators::mixnode::vesting_decrease_pledge::vesting_decrease_pledge(args, create_signing_client(global_args, network_details)?).await
         }
+        nym_cli_commands::validator::mixnet::operators::mixnode::MixnetOperatorsMixnodeCommands::TransferOwnership(args) => {
+            nym_cli_commands::validator::mixnet::operators::mixnode::transfer_ownership::transfer_mixnode_ownership(args, create_signing_client(global_args, network_details)?).await
+        }
+        nym_cli_commands::validator::mixnet::operators::mixnode::MixnetOperatorsMixnodeCommands::AcceptOwnership(args) => {
+            nym_cli_commands::validator::mixnet::operators::mixnode::accept_ownership::accept_mixnode_ownership(args, create_signing_client(global_args, network_details)?).await
+        }
+        nym_cli_commands::validator::mixnet::operators::mixnode::MixnetOperatorsMixnodeCommands::CancelOwnershipTransfer(args) => {
+            nym_cli_commands::validator::mixnet::operators::mixnode::cancel_ownership_transfer::cancel_mixnode_ownership_transfer(args, create_signing_client(global_args, network_details)?).await
+        }
         _ => unreachable!(),
     }
     Ok(())
@@ -23,6 +23,22 @@ pub(crate) async fn execute(
             };
             res
         },
+        nym_cli_commands::validator::mixnet::operators::name::MixnetOperatorsNameCommands::Renew(renew) => {
+            let res = nym_cli_commands::validator::mixnet::operators::name::renew::renew(renew, create_signing_client(global_args, network_details)?).await;
+            match res {
+                Ok(_) => println!("Successfully renewed the name"),
+                Err(_) => println!("Failed to renew name")
+            };
+            res
+        },
+        nym_cli_commands::validator::mixnet::operators::name::MixnetOperatorsNameCommands::Transfer(transfer) => {
+            let res = nym_cli_commands::validator::mixnet::operators::name::transfer::transfer(transfer, create_signing_client(global_args, network_details)?).await;
+            match res {
+                Ok(_) => println!("Successfully transferred the name"),
+                Err(_) => println!("Failed to transfer name")
+            };
+            res
+        },
     };
     Ok(res?)
 }
@@ -5,6 +5,7 @@ pub(crate) mod account;
 pub(crate) mod block;
 pub(crate) mod cosmwasm;
 pub(crate) mod mixnet;
+pub(crate) mod multisig;
 pub(crate) mod signature;
 pub(crate) mod transactions;
 pub(crate) mod vesting;
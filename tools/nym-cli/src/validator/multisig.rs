@@ -0,0 +1,51 @@
+// Copyright 2023 - Nym Technologies SA <contact@nymtech.net>
+// SPDX-License-Identifier: Apache-2.0
+
+use nym_cli_commands::context::{create_query_client, create_signing_client, ClientArgs};
+use nym_cli_commands::validator::multisig::{Multisig, MultisigCommands};
+use nym_network_defaults::NymNetworkDetails;
+
+pub(crate) async fn execute(
+    global_args: ClientArgs,
+    multisig: Multisig,
+    network_details: &NymNetworkDetails,
+) -> anyhow::Result<()> {
+    match multisig.command {
+        MultisigCommands::ProposeMixnet(args) => {
+            nym_cli_commands::validator::multisig::propose_mixnet::propose(
+                args,
+                create_signing_client(global_args, network_details)?,
+            )
+            .await
+        }
+        MultisigCommands::ProposeVesting(args) => {
+            nym_cli_commands::validator::multisig::propose_vesting::propose(
+                args,
+                create_signing_client(global_args, network_details)?,
+            )
+            .await
+        }
+        MultisigCommands::ListProposals(args) => {
+            nym_cli_commands::validator::multisig::list_proposals::list(
+                args,
+                create_query_client(network_details)?,
+            )
+            .await
+        }
+        MultisigCommands::Vote(args) => {
+            nym_cli_commands::validator::multisig::vote::vote(
+                args,
+                create_signing_client(global_args, network_details)?,
+            )
+            .await
+        }
+        MultisigCommands::Execute(args) => {
+            nym_cli_commands::validator::multisig::execute_proposal::execute(
+                args,
+                create_signing_client(global_args, network_details)?,
+            )
+            .await
+        }
+    }
+    Ok(())
+}
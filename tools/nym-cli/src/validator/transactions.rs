@@ -7,6 +7,7 @@ use nym_network_defaults::NymNetworkDetails;
 pub(crate) async fn execute(
     transactions: nym_cli_commands::validator::transactions::Transactions,
     network_details: &NymNetworkDetails,
+    mnemonic: Option<bip39::Mnemonic>,
 ) -> anyhow::Result<()> {
     match transactions.command {
         Some(nym_cli_commands::validator::transactions::TransactionsCommands::Get(args)) => {
@@ -23,6 +24,23 @@ pub(crate) async fn execute(
             )
             .await
         }
+        Some(nym_cli_commands::validator::transactions::TransactionsCommands::Generate(args)) => {
+            nym_cli_commands::validator::transactions::generate_transaction::generate(
+                args,
+                &create_query_client(network_details)?,
+            )
+            .await
+        }
+        Some(nym_cli_commands::validator::transactions::TransactionsCommands::Sign(args)) => {
+            nym_cli_commands::validator::transactions::sign_transaction::sign(args, mnemonic)
+        }
+        Some(nym_cli_commands::validator::transactions::TransactionsCommands::Broadcast(args)) => {
+            nym_cli_commands::validator::transactions::broadcast_transaction::broadcast(
+                args,
+                &create_query_client(network_details)?,
+            )
+            .await
+        }
         _ => unreachable!(),
     }
     Ok(())
@@ -4,6 +4,7 @@ export interface GatewayConfigUpdate {
   host: string;
   mix_port: number;
   clients_port: number;
+  clients_wss_port: number | null;
   location: string;
   version: string;
 }